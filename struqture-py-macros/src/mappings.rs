@@ -33,6 +33,8 @@ pub fn mappings_macro(
         jordan_wigner_spin_to_fermion_quote(&attribute_arguments, &struct_name);
     let jordan_wigner_fermion_to_spin_quote =
         jordan_wigner_fermion_to_spin_quote(&attribute_arguments, &struct_name);
+    let bravyi_kitaev_fermion_to_spin_quote =
+        bravyi_kitaev_fermion_to_spin_quote(&attribute_arguments, &struct_name);
 
     let q = quote! {
 
@@ -43,6 +45,7 @@ pub fn mappings_macro(
 
             #jordan_wigner_spin_to_fermion_quote
             #jordan_wigner_fermion_to_spin_quote
+            #bravyi_kitaev_fermion_to_spin_quote
         }
     };
     q.into()
@@ -154,3 +157,56 @@ fn jordan_wigner_fermion_to_spin_quote(
         TokenStream::new()
     }
 }
+
+fn bravyi_kitaev_fermion_to_spin_quote(
+    attribute_arguments: &AttributeMacroArguments,
+    struct_name: &str,
+) -> TokenStream {
+    if attribute_arguments.contains("BravyiKitaevFermionToSpin") {
+        let output_wrapper_type;
+        let output_type;
+        let from_method;
+
+        if struct_name.contains("System") {
+            let mut output_wrapper_name = format!("{}Wrapper", struct_name);
+            output_wrapper_name = output_wrapper_name.replace("Fermion", "Spin");
+            output_wrapper_type = quote::format_ident!("{}", output_wrapper_name);
+
+            quote! {
+                /// Transform the given fermionic object into a spin object using
+                /// the Bravyi Kitaev mapping.
+                pub fn bravyi_kitaev(&self) -> #output_wrapper_type {
+                    #output_wrapper_type {
+                        internal: self.internal.bravyi_kitaev()
+                    }
+                }
+            }
+        } else {
+            if struct_name == "FermionProduct" {
+                output_wrapper_type = quote::format_ident!("SpinSystemWrapper");
+                output_type = quote::format_ident!("SpinSystem");
+                from_method = quote::format_ident!("from_operator");
+            } else if struct_name == "HermitianFermionProduct" {
+                output_wrapper_type = quote::format_ident!("SpinHamiltonianSystemWrapper");
+                output_type = quote::format_ident!("SpinHamiltonianSystem");
+                from_method = quote::format_ident!("from_hamiltonian");
+            } else {
+                panic!("BravyiKitaevFermionToSpin can only be implemented for fermionic types!")
+            };
+
+            quote! {
+                /// Transform the given fermionic object into a spin object using
+                /// the Bravyi Kitaev mapping.
+                pub fn bravyi_kitaev(&self) -> #output_wrapper_type {
+                    #output_wrapper_type {
+                        internal: #output_type::#from_method(
+                            self.internal.bravyi_kitaev(), None)
+                            .expect("Internal bug when creating spin system object from spin operator object.")
+                    }
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    }
+}
@@ -27,6 +27,9 @@ pub fn noiselesswrapper(
     let items = parsed_input.items;
     let attribute_arguments = parse_macro_input!(metadata as AttributeMacroArguments);
     let (struct_name, struct_ident) = strip_python_wrapper_name(&ident);
+    let iterator_name = format!("{}Iterator", struct_name);
+    let iterator_ident = quote::format_ident!("{}IteratorWrapper", struct_name);
+    let iteration_mode_ident = quote::format_ident!("{}IterationMode", struct_name);
     let index_type = if struct_name.contains("Spin") {
         quote::format_ident!("PauliProductWrapper")
     } else if struct_name.contains("MixedPlusMinusOperator") {
@@ -51,23 +54,72 @@ pub fn noiselesswrapper(
     } else {
         quote::format_ident!("CalculatorComplexWrapper")
     };
+    let convert_batch_value = if struct_name.contains("SpinHamiltonian") {
+        quote! {
+            |value: num_complex::Complex64| -> PyResult<qoqo_calculator::CalculatorFloat> {
+                if value.im != 0.0 {
+                    return Err(PyValueError::new_err(format!(
+                        "Coefficient {} is not real",
+                        value
+                    )));
+                }
+                Ok(qoqo_calculator::CalculatorFloat::from(value.re))
+            }
+        }
+    } else {
+        quote! {
+            |value: num_complex::Complex64| -> PyResult<qoqo_calculator::CalculatorComplex> {
+                Ok(qoqo_calculator::CalculatorComplex::new(value.re, value.im))
+            }
+        }
+    };
+    // Converts the keys and the numpy array of values of the batch functions into entries
+    let parse_batch_entries = quote! {
+        let values: Vec<num_complex::Complex64> =
+            if let Ok(array) = values.extract::<numpy::PyReadonlyArray1<num_complex::Complex64>>() {
+                array.as_array().to_vec()
+            } else if let Ok(array) = values.extract::<numpy::PyReadonlyArray1<f64>>() {
+                array
+                    .as_array()
+                    .iter()
+                    .map(|value| num_complex::Complex64::new(*value, 0.0))
+                    .collect()
+            } else {
+                return Err(PyTypeError::new_err(
+                    "Values are not a one-dimensional numpy array of real or complex numbers",
+                ));
+            };
+        if keys.len() != values.len() {
+            return Err(PyValueError::new_err(format!(
+                "Number of keys {} does not match number of values {}",
+                keys.len(),
+                values.len()
+            )));
+        }
+        let mut entries = Vec::with_capacity(keys.len());
+        for (key, value) in keys.iter().zip(values.into_iter()) {
+            let converted_key = #index_type::from_pyany(key).map_err(|err| {
+                PyValueError::new_err(format!(
+                    "Product could not be constructed: {:?}",
+                    err
+                ))
+            })?;
+            entries.push((converted_key, (#convert_batch_value)(value)?));
+        }
+    };
     // ------------
     // Start the generating part of the macro
     let operate_on_density_matrix_quote = if attribute_arguments.contains("OperateOnDensityMatrix")
     {
         quote! {
-                /// Return a list of the unsorted keys in self.
+                /// Return an iterator over the unsorted keys in self.
+                ///
+                /// The keys are copied when the iterator is created and converted to python objects one per iteration step.
                 ///
                 /// Returns:
-                ///     List[OperatorProduct]: The sequence of keys of the self.
-                pub fn keys(&self) -> Vec<#index_type> {
-                    let mut system_keys: Vec<#index_type> = Vec::new();
-                    for key in self.internal.keys() {
-                        system_keys.push(
-                            #index_type { internal: key.clone() },
-                        );
-                    }
-                    system_keys
+                ///     Iterator[OperatorProduct]: The iterator over the keys of self.
+                pub fn keys(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Keys)
                 }
 
                 /// Return number of entries in self.
@@ -111,6 +163,160 @@ pub fn noiselesswrapper(
                     #ident { internal: self.internal.truncate(threshold) }
                 }
 
+                /// Truncate self by returning the kept and the discarded terms for a threshold.
+                ///
+                /// The discarded part contains the removed terms and, for complex coefficients, the removed
+                /// real or imaginary parts of kept terms, so that the sum of both parts is self.
+                ///
+                /// Args:
+                ///     threshold (float): The threshold for inclusion.
+                ///
+                /// Returns:
+                ///     Tuple[self, self]: The kept and the discarded terms.
+                pub fn truncate_with_remainder(&self, threshold: f64) -> (#ident, #ident) {
+                    let (kept, discarded) = self.internal.truncate_with_remainder(threshold);
+                    (#ident { internal: kept }, #ident { internal: discarded })
+                }
+
+                /// Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+                ///
+                /// The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+                /// of the kept coefficients reaches the given fraction of the 1-norm of self.
+                ///
+                /// Args:
+                ///     fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+                ///
+                /// Returns:
+                ///     Tuple[self, self]: The kept and the dropped terms.
+                ///
+                /// Raises:
+                ///     ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+                pub fn truncate_to_weight(&self, fraction: f64) -> PyResult<(#ident, #ident)> {
+                    let (kept, dropped) = self.internal.truncate_to_weight(fraction).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    Ok((#ident { internal: kept }, #ident { internal: dropped }))
+                }
+
+                /// Return whether self and other are equal within the given tolerances.
+                ///
+                /// Keys missing in one of the objects are treated as having a zero coefficient.
+                /// Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+                /// symbolic coefficients need to match exactly.
+                ///
+                /// Args:
+                ///     other (self): The object to compare to.
+                ///     atol (float): The absolute tolerance.
+                ///     rtol (float): The relative tolerance.
+                ///
+                /// Returns:
+                ///     bool: Whether the objects are approximately equal.
+                #[pyo3(signature = (other, atol = 1e-8, rtol = 1e-5))]
+                pub fn approx_eq(&self, other: #ident, atol: f64, rtol: f64) -> bool {
+                    self.internal.approx_eq(&other.internal, atol, rtol)
+                }
+
+                /// Return the derivative of self with respect to a symbolic parameter.
+                ///
+                /// Every coefficient is differentiated symbolically, terms with a vanishing derivative are removed.
+                ///
+                /// Args:
+                ///     parameter (str): The name of the symbolic parameter.
+                ///
+                /// Returns:
+                ///     self: The derivative of self.
+                ///
+                /// Raises:
+                ///     ValueError: A coefficient cannot be differentiated.
+                pub fn derivative(&self, parameter: &str) -> PyResult<#ident> {
+                    let derivative = self.internal.derivative(parameter).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    Ok(#ident { internal: derivative })
+                }
+
+                /// Return a copy of self with every coefficient replaced by the result of a function.
+                ///
+                /// Terms mapped to a vanishing coefficient are removed.
+                ///
+                /// Args:
+                ///     function (Callable[[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]], Union[CalculatorComplex, CalculatorFloat]]): The function computing the new coefficient from the key and the old coefficient.
+                ///
+                /// Returns:
+                ///     self: The copy of self with the mapped coefficients.
+                ///
+                /// Raises:
+                ///     TypeError: Value returned by the function is not CalculatorComplex or CalculatorFloat.
+                ///     ValueError: A mapped coefficient cannot be set.
+                pub fn map_values(&self, function: &Bound<PyAny>) -> PyResult<#ident> {
+                    let mut mapped = self.internal.empty_clone(Some(self.internal.len()));
+                    for (key, value) in self.internal.iter() {
+                        let new_value = function.call1((
+                            #index_type { internal: key.clone() },
+                            #value_type { internal: value.clone() },
+                        ))?;
+                        let new_value = #value_type::from_pyany(&new_value).map_err(|_| {
+                            PyTypeError::new_err("Value is not CalculatorComplex or CalculatorFloat")
+                        })?;
+                        mapped.set(key.clone(), new_value).map_err(|err| {
+                            PyValueError::new_err(format!("{:?}", err))
+                        })?;
+                    }
+                    Ok(#ident { internal: mapped })
+                }
+
+                /// Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+                ///
+                /// Args:
+                ///     other (self): The object to add to self.
+                ///     policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+                ///
+                /// Returns:
+                ///     self: The merged object.
+                ///
+                /// Raises:
+                ///     ValueError: Unknown merge policy.
+                ///     ValueError: Conflicting symbolic coefficients or objects could not be added.
+                #[pyo3(signature = (other, policy = "sum"))]
+                pub fn add_with_policy(&self, other: #ident, policy: &str) -> PyResult<#ident> {
+                    let merge_policy = <struqture::MergePolicy as std::str::FromStr>::from_str(policy).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    let new_internal = self
+                        .internal
+                        .add_with_policy(&other.internal, merge_policy)
+                        .map_err(|err| {
+                            PyValueError::new_err(format!("Objects could not be added: {:?}", err))
+                        })?;
+                    Ok(#ident { internal: new_internal })
+                }
+
+                /// Return the 1-norm of the coefficients of self, the sum of their absolute values.
+                ///
+                /// Returns:
+                ///     float: The sum of the absolute values of the coefficients.
+                ///
+                /// Raises:
+                ///     ValueError: Self contains symbolic coefficients.
+                pub fn coefficient_1_norm(&self) -> PyResult<f64> {
+                    self.internal.coefficient_1_norm().map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })
+                }
+
+                /// Return the max-norm of the coefficients of self, the largest absolute value.
+                ///
+                /// Returns:
+                ///     float: The largest absolute value of the coefficients.
+                ///
+                /// Raises:
+                ///     ValueError: Self contains symbolic coefficients.
+                pub fn coefficient_max_norm(&self) -> PyResult<f64> {
+                    self.internal.coefficient_max_norm().map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })
+                }
+
                 /// Get the coefficient corresponding to the key.
                 ///
                 /// Args:
@@ -195,6 +401,7 @@ pub fn noiselesswrapper(
                 ///
                 /// Args:
                 ///     key (Product type): The key object
+                ///     value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
                 ///
                 /// Raises:
                 ///     TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -219,18 +426,204 @@ pub fn noiselesswrapper(
                         })
                 }
 
-                /// Return unsorted values in self.
+                /// Return the threshold up to which values are dropped from self.
+                ///
+                /// Returns:
+                ///     Optional[float]: The threshold, None if only exact zeros are dropped.
+                pub fn zero_threshold(&self) -> Option<f64> {
+                    match self.internal.zero_term_policy() {
+                        struqture::ZeroTermPolicy::DropExactZeros => None,
+                        struqture::ZeroTermPolicy::DropBelowThreshold(threshold) => Some(threshold),
+                    }
+                }
+
+                /// Set the threshold up to which values are dropped from self.
+                ///
+                /// Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+                /// Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+                ///
+                /// Args:
+                ///     threshold (float): The threshold.
+                ///
+                /// Raises:
+                ///     ValueError: The threshold is negative or NaN.
+                pub fn set_zero_threshold(&mut self, threshold: f64) -> PyResult<()> {
+                    self.internal.set_zero_threshold(threshold).map_err(|err| {
+                        PyValueError::new_err(format!("Threshold could not be set: {:?}", err))
+                    })
+                }
+
+                /// Add many (key object, value) pairs to the existing entries at once.
+                ///
+                /// The keys are parsed before any entry is added, the entries are added without holding the GIL.
+                ///
+                /// Args:
+                ///     keys (List[Union[str, Product type]]): The key objects.
+                ///     values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+                ///
+                /// Raises:
+                ///     TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+                ///     ValueError: Number of keys does not match number of values.
+                ///     ValueError: Product could not be constructed.
+                ///     ValueError: Error in add_operator_product function of self.
+                pub fn add_operator_products(
+                    &mut self,
+                    py: Python,
+                    keys: Vec<Bound<PyAny>>,
+                    values: &Bound<PyAny>,
+                ) -> PyResult<()> {
+                    #parse_batch_entries
+                    let internal = &mut self.internal;
+                    py.allow_threads(move || {
+                        for (key, value) in entries {
+                            internal.add_operator_product(key, value)?;
+                        }
+                        Ok(())
+                    })
+                    .map_err(|err: struqture::StruqtureError| {
+                        PyValueError::new_err(format!(
+                            "Error in add_operator_product function of System: {:?}",
+                            err
+                        ))
+                    })
+                }
+
+                /// Overwrite the values of many keys at once.
+                ///
+                /// The keys are parsed before any entry is set, the entries are set without holding the GIL.
+                ///
+                /// Args:
+                ///     keys (List[Union[str, Product type]]): The key objects.
+                ///     values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+                ///
+                /// Raises:
+                ///     TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+                ///     ValueError: Number of keys does not match number of values.
+                ///     ValueError: Product could not be constructed.
+                ///     ValueError: Error in set function of self.
+                pub fn set_operator_products(
+                    &mut self,
+                    py: Python,
+                    keys: Vec<Bound<PyAny>>,
+                    values: &Bound<PyAny>,
+                ) -> PyResult<()> {
+                    #parse_batch_entries
+                    let internal = &mut self.internal;
+                    py.allow_threads(move || {
+                        for (key, value) in entries {
+                            internal.set(key, value)?;
+                        }
+                        Ok(())
+                    })
+                    .map_err(|err: struqture::StruqtureError| {
+                        PyValueError::new_err(format!(
+                            "Error in set function of System: {:?}",
+                            err
+                        ))
+                    })
+                }
+
+                /// Return an iterator over the unsorted values in self.
+                ///
+                /// The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
+                pub fn values(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Values)
+                }
+
+                /// Return an iterator over the unsorted (key, value) pairs in self.
+                ///
+                /// The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
+                pub fn items(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Items)
+                }
+
+                /// Return an iterator over the unsorted keys in self.
+                ///
+                /// The keys are copied when the iterator is created and converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[OperatorProduct]: The iterator over the keys of self.
+                pub fn __iter__(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Keys)
+                }
+
+                /// Return whether the key is an entry of self.
+                ///
+                /// Args:
+                ///     key (Product type): The key to look up.
+                ///
+                /// Returns:
+                ///     bool: Whether self contains an entry for the key.
+                pub fn __contains__(&self, key: &Bound<PyAny>) -> bool {
+                    match #index_type::from_pyany(key) {
+                        Ok(converted_key) => self.internal.keys().any(|existing| existing == &converted_key),
+                        Err(_) => false,
+                    }
+                }
+
+                /// Get the coefficient of an existing entry in self.
+                ///
+                /// Args:
+                ///     key (Product type): The key of the value to get.
                 ///
                 /// Returns:
-                ///     List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
-                pub fn values(&self) -> Vec<#value_type> {
-                    let mut system_values: Vec<#value_type> = Vec::new();
-                    for val in self.internal.values() {
-                        system_values.push(
-                            #value_type { internal: val.clone() },
-                        );
+                ///     Union[CalculatorComplex, CalculatorFloat]: The value at the key.
+                ///
+                /// Raises:
+                ///     ValueError: Product could not be constructed.
+                ///     KeyError: Self has no entry for the key.
+                pub fn __getitem__(&self, key: &Bound<PyAny>) -> PyResult<#value_type> {
+                    let converted_key = #index_type::from_pyany(key).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Product could not be constructed: {:?}",
+                            err
+                        ))
+                    })?;
+                    match self.internal.iter().find(|(existing, _)| *existing == &converted_key) {
+                        Some((_, val)) => Ok(#value_type { internal: val.clone() }),
+                        None => Err(pyo3::exceptions::PyKeyError::new_err(format!(
+                            "{}",
+                            converted_key
+                        ))),
+                    }
+                }
+
+                /// Overwrite an existing entry or set a new entry in self.
+                ///
+                /// Args:
+                ///     key (Product type): The key to set.
+                ///     value (Union[CalculatorComplex, CalculatorFloat]): The value to set.
+                ///
+                /// Raises:
+                ///     TypeError: Value is not CalculatorComplex or CalculatorFloat.
+                ///     ValueError: Product could not be constructed.
+                ///     ValueError: Error in set function of self.
+                pub fn __setitem__(&mut self, key: &Bound<PyAny>, value: &Bound<PyAny>) -> PyResult<()> {
+                    self.set(key, value).map(|_| ())
+                }
+
+                /// Remove an existing entry from self.
+                ///
+                /// Args:
+                ///     key (Product type): The key of the entry to remove.
+                ///
+                /// Raises:
+                ///     ValueError: Product could not be constructed.
+                ///     KeyError: Self has no entry for the key.
+                pub fn __delitem__(&mut self, key: &Bound<PyAny>) -> PyResult<()> {
+                    match self.remove(key)? {
+                        Some(_) => Ok(()),
+                        None => Err(pyo3::exceptions::PyKeyError::new_err(format!(
+                            "{}",
+                            key
+                        ))),
                     }
-                    system_values
                 }
         }
     } else {
@@ -300,54 +693,349 @@ pub fn noiselesswrapper(
                     self.internal.current_number_spins()
                 }
 
-                /// Return the number_spins input of self.
+                /// Return the number_spins input of self.
+                ///
+                /// Returns:
+                ///     int: The number of spins in self.
+                pub fn number_spins(&self) -> usize {
+                    self.internal.number_spins()
+                }
+
+                /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations.
+                ///
+                /// Args:
+                ///     number_spins (int): Number of spins to filter for in the keys.
+                ///
+                /// Returns:
+                ///     Tuple[Self, Self]: Operator with the noise terms where the number of spins matches the number of spins the operator product acts on and Operator with all other contributions.
+                ///
+                /// Raises:
+                ///     ValueError: Error in adding terms to return values.
+        pub fn separate_into_n_terms(&self, number_spins: usize) -> PyResult<(#ident, #ident)> {
+                    let (separated, remainder) = self.internal.separate_into_n_terms(number_spins).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+                    Ok((
+                        #ident { internal: separated },
+                        #ident { internal: remainder }
+                    ))
+                }
+            }
+    } else {
+        TokenStream::new()
+    };
+    let to_sparse_matrix_operator_quote = if attribute_arguments.contains("ToSparseMatrixOperator")
+    {
+        quote! {
+                /// Constructs the sparse matrix representation of self as a scipy COO matrix with a given number of spins.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
+                    let coo = self
+                        .internal
+                        .sparse_matrix_coo(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo(coo)
+                }
+
+                /// Constructs the sparse matrix representation of self as a COO matrix with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex operator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_coo_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCooMatrixF32, Py<PyDict>)> {
+                    let (coo, diagnostics) = self
+                        .internal
+                        .sparse_matrix_coo_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex operator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo_f32(coo, diagnostics)
+                }
+
+                /// Constructs the sparse matrix representation of self as a CSR matrix with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex operator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_csr_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCsrMatrixF32, Py<PyDict>)> {
+                    let (csr, diagnostics) = self
+                        .internal
+                        .sparse_matrix_csr_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex operator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr_f32(csr, diagnostics)
+                }
+
+                /// Constructs the sparse matrix representation of self as a CSR matrix.
+                ///
+                /// The result can be passed directly to `scipy.sparse.csr_matrix`.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr(csr)
+                }
+
+                /// Constructs the sparse matrix representation of self as a scipy.sparse.csr_matrix.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     scipy.sparse.csr_matrix: The matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     ImportError: scipy is not installed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn to_scipy_sparse(&self, number_spins: Option<usize>) -> PyResult<PyObject> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_scipy_csr(csr)
+                }
+
+                /// Constructs the matrix representation of self as a qutip.Qobj.
+                ///
+                /// The Qobj has one subsystem of dimension two per spin. Since spin 0 corresponds to the least
+                /// significant bit of the basis states, the first subsystem of the Qobj is the spin with the highest index.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins in self.
+                ///
+                /// Returns:
+                ///     qutip.Qobj: The operator representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     ImportError: qutip or scipy is not installed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn to_qutip(&self, number_spins: Option<usize>) -> PyResult<PyObject> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_qutip_qobj(csr)
+                }
+        }
+    } else {
+        TokenStream::new()
+    };
+    let to_sparse_matrix_superoperator_quote = if attribute_arguments
+        .contains("ToSparseMatrixSuperOperator")
+    {
+        quote! {
+                /// Construct the sparse matrix representation of the superoperator in COO representation.
+                ///
+                /// The superoperator for the operator O is defined as the Matrix S so that
+                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+                /// and `flatten` flattens a matrix into a vector in row-major form.
+                ///
+                /// Args:
+                ///     number_spins: The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
+                    let coo = self
+                        .internal
+                        .sparse_matrix_superoperator_coo(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo(coo)
+                }
+
+                /// Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_coo_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCooMatrixF32, Py<PyDict>)> {
+                    let (coo, diagnostics) = self
+                        .internal
+                        .sparse_matrix_superoperator_coo_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo_f32(coo, diagnostics)
+                }
+
+                /// Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
                 ///
                 /// Returns:
-                ///     int: The number of spins in self.
-                pub fn number_spins(&self) -> usize {
-                    self.internal.number_spins()
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCsrMatrixF32, Py<PyDict>)> {
+                    let (csr, diagnostics) = self
+                        .internal
+                        .sparse_matrix_superoperator_csr_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr_f32(csr, diagnostics)
                 }
 
-                /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations.
+                /// Construct the sparse matrix representation of the superoperator in CSR representation.
+                ///
+                /// The result can be passed directly to `scipy.sparse.csr_matrix`.
                 ///
                 /// Args:
-                ///     number_spins (int): Number of spins to filter for in the keys.
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
                 ///
                 /// Returns:
-                ///     Tuple[Self, Self]: Operator with the noise terms where the number of spins matches the number of spins the operator product acts on and Operator with all other contributions.
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
                 ///
                 /// Raises:
-                ///     ValueError: Error in adding terms to return values.
-        pub fn separate_into_n_terms(&self, number_spins: usize) -> PyResult<(#ident, #ident)> {
-                    let (separated, remainder) = self.internal.separate_into_n_terms(number_spins).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
-                    Ok((
-                        #ident { internal: separated },
-                        #ident { internal: remainder }
-                    ))
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr(csr)
                 }
-            }
-    } else {
-        TokenStream::new()
-    };
-    let to_sparse_matrix_operator_quote = if attribute_arguments.contains("ToSparseMatrixOperator")
-    {
-        quote! {
-                /// Constructs the sparse matrix representation of self as a scipy COO matrix with a given number of spins.
+
+                /// Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
                 ///
                 /// Args:
-                ///     number_spins (Optional[int]): The number of spins in self.
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
                 ///
                 /// Returns:
-                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+                ///     scipy.sparse.csr_matrix: The superoperator matrix representation of self.
                 ///
                 /// Raises:
                 ///     ValueError: CalculatorError.
                 ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     ImportError: scipy is not installed.
                 #[pyo3(signature = (number_spins = None))]
-                pub fn sparse_matrix_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
-                    let coo = self
+                pub fn sparse_matrix_superoperator_scipy(&self, number_spins: Option<usize>) -> PyResult<PyObject> {
+                    let csr = self
                         .internal
-                        .sparse_matrix_coo(number_spins)
+                        .sparse_matrix_superoperator_csr(number_spins)
                         .map_err(|err| match err {
                             StruqtureError::CalculatorError(c_err) => {
                                 PyValueError::new_err(format!("{}", c_err))
@@ -356,36 +1044,28 @@ pub fn noiselesswrapper(
                                 "Could not convert to complex superoperator matrix".to_string(),
                             ),
                         })?;
-                    to_py_coo(coo)
+                    to_scipy_csr(csr)
                 }
-        }
-    } else {
-        TokenStream::new()
-    };
-    let to_sparse_matrix_superoperator_quote = if attribute_arguments
-        .contains("ToSparseMatrixSuperOperator")
-    {
-        quote! {
-                /// Construct the sparse matrix representation of the superoperator in COO representation.
+
+                /// Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
                 ///
-                /// The superoperator for the operator O is defined as the Matrix S so that
-                /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
-                /// and `flatten` flattens a matrix into a vector in row-major form.
+                /// The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
                 ///
                 /// Args:
-                ///     number_spins: The number of spins to construct the matrix for.
+                ///     time (float): The time for which the channel is constructed.
+                ///     number_spins (Optional[int]): The number of spins to construct the channel for.
                 ///
                 /// Returns:
-                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
                 ///
                 /// Raises:
                 ///     ValueError: CalculatorError.
                 ///     RuntimeError: Could not convert to complex superoperator matrix.
-                #[pyo3(signature = (number_spins = None))]
-                pub fn sparse_matrix_superoperator_coo(&self, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
+                #[pyo3(signature = (time, number_spins = None))]
+                pub fn channel_superoperator_coo(&self, time: f64, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
                     let coo = self
                         .internal
-                        .sparse_matrix_superoperator_coo(number_spins)
+                        .channel_superoperator_coo(time, number_spins)
                         .map_err(|err| match err {
                             StruqtureError::CalculatorError(c_err) => {
                                 PyValueError::new_err(format!("{}", c_err))
@@ -579,7 +1259,78 @@ pub fn noiselesswrapper(
     } else {
         TokenStream::new()
     };
+    let iterator_quote = if attribute_arguments.contains("OperateOnDensityMatrix") {
+        quote! {
+            /// The part of the entries returned by the iterator over a struqture object.
+            #[derive(Clone, Copy)]
+            enum #iteration_mode_ident {
+                Keys,
+                Values,
+                Items,
+            }
+
+            /// Iterator over the entries of a struqture object, converting one entry per step.
+            ///
+            /// The keys are copied when the iterator is created, the values are looked up in the
+            /// iterated object when they are reached. Entries removed from the iterated object
+            /// during the iteration are skipped, entries added during the iteration are not seen.
+            #[pyclass(name = #iterator_name)]
+            pub struct #iterator_ident {
+                owner: Py<#ident>,
+                keys: std::vec::IntoIter<#index_type>,
+                mode: #iteration_mode_ident,
+            }
+
+            impl #iterator_ident {
+                /// Create an iterator over a snapshot of the keys of the struqture object.
+                fn new(slf: pyo3::PyRef<'_, #ident>, mode: #iteration_mode_ident) -> Self {
+                    let keys = slf.internal.keys().map(|key| #index_type { internal: key.clone() }).collect::<Vec<_>>();
+                    #iterator_ident {
+                        owner: slf.into(),
+                        keys: keys.into_iter(),
+                        mode,
+                    }
+                }
+            }
+
+            #[pymethods]
+            impl #iterator_ident {
+                /// Return the iterator itself.
+                ///
+                /// Returns:
+                ///     Iterator: The iterator.
+                pub fn __iter__(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+                    slf
+                }
+
+                /// Return the next key, value or (key, value) pair of the iterated object.
+                ///
+                /// Returns:
+                ///     The next entry, None stops the iteration.
+                pub fn __next__(&mut self, py: Python) -> Option<PyObject> {
+                    let owner = self.owner.borrow(py);
+                    for key in self.keys.by_ref() {
+                        let value = owner.internal.get(&key.internal);
+                        // Terms are never stored with a value dropped by the policy, such a value marks a removed entry
+                        if owner.internal.zero_term_policy().drops(value) {
+                            continue;
+                        }
+                        let value = #value_type { internal: value.clone() };
+                        return Some(match self.mode {
+                            #iteration_mode_ident::Keys => key.into_py(py),
+                            #iteration_mode_ident::Values => value.into_py(py),
+                            #iteration_mode_ident::Items => (key, value).into_py(py),
+                        });
+                    }
+                    None
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let q = quote! {
+        #iterator_quote
 
         impl #ident {
             /// Fallible conversion of generic python object.
@@ -686,6 +1437,45 @@ pub fn noiselesswrapper(
                 Ok(b)
             }
 
+            /// Return the state of self used by pickle.
+            ///
+            /// Returns:
+            ///     bytes: The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __getstate__(&self, py: Python) -> PyResult<Py<pyo3::types::PyBytes>> {
+                let serialized = bincode::serialize(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                Ok(pyo3::types::PyBytes::new_bound(py, &serialized[..]).unbind())
+            }
+
+            /// Set the state of self from the state created by `__getstate__`.
+            ///
+            /// Args:
+            ///     state (bytes): The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     TypeError: State cannot be converted to byte array.
+            ///     ValueError: State cannot be deserialized.
+            pub fn __setstate__(&mut self, state: &Bound<PyAny>) -> PyResult<()> {
+                self.internal = #ident::from_bincode(state)?.internal;
+                Ok(())
+            }
+
+            /// Return the information pickle needs to reconstruct self.
+            ///
+            /// Returns:
+            ///     Tuple[Callable, Tuple[bytes]]: The `from_bincode` function of the class and the serialized object.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (Py<pyo3::types::PyBytes>,))> {
+                let constructor = py.get_type_bound::<#ident>().getattr("from_bincode")?;
+                Ok((constructor.unbind(), (self.__getstate__(py)?,)))
+            }
+
             /// Return the json representation of self.
             ///
             /// Returns:
@@ -699,6 +1489,114 @@ pub fn noiselesswrapper(
                 Ok(serialized)
             }
 
+            /// Return the canonical json representation of self.
+            ///
+            /// The terms are sorted by their keys, so that equal objects always have identical json representations.
+            ///
+            /// Returns:
+            ///     str: The serialized form of self with sorted terms.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to json.
+            pub fn to_json_canonical(&self) -> PyResult<String> {
+                struqture::SerializeCanonical::to_json_canonical(&self.internal)
+                    .map_err(|_| PyValueError::new_err("Cannot serialize object to json".to_string()))
+            }
+
+            /// Return the MessagePack representation of self.
+            ///
+            /// Returns:
+            ///     bytearray: The serialized object (in MessagePack form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            #[cfg(feature = "msgpack")]
+            pub fn to_msgpack(&self) -> PyResult<Py<PyByteArray>> {
+                let serialized = struqture::SerializeBinary::to_msgpack(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+                    PyByteArray::new_bound(py, &serialized[..]).into()
+                });
+                Ok(b)
+            }
+
+            /// Convert the MessagePack representation of self to an instance.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in MessagePack form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     ValueError: Input cannot be deserialized.
+            #[cfg(feature = "msgpack")]
+            #[staticmethod]
+            pub fn from_msgpack(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_gil_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+                Ok(#ident {
+                    internal: struqture::SerializeBinary::from_msgpack(&bytes[..]).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be deserialized from bytes. {}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
+            /// Return the CBOR representation of self.
+            ///
+            /// Returns:
+            ///     bytearray: The serialized object (in CBOR form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            #[cfg(feature = "cbor")]
+            pub fn to_cbor(&self) -> PyResult<Py<PyByteArray>> {
+                let serialized = struqture::SerializeBinary::to_cbor(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+                    PyByteArray::new_bound(py, &serialized[..]).into()
+                });
+                Ok(b)
+            }
+
+            /// Convert the CBOR representation of self to an instance.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in CBOR form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     ValueError: Input cannot be deserialized.
+            #[cfg(feature = "cbor")]
+            #[staticmethod]
+            pub fn from_cbor(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_gil_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+                Ok(#ident {
+                    internal: struqture::SerializeBinary::from_cbor(&bytes[..]).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be deserialized from bytes. {}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
             /// Convert the json representation of self to an instance.
             ///
             /// Args:
@@ -722,6 +1620,88 @@ pub fn noiselesswrapper(
                 })
             }
 
+            /// Convert the json representation of a struqture 2.x object to an instance.
+            ///
+            /// The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+            /// to a SpinHamiltonianSystem without a fixed number of spins.
+            ///
+            /// Args:
+            ///     input (str): The serialized struqture 2.x object in json form.
+            ///
+            /// Returns:
+            ///     The deserialized object.
+            ///
+            /// Raises:
+            ///     ValueError: Input cannot be migrated or deserialized.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_json_struqture_2(input: String) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::migrate::Struqture2Json::from_struqture_2_json(&input)
+                        .map_err(|err| {
+                            PyValueError::new_err(format!(
+                                "Input cannot be migrated or deserialized: {}",
+                                err
+                            ))
+                        })?,
+                })
+            }
+
+            /// Return the json representation of the corresponding struqture 2.x object.
+            ///
+            /// The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+            /// is exported as a PauliHamiltonian.
+            ///
+            /// Returns:
+            ///     str: The serialized struqture 2.x object.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot convert self to struqture 2.x.
+            pub fn to_json_struqture_2(&self) -> PyResult<String> {
+                struqture::migrate::Struqture2Json::to_struqture_2_json(&self.internal).map_err(
+                    |err| {
+                        PyValueError::new_err(format!(
+                            "Cannot convert self to struqture 2.x: {}",
+                            err
+                        ))
+                    },
+                )
+            }
+
+            /// Return the canonical text representation of self.
+            ///
+            /// The canonical text contains one line per term, sorted independently of the insertion order,
+            /// and is intended for committing model definitions to version control.
+            ///
+            /// Returns:
+            ///     str: The canonical text representation of self.
+            pub fn to_canonical_text(&self) -> String {
+                struqture::CanonicalText::to_canonical_text(&self.internal)
+            }
+
+            /// Convert the canonical text representation of self to an instance.
+            ///
+            /// Args:
+            ///     input (str): The canonical text representation of the object.
+            ///
+            /// Returns:
+            ///     The object represented by the canonical text.
+            ///
+            /// Raises:
+            ///     ValueError: Input cannot be parsed.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_canonical_text(input: &str) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::CanonicalText::from_canonical_text(input).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be parsed: {:?}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
             /// Return a string containing a printable representation of self.
             ///
             /// Returns:
@@ -28,6 +28,9 @@ pub fn noisywrapper(
     let items = parsed_input.items;
     let attribute_arguments = parse_macro_input!(metadata as AttributeMacroArguments);
     let (struct_name, struct_ident) = strip_python_wrapper_name(&ident);
+    let iterator_name = format!("{}Iterator", struct_name);
+    let iterator_ident = quote::format_ident!("{}IteratorWrapper", struct_name);
+    let iteration_mode_ident = quote::format_ident!("{}IterationMode", struct_name);
     let index_type = if struct_name.contains("Spin") {
         quote::format_ident!("DecoherenceProductWrapper")
     } else if struct_name.contains("PlusMinus") {
@@ -209,32 +212,51 @@ pub fn noisywrapper(
                     })
                 }
 
-                /// Return unsorted keys in self.
+                /// Return the threshold up to which values are dropped from self.
                 ///
                 /// Returns:
-                ///     List[(OperatorProduct, OperatorProduct)]: The sequence of keys of self.
-                pub fn keys(&self) -> Vec<(#index_type, #index_type)> {
-                    let mut system_keys: Vec<(#index_type, #index_type)> = Vec::new();
-                    for (key_l, key_r) in self.internal.keys() {
-                        system_keys.push(
-                            (#index_type { internal: key_l.clone() }, #index_type { internal: key_r.clone() })
-                        );
+                ///     Optional[float]: The threshold, None if only exact zeros are dropped.
+                pub fn zero_threshold(&self) -> Option<f64> {
+                    match self.internal.zero_term_policy() {
+                        struqture::ZeroTermPolicy::DropExactZeros => None,
+                        struqture::ZeroTermPolicy::DropBelowThreshold(threshold) => Some(threshold),
+                    }
                 }
-                    system_keys
+
+                /// Set the threshold up to which values are dropped from self.
+                ///
+                /// Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+                /// Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+                ///
+                /// Args:
+                ///     threshold (float): The threshold.
+                ///
+                /// Raises:
+                ///     ValueError: The threshold is negative or NaN.
+                pub fn set_zero_threshold(&mut self, threshold: f64) -> PyResult<()> {
+                    self.internal.set_zero_threshold(threshold).map_err(|err| {
+                        PyValueError::new_err(format!("Threshold could not be set: {:?}", err))
+                    })
                 }
 
-                /// Return unsorted values in self.
+                /// Return an iterator over the unsorted keys in self.
+                ///
+                /// The keys are copied when the iterator is created and converted to python objects one per iteration step.
                 ///
                 /// Returns:
-                ///     List[CalculatorComplex]: The sequence of values of self.
-                pub fn values(&self) -> Vec<CalculatorComplexWrapper> {
-                    let mut system_values: Vec<CalculatorComplexWrapper> = Vec::new();
-                    for val in self.internal.values() {
-                        system_values.push(
-                            CalculatorComplexWrapper { internal: val.clone() },
-                        );
-                    }
-                    system_values
+                ///     Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+                pub fn keys(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Keys)
+                }
+
+                /// Return an iterator over the unsorted values in self.
+                ///
+                /// The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[CalculatorComplex]: The iterator over the values of self.
+                pub fn values(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Values)
                 }
 
                 /// Return number of entries in object.
@@ -245,6 +267,119 @@ pub fn noisywrapper(
                     self.internal.len()
                 }
 
+                /// Return an iterator over the unsorted (key, value) pairs in self.
+                ///
+                /// The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[Tuple[(OperatorProduct, OperatorProduct), CalculatorComplex]]: The iterator over the entries of self.
+                pub fn items(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Items)
+                }
+
+                /// Return an iterator over the unsorted keys in self.
+                ///
+                /// The keys are copied when the iterator is created and converted to python objects one per iteration step.
+                ///
+                /// Returns:
+                ///     Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+                pub fn __iter__(slf: pyo3::PyRef<'_, Self>) -> #iterator_ident {
+                    #iterator_ident::new(slf, #iteration_mode_ident::Keys)
+                }
+
+                /// Return whether the key is an entry of self.
+                ///
+                /// Args:
+                ///     key (Tuple[Product type, Product type]): The key to look up.
+                ///
+                /// Returns:
+                ///     bool: Whether self contains an entry for the key.
+                pub fn __contains__(&self, key: &Bound<PyAny>) -> bool {
+                    let Ok((left, right)) = key.extract::<(Bound<PyAny>, Bound<PyAny>)>() else {
+                        return false;
+                    };
+                    match (#index_type::from_pyany(&left), #index_type::from_pyany(&right)) {
+                        (Ok(converted_left), Ok(converted_right)) => self
+                            .internal
+                            .keys()
+                            .any(|(key_l, key_r)| key_l == &converted_left && key_r == &converted_right),
+                        _ => false,
+                    }
+                }
+
+                /// Get the coefficient of an existing entry in self.
+                ///
+                /// Args:
+                ///     key (Tuple[Product type, Product type]): The key of the value to get.
+                ///
+                /// Returns:
+                ///     CalculatorComplex: The value at the key.
+                ///
+                /// Raises:
+                ///     ValueError: Left-hand product could not be constructed from key.
+                ///     ValueError: Right-hand product could not be constructed from key.
+                ///     KeyError: Self has no entry for the key.
+                pub fn __getitem__(&self, key: (Py<PyAny>, Py<PyAny>)) -> PyResult<CalculatorComplexWrapper> {
+                    Python::with_gil(|py| -> PyResult<CalculatorComplexWrapper> {
+                        let (converted_left, converted_right) = (
+                            #index_type::from_pyany(key.0.bind(py)).map_err(|err| {
+                                PyValueError::new_err(format!(
+                                    "Product could not be constructed: {:?}",
+                                    err
+                                ))
+                            })?,
+                            #index_type::from_pyany(key.1.bind(py)).map_err(|err| {
+                                PyValueError::new_err(format!(
+                                    "Product could not be constructed: {:?}",
+                                    err
+                                ))
+                            })?,
+                        );
+                        match self.internal.iter().find(|((key_l, key_r), _)| {
+                            key_l == &converted_left && key_r == &converted_right
+                        }) {
+                            Some((_, val)) => Ok(CalculatorComplexWrapper { internal: val.clone() }),
+                            None => Err(pyo3::exceptions::PyKeyError::new_err(format!(
+                                "({}, {})",
+                                converted_left, converted_right
+                            ))),
+                        }
+                    })
+                }
+
+                /// Overwrite an existing entry or set a new entry in self.
+                ///
+                /// Args:
+                ///     key (Tuple[Product type, Product type]): The key of the value to set.
+                ///     value (CalculatorComplex): The value to set.
+                ///
+                /// Raises:
+                ///     TypeError: Value is not CalculatorComplex.
+                ///     ValueError: Left-hand Product could not be constructed.
+                ///     ValueError: Right-hand Product could not be constructed.
+                ///     ValueError: Error in set function of self.
+                pub fn __setitem__(&mut self, key: (Py<PyAny>, Py<PyAny>), value: &Bound<PyAny>) -> PyResult<()> {
+                    self.set(key, value).map(|_| ())
+                }
+
+                /// Remove an existing entry from self.
+                ///
+                /// Args:
+                ///     key (Tuple[Product type, Product type]): The key of the entry to remove.
+                ///
+                /// Raises:
+                ///     ValueError: Left-hand Product could not be constructed.
+                ///     ValueError: Right-hand Product could not be constructed.
+                ///     KeyError: Self has no entry for the key.
+                pub fn __delitem__(&mut self, key: (Py<PyAny>, Py<PyAny>)) -> PyResult<()> {
+                    match self.remove(key)? {
+                        Some(_) => Ok(()),
+                        None => Err(pyo3::exceptions::PyKeyError::new_err(
+                            "Self has no entry for the key",
+                        )),
+                    }
+                }
+
                 /// Return an instance of self that has no entries but clones all other properties, with the given capacity.
                 ///
                 /// Args:
@@ -278,6 +413,162 @@ pub fn noisywrapper(
                     #ident { internal: self.internal.truncate(threshold) }
                 }
 
+                /// Truncate self by returning the kept and the discarded terms for a threshold.
+                ///
+                /// The discarded part contains the removed terms and, for complex coefficients, the removed
+                /// real or imaginary parts of kept terms, so that the sum of both parts is self.
+                ///
+                /// Args:
+                ///     threshold (float): The threshold for inclusion.
+                ///
+                /// Returns:
+                ///     Tuple[self, self]: The kept and the discarded terms.
+                pub fn truncate_with_remainder(&self, threshold: f64) -> (#ident, #ident) {
+                    let (kept, discarded) = self.internal.truncate_with_remainder(threshold);
+                    (#ident { internal: kept }, #ident { internal: discarded })
+                }
+
+                /// Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+                ///
+                /// The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+                /// of the kept coefficients reaches the given fraction of the 1-norm of self.
+                ///
+                /// Args:
+                ///     fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+                ///
+                /// Returns:
+                ///     Tuple[self, self]: The kept and the dropped terms.
+                ///
+                /// Raises:
+                ///     ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+                pub fn truncate_to_weight(&self, fraction: f64) -> PyResult<(#ident, #ident)> {
+                    let (kept, dropped) = self.internal.truncate_to_weight(fraction).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    Ok((#ident { internal: kept }, #ident { internal: dropped }))
+                }
+
+                /// Return whether self and other are equal within the given tolerances.
+                ///
+                /// Keys missing in one of the objects are treated as having a zero coefficient.
+                /// Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+                /// symbolic coefficients need to match exactly.
+                ///
+                /// Args:
+                ///     other (self): The object to compare to.
+                ///     atol (float): The absolute tolerance.
+                ///     rtol (float): The relative tolerance.
+                ///
+                /// Returns:
+                ///     bool: Whether the objects are approximately equal.
+                #[pyo3(signature = (other, atol = 1e-8, rtol = 1e-5))]
+                pub fn approx_eq(&self, other: #ident, atol: f64, rtol: f64) -> bool {
+                    self.internal.approx_eq(&other.internal, atol, rtol)
+                }
+
+                /// Return the derivative of self with respect to a symbolic parameter.
+                ///
+                /// Every coefficient is differentiated symbolically, terms with a vanishing derivative are removed.
+                ///
+                /// Args:
+                ///     parameter (str): The name of the symbolic parameter.
+                ///
+                /// Returns:
+                ///     self: The derivative of self.
+                ///
+                /// Raises:
+                ///     ValueError: A coefficient cannot be differentiated.
+                pub fn derivative(&self, parameter: &str) -> PyResult<#ident> {
+                    let derivative = self.internal.derivative(parameter).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    Ok(#ident { internal: derivative })
+                }
+
+                /// Return a copy of self with every coefficient replaced by the result of a function.
+                ///
+                /// Terms mapped to a vanishing coefficient are removed.
+                ///
+                /// Args:
+                ///     function (Callable[[Tuple[OperatorProduct, OperatorProduct], CalculatorComplex], CalculatorComplex]): The function computing the new coefficient from the key and the old coefficient.
+                ///
+                /// Returns:
+                ///     self: The copy of self with the mapped coefficients.
+                ///
+                /// Raises:
+                ///     TypeError: Value returned by the function is not CalculatorComplex.
+                ///     ValueError: A mapped coefficient cannot be set.
+                pub fn map_values(&self, function: &Bound<PyAny>) -> PyResult<#ident> {
+                    let mut mapped = self.internal.empty_clone(Some(self.internal.len()));
+                    for (key, value) in self.internal.iter() {
+                        let new_value = function.call1((
+                            (
+                                #index_type { internal: key.0.clone() },
+                                #index_type { internal: key.1.clone() },
+                            ),
+                            CalculatorComplexWrapper { internal: value.clone() },
+                        ))?;
+                        let new_value = qoqo_calculator_pyo3::convert_into_calculator_complex(&new_value)
+                            .map_err(|_| PyTypeError::new_err("Value is not CalculatorComplex"))?;
+                        mapped.set(key.clone(), new_value).map_err(|err| {
+                            PyValueError::new_err(format!("{:?}", err))
+                        })?;
+                    }
+                    Ok(#ident { internal: mapped })
+                }
+
+                /// Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+                ///
+                /// Args:
+                ///     other (self): The object to add to self.
+                ///     policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+                ///
+                /// Returns:
+                ///     self: The merged object.
+                ///
+                /// Raises:
+                ///     ValueError: Unknown merge policy.
+                ///     ValueError: Conflicting symbolic coefficients or objects could not be added.
+                #[pyo3(signature = (other, policy = "sum"))]
+                pub fn add_with_policy(&self, other: #ident, policy: &str) -> PyResult<#ident> {
+                    let merge_policy = <struqture::MergePolicy as std::str::FromStr>::from_str(policy).map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })?;
+                    let new_internal = self
+                        .internal
+                        .add_with_policy(&other.internal, merge_policy)
+                        .map_err(|err| {
+                            PyValueError::new_err(format!("Objects could not be added: {:?}", err))
+                        })?;
+                    Ok(#ident { internal: new_internal })
+                }
+
+                /// Return the 1-norm of the coefficients of self, the sum of their absolute values.
+                ///
+                /// Returns:
+                ///     float: The sum of the absolute values of the coefficients.
+                ///
+                /// Raises:
+                ///     ValueError: Self contains symbolic coefficients.
+                pub fn coefficient_1_norm(&self) -> PyResult<f64> {
+                    self.internal.coefficient_1_norm().map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })
+                }
+
+                /// Return the max-norm of the coefficients of self, the largest absolute value.
+                ///
+                /// Returns:
+                ///     float: The largest absolute value of the coefficients.
+                ///
+                /// Raises:
+                ///     ValueError: Self contains symbolic coefficients.
+                pub fn coefficient_max_norm(&self) -> PyResult<f64> {
+                    self.internal.coefficient_max_norm().map_err(|err| {
+                        PyValueError::new_err(format!("{:?}", err))
+                    })
+                }
+
                 /// Implement `*` for self and Union[CalculatorComplex, CalculatorFloat].
                 ///
                 /// Args:
@@ -386,6 +677,149 @@ pub fn noisywrapper(
                     to_py_coo(coo)
                 }
 
+                /// Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_coo_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCooMatrixF32, Py<PyDict>)> {
+                    let (coo, diagnostics) = self
+                        .internal
+                        .sparse_matrix_superoperator_coo_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo_f32(coo, diagnostics)
+                }
+
+                /// Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError or value exceeds the single precision range.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr_f32(&self, number_spins: Option<usize>) -> PyResult<(PyCsrMatrixF32, Py<PyDict>)> {
+                    let (csr, diagnostics) = self
+                        .internal
+                        .sparse_matrix_superoperator_csr_f32(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr_f32(csr, diagnostics)
+                }
+
+                /// Construct the sparse matrix representation of the superoperator in CSR representation.
+                ///
+                /// The result can be passed directly to `scipy.sparse.csr_matrix`.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_csr(&self, number_spins: Option<usize>) -> PyResult<PyCsrMatrix> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_csr(csr)
+                }
+
+                /// Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
+                ///
+                /// Args:
+                ///     number_spins (Optional[int]): The number of spins to construct the matrix for.
+                ///
+                /// Returns:
+                ///     scipy.sparse.csr_matrix: The superoperator matrix representation of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                ///     ImportError: scipy is not installed.
+                #[pyo3(signature = (number_spins = None))]
+                pub fn sparse_matrix_superoperator_scipy(&self, number_spins: Option<usize>) -> PyResult<PyObject> {
+                    let csr = self
+                        .internal
+                        .sparse_matrix_superoperator_csr(number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_scipy_csr(csr)
+                }
+
+                /// Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
+                ///
+                /// The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
+                ///
+                /// Args:
+                ///     time (float): The time for which the channel is constructed.
+                ///     number_spins (Optional[int]): The number of spins to construct the channel for.
+                ///
+                /// Returns:
+                ///     Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
+                ///
+                /// Raises:
+                ///     ValueError: CalculatorError.
+                ///     RuntimeError: Could not convert to complex superoperator matrix.
+                #[pyo3(signature = (time, number_spins = None))]
+                pub fn channel_superoperator_coo(&self, time: f64, number_spins: Option<usize>) -> PyResult<PyCooMatrix> {
+                    let coo = self
+                        .internal
+                        .channel_superoperator_coo(time, number_spins)
+                        .map_err(|err| match err {
+                            StruqtureError::CalculatorError(c_err) => {
+                                PyValueError::new_err(format!("{}", c_err))
+                            }
+                            _ => PyRuntimeError::new_err(
+                                "Could not convert to complex superoperator matrix".to_string(),
+                            ),
+                        })?;
+                    to_py_coo(coo)
+                }
+
                 /// Return the unitary part of the superoperator in the sparse COO format.
                 ///
                 /// Returns:
@@ -616,6 +1050,77 @@ pub fn noisywrapper(
                 }
             }
 
+            /// Truncate self by returning the kept and the discarded terms for a threshold.
+            ///
+            /// The discarded part contains the removed terms and, for complex coefficients, the removed
+            /// real or imaginary parts of kept terms, so that the sum of both parts is self.
+            ///
+            /// Args:
+            ///     threshold (float): The threshold for inclusion.
+            ///
+            /// Returns:
+            ///     Tuple[self, self]: The kept and the discarded terms.
+            pub fn truncate_with_remainder(&self, threshold: f64) -> (#ident, #ident) {
+                let (kept, discarded) = self.internal.truncate_with_remainder(threshold);
+                (#ident { internal: kept }, #ident { internal: discarded })
+            }
+
+            /// Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+            ///
+            /// The system and the noise are truncated separately.
+            ///
+            /// Args:
+            ///     fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+            ///
+            /// Returns:
+            ///     Tuple[self, self]: The kept and the dropped terms.
+            ///
+            /// Raises:
+            ///     ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+            pub fn truncate_to_weight(&self, fraction: f64) -> PyResult<(#ident, #ident)> {
+                let (kept, dropped) = self.internal.truncate_to_weight(fraction).map_err(|err| {
+                    PyValueError::new_err(format!("{:?}", err))
+                })?;
+                Ok((#ident { internal: kept }, #ident { internal: dropped }))
+            }
+
+            /// Return whether self and other are equal within the given tolerances.
+            ///
+            /// The systems and the noises are compared separately, keys missing in one of the objects are treated as having a zero coefficient.
+            /// Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+            /// symbolic coefficients need to match exactly.
+            ///
+            /// Args:
+            ///     other (self): The object to compare to.
+            ///     atol (float): The absolute tolerance.
+            ///     rtol (float): The relative tolerance.
+            ///
+            /// Returns:
+            ///     bool: Whether the objects are approximately equal.
+            #[pyo3(signature = (other, atol = 1e-8, rtol = 1e-5))]
+            pub fn approx_eq(&self, other: #ident, atol: f64, rtol: f64) -> bool {
+                self.internal.approx_eq(&other.internal, atol, rtol)
+            }
+
+            /// Return the derivative of self with respect to a symbolic parameter.
+            ///
+            /// Every coefficient is differentiated symbolically, terms with a vanishing derivative are removed.
+            ///
+            /// Args:
+            ///     parameter (str): The name of the symbolic parameter.
+            ///
+            /// Returns:
+            ///     self: The derivative of self.
+            ///
+            /// Raises:
+            ///     ValueError: A coefficient cannot be differentiated.
+            pub fn derivative(&self, parameter: &str) -> PyResult<#ident> {
+                let derivative = self.internal.derivative(parameter).map_err(|err| {
+                    PyValueError::new_err(format!("{:?}", err))
+                })?;
+                Ok(#ident { internal: derivative })
+            }
+
             /// Set a new entry in the system of the open system.
             ///
             /// Args:
@@ -687,11 +1192,11 @@ pub fn noisywrapper(
             /// Raises:
             ///     ValueError: key element cannot be converted to product.
             pub fn system_get(
-                &mut self,
+                &self,
                 key: &Bound<PyAny>,
             ) -> PyResult<#value_type> {
                 let pp = #system_index_type::from_pyany(key)?;
-                let get_value = self.internal.system().get(&pp);
+                let get_value = self.internal.system_get(&pp);
 
                 Ok(#value_type {
                     internal: get_value.into(),
@@ -710,13 +1215,13 @@ pub fn noisywrapper(
             ///     ValueError: Left key element cannot be converted to product.
             ///     ValueError: Right key element cannot be converted to product.
             pub fn noise_get(
-                &mut self,
+                &self,
                 key: (Py<PyAny>, Py<PyAny>),
             ) -> PyResult<CalculatorComplexWrapper> {
                 Python::with_gil(|py| -> PyResult<CalculatorComplexWrapper> {
                     let dp_left = #index_type::from_pyany(key.0.bind(py))?;
                     let dp_right = #index_type::from_pyany(key.1.bind(py))?;
-                    let get_value = self.internal.noise().get(&(dp_left, dp_right));
+                    let get_value = self.internal.noise_get(&(dp_left, dp_right));
 
                     Ok(CalculatorComplexWrapper {
                         internal: get_value.into(),
@@ -724,6 +1229,114 @@ pub fn noisywrapper(
                 })
             }
 
+            /// Remove the value of a key from the system of the open system.
+            ///
+            /// Args:
+            ///     key (Product type): Product key of the removed object.
+            ///
+            /// Returns:
+            ///     Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+            ///
+            /// Raises:
+            ///     ValueError: key element cannot be converted to product.
+            pub fn system_remove(
+                &mut self,
+                key: &Bound<PyAny>,
+            ) -> PyResult<Option<#value_type>> {
+                let pp = #system_index_type::from_pyany(key)?;
+                Ok(self
+                    .internal
+                    .system_remove(&pp)
+                    .map(|value| #value_type { internal: value }))
+            }
+
+            /// Remove the value of a key from the noise of the open system.
+            ///
+            /// Args:
+            ///     key (Tuple[Product type, Product type]): Tuple of Products of the removed object.
+            ///
+            /// Returns:
+            ///     Optional[CalculatorComplex]: Key existed if this is not None, and this is the value it had before it was removed.
+            ///
+            /// Raises:
+            ///     ValueError: Left key element cannot be converted to product.
+            ///     ValueError: Right key element cannot be converted to product.
+            pub fn noise_remove(
+                &mut self,
+                key: (Py<PyAny>, Py<PyAny>),
+            ) -> PyResult<Option<CalculatorComplexWrapper>> {
+                Python::with_gil(|py| -> PyResult<Option<CalculatorComplexWrapper>> {
+                    let dp_left = #index_type::from_pyany(key.0.bind(py))?;
+                    let dp_right = #index_type::from_pyany(key.1.bind(py))?;
+                    Ok(self
+                        .internal
+                        .noise_remove(&(dp_left, dp_right))
+                        .map(|value| CalculatorComplexWrapper { internal: value }))
+                })
+            }
+
+            /// Return unsorted keys in the system of the open system.
+            ///
+            /// Returns:
+            ///     List[OperatorProduct]: The sequence of keys of the system.
+            pub fn system_keys(&self) -> Vec<#system_index_type> {
+                self.internal
+                    .system_keys()
+                    .map(|key| #system_index_type { internal: key.clone() })
+                    .collect()
+            }
+
+            /// Return unsorted keys in the noise of the open system.
+            ///
+            /// Returns:
+            ///     List[(OperatorProduct, OperatorProduct)]: The sequence of keys of the noise.
+            pub fn noise_keys(&self) -> Vec<(#index_type, #index_type)> {
+                self.internal
+                    .noise_keys()
+                    .map(|(key_l, key_r)| {
+                        (#index_type { internal: key_l.clone() }, #index_type { internal: key_r.clone() })
+                    })
+                    .collect()
+            }
+
+            /// Return unsorted values in the system of the open system.
+            ///
+            /// Returns:
+            ///     List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of the system.
+            pub fn system_values(&self) -> Vec<#value_type> {
+                self.internal
+                    .system_values()
+                    .map(|value| #value_type { internal: value.clone() })
+                    .collect()
+            }
+
+            /// Return unsorted values in the noise of the open system.
+            ///
+            /// Returns:
+            ///     List[CalculatorComplex]: The sequence of values of the noise.
+            pub fn noise_values(&self) -> Vec<CalculatorComplexWrapper> {
+                self.internal
+                    .noise_values()
+                    .map(|value| CalculatorComplexWrapper { internal: value.clone() })
+                    .collect()
+            }
+
+            /// Return the number of entries in the system of the open system.
+            ///
+            /// Returns:
+            ///     int: The number of entries in the system.
+            pub fn system_len(&self) -> usize {
+                self.internal.system_len()
+            }
+
+            /// Return the number of entries in the noise of the open system.
+            ///
+            /// Returns:
+            ///     int: The number of entries in the noise.
+            pub fn noise_len(&self) -> usize {
+                self.internal.noise_len()
+            }
+
             /// Add a new entry to the system of the open system.
             ///
             /// Args:
@@ -859,7 +1472,78 @@ pub fn noisywrapper(
     } else {
         TokenStream::new()
     };
+    let iterator_quote = if attribute_arguments.contains("OperateOnDensityMatrix") {
+        quote! {
+            /// The part of the entries returned by the iterator over a struqture object.
+            #[derive(Clone, Copy)]
+            enum #iteration_mode_ident {
+                Keys,
+                Values,
+                Items,
+            }
+
+            /// Iterator over the entries of a struqture object, converting one entry per step.
+            ///
+            /// The keys are copied when the iterator is created, the values are looked up in the
+            /// iterated object when they are reached. Entries removed from the iterated object
+            /// during the iteration are skipped, entries added during the iteration are not seen.
+            #[pyclass(name = #iterator_name)]
+            pub struct #iterator_ident {
+                owner: Py<#ident>,
+                keys: std::vec::IntoIter<(#index_type, #index_type)>,
+                mode: #iteration_mode_ident,
+            }
+
+            impl #iterator_ident {
+                /// Create an iterator over a snapshot of the keys of the struqture object.
+                fn new(slf: pyo3::PyRef<'_, #ident>, mode: #iteration_mode_ident) -> Self {
+                    let keys = slf.internal.keys().map(|(key_l, key_r)| (#index_type { internal: key_l.clone() }, #index_type { internal: key_r.clone() })).collect::<Vec<_>>();
+                    #iterator_ident {
+                        owner: slf.into(),
+                        keys: keys.into_iter(),
+                        mode,
+                    }
+                }
+            }
+
+            #[pymethods]
+            impl #iterator_ident {
+                /// Return the iterator itself.
+                ///
+                /// Returns:
+                ///     Iterator: The iterator.
+                pub fn __iter__(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+                    slf
+                }
+
+                /// Return the next key, value or (key, value) pair of the iterated object.
+                ///
+                /// Returns:
+                ///     The next entry, None stops the iteration.
+                pub fn __next__(&mut self, py: Python) -> Option<PyObject> {
+                    let owner = self.owner.borrow(py);
+                    for key in self.keys.by_ref() {
+                        let value = owner.internal.get(&(key.0.internal.clone(), key.1.internal.clone()));
+                        // Terms are never stored with a value dropped by the policy, such a value marks a removed entry
+                        if owner.internal.zero_term_policy().drops(value) {
+                            continue;
+                        }
+                        let value = CalculatorComplexWrapper { internal: value.clone() };
+                        return Some(match self.mode {
+                            #iteration_mode_ident::Keys => key.into_py(py),
+                            #iteration_mode_ident::Values => value.into_py(py),
+                            #iteration_mode_ident::Items => (key, value).into_py(py),
+                        });
+                    }
+                    None
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
     let q = quote! {
+        #iterator_quote
 
         impl #ident {
             /// Fallible conversion of generic python object..
@@ -959,6 +1643,45 @@ pub fn noisywrapper(
                 Ok(b)
             }
 
+            /// Return the state of self used by pickle.
+            ///
+            /// Returns:
+            ///     bytes: The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __getstate__(&self, py: Python) -> PyResult<Py<pyo3::types::PyBytes>> {
+                let serialized = bincode::serialize(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                Ok(pyo3::types::PyBytes::new_bound(py, &serialized[..]).unbind())
+            }
+
+            /// Set the state of self from the state created by `__getstate__`.
+            ///
+            /// Args:
+            ///     state (bytes): The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     TypeError: State cannot be converted to byte array.
+            ///     ValueError: State cannot be deserialized.
+            pub fn __setstate__(&mut self, state: &Bound<PyAny>) -> PyResult<()> {
+                self.internal = #ident::from_bincode(state)?.internal;
+                Ok(())
+            }
+
+            /// Return the information pickle needs to reconstruct self.
+            ///
+            /// Returns:
+            ///     Tuple[Callable, Tuple[bytes]]: The `from_bincode` function of the class and the serialized object.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (Py<pyo3::types::PyBytes>,))> {
+                let constructor = py.get_type_bound::<#ident>().getattr("from_bincode")?;
+                Ok((constructor.unbind(), (self.__getstate__(py)?,)))
+            }
+
             /// Return the json representation of the object.
             ///
             /// Returns:
@@ -972,6 +1695,114 @@ pub fn noisywrapper(
                 Ok(serialized)
             }
 
+            /// Return the canonical json representation of the object.
+            ///
+            /// The terms are sorted by their keys, so that equal objects always have identical json representations.
+            ///
+            /// Returns:
+            ///     str: The serialized form of the object with sorted terms.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to json.
+            pub fn to_json_canonical(&self) -> PyResult<String> {
+                struqture::SerializeCanonical::to_json_canonical(&self.internal)
+                    .map_err(|_| PyValueError::new_err("Cannot serialize object to json".to_string()))
+            }
+
+            /// Return the MessagePack representation of the object.
+            ///
+            /// Returns:
+            ///     bytearray: The serialized object (in MessagePack form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            #[cfg(feature = "msgpack")]
+            pub fn to_msgpack(&self) -> PyResult<Py<PyByteArray>> {
+                let serialized = struqture::SerializeBinary::to_msgpack(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+                    PyByteArray::new_bound(py, &serialized[..]).into()
+                });
+                Ok(b)
+            }
+
+            /// Convert the MessagePack representation of the object to an instance.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in MessagePack form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     ValueError: Input cannot be deserialized.
+            #[cfg(feature = "msgpack")]
+            #[staticmethod]
+            pub fn from_msgpack(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_gil_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+                Ok(#ident {
+                    internal: struqture::SerializeBinary::from_msgpack(&bytes[..]).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be deserialized from bytes. {}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
+            /// Return the CBOR representation of the object.
+            ///
+            /// Returns:
+            ///     bytearray: The serialized object (in CBOR form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            #[cfg(feature = "cbor")]
+            pub fn to_cbor(&self) -> PyResult<Py<PyByteArray>> {
+                let serialized = struqture::SerializeBinary::to_cbor(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                let b: Py<PyByteArray> = Python::with_gil(|py| -> Py<PyByteArray> {
+                    PyByteArray::new_bound(py, &serialized[..]).into()
+                });
+                Ok(b)
+            }
+
+            /// Convert the CBOR representation of the object to an instance.
+            ///
+            /// Args:
+            ///     input (bytearray): The serialized object (in CBOR form).
+            ///
+            /// Returns:
+            ///    The deserialized object.
+            ///
+            /// Raises:
+            ///     TypeError: Input cannot be converted to byte array.
+            ///     ValueError: Input cannot be deserialized.
+            #[cfg(feature = "cbor")]
+            #[staticmethod]
+            pub fn from_cbor(input: &Bound<PyAny>) -> PyResult<#ident> {
+                let bytes = input
+                    .as_gil_ref()
+                    .extract::<Vec<u8>>()
+                    .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?;
+
+                Ok(#ident {
+                    internal: struqture::SerializeBinary::from_cbor(&bytes[..]).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be deserialized from bytes. {}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
             /// Convert the json representation of the object to an instance.
             ///
             /// Args:
@@ -995,6 +1826,88 @@ pub fn noisywrapper(
                 })
             }
 
+            /// Convert the json representation of a struqture 2.x object to an instance.
+            ///
+            /// The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+            /// to a SpinHamiltonianSystem without a fixed number of spins.
+            ///
+            /// Args:
+            ///     input (str): The serialized struqture 2.x object in json form.
+            ///
+            /// Returns:
+            ///     The deserialized object.
+            ///
+            /// Raises:
+            ///     ValueError: Input cannot be migrated or deserialized.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_json_struqture_2(input: String) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::migrate::Struqture2Json::from_struqture_2_json(&input)
+                        .map_err(|err| {
+                            PyValueError::new_err(format!(
+                                "Input cannot be migrated or deserialized: {}",
+                                err
+                            ))
+                        })?,
+                })
+            }
+
+            /// Return the json representation of the corresponding struqture 2.x object.
+            ///
+            /// The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+            /// is exported as a PauliHamiltonian.
+            ///
+            /// Returns:
+            ///     str: The serialized struqture 2.x object.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot convert self to struqture 2.x.
+            pub fn to_json_struqture_2(&self) -> PyResult<String> {
+                struqture::migrate::Struqture2Json::to_struqture_2_json(&self.internal).map_err(
+                    |err| {
+                        PyValueError::new_err(format!(
+                            "Cannot convert self to struqture 2.x: {}",
+                            err
+                        ))
+                    },
+                )
+            }
+
+            /// Return the canonical text representation of self.
+            ///
+            /// The canonical text contains one line per term, sorted independently of the insertion order,
+            /// and is intended for committing model definitions to version control.
+            ///
+            /// Returns:
+            ///     str: The canonical text representation of self.
+            pub fn to_canonical_text(&self) -> String {
+                struqture::CanonicalText::to_canonical_text(&self.internal)
+            }
+
+            /// Convert the canonical text representation of self to an instance.
+            ///
+            /// Args:
+            ///     input (str): The canonical text representation of the object.
+            ///
+            /// Returns:
+            ///     The object represented by the canonical text.
+            ///
+            /// Raises:
+            ///     ValueError: Input cannot be parsed.
+            #[staticmethod]
+            #[pyo3(text_signature = "(input)")]
+            pub fn from_canonical_text(input: &str) -> PyResult<#ident> {
+                Ok(#ident {
+                    internal: struqture::CanonicalText::from_canonical_text(input).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "Input cannot be parsed: {:?}",
+                            err
+                        ))
+                    })?,
+                })
+            }
+
             /// Return a string containing a printable representation of self.
             ///
             /// Returns:
@@ -147,6 +147,29 @@ pub fn productwrapper(
                     let (index, value) = #struct_ident::create_valid_pair(creators, annihilators, value).map_err(|err| PyValueError::new_err(format!("Valid pair could not be constructed: {:?}", err)))?;
                     Ok((#ident{internal: index}, qoqo_calculator_pyo3::CalculatorComplexWrapper{internal: value}))
                 }
+
+                /// Create the number operator acting on a single mode.
+                ///
+                /// Args:
+                ///    mode (int): The mode the number operator acts on.
+                ///
+                /// Returns:
+                ///    self: The product with one creator and one annihilator acting on mode.
+                #[classmethod]
+                pub fn number(_cls: Bound<PyType>, mode: usize) -> #ident {
+                    #ident{internal: #struct_ident::number(mode)}
+                }
+
+                /// Return whether self is a density term.
+                ///
+                /// A density term is a non-empty product with identical creator and annihilator indices,
+                /// i.e. a product of number operators up to normal ordering.
+                ///
+                /// Returns:
+                ///     bool: Whether self is a density term.
+                pub fn is_density_term(&self) -> bool {
+                    self.internal.is_density_term()
+                }
         }
     } else {
         TokenStream::new()
@@ -429,6 +452,45 @@ pub fn productwrapper(
                 Ok(b)
             }
 
+            /// Return the state of self used by pickle.
+            ///
+            /// Returns:
+            ///     bytes: The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __getstate__(&self, py: Python) -> PyResult<Py<pyo3::types::PyBytes>> {
+                let serialized = bincode::serialize(&self.internal).map_err(|_| {
+                    PyValueError::new_err("Cannot serialize object to bytes")
+                })?;
+                Ok(pyo3::types::PyBytes::new_bound(py, &serialized[..]).unbind())
+            }
+
+            /// Set the state of self from the state created by `__getstate__`.
+            ///
+            /// Args:
+            ///     state (bytes): The serialized object (in [bincode] form).
+            ///
+            /// Raises:
+            ///     TypeError: State cannot be converted to byte array.
+            ///     ValueError: State cannot be deserialized.
+            pub fn __setstate__(&mut self, state: &Bound<PyAny>) -> PyResult<()> {
+                self.internal = #ident::from_bincode(state)?.internal;
+                Ok(())
+            }
+
+            /// Return the information pickle needs to reconstruct self.
+            ///
+            /// Returns:
+            ///     Tuple[Callable, Tuple[bytes]]: The `from_bincode` function of the class and the serialized object.
+            ///
+            /// Raises:
+            ///     ValueError: Cannot serialize object to bytes.
+            pub fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (Py<pyo3::types::PyBytes>,))> {
+                let constructor = py.get_type_bound::<#ident>().getattr("from_bincode")?;
+                Ok((constructor.unbind(), (self.__getstate__(py)?,)))
+            }
+
             /// Return the json representation of the object.
             ///
             /// Returns:
@@ -0,0 +1,37 @@
+"Tests for the canonical text export python interface."
+
+import pytest
+from struqture_py.spins import (
+    DecoherenceProduct,
+    PauliProduct,
+    SpinLindbladOpenSystem,
+    SpinSystem,
+)
+
+
+def test_spin_system_canonical_text():
+    first = SpinSystem(2)
+    first.add_operator_product(PauliProduct().z(1), 0.5)
+    first.add_operator_product(PauliProduct().x(0), "theta")
+    second = SpinSystem(2)
+    second.add_operator_product(PauliProduct().x(0), "theta")
+    second.add_operator_product(PauliProduct().z(1), 0.5)
+
+    text = first.to_canonical_text()
+    assert text == "SpinSystem\nnumber_spins\t2\n0X\ttheta\t0.0\n1Z\t0.5\t0.0\n"
+    assert second.to_canonical_text() == text
+    assert SpinSystem.from_canonical_text(text) == first
+
+
+def test_open_system_canonical_text():
+    slos = SpinLindbladOpenSystem(2)
+    slos = slos.noise_add_operator_product(
+        (DecoherenceProduct().z(0), DecoherenceProduct().z(0)), 0.1
+    )
+    text = slos.to_canonical_text()
+    assert SpinLindbladOpenSystem.from_canonical_text(text) == slos
+
+
+def test_canonical_text_wrong_type():
+    with pytest.raises(ValueError):
+        SpinSystem.from_canonical_text("SpinHamiltonianSystem\nnumber_spins\tnone\n")
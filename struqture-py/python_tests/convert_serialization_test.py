@@ -0,0 +1,127 @@
+"Tests for the serialization format conversion python interface."
+
+import pytest
+from struqture_py import convert_serialization, migrate_json
+from struqture_py.spins import (
+    DecoherenceProduct,
+    PauliProduct,
+    SpinHamiltonianSystem,
+    SpinLindbladOpenSystem,
+    SpinSystem,
+)
+
+
+def test_convert_serialization():
+    system = SpinSystem(2)
+    system.add_operator_product(PauliProduct().z(1), 0.5)
+    system.add_operator_product(PauliProduct().x(0), "theta")
+
+    binary = convert_serialization("SpinSystem", system.to_json(), "json", "bincode")
+    assert isinstance(binary, bytearray)
+    assert SpinSystem.from_bincode(binary) == system
+
+    pretty = convert_serialization("SpinSystem", system.to_bincode(), "bincode", "json_pretty")
+    assert "\n" in pretty
+    assert SpinSystem.from_json(pretty) == system
+    assert convert_serialization("SpinSystem", pretty, "json_pretty", "json") == system.to_json()
+
+
+def test_convert_serialization_errors():
+    system = SpinSystem(2)
+    with pytest.raises(ValueError):
+        convert_serialization("SpinSystem", system.to_json(), "yaml", "json")
+    with pytest.raises(ValueError):
+        convert_serialization("SpinCircuit", system.to_json(), "json", "bincode")
+    with pytest.raises(ValueError):
+        convert_serialization("SpinHamiltonian", "{}", "json", "bincode")
+    with pytest.raises(TypeError):
+        convert_serialization("SpinSystem", 1, "bincode", "json")
+
+
+
+def test_to_json_canonical():
+    first = SpinSystem(10)
+    second = SpinSystem(10)
+    for index in range(10):
+        first.add_operator_product(PauliProduct().z(index), float(index))
+    for index in reversed(range(10)):
+        second.add_operator_product(PauliProduct().z(index), float(index))
+    json = first.to_json_canonical()
+    assert json == second.to_json_canonical()
+    assert SpinSystem.from_json(json) == first
+
+    slos = SpinLindbladOpenSystem(2)
+    slos = slos.noise_add_operator_product(
+        (DecoherenceProduct().z(0), DecoherenceProduct().x(1)), 0.1
+    )
+    assert SpinLindbladOpenSystem.from_json(slos.to_json_canonical()) == slos
+
+
+@pytest.mark.skipif(
+    not (hasattr(SpinSystem, "to_msgpack") and hasattr(SpinSystem, "to_cbor")),
+    reason="struqture_py was built without the msgpack and cbor features",
+)
+def test_msgpack_and_cbor():
+    system = SpinHamiltonianSystem(2)
+    system.add_operator_product(PauliProduct().z(0).x(1), 0.5)
+    system.add_operator_product(PauliProduct().y(1), "theta")
+
+    msgpack = system.to_msgpack()
+    assert isinstance(msgpack, bytearray)
+    assert SpinHamiltonianSystem.from_msgpack(msgpack) == system
+    cbor = system.to_cbor()
+    assert isinstance(cbor, bytearray)
+    assert SpinHamiltonianSystem.from_cbor(cbor) == system
+
+    slos = SpinLindbladOpenSystem(2)
+    slos = slos.noise_add_operator_product(
+        (DecoherenceProduct().z(0), DecoherenceProduct().x(1)), 0.1
+    )
+    assert SpinLindbladOpenSystem.from_msgpack(slos.to_msgpack()) == slos
+    assert SpinLindbladOpenSystem.from_cbor(slos.to_cbor()) == slos
+
+    converted = convert_serialization(
+        "SpinHamiltonianSystem", system.to_json(), "json", "msgpack"
+    )
+    assert SpinHamiltonianSystem.from_msgpack(converted) == system
+    assert (
+        convert_serialization("SpinHamiltonianSystem", cbor, "cbor", "json")
+        == system.to_json()
+    )
+
+    with pytest.raises(ValueError):
+        SpinSystem.from_msgpack(bytearray([1, 2, 3]))
+    with pytest.raises(TypeError):
+        SpinSystem.from_cbor("cbor")
+
+
+def test_migrate_struqture_2():
+    input = (
+        '{"items":[["0Z",1.0]],"serialisation_meta":'
+        '{"type_name":"PauliHamiltonian","min_version":[2,0,0],"version":"2.0.0"}}'
+    )
+    system = SpinHamiltonianSystem.from_json_struqture_2(input)
+    assert system.get(PauliProduct().z(0)) == 1.0
+    assert SpinHamiltonianSystem.from_json(migrate_json(input, (1, 0))) == system
+
+    with pytest.raises(ValueError):
+        SpinSystem.from_json_struqture_2(input)
+    # Migrating struqture 1.x data to struqture 2.x requires the type name
+    with pytest.raises(ValueError):
+        migrate_json(system.to_json(), (2, 0))
+
+
+def test_export_struqture_2():
+    system = SpinHamiltonianSystem()
+    system.add_operator_product(PauliProduct().z(0).x(1), "theta")
+    exported = system.to_json_struqture_2()
+    assert "PauliHamiltonian" in exported
+    assert SpinHamiltonianSystem.from_json_struqture_2(exported) == system
+
+    open_system = SpinLindbladOpenSystem()
+    open_system = open_system.system_add_operator_product(PauliProduct().z(0), 1.0)
+    open_system = open_system.noise_add_operator_product(
+        (DecoherenceProduct().z(0), DecoherenceProduct().z(0)), 0.1
+    )
+    exported = open_system.to_json_struqture_2()
+    assert SpinLindbladOpenSystem.from_json_struqture_2(exported) == open_system
@@ -0,0 +1,53 @@
+"Tests for the flat array export of the spin python interface."
+
+import numpy as np
+import pytest
+from struqture_py.spins import PauliProduct, SpinHamiltonianSystem, SpinSystem
+
+
+def test_spin_system_flat_arrays():
+    system = SpinSystem(71)
+    system.add_operator_product(PauliProduct().x(0), 1.0)
+    system.add_operator_product(PauliProduct().y(1).z(70), 0.5 - 0.25j)
+
+    ((x_masks, z_masks), real, imag) = system.to_flat_arrays()
+    assert x_masks.dtype == np.uint64
+    assert np.array_equal(x_masks, [1, 0, 2, 0])
+    assert np.array_equal(z_masks, [0, 0, 2, 1 << 6])
+    assert np.array_equal(real, [1.0, 0.5])
+    assert np.array_equal(imag, [0.0, -0.25])
+
+    new_system = SpinSystem.from_flat_arrays(x_masks, z_masks, real, imag, 71)
+    assert new_system == system
+
+    with pytest.raises(ValueError):
+        SpinSystem.from_flat_arrays(x_masks, z_masks[1:], real, imag)
+
+    (((x_masks_f32, z_masks_f32), real_f32, imag_f32), diagnostics) = system.to_flat_arrays_f32()
+    assert real_f32.dtype == np.float32
+    assert np.array_equal(x_masks_f32, x_masks)
+    assert np.array_equal(z_masks_f32, z_masks)
+    assert np.array_equal(real_f32, [1.0, 0.5])
+    assert np.array_equal(imag_f32, [0.0, -0.25])
+    assert diagnostics["max_absolute_error"] == 0.0
+
+    system.add_operator_product(PauliProduct().z(0), "theta")
+    with pytest.raises(ValueError):
+        system.to_flat_arrays()
+    with pytest.raises(ValueError):
+        system.to_flat_arrays_f32()
+
+
+def test_spin_hamiltonian_system_flat_arrays():
+    system = SpinHamiltonianSystem(2)
+    system.add_operator_product(PauliProduct().z(0).z(1), -1.0)
+
+    ((x_masks, z_masks), real, imag) = system.to_flat_arrays()
+    assert np.array_equal(x_masks, [0])
+    assert np.array_equal(z_masks, [3])
+    assert np.array_equal(real, [-1.0])
+    assert np.array_equal(imag, [0.0])
+    assert SpinHamiltonianSystem.from_flat_arrays(x_masks, z_masks, real, imag, 2) == system
+
+    with pytest.raises(ValueError):
+        SpinHamiltonianSystem.from_flat_arrays(x_masks, z_masks, real, np.array([1.0]))
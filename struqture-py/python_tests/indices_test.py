@@ -66,3 +66,11 @@ def test_simple():
     print("from get",c)
     s.add_operator_product((a, a.__repr__()), 3)
     print(s.__repr__())
+
+
+def test_number_operator():
+    assert bosons.BosonProduct.number(2) == bosons.BosonProduct([2], [2])
+    assert fermions.HermitianFermionProduct.number(1) == fermions.HermitianFermionProduct([1], [1])
+    assert fermions.FermionProduct([0, 1], [0, 1]).is_density_term()
+    assert not fermions.FermionProduct([0], [1]).is_density_term()
+    assert not bosons.BosonProduct([], []).is_density_term()
@@ -62,3 +62,27 @@ def test_jordan_wigner_fermion_to_spin():
     flos.system_add_operator_product(fp, 2.0)
     flos.noise_add_operator_product((fp, fp), 2.0)
     assert type(flos.jordan_wigner()) == SpinLindbladOpenSystem
+
+def test_bravyi_kitaev_fermion_to_spin():
+    fp = FermionProduct([0], [2, 3])
+    assert type(fp.bravyi_kitaev()) == SpinSystem
+
+    hfp = HermitianFermionProduct([0], [2, 3])
+    assert type(hfp.bravyi_kitaev()) == SpinHamiltonianSystem
+
+    fs = FermionSystem(4)
+    fs.add_operator_product(fp, 1.0)
+    assert type(fs.bravyi_kitaev()) == SpinSystem
+
+    fh = FermionHamiltonianSystem(5)
+    fh.add_operator_product(hfp, 1.0)
+    assert type(fh.bravyi_kitaev()) == SpinHamiltonianSystem
+
+    flns = FermionLindbladNoiseSystem()
+    flns.add_operator_product((fp, fp), 1.0)
+    assert type(flns.bravyi_kitaev()) == SpinLindbladNoiseSystem
+
+    flos = FermionLindbladOpenSystem()
+    flos.system_add_operator_product(fp, 2.0)
+    flos.noise_add_operator_product((fp, fp), 2.0)
+    assert type(flos.bravyi_kitaev()) == SpinLindbladOpenSystem
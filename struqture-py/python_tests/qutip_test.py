@@ -0,0 +1,52 @@
+"Tests for the qutip export of the spin python interface."
+
+import numpy as np
+import pytest
+from struqture_py.spins import (
+    DecoherenceProduct,
+    PauliProduct,
+    SpinHamiltonianSystem,
+    SpinLindbladOpenSystem,
+)
+
+qutip = pytest.importorskip("qutip")
+
+
+def test_to_qutip():
+    system = SpinHamiltonianSystem(2)
+    system.add_operator_product(PauliProduct().z(0), 0.5)
+    system.add_operator_product(PauliProduct().x(1), 1.0)
+
+    qobj = system.to_qutip()
+    assert isinstance(qobj, qutip.Qobj)
+    assert qobj.dims == [[2, 2], [2, 2]]
+    # The first subsystem of the Qobj is the spin with the highest index
+    expected = 0.5 * qutip.tensor(qutip.qeye(2), qutip.sigmaz()) + qutip.tensor(
+        qutip.sigmax(), qutip.qeye(2)
+    )
+    assert np.allclose(qobj.full(), expected.full())
+
+
+def test_open_system_to_qutip():
+    system = SpinLindbladOpenSystem(1)
+    system.system_add_operator_product(PauliProduct().z(0), 0.5)
+    system.noise_add_operator_product(
+        (DecoherenceProduct().x(0), DecoherenceProduct().x(0)), 2.0
+    )
+
+    (hamiltonian, collapse_operators) = system.to_qutip()
+    assert np.allclose(hamiltonian.full(), 0.5 * qutip.sigmaz().full())
+    assert len(collapse_operators) == 1
+    assert np.allclose(
+        (collapse_operators[0].dag() * collapse_operators[0]).full(), 2.0 * np.eye(2)
+    )
+
+    state = qutip.basis(2, 0) * qutip.basis(2, 0).dag()
+    result = qutip.mesolve(hamiltonian, state, [0.0, 1.0], collapse_operators)
+    assert len(result.states) == 2
+
+    system.noise_add_operator_product(
+        (DecoherenceProduct().z(0), DecoherenceProduct().z(0)), -1.0
+    )
+    with pytest.raises(ValueError):
+        system.to_qutip()
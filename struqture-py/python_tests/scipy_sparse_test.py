@@ -0,0 +1,56 @@
+"Tests for the scipy.sparse conversion of the spin python interface."
+
+import numpy as np
+import pytest
+from struqture_py.spins import PauliProduct, SpinLindbladOpenSystem, SpinSystem
+
+sparse = pytest.importorskip("scipy.sparse")
+
+
+def test_to_scipy_sparse():
+    system = SpinSystem(2)
+    system.add_operator_product(PauliProduct().z(0), 0.5)
+    system.add_operator_product(PauliProduct().x(1), 1.0)
+
+    matrix = system.to_scipy_sparse()
+    assert isinstance(matrix, sparse.csr_matrix)
+    assert matrix.shape == (4, 4)
+    (values, (rows, columns)) = system.sparse_matrix_coo()
+    expected = sparse.coo_matrix((values, (rows, columns)), shape=(4, 4))
+    assert np.allclose(matrix.toarray(), expected.toarray())
+
+    system.add_operator_product(PauliProduct().z(1), "theta")
+    with pytest.raises(ValueError):
+        system.to_scipy_sparse()
+
+
+def test_sparse_matrix_superoperator_scipy():
+    system = SpinLindbladOpenSystem(1)
+    system.system_add_operator_product(PauliProduct().z(0), 0.5)
+
+    matrix = system.sparse_matrix_superoperator_scipy()
+    assert isinstance(matrix, sparse.csr_matrix)
+    assert matrix.shape == (4, 4)
+    (values, (rows, columns)) = system.sparse_matrix_superoperator_coo()
+    expected = sparse.coo_matrix((values, (rows, columns)), shape=(4, 4))
+    assert np.allclose(matrix.toarray(), expected.toarray())
+
+
+def test_sparse_matrix_csr_f32():
+    system = SpinSystem(2)
+    system.add_operator_product(PauliProduct().z(0), 0.5)
+    system.add_operator_product(PauliProduct().x(1), 0.1)
+
+    ((values, columns, row_pointers), diagnostics) = system.sparse_matrix_csr_f32()
+    assert values.dtype == np.complex64
+    matrix = sparse.csr_matrix((values, columns, row_pointers), shape=(4, 4))
+    assert np.allclose(matrix.toarray(), system.to_scipy_sparse().toarray())
+    assert diagnostics["number_underflows"] == 0
+    assert diagnostics["max_relative_error"] < 1e-6
+
+    open_system = SpinLindbladOpenSystem(1)
+    open_system.system_add_operator_product(PauliProduct().z(0), 0.5)
+    ((values, columns, row_pointers), _) = open_system.sparse_matrix_superoperator_csr_f32()
+    assert values.dtype == np.complex64
+    matrix = sparse.csr_matrix((values, columns, row_pointers), shape=(4, 4))
+    assert np.allclose(matrix.toarray(), open_system.sparse_matrix_superoperator_scipy().toarray())
@@ -0,0 +1,58 @@
+"Tests for the SymPy conversion python interface."
+
+import pytest
+
+sympy = pytest.importorskip("sympy")
+
+from sympy.physics.quantum import Dagger
+from sympy.physics.quantum.boson import BosonOp
+from sympy.physics.quantum.fermion import FermionOp
+from struqture_py.spins import PauliProduct, SpinSystem, SpinHamiltonianSystem
+from struqture_py.fermions import FermionProduct, FermionSystem
+from struqture_py.bosons import BosonProduct, BosonSystem
+
+
+def test_spin_system_roundtrip():
+    ss = SpinSystem(3)
+    ss.add_operator_product(PauliProduct().x(0).z(2), 1.5)
+    ss.add_operator_product(PauliProduct().y(1), "theta")
+    expression = ss.to_sympy()
+    assert SpinSystem.from_sympy(expression, 3) == ss
+
+
+def test_spin_system_from_sympy():
+    x0, z1 = sympy.symbols("X_0 Z_1", commutative=False)
+    ss = SpinSystem.from_sympy(2 * x0 * z1 + x0 * x0)
+    expected = SpinSystem()
+    expected.add_operator_product(PauliProduct().x(0).z(1), 2.0)
+    expected.add_operator_product(PauliProduct(), 1.0)
+    assert ss == expected
+
+
+def test_spin_hamiltonian_system_not_hermitian():
+    x0 = sympy.Symbol("X_0", commutative=False)
+    with pytest.raises(ValueError):
+        SpinHamiltonianSystem.from_sympy(sympy.I * x0)
+
+
+def test_fermion_system_roundtrip():
+    fs = FermionSystem(2)
+    fs.add_operator_product(FermionProduct([0], [1]), 1.0)
+    fs.add_operator_product(FermionProduct([1], [1]), 0.5j)
+    assert FermionSystem.from_sympy(fs.to_sympy(), 2) == fs
+
+
+def test_fermion_system_normal_ordering():
+    c0 = FermionOp("c_0")
+    fs = FermionSystem.from_sympy(c0 * Dagger(c0))
+    expected = FermionSystem()
+    expected.add_operator_product(FermionProduct([], []), 1.0)
+    expected.add_operator_product(FermionProduct([0], [0]), -1.0)
+    assert fs == expected
+
+
+def test_boson_system_roundtrip():
+    bs = BosonSystem(2)
+    bs.add_operator_product(BosonProduct([0, 0], [1]), 2.0)
+    bs.add_operator_product(BosonProduct([], [0]), "g")
+    assert BosonSystem.from_sympy(bs.to_sympy(), 2) == bs
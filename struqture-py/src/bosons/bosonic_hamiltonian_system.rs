@@ -11,13 +11,14 @@
 // limitations under the License.
 
 use crate::bosons::{BosonSystemWrapper, HermitianBosonProductWrapper};
+use crate::sympy_conversion::{boson_operator_from_sympy, boson_operator_to_sympy};
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
-use struqture::bosons::BosonHamiltonianSystem;
+use struqture::bosons::{BosonHamiltonian, BosonHamiltonianSystem, BosonOperator};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, OperateOnModes, OperateOnState};
@@ -121,4 +122,57 @@ impl BosonHamiltonianSystemWrapper {
             }
         }
     }
+
+    /// Convert the BosonHamiltonianSystem to a SymPy expression.
+    ///
+    /// The bosonic annihilation operators are represented by `sympy.physics.quantum.boson.BosonOp("b_i")` and creation operators by their `Dagger`.
+    /// Symbolic coefficients are parsed into commutative SymPy symbols.
+    ///
+    /// Returns:
+    ///     sympy.Expr: The SymPy expression representing self.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: Coefficients could not be parsed by SymPy.
+    pub fn to_sympy(&self, py: Python) -> PyResult<PyObject> {
+        boson_operator_to_sympy(
+            py,
+            &BosonOperator::from(self.internal.hamiltonian().clone()),
+        )
+    }
+
+    /// Create a BosonHamiltonianSystem from a SymPy expression.
+    ///
+    /// Args:
+    ///     expression (sympy.Expr): A polynomial in `BosonOp("b_i")` and `Dagger(BosonOp("b_i"))`.
+    ///     number_bosons (Optional[int]): The number of bosons of the new BosonHamiltonianSystem.
+    ///
+    /// Returns:
+    ///     BosonHamiltonianSystem: The BosonHamiltonianSystem represented by the expression.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: The expression contains unrecognized operators or non-integer powers.
+    ///     ValueError: The expression is not hermitian.
+    ///     ValueError: Could not create BosonHamiltonianSystem.
+    #[staticmethod]
+    #[pyo3(signature = (expression, number_bosons = None))]
+    pub fn from_sympy(expression: &Bound<PyAny>, number_bosons: Option<usize>) -> PyResult<Self> {
+        let operator = boson_operator_from_sympy(expression)?;
+        let hamiltonian = BosonHamiltonian::try_from(operator).map_err(|err| {
+            PyValueError::new_err(format!(
+                "Expression could not be converted to a BosonHamiltonian: {:?}",
+                err
+            ))
+        })?;
+        Ok(Self {
+            internal: BosonHamiltonianSystem::from_hamiltonian(hamiltonian, number_bosons)
+                .map_err(|err| {
+                    PyValueError::new_err(format!(
+                        "Could not create BosonHamiltonianSystem: {:?}",
+                        err
+                    ))
+                })?,
+        })
+    }
 }
@@ -11,6 +11,7 @@
 // limitations under the License.
 
 use crate::bosons::BosonProductWrapper;
+use crate::sympy_conversion::{boson_operator_from_sympy, boson_operator_to_sympy};
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
@@ -119,4 +120,43 @@ impl BosonSystemWrapper {
             }
         }
     }
+
+    /// Convert the BosonSystem to a SymPy expression.
+    ///
+    /// The bosonic annihilation operators are represented by `sympy.physics.quantum.boson.BosonOp("b_i")` and creation operators by their `Dagger`.
+    /// Symbolic coefficients are parsed into commutative SymPy symbols.
+    ///
+    /// Returns:
+    ///     sympy.Expr: The SymPy expression representing self.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: Coefficients could not be parsed by SymPy.
+    pub fn to_sympy(&self, py: Python) -> PyResult<PyObject> {
+        boson_operator_to_sympy(py, self.internal.operator())
+    }
+
+    /// Create a BosonSystem from a SymPy expression.
+    ///
+    /// Args:
+    ///     expression (sympy.Expr): A polynomial in `BosonOp("b_i")` and `Dagger(BosonOp("b_i"))`.
+    ///     number_bosons (Optional[int]): The number of bosons of the new BosonSystem.
+    ///
+    /// Returns:
+    ///     BosonSystem: The BosonSystem represented by the expression.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: The expression contains unrecognized operators or non-integer powers.
+    ///     ValueError: Could not create BosonSystem.
+    #[staticmethod]
+    #[pyo3(signature = (expression, number_bosons = None))]
+    pub fn from_sympy(expression: &Bound<PyAny>, number_bosons: Option<usize>) -> PyResult<Self> {
+        let operator = boson_operator_from_sympy(expression)?;
+        Ok(Self {
+            internal: BosonSystem::from_operator(operator, number_bosons).map_err(|err| {
+                PyValueError::new_err(format!("Could not create BosonSystem: {:?}", err))
+            })?,
+        })
+    }
 }
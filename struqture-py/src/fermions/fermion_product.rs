@@ -20,7 +20,7 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use struqture::fermions::*;
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use struqture::prelude::*;
 use struqture::spins::*;
 #[cfg(feature = "json_schema")]
@@ -59,7 +59,7 @@ pub struct FermionProductWrapper {
     pub internal: FermionProduct,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[product_wrapper(FermionIndex, ModeIndex, SymmetricIndex)]
 impl FermionProductWrapper {
     /// Create a new FermionProduct.
@@ -11,16 +11,21 @@
 // limitations under the License.
 
 use super::FermionSystemWrapper;
-use crate::fermions::HermitianFermionProductWrapper;
+use crate::fermions::{FermionProductWrapper, HermitianFermionProductWrapper};
 use crate::spins::SpinHamiltonianSystemWrapper;
+use crate::sympy_conversion::{fermion_operator_from_sympy, fermion_operator_to_sympy};
 use bincode::deserialize;
+use num_complex::Complex64;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PyDict};
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
-use struqture::fermions::FermionHamiltonianSystem;
-use struqture::mappings::JordanWignerFermionToSpin;
+use std::collections::HashMap;
+use struqture::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionOperator, FermionProduct,
+};
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, OperateOnModes, OperateOnState};
@@ -61,7 +66,7 @@ pub struct FermionHamiltonianSystemWrapper {
     pub internal: FermionHamiltonianSystem,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[noiseless_system_wrapper(
     OperateOnFermions,
     OperateOnState,
@@ -130,4 +135,93 @@ impl FermionHamiltonianSystemWrapper {
             }
         }
     }
+
+    /// Decouple the two-particle terms in the Hartree-Fock mean-field approximation.
+    ///
+    /// Every term c^†_p c^†_q c_r c_s is replaced by its linearization in the one-particle averages G_ij = <c^†_i c_j>,
+    /// G_ps c^†_q c_r + G_qr c^†_p c_s - G_pr c^†_q c_s - G_qs c^†_p c_r - (G_ps G_qr - G_pr G_qs).
+    /// Terms with at most two fermionic operators are kept unchanged.
+    ///
+    /// Args:
+    ///     averages (Dict[Union[FermionProduct, str], complex]): The one-particle averages <c^†_i c_j> with the FermionProduct c^†_i c_j as key. Missing averages are zero.
+    ///
+    /// Returns:
+    ///     FermionHamiltonianSystem: The quadratic mean-field FermionHamiltonianSystem including the constant term.
+    ///
+    /// Raises:
+    ///     TypeError: A key cannot be converted to a FermionProduct or a value to complex.
+    ///     ValueError: An average is not a one-particle average or a term cannot be decoupled.
+    pub fn mean_field_decouple(&self, averages: &Bound<PyDict>) -> PyResult<Self> {
+        let mut converted_averages: HashMap<FermionProduct, Complex64> = HashMap::new();
+        for (key, value) in averages.iter() {
+            let product = FermionProductWrapper::from_pyany(&key)?;
+            let average = value.extract::<Complex64>().map_err(|_| {
+                PyTypeError::new_err(format!(
+                    "Average of {} cannot be converted to complex",
+                    product
+                ))
+            })?;
+            converted_averages.insert(product, average);
+        }
+        let mean_field = self
+            .internal
+            .mean_field_decouple(&converted_averages)
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        Ok(Self {
+            internal: mean_field,
+        })
+    }
+
+    /// Convert the FermionHamiltonianSystem to a SymPy expression.
+    ///
+    /// The fermionic annihilation operators are represented by `sympy.physics.quantum.fermion.FermionOp("c_i")` and creation operators by their `Dagger`.
+    /// Symbolic coefficients are parsed into commutative SymPy symbols.
+    ///
+    /// Returns:
+    ///     sympy.Expr: The SymPy expression representing self.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: Coefficients could not be parsed by SymPy.
+    pub fn to_sympy(&self, py: Python) -> PyResult<PyObject> {
+        fermion_operator_to_sympy(
+            py,
+            &FermionOperator::from(self.internal.hamiltonian().clone()),
+        )
+    }
+
+    /// Create a FermionHamiltonianSystem from a SymPy expression.
+    ///
+    /// Args:
+    ///     expression (sympy.Expr): A polynomial in `FermionOp("c_i")` and `Dagger(FermionOp("c_i"))`.
+    ///     number_fermions (Optional[int]): The number of fermions of the new FermionHamiltonianSystem.
+    ///
+    /// Returns:
+    ///     FermionHamiltonianSystem: The FermionHamiltonianSystem represented by the expression.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: The expression contains unrecognized operators or non-integer powers.
+    ///     ValueError: The expression is not hermitian.
+    ///     ValueError: Could not create FermionHamiltonianSystem.
+    #[staticmethod]
+    #[pyo3(signature = (expression, number_fermions = None))]
+    pub fn from_sympy(expression: &Bound<PyAny>, number_fermions: Option<usize>) -> PyResult<Self> {
+        let operator = fermion_operator_from_sympy(expression)?;
+        let hamiltonian = FermionHamiltonian::try_from(operator).map_err(|err| {
+            PyValueError::new_err(format!(
+                "Expression could not be converted to a FermionHamiltonian: {:?}",
+                err
+            ))
+        })?;
+        Ok(Self {
+            internal: FermionHamiltonianSystem::from_hamiltonian(hamiltonian, number_fermions)
+                .map_err(|err| {
+                    PyValueError::new_err(format!(
+                        "Could not create FermionHamiltonianSystem: {:?}",
+                        err
+                    ))
+                })?,
+        })
+    }
 }
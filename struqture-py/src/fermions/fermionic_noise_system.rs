@@ -18,7 +18,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::fermions::FermionLindbladNoiseSystem;
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, OperateOnModes};
@@ -57,7 +57,7 @@ pub struct FermionLindbladNoiseSystemWrapper {
     pub internal: FermionLindbladNoiseSystem,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[noisy_system_wrapper(OperateOnModes, OperateOnFermions, OperateOnDensityMatrix, Calculus)]
 impl FermionLindbladNoiseSystemWrapper {
     /// Create a new FermionLindbladNoiseSystem.
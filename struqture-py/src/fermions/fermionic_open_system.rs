@@ -21,7 +21,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyByteArray;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::fermions::FermionLindbladOpenSystem;
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OpenSystem, OperateOnDensityMatrix, OperateOnModes};
@@ -60,7 +60,7 @@ pub struct FermionLindbladOpenSystemWrapper {
     pub internal: FermionLindbladOpenSystem,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[noisy_system_wrapper(OpenSystem, OperateOnModes, Calculus)]
 impl FermionLindbladOpenSystemWrapper {
     /// Create a new FermionLindbladOpenSystem.
@@ -12,6 +12,7 @@
 
 use crate::fermions::FermionProductWrapper;
 use crate::spins::SpinSystemWrapper;
+use crate::sympy_conversion::{fermion_operator_from_sympy, fermion_operator_to_sympy};
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
@@ -19,7 +20,7 @@ use pyo3::types::PyByteArray;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::fermions::FermionSystem;
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OperateOnDensityMatrix, OperateOnModes, OperateOnState};
@@ -60,7 +61,7 @@ pub struct FermionSystemWrapper {
     pub internal: FermionSystem,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[noiseless_system_wrapper(
     OperateOnFermions,
     OperateOnState,
@@ -122,4 +123,43 @@ impl FermionSystemWrapper {
             }
         }
     }
+
+    /// Convert the FermionSystem to a SymPy expression.
+    ///
+    /// The fermionic annihilation operators are represented by `sympy.physics.quantum.fermion.FermionOp("c_i")` and creation operators by their `Dagger`.
+    /// Symbolic coefficients are parsed into commutative SymPy symbols.
+    ///
+    /// Returns:
+    ///     sympy.Expr: The SymPy expression representing self.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: Coefficients could not be parsed by SymPy.
+    pub fn to_sympy(&self, py: Python) -> PyResult<PyObject> {
+        fermion_operator_to_sympy(py, self.internal.operator())
+    }
+
+    /// Create a FermionSystem from a SymPy expression.
+    ///
+    /// Args:
+    ///     expression (sympy.Expr): A polynomial in `FermionOp("c_i")` and `Dagger(FermionOp("c_i"))`.
+    ///     number_fermions (Optional[int]): The number of fermions of the new FermionSystem.
+    ///
+    /// Returns:
+    ///     FermionSystem: The FermionSystem represented by the expression.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: The expression contains unrecognized operators or non-integer powers.
+    ///     ValueError: Could not create FermionSystem.
+    #[staticmethod]
+    #[pyo3(signature = (expression, number_fermions = None))]
+    pub fn from_sympy(expression: &Bound<PyAny>, number_fermions: Option<usize>) -> PyResult<Self> {
+        let operator = fermion_operator_from_sympy(expression)?;
+        Ok(Self {
+            internal: FermionSystem::from_operator(operator, number_fermions).map_err(|err| {
+                PyValueError::new_err(format!("Could not create FermionSystem: {:?}", err))
+            })?,
+        })
+    }
 }
@@ -21,7 +21,7 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use struqture::fermions::*;
-use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
 use struqture::prelude::*;
 use struqture::spins::*;
 #[cfg(feature = "json_schema")]
@@ -60,7 +60,7 @@ pub struct HermitianFermionProductWrapper {
     pub internal: HermitianFermionProduct,
 }
 
-#[mappings(JordanWignerFermionToSpin)]
+#[mappings(JordanWignerFermionToSpin, BravyiKitaevFermionToSpin)]
 #[product_wrapper(FermionIndex, ModeIndex, SymmetricIndex)]
 impl HermitianFermionProductWrapper {
     /// Create a new HermitianFermionProduct.
@@ -10,14 +10,18 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyDict};
 use pyo3::wrap_pymodule;
+use std::str::FromStr;
+use struqture::io::{convert_by_type_name, SerializationFormat};
 
 pub mod bosons;
 pub mod fermions;
 pub mod mixed_systems;
 pub mod spins;
+mod sympy_conversion;
 
 use thiserror::Error;
 
@@ -43,9 +47,17 @@ pub enum StruqturePyError {
 ///     mixed_systems
 ///     spins
 ///
+/// .. autosummary::
+///     :toctree: generated/
+///
+///     convert_serialization
+///     migrate_json
+///
 #[pymodule]
 fn struqture_py(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     // pyo3_log::init();
+    module.add_function(wrap_pyfunction!(convert_serialization, module)?)?;
+    module.add_function(wrap_pyfunction!(migrate_json, module)?)?;
     let wrapper1 = wrap_pymodule!(spins::spins);
     module.add_wrapped(wrapper1)?;
 
@@ -70,9 +82,81 @@ fn struqture_py(_py: Python, module: &Bound<PyModule>) -> PyResult<()> {
     Ok(())
 }
 
-use num_complex::Complex64;
+/// Convert a serialized struqture object between serialization formats.
+///
+/// The input is deserialized into the given struqture type, so the conversion also validates the data.
+///
+/// Args:
+///     type_name (str): The name of the struqture type, e.g. "SpinSystem".
+///     input (Union[str, bytearray]): The serialized object, a str for json formats and a bytearray for binary formats.
+///     from_format (str): The format of the input, one of "json", "json_pretty", "bincode", "msgpack" or "cbor".
+///     to_format (str): The format of the output, one of "json", "json_pretty", "bincode", "msgpack" or "cbor".
+///
+/// Returns:
+///     Union[str, bytearray]: The object serialized in the output format, a str for json formats and a bytearray for binary formats.
+///
+/// Raises:
+///     TypeError: Input cannot be converted to str or byte array.
+///     ValueError: Unknown format or type name, or the input cannot be converted.
+#[pyfunction]
+pub fn convert_serialization(
+    py: Python,
+    type_name: &str,
+    input: &Bound<PyAny>,
+    from_format: &str,
+    to_format: &str,
+) -> PyResult<PyObject> {
+    let from_format = SerializationFormat::from_str(from_format)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    let to_format = SerializationFormat::from_str(to_format)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    let bytes: Vec<u8> = match from_format {
+        SerializationFormat::Json | SerializationFormat::JsonPretty => input
+            .extract::<String>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to str"))?
+            .into_bytes(),
+        _ => input
+            .extract::<Vec<u8>>()
+            .map_err(|_| PyTypeError::new_err("Input cannot be converted to byte array"))?,
+    };
+    let converted = convert_by_type_name(type_name, &bytes, from_format, to_format)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))?;
+    match to_format {
+        SerializationFormat::Json | SerializationFormat::JsonPretty => {
+            Ok(String::from_utf8(converted)
+                .map_err(|err| PyValueError::new_err(format!("{}", err)))?
+                .into_py(py))
+        }
+        _ => Ok(PyByteArray::new_bound(py, &converted).into_py(py)),
+    }
+}
+
+/// Migrate the json representation of a struqture object to the given struqture version.
+///
+/// struqture 1.x objects keep their type, struqture 2.x objects are converted to the corresponding
+/// struqture 1.x type, e.g. a PauliHamiltonian to a SpinHamiltonianSystem.
+///
+/// Args:
+///     input (str): The serialized object in json form.
+///     target_version (Tuple[int, int]): The major and minor struqture version the output is read with.
+///
+/// Returns:
+///     str: The migrated object in json form.
+///
+/// Raises:
+///     ValueError: The input cannot be migrated to the target version.
+#[pyfunction]
+pub fn migrate_json(input: &str, target_version: (u32, u32)) -> PyResult<String> {
+    struqture::migrate::migrate_json(input, target_version)
+        .map_err(|err| PyValueError::new_err(format!("{}", err)))
+}
+
+use num_complex::{Complex32, Complex64};
 use numpy::{IntoPyArray, PyArray1};
-use struqture::CooSparseMatrix;
+use struqture::spins::{FlatPauliArrays, FlatPauliArraysF32};
+use struqture::{
+    CooSparseMatrix, CooSparseMatrixF32, CsrSparseMatrix, CsrSparseMatrixF32, RoundingDiagnostics,
+};
 // use pyo3::prelude::*;
 
 pub type PyCooMatrix = (
@@ -90,3 +174,162 @@ fn to_py_coo(coo: CooSparseMatrix) -> PyResult<PyCooMatrix> {
         Ok((values, (rows, columns)))
     })
 }
+
+pub type PyCooMatrixF32 = (
+    Py<PyArray1<Complex32>>,
+    (Py<PyArray1<usize>>, Py<PyArray1<usize>>),
+);
+
+// Simple wrapper function to convert internal single precision COO matrix and the diagnostics of the
+// rounding to a Python compatible form, any error handling should be done before using it.
+fn to_py_coo_f32(
+    coo: CooSparseMatrixF32,
+    diagnostics: RoundingDiagnostics,
+) -> PyResult<(PyCooMatrixF32, Py<PyDict>)> {
+    Python::with_gil(|py| -> PyResult<(PyCooMatrixF32, Py<PyDict>)> {
+        let values: Py<PyArray1<Complex32>> = coo.0.into_pyarray_bound(py).into();
+        let rows: Py<PyArray1<usize>> = coo.1 .0.into_pyarray_bound(py).into();
+        let columns: Py<PyArray1<usize>> = coo.1 .1.into_pyarray_bound(py).into();
+        Ok((
+            (values, (rows, columns)),
+            to_py_rounding_diagnostics(py, diagnostics)?,
+        ))
+    })
+}
+
+// Simple wrapper function to convert the diagnostics of the rounding to single precision to a Python dict.
+fn to_py_rounding_diagnostics(
+    py: Python,
+    diagnostics: RoundingDiagnostics,
+) -> PyResult<Py<PyDict>> {
+    let py_diagnostics = PyDict::new_bound(py);
+    py_diagnostics.set_item("max_absolute_error", diagnostics.max_absolute_error)?;
+    py_diagnostics.set_item("max_relative_error", diagnostics.max_relative_error)?;
+    py_diagnostics.set_item("number_underflows", diagnostics.number_underflows)?;
+    Ok(py_diagnostics.unbind())
+}
+
+pub type PyCsrMatrix = (
+    Py<PyArray1<Complex64>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+);
+
+// Simple wrapper function to convert internal CSR matrix to a Python compatible form,
+// any error handling should be done before using it.
+fn to_py_csr(csr: CsrSparseMatrix) -> PyResult<PyCsrMatrix> {
+    Python::with_gil(|py| -> PyResult<PyCsrMatrix> {
+        let values: Py<PyArray1<Complex64>> = csr.0.into_pyarray_bound(py).into();
+        let columns: Py<PyArray1<usize>> = csr.1.into_pyarray_bound(py).into();
+        let row_pointers: Py<PyArray1<usize>> = csr.2.into_pyarray_bound(py).into();
+        Ok((values, columns, row_pointers))
+    })
+}
+
+pub type PyCsrMatrixF32 = (
+    Py<PyArray1<Complex32>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+);
+
+// Simple wrapper function to convert internal single precision CSR matrix and the diagnostics of the
+// rounding to a Python compatible form, any error handling should be done before using it.
+fn to_py_csr_f32(
+    csr: CsrSparseMatrixF32,
+    diagnostics: RoundingDiagnostics,
+) -> PyResult<(PyCsrMatrixF32, Py<PyDict>)> {
+    Python::with_gil(|py| -> PyResult<(PyCsrMatrixF32, Py<PyDict>)> {
+        let values: Py<PyArray1<Complex32>> = csr.0.into_pyarray_bound(py).into();
+        let columns: Py<PyArray1<usize>> = csr.1.into_pyarray_bound(py).into();
+        let row_pointers: Py<PyArray1<usize>> = csr.2.into_pyarray_bound(py).into();
+        Ok((
+            (values, columns, row_pointers),
+            to_py_rounding_diagnostics(py, diagnostics)?,
+        ))
+    })
+}
+
+pub type PyFlatPauliArrays = (
+    (Py<PyArray1<u64>>, Py<PyArray1<u64>>),
+    Py<PyArray1<f64>>,
+    Py<PyArray1<f64>>,
+);
+
+// Simple wrapper function to move the flat arrays of a spin operator into numpy without copying,
+// any error handling should be done before using it.
+fn to_py_flat_arrays(arrays: FlatPauliArrays) -> PyResult<PyFlatPauliArrays> {
+    Python::with_gil(|py| -> PyResult<PyFlatPauliArrays> {
+        let ((x_masks, z_masks), real, imag) = arrays;
+        Ok((
+            (
+                x_masks.into_pyarray_bound(py).into(),
+                z_masks.into_pyarray_bound(py).into(),
+            ),
+            real.into_pyarray_bound(py).into(),
+            imag.into_pyarray_bound(py).into(),
+        ))
+    })
+}
+
+pub type PyFlatPauliArraysF32 = (
+    (Py<PyArray1<u64>>, Py<PyArray1<u64>>),
+    Py<PyArray1<f32>>,
+    Py<PyArray1<f32>>,
+);
+
+// Simple wrapper function to move the single precision flat arrays of a spin operator and the diagnostics
+// of the rounding into Python, any error handling should be done before using it.
+fn to_py_flat_arrays_f32(
+    arrays: FlatPauliArraysF32,
+    diagnostics: RoundingDiagnostics,
+) -> PyResult<(PyFlatPauliArraysF32, Py<PyDict>)> {
+    Python::with_gil(|py| -> PyResult<(PyFlatPauliArraysF32, Py<PyDict>)> {
+        let ((x_masks, z_masks), real, imag) = arrays;
+        Ok((
+            (
+                (
+                    x_masks.into_pyarray_bound(py).into(),
+                    z_masks.into_pyarray_bound(py).into(),
+                ),
+                real.into_pyarray_bound(py).into(),
+                imag.into_pyarray_bound(py).into(),
+            ),
+            to_py_rounding_diagnostics(py, diagnostics)?,
+        ))
+    })
+}
+
+// Simple wrapper function to convert internal CSR matrix to a scipy.sparse.csr_matrix, the arrays are
+// moved into numpy without copying and scipy is only imported when the function is used.
+fn to_scipy_csr(csr: CsrSparseMatrix) -> PyResult<PyObject> {
+    Python::with_gil(|py| -> PyResult<PyObject> {
+        let dimension = csr.2.len().saturating_sub(1);
+        let values = csr.0.into_pyarray_bound(py);
+        let columns = csr.1.into_pyarray_bound(py);
+        let row_pointers = csr.2.into_pyarray_bound(py);
+        let kwargs = PyDict::new_bound(py);
+        kwargs.set_item("shape", (dimension, dimension))?;
+        let matrix = PyModule::import_bound(py, "scipy.sparse")?
+            .getattr("csr_matrix")?
+            .call(((values, columns, row_pointers),), Some(&kwargs))?;
+        Ok(matrix.unbind())
+    })
+}
+
+// Simple wrapper function to convert internal CSR matrix of a spin operator to a qutip.Qobj with one
+// subsystem per spin, qutip is only imported when the function is used.
+fn to_qutip_qobj(csr: CsrSparseMatrix) -> PyResult<PyObject> {
+    Python::with_gil(|py| -> PyResult<PyObject> {
+        let number_spins = csr.2.len().saturating_sub(1).trailing_zeros() as usize;
+        let subsystem_dimensions = vec![2_usize; number_spins];
+        let kwargs = PyDict::new_bound(py);
+        kwargs.set_item(
+            "dims",
+            vec![subsystem_dimensions.clone(), subsystem_dimensions],
+        )?;
+        let qobj = PyModule::import_bound(py, "qutip")?
+            .getattr("Qobj")?
+            .call((to_scipy_csr(csr)?,), Some(&kwargs))?;
+        Ok(qobj.unbind())
+    })
+}
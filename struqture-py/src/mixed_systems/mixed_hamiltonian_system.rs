@@ -11,7 +11,7 @@
 // limitations under the License.
 
 use super::MixedSystemWrapper;
-use crate::mixed_systems::HermitianMixedProductWrapper;
+use crate::mixed_systems::{HermitianMixedProductWrapper, MixedProductBuilderWrapper};
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
@@ -97,16 +97,26 @@ impl MixedHamiltonianSystemWrapper {
         }
     }
 
-    /// Implement `*` for MixedHamiltonianSystem and MixedHamiltonianSystem/CalculatorComplex/CalculatorFloat.
+    /// Return a MixedProductBuilder with the number of subsystems of the MixedHamiltonianSystem.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The builder acting as the identity on all subsystems of the MixedHamiltonianSystem.
+    pub fn product_builder(&self) -> MixedProductBuilderWrapper {
+        MixedProductBuilderWrapper {
+            internal: self.internal.product_builder(),
+        }
+    }
+
+    /// Implement `*` for MixedHamiltonianSystem and MixedHamiltonianSystem/MixedSystem/CalculatorComplex/CalculatorFloat.
     ///
     /// Args:
-    ///     value (Union[MixedHamiltonianSystem, CalculatorComplex, CalculatorFloat]): value by which to multiply the self MixedHamiltonianSystem
+    ///     value (Union[MixedHamiltonianSystem, MixedSystem, CalculatorComplex, CalculatorFloat]): value by which to multiply the self MixedHamiltonianSystem
     ///
     /// Returns:
     ///     MixedSystem: The MixedHamiltonianSystem multiplied by the value.
     ///
     /// Raises:
-    ///     ValueError: The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, nor MixedHamiltonianSystem.
+    ///     ValueError: The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, MixedHamiltonianSystem, nor MixedSystem.
     pub fn __mul__(&self, value: &Bound<PyAny>) -> PyResult<MixedSystemWrapper> {
         let mut new_spins: Vec<Option<usize>> = Vec::new();
         for spin in self.internal.number_spins() {
@@ -139,6 +149,15 @@ impl MixedHamiltonianSystemWrapper {
                 internal: mixed_system * x,
             }),
             Err(_) => {
+                if let Ok(x) = value.extract::<MixedSystemWrapper>() {
+                    let new_self = (self.clone().internal * x.internal).map_err(|err| {
+                        PyValueError::new_err(format!(
+                            "MixedHamiltonianSystem and MixedSystem could not be multiplied: {:?}",
+                            err
+                        ))
+                    })?;
+                    return Ok(MixedSystemWrapper { internal: new_self });
+                }
                 let bhs_value = Self::from_pyany(value);
                 match bhs_value {
                     Ok(x) => {
@@ -151,7 +170,7 @@ impl MixedHamiltonianSystemWrapper {
                         Ok(MixedSystemWrapper { internal: new_self })
                     },
                     Err(err) => Err(PyValueError::new_err(format!(
-                        "The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, nor MixedHamiltonianSystem: {:?}",
+                        "The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, MixedHamiltonianSystem, nor MixedSystem: {:?}",
                         err)))
                 }
             }
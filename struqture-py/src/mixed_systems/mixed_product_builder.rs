@@ -0,0 +1,178 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{HermitianMixedProductWrapper, MixedProductWrapper};
+use crate::bosons::BosonProductWrapper;
+use crate::fermions::FermionProductWrapper;
+use crate::spins::PauliProductWrapper;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use struqture::mixed_systems::MixedProductBuilder;
+
+/// Builder for MixedProducts and HermitianMixedProducts with a fixed number of subsystems.
+///
+/// Only the subsystems the product acts on need to be set, all other subsystems are the identity.
+/// The number of subsystems is validated when the product is built.
+///
+/// Args:
+///     number_spin_subsystems (int): The number of spin subsystems of the product.
+///     number_boson_subsystems (int): The number of bosonic subsystems of the product.
+///     number_fermion_subsystems (int): The number of fermionic subsystems of the product.
+///
+/// Returns:
+///     MixedProductBuilder: The new builder acting as the identity on all subsystems.
+///
+/// Examples
+/// --------
+///
+/// .. code-block:: python
+///
+///     from struqture_py.mixed_systems import MixedProduct, MixedProductBuilder
+///     from struqture_py.spins import PauliProduct
+///     from struqture_py.bosons import BosonProduct
+///
+///     mp = MixedProductBuilder(2, 1, 0).spin(1, PauliProduct().x(0)).boson(0, BosonProduct([0], [1])).build()
+///     assert mp == MixedProduct([PauliProduct(), PauliProduct().x(0)], [BosonProduct([0], [1])], [])
+///
+#[pyclass(name = "MixedProductBuilder", module = "struqture_py.mixed_systems")]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct MixedProductBuilderWrapper {
+    /// Internal storage of [struqture::mixed_systems::MixedProductBuilder]
+    pub internal: MixedProductBuilder,
+}
+
+#[pymethods]
+impl MixedProductBuilderWrapper {
+    /// Create a new MixedProductBuilder.
+    ///
+    /// Args:
+    ///     number_spin_subsystems (int): The number of spin subsystems of the product.
+    ///     number_boson_subsystems (int): The number of bosonic subsystems of the product.
+    ///     number_fermion_subsystems (int): The number of fermionic subsystems of the product.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The new builder acting as the identity on all subsystems.
+    #[new]
+    pub fn new(
+        number_spin_subsystems: usize,
+        number_boson_subsystems: usize,
+        number_fermion_subsystems: usize,
+    ) -> Self {
+        Self {
+            internal: MixedProductBuilder::new(
+                number_spin_subsystems,
+                number_boson_subsystems,
+                number_fermion_subsystems,
+            ),
+        }
+    }
+
+    /// Set the PauliProduct acting on a spin subsystem.
+    ///
+    /// Args:
+    ///     index (int): The index of the spin subsystem.
+    ///     product (PauliProduct): The PauliProduct acting on the subsystem.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The builder with the subsystem set.
+    ///
+    /// Raises:
+    ///     TypeError: The product cannot be converted to a PauliProduct.
+    pub fn spin(&self, index: usize, product: &Bound<PyAny>) -> PyResult<Self> {
+        let product = PauliProductWrapper::from_pyany(product)?;
+        Ok(Self {
+            internal: self.internal.clone().spin(index, product),
+        })
+    }
+
+    /// Set the BosonProduct acting on a bosonic subsystem.
+    ///
+    /// Args:
+    ///     index (int): The index of the bosonic subsystem.
+    ///     product (BosonProduct): The BosonProduct acting on the subsystem.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The builder with the subsystem set.
+    ///
+    /// Raises:
+    ///     TypeError: The product cannot be converted to a BosonProduct.
+    pub fn boson(&self, index: usize, product: &Bound<PyAny>) -> PyResult<Self> {
+        let product = BosonProductWrapper::from_pyany(product)?;
+        Ok(Self {
+            internal: self.internal.clone().boson(index, product),
+        })
+    }
+
+    /// Set the FermionProduct acting on a fermionic subsystem.
+    ///
+    /// Args:
+    ///     index (int): The index of the fermionic subsystem.
+    ///     product (FermionProduct): The FermionProduct acting on the subsystem.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The builder with the subsystem set.
+    ///
+    /// Raises:
+    ///     TypeError: The product cannot be converted to a FermionProduct.
+    pub fn fermion(&self, index: usize, product: &Bound<PyAny>) -> PyResult<Self> {
+        let product = FermionProductWrapper::from_pyany(product)?;
+        Ok(Self {
+            internal: self.internal.clone().fermion(index, product),
+        })
+    }
+
+    /// Build the MixedProduct.
+    ///
+    /// Returns:
+    ///     MixedProduct: The MixedProduct acting on the subsystems that have been set.
+    ///
+    /// Raises:
+    ///     ValueError: A subsystem index exceeds the number of subsystems of the builder.
+    pub fn build(&self) -> PyResult<MixedProductWrapper> {
+        Ok(MixedProductWrapper {
+            internal: self.internal.build().map_err(|err| {
+                PyValueError::new_err(format!("Could not build MixedProduct: {:?}", err))
+            })?,
+        })
+    }
+
+    /// Build the HermitianMixedProduct.
+    ///
+    /// Returns:
+    ///     HermitianMixedProduct: The HermitianMixedProduct acting on the subsystems that have been set.
+    ///
+    /// Raises:
+    ///     ValueError: A subsystem index exceeds the number of subsystems of the builder or the product is not in hermitian order.
+    pub fn build_hermitian(&self) -> PyResult<HermitianMixedProductWrapper> {
+        Ok(HermitianMixedProductWrapper {
+            internal: self.internal.build_hermitian().map_err(|err| {
+                PyValueError::new_err(format!("Could not build HermitianMixedProduct: {:?}", err))
+            })?,
+        })
+    }
+
+    /// Return a copy of self (copy here produces a deepcopy).
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: A deep copy of self.
+    pub fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Return a deep copy of self.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: A deep copy of self.
+    pub fn __deepcopy__(&self, _memodict: &Bound<PyAny>) -> Self {
+        self.clone()
+    }
+}
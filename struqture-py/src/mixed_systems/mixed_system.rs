@@ -10,7 +10,9 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::mixed_systems::MixedProductWrapper;
+use crate::mixed_systems::{
+    MixedHamiltonianSystemWrapper, MixedProductBuilderWrapper, MixedProductWrapper,
+};
 use bincode::deserialize;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
@@ -95,16 +97,26 @@ impl MixedSystemWrapper {
         }
     }
 
-    /// Implement `*` for MixedSystem and MixedSystem/CalculatorComplex/CalculatorFloat.
+    /// Return a MixedProductBuilder with the number of subsystems of the MixedSystem.
+    ///
+    /// Returns:
+    ///     MixedProductBuilder: The builder acting as the identity on all subsystems of the MixedSystem.
+    pub fn product_builder(&self) -> MixedProductBuilderWrapper {
+        MixedProductBuilderWrapper {
+            internal: self.internal.product_builder(),
+        }
+    }
+
+    /// Implement `*` for MixedSystem and MixedSystem/MixedHamiltonianSystem/CalculatorComplex/CalculatorFloat.
     ///
     /// Args:
-    ///     value (Union[MixedSystem, CalculatorComplex, CalculatorFloat]): value by which to multiply the self MixedSystem
+    ///     value (Union[MixedSystem, MixedHamiltonianSystem, CalculatorComplex, CalculatorFloat]): value by which to multiply the self MixedSystem
     ///
     /// Returns:
     ///     MixedSystem: The MixedSystem multiplied by the value.
     ///
     /// Raises:
-    ///     ValueError: The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, nor MixedSystem.
+    ///     ValueError: The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, MixedSystem, nor MixedHamiltonianSystem.
     pub fn __mul__(&self, value: &Bound<PyAny>) -> PyResult<Self> {
         let cf_value = qoqo_calculator_pyo3::convert_into_calculator_float(value);
         match cf_value {
@@ -118,6 +130,15 @@ impl MixedSystemWrapper {
                         internal: self.clone().internal * x,
                     }),
                     Err(_) => {
+                        if let Ok(x) = value.extract::<MixedHamiltonianSystemWrapper>() {
+                            let new_self = (self.clone().internal * x.internal).map_err(|err| {
+                                PyValueError::new_err(format!(
+                                    "MixedSystem and MixedHamiltonianSystem could not be multiplied: {:?}",
+                                    err
+                                ))
+                            })?;
+                            return Ok(Self { internal: new_self });
+                        }
                         let bhs_value = Self::from_pyany(value);
                         match bhs_value {
                             Ok(x) => {
@@ -130,7 +151,7 @@ impl MixedSystemWrapper {
                                 Ok(Self { internal: new_self })
                             },
                             Err(err) => Err(PyValueError::new_err(format!(
-                                "The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, nor MixedSystem: {:?}",
+                                "The rhs of the multiplication is neither CalculatorFloat, CalculatorComplex, MixedSystem, nor MixedHamiltonianSystem: {:?}",
                                 err)))
                         }
                     }
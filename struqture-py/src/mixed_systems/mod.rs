@@ -15,6 +15,9 @@ use pyo3::prelude::*;
 mod mixed_product;
 pub use mixed_product::MixedProductWrapper;
 
+mod mixed_product_builder;
+pub use mixed_product_builder::MixedProductBuilderWrapper;
+
 mod mixed_plus_minus_product;
 pub use mixed_plus_minus_product::MixedPlusMinusProductWrapper;
 
@@ -62,6 +65,7 @@ pub use mixed_open_system::MixedLindbladOpenSystemWrapper;
 ///     MixedLindbladOpenSystem
 ///     MixedPlusMinusProduct
 ///     MixedPlusMinusOperator
+///     MixedProductBuilder
 ///
 #[pymodule]
 pub fn mixed_systems(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
@@ -75,6 +79,7 @@ pub fn mixed_systems(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<MixedLindbladOpenSystemWrapper>()?;
     m.add_class::<MixedPlusMinusProductWrapper>()?;
     m.add_class::<MixedPlusMinusOperatorWrapper>()?;
+    m.add_class::<MixedProductBuilderWrapper>()?;
 
     Ok(())
 }
@@ -13,17 +13,24 @@
 use super::SpinSystemWrapper;
 use crate::fermions::FermionHamiltonianSystemWrapper;
 use crate::spins::PauliProductWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::sympy_conversion::{spin_operator_from_sympy, spin_operator_to_sympy};
+use crate::{
+    to_py_coo, to_py_coo_f32, to_py_csr, to_py_csr_f32, to_py_flat_arrays, to_py_flat_arrays_f32,
+    to_qutip_qobj, to_scipy_csr, PyCooMatrix, PyCooMatrixF32, PyCsrMatrix, PyCsrMatrixF32,
+    PyFlatPauliArrays, PyFlatPauliArraysF32,
+};
 use bincode::deserialize;
 use num_complex::Complex64;
+use numpy::{NotContiguousError, PyReadonlyArray1};
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PyDict};
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorFloatWrapper;
 use struqture::mappings::JordanWignerSpinToFermion;
 use struqture::spins::{
-    OperateOnSpins, SpinHamiltonianSystem, ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+    OperateOnSpins, SpinHamiltonian, SpinHamiltonianSystem, SpinOperator, ToSparseMatrixOperator,
+    ToSparseMatrixSuperOperator,
 };
 use struqture::StruqtureError;
 #[cfg(feature = "json_schema")]
@@ -135,4 +142,140 @@ impl SpinHamiltonianSystemWrapper {
             }
         }
     }
+
+    /// Convert the SpinHamiltonianSystem to a SymPy expression.
+    ///
+    /// The Pauli operators are represented by non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+    /// Symbolic coefficients are parsed into commutative SymPy symbols.
+    ///
+    /// Returns:
+    ///     sympy.Expr: The SymPy expression representing self.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: Coefficients could not be parsed by SymPy.
+    pub fn to_sympy(&self, py: Python) -> PyResult<PyObject> {
+        spin_operator_to_sympy(py, &SpinOperator::from(self.internal.hamiltonian().clone()))
+    }
+
+    /// Create a SpinHamiltonianSystem from a SymPy expression.
+    ///
+    /// Args:
+    ///     expression (sympy.Expr): A polynomial in the non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+    ///     number_spins (Optional[int]): The number of spins of the new SpinHamiltonianSystem.
+    ///
+    /// Returns:
+    ///     SpinHamiltonianSystem: The SpinHamiltonianSystem represented by the expression.
+    ///
+    /// Raises:
+    ///     ImportError: SymPy is not installed.
+    ///     ValueError: The expression contains unrecognized operators or non-integer powers.
+    ///     ValueError: The expression is not hermitian.
+    ///     ValueError: Could not create SpinHamiltonianSystem.
+    #[staticmethod]
+    #[pyo3(signature = (expression, number_spins = None))]
+    pub fn from_sympy(expression: &Bound<PyAny>, number_spins: Option<usize>) -> PyResult<Self> {
+        let operator = spin_operator_from_sympy(expression)?;
+        let hamiltonian = SpinHamiltonian::try_from(operator).map_err(|err| {
+            PyValueError::new_err(format!(
+                "Expression could not be converted to a SpinHamiltonian: {:?}",
+                err
+            ))
+        })?;
+        Ok(Self {
+            internal: SpinHamiltonianSystem::from_hamiltonian(hamiltonian, number_spins).map_err(
+                |err| {
+                    PyValueError::new_err(format!(
+                        "Could not create SpinHamiltonianSystem: {:?}",
+                        err
+                    ))
+                },
+            )?,
+        })
+    }
+
+    /// Return the terms of self as flat numpy arrays.
+    ///
+    /// The terms are sorted by their PauliProduct. Every term is encoded by a fixed number of uint64 words of the
+    /// x-bitmask (spins acted on by X or Y) and of the z-bitmask (spins acted on by Z or Y), bit j of word w
+    /// corresponding to spin 64 * w + j. The arrays are moved into numpy without copying. The imaginary parts are all zero.
+    ///
+    /// Returns:
+    ///     Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray]: The x- and z-bitmasks of the terms and the real and imaginary parts of the coefficients.
+    ///
+    /// Raises:
+    ///     ValueError: A coefficient is symbolic.
+    pub fn to_flat_arrays(&self) -> PyResult<PyFlatPauliArrays> {
+        let arrays = self
+            .internal
+            .hamiltonian()
+            .to_flat_arrays()
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        to_py_flat_arrays(arrays)
+    }
+
+    /// Return the terms of self as flat numpy arrays with single precision coefficients.
+    ///
+    /// The same as `to_flat_arrays` with the coefficients rounded to float32.
+    ///
+    /// Returns:
+    ///     Tuple[Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray], Dict[str, float]]: The flat arrays with float32 coefficients and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+    ///
+    /// Raises:
+    ///     ValueError: A coefficient is symbolic or exceeds the single precision range.
+    pub fn to_flat_arrays_f32(&self) -> PyResult<(PyFlatPauliArraysF32, Py<PyDict>)> {
+        let (arrays, diagnostics) = self
+            .internal
+            .operator()
+            .to_flat_arrays_f32()
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        to_py_flat_arrays_f32(arrays, diagnostics)
+    }
+
+    /// Create a SpinHamiltonianSystem from flat numpy arrays, see `to_flat_arrays`.
+    ///
+    /// Terms with the same PauliProduct are summed.
+    ///
+    /// Args:
+    ///     x_masks (np.ndarray): The x-bitmasks of all terms (uint64), with the same number of words per term.
+    ///     z_masks (np.ndarray): The z-bitmasks of all terms (uint64), with the same number of words per term.
+    ///     real (np.ndarray): The real parts of the coefficients (float64).
+    ///     imag (np.ndarray): The imaginary parts of the coefficients (float64).
+    ///     number_spins (Optional[int]): The number of spins of the new SpinHamiltonianSystem.
+    ///
+    /// Returns:
+    ///     SpinHamiltonianSystem: The SpinHamiltonianSystem with the terms of the arrays.
+    ///
+    /// Raises:
+    ///     ValueError: The arrays are not contiguous or their lengths do not fit together.
+    ///     ValueError: A coefficient has a non-zero imaginary part.
+    ///     ValueError: Could not create SpinHamiltonianSystem.
+    #[staticmethod]
+    #[pyo3(signature = (x_masks, z_masks, real, imag, number_spins = None))]
+    pub fn from_flat_arrays(
+        x_masks: PyReadonlyArray1<u64>,
+        z_masks: PyReadonlyArray1<u64>,
+        real: PyReadonlyArray1<f64>,
+        imag: PyReadonlyArray1<f64>,
+        number_spins: Option<usize>,
+    ) -> PyResult<Self> {
+        let to_value_error = |err: NotContiguousError| PyValueError::new_err(format!("{}", err));
+        let operator = SpinHamiltonian::from_flat_arrays(
+            x_masks.as_slice().map_err(to_value_error)?,
+            z_masks.as_slice().map_err(to_value_error)?,
+            real.as_slice().map_err(to_value_error)?,
+            imag.as_slice().map_err(to_value_error)?,
+        )
+        .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        Ok(Self {
+            internal: SpinHamiltonianSystem::from_hamiltonian(operator, number_spins).map_err(
+                |err| {
+                    PyValueError::new_err(format!(
+                        "Could not create SpinHamiltonianSystem: {:?}",
+                        err
+                    ))
+                },
+            )?,
+        })
+    }
 }
@@ -12,12 +12,15 @@
 
 use crate::fermions::FermionLindbladNoiseSystemWrapper;
 use crate::spins::DecoherenceProductWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{
+    to_py_coo, to_py_coo_f32, to_py_csr, to_py_csr_f32, to_scipy_csr, PyCooMatrix, PyCooMatrixF32,
+    PyCsrMatrix, PyCsrMatrixF32,
+};
 use bincode::deserialize;
 use num_complex::Complex64;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PyDict};
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::mappings::JordanWignerSpinToFermion;
 use struqture::spins::{OperateOnSpins, SpinLindbladNoiseSystem, ToSparseMatrixSuperOperator};
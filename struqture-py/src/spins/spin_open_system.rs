@@ -13,15 +13,20 @@
 use super::{DecoherenceProductWrapper, PauliProductWrapper};
 use super::{SpinHamiltonianSystemWrapper, SpinLindbladNoiseSystemWrapper};
 use crate::fermions::FermionLindbladOpenSystemWrapper;
-use crate::{to_py_coo, PyCooMatrix};
+use crate::{
+    to_py_coo, to_py_coo_f32, to_py_csr, to_py_csr_f32, to_qutip_qobj, to_scipy_csr, PyCooMatrix,
+    PyCooMatrixF32, PyCsrMatrix, PyCsrMatrixF32,
+};
 use bincode::deserialize;
 use num_complex::Complex64;
 use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyByteArray;
+use pyo3::types::{PyByteArray, PyDict};
 use qoqo_calculator_pyo3::{CalculatorComplexWrapper, CalculatorFloatWrapper};
 use struqture::mappings::JordanWignerSpinToFermion;
-use struqture::spins::{OperateOnSpins, SpinLindbladOpenSystem, ToSparseMatrixSuperOperator};
+use struqture::spins::{
+    OperateOnSpins, SpinLindbladOpenSystem, ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+};
 #[cfg(feature = "json_schema")]
 use struqture::{MinSupportedVersion, STRUQTURE_VERSION};
 use struqture::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
@@ -79,4 +84,54 @@ impl SpinLindbladOpenSystemWrapper {
             internal: SpinLindbladOpenSystem::new(number_spins),
         }
     }
+
+    /// Convert self into the Hamiltonian and the collapse operators used by `qutip.mesolve`.
+    ///
+    /// The collapse operators are the jump operators of the diagonal form of the noise, obtained by diagonalizing
+    /// the matrix of the noise rates. All Qobj have one subsystem of dimension two per spin,
+    /// the first subsystem is the spin with the highest index.
+    ///
+    /// Args:
+    ///     number_spins (Optional[int]): The number of spins, defaults to the number of spins of self.
+    ///     tolerance (float): The tolerance for the positivity of the noise and for dropping vanishing collapse operators.
+    ///
+    /// Returns:
+    ///     Tuple[qutip.Qobj, List[qutip.Qobj]]: The Hamiltonian and the collapse operators.
+    ///
+    /// Raises:
+    ///     ValueError: CalculatorError or the noise is not completely positive.
+    ///     RuntimeError: Could not convert to complex matrix.
+    ///     ImportError: qutip or scipy is not installed.
+    #[pyo3(signature = (number_spins = None, tolerance = 1e-10))]
+    pub fn to_qutip(
+        &self,
+        number_spins: Option<usize>,
+        tolerance: f64,
+    ) -> PyResult<(PyObject, Vec<PyObject>)> {
+        let number_spins = number_spins.unwrap_or_else(|| self.internal.number_spins());
+        let to_value_error = |err: StruqtureError| match err {
+            StruqtureError::CalculatorError(c_err) => PyValueError::new_err(format!("{}", c_err)),
+            StruqtureError::GenericError { msg } => PyValueError::new_err(msg),
+            _ => PyRuntimeError::new_err("Could not convert to complex matrix".to_string()),
+        };
+        let hamiltonian = self
+            .internal
+            .system()
+            .sparse_matrix_csr(Some(number_spins))
+            .map_err(to_value_error)?;
+        let collapse_operators = self
+            .internal
+            .noise()
+            .jump_operators(tolerance)
+            .map_err(to_value_error)?
+            .iter()
+            .map(|jump_operator| {
+                jump_operator
+                    .sparse_matrix_csr(Some(number_spins))
+                    .map_err(to_value_error)
+                    .and_then(to_qutip_qobj)
+            })
+            .collect::<PyResult<Vec<PyObject>>>()?;
+        Ok((to_qutip_qobj(hamiltonian)?, collapse_operators))
+    }
 }
@@ -0,0 +1,364 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of struqture operators to and from SymPy expressions.
+//!
+//! Pauli operators are represented by non-commutative SymPy symbols `X_i`, `Y_i` and `Z_i`,
+//! fermionic and bosonic operators by `FermionOp("c_i")` and `BosonOp("b_i")` from `sympy.physics.quantum`
+//! and their daggers. Symbolic coefficients are parsed into commutative SymPy symbols.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::bosons::{BosonOperator, BosonProduct};
+use struqture::fermions::{FermionOperator, FermionProduct};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SingleSpinOperator, SpinOperator};
+
+/// Returns the string representation of a CalculatorFloat that can be parsed by SymPy.
+fn float_string(value: &CalculatorFloat) -> String {
+    match value {
+        CalculatorFloat::Float(x) => format!("{:?}", x),
+        CalculatorFloat::Str(x) => x.replace('^', "**"),
+    }
+}
+
+/// Returns the string representation of a CalculatorComplex that can be parsed by SymPy.
+fn coefficient_string(value: &CalculatorComplex) -> String {
+    format!(
+        "(({}) + I*({}))",
+        float_string(&value.re),
+        float_string(&value.im)
+    )
+}
+
+/// Parses the sum of the given terms into a SymPy expression.
+///
+/// Only the names of the operators in `local_dict` and a restricted set of SymPy functions are
+/// predefined, all other names in the coefficients are parsed as commutative symbols.
+fn parse_terms(py: Python, terms: Vec<String>, local_dict: Bound<PyDict>) -> PyResult<PyObject> {
+    let sympy = py.import_bound("sympy")?;
+    if terms.is_empty() {
+        return Ok(sympy.getattr("Integer")?.call1((0,))?.unbind());
+    }
+    let global_dict = PyDict::new_bound(py);
+    for name in [
+        "I", "Integer", "Float", "Rational", "Symbol", "Function", "sin", "cos", "tan", "asin",
+        "acos", "atan", "sinh", "cosh", "tanh", "exp", "log", "sqrt",
+    ] {
+        global_dict.set_item(name, sympy.getattr(name)?)?;
+    }
+    global_dict.set_item("abs", sympy.getattr("Abs")?)?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("local_dict", local_dict)?;
+    kwargs.set_item("global_dict", global_dict)?;
+    let expression = sympy
+        .getattr("parse_expr")?
+        .call((terms.join(" + "),), Some(&kwargs))
+        .map_err(|err| {
+            PyValueError::new_err(format!(
+                "Coefficients could not be parsed by SymPy: {:?}",
+                err
+            ))
+        })?;
+    Ok(expression.unbind())
+}
+
+/// Converts a SpinOperator into a SymPy expression.
+pub(crate) fn spin_operator_to_sympy(py: Python, operator: &SpinOperator) -> PyResult<PyObject> {
+    let sympy = py.import_bound("sympy")?;
+    let local_dict = PyDict::new_bound(py);
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("commutative", false)?;
+    let mut terms: Vec<String> = Vec::with_capacity(operator.len());
+    for (product, value) in operator.iter() {
+        let mut factors: Vec<String> = vec![coefficient_string(value)];
+        for (index, pauli) in product.iter() {
+            let name = match pauli {
+                SingleSpinOperator::Identity => continue,
+                SingleSpinOperator::X => format!("X_{}", index),
+                SingleSpinOperator::Y => format!("Y_{}", index),
+                SingleSpinOperator::Z => format!("Z_{}", index),
+            };
+            local_dict.set_item(
+                &name,
+                sympy
+                    .getattr("Symbol")?
+                    .call((name.clone(),), Some(&kwargs))?,
+            )?;
+            factors.push(name);
+        }
+        terms.push(factors.join("*"));
+    }
+    parse_terms(py, terms, local_dict)
+}
+
+/// Converts the terms of a FermionOperator or BosonOperator into a SymPy expression.
+fn mode_terms_to_sympy<'a>(
+    py: Python,
+    terms_iter: impl Iterator<Item = (Vec<usize>, Vec<usize>, &'a CalculatorComplex)>,
+    operator_module: &str,
+    operator_class: &str,
+    prefix: &str,
+) -> PyResult<PyObject> {
+    let quantum = py.import_bound("sympy.physics.quantum")?;
+    let operator_type = py.import_bound(operator_module)?.getattr(operator_class)?;
+    let local_dict = PyDict::new_bound(py);
+    local_dict.set_item("Dagger", quantum.getattr("Dagger")?)?;
+    let mut terms: Vec<String> = Vec::new();
+    for (creators, annihilators, value) in terms_iter {
+        let mut factors: Vec<String> = vec![coefficient_string(value)];
+        for (index, is_creator) in creators
+            .iter()
+            .map(|c| (c, true))
+            .chain(annihilators.iter().map(|a| (a, false)))
+        {
+            let name = format!("{}_{}", prefix, index);
+            local_dict.set_item(&name, operator_type.call1((name.clone(),))?)?;
+            if is_creator {
+                factors.push(format!("Dagger({})", name));
+            } else {
+                factors.push(name);
+            }
+        }
+        terms.push(factors.join("*"));
+    }
+    parse_terms(py, terms, local_dict)
+}
+
+/// Converts a FermionOperator into a SymPy expression.
+pub(crate) fn fermion_operator_to_sympy(
+    py: Python,
+    operator: &FermionOperator,
+) -> PyResult<PyObject> {
+    mode_terms_to_sympy(
+        py,
+        operator.iter().map(|(product, value)| {
+            (
+                product.creators().cloned().collect(),
+                product.annihilators().cloned().collect(),
+                value,
+            )
+        }),
+        "sympy.physics.quantum.fermion",
+        "FermionOp",
+        "c",
+    )
+}
+
+/// Converts a BosonOperator into a SymPy expression.
+pub(crate) fn boson_operator_to_sympy(py: Python, operator: &BosonOperator) -> PyResult<PyObject> {
+    mode_terms_to_sympy(
+        py,
+        operator.iter().map(|(product, value)| {
+            (
+                product.creators().cloned().collect(),
+                product.annihilators().cloned().collect(),
+                value,
+            )
+        }),
+        "sympy.physics.quantum.boson",
+        "BosonOp",
+        "b",
+    )
+}
+
+/// Converts a commutative SymPy expression without imaginary unit into a CalculatorFloat.
+fn sympy_to_calculator_float(value: &Bound<PyAny>) -> PyResult<CalculatorFloat> {
+    if value.getattr("is_number")?.extract::<bool>()? {
+        Ok(CalculatorFloat::from(value.extract::<f64>()?))
+    } else {
+        Ok(CalculatorFloat::from(
+            value.str()?.to_string().replace("**", "^"),
+        ))
+    }
+}
+
+/// Converts a commutative SymPy expression into a CalculatorComplex.
+fn sympy_to_calculator_complex(
+    sympy: &Bound<PyModule>,
+    value: &Bound<PyAny>,
+) -> PyResult<CalculatorComplex> {
+    let py = value.py();
+    let imaginary_unit = sympy.getattr("I")?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("as_Add", true)?;
+    let parts = value.call_method("as_independent", (imaginary_unit.clone(),), Some(&kwargs))?;
+    let real = parts.get_item(0)?;
+    let imaginary = sympy.call_method1("expand", (parts.get_item(1)?.div(imaginary_unit)?,))?;
+    Ok(CalculatorComplex::new(
+        sympy_to_calculator_float(&real)?,
+        sympy_to_calculator_float(&imaginary)?,
+    ))
+}
+
+/// Splits a SymPy expression into its terms.
+///
+/// Every term is returned as its commutative coefficient and the ordered list of non-commutative factors,
+/// with integer powers of factors expanded into repeated factors.
+fn sympy_terms<'py>(
+    expression: &Bound<'py, PyAny>,
+) -> PyResult<Vec<(CalculatorComplex, Vec<Bound<'py, PyAny>>)>> {
+    let py = expression.py();
+    let sympy = py.import_bound("sympy")?;
+    let expanded =
+        sympy.call_method1("expand", (sympy.call_method1("sympify", (expression,))?,))?;
+    let terms = sympy
+        .getattr("Add")?
+        .call_method1("make_args", (expanded,))?;
+    let mut out: Vec<(CalculatorComplex, Vec<Bound<'py, PyAny>>)> = Vec::new();
+    for term in terms.iter()? {
+        let split = term?.call_method0("args_cnc")?;
+        let commutative: Vec<Bound<PyAny>> = split.get_item(0)?.iter()?.collect::<PyResult<_>>()?;
+        let coefficient = sympy
+            .getattr("Mul")?
+            .call1(PyTuple::new_bound(py, commutative))?;
+        let mut factors: Vec<Bound<PyAny>> = Vec::new();
+        for factor in split.get_item(1)?.iter()? {
+            let base_exponent = factor?.call_method0("as_base_exp")?;
+            let exponent: usize = base_exponent.get_item(1)?.extract().map_err(|_| {
+                PyValueError::new_err("Only non-negative integer powers of operators are supported")
+            })?;
+            let base = base_exponent.get_item(0)?;
+            for _ in 0..exponent {
+                factors.push(base.clone());
+            }
+        }
+        out.push((sympy_to_calculator_complex(&sympy, &coefficient)?, factors));
+    }
+    Ok(out)
+}
+
+/// Returns the index of an operator name of the form `<prefix>_<index>`.
+fn index_from_name(name: &str) -> Option<usize> {
+    name.rsplit_once('_')
+        .and_then(|(_, index)| index.parse::<usize>().ok())
+}
+
+/// Converts a SymPy expression in the non-commutative symbols `X_i`, `Y_i` and `Z_i` into a SpinOperator.
+pub(crate) fn spin_operator_from_sympy(expression: &Bound<PyAny>) -> PyResult<SpinOperator> {
+    let mut operator = SpinOperator::new();
+    for (coefficient, factors) in sympy_terms(expression)? {
+        let mut term = SpinOperator::new();
+        term.add_operator_product(PauliProduct::new(), coefficient)
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        for factor in factors {
+            let name: String = factor
+                .getattr("name")?
+                .extract()
+                .map_err(|_| PyValueError::new_err(format!("Unrecognized operator {}", factor)))?;
+            let index = index_from_name(&name)
+                .ok_or_else(|| PyValueError::new_err(format!("Unrecognized operator {}", name)))?;
+            let product = match name.split_once('_').map(|(pauli, _)| pauli) {
+                Some("X") => PauliProduct::new().x(index),
+                Some("Y") => PauliProduct::new().y(index),
+                Some("Z") => PauliProduct::new().z(index),
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Unrecognized operator {}",
+                        name
+                    )))
+                }
+            };
+            let mut single = SpinOperator::new();
+            single
+                .add_operator_product(product, CalculatorComplex::from(1.0))
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+            term = term * single;
+        }
+        operator = operator + term;
+    }
+    Ok(operator)
+}
+
+/// Returns the index and whether the factor is a creator for a `FermionOp` or `BosonOp` factor.
+fn mode_factor(
+    factor: &Bound<PyAny>,
+    operator_module: &str,
+    operator_class: &str,
+) -> PyResult<(usize, bool)> {
+    let py = factor.py();
+    let operator_type = py.import_bound(operator_module)?.getattr(operator_class)?;
+    if !factor.is_instance(&operator_type)? {
+        return Err(PyValueError::new_err(format!(
+            "Unrecognized operator {}, expected {}",
+            factor, operator_class
+        )));
+    }
+    let name = factor.getattr("name")?.str()?.to_string();
+    let index = index_from_name(&name)
+        .ok_or_else(|| PyValueError::new_err(format!("Unrecognized operator {}", name)))?;
+    let is_annihilation: bool = factor.getattr("is_annihilation")?.extract()?;
+    Ok((index, !is_annihilation))
+}
+
+/// Converts a SymPy expression in `FermionOp("c_i")` and their daggers into a FermionOperator.
+pub(crate) fn fermion_operator_from_sympy(expression: &Bound<PyAny>) -> PyResult<FermionOperator> {
+    let mut operator = FermionOperator::new();
+    for (coefficient, factors) in sympy_terms(expression)? {
+        let mut term = FermionOperator::new();
+        term.add_operator_product(
+            FermionProduct::new([], [])
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+            coefficient,
+        )
+        .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        for factor in factors {
+            let (index, is_creator) =
+                mode_factor(&factor, "sympy.physics.quantum.fermion", "FermionOp")?;
+            let product = if is_creator {
+                FermionProduct::new([index], [])
+            } else {
+                FermionProduct::new([], [index])
+            }
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+            let mut single = FermionOperator::new();
+            single
+                .add_operator_product(product, CalculatorComplex::from(1.0))
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+            term = term * single;
+        }
+        operator = operator + term;
+    }
+    Ok(operator)
+}
+
+/// Converts a SymPy expression in `BosonOp("b_i")` and their daggers into a BosonOperator.
+pub(crate) fn boson_operator_from_sympy(expression: &Bound<PyAny>) -> PyResult<BosonOperator> {
+    let mut operator = BosonOperator::new();
+    for (coefficient, factors) in sympy_terms(expression)? {
+        let mut term = BosonOperator::new();
+        term.add_operator_product(
+            BosonProduct::new([], []).map_err(|err| PyValueError::new_err(format!("{:?}", err)))?,
+            coefficient,
+        )
+        .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+        for factor in factors {
+            let (index, is_creator) =
+                mode_factor(&factor, "sympy.physics.quantum.boson", "BosonOp")?;
+            let product = if is_creator {
+                BosonProduct::new([index], [])
+            } else {
+                BosonProduct::new([], [index])
+            }
+            .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+            let mut single = BosonOperator::new();
+            single
+                .add_operator_product(product, CalculatorComplex::from(1.0))
+                .map_err(|err| PyValueError::new_err(format!("{:?}", err)))?;
+            term = term * single;
+        }
+        operator = operator + term;
+    }
+    Ok(operator)
+}
@@ -21,7 +21,7 @@ and Lindblad type fermionic open systems (FermionLindbladNoiseSystem, FermionLin
 """
 
 from .struqture_py import ProductType, SystemType, NoiseType
-from typing import Optional, List, Tuple, Union
+from typing import Optional, Iterator, List, Tuple, Dict, Union
 
 class FermionProduct(ProductType):
     """
@@ -62,6 +62,12 @@ class FermionProduct(ProductType):
         the Jordan Wigner mapping.
         """
 
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
     def hermitian_conjugate(self):  # type: ignore
         """
         Return the hermitian conjugate of self and its prefactor.
@@ -163,6 +169,28 @@ class FermionProduct(ProductType):
             ValueError: Indices given in either creators or annihilators contain a double index specification (only applicable to fermionic objects).
         """
 
+    def number(self, mode: int):  # type: ignore
+        """
+        Create the number operator acting on a single mode.
+
+        Args:
+           mode (int): The mode the number operator acts on.
+
+        Returns:
+           self: The product with one creator and one annihilator acting on mode.
+        """
+
+    def is_density_term(self) -> bool:  # type: ignore
+        """
+        Return whether self is a density term.
+
+        A density term is a non-empty product with identical creator and annihilator indices,
+        i.e. a product of number operators up to normal ordering.
+
+        Returns:
+            bool: Whether self is a density term.
+        """
+
     def from_bincode(self, input: bytearray):  # type: ignore
         """
         Convert the bincode representation of the object to an instance using the [bincode] crate.
@@ -291,6 +319,12 @@ class HermitianFermionProduct(ProductType):
         the Jordan Wigner mapping.
         """
 
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
     def hermitian_conjugate(self):  # type: ignore
         """
         Return the hermitian conjugate of self and its prefactor.
@@ -392,6 +426,28 @@ class HermitianFermionProduct(ProductType):
             ValueError: Indices given in either creators or annihilators contain a double index specification (only applicable to fermionic objects).
         """
 
+    def number(self, mode: int):  # type: ignore
+        """
+        Create the number operator acting on a single mode.
+
+        Args:
+           mode (int): The mode the number operator acts on.
+
+        Returns:
+           self: The product with one creator and one annihilator acting on mode.
+        """
+
+    def is_density_term(self) -> bool:  # type: ignore
+        """
+        Return whether self is a density term.
+
+        A density term is a non-empty product with identical creator and annihilator indices,
+        i.e. a product of number operators up to normal ordering.
+
+        Returns:
+            bool: Whether self is a density term.
+        """
+
     def from_bincode(self, input: bytearray):  # type: ignore
         """
         Convert the bincode representation of the object to an instance using the [bincode] crate.
@@ -522,12 +578,30 @@ class FermionSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> FermionSystem:  # type: ignore
@@ -560,6 +634,94 @@ class FermionSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: FermionSystem, policy: str = "sum") -> FermionSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -603,12 +765,13 @@ class FermionSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -616,12 +779,70 @@ class FermionSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> FermionSystem:  # type: ignore
@@ -699,6 +920,71 @@ class FermionSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -713,6 +999,62 @@ class FermionSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -737,6 +1079,39 @@ class FermionSystem(SystemType):
             str: The json schema serialized to json
         """
 
+    def to_sympy(self):  # type: ignore
+        """
+        Convert the FermionSystem to a SymPy expression.
+
+        The fermionic annihilation operators are represented by `sympy.physics.quantum.fermion.FermionOp("c_i")` and creation operators by their `Dagger`.
+        Symbolic coefficients are parsed into commutative SymPy symbols.
+
+        Returns:
+            sympy.Expr: The SymPy expression representing self.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: Coefficients could not be parsed by SymPy.
+        """
+
+    @staticmethod
+    def from_sympy(expression, number_fermions: Optional[int]) -> FermionSystem:  # type: ignore
+        """
+        Create a FermionSystem from a SymPy expression.
+
+        Args:
+            expression (sympy.Expr): A polynomial in `FermionOp("c_i")` and `Dagger(FermionOp("c_i"))`.
+            number_fermions (Optional[int]): The number of fermions of the new FermionSystem.
+
+        Returns:
+            FermionSystem: The FermionSystem represented by the expression.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: The expression contains unrecognized operators or non-integer powers.
+            ValueError: Could not create FermionSystem.
+        """
+
 class FermionHamiltonianSystem(SystemType):
     """
     These are representations of systems of fermions.
@@ -778,12 +1153,30 @@ class FermionHamiltonianSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> FermionHamiltonianSystem:  # type: ignore
@@ -816,32 +1209,120 @@ class FermionHamiltonianSystem(SystemType):
             self: The truncated version of self.
         """
 
-    def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
         """
-        Get the coefficient corresponding to the key.
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
 
         Args:
-            key: Product to get the value of.
+            threshold (float): The threshold for inclusion.
 
         Returns:
-            CalculatorComplex: Value at key (or 0.0).
-
-        Raises:
-            ValueError: Product could not be constructed from key.
+            Tuple[self, self]: The kept and the discarded terms.
         """
 
-    def remove(self, key: ProductType) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
         """
-        Remove the value of the input key.
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
 
         Args:
-            key (Product type): The key of the value to remove.
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
 
-         Returns:
-            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
 
         Raises:
-            ValueError: Product could not be constructed.
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: FermionHamiltonianSystem, policy: str = "sum") -> FermionHamiltonianSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
+        """
+        Get the coefficient corresponding to the key.
+
+        Args:
+            key: Product to get the value of.
+
+        Returns:
+            CalculatorComplex: Value at key (or 0.0).
+
+        Raises:
+            ValueError: Product could not be constructed from key.
+        """
+
+    def remove(self, key: ProductType) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Remove the value of the input key.
+
+        Args:
+            key (Product type): The key of the value to remove.
+
+         Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: Product could not be constructed.
         """
 
     def set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
@@ -859,12 +1340,13 @@ class FermionHamiltonianSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -872,12 +1354,70 @@ class FermionHamiltonianSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return the threshold up to which values are dropped from self.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> FermionHamiltonianSystem:  # type: ignore
@@ -955,6 +1495,71 @@ class FermionHamiltonianSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -969,6 +1574,62 @@ class FermionHamiltonianSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -993,6 +1654,59 @@ class FermionHamiltonianSystem(SystemType):
             str: The json schema serialized to json
         """
 
+    def mean_field_decouple(self, averages: Dict[Union[FermionProduct, str], complex]) -> FermionHamiltonianSystem:  # type: ignore
+        """
+        Decouple the two-particle terms in the Hartree-Fock mean-field approximation.
+
+        Every term c^†_p c^†_q c_r c_s is replaced by its linearization in the one-particle averages G_ij = <c^†_i c_j>,
+        G_ps c^†_q c_r + G_qr c^†_p c_s - G_pr c^†_q c_s - G_qs c^†_p c_r - (G_ps G_qr - G_pr G_qs).
+        Terms with at most two fermionic operators are kept unchanged.
+
+        Args:
+            averages (Dict[Union[FermionProduct, str], complex]): The one-particle averages <c^†_i c_j> with the FermionProduct c^†_i c_j as key. Missing averages are zero.
+
+        Returns:
+            FermionHamiltonianSystem: The quadratic mean-field FermionHamiltonianSystem including the constant term.
+
+        Raises:
+            TypeError: A key cannot be converted to a FermionProduct or a value to complex.
+            ValueError: An average is not a one-particle average or a term cannot be decoupled.
+        """
+
+    def to_sympy(self):  # type: ignore
+        """
+        Convert the FermionHamiltonianSystem to a SymPy expression.
+
+        The fermionic annihilation operators are represented by `sympy.physics.quantum.fermion.FermionOp("c_i")` and creation operators by their `Dagger`.
+        Symbolic coefficients are parsed into commutative SymPy symbols.
+
+        Returns:
+            sympy.Expr: The SymPy expression representing self.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: Coefficients could not be parsed by SymPy.
+        """
+
+    @staticmethod
+    def from_sympy(expression, number_fermions: Optional[int]) -> FermionHamiltonianSystem:  # type: ignore
+        """
+        Create a FermionHamiltonianSystem from a SymPy expression.
+
+        Args:
+            expression (sympy.Expr): A polynomial in `FermionOp("c_i")` and `Dagger(FermionOp("c_i"))`.
+            number_fermions (Optional[int]): The number of fermions of the new FermionHamiltonianSystem.
+
+        Returns:
+            FermionHamiltonianSystem: The FermionHamiltonianSystem represented by the expression.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: The expression contains unrecognized operators or non-integer powers.
+            ValueError: The expression is not hermitian.
+            ValueError: Could not create FermionHamiltonianSystem.
+        """
+
 class FermionLindbladNoiseSystem(SystemType):
     """
     These are representations of noisy systems of fermions.
@@ -1032,6 +1746,12 @@ class FermionLindbladNoiseSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
     def separate_into_n_terms(self, number_creators_annihilators_left: Tuple[int, int], number_creators_annihilators_right: Tuple[int, int]) -> Tuple[FermionLindbladNoiseSystem, FermionLindbladNoiseSystem]:  # type: ignore
         """
         Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations.
@@ -1089,136 +1809,381 @@ class FermionLindbladNoiseSystem(SystemType):
             Optional[CalculatorComplex]: Key existed if this is not None, and this is the value it had before it was overwritten.
 
         Raises:
-            ValueError: Left-hand Product could not be constructed.
-            ValueError: Right-hand Product could not be constructed.
+            ValueError: Left-hand Product could not be constructed.
+            ValueError: Right-hand Product could not be constructed.
+        """
+
+    def add_operator_product(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]):  # type: ignore
+        """
+        Adds a new (key object, CalculatorComplex) pair to existing entries.
+
+        Args:
+            key (Tuple[Product type, Product type]): The key of the value to add.
+            value (CalculatorComplex): The value to add.
+
+        Raises:
+            TypeError: Value is not CalculatorComplex or CalculatorFloat.
+            ValueError: Left-hand product could not be constructed.
+            ValueError: Right-hand product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def keys(self) -> Iterator[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[(OperatorProduct, OperatorProduct), Union[float, int, str, complex]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Tuple[(OperatorProduct, OperatorProduct), CalculatorComplex]]: The iterator over the entries of self.
+        """
+
+    def values(self) -> Iterator[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[CalculatorComplex]: The iterator over the values of self.
+        """
+
+    def empty_clone(self, capacity) -> FermionLindbladNoiseSystem:  # type: ignore
+        """
+        Return an instance of self that has no entries but clones all other properties, with the given capacity.
+
+        Args:
+            capacity: The capacity of the object to create.
+
+        Returns:
+            self: An empty clone with the same properties as self, with the given capacity.
+        """
+
+    def is_empty(self) -> bool:  # type: ignore
+        """
+        Return true if object contains no values.
+
+        Returns:
+            bool: Whether self is empty or not.
+        """
+
+    def truncate(self, threshold) -> FermionLindbladNoiseSystem:  # type: ignore
+        """
+        Truncate self by returning a copy without entries under a threshold.
+
+        Args:
+            threshold: The threshold for inclusion.
+
+        Returns:
+            self: The truncated version of self.
+        """
+
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: FermionLindbladNoiseSystem, policy: str = "sum") -> FermionLindbladNoiseSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def current_number_modes(self) -> int:  # type: ignore
+        """
+        Return maximum index in object.
+
+        Returns:
+            int: Maximum index.
+        """
+
+    def number_modes(self) -> int:  # type: ignore
+        """
+        Return the number_modes input of self.
+
+        Returns:
+            int: The number of modes in self.
+        """
+
+    def from_bincode(self, input: bytearray):  # type: ignore
+        """
+        Convert the bincode representation of the object to an instance using the [bincode] crate.
+
+        Args:
+            input (bytearray): The serialized object (in [bincode] form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_bincode(self) -> bytearray:  # type: ignore
+        """
+        Return the bincode representation of the object using the [bincode] crate.
+
+        Returns:
+            bytearray: The serialized object (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
         """
 
-    def add_operator_product(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]):  # type: ignore
+    def to_json(self) -> str:  # type: ignore
         """
-        Adds a new (key object, CalculatorComplex) pair to existing entries.
+        Return the json representation of the object.
 
-        Args:
-            key (Tuple[Product type, Product type]): The key of the value to add.
-            value (CalculatorComplex): The value to add.
+        Returns:
+            str: The serialized form of the object.
 
         Raises:
-            TypeError: Value is not CalculatorComplex or CalculatorFloat.
-            ValueError: Left-hand product could not be constructed.
-            ValueError: Right-hand product could not be constructed.
-            ValueError: Error in add_operator_product function of self.
+            ValueError: Cannot serialize object to json.
         """
 
-    def keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+    def to_json_canonical(self) -> str:  # type: ignore
         """
-        Return unsorted keys in self.
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
 
         Returns:
-            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of self.
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
         """
 
-    def values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+    def to_msgpack(self) -> bytearray:  # type: ignore
         """
-        Return unsorted values in self.
+        Return the MessagePack representation of the object.
 
         Returns:
-            List[CalculatorComplex]: The sequence of values of self.
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
         """
 
-    def empty_clone(self, capacity) -> FermionLindbladNoiseSystem:  # type: ignore
+    def from_msgpack(self, input: bytearray):  # type: ignore
         """
-        Return an instance of self that has no entries but clones all other properties, with the given capacity.
+        Convert the MessagePack representation of the object to an instance.
 
         Args:
-            capacity: The capacity of the object to create.
+            input (bytearray): The serialized object (in MessagePack form).
 
         Returns:
-            self: An empty clone with the same properties as self, with the given capacity.
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
         """
 
-    def is_empty(self) -> bool:  # type: ignore
+    def to_cbor(self) -> bytearray:  # type: ignore
         """
-        Return true if object contains no values.
+        Return the CBOR representation of the object.
 
         Returns:
-            bool: Whether self is empty or not.
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
         """
 
-    def truncate(self, threshold) -> FermionLindbladNoiseSystem:  # type: ignore
+    def from_cbor(self, input: bytearray):  # type: ignore
         """
-        Truncate self by returning a copy without entries under a threshold.
+        Convert the CBOR representation of the object to an instance.
 
         Args:
-            threshold: The threshold for inclusion.
+            input (bytearray): The serialized object (in CBOR form).
 
         Returns:
-            self: The truncated version of self.
-        """
+           The deserialized object.
 
-    def current_number_modes(self) -> int:  # type: ignore
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
         """
-        Return maximum index in object.
 
-        Returns:
-            int: Maximum index.
+    def from_json(self, input: str):  # type: ignore
         """
+        Convert the json representation of the object to an instance.
 
-    def number_modes(self) -> int:  # type: ignore
-        """
-        Return the number_modes input of self.
+        Args:
+            input (str): The serialized object in json form.
 
         Returns:
-            int: The number of modes in self.
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be deserialized.
         """
 
-    def from_bincode(self, input: bytearray):  # type: ignore
+    def from_json_struqture_2(self, input: str):  # type: ignore
         """
-        Convert the bincode representation of the object to an instance using the [bincode] crate.
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
 
         Args:
-            input (bytearray): The serialized object (in [bincode] form).
+            input (str): The serialized struqture 2.x object in json form.
 
         Returns:
-           The deserialized object.
+            The deserialized object.
 
         Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized.
+            ValueError: Input cannot be migrated or deserialized.
         """
 
-    def to_bincode(self) -> bytearray:  # type: ignore
+    def to_json_struqture_2(self) -> str:  # type: ignore
         """
-        Return the bincode representation of the object using the [bincode] crate.
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
 
         Returns:
-            bytearray: The serialized object (in [bincode] form).
+            str: The serialized struqture 2.x object.
 
         Raises:
-            ValueError: Cannot serialize object to bytes.
+            ValueError: Cannot convert self to struqture 2.x.
         """
 
-    def to_json(self) -> str:  # type: ignore
+    def to_canonical_text(self) -> str:  # type: ignore
         """
-        Return the json representation of the object.
+        Return the canonical text representation of self.
 
-        Returns:
-            str: The serialized form of the object.
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
 
-        Raises:
-            ValueError: Cannot serialize object to json.
+        Returns:
+            str: The canonical text representation of self.
         """
 
-    def from_json(self, input: str):  # type: ignore
+    def from_canonical_text(self, input: str):  # type: ignore
         """
-        Convert the json representation of the object to an instance.
+        Convert the canonical text representation of self to an instance.
 
         Args:
-            input (str): The serialized object in json form.
+            input (str): The canonical text representation of the object.
 
         Returns:
-            The deserialized object.
+            The object represented by the canonical text.
 
         Raises:
-            ValueError: Input cannot be deserialized.
+            ValueError: Input cannot be parsed.
         """
 
     def current_version(self) -> str:  # type: ignore
@@ -1284,6 +2249,12 @@ class FermionLindbladOpenSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
+    def bravyi_kitaev(self):  # type: ignore
+        """
+        Transform the given fermionic object into a spin object using
+        the Bravyi Kitaev mapping.
+        """
+
     def current_number_modes(self) -> int:  # type: ignore
         """
         Return maximum index in object.
@@ -1360,6 +2331,56 @@ class FermionLindbladOpenSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
     def system_set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
         """
         Set a new entry in the system of the open system.
@@ -1422,6 +2443,83 @@ class FermionLindbladOpenSystem(SystemType):
             ValueError: Right key element cannot be converted to product.
         """
 
+    def system_remove(self, key: ProductType) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Remove the value of a key from the system of the open system.
+
+        Args:
+            key (Product type): Product key of the removed object.
+
+        Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+        """
+
+    def noise_remove(self, key: Tuple[ProductType, ProductType]) -> Optional[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Remove the value of a key from the noise of the open system.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of the removed object.
+
+        Returns:
+            Optional[CalculatorComplex]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+        """
+
+    def system_keys(self) -> List[OperatorProduct]:  # type: ignore
+        """
+        Return unsorted keys in the system of the open system.
+
+        Returns:
+            List[OperatorProduct]: The sequence of keys of the system.
+        """
+
+    def noise_keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return unsorted keys in the noise of the open system.
+
+        Returns:
+            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of the noise.
+        """
+
+    def system_values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return unsorted values in the system of the open system.
+
+        Returns:
+            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of the system.
+        """
+
+    def noise_values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Return unsorted values in the noise of the open system.
+
+        Returns:
+            List[CalculatorComplex]: The sequence of values of the noise.
+        """
+
+    def system_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the system of the open system.
+
+        Returns:
+            int: The number of entries in the system.
+        """
+
+    def noise_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the noise of the open system.
+
+        Returns:
+            int: The number of entries in the noise.
+        """
+
     def system_add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
         """
         Add a new entry to the system of the open system.
@@ -1492,6 +2590,71 @@ class FermionLindbladOpenSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of the object to an instance.
@@ -1506,6 +2669,62 @@ class FermionLindbladOpenSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
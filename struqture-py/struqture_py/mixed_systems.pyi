@@ -26,11 +26,13 @@ mixed systems (MixedSystem and MixedHamiltonianSystem) and Lindblad type mixed o
     MixedLindbladOpenSystem
     MixedPlusMinusProduct
     MixedPlusMinusOperator
+    MixedProductBuilder
 
 """
 
 from .struqture_py import ProductType, SystemType, NoiseType
-from typing import Optional, List, Tuple, Union
+import numpy
+from typing import Optional, Iterator, List, Tuple, Union
 from .bosons import *
 from .fermions import *
 from .spins import *
@@ -793,12 +795,24 @@ class MixedSystem(SystemType):
     ):
         return
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> MixedSystem:  # type: ignore
@@ -831,6 +845,94 @@ class MixedSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: MixedSystem, policy: str = "sum") -> MixedSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -874,12 +976,13 @@ class MixedSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -887,12 +990,70 @@ class MixedSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
         """
-        Return unsorted values in self.
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> MixedSystem:  # type: ignore
@@ -903,6 +1064,14 @@ class MixedSystem(SystemType):
             self: The hermitian conjugate of self.
         """
 
+    def product_builder(self) -> MixedProductBuilder:  # type: ignore
+        """
+        Return a MixedProductBuilder with the number of subsystems of the MixedSystem.
+
+        Returns:
+            MixedProductBuilder: The builder acting as the identity on all subsystems of the MixedSystem.
+        """
+
     def number_spins(self) -> int:  # type: ignore
         """
         Return the number_spins input of each spin subsystem of self.
@@ -988,6 +1157,71 @@ class MixedSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -1002,6 +1236,62 @@ class MixedSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -1070,12 +1360,24 @@ class MixedHamiltonianSystem(SystemType):
     ):
         return
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> MixedHamiltonianSystem:  # type: ignore
@@ -1108,6 +1410,94 @@ class MixedHamiltonianSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: MixedHamiltonianSystem, policy: str = "sum") -> MixedHamiltonianSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -1151,12 +1541,13 @@ class MixedHamiltonianSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -1164,41 +1555,107 @@ class MixedHamiltonianSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return the threshold up to which values are dropped from self.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Optional[float]: The threshold, None if only exact zeros are dropped.
         """
 
-    def hermitian_conjugate(self) -> MixedHamiltonianSystem:  # type: ignore
+    def set_zero_threshold(self, threshold: float):  # type: ignore
         """
-        Return the hermitian conjugate of self.
+        Set the threshold up to which values are dropped from self.
 
-        Returns:
-            self: The hermitian conjugate of self.
-        """
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
 
-    def number_spins(self) -> int:  # type: ignore
-        """
-        Return the number_spins input of each spin subsystem of self.
+        Args:
+            threshold (float): The threshold.
 
-        Returns:
-            int: The number of spins in each spin subsystem of self.
+        Raises:
+            ValueError: The threshold is negative or NaN.
         """
 
-    def current_number_spins(self) -> int:  # type: ignore
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
         """
-        Return maximum spin index in each spin subsystem of self.
+        Add many (key object, value) pairs to the existing entries at once.
 
-        Returns:
-            int: Maximum index in each spin subsystem of self.
-        """
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
 
-    def number_bosonic_modes(self) -> List[int]:  # type: ignore
-        """
-        Return the number of bosonic modes in each bosonic subsystem of self.
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
+        """
+
+    def hermitian_conjugate(self) -> MixedHamiltonianSystem:  # type: ignore
+        """
+        Return the hermitian conjugate of self.
+
+        Returns:
+            self: The hermitian conjugate of self.
+        """
+
+    def product_builder(self) -> MixedProductBuilder:  # type: ignore
+        """
+        Return a MixedProductBuilder with the number of subsystems of the MixedHamiltonianSystem.
+
+        Returns:
+            MixedProductBuilder: The builder acting as the identity on all subsystems of the MixedHamiltonianSystem.
+        """
+
+    def number_spins(self) -> int:  # type: ignore
+        """
+        Return the number_spins input of each spin subsystem of self.
+
+        Returns:
+            int: The number of spins in each spin subsystem of self.
+        """
+
+    def current_number_spins(self) -> int:  # type: ignore
+        """
+        Return maximum spin index in each spin subsystem of self.
+
+        Returns:
+            int: Maximum index in each spin subsystem of self.
+        """
+
+    def number_bosonic_modes(self) -> List[int]:  # type: ignore
+        """
+        Return the number of bosonic modes in each bosonic subsystem of self.
 
         Returns:
             List[int]: The number of bosonic modes in each bosonic subsystem of self.
@@ -1265,6 +1722,71 @@ class MixedHamiltonianSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -1279,6 +1801,62 @@ class MixedHamiltonianSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -1407,20 +1985,56 @@ class MixedLindbladNoiseSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
         """
-        Return unsorted keys in self.
+        Return the threshold up to which values are dropped from self.
 
         Returns:
-            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of self.
+            Optional[float]: The threshold, None if only exact zeros are dropped.
         """
 
-    def values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+    def set_zero_threshold(self, threshold: float):  # type: ignore
         """
-        Return unsorted values in self.
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def keys(self) -> Iterator[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
 
         Returns:
-            List[CalculatorComplex]: The sequence of values of self.
+            Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[(OperatorProduct, OperatorProduct), Union[float, int, str, complex]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Tuple[(OperatorProduct, OperatorProduct), CalculatorComplex]]: The iterator over the entries of self.
+        """
+
+    def values(self) -> Iterator[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[CalculatorComplex]: The iterator over the values of self.
         """
 
     def empty_clone(self, capacity) -> MixedLindbladNoiseSystem:  # type: ignore
@@ -1453,6 +2067,94 @@ class MixedLindbladNoiseSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: MixedLindbladNoiseSystem, policy: str = "sum") -> MixedLindbladNoiseSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def number_spins(self) -> int:  # type: ignore
         """
         Return the number_spins input of each spin subsystem of self.
@@ -1538,6 +2240,71 @@ class MixedLindbladNoiseSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of the object to an instance.
@@ -1552,6 +2319,62 @@ class MixedLindbladNoiseSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -1678,6 +2501,56 @@ class MixedLindbladOpenSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
     def system_set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
         """
         Set a new entry in the system of the open system.
@@ -1740,6 +2613,83 @@ class MixedLindbladOpenSystem(SystemType):
             ValueError: Right key element cannot be converted to product.
         """
 
+    def system_remove(self, key: ProductType) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Remove the value of a key from the system of the open system.
+
+        Args:
+            key (Product type): Product key of the removed object.
+
+        Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+        """
+
+    def noise_remove(self, key: Tuple[ProductType, ProductType]) -> Optional[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Remove the value of a key from the noise of the open system.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of the removed object.
+
+        Returns:
+            Optional[CalculatorComplex]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+        """
+
+    def system_keys(self) -> List[OperatorProduct]:  # type: ignore
+        """
+        Return unsorted keys in the system of the open system.
+
+        Returns:
+            List[OperatorProduct]: The sequence of keys of the system.
+        """
+
+    def noise_keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return unsorted keys in the noise of the open system.
+
+        Returns:
+            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of the noise.
+        """
+
+    def system_values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return unsorted values in the system of the open system.
+
+        Returns:
+            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of the system.
+        """
+
+    def noise_values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Return unsorted values in the noise of the open system.
+
+        Returns:
+            List[CalculatorComplex]: The sequence of values of the noise.
+        """
+
+    def system_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the system of the open system.
+
+        Returns:
+            int: The number of entries in the system.
+        """
+
+    def noise_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the noise of the open system.
+
+        Returns:
+            int: The number of entries in the noise.
+        """
+
     def system_add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
         """
         Add a new entry to the system of the open system.
@@ -1832,44 +2782,165 @@ class MixedLindbladOpenSystem(SystemType):
            The deserialized object.
 
         Raises:
-            TypeError: Input cannot be converted to byte array.
-            ValueError: Input cannot be deserialized.
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_bincode(self) -> bytearray:  # type: ignore
+        """
+        Return the bincode representation of the object using the [bincode] crate.
+
+        Returns:
+            bytearray: The serialized object (in [bincode] form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def to_json(self) -> str:  # type: ignore
+        """
+        Return the json representation of the object.
+
+        Returns:
+            str: The serialized form of the object.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def from_json(self, input: str):  # type: ignore
+        """
+        Convert the json representation of the object to an instance.
+
+        Args:
+            input (str): The serialized object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be deserialized.
+        """
+
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
         """
 
-    def to_bincode(self) -> bytearray:  # type: ignore
+    def to_json_struqture_2(self) -> str:  # type: ignore
         """
-        Return the bincode representation of the object using the [bincode] crate.
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
 
         Returns:
-            bytearray: The serialized object (in [bincode] form).
+            str: The serialized struqture 2.x object.
 
         Raises:
-            ValueError: Cannot serialize object to bytes.
+            ValueError: Cannot convert self to struqture 2.x.
         """
 
-    def to_json(self) -> str:  # type: ignore
+    def to_canonical_text(self) -> str:  # type: ignore
         """
-        Return the json representation of the object.
+        Return the canonical text representation of self.
 
-        Returns:
-            str: The serialized form of the object.
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
 
-        Raises:
-            ValueError: Cannot serialize object to json.
+        Returns:
+            str: The canonical text representation of self.
         """
 
-    def from_json(self, input: str):  # type: ignore
+    def from_canonical_text(self, input: str):  # type: ignore
         """
-        Convert the json representation of the object to an instance.
+        Convert the canonical text representation of self to an instance.
 
         Args:
-            input (str): The serialized object in json form.
+            input (str): The canonical text representation of the object.
 
         Returns:
-            The deserialized object.
+            The object represented by the canonical text.
 
         Raises:
-            ValueError: Input cannot be deserialized.
+            ValueError: Input cannot be parsed.
         """
 
     def current_version(self) -> str:  # type: ignore
@@ -2211,12 +3282,24 @@ class MixedPlusMinusOperator:
             ValueError: Could not create MixedSystem from MixedOperator.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> MixedPlusMinusOperator:  # type: ignore
@@ -2249,6 +3332,94 @@ class MixedPlusMinusOperator:
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: MixedPlusMinusOperator, policy: str = "sum") -> MixedPlusMinusOperator:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -2292,12 +3463,13 @@ class MixedPlusMinusOperator:
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -2305,12 +3477,70 @@ class MixedPlusMinusOperator:
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> MixedPlusMinusOperator:  # type: ignore
@@ -2406,6 +3636,71 @@ class MixedPlusMinusOperator:
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -2420,6 +3715,62 @@ class MixedPlusMinusOperator:
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -2443,3 +3794,107 @@ class MixedPlusMinusOperator:
         Returns:
             str: The json schema serialized to json
         """
+
+class MixedProductBuilder:
+    """
+    Builder for MixedProducts and HermitianMixedProducts with a fixed number of subsystems.
+
+    Only the subsystems the product acts on need to be set, all other subsystems are the identity.
+    The number of subsystems is validated when the product is built.
+
+    Args:
+        number_spin_subsystems (int): The number of spin subsystems of the product.
+        number_boson_subsystems (int): The number of bosonic subsystems of the product.
+        number_fermion_subsystems (int): The number of fermionic subsystems of the product.
+
+    Returns:
+        MixedProductBuilder: The new builder acting as the identity on all subsystems.
+
+    Examples
+    --------
+
+    .. code-block:: python
+
+        from struqture_py.mixed_systems import MixedProduct, MixedProductBuilder
+        from struqture_py.spins import PauliProduct
+        from struqture_py.bosons import BosonProduct
+
+        mp = MixedProductBuilder(2, 1, 0).spin(1, PauliProduct().x(0)).boson(0, BosonProduct([0], [1])).build()
+        assert mp == MixedProduct([PauliProduct(), PauliProduct().x(0)], [BosonProduct([0], [1])], [])
+
+    """
+
+    def __init__(
+        self,
+        number_spin_subsystems: int,
+        number_boson_subsystems: int,
+        number_fermion_subsystems: int,
+    ):
+        return
+
+    def spin(self, index: int, product: PauliProduct) -> MixedProductBuilder:  # type: ignore
+        """
+        Set the PauliProduct acting on a spin subsystem.
+
+        Args:
+            index (int): The index of the spin subsystem.
+            product (PauliProduct): The PauliProduct acting on the subsystem.
+
+        Returns:
+            MixedProductBuilder: The builder with the subsystem set.
+
+        Raises:
+            TypeError: The product cannot be converted to a PauliProduct.
+        """
+
+    def boson(self, index: int, product: BosonProduct) -> MixedProductBuilder:  # type: ignore
+        """
+        Set the BosonProduct acting on a bosonic subsystem.
+
+        Args:
+            index (int): The index of the bosonic subsystem.
+            product (BosonProduct): The BosonProduct acting on the subsystem.
+
+        Returns:
+            MixedProductBuilder: The builder with the subsystem set.
+
+        Raises:
+            TypeError: The product cannot be converted to a BosonProduct.
+        """
+
+    def fermion(self, index: int, product: FermionProduct) -> MixedProductBuilder:  # type: ignore
+        """
+        Set the FermionProduct acting on a fermionic subsystem.
+
+        Args:
+            index (int): The index of the fermionic subsystem.
+            product (FermionProduct): The FermionProduct acting on the subsystem.
+
+        Returns:
+            MixedProductBuilder: The builder with the subsystem set.
+
+        Raises:
+            TypeError: The product cannot be converted to a FermionProduct.
+        """
+
+    def build(self) -> MixedProduct:  # type: ignore
+        """
+        Build the MixedProduct.
+
+        Returns:
+            MixedProduct: The MixedProduct acting on the subsystems that have been set.
+
+        Raises:
+            ValueError: A subsystem index exceeds the number of subsystems of the builder.
+        """
+
+    def build_hermitian(self) -> HermitianMixedProduct:  # type: ignore
+        """
+        Build the HermitianMixedProduct.
+
+        Returns:
+            HermitianMixedProduct: The HermitianMixedProduct acting on the subsystems that have been set.
+
+        Raises:
+            ValueError: A subsystem index exceeds the number of subsystems of the builder or the product is not in hermitian order.
+        """
@@ -22,7 +22,7 @@ and Lindblad type spin open systems (SpinLindbladNoiseSystem and SpinLindbladOpe
 
 from .struqture_py import ProductType, SystemType, NoiseType
 import numpy
-from typing import Optional, List, Tuple, Dict, Union
+from typing import Optional, Iterator, List, Tuple, Dict, Union
 
 class PauliProduct(ProductType):
     """
@@ -602,12 +602,24 @@ class SpinSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> SpinSystem:  # type: ignore
@@ -640,6 +652,94 @@ class SpinSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: SpinSystem, policy: str = "sum") -> SpinSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -683,12 +783,13 @@ class SpinSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -696,12 +797,70 @@ class SpinSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> SpinSystem:  # type: ignore
@@ -757,6 +916,88 @@ class SpinSystem(SystemType):
             RuntimeError: Could not convert to complex superoperator matrix.
         """
 
+    def sparse_matrix_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a COO matrix with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex operator matrix.
+        """
+
+    def sparse_matrix_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a CSR matrix with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex operator matrix.
+        """
+
+    def sparse_matrix_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a CSR matrix.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def to_scipy_sparse(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy.sparse.csr_matrix.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            scipy.sparse.csr_matrix: The matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
+        """
+
+    def to_qutip(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Constructs the matrix representation of self as a qutip.Qobj.
+
+        The Qobj has one subsystem of dimension two per spin. Since spin 0 corresponds to the least
+        significant bit of the basis states, the first subsystem of the Qobj is the spin with the highest index.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            qutip.Qobj: The operator representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: qutip or scipy is not installed.
+        """
+
     def sparse_matrix_superoperator_coo(self, number_spins) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
         """
         Construct the sparse matrix representation of the superoperator in COO representation.
@@ -776,6 +1017,87 @@ class SpinSystem(SystemType):
             RuntimeError: Could not convert to complex superoperator matrix.
         """
 
+    def sparse_matrix_superoperator_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_scipy(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            scipy.sparse.csr_matrix: The superoperator matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
+        """
+
+    def channel_superoperator_coo(self, time: float, number_spins: Optional[int]) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
+        """
+        Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
+
+        The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
+
+        Args:
+            time (float): The time for which the channel is constructed.
+            number_spins (Optional[int]): The number of spins to construct the channel for.
+
+        Returns:
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
     def unitary_sparse_matrix_coo(self) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
         """
         Return the unitary part of the superoperator in the sparse COO format.
@@ -837,61 +1159,265 @@ class SpinSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
-    def from_json(self, input: str):  # type: ignore
+    def to_json_canonical(self) -> str:  # type: ignore
         """
-        Convert the json representation of self to an instance.
+        Return the canonical json representation of the object.
 
-        Args:
-            input (str): The serialized object in json form.
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
 
         Returns:
-            The deserialized object.
+            str: The serialized form of the object with sorted terms.
 
         Raises:
-            ValueError: Input cannot be deserialized.
+            ValueError: Cannot serialize object to json.
         """
 
-    def current_version(self) -> str:  # type: ignore
+    def to_msgpack(self) -> bytearray:  # type: ignore
         """
-        Returns the current version of the struqture library .
+        Return the MessagePack representation of the object.
 
         Returns:
-            str: The current version of the library.
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
         """
 
-    def min_supported_version(self) -> str:  # type: ignore
+    def from_msgpack(self, input: bytearray):  # type: ignore
         """
-        Return the minimum version of struqture that supports this object.
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
 
         Returns:
-            str: The minimum version of the struqture library to deserialize this object.
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
         """
 
-    def json_schema(self) -> str:  # type: ignore
+    def to_cbor(self) -> bytearray:  # type: ignore
         """
-        Return the JsonSchema for the json serialisation of the class.
+        Return the CBOR representation of the object.
 
         Returns:
-            str: The json schema serialized to json
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
         """
 
-class SpinHamiltonianSystem(SystemType):
-    """
-    These are representations of systems of spins.
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
 
-    SpinHamiltonianSystems are characterized by a SpinOperator to represent the hamiltonian of the spin system
-    and an optional number of spins.
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
 
-    Args:
-        number_spins (Optional[int]): The number of spins in the SpinHamiltonianSystem.
+        Returns:
+           The deserialized object.
 
-    Returns:
-        self: The new SpinHamiltonianSystem with the input number of spins.
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
 
-    Examples
-    --------
+    def from_json(self, input: str):  # type: ignore
+        """
+        Convert the json representation of self to an instance.
 
-    .. code-block:: python
+        Args:
+            input (str): The serialized object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be deserialized.
+        """
+
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
+    def current_version(self) -> str:  # type: ignore
+        """
+        Returns the current version of the struqture library .
+
+        Returns:
+            str: The current version of the library.
+        """
+
+    def min_supported_version(self) -> str:  # type: ignore
+        """
+        Return the minimum version of struqture that supports this object.
+
+        Returns:
+            str: The minimum version of the struqture library to deserialize this object.
+        """
+
+    def json_schema(self) -> str:  # type: ignore
+        """
+        Return the JsonSchema for the json serialisation of the class.
+
+        Returns:
+            str: The json schema serialized to json
+        """
+
+    def to_sympy(self):  # type: ignore
+        """
+        Convert the SpinSystem to a SymPy expression.
+
+        The Pauli operators are represented by non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+        Symbolic coefficients are parsed into commutative SymPy symbols.
+
+        Returns:
+            sympy.Expr: The SymPy expression representing self.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: Coefficients could not be parsed by SymPy.
+        """
+
+    @staticmethod
+    def from_sympy(expression, number_spins: Optional[int]) -> SpinSystem:  # type: ignore
+        """
+        Create a SpinSystem from a SymPy expression.
+
+        Args:
+            expression (sympy.Expr): A polynomial in the non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+            number_spins (Optional[int]): The number of spins of the new SpinSystem.
+
+        Returns:
+            SpinSystem: The SpinSystem represented by the expression.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: The expression contains unrecognized operators or non-integer powers.
+            ValueError: Could not create SpinSystem.
+        """
+
+    def to_flat_arrays(self) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray], numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Return the terms of self as flat numpy arrays.
+
+        The terms are sorted by their PauliProduct. Every term is encoded by a fixed number of uint64 words of the
+        x-bitmask (spins acted on by X or Y) and of the z-bitmask (spins acted on by Z or Y), bit j of word w
+        corresponding to spin 64 * w + j. The arrays are moved into numpy without copying.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray]: The x- and z-bitmasks of the terms and the real and imaginary parts of the coefficients.
+
+        Raises:
+            ValueError: A coefficient is symbolic.
+        """
+
+    def to_flat_arrays_f32(self) -> Tuple[Tuple[Tuple[numpy.ndarray, numpy.ndarray], numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Return the terms of self as flat numpy arrays with single precision coefficients.
+
+        The same as `to_flat_arrays` with the coefficients rounded to float32.
+
+        Returns:
+            Tuple[Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray], Dict[str, float]]: The flat arrays with float32 coefficients and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: A coefficient is symbolic or exceeds the single precision range.
+        """
+
+    @staticmethod
+    def from_flat_arrays(x_masks: numpy.ndarray, z_masks: numpy.ndarray, real: numpy.ndarray, imag: numpy.ndarray, number_spins: Optional[int]) -> SpinSystem:  # type: ignore
+        """
+        Create a SpinSystem from flat numpy arrays, see `to_flat_arrays`.
+
+        Terms with the same PauliProduct are summed.
+
+        Args:
+            x_masks (np.ndarray): The x-bitmasks of all terms (uint64), with the same number of words per term.
+            z_masks (np.ndarray): The z-bitmasks of all terms (uint64), with the same number of words per term.
+            real (np.ndarray): The real parts of the coefficients (float64).
+            imag (np.ndarray): The imaginary parts of the coefficients (float64).
+            number_spins (Optional[int]): The number of spins of the new SpinSystem.
+
+        Returns:
+            SpinSystem: The SpinSystem with the terms of the arrays.
+
+        Raises:
+            ValueError: The arrays are not contiguous or their lengths do not fit together.
+            ValueError: Could not create SpinSystem.
+        """
+
+class SpinHamiltonianSystem(SystemType):
+    """
+    These are representations of systems of spins.
+
+    SpinHamiltonianSystems are characterized by a SpinOperator to represent the hamiltonian of the spin system
+    and an optional number of spins.
+
+    Args:
+        number_spins (Optional[int]): The number of spins in the SpinHamiltonianSystem.
+
+    Returns:
+        self: The new SpinHamiltonianSystem with the input number of spins.
+
+    Examples
+    --------
+
+    .. code-block:: python
 
         import numpy.testing as npt
         import scipy.sparse as sp
@@ -918,12 +1444,24 @@ class SpinHamiltonianSystem(SystemType):
         the Jordan Wigner mapping.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> SpinHamiltonianSystem:  # type: ignore
@@ -956,6 +1494,94 @@ class SpinHamiltonianSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: SpinHamiltonianSystem, policy: str = "sum") -> SpinHamiltonianSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -999,12 +1625,13 @@ class SpinHamiltonianSystem(SystemType):
             ValueError: Product could not be constructed.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
         """
         Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
 
         Args:
             key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
 
         Raises:
             TypeError: Value is not CalculatorComplex or CalculatorFloat.
@@ -1012,12 +1639,70 @@ class SpinHamiltonianSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return the threshold up to which values are dropped from self.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Add many (key object, value) pairs to the existing entries at once.
+
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
+        """
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
+
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
+
+        Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
+            ValueError: Product could not be constructed.
+            ValueError: Error in set function of self.
+        """
+
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> SpinHamiltonianSystem:  # type: ignore
@@ -1073,6 +1758,88 @@ class SpinHamiltonianSystem(SystemType):
             RuntimeError: Could not convert to complex superoperator matrix.
         """
 
+    def sparse_matrix_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a COO matrix with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex operator matrix.
+        """
+
+    def sparse_matrix_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a CSR matrix with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex operator matrix.
+        """
+
+    def sparse_matrix_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a CSR matrix.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def to_scipy_sparse(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Constructs the sparse matrix representation of self as a scipy.sparse.csr_matrix.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            scipy.sparse.csr_matrix: The matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
+        """
+
+    def to_qutip(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Constructs the matrix representation of self as a qutip.Qobj.
+
+        The Qobj has one subsystem of dimension two per spin. Since spin 0 corresponds to the least
+        significant bit of the basis states, the first subsystem of the Qobj is the spin with the highest index.
+
+        Args:
+            number_spins (Optional[int]): The number of spins in self.
+
+        Returns:
+            qutip.Qobj: The operator representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: qutip or scipy is not installed.
+        """
+
     def sparse_matrix_superoperator_coo(self, number_spins) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
         """
         Construct the sparse matrix representation of the superoperator in COO representation.
@@ -1082,10 +1849,91 @@ class SpinHamiltonianSystem(SystemType):
         and `flatten` flattens a matrix into a vector in row-major form.
 
         Args:
-            number_spins: The number of spins to construct the matrix for.
+            number_spins: The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_scipy(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            scipy.sparse.csr_matrix: The superoperator matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
+        """
+
+    def channel_superoperator_coo(self, time: float, number_spins: Optional[int]) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
+        """
+        Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
+
+        The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
+
+        Args:
+            time (float): The time for which the channel is constructed.
+            number_spins (Optional[int]): The number of spins to construct the channel for.
 
         Returns:
-            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
 
         Raises:
             ValueError: CalculatorError.
@@ -1153,6 +2001,71 @@ class SpinHamiltonianSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -1167,6 +2080,62 @@ class SpinHamiltonianSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -1191,6 +2160,91 @@ class SpinHamiltonianSystem(SystemType):
             str: The json schema serialized to json
         """
 
+    def to_sympy(self):  # type: ignore
+        """
+        Convert the SpinHamiltonianSystem to a SymPy expression.
+
+        The Pauli operators are represented by non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+        Symbolic coefficients are parsed into commutative SymPy symbols.
+
+        Returns:
+            sympy.Expr: The SymPy expression representing self.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: Coefficients could not be parsed by SymPy.
+        """
+
+    @staticmethod
+    def from_sympy(expression, number_spins: Optional[int]) -> SpinHamiltonianSystem:  # type: ignore
+        """
+        Create a SpinHamiltonianSystem from a SymPy expression.
+
+        Args:
+            expression (sympy.Expr): A polynomial in the non-commutative symbols `X_i`, `Y_i` and `Z_i`.
+            number_spins (Optional[int]): The number of spins of the new SpinHamiltonianSystem.
+
+        Returns:
+            SpinHamiltonianSystem: The SpinHamiltonianSystem represented by the expression.
+
+        Raises:
+            ImportError: SymPy is not installed.
+            ValueError: The expression contains unrecognized operators or non-integer powers.
+            ValueError: The expression is not hermitian.
+            ValueError: Could not create SpinHamiltonianSystem.
+        """
+
+    def to_flat_arrays(self) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray], numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Return the terms of self as flat numpy arrays.
+
+        The terms are sorted by their PauliProduct. Every term is encoded by a fixed number of uint64 words of the
+        x-bitmask (spins acted on by X or Y) and of the z-bitmask (spins acted on by Z or Y), bit j of word w
+        corresponding to spin 64 * w + j. The arrays are moved into numpy without copying. The imaginary parts are all zero.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray]: The x- and z-bitmasks of the terms and the real and imaginary parts of the coefficients.
+
+        Raises:
+            ValueError: A coefficient is symbolic.
+        """
+
+    def to_flat_arrays_f32(self) -> Tuple[Tuple[Tuple[numpy.ndarray, numpy.ndarray], numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Return the terms of self as flat numpy arrays with single precision coefficients.
+
+        The same as `to_flat_arrays` with the coefficients rounded to float32.
+
+        Returns:
+            Tuple[Tuple[Tuple[np.ndarray, np.ndarray], np.ndarray, np.ndarray], Dict[str, float]]: The flat arrays with float32 coefficients and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: A coefficient is symbolic or exceeds the single precision range.
+        """
+
+    @staticmethod
+    def from_flat_arrays(x_masks: numpy.ndarray, z_masks: numpy.ndarray, real: numpy.ndarray, imag: numpy.ndarray, number_spins: Optional[int]) -> SpinHamiltonianSystem:  # type: ignore
+        """
+        Create a SpinHamiltonianSystem from flat numpy arrays, see `to_flat_arrays`.
+
+        Terms with the same PauliProduct are summed.
+
+        Args:
+            x_masks (np.ndarray): The x-bitmasks of all terms (uint64), with the same number of words per term.
+            z_masks (np.ndarray): The z-bitmasks of all terms (uint64), with the same number of words per term.
+            real (np.ndarray): The real parts of the coefficients (float64).
+            imag (np.ndarray): The imaginary parts of the coefficients (float64).
+            number_spins (Optional[int]): The number of spins of the new SpinHamiltonianSystem.
+
+        Returns:
+            SpinHamiltonianSystem: The SpinHamiltonianSystem with the terms of the arrays.
+
+        Raises:
+            ValueError: The arrays are not contiguous or their lengths do not fit together.
+            ValueError: A coefficient has a non-zero imaginary part.
+            ValueError: Could not create SpinHamiltonianSystem.
+        """
+
 class SpinLindbladNoiseSystem(SystemType):
     """
     These are representations of noisy systems of spins.
@@ -1309,20 +2363,56 @@ class SpinLindbladNoiseSystem(SystemType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def keys(self) -> Iterator[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[(OperatorProduct, OperatorProduct), Union[float, int, str, complex]]]:  # type: ignore
         """
-        Return unsorted keys in self.
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of self.
+            Iterator[Tuple[(OperatorProduct, OperatorProduct), CalculatorComplex]]: The iterator over the entries of self.
         """
 
-    def values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+    def values(self) -> Iterator[Union[float, int, str, complex]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[CalculatorComplex]: The sequence of values of self.
+            Iterator[CalculatorComplex]: The iterator over the values of self.
         """
 
     def empty_clone(self, capacity) -> SpinLindbladNoiseSystem:  # type: ignore
@@ -1355,6 +2445,94 @@ class SpinLindbladNoiseSystem(SystemType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: SpinLindbladNoiseSystem, policy: str = "sum") -> SpinLindbladNoiseSystem:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def current_number_spins(self) -> int:  # type: ignore
         """
         Return maximum spin index in object.
@@ -1380,10 +2558,91 @@ class SpinLindbladNoiseSystem(SystemType):
         and `flatten` flattens a matrix into a vector in row-major form.
 
         Args:
-            number_spins: The number of spins in self.
+            number_spins: The number of spins in self.
+
+        Returns:
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
+
+        Raises:
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+        """
+
+    def sparse_matrix_superoperator_scipy(self, number_spins: Optional[int]):  # type: ignore
+        """
+        Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
+
+        Args:
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
+
+        Returns:
+            scipy.sparse.csr_matrix: The superoperator matrix representation of self.
+
+        Raises:
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
+        """
+
+    def channel_superoperator_coo(self, time: float, number_spins: Optional[int]) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
+        """
+        Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
+
+        The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
+
+        Args:
+            time (float): The time for which the channel is constructed.
+            number_spins (Optional[int]): The number of spins to construct the channel for.
 
         Returns:
-            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
 
         Raises:
             ValueError: CalculatorError.
@@ -1451,6 +2710,71 @@ class SpinLindbladNoiseSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of the object to an instance.
@@ -1465,6 +2789,62 @@ class SpinLindbladNoiseSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -1603,117 +2983,346 @@ class SpinLindbladOpenSystem(SystemType):
             threshold: The threshold for inclusion.
 
         Returns:
-            self: The truncated version of self.
+            self: The truncated version of self.
+        """
+
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def system_set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
+        """
+        Set a new entry in the system of the open system.
+
+        Args:
+            key (Product type): Product key of set object.
+            value (Union[CalculatorComplex, CalculatorFloat]): Value of set object.
+
+        Returns:
+            OpenSystem: The OpenSystem with the new entry.
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+        """
+
+    def noise_set(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]) -> OpenSystem:  # type: ignore
+        """
+        Set a new entry in the noise of the open system.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of set object.
+            value (CalculatorComplex): CalculatorComplex value of set object.
+
+        Returns:
+            OpenSystem: The OpenSystem with the new entry.
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+        """
+
+    def system_get(self, key: ProductType) -> Union[float, int, str, complex] or Union[float, int, str]:  # type: ignore
+        """
+        Get the CalculatorComplex or CalculatorFloat coefficient corresponding to the key.
+
+        Args:
+            key (Product type): Product key of set object.
+
+        Returns:
+            CalculatorComplex or CalculatorFloat: Value at key (or 0.0).
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+        """
+
+    def noise_get(self, key: Tuple[ProductType, ProductType]) -> Union[float, int, str, complex]:  # type: ignore
+        """
+        Get the CalculatorComplex coefficient corresponding to the key.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of set object.
+
+        Returns:
+            CalculatorComplex: Value at key (or 0.0).
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+        """
+
+    def system_remove(self, key: ProductType) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Remove the value of a key from the system of the open system.
+
+        Args:
+            key (Product type): Product key of the removed object.
+
+        Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+        """
+
+    def noise_remove(self, key: Tuple[ProductType, ProductType]) -> Optional[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Remove the value of a key from the noise of the open system.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of the removed object.
+
+        Returns:
+            Optional[CalculatorComplex]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+        """
+
+    def system_keys(self) -> List[OperatorProduct]:  # type: ignore
+        """
+        Return unsorted keys in the system of the open system.
+
+        Returns:
+            List[OperatorProduct]: The sequence of keys of the system.
+        """
+
+    def noise_keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return unsorted keys in the noise of the open system.
+
+        Returns:
+            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of the noise.
+        """
+
+    def system_values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Return unsorted values in the system of the open system.
+
+        Returns:
+            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of the system.
+        """
+
+    def noise_values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+        """
+        Return unsorted values in the noise of the open system.
+
+        Returns:
+            List[CalculatorComplex]: The sequence of values of the noise.
+        """
+
+    def system_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the system of the open system.
+
+        Returns:
+            int: The number of entries in the system.
+        """
+
+    def noise_len(self) -> int:  # type: ignore
+        """
+        Return the number of entries in the noise of the open system.
+
+        Returns:
+            int: The number of entries in the noise.
+        """
+
+    def system_add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
+        """
+        Add a new entry to the system of the open system.
+
+        Args:
+            key (Product type): Product key of set object.
+            value (Union[CalculatorComplex, CalculatorFloat]): Value of set object.
+
+        Returns:
+            OpenSystem: The OpenSystem with the new entry.
+
+        Raises:
+            ValueError: key element cannot be converted to product.
+            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+        """
+
+    def noise_add_operator_product(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]) -> OpenSystem:  # type: ignore
+        """
+        Add a new entry to the system of the open system.
+
+        Args:
+            key (Tuple[Product type, Product type]): Tuple of Products of set object.
+            value (CalculatorComplex): Value of set object.
+
+        Returns:
+            OpenSystem: The OpenSystem with the new entry.
+
+        Raises:
+            ValueError: Left key element cannot be converted to product.
+            ValueError: Right key element cannot be converted to product.
+            TypeError: Value cannot be converted to CalculatorComplex.
         """
 
-    def system_set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
+    def sparse_matrix_superoperator_coo(self, number_spins) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
         """
-        Set a new entry in the system of the open system.
+        Construct the sparse matrix representation of the superoperator in COO representation.
+
+        The superoperator for the operator O is defined as the Matrix S so that
+        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+        and `flatten` flattens a matrix into a vector in row-major form.
 
         Args:
-            key (Product type): Product key of set object.
-            value (Union[CalculatorComplex, CalculatorFloat]): Value of set object.
+            number_spins: The number of spins in self.
 
         Returns:
-            OpenSystem: The OpenSystem with the new entry.
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
 
         Raises:
-            ValueError: key element cannot be converted to product.
-            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
         """
 
-    def noise_set(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]) -> OpenSystem:  # type: ignore
+    def sparse_matrix_superoperator_coo_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]], Dict[str, float]]:  # type: ignore
         """
-        Set a new entry in the noise of the open system.
+        Construct the sparse matrix representation of the superoperator in COO representation with single precision values.
 
         Args:
-            key (Tuple[Product type, Product type]): Tuple of Products of set object.
-            value (CalculatorComplex): CalculatorComplex value of set object.
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
 
         Returns:
-            OpenSystem: The OpenSystem with the new entry.
+            Tuple[Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]], Dict[str, float]]: The matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
 
         Raises:
-            ValueError: Left key element cannot be converted to product.
-            ValueError: Right key element cannot be converted to product.
-            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
         """
 
-    def system_get(self, key: ProductType) -> Union[float, int, str, complex] or Union[float, int, str]:  # type: ignore
+    def sparse_matrix_superoperator_csr_f32(self, number_spins: Optional[int]) -> Tuple[Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray], Dict[str, float]]:  # type: ignore
         """
-        Get the CalculatorComplex or CalculatorFloat coefficient corresponding to the key.
+        Construct the sparse matrix representation of the superoperator in CSR representation with single precision values.
 
         Args:
-            key (Product type): Product key of set object.
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
 
         Returns:
-            CalculatorComplex or CalculatorFloat: Value at key (or 0.0).
+            Tuple[Tuple[np.ndarray, np.ndarray, np.ndarray], Dict[str, float]]: The values, column indices and row pointers of the matrix representation of self with complex64 values and the diagnostics of the rounding (max_absolute_error, max_relative_error, number_underflows).
 
         Raises:
-            ValueError: key element cannot be converted to product.
+            ValueError: CalculatorError or value exceeds the single precision range.
+            RuntimeError: Could not convert to complex superoperator matrix.
         """
 
-    def noise_get(self, key: Tuple[ProductType, ProductType]) -> Union[float, int, str, complex]:  # type: ignore
+    def sparse_matrix_superoperator_csr(self, number_spins: Optional[int]) -> Tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]:  # type: ignore
         """
-        Get the CalculatorComplex coefficient corresponding to the key.
+        Construct the sparse matrix representation of the superoperator in CSR representation.
+
+        The result can be passed directly to `scipy.sparse.csr_matrix`.
 
         Args:
-            key (Tuple[Product type, Product type]): Tuple of Products of set object.
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
 
         Returns:
-            CalculatorComplex: Value at key (or 0.0).
+            Tuple[np.ndarray, np.ndarray, np.ndarray]: The values, column indices and row pointers of the matrix representation of self.
 
         Raises:
-            ValueError: Left key element cannot be converted to product.
-            ValueError: Right key element cannot be converted to product.
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
         """
 
-    def system_add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> OpenSystem:  # type: ignore
+    def sparse_matrix_superoperator_scipy(self, number_spins: Optional[int]):  # type: ignore
         """
-        Add a new entry to the system of the open system.
+        Construct the sparse matrix representation of the superoperator as a scipy.sparse.csr_matrix.
 
         Args:
-            key (Product type): Product key of set object.
-            value (Union[CalculatorComplex, CalculatorFloat]): Value of set object.
+            number_spins (Optional[int]): The number of spins to construct the matrix for.
 
         Returns:
-            OpenSystem: The OpenSystem with the new entry.
+            scipy.sparse.csr_matrix: The superoperator matrix representation of self.
 
         Raises:
-            ValueError: key element cannot be converted to product.
-            TypeError: Value cannot be converted to Union[CalculatorComplex, CalculatorFloat].
+            ValueError: CalculatorError.
+            RuntimeError: Could not convert to complex superoperator matrix.
+            ImportError: scipy is not installed.
         """
 
-    def noise_add_operator_product(self, key: Tuple[ProductType, ProductType], value: Union[float, int, str, complex]) -> OpenSystem:  # type: ignore
+    def to_qutip(self, number_spins: Optional[int], tolerance: float = 1e-10):  # type: ignore
         """
-        Add a new entry to the system of the open system.
+        Convert self into the Hamiltonian and the collapse operators used by `qutip.mesolve`.
+
+        The collapse operators are the jump operators of the diagonal form of the noise, obtained by diagonalizing
+        the matrix of the noise rates. All Qobj have one subsystem of dimension two per spin,
+        the first subsystem is the spin with the highest index.
 
         Args:
-            key (Tuple[Product type, Product type]): Tuple of Products of set object.
-            value (CalculatorComplex): Value of set object.
+            number_spins (Optional[int]): The number of spins, defaults to the number of spins of self.
+            tolerance (float): The tolerance for the positivity of the noise and for dropping vanishing collapse operators.
 
         Returns:
-            OpenSystem: The OpenSystem with the new entry.
+            Tuple[qutip.Qobj, List[qutip.Qobj]]: The Hamiltonian and the collapse operators.
 
         Raises:
-            ValueError: Left key element cannot be converted to product.
-            ValueError: Right key element cannot be converted to product.
-            TypeError: Value cannot be converted to CalculatorComplex.
+            ValueError: CalculatorError or the noise is not completely positive.
+            RuntimeError: Could not convert to complex matrix.
+            ImportError: qutip or scipy is not installed.
         """
 
-    def sparse_matrix_superoperator_coo(self, number_spins) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
+    def channel_superoperator_coo(self, time: float, number_spins: Optional[int]) -> Tuple[numpy.ndarray, Tuple[numpy.ndarray, numpy.ndarray]]:  # type: ignore
         """
-        Construct the sparse matrix representation of the superoperator in COO representation.
+        Construct the superoperator of the quantum channel exp(time * S) generated by the superoperator S of self in COO representation.
 
-        The superoperator for the operator O is defined as the Matrix S so that
-        `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
-        and `flatten` flattens a matrix into a vector in row-major form.
+        The exponential is computed by scaling and squaring on the sparse data and is intended for small systems.
 
         Args:
-            number_spins: The number of spins in self.
+            time (float): The time for which the channel is constructed.
+            number_spins (Optional[int]): The number of spins to construct the channel for.
 
         Returns:
-            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The matrix representation of self.
+            Tuple[np.ndarray, Tuple[np.ndarray, np.ndarray]]: The channel superoperator of self.
 
         Raises:
             ValueError: CalculatorError.
@@ -1781,6 +3390,71 @@ class SpinLindbladOpenSystem(SystemType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of the object to an instance.
@@ -1795,6 +3469,62 @@ class SpinLindbladOpenSystem(SystemType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -2219,12 +3949,24 @@ class PlusMinusOperator:
             ValueError: Could not create SpinHamiltonianSystem from PlusMinusOperator.
         """
 
-    def keys(self) -> List[OperatorProduct]:  # type: ignore
+    def keys(self) -> Iterator[OperatorProduct]:  # type: ignore
         """
-        Return a list of the unsorted keys in self.
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[OperatorProduct]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[OperatorProduct, Union[Union[float, int, str, complex], Union[float, int, str]]]]:  # type: ignore
+        """
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[OperatorProduct]: The sequence of keys of the self.
+            Iterator[Tuple[OperatorProduct, Union[CalculatorComplex, CalculatorFloat]]]: The iterator over the entries of self.
         """
 
     def empty_clone(self, capacity: Optional[int]) -> PlusMinusOperator:  # type: ignore
@@ -2257,6 +3999,94 @@ class PlusMinusOperator:
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: PlusMinusOperator, policy: str = "sum") -> PlusMinusOperator:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def get(self, key) -> Union[float, int, str, complex]:  # type: ignore
         """
         Get the coefficient corresponding to the key.
@@ -2278,47 +4108,106 @@ class PlusMinusOperator:
         Args:
             key (Product type): The key of the value to remove.
 
-         Returns:
-            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+         Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was removed.
+
+        Raises:
+            ValueError: Product could not be constructed.
+        """
+
+    def set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+        """
+        Overwrite an existing entry or set a new entry in self.
+
+        Args:
+            key (Product type): The key to set.
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to set.
+
+        Returns:
+            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was overwritten.
+
+        Raises:
+            ValueError: Product could not be constructed.
+        """
+
+    def add_operator_product(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]):  # type: ignore
+        """
+        Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
+
+        Args:
+            key (Product type): The key object
+            value (Union[CalculatorComplex, CalculatorFloat]): The value to add.
+
+        Raises:
+            TypeError: Value is not CalculatorComplex or CalculatorFloat.
+            ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
+        """
+
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
 
         Raises:
-            ValueError: Product could not be constructed.
+            ValueError: The threshold is negative or NaN.
         """
 
-    def set(self, key: ProductType, value: Union[Union[float, int, str, complex], Union[float, int, str]]) -> Optional[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def add_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
         """
-        Overwrite an existing entry or set a new entry in self.
+        Add many (key object, value) pairs to the existing entries at once.
 
-        Args:
-            key (Product type): The key to set.
-            value (Union[CalculatorComplex, CalculatorFloat]): The value to set.
+        The keys are parsed before any entry is added, the entries are added without holding the GIL.
 
-        Returns:
-            Optional[Union[CalculatorComplex, CalculatorFloat]]: Key existed if this is not None, and this is the value it had before it was overwritten.
+        Args:
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the values of the keys.
 
         Raises:
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
             ValueError: Product could not be constructed.
+            ValueError: Error in add_operator_product function of self.
         """
 
-    def add_operator_product(self, key: ProductType):  # type: ignore
+    def set_operator_products(self, keys: List[Union[str, ProductType]], values: numpy.ndarray):  # type: ignore
         """
-        Add a new (key object, value Union[CalculatorComplex, CalculatorFloat]) pair to existing entries.
+        Overwrite the values of many keys at once.
+
+        The keys are parsed before any entry is set, the entries are set without holding the GIL.
 
         Args:
-            key (Product type): The key object
+            keys (List[Union[str, Product type]]): The key objects.
+            values (np.ndarray): The one-dimensional real or complex array of the new values of the keys.
 
         Raises:
-            TypeError: Value is not CalculatorComplex or CalculatorFloat.
+            TypeError: Values are not a one-dimensional numpy array of real or complex numbers.
+            ValueError: Number of keys does not match number of values.
             ValueError: Product could not be constructed.
-            ValueError: Error in add_operator_product function of self.
+            ValueError: Error in set function of self.
         """
 
-    def values(self) -> List[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
+    def values(self) -> Iterator[Union[Union[float, int, str, complex], Union[float, int, str]]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[Union[CalculatorComplex, CalculatorFloat]]: The sequence of values of self.
+            Iterator[Union[CalculatorComplex, CalculatorFloat]]: The iterator over the values of self.
         """
 
     def hermitian_conjugate(self) -> PlusMinusOperator:  # type: ignore
@@ -2366,6 +4255,71 @@ class PlusMinusOperator:
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of self to an instance.
@@ -2380,6 +4334,62 @@ class PlusMinusOperator:
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
@@ -2543,20 +4553,56 @@ class PlusMinusLindbladNoiseOperator(NoiseType):
             ValueError: Error in add_operator_product function of self.
         """
 
-    def keys(self) -> List[(OperatorProduct, OperatorProduct)]:  # type: ignore
+    def zero_threshold(self) -> Optional[float]:  # type: ignore
+        """
+        Return the threshold up to which values are dropped from self.
+
+        Returns:
+            Optional[float]: The threshold, None if only exact zeros are dropped.
+        """
+
+    def set_zero_threshold(self, threshold: float):  # type: ignore
+        """
+        Set the threshold up to which values are dropped from self.
+
+        Values with an absolute value smaller than or equal to the threshold are dropped by set, add_operator_product and the arithmetic of self.
+        Entries of self that are below the threshold are removed. By default only exact zeros are dropped.
+
+        Args:
+            threshold (float): The threshold.
+
+        Raises:
+            ValueError: The threshold is negative or NaN.
+        """
+
+    def keys(self) -> Iterator[(OperatorProduct, OperatorProduct)]:  # type: ignore
+        """
+        Return an iterator over the unsorted keys in self.
+
+        The keys are copied when the iterator is created and converted to python objects one per iteration step.
+
+        Returns:
+            Iterator[(OperatorProduct, OperatorProduct)]: The iterator over the keys of self.
+        """
+
+    def items(self) -> Iterator[Tuple[(OperatorProduct, OperatorProduct), Union[float, int, str, complex]]]:  # type: ignore
         """
-        Return unsorted keys in self.
+        Return an iterator over the unsorted (key, value) pairs in self.
+
+        The keys are copied when the iterator is created, the entries are converted to python objects one per iteration step.
 
         Returns:
-            List[(OperatorProduct, OperatorProduct)]: The sequence of keys of self.
+            Iterator[Tuple[(OperatorProduct, OperatorProduct), CalculatorComplex]]: The iterator over the entries of self.
         """
 
-    def values(self) -> List[Union[float, int, str, complex]]:  # type: ignore
+    def values(self) -> Iterator[Union[float, int, str, complex]]:  # type: ignore
         """
-        Return unsorted values in self.
+        Return an iterator over the unsorted values in self.
+
+        The keys are copied when the iterator is created, the values are converted to python objects one per iteration step.
 
         Returns:
-            List[CalculatorComplex]: The sequence of values of self.
+            Iterator[CalculatorComplex]: The iterator over the values of self.
         """
 
     def empty_clone(self, capacity) -> PlusMinusLindbladNoiseOperator:  # type: ignore
@@ -2589,6 +4635,94 @@ class PlusMinusLindbladNoiseOperator(NoiseType):
             self: The truncated version of self.
         """
 
+    def truncate_with_remainder(self, threshold: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by returning the kept and the discarded terms for a threshold.
+
+        The discarded part contains the removed terms and, for complex coefficients, the removed
+        real or imaginary parts of kept terms, so that the sum of both parts is self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            threshold (float): The threshold for inclusion.
+
+        Returns:
+            Tuple[self, self]: The kept and the discarded terms.
+        """
+
+    def truncate_to_weight(self, fraction: float) -> Tuple[Self, Self]:  # type: ignore
+        """
+        Truncate self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+
+        The terms are sorted by the absolute value of their coefficients and kept until the 1-norm
+        of the kept coefficients reaches the given fraction of the 1-norm of self.
+        For open systems, the system and the noise are truncated separately.
+
+        Args:
+            fraction (float): The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+
+        Returns:
+            Tuple[self, self]: The kept and the dropped terms.
+
+        Raises:
+            ValueError: The fraction is not between 0.0 and 1.0 or self contains symbolic coefficients.
+        """
+
+    def approx_eq(self, other: Self, atol: float = 1e-8, rtol: float = 1e-5) -> bool:  # type: ignore
+        """
+        Return whether self and other are equal within the given tolerances.
+
+        Keys missing in one of the objects are treated as having a zero coefficient.
+        Numerical coefficients are equal when `|a - b| <= max(rtol * max(|a|, |b|), atol)`,
+        symbolic coefficients need to match exactly.
+
+        Args:
+            other (self): The object to compare to.
+            atol (float): The absolute tolerance.
+            rtol (float): The relative tolerance.
+
+        Returns:
+            bool: Whether the objects are approximately equal.
+        """
+
+    def add_with_policy(self, other: PlusMinusLindbladNoiseOperator, policy: str = "sum") -> PlusMinusLindbladNoiseOperator:  # type: ignore
+        """
+        Add another object to a copy of self, merging the coefficients of shared keys according to a policy.
+
+        Args:
+            other (self): The object to add to self.
+            policy (str): How coefficients of keys present in both objects are merged, one of "sum", "prefer_left" or "error_on_symbol_conflict".
+
+        Returns:
+            self: The merged object.
+
+        Raises:
+            ValueError: Unknown merge policy.
+            ValueError: Conflicting symbolic coefficients or objects could not be added.
+        """
+
+    def coefficient_1_norm(self) -> float:  # type: ignore
+        """
+        Return the 1-norm of the coefficients of self, the sum of their absolute values.
+
+        Returns:
+            float: The sum of the absolute values of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
+    def coefficient_max_norm(self) -> float:  # type: ignore
+        """
+        Return the max-norm of the coefficients of self, the largest absolute value.
+
+        Returns:
+            float: The largest absolute value of the coefficients.
+
+        Raises:
+            ValueError: Self contains symbolic coefficients.
+        """
+
     def from_bincode(self, input: bytearray):  # type: ignore
         """
         Convert the bincode representation of the object to an instance using the [bincode] crate.
@@ -2626,6 +4760,71 @@ class PlusMinusLindbladNoiseOperator(NoiseType):
             ValueError: Cannot serialize object to json.
         """
 
+    def to_json_canonical(self) -> str:  # type: ignore
+        """
+        Return the canonical json representation of the object.
+
+        The terms are sorted by their keys, so that equal objects always have identical json representations.
+
+        Returns:
+            str: The serialized form of the object with sorted terms.
+
+        Raises:
+            ValueError: Cannot serialize object to json.
+        """
+
+    def to_msgpack(self) -> bytearray:  # type: ignore
+        """
+        Return the MessagePack representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in MessagePack form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_msgpack(self, input: bytearray):  # type: ignore
+        """
+        Convert the MessagePack representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in MessagePack form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
+    def to_cbor(self) -> bytearray:  # type: ignore
+        """
+        Return the CBOR representation of the object.
+
+        Returns:
+            bytearray: The serialized object (in CBOR form).
+
+        Raises:
+            ValueError: Cannot serialize object to bytes.
+        """
+
+    def from_cbor(self, input: bytearray):  # type: ignore
+        """
+        Convert the CBOR representation of the object to an instance.
+
+        Args:
+            input (bytearray): The serialized object (in CBOR form).
+
+        Returns:
+           The deserialized object.
+
+        Raises:
+            TypeError: Input cannot be converted to byte array.
+            ValueError: Input cannot be deserialized.
+        """
+
     def from_json(self, input: str):  # type: ignore
         """
         Convert the json representation of the object to an instance.
@@ -2640,6 +4839,62 @@ class PlusMinusLindbladNoiseOperator(NoiseType):
             ValueError: Input cannot be deserialized.
         """
 
+    def from_json_struqture_2(self, input: str):  # type: ignore
+        """
+        Convert the json representation of a struqture 2.x object to an instance.
+
+        The struqture 2.x object is migrated to the struqture 1.x type, e.g. a PauliHamiltonian
+        to a SpinHamiltonianSystem without a fixed number of spins.
+
+        Args:
+            input (str): The serialized struqture 2.x object in json form.
+
+        Returns:
+            The deserialized object.
+
+        Raises:
+            ValueError: Input cannot be migrated or deserialized.
+        """
+
+    def to_json_struqture_2(self) -> str:  # type: ignore
+        """
+        Return the json representation of the corresponding struqture 2.x object.
+
+        The struqture 2.x types do not contain the number of spins or modes, e.g. a SpinHamiltonianSystem
+        is exported as a PauliHamiltonian.
+
+        Returns:
+            str: The serialized struqture 2.x object.
+
+        Raises:
+            ValueError: Cannot convert self to struqture 2.x.
+        """
+
+    def to_canonical_text(self) -> str:  # type: ignore
+        """
+        Return the canonical text representation of self.
+
+        The canonical text contains one line per term, sorted independently of the insertion order,
+        and is intended for committing model definitions to version control.
+
+        Returns:
+            str: The canonical text representation of self.
+        """
+
+    def from_canonical_text(self, input: str):  # type: ignore
+        """
+        Convert the canonical text representation of self to an instance.
+
+        Args:
+            input (str): The canonical text representation of the object.
+
+        Returns:
+            The object represented by the canonical text.
+
+        Raises:
+            ValueError: Input cannot be parsed.
+        """
+
     def current_version(self) -> str:  # type: ignore
         """
         Returns the current version of the struqture library .
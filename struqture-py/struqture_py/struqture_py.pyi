@@ -1,3 +1,5 @@
+from typing import Tuple, Union
+
 class ProductType:
     """Product type operators"""
 
@@ -6,3 +8,41 @@ class SystemType:
 
 class NoiseType:
     """Noise type"""
+
+def convert_serialization(type_name: str, input: Union[str, bytearray], from_format: str, to_format: str) -> Union[str, bytearray]:  # type: ignore
+    """
+    Convert a serialized struqture object between serialization formats.
+
+    The input is deserialized into the given struqture type, so the conversion also validates the data.
+
+    Args:
+        type_name (str): The name of the struqture type, e.g. "SpinSystem".
+        input (Union[str, bytearray]): The serialized object, a str for json formats and a bytearray for binary formats.
+        from_format (str): The format of the input, one of "json", "json_pretty", "bincode", "msgpack" or "cbor".
+        to_format (str): The format of the output, one of "json", "json_pretty", "bincode", "msgpack" or "cbor".
+
+    Returns:
+        Union[str, bytearray]: The object serialized in the output format, a str for json formats and a bytearray for binary formats.
+
+    Raises:
+        TypeError: Input cannot be converted to str or byte array.
+        ValueError: Unknown format or type name, or the input cannot be converted.
+    """
+
+def migrate_json(input: str, target_version: Tuple[int, int]) -> str:  # type: ignore
+    """
+    Migrate the json representation of a struqture object to the given struqture version.
+
+    struqture 1.x objects keep their type, struqture 2.x objects are converted to the corresponding
+    struqture 1.x type, e.g. a PauliHamiltonian to a SpinHamiltonianSystem.
+
+    Args:
+        input (str): The serialized object in json form.
+        target_version (Tuple[int, int]): The major and minor struqture version the output is read with.
+
+    Returns:
+        str: The migrated object in json form.
+
+    Raises:
+        ValueError: The input cannot be migrated to the target version.
+    """
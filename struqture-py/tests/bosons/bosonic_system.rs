@@ -12,6 +12,7 @@
 
 use num_complex::Complex64;
 use pyo3::prelude::*;
+use pyo3::types::PyList;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::bosons::{BosonProduct, BosonSystem};
@@ -278,7 +279,16 @@ fn test_keys_values() {
             .call_method1("add_operator_product", ("c0c1a0a1", 0.1))
             .unwrap();
 
-        let keys_system = system.call_method0("keys").unwrap();
+        let keys_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("keys")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison = bool::extract_bound(
             &keys_system
                 .call_method1("__eq__", (vec!["c0c1a0a1"],))
@@ -287,7 +297,16 @@ fn test_keys_values() {
         .unwrap();
         assert!(comparison);
 
-        let values_system = system.call_method0("values").unwrap();
+        let values_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("values")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison =
             bool::extract_bound(&values_system.call_method1("__eq__", (vec![0.1],)).unwrap())
                 .unwrap();
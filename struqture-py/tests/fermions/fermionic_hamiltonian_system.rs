@@ -12,6 +12,7 @@
 
 use num_complex::Complex64;
 use pyo3::prelude::*;
+use pyo3::types::PyList;
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
@@ -298,7 +299,16 @@ fn test_keys_values() {
             .call_method1("add_operator_product", ("c0c1a0a1", 0.1))
             .unwrap();
 
-        let keys_system = system.call_method0("keys").unwrap();
+        let keys_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("keys")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison = bool::extract_bound(
             &keys_system
                 .call_method1("__eq__", (vec!["c0c1a0a1"],))
@@ -307,7 +317,16 @@ fn test_keys_values() {
         .unwrap();
         assert!(comparison);
 
-        let values_system = system.call_method0("values").unwrap();
+        let values_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("values")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison =
             bool::extract_bound(&values_system.call_method1("__eq__", (vec![0.1],)).unwrap())
                 .unwrap();
@@ -514,6 +533,56 @@ fn test_neg() {
     });
 }
 
+/// Test mean_field_decouple function of FermionHamiltonianSystem
+#[test]
+fn test_mean_field_decouple() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_system(py, Some(2));
+        system
+            .call_method1("add_operator_product", ("c0c1a0a1", -1.0))
+            .unwrap();
+        let averages = pyo3::types::PyDict::new_bound(py);
+        averages.set_item("c0a0", 1.0).unwrap();
+        averages.set_item("c1a1", Complex64::new(0.5, 0.0)).unwrap();
+
+        let mean_field = system
+            .call_method1("mean_field_decouple", (averages,))
+            .unwrap()
+            .extract::<FermionHamiltonianSystemWrapper>()
+            .unwrap();
+        let mut expected = FermionHamiltonianSystem::new(Some(2));
+        expected
+            .add_operator_product(
+                HermitianFermionProduct::new([0], [0]).unwrap(),
+                CalculatorComplex::from(0.5),
+            )
+            .unwrap();
+        expected
+            .add_operator_product(
+                HermitianFermionProduct::new([1], [1]).unwrap(),
+                CalculatorComplex::from(1.0),
+            )
+            .unwrap();
+        expected
+            .add_operator_product(
+                HermitianFermionProduct::new([], []).unwrap(),
+                CalculatorComplex::from(-0.5),
+            )
+            .unwrap();
+        assert!(mean_field.internal.approx_eq(&expected, 1e-12, 0.0));
+
+        let invalid_averages = pyo3::types::PyDict::new_bound(py);
+        invalid_averages.set_item("c0c1a0a1", 1.0).unwrap();
+        let error = system.call_method1("mean_field_decouple", (invalid_averages,));
+        assert!(error.is_err());
+        let invalid_averages = pyo3::types::PyDict::new_bound(py);
+        invalid_averages.set_item("c0a0", "a").unwrap();
+        let error = system.call_method1("mean_field_decouple", (invalid_averages,));
+        assert!(error.is_err());
+    })
+}
+
 /// Test add magic method function of FermionHamiltonianSystem
 #[test]
 fn test_add() {
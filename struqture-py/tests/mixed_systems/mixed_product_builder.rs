@@ -0,0 +1,133 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pyo3::prelude::*;
+use struqture::prelude::*;
+use struqture::{
+    bosons::BosonProduct, fermions::FermionProduct, mixed_systems::HermitianMixedProduct,
+    mixed_systems::MixedProduct, spins::PauliProduct,
+};
+use struqture_py::mixed_systems::{
+    HermitianMixedProductWrapper, MixedProductBuilderWrapper, MixedProductWrapper,
+    MixedSystemWrapper,
+};
+use struqture_py::spins::PauliProductWrapper;
+
+// helper functions
+fn new_builder(
+    py: Python,
+    number_spins: usize,
+    number_bosons: usize,
+    number_fermions: usize,
+) -> Bound<MixedProductBuilderWrapper> {
+    let builder_type = py.get_type_bound::<MixedProductBuilderWrapper>();
+    builder_type
+        .call1((number_spins, number_bosons, number_fermions))
+        .unwrap()
+        .downcast::<MixedProductBuilderWrapper>()
+        .unwrap()
+        .to_owned()
+}
+
+/// Test chained setters and build of MixedProductBuilder
+#[test]
+fn test_build() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let builder = new_builder(py, 2, 1, 1);
+        let pp = PauliProductWrapper {
+            internal: PauliProduct::new().x(0),
+        };
+        let builder = builder
+            .call_method1("spin", (1, pp))
+            .unwrap()
+            .call_method1("boson", (0, "c0a1"))
+            .unwrap()
+            .call_method1("fermion", (0, "c0a0"))
+            .unwrap();
+
+        let mp = builder
+            .call_method0("build")
+            .unwrap()
+            .extract::<MixedProductWrapper>()
+            .unwrap();
+        assert_eq!(
+            mp.internal,
+            MixedProduct::new(
+                [PauliProduct::new(), PauliProduct::new().x(0)],
+                [BosonProduct::new([0], [1]).unwrap()],
+                [FermionProduct::new([0], [0]).unwrap()],
+            )
+            .unwrap()
+        );
+
+        let hmp = builder
+            .call_method0("build_hermitian")
+            .unwrap()
+            .extract::<HermitianMixedProductWrapper>()
+            .unwrap();
+        assert_eq!(
+            hmp.internal,
+            HermitianMixedProduct::new(
+                [PauliProduct::new(), PauliProduct::new().x(0)],
+                [BosonProduct::new([0], [1]).unwrap()],
+                [FermionProduct::new([0], [0]).unwrap()],
+            )
+            .unwrap()
+        );
+    });
+}
+
+/// Test the errors of MixedProductBuilder
+#[test]
+fn test_build_error() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let builder = new_builder(py, 1, 0, 0);
+        let error = builder.call_method1("spin", (0, vec![0]));
+        assert!(error.is_err());
+
+        let builder = builder.call_method1("spin", (1, "0Z")).unwrap();
+        let error = builder.call_method0("build");
+        assert!(error.is_err());
+        let error = builder.call_method0("build_hermitian");
+        assert!(error.is_err());
+
+        let builder = new_builder(py, 0, 1, 0)
+            .call_method1("boson", (0, "c1a0"))
+            .unwrap();
+        let error = builder.call_method0("build_hermitian");
+        assert!(error.is_err());
+    });
+}
+
+/// Test product_builder of MixedSystem
+#[test]
+fn test_system_product_builder() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let system_type = py.get_type_bound::<MixedSystemWrapper>();
+        let system = system_type
+            .call1((
+                vec![Some(2), None],
+                vec![Some(1)],
+                Vec::<Option<usize>>::new(),
+            ))
+            .unwrap();
+        let builder = system
+            .call_method0("product_builder")
+            .unwrap()
+            .extract::<MixedProductBuilderWrapper>()
+            .unwrap();
+        assert_eq!(builder, MixedProductBuilderWrapper::new(2, 1, 0));
+    });
+}
@@ -12,6 +12,7 @@
 
 use num_complex::Complex64;
 use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use qoqo_calculator_pyo3::{CalculatorComplexWrapper, CalculatorFloatWrapper};
 #[cfg(feature = "json_schema")]
@@ -114,6 +115,78 @@ fn test_number_spins_current() {
     });
 }
 
+/// Test the mapping protocol of SpinLindbladNoiseSystem
+#[test]
+fn test_mapping_protocol() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_noisesystem(py);
+        system.set_item(("0X", "0X"), 0.5).unwrap();
+        system.set_item(("1Z", "1Z"), 0.2).unwrap();
+
+        assert!(system.contains(("0X", "0X")).unwrap());
+        assert!(!system.contains(("0X", "1Z")).unwrap());
+        assert!(!system.contains("0X").unwrap());
+        let comp_op = system.get_item(("0X", "0X")).unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (0.5,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(system.get_item(("0X", "1Z")).is_err());
+
+        let keys = py
+            .eval_bound(
+                "lambda system: sorted((str(left), str(right)) for (left, right) in system)",
+                None,
+                None,
+            )
+            .unwrap()
+            .call1((system.clone(),))
+            .unwrap();
+        assert_eq!(
+            Vec::<(String, String)>::extract_bound(&keys).unwrap(),
+            vec![
+                ("0X".to_string(), "0X".to_string()),
+                ("1Z".to_string(), "1Z".to_string())
+            ]
+        );
+        let items: Vec<Bound<PyAny>> = system
+            .call_method0("items")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|item| item.unwrap())
+            .collect();
+        assert_eq!(items.len(), 2);
+
+        system.del_item(("1Z", "1Z")).unwrap();
+        assert!(system.del_item(("1Z", "1Z")).is_err());
+        assert_eq!(system.len().unwrap(), 1);
+    });
+}
+
+/// Test the pickle support functions of SpinLindbladNoiseSystem
+#[test]
+fn test_pickle_state() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_noisesystem(py);
+        system
+            .call_method1("add_operator_product", (("0X", "0X"), 0.1))
+            .unwrap();
+
+        let (constructor, arguments) = system
+            .call_method0("__reduce__")
+            .unwrap()
+            .extract::<(Bound<PyAny>, Bound<PyTuple>)>()
+            .unwrap();
+        let reconstructed = constructor.call1(arguments).unwrap();
+        let comparison =
+            bool::extract_bound(&reconstructed.call_method1("__eq__", (&system,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+    });
+}
+
 /// Test empty_clone function of SpinSystem
 #[test]
 fn test_empty_clone() {
@@ -223,7 +296,16 @@ fn test_keys_values() {
             .call_method1("add_operator_product", (("0X", "0X"), 0.1))
             .unwrap();
 
-        let keys_system = system.call_method0("keys").unwrap();
+        let keys_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("keys")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison = bool::extract_bound(
             &keys_system
                 .call_method1("__eq__", (vec![("0X", "0X")],))
@@ -232,7 +314,16 @@ fn test_keys_values() {
         .unwrap();
         assert!(comparison);
 
-        let values_system = system.call_method0("values").unwrap();
+        let values_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("values")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison =
             bool::extract_bound(&values_system.call_method1("__eq__", (vec![0.1],)).unwrap())
                 .unwrap();
@@ -222,6 +222,75 @@ fn spin_system_test_add_operator_product_remove_noise() {
     });
 }
 
+/// Test the pass-through accessors of the system and noise of SpinLindbladOpenSystem
+#[test]
+fn test_system_noise_pass_through() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_system(py);
+        system
+            .call_method1("system_add_operator_product", ("0X", 0.1))
+            .unwrap();
+        system
+            .call_method1("system_add_operator_product", ("1Z", 0.2))
+            .unwrap();
+        system
+            .call_method1("noise_add_operator_product", (("0X", "1Z"), 0.3))
+            .unwrap();
+
+        let system_len = usize::extract_bound(&system.call_method0("system_len").unwrap()).unwrap();
+        assert_eq!(system_len, 2);
+        let noise_len = usize::extract_bound(&system.call_method0("noise_len").unwrap()).unwrap();
+        assert_eq!(noise_len, 1);
+
+        let system_keys = system.call_method0("system_keys").unwrap();
+        assert_eq!(system_keys.len().unwrap(), 2);
+        let noise_keys = system.call_method0("noise_keys").unwrap();
+        assert_eq!(noise_keys.len().unwrap(), 1);
+        let system_values = system.call_method0("system_values").unwrap();
+        let comparison = bool::extract_bound(
+            &system_values
+                .get_item(1)
+                .unwrap()
+                .call_method1("__eq__", (0.2,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+        let noise_values = system.call_method0("noise_values").unwrap();
+        let comparison = bool::extract_bound(
+            &noise_values
+                .get_item(0)
+                .unwrap()
+                .call_method1("__eq__", (0.3,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Removing existing and missing keys
+        let removed = system.call_method1("system_remove", ("0X",)).unwrap();
+        let comparison =
+            bool::extract_bound(&removed.call_method1("__eq__", (0.1,)).unwrap()).unwrap();
+        assert!(comparison);
+        let removed = system.call_method1("system_remove", ("0X",)).unwrap();
+        assert!(removed.is_none());
+        let removed = system
+            .call_method1("noise_remove", (("0X", "1Z"),))
+            .unwrap();
+        let comparison =
+            bool::extract_bound(&removed.call_method1("__eq__", (0.3,)).unwrap()).unwrap();
+        assert!(comparison);
+        let noise_len = usize::extract_bound(&system.call_method0("noise_len").unwrap()).unwrap();
+        assert_eq!(noise_len, 0);
+
+        let error = system.call_method1("system_remove", ("2J",));
+        assert!(error.is_err());
+        let error = system.call_method1("noise_remove", (("2J", "0X"),));
+        assert!(error.is_err());
+    });
+}
+
 /// Test add magic method function of SpinSystem
 #[test]
 fn test_neg() {
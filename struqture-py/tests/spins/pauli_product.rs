@@ -12,6 +12,7 @@
 
 use num_complex::Complex64;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use std::{cmp::Ordering, collections::HashMap};
 #[cfg(feature = "json_schema")]
 use struqture::{spins::PauliProduct, STRUQTURE_VERSION};
@@ -398,6 +399,34 @@ fn test_value_error_bincode() {
     });
 }
 
+/// Test the pickle support functions of PauliProduct
+#[test]
+fn test_pickle_state() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let new_pp_1 = new_pp(py);
+        let pp = new_pp_1.call_method1("set_pauli", (0, "X")).unwrap();
+
+        let state = pp.call_method0("__getstate__").unwrap();
+        let restored = new_pp(py);
+        restored.call_method1("__setstate__", (&state,)).unwrap();
+        let comparison =
+            bool::extract_bound(&restored.call_method1("__eq__", (&pp,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(restored.call_method1("__setstate__", ("J",)).is_err());
+
+        let (constructor, arguments) = pp
+            .call_method0("__reduce__")
+            .unwrap()
+            .extract::<(Bound<PyAny>, Bound<PyTuple>)>()
+            .unwrap();
+        let reconstructed = constructor.call1(arguments).unwrap();
+        let comparison =
+            bool::extract_bound(&reconstructed.call_method1("__eq__", (&pp,)).unwrap()).unwrap();
+        assert!(comparison);
+    });
+}
+
 /// Test to_ and from_json functions of PauliProduct
 #[test]
 fn test_to_from_json() {
@@ -12,6 +12,7 @@
 
 use num_complex::Complex64;
 use pyo3::prelude::*;
+use pyo3::types::{PyList, PyTuple};
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator_pyo3::CalculatorComplexWrapper;
 use struqture::spins::{PauliProduct, SpinSystem};
@@ -182,6 +183,52 @@ fn spin_system_test_set_get() {
     });
 }
 
+/// Test the zero_threshold and set_zero_threshold functions of SpinSystem
+#[test]
+fn spin_system_test_zero_threshold() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_system(py, Some(4));
+        assert!(system.call_method0("zero_threshold").unwrap().is_none());
+        system.call_method1("set", ("0X", 1e-13)).unwrap();
+        assert_eq!(
+            usize::extract_bound(&system.call_method0("__len__").unwrap()).unwrap(),
+            1
+        );
+
+        system.call_method1("set_zero_threshold", (1e-12,)).unwrap();
+        assert_eq!(
+            f64::extract_bound(&system.call_method0("zero_threshold").unwrap()).unwrap(),
+            1e-12
+        );
+        assert_eq!(
+            usize::extract_bound(&system.call_method0("__len__").unwrap()).unwrap(),
+            0
+        );
+
+        system
+            .call_method1("add_operator_product", ("1Z", 0.3))
+            .unwrap();
+        system
+            .call_method1("add_operator_product", ("1Z", -0.1 - 0.2))
+            .unwrap();
+        assert_eq!(
+            usize::extract_bound(&system.call_method0("__len__").unwrap()).unwrap(),
+            0
+        );
+        system.call_method1("set", ("0X", 1e-13)).unwrap();
+        assert_eq!(
+            usize::extract_bound(&system.call_method0("__len__").unwrap()).unwrap(),
+            0
+        );
+
+        let error = system.call_method1("set_zero_threshold", (-1.0,));
+        assert!(error.is_err());
+        let error = system.call_method1("set_zero_threshold", (f64::NAN,));
+        assert!(error.is_err());
+    });
+}
+
 /// Test add_operator_product and remove functions of SpinSystem
 #[test]
 fn spin_system_test_add_operator_product_remove() {
@@ -269,13 +316,31 @@ fn test_keys_values() {
             .call_method1("add_operator_product", ("0X", 0.1))
             .unwrap();
 
-        let keys_system = system.call_method0("keys").unwrap();
+        let keys_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("keys")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison =
             bool::extract_bound(&keys_system.call_method1("__eq__", (vec!["0X"],)).unwrap())
                 .unwrap();
         assert!(comparison);
 
-        let values_system = system.call_method0("values").unwrap();
+        let values_system = PyList::new_bound(
+            py,
+            system
+                .call_method0("values")
+                .unwrap()
+                .iter()
+                .unwrap()
+                .map(|entry| entry.unwrap())
+                .collect::<Vec<_>>(),
+        );
         let comparison =
             bool::extract_bound(&values_system.call_method1("__eq__", (vec![0.1],)).unwrap())
                 .unwrap();
@@ -290,6 +355,218 @@ fn test_keys_values() {
     });
 }
 
+/// Test map_values function of SpinSystem
+#[test]
+fn test_map_values() {
+    pyo3::Python::with_gil(|py| {
+        let number_spins: Option<usize> = Some(3);
+        let system = new_system(py, number_spins);
+        system
+            .call_method1("add_operator_product", ("0X", 0.5))
+            .unwrap();
+        system
+            .call_method1("add_operator_product", ("1Z", 0.2))
+            .unwrap();
+
+        let function = py
+            .eval_bound(
+                "lambda key, value: value * 0 if key.get(1) is not None else value * 2",
+                None,
+                None,
+            )
+            .unwrap();
+        let mapped = system.call_method1("map_values", (function,)).unwrap();
+        assert_eq!(
+            usize::extract_bound(&mapped.call_method0("__len__").unwrap()).unwrap(),
+            1
+        );
+        let comp_op = mapped.call_method1("get", ("0X",)).unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (1.0,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let failing = py.eval_bound("lambda key, value: key.unknown_method()", None, None);
+        let error = system.call_method1("map_values", (failing.unwrap(),));
+        assert!(error.is_err());
+    });
+}
+
+/// Test add_operator_products and set_operator_products functions of SpinSystem
+#[test]
+fn test_batch_operator_products() {
+    pyo3::Python::with_gil(|py| {
+        let number_spins: Option<usize> = Some(3);
+        let system = new_system(py, number_spins);
+        let values = numpy::PyArray1::from_vec_bound(
+            py,
+            vec![Complex64::new(0.5, 0.0), Complex64::new(0.0, 1.0)],
+        );
+        system
+            .call_method1("add_operator_products", (vec!["0X", "1Z"], values.clone()))
+            .unwrap();
+        system
+            .call_method1("add_operator_products", (vec!["0X", "1Z"], values))
+            .unwrap();
+        let comp_op = system.call_method1("get", ("0X",)).unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (1.0,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let real_values = numpy::PyArray1::from_vec_bound(py, vec![0.25_f64]);
+        system
+            .call_method1("set_operator_products", (vec!["0X"], real_values))
+            .unwrap();
+        let comp_op = system.call_method1("get", ("0X",)).unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (0.25,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let too_few_values = numpy::PyArray1::from_vec_bound(py, vec![0.25_f64]);
+        let error =
+            system.call_method1("add_operator_products", (vec!["0X", "1Z"], too_few_values));
+        assert!(error.is_err());
+        let too_many_spins = numpy::PyArray1::from_vec_bound(py, vec![0.25_f64]);
+        let error = system.call_method1("add_operator_products", (vec!["5X"], too_many_spins));
+        assert!(error.is_err());
+        let error = system.call_method1("add_operator_products", (vec!["0X"], vec![0.25]));
+        assert!(error.is_err());
+    });
+}
+
+/// Test the mapping protocol of SpinSystem
+#[test]
+fn test_mapping_protocol() {
+    pyo3::Python::with_gil(|py| {
+        let number_spins: Option<usize> = Some(3);
+        let system = new_system(py, number_spins);
+        system.set_item("0X", 0.5).unwrap();
+        system.set_item("1Z", 0.2).unwrap();
+
+        assert!(system.contains("0X").unwrap());
+        assert!(!system.contains("2Y").unwrap());
+        assert!(!system.contains(1.0).unwrap());
+        let comp_op = system.get_item("0X").unwrap();
+        let comparison =
+            bool::extract_bound(&comp_op.call_method1("__eq__", (0.5,)).unwrap()).unwrap();
+        assert!(comparison);
+        assert!(system.get_item("2Y").is_err());
+
+        let keys = py
+            .eval_bound(
+                "lambda system: sorted(str(key) for key in system)",
+                None,
+                None,
+            )
+            .unwrap()
+            .call1((system.clone(),))
+            .unwrap();
+        assert_eq!(
+            Vec::<String>::extract_bound(&keys).unwrap(),
+            vec!["0X".to_string(), "1Z".to_string()]
+        );
+        let items: Vec<Bound<PyAny>> = system
+            .call_method0("items")
+            .unwrap()
+            .iter()
+            .unwrap()
+            .map(|item| item.unwrap())
+            .collect();
+        assert_eq!(items.len(), 2);
+
+        system.del_item("1Z").unwrap();
+        assert!(system.del_item("1Z").is_err());
+        assert_eq!(system.len().unwrap(), 1);
+    });
+}
+
+/// Test the lazy iteration over the keys, values and items of SpinSystem
+#[test]
+fn test_lazy_iteration() {
+    pyo3::Python::with_gil(|py| {
+        let number_spins: Option<usize> = Some(3);
+        let system = new_system(py, number_spins);
+        system.set_item("0X", 0.5).unwrap();
+        system.set_item("1Z", 0.2).unwrap();
+
+        let items = system.call_method0("items").unwrap();
+        let same_items = items.call_method0("__iter__").unwrap();
+        assert!(same_items.is(&items));
+        let (key, value) = items
+            .call_method0("__next__")
+            .unwrap()
+            .extract::<(Bound<PyAny>, Bound<PyAny>)>()
+            .unwrap();
+        let comparison = bool::extract_bound(
+            &system
+                .get_item(&key)
+                .unwrap()
+                .call_method1("__eq__", (value,))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+
+        // Keys are snapshotted, values are looked up when they are reached
+        let keys = system.call_method0("__iter__").unwrap();
+        let values = system.call_method0("values").unwrap();
+        let key = keys.call_method0("__next__").unwrap();
+        assert!(system.contains(&key).unwrap());
+        system.set_item(&key, 0.7).unwrap();
+        let value = values.call_method0("__next__").unwrap();
+        let comparison =
+            bool::extract_bound(&value.call_method1("__eq__", (0.7,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        // Entries added during the iteration are not seen, removed entries are skipped
+        system.set_item("2Y", 0.1).unwrap();
+        let remaining = items
+            .call_method0("__next__")
+            .unwrap()
+            .extract::<(Bound<PyAny>, Bound<PyAny>)>()
+            .unwrap()
+            .0;
+        system.del_item(&remaining).unwrap();
+        assert!(keys.call_method0("__next__").is_err());
+        assert!(values.call_method0("__next__").is_err());
+
+        let keys = system.call_method0("keys").unwrap();
+        for _ in 0..2 {
+            keys.call_method0("__next__").unwrap();
+        }
+        assert!(keys.call_method0("__next__").is_err());
+    });
+}
+
+/// Test the pickle support functions of SpinSystem
+#[test]
+fn test_pickle_state() {
+    pyo3::Python::with_gil(|py| {
+        let number_spins: Option<usize> = Some(3);
+        let system = new_system(py, number_spins);
+        system
+            .call_method1("add_operator_product", ("0X", 0.5))
+            .unwrap();
+
+        let state = system.call_method0("__getstate__").unwrap();
+        let restored = new_system(py, None);
+        restored.call_method1("__setstate__", (&state,)).unwrap();
+        let comparison =
+            bool::extract_bound(&restored.call_method1("__eq__", (&system,)).unwrap()).unwrap();
+        assert!(comparison);
+
+        let (constructor, arguments) = system
+            .call_method0("__reduce__")
+            .unwrap()
+            .extract::<(Bound<PyAny>, Bound<PyTuple>)>()
+            .unwrap();
+        let reconstructed = constructor.call1(arguments).unwrap();
+        let comparison =
+            bool::extract_bound(&reconstructed.call_method1("__eq__", (&system,)).unwrap())
+                .unwrap();
+        assert!(comparison);
+    });
+}
+
 #[test_case(1.0,0.0;"real")]
 #[test_case(0.0,1.0;"imag")]
 #[test_case(0.7,0.7;"mixed")]
@@ -422,6 +699,104 @@ fn test_truncate(re: f64, im: f64) {
     });
 }
 
+/// Test truncate_with_remainder function of SpinSystem
+#[test]
+fn test_truncate_with_remainder() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let system = new_system(py, None);
+        system
+            .call_method1("add_operator_product", ("0X", 1.0))
+            .unwrap();
+        system
+            .call_method1("add_operator_product", ("1Y", 0.01))
+            .unwrap();
+
+        let (kept, discarded) = system
+            .call_method1("truncate_with_remainder", (0.1_f64,))
+            .unwrap()
+            .extract::<(SpinSystemWrapper, SpinSystemWrapper)>()
+            .unwrap();
+        let truncated = system
+            .call_method1("truncate", (0.1_f64,))
+            .unwrap()
+            .extract::<SpinSystemWrapper>()
+            .unwrap();
+        assert_eq!(kept, truncated);
+        assert_eq!(discarded.internal.len(), 1);
+        assert_eq!(
+            discarded.internal.get(&PauliProduct::new().y(1)),
+            &CalculatorComplex::from(0.01)
+        );
+    });
+}
+
+/// Test add_with_policy function of SpinSystem
+#[test]
+fn test_add_with_policy() {
+    pyo3::prepare_freethreaded_python();
+    pyo3::Python::with_gil(|py| {
+        let left = new_system(py, None);
+        left.call_method1(
+            "add_operator_product",
+            (
+                "0X",
+                CalculatorComplexWrapper {
+                    internal: CalculatorComplex::from("a"),
+                },
+            ),
+        )
+        .unwrap();
+        let right = new_system(py, None);
+        right
+            .call_method1(
+                "add_operator_product",
+                (
+                    "0X",
+                    CalculatorComplexWrapper {
+                        internal: CalculatorComplex::from("b"),
+                    },
+                ),
+            )
+            .unwrap();
+        right
+            .call_method1("add_operator_product", ("1Z", 1.0))
+            .unwrap();
+
+        let merged = left
+            .call_method1("add_with_policy", (right.clone(), "prefer_left"))
+            .unwrap();
+        let merged_wrapper = merged.extract::<SpinSystemWrapper>().unwrap();
+        assert_eq!(
+            merged_wrapper
+                .internal
+                .get(&PauliProduct::new().x(0))
+                .clone(),
+            CalculatorComplex::from("a")
+        );
+        assert_eq!(merged_wrapper.internal.len(), 2);
+
+        let error = left.call_method1(
+            "add_with_policy",
+            (right.clone(), "error_on_symbol_conflict"),
+        );
+        assert!(error.is_err());
+        let error = left.call_method1("add_with_policy", (right.clone(), "left"));
+        assert!(error.is_err());
+
+        let summed = left
+            .call_method1("add_with_policy", (right.clone(),))
+            .unwrap();
+        let comparison = bool::extract_bound(
+            &summed
+                .call_method1("__eq__", (left.call_method1("__add__", (right,)).unwrap(),))
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(comparison);
+    });
+}
+
 #[test]
 fn test_separate() {
     pyo3::prepare_freethreaded_python();
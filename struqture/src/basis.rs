@@ -0,0 +1,349 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enumeration of the basis states used by the matrix representations of struqture objects.
+//!
+//! States of spins and fermionic modes are encoded as integers, where bit j is the state of spin j
+//! (0 for |0>, 1 for |1>) or the occupation of fermionic mode j. This is the ordering of the rows and columns
+//! of the sparse matrices of spin operators. States of bosonic modes are lists of occupation numbers,
+//! which are numbered in mixed radix with mode 0 changing fastest.
+//!
+//! The states of a sector are enumerated in increasing order of their index in the full space, so the
+//! matrix of an operator in a sector is a block of its matrix in the full space. The ranking functions
+//! return the position of a state in this enumeration, the unranking functions invert them.
+//!
+//! # Example
+//! ```
+//! use struqture::basis::{
+//!     fixed_particle_number_states, rank_fixed_particle_number_state,
+//!     unrank_fixed_particle_number_state,
+//! };
+//!
+//! let states: Vec<usize> = fixed_particle_number_states(4, 2).unwrap().collect();
+//! assert_eq!(states, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+//! assert_eq!(rank_fixed_particle_number_state(0b1001), 3);
+//! assert_eq!(unrank_fixed_particle_number_state(3, 4, 2).unwrap(), 0b1001);
+//! ```
+
+use crate::StruqtureError;
+
+/// Iterator over the states of two-level systems with a fixed number of excitations, in increasing order.
+///
+/// Created by [fixed_particle_number_states] and [spin_z_sector_states].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedParticleNumberStates {
+    /// The next state to return, None when the iterator is exhausted.
+    next_state: Option<usize>,
+    /// The number of remaining states.
+    remaining: usize,
+}
+
+impl Iterator for FixedParticleNumberStates {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let state = self.next_state?;
+        self.remaining -= 1;
+        self.next_state = if self.remaining == 0 {
+            None
+        } else {
+            // Next larger integer with the same number of set bits (Gosper's hack)
+            let lowest_bit = state & state.wrapping_neg();
+            let ripple = state + lowest_bit;
+            Some((((ripple ^ state) >> 2) / lowest_bit) | ripple)
+        };
+        Some(state)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for FixedParticleNumberStates {}
+
+/// Returns the binomial coefficient n choose k.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The binomial coefficient, 0 if k > n.
+/// * `Err(StruqtureError::GenericError)` - The binomial coefficient overflows usize.
+pub fn binomial(n: usize, k: usize) -> Result<usize, StruqtureError> {
+    if k > n {
+        return Ok(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // The intermediate results are binomial coefficients, so the division is exact
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > usize::MAX as u128 {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The binomial coefficient {} choose {} overflows usize",
+                    n, k
+                ),
+            });
+        }
+    }
+    Ok(result as usize)
+}
+
+/// Returns the iterator over the states of two-level modes with a fixed number of occupied modes.
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of spins or fermionic modes.
+/// * `number_particles` - The number of occupied modes (spins in |1>).
+///
+/// # Returns
+///
+/// * `Ok(FixedParticleNumberStates)` - The states in increasing order, binomial(number_modes, number_particles) in total.
+/// * `Err(StruqtureError::GenericError)` - The number of particles exceeds the number of modes or the states do not fit into usize.
+pub fn fixed_particle_number_states(
+    number_modes: usize,
+    number_particles: usize,
+) -> Result<FixedParticleNumberStates, StruqtureError> {
+    if number_modes >= usize::BITS as usize {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The states of {} modes cannot be encoded in usize",
+                number_modes
+            ),
+        });
+    }
+    if number_particles > number_modes {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The number of particles {} exceeds the number of modes {}",
+                number_particles, number_modes
+            ),
+        });
+    }
+    Ok(FixedParticleNumberStates {
+        next_state: Some((1_usize << number_particles) - 1),
+        remaining: binomial(number_modes, number_particles)?,
+    })
+}
+
+/// Returns the iterator over the spin states with a fixed total magnetization in z-direction.
+///
+/// The magnetization is the eigenvalue of the sum of the PauliZ operators of all spins, i.e. the number of
+/// spins in |0> minus the number of spins in |1>.
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins.
+/// * `magnetization` - The eigenvalue of the sum of the PauliZ operators, between -number_spins and number_spins.
+///
+/// # Returns
+///
+/// * `Ok(FixedParticleNumberStates)` - The states in increasing order.
+/// * `Err(StruqtureError::GenericError)` - The magnetization cannot be reached by number_spins spins or the states do not fit into usize.
+pub fn spin_z_sector_states(
+    number_spins: usize,
+    magnetization: isize,
+) -> Result<FixedParticleNumberStates, StruqtureError> {
+    let difference = number_spins as isize - magnetization;
+    if magnetization.unsigned_abs() > number_spins || difference % 2 != 0 {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "A magnetization of {} is not possible for {} spins",
+                magnetization, number_spins
+            ),
+        });
+    }
+    fixed_particle_number_states(number_spins, (difference / 2) as usize)
+}
+
+/// Returns the position of a state in the enumeration of the states with the same number of occupied modes.
+///
+/// The rank follows the combinatorial number system, the sum of binomial(p_i, i) over the positions
+/// p_1 < p_2 < ... of the set bits of the state.
+///
+/// # Arguments
+///
+/// * `state` - The state, bit j is the occupation of mode j.
+///
+/// # Returns
+///
+/// * `usize` - The position of the state in [fixed_particle_number_states].
+pub fn rank_fixed_particle_number_state(state: usize) -> usize {
+    let mut rank = 0;
+    let mut number_set_bits = 0;
+    for position in 0..usize::BITS as usize {
+        if state & (1 << position) != 0 {
+            number_set_bits += 1;
+            // Cannot overflow, as the rank is smaller than the number of states below 2^BITS
+            rank += binomial(position, number_set_bits).unwrap_or_default();
+        }
+    }
+    rank
+}
+
+/// Returns the state at a position in the enumeration of the states with a fixed number of occupied modes.
+///
+/// # Arguments
+///
+/// * `rank` - The position of the state.
+/// * `number_modes` - The number of spins or fermionic modes.
+/// * `number_particles` - The number of occupied modes.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The state, bit j is the occupation of mode j.
+/// * `Err(StruqtureError::GenericError)` - The rank exceeds the number of states or the states do not fit into usize.
+pub fn unrank_fixed_particle_number_state(
+    rank: usize,
+    number_modes: usize,
+    number_particles: usize,
+) -> Result<usize, StruqtureError> {
+    let number_states = fixed_particle_number_states(number_modes, number_particles)?.len();
+    if rank >= number_states {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The rank {} exceeds the number of states {}",
+                rank, number_states
+            ),
+        });
+    }
+    let mut remaining_rank = rank;
+    let mut state = 0;
+    let mut position = number_modes;
+    for number_set_bits in (1..=number_particles).rev() {
+        // Largest position with binomial(position, number_set_bits) <= remaining_rank
+        position -= 1;
+        while binomial(position, number_set_bits)? > remaining_rank {
+            position -= 1;
+        }
+        remaining_rank -= binomial(position, number_set_bits)?;
+        state |= 1 << position;
+    }
+    Ok(state)
+}
+
+/// Returns the iterator over the occupation number states of bosonic modes with Fock space cutoffs.
+///
+/// The occupation of mode j runs from 0 to `fock_cutoffs[j] - 1`, mode 0 changes fastest.
+///
+/// # Arguments
+///
+/// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode.
+///
+/// # Returns
+///
+/// * `impl Iterator<Item = Vec<usize>>` - The occupation numbers of all states, ordered by [rank_fock_state].
+pub fn fock_states(fock_cutoffs: &[usize]) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let number_states: usize = fock_cutoffs.iter().product();
+    let mut occupations = vec![0; fock_cutoffs.len()];
+    (0..number_states).map(move |index| {
+        if index > 0 {
+            for (occupation, cutoff) in occupations.iter_mut().zip(fock_cutoffs.iter()) {
+                *occupation += 1;
+                if *occupation < *cutoff {
+                    break;
+                }
+                *occupation = 0;
+            }
+        }
+        occupations.clone()
+    })
+}
+
+/// Returns the iterator over the occupation number states of bosonic modes with a fixed total number of bosons.
+///
+/// # Arguments
+///
+/// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode.
+/// * `number_particles` - The total number of bosons.
+///
+/// # Returns
+///
+/// * `impl Iterator<Item = Vec<usize>>` - The occupation numbers of the states in the sector, ordered by [rank_fock_state].
+pub fn fock_states_with_particle_number(
+    fock_cutoffs: &[usize],
+    number_particles: usize,
+) -> impl Iterator<Item = Vec<usize>> + '_ {
+    fock_states(fock_cutoffs)
+        .filter(move |occupations| occupations.iter().sum::<usize>() == number_particles)
+}
+
+/// Returns the index of an occupation number state of bosonic modes in the full Fock space.
+///
+/// # Arguments
+///
+/// * `occupations` - The occupation number of every bosonic mode.
+/// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The index, the sum of occupations[j] times the product of the cutoffs of the modes below j.
+/// * `Err(StruqtureError::MissmatchedNumberModes)` - The numbers of occupations and cutoffs differ.
+/// * `Err(StruqtureError::GenericError)` - An occupation is not below the cutoff of its mode.
+pub fn rank_fock_state(
+    occupations: &[usize],
+    fock_cutoffs: &[usize],
+) -> Result<usize, StruqtureError> {
+    if occupations.len() != fock_cutoffs.len() {
+        return Err(StruqtureError::MissmatchedNumberModes);
+    }
+    let mut index = 0;
+    let mut stride = 1;
+    for (mode, (occupation, cutoff)) in occupations.iter().zip(fock_cutoffs.iter()).enumerate() {
+        if occupation >= cutoff {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The occupation {} of mode {} is not below the cutoff {}",
+                    occupation, mode, cutoff
+                ),
+            });
+        }
+        index += occupation * stride;
+        stride = stride.saturating_mul(*cutoff);
+    }
+    Ok(index)
+}
+
+/// Returns the occupation number state of bosonic modes with an index in the full Fock space.
+///
+/// # Arguments
+///
+/// * `index` - The index of the state, see [rank_fock_state].
+/// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode.
+///
+/// # Returns
+///
+/// * `Ok(Vec<usize>)` - The occupation number of every bosonic mode.
+/// * `Err(StruqtureError::GenericError)` - The index exceeds the dimension of the Fock space.
+pub fn unrank_fock_state(
+    index: usize,
+    fock_cutoffs: &[usize],
+) -> Result<Vec<usize>, StruqtureError> {
+    let mut remaining = index;
+    let mut occupations = Vec::with_capacity(fock_cutoffs.len());
+    for cutoff in fock_cutoffs.iter() {
+        if *cutoff == 0 {
+            break;
+        }
+        occupations.push(remaining % cutoff);
+        remaining /= cutoff;
+    }
+    if remaining != 0 || occupations.len() != fock_cutoffs.len() {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The index {} exceeds the dimension of the Fock space",
+                index
+            ),
+        });
+    }
+    Ok(occupations)
+}
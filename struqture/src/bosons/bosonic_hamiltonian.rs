@@ -11,12 +11,19 @@
 // limitations under the License.
 
 use super::{BosonOperator, BosonProduct, HermitianBosonProduct, ModeIndex, OperateOnBosons};
+use crate::linalg::{cholesky_decomposition, hermitian_eigh};
 use crate::{
-    GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, expression_from_terms,
+    parse_canonical_text_entries, parse_expression_terms, split_canonical_text, CanonicalText,
+    GetValue, OperateOnConstant, OperateOnDensityMatrix, OperateOnModes, OperateOnState,
+    OperatorExpression, StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+    ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
+use ndarray::{s, Array2};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -30,6 +37,9 @@ use std::collections::hash_map::{Entry, Iter, Keys, Values};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::HashMap;
 
+/// Pivots of the quadratic form below this threshold (relative to its largest diagonal entry) are treated as vanishing.
+const NORMAL_MODE_TOLERANCE: f64 = 1e-12;
+
 /// BosonHamiltonians are combinations of HermitianBosonProducts with specific CalculatorComplex coefficients.
 ///
 /// This is a representation of sums of creation and annihilation operators with weightings (and their hermitian conjugates), in order to build a full hamiltonian.
@@ -64,6 +74,8 @@ pub struct BosonHamiltonian {
     internal_map: IndexMap<HermitianBosonProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<HermitianBosonProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for BosonHamiltonian {}
@@ -79,27 +91,66 @@ impl schemars::JsonSchema for BosonHamiltonian {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct BosonHamiltonianSerialize {
     items: Vec<(HermitianBosonProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for BosonHamiltonianSerialize {
+    /// Serialization function for BosonHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "BosonHamiltonianSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<BosonHamiltonianSerialize> for BosonHamiltonian {
     fn from(value: BosonHamiltonianSerialize) -> Self {
-        let new_noise_op: BosonHamiltonian = value
+        let mut new_noise_op: BosonHamiltonian = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<BosonHamiltonian> for BosonHamiltonianSerialize {
     fn from(value: BosonHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(HermitianBosonProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -110,11 +161,21 @@ impl From<BosonHamiltonian> for BosonHamiltonianSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for BosonHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = BosonHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonHamiltonian {
     type Index = HermitianBosonProduct;
     type Value = CalculatorComplex;
@@ -159,10 +220,25 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonHamiltonian {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the BosonHamiltonian with the given (HermitianBosonProduct key, CalculatorComplex value) pair.
@@ -182,7 +258,7 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonHamiltonian {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value.re != CalculatorFloat::ZERO || value.im != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             // Catch on diagonals with non-zero imaginary values
             if key.is_natural_hermitian() && value.im != CalculatorFloat::ZERO {
                 Err(StruqtureError::NonHermitianOperator)
@@ -234,6 +310,13 @@ impl<'a> OperateOnState<'a> for BosonHamiltonian {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for BosonHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        HermitianBosonProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for BosonHamiltonian {
     /// Returns maximum index in BosonHamiltonian internal_map.
     ///
@@ -264,6 +347,17 @@ impl<'a> OperateOnModes<'a> for BosonHamiltonian {
 
 impl<'a> OperateOnBosons<'a> for BosonHamiltonian {}
 
+/// The normal modes of a quadratic BosonHamiltonian.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalModes {
+    /// The frequencies of the normal modes in ascending order.
+    pub frequencies: Vec<f64>,
+    /// The symplectic transformation T with (b, b^{\dagger}) = T (c, c^{\dagger}), where c_k annihilates a quantum in the k-th normal mode.
+    pub transformation: Array2<Complex64>,
+    /// The Hamiltonian in the normal modes, \sum_k \omega_k c_k^{\dagger} c_k plus a constant.
+    pub transformed_hamiltonian: BosonHamiltonian,
+}
+
 /// Implements the default function (Default trait) of BosonHamiltonian (an empty BosonHamiltonian).
 ///
 impl Default for BosonHamiltonian {
@@ -286,6 +380,7 @@ impl BosonHamiltonian {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -304,9 +399,34 @@ impl BosonHamiltonian {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
+    /// Creates the BosonHamiltonian counting the particles in the given modes, $\sum_i b_i^{\dagger} b_i$.
+    ///
+    /// Every mode that is given several times contributes its number operator several times.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes whose occupation is counted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonHamiltonian with the number operator of every mode with coefficient one.
+    pub fn occupation_operator(modes: impl IntoIterator<Item = usize>) -> Self {
+        let mut new_operator = Self::new();
+        for mode in modes {
+            new_operator
+                .add_operator_product(
+                    HermitianBosonProduct::number(mode),
+                    CalculatorComplex::from(1.0),
+                )
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -331,6 +451,118 @@ impl BosonHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Converts the BosonHamiltonian into a BosonOperator, adding the hermitian conjugates of all terms that are not naturally hermitian.
+    ///
+    /// # Returns
+    ///
+    /// * `BosonOperator` - The BosonHamiltonian as a BosonOperator.
+    pub fn to_operator(&self) -> BosonOperator {
+        BosonOperator::from(self.clone())
+    }
+
+    /// Computes the normal modes of a quadratic BosonHamiltonian by a symplectic (Bogoliubov) diagonalization.
+    ///
+    /// The Hamiltonian is written as H = 1/2 (b^{\dagger}, b) M (b, b^{\dagger})^T + const with the
+    /// Hermitian matrix M = ((A, B), (B^*, A^*)). For a positive definite M, the Cholesky decomposition
+    /// M = L L^{\dagger} is used to diagonalize L^{\dagger} \eta L with \eta = diag(1, -1), whose positive
+    /// eigenvalues are the normal-mode frequencies (Colpa's method).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NormalModes)` - The frequencies, the transformation matrix and the Hamiltonian in the normal modes.
+    /// * `Err(StruqtureError::GenericError)` - The Hamiltonian contains a term that is not quadratic or constant.
+    /// * `Err(StruqtureError::GenericError)` - The quadratic form is not positive definite, so there are no stable normal modes.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn normal_modes(&self) -> Result<NormalModes, StruqtureError> {
+        let number_modes = self.current_number_modes();
+        let dimension = 2 * number_modes;
+        let mut matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        let mut constant = 0.0;
+        for (product, value) in self.iter() {
+            let value = Complex64::new(*value.re.float()?, *value.im.float()?);
+            let creators: Vec<usize> = product.creators().copied().collect();
+            let annihilators: Vec<usize> = product.annihilators().copied().collect();
+            match (creators.as_slice(), annihilators.as_slice()) {
+                ([], []) => constant += value.re,
+                ([i], [j]) => {
+                    // c b_i^{\dagger} b_j + c^* b_j^{\dagger} b_i
+                    matrix[[*i, *j]] += value;
+                    if i != j {
+                        matrix[[*j, *i]] += value.conj();
+                    }
+                }
+                ([], [i, j]) => {
+                    // c b_i b_j + c^* b_j^{\dagger} b_i^{\dagger}, B is symmetric and counted twice in 1/2 M
+                    if i == j {
+                        matrix[[*i, number_modes + *i]] += value.conj() * 2.0;
+                    } else {
+                        matrix[[*i, number_modes + *j]] += value.conj();
+                        matrix[[*j, number_modes + *i]] += value.conj();
+                    }
+                }
+                _ => {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Normal modes can only be computed for quadratic Hamiltonians, found term {}.",
+                            product
+                        ),
+                    })
+                }
+            }
+        }
+        for i in 0..number_modes {
+            for j in 0..number_modes {
+                matrix[[number_modes + i, j]] = matrix[[i, number_modes + j]].conj();
+                matrix[[number_modes + i, number_modes + j]] = matrix[[i, j]].conj();
+            }
+        }
+        // Normal ordering of 1/2 b A^* b^{\dagger} leaves -1/2 tr(A)
+        let trace: f64 = (0..number_modes).map(|i| matrix[[i, i]].re).sum();
+
+        let lower = cholesky_decomposition(&matrix, NORMAL_MODE_TOLERANCE).ok_or_else(|| {
+            StruqtureError::GenericError {
+                msg: "The quadratic form of the Hamiltonian is not positive definite, the Hamiltonian has no stable normal modes.".to_string(),
+            }
+        })?;
+        let metric_lower = Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+            if row < number_modes {
+                lower[[row, column]]
+            } else {
+                -lower[[row, column]]
+            }
+        });
+        let (values, vectors) = hermitian_eigh(&lower.t().mapv(|x| x.conj()).dot(&metric_lower));
+        // L^{\dagger} \eta L has the eigenvalues -\omega_k and \omega_k, the positive half determines
+        // the transformation, the columns for c^{\dagger} follow from the particle-hole symmetry
+        let frequencies: Vec<f64> = values[number_modes..].to_vec();
+        let positive = metric_lower.dot(&vectors.slice(s![.., number_modes..]));
+        let transformation = Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+            if column < number_modes {
+                positive[[row, column]] / frequencies[column].sqrt()
+            } else {
+                let mode = column - number_modes;
+                positive[[(row + number_modes) % dimension, mode]].conj() / frequencies[mode].sqrt()
+            }
+        });
+
+        let mut transformed_hamiltonian = BosonHamiltonian::with_capacity(number_modes + 1);
+        for (mode, frequency) in frequencies.iter().enumerate() {
+            transformed_hamiltonian.add_operator_product(
+                HermitianBosonProduct::number(mode),
+                CalculatorComplex::from(*frequency),
+            )?;
+        }
+        transformed_hamiltonian.add_operator_product(
+            HermitianBosonProduct::new([], [])?,
+            CalculatorComplex::from(constant + 0.5 * (frequencies.iter().sum::<f64>() - trace)),
+        )?;
+        Ok(NormalModes {
+            frequencies,
+            transformation,
+            transformed_hamiltonian,
+        })
+    }
 }
 
 impl TryFrom<BosonOperator> for BosonHamiltonian {
@@ -373,12 +605,14 @@ impl ops::Neg for BosonHamiltonian {
     ///
     /// * `Self` - The BosonHamiltonian * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * -1.0);
         }
         BosonHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -449,12 +683,15 @@ impl ops::Mul<CalculatorFloat> for BosonHamiltonian {
     ///
     /// * `Self` - The BosonHamiltonian multiplied by the CalculatorFloat.
     fn mul(self, other: CalculatorFloat) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         BosonHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -479,6 +716,9 @@ impl ops::Mul<CalculatorComplex> for BosonHamiltonian {
     /// * Internal bug in add_operator_product.
     fn mul(self, other: CalculatorComplex) -> BosonOperator {
         let mut new_out = BosonOperator::with_capacity(self.len());
+        new_out
+            .set_zero_term_policy(self.zero_term_policy)
+            .expect("Internal bug in set_zero_term_policy");
         for (key, val) in self {
             if key.is_natural_hermitian() {
                 let new_key =
@@ -526,6 +766,8 @@ impl ops::Mul<BosonHamiltonian> for BosonHamiltonian {
     /// * Internal bug in add_operator_product.
     fn mul(self, other: BosonHamiltonian) -> BosonOperator {
         let mut op = BosonOperator::with_capacity(self.len() * other.len());
+        op.set_zero_term_policy(self.zero_term_policy)
+            .expect("Internal bug in set_zero_term_policy");
         for (bps, vals) in self {
             for (bpo, valo) in other.iter() {
                 let boson_products = bps.clone() * bpo.clone();
@@ -649,6 +891,77 @@ impl fmt::Display for BosonHamiltonian {
     }
 }
 
+impl CanonicalText for BosonHamiltonian {
+    /// Returns the canonical text representation of the BosonHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonHamiltonian.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "BosonHamiltonian",
+            &[],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a BosonHamiltonian from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonian represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonHamiltonian.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonHamiltonian.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "BosonHamiltonian", &[])?;
+        let mut new_self = BosonHamiltonian::new();
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianBosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for BosonHamiltonian {
+    /// Returns the expression of the BosonHamiltonian, e.g. `0.5*c0a1 + 2*c1a1`.
+    ///
+    /// Only the stored terms are written, their hermitian conjugates are implied.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a BosonHamiltonian from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonian represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the BosonHamiltonian.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = BosonHamiltonian::new();
+        for (key, value) in
+            parse_expression_terms::<HermitianBosonProduct, CalculatorComplex>(expression)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,9 +11,18 @@
 // limitations under the License.
 
 use super::{BosonHamiltonian, BosonSystem, HermitianBosonProduct, ModeIndex, OperateOnBosons};
-use crate::{OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use crate::metadata::merge_operand_metadata;
+use crate::{
+    canonical_number, canonical_text_document, canonical_text_entries, hermitian_term_count,
+    hilbert_space_dimension, parse_canonical_number, parse_canonical_text_entries,
+    sparse_matrix_memory, split_canonical_text, CanonicalText, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError, SystemDimension,
+    ZeroTermPolicy,
+};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -49,7 +58,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(sh.get(&bp_1), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct BosonHamiltonianSystem {
@@ -57,10 +66,88 @@ pub struct BosonHamiltonianSystem {
     pub(crate) number_modes: Option<usize>,
     /// The BosonHamiltonian representing the Hamiltonian of the BosonHamiltonianSystem
     pub(crate) hamiltonian: BosonHamiltonian,
+    /// Provenance metadata of the BosonHamiltonianSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for BosonHamiltonianSystem {}
 
+impl Serialize for BosonHamiltonianSystem {
+    /// Serialization function for BosonHamiltonianSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonHamiltonianSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonHamiltonianSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.hamiltonian, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for BosonHamiltonianSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.hamiltonian), serializer)
+    }
+}
+
+/// Serializes a BosonHamiltonianSystem with the given serialization of its hamiltonian.
+///
+/// # Arguments
+///
+/// * `system` - BosonHamiltonianSystem to be serialized.
+/// * `hamiltonian` - Serialization of the hamiltonian of the BosonHamiltonianSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of BosonHamiltonianSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &BosonHamiltonianSystem,
+    hamiltonian: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("BosonHamiltonianSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("hamiltonian", hamiltonian)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for BosonHamiltonianSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonHamiltonianSystem {
     type Index = HermitianBosonProduct;
     type Value = CalculatorComplex;
@@ -98,15 +185,27 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonHamiltonianSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                hamiltonian: BosonHamiltonian::with_capacity(cap),
+                hamiltonian: self.hamiltonian.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                hamiltonian: BosonHamiltonian::new(),
+                hamiltonian: self.hamiltonian.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.hamiltonian.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.hamiltonian.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the BosonHamiltonianSystem with the given (HermitianBosonProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -172,6 +271,13 @@ impl<'a> OperateOnState<'a> for BosonHamiltonianSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for BosonHamiltonianSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        HermitianBosonProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for BosonHamiltonianSystem {
     /// Gets the number_modes input of the BosonHamiltonianSystem, or the current_number_modes if number_modes is None.
     ///
@@ -213,6 +319,7 @@ impl BosonHamiltonianSystem {
         BosonHamiltonianSystem {
             number_modes,
             hamiltonian: BosonHamiltonian::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -230,6 +337,7 @@ impl BosonHamiltonianSystem {
         BosonHamiltonianSystem {
             number_modes,
             hamiltonian: BosonHamiltonian::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -263,6 +371,7 @@ impl BosonHamiltonianSystem {
                     Ok(BosonHamiltonianSystem {
                         number_modes: Some(x),
                         hamiltonian,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -271,6 +380,7 @@ impl BosonHamiltonianSystem {
             None => Ok(BosonHamiltonianSystem {
                 number_modes: None,
                 hamiltonian,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -320,7 +430,7 @@ impl ops::Neg for BosonHamiltonianSystem {
 ///
 impl<T, V> ops::Add<T> for BosonHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianBosonProduct, V)>,
+    T: IntoIterator<Item = (HermitianBosonProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -335,6 +445,7 @@ where
     /// * `Ok(Self)` - The two BosonHamiltonianSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of HermitianBosonProduct exceeds that of the BosonHamiltonianSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -346,7 +457,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for BosonHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianBosonProduct, V)>,
+    T: IntoIterator<Item = (HermitianBosonProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -361,6 +472,7 @@ where
     /// * `Ok(Self)` - The two BosonHamiltonianSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of HermitianBosonProduct exceeds that of the BosonHamiltonianSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -403,6 +515,7 @@ impl ops::Mul<CalculatorComplex> for BosonHamiltonianSystem {
     fn mul(self, other: CalculatorComplex) -> BosonSystem {
         let mut system = BosonSystem::new(self.number_modes);
         system.operator = self.hamiltonian * other;
+        system.metadata = self.metadata;
         system
     }
 }
@@ -421,9 +534,12 @@ impl ops::Mul<BosonHamiltonianSystem> for BosonHamiltonianSystem {
     ///
     /// * `Ok(BosonSystem)` - The two BosonHamiltonianSystems multiplied.
     fn mul(self, other: BosonHamiltonianSystem) -> Self::Output {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<BosonHamiltonianSystem>(&mut metadata, &other);
         Ok(BosonSystem {
             number_modes: Some(self.number_modes().max(other.number_modes())),
             operator: self.hamiltonian * other.hamiltonian,
+            metadata,
         })
     }
 }
@@ -535,3 +651,85 @@ impl fmt::Display for BosonHamiltonianSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for BosonHamiltonianSystem {
+    /// Returns the canonical text representation of the BosonHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonHamiltonianSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "BosonHamiltonianSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a BosonHamiltonianSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonHamiltonianSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonHamiltonianSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonHamiltonianSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "BosonHamiltonianSystem", &["number_modes"])?;
+        let mut new_self = BosonHamiltonianSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianBosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SystemDimension for BosonHamiltonianSystem {
+    /// Returns the dimension of the Hilbert space of the BosonHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(0, self.number_modes(), fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the BosonHamiltonianSystem.
+    ///
+    /// Terms that are not naturally hermitian also represent their hermitian conjugate and are counted twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonHamiltonianSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(
+            dimension,
+            hermitian_term_count(self.keys()),
+            bytes_per_entry,
+        )
+    }
+}
@@ -65,6 +65,8 @@ impl schemars::JsonSchema for BosonProduct {
 
 impl crate::MinSupportedVersion for BosonProduct {}
 
+impl crate::SerializeCanonical for BosonProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for BosonProduct {
@@ -570,6 +572,8 @@ impl schemars::JsonSchema for HermitianBosonProduct {
 
 impl crate::MinSupportedVersion for HermitianBosonProduct {}
 
+impl crate::SerializeCanonical for HermitianBosonProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for HermitianBosonProduct {
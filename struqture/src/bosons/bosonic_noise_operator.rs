@@ -12,11 +12,14 @@
 
 use super::{BosonProduct, OperateOnBosons};
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_noise_text_entries, canonical_text_document, noise_adjoint_violations,
+    noise_symmetrized_entries, parse_canonical_noise_text_entries, split_canonical_text,
+    CanonicalText, ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -65,6 +68,8 @@ pub struct BosonLindbladNoiseOperator {
     internal_map: IndexMap<(BosonProduct, BosonProduct), CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<(BosonProduct, BosonProduct), CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for BosonLindbladNoiseOperator {}
@@ -80,31 +85,71 @@ impl schemars::JsonSchema for BosonLindbladNoiseOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct BosonLindbladNoiseOperatorSerialize {
     /// The vector representing the internal map of the BosonLindbladNoiseOperator
     items: Vec<(BosonProduct, BosonProduct, CalculatorFloat, CalculatorFloat)>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// The struqture version
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for BosonLindbladNoiseOperatorSerialize {
+    /// Serialization function for BosonLindbladNoiseOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonLindbladNoiseOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonLindbladNoiseOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "BosonLindbladNoiseOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<BosonLindbladNoiseOperatorSerialize> for BosonLindbladNoiseOperator {
     fn from(value: BosonLindbladNoiseOperatorSerialize) -> Self {
-        let new_noise_op: BosonLindbladNoiseOperator = value
+        let mut new_noise_op: BosonLindbladNoiseOperator = value
             .items
             .into_iter()
             .map(|(left, right, real, imag)| {
                 ((left, right), CalculatorComplex { re: real, im: imag })
             })
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<BosonLindbladNoiseOperator> for BosonLindbladNoiseOperatorSerialize {
     fn from(value: BosonLindbladNoiseOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(BosonProduct, BosonProduct, CalculatorFloat, CalculatorFloat)> =
             value
                 .into_iter()
@@ -116,11 +161,23 @@ impl From<BosonLindbladNoiseOperator> for BosonLindbladNoiseOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for BosonLindbladNoiseOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = BosonLindbladNoiseOperatorSerialize::from(self.clone());
+        serializable
+            .items
+            .sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonLindbladNoiseOperator {
     type Index = (BosonProduct, BosonProduct);
     type Value = CalculatorComplex;
@@ -165,10 +222,25 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonLindbladNoiseOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the BosonLindbladNoiseOperator with the given ((BosonProduct, BosonProduct) key, CalculatorComplex value) pair.
@@ -196,7 +268,7 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonLindbladNoiseOperator {
             return Err(StruqtureError::InvalidLindbladTerms);
         }
 
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -262,6 +334,7 @@ impl BosonLindbladNoiseOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -280,6 +353,7 @@ impl BosonLindbladNoiseOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -313,6 +387,107 @@ impl BosonLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the keys (A, B) whose coefficient is not the complex conjugate of the coefficient of (B, A).
+    ///
+    /// The Kossakowski matrix of a physical noise operator is self-adjoint, so every entry (A, B)
+    /// has to be matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(BosonProduct, BosonProduct)>` - The keys violating the self-adjoint structure.
+    pub fn adjoint_violations(&self) -> Vec<(BosonProduct, BosonProduct)> {
+        noise_adjoint_violations(self.iter())
+    }
+
+    /// Returns true if every entry (A, B) is matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the BosonLindbladNoiseOperator is self-adjoint.
+    pub fn is_self_adjoint(&self) -> bool {
+        self.adjoint_violations().is_empty()
+    }
+
+    /// Enforces the self-adjoint structure of the BosonLindbladNoiseOperator.
+    ///
+    /// The coefficient of every violating entry (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(BosonProduct, BosonProduct)>` - The keys whose coefficients were changed or added.
+    pub fn symmetrize(&mut self) -> Vec<(BosonProduct, BosonProduct)> {
+        let changes = noise_symmetrized_entries(self.iter());
+        let mut changed_keys: Vec<(BosonProduct, BosonProduct)> = Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            self.set(key.clone(), value)
+                .expect("Internal bug in set for a key already contained in transposed form.");
+            changed_keys.push(key);
+        }
+        changed_keys
+    }
+
+    /// Creates the standard thermalization noise of independent bosonic modes coupled to thermal baths.
+    ///
+    /// For mode i with thermal occupation n_i and loss rate κ_i the noise terms are
+    /// * loss (annihilator a_i) with rate κ_i (n_i + 1),
+    /// * thermal excitation (creator a_i^†) with rate κ_i n_i.
+    ///
+    /// # Arguments
+    ///
+    /// * `nbar` - The thermal occupation of the bath of every mode.
+    /// * `kappa` - The loss rate of every mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladNoiseOperator with the thermalization noise of all modes.
+    /// * `Err(StruqtureError::GenericError)` - The numbers of occupations and rates differ or an occupation or rate is negative.
+    pub fn thermal_bath(nbar: &[f64], kappa: &[f64]) -> Result<Self, StruqtureError> {
+        if nbar.len() != kappa.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Got {} thermal occupations but {} rates, one of each is needed for every mode",
+                    nbar.len(),
+                    kappa.len()
+                ),
+            });
+        }
+        let mut noise = BosonLindbladNoiseOperator::new();
+        for (mode, (occupation, rate)) in nbar.iter().zip(kappa.iter()).enumerate() {
+            if *occupation < 0.0 || *rate < 0.0 {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Thermal occupation {} and rate {} of mode {} must not be negative",
+                        occupation, rate, mode
+                    ),
+                });
+            }
+            for (product, value) in [
+                (BosonProduct::new([], [mode])?, rate * (occupation + 1.0)),
+                (BosonProduct::new([mode], [])?, rate * occupation),
+            ] {
+                if value > 0.0 {
+                    noise.set((product.clone(), product), CalculatorComplex::from(value))?;
+                }
+            }
+        }
+        Ok(noise)
+    }
+
+    /// Returns the noise operator of the simultaneous action of self and another noise operator.
+    ///
+    /// Generators of the Lindblad equation add, so the result is the sum of both noise operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The noise operator acting together with self.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The sum of the generators.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
 }
 
 /// Implements the negative sign function of BosonLindbladNoiseOperator.
@@ -325,6 +500,7 @@ impl ops::Neg for BosonLindbladNoiseOperator {
     ///
     /// * `Self` - The BosonLindbladNoiseOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -334,6 +510,7 @@ impl ops::Neg for BosonLindbladNoiseOperator {
         }
         BosonLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -415,6 +592,7 @@ where
     ///
     /// * `Self` - The BosonLindbladNoiseOperator multiplied by the CalculatorComplex.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -423,8 +601,10 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         BosonLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -532,6 +712,43 @@ impl fmt::Display for BosonLindbladNoiseOperator {
     }
 }
 
+impl CanonicalText for BosonLindbladNoiseOperator {
+    /// Returns the canonical text representation of the BosonLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonLindbladNoiseOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "BosonLindbladNoiseOperator",
+            &[],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a BosonLindbladNoiseOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladNoiseOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonLindbladNoiseOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonLindbladNoiseOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "BosonLindbladNoiseOperator", &[])?;
+        let mut new_self = BosonLindbladNoiseOperator::new();
+        for (key, value) in
+            parse_canonical_noise_text_entries::<BosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,9 +11,17 @@
 // limitations under the License.
 
 use super::{BosonLindbladNoiseOperator, BosonProduct, OperateOnBosons};
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use crate::metadata::merge_operand_metadata;
+use crate::{
+    canonical_noise_text_entries, canonical_number, canonical_text_document,
+    hilbert_space_dimension, noise_is_completely_positive, parse_canonical_noise_text_entries,
+    parse_canonical_number, sparse_matrix_memory, split_canonical_text, CanonicalText, ModeIndex,
+    OperateOnDensityMatrix, OperateOnModes, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::{
     fmt::{self, Write},
@@ -51,7 +59,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(system.get(&(bp_0.clone(),bp_0.clone())), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct BosonLindbladNoiseSystem {
@@ -59,10 +67,90 @@ pub struct BosonLindbladNoiseSystem {
     pub(crate) number_modes: Option<usize>,
     /// The BosonLindbladNoiseOperator representing the Lindblad terms of the BosonLindbladNoiseSystem.
     pub(crate) operator: BosonLindbladNoiseOperator,
+    /// Provenance metadata of the BosonLindbladNoiseSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for BosonLindbladNoiseSystem {}
 
+impl Serialize for BosonLindbladNoiseSystem {
+    /// Serialization function for BosonLindbladNoiseSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonLindbladNoiseSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonLindbladNoiseSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for BosonLindbladNoiseSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a BosonLindbladNoiseSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - BosonLindbladNoiseSystem to be serialized.
+/// * `operator` - Serialization of the operator of the BosonLindbladNoiseSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of BosonLindbladNoiseSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &BosonLindbladNoiseSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer.serialize_struct(
+        "BosonLindbladNoiseSystem",
+        if skip_metadata { 2 } else { 3 },
+    )?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for BosonLindbladNoiseSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonLindbladNoiseSystem {
     type Value = CalculatorComplex;
     type Index = (BosonProduct, BosonProduct);
@@ -100,15 +188,27 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonLindbladNoiseSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                operator: BosonLindbladNoiseOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                operator: BosonLindbladNoiseOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the BosonLindbladNoiseSystem with the given ((BosonProduct, BosonProduct) key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -207,6 +307,7 @@ impl BosonLindbladNoiseSystem {
         BosonLindbladNoiseSystem {
             number_modes,
             operator: BosonLindbladNoiseOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -224,6 +325,7 @@ impl BosonLindbladNoiseSystem {
         BosonLindbladNoiseSystem {
             number_modes,
             operator: BosonLindbladNoiseOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -257,6 +359,7 @@ impl BosonLindbladNoiseSystem {
                     Ok(BosonLindbladNoiseSystem {
                         number_modes: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -265,6 +368,7 @@ impl BosonLindbladNoiseSystem {
             None => Ok(BosonLindbladNoiseSystem {
                 number_modes: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -299,6 +403,22 @@ impl BosonLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns whether the noise is completely positive, i.e. whether its Kossakowski matrix is positive semidefinite.
+    ///
+    /// The Kossakowski matrix is constructed in the basis of all operator products appearing in the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues of the Kossakowski matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the BosonLindbladNoiseSystem is completely positive.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn is_completely_positive(&self, tolerance: f64) -> Result<bool, StruqtureError> {
+        noise_is_completely_positive(self.iter(), tolerance)
+    }
 }
 
 /// Implements the negative sign function of BosonLindbladNoiseSystem.
@@ -320,7 +440,7 @@ impl ops::Neg for BosonLindbladNoiseSystem {
 ///
 impl<T, V> ops::Add<T> for BosonLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((BosonProduct, BosonProduct), V)>,
+    T: IntoIterator<Item = ((BosonProduct, BosonProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -335,6 +455,7 @@ where
     /// * `Ok(Self)` - The two BosonLindbladNoiseSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of (BosonProduct, BosonProduct) exceeds that of the BosonLindbladNoiseSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -346,7 +467,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for BosonLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((BosonProduct, BosonProduct), V)>,
+    T: IntoIterator<Item = ((BosonProduct, BosonProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -361,6 +482,7 @@ where
     /// * `Ok(Self)` - The two BosonLindbladNoiseSystems subtracted.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of (BosonProduct, BosonProduct) exceeds that of the BosonLindbladNoiseSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -498,3 +620,82 @@ impl fmt::Display for BosonLindbladNoiseSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for BosonLindbladNoiseSystem {
+    /// Returns the canonical text representation of the BosonLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonLindbladNoiseSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "BosonLindbladNoiseSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a BosonLindbladNoiseSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladNoiseSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonLindbladNoiseSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonLindbladNoiseSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "BosonLindbladNoiseSystem", &["number_modes"])?;
+        let mut new_self = BosonLindbladNoiseSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_noise_text_entries::<BosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SystemDimension for BosonLindbladNoiseSystem {
+    /// Returns the dimension of the Hilbert space of the BosonLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(0, self.number_modes(), fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the BosonLindbladNoiseSystem.
+    ///
+    /// The estimate is for the sparse superoperator, to which every Lindblad term contributes at most three non-zero entries per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonLindbladNoiseSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Every Lindblad term contributes L ρ R^dagger and the two terms of its anticommutator
+        sparse_matrix_memory(dimension, 3 * self.len(), bytes_per_entry)
+    }
+}
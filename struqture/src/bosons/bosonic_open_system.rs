@@ -11,9 +11,15 @@
 // limitations under the License.
 
 use super::{BosonHamiltonianSystem, BosonLindbladNoiseSystem};
-use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use crate::{
+    hermitian_term_count, hilbert_space_dimension, sparse_matrix_memory,
+    split_canonical_open_system, CanonicalText, OpenSystem, OperateOnDensityMatrix, OperateOnModes,
+    StruqtureError, SystemDimension,
+};
 use qoqo_calculator::CalculatorFloat;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::ops;
 
@@ -50,6 +56,28 @@ pub struct BosonLindbladOpenSystem {
 
 impl crate::MinSupportedVersion for BosonLindbladOpenSystem {}
 
+impl crate::SerializeCanonical for BosonLindbladOpenSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("BosonLindbladOpenSystem", 2)?;
+        state.serialize_field("system", &crate::Canonical(&self.system))?;
+        state.serialize_field("noise", &crate::Canonical(&self.noise))?;
+        state.end()
+    }
+}
+
+impl crate::OperateOnMetadata for BosonLindbladOpenSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.system.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.system.metadata
+    }
+}
+
 impl<'a> OpenSystem<'a> for BosonLindbladOpenSystem {
     type System = BosonHamiltonianSystem;
     type Noise = BosonLindbladNoiseSystem;
@@ -284,3 +312,84 @@ impl fmt::Display for BosonLindbladOpenSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for BosonLindbladOpenSystem {
+    /// Returns the canonical text representation of the BosonLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonLindbladOpenSystem.
+    fn to_canonical_text(&self) -> String {
+        format!(
+            "BosonLindbladOpenSystem\n{}{}",
+            self.system().to_canonical_text(),
+            self.noise().to_canonical_text()
+        )
+    }
+
+    /// Creates a BosonLindbladOpenSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonLindbladOpenSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonLindbladOpenSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonLindbladOpenSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (system, noise) = split_canonical_open_system(
+            text,
+            "BosonLindbladOpenSystem",
+            "BosonLindbladNoiseSystem",
+        )?;
+        Self::group(
+            BosonHamiltonianSystem::from_canonical_text(&system)?,
+            BosonLindbladNoiseSystem::from_canonical_text(&noise)?,
+        )
+    }
+}
+
+impl SystemDimension for BosonLindbladOpenSystem {
+    /// Returns the dimension of the Hilbert space of the BosonLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(0, self.number_modes(), fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the BosonLindbladOpenSystem.
+    ///
+    /// The estimate is for the sparse superoperator of the Hamiltonian and the noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonLindbladOpenSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Hamiltonian terms contribute to the commutator twice, Lindblad terms three times
+        let entries_per_row =
+            2 * hermitian_term_count(self.system().keys()) + 3 * self.noise().len();
+        sparse_matrix_memory(dimension, entries_per_row, bytes_per_entry)
+    }
+}
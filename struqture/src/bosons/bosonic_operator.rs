@@ -11,14 +11,22 @@
 // limitations under the License.
 
 use super::{BosonHamiltonian, OperateOnBosons};
-use crate::bosons::BosonProduct;
+use crate::bosons::{BosonProduct, HermitianBosonProduct};
 use crate::{
-    GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, commutator_residual_terms,
+    expression_from_terms, fourier_transform_terms, non_hermitian_terms_error, operator_powi,
+    parse_canonical_text_entries, parse_expression_terms, split_canonical_text, CanonicalText,
+    CoefficientEntry, GetValue, ModeIndex, OperateOnConstant, OperateOnDensityMatrix,
+    OperateOnModes, OperateOnState, OperatorExpression, StruqtureError,
+    StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::f64::consts::FRAC_1_SQRT_2;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -65,6 +73,8 @@ pub struct BosonOperator {
     internal_map: IndexMap<BosonProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<BosonProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for BosonOperator {}
@@ -80,27 +90,66 @@ impl schemars::JsonSchema for BosonOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct BosonOperatorSerialize {
     items: Vec<(BosonProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for BosonOperatorSerialize {
+    /// Serialization function for BosonOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "BosonOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<BosonOperatorSerialize> for BosonOperator {
     fn from(value: BosonOperatorSerialize) -> Self {
-        let new_noise_op: BosonOperator = value
+        let mut new_noise_op: BosonOperator = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<BosonOperator> for BosonOperatorSerialize {
     fn from(value: BosonOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(BosonProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -111,11 +160,21 @@ impl From<BosonOperator> for BosonOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for BosonOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = BosonOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonOperator {
     type Index = BosonProduct;
     type Value = CalculatorComplex;
@@ -160,10 +219,25 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the BosonOperator with the given (BosonProduct key, CalculatorComplex value) pair.
@@ -182,7 +256,7 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -198,6 +272,13 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonOperator {
 
 impl<'a> OperateOnState<'a> for BosonOperator {}
 
+impl<'a> OperateOnConstant<'a> for BosonOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        BosonProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for BosonOperator {
     // From trait
     fn current_number_modes(&'a self) -> usize {
@@ -247,6 +328,7 @@ impl BosonOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -265,9 +347,157 @@ impl BosonOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
+    /// Creates the BosonOperator counting the particles in the given modes, $\sum_i b_i^{\dagger} b_i$.
+    ///
+    /// Every mode that is given several times contributes its number operator several times.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes whose occupation is counted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonOperator with the number operator of every mode with coefficient one.
+    pub fn occupation_operator(modes: impl IntoIterator<Item = usize>) -> Self {
+        let mut new_operator = Self::new();
+        for mode in modes {
+            new_operator
+                .add_operator_product(BosonProduct::number(mode), CalculatorComplex::from(1.0))
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+
+    /// Creates the generator $\alpha b^{\dagger} - \alpha^{*} b$ of the displacement operator $D(\alpha) = \exp(\alpha b^{\dagger} - \alpha^{*} b)$.
+    ///
+    /// The generator is anti-hermitian.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode b acts on.
+    /// * `alpha` - The complex displacement.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The generator of the displacement operator.
+    pub fn displacement_generator(mode: usize, alpha: impl Into<CalculatorComplex>) -> Self {
+        let alpha: CalculatorComplex = alpha.into();
+        [
+            (
+                BosonProduct::new([mode], []).expect("Internal bug in BosonProduct::new"),
+                alpha.clone(),
+            ),
+            (
+                BosonProduct::new([], [mode]).expect("Internal bug in BosonProduct::new"),
+                alpha.conj() * -1.0,
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Creates the generator $\frac{1}{2} (z^{*} b^2 - z (b^{\dagger})^2)$ of the squeezing operator $S(z) = \exp(\frac{1}{2} (z^{*} b^2 - z (b^{\dagger})^2))$.
+    ///
+    /// The generator is anti-hermitian.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode b acts on.
+    /// * `z` - The complex squeezing parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The generator of the squeezing operator.
+    pub fn squeezing_generator(mode: usize, z: impl Into<CalculatorComplex>) -> Self {
+        let z: CalculatorComplex = z.into();
+        [
+            (
+                BosonProduct::new([], [mode, mode]).expect("Internal bug in BosonProduct::new"),
+                z.conj() * 0.5,
+            ),
+            (
+                BosonProduct::new([mode, mode], []).expect("Internal bug in BosonProduct::new"),
+                z * -0.5,
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Creates the position quadrature $x = (b + b^{\dagger}) / \sqrt{2}$ of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode b acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian position quadrature.
+    pub fn x(mode: usize) -> Self {
+        [
+            (
+                BosonProduct::new([mode], []).expect("Internal bug in BosonProduct::new"),
+                CalculatorComplex::from(FRAC_1_SQRT_2),
+            ),
+            (
+                BosonProduct::new([], [mode]).expect("Internal bug in BosonProduct::new"),
+                CalculatorComplex::from(FRAC_1_SQRT_2),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Creates the momentum quadrature $p = i (b^{\dagger} - b) / \sqrt{2}$ of a mode.
+    ///
+    /// With this convention the quadratures fulfill $[x, p] = i$.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode b acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian momentum quadrature.
+    pub fn p(mode: usize) -> Self {
+        [
+            (
+                BosonProduct::new([mode], []).expect("Internal bug in BosonProduct::new"),
+                CalculatorComplex::new(0.0, FRAC_1_SQRT_2),
+            ),
+            (
+                BosonProduct::new([], [mode]).expect("Internal bug in BosonProduct::new"),
+                CalculatorComplex::new(0.0, -FRAC_1_SQRT_2),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// Returns a mutable handle to the coefficient of a key, see [CoefficientEntry].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The BosonProduct whose coefficient is accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `CoefficientEntry<BosonProduct, CalculatorComplex>` - The handle to the coefficient, which is zero if the key is not in the BosonOperator.
+    pub fn entry(
+        &mut self,
+        key: BosonProduct,
+    ) -> CoefficientEntry<BosonProduct, CalculatorComplex> {
+        CoefficientEntry::new(
+            self.internal_map.entry(key),
+            CalculatorComplex::ZERO,
+            self.zero_term_policy,
+        )
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -292,6 +522,244 @@ impl BosonOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the largest change of the particle number caused by a term of the BosonOperator.
+    ///
+    /// A term with c creators and a annihilators changes the particle number by c - a.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The maximum of |c - a| over all terms, 0 if the BosonOperator conserves the particle number.
+    pub fn max_particle_number_change(&self) -> usize {
+        self.keys()
+            .map(|prod| prod.creators().len().abs_diff(prod.annihilators().len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the changes of the particle number caused by the terms of the BosonOperator.
+    ///
+    /// For every change d in the returned set, the BosonOperator couples the sector with N particles to the sector
+    /// with N + d particles. The BosonOperator is block-diagonal in the particle number sectors if the set only contains 0.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<isize>` - The particle number changes c - a of all terms, sorted.
+    pub fn particle_number_sectors(&self) -> BTreeSet<isize> {
+        self.keys()
+            .map(|prod| prod.creators().len() as isize - prod.annihilators().len() as isize)
+            .collect()
+    }
+
+    /// Returns true if the BosonOperator commutes with a BosonHamiltonian, i.e. if it is a conserved quantity of the BosonHamiltonian.
+    ///
+    /// The commutator [A, H] is evaluated term by term without constructing matrices. Symbolic terms of the commutator
+    /// only vanish when they cancel structurally, numerical terms when they cancel up to floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The BosonHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the commutator [A, H] vanishes.
+    pub fn commutes_with(&self, hamiltonian: &BosonHamiltonian) -> bool {
+        self.commutator_residual(hamiltonian)
+            .map(|residual| residual.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Returns the sum of the absolute values of the coefficients of the commutator [A, H] with a BosonHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The BosonHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The 1-norm of the commutator, 0.0 if the BosonOperator is conserved.
+    /// * `Err(StruqtureError::GenericError)` - Symbolic terms of the commutator do not cancel.
+    pub fn symmetry_violation_norm(
+        &self,
+        hamiltonian: &BosonHamiltonian,
+    ) -> Result<f64, StruqtureError> {
+        Ok(self
+            .commutator_residual(hamiltonian)?
+            .iter()
+            .map(|(_, value)| value.norm())
+            .sum())
+    }
+
+    /// Raises the BosonOperator to a non-negative integer power.
+    ///
+    /// The power is computed by repeated squaring. Terms whose coefficients cancel exactly are removed
+    /// after every multiplication to keep the number of terms manageable.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power, `powi(0)` is the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonOperator raised to the power n.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn powi(&self, n: usize) -> Self {
+        let mut identity = BosonOperator::new();
+        identity
+            .add_operator_product(
+                BosonProduct::new([], []).expect("Internal bug in BosonProduct::new"),
+                CalculatorComplex::from(1.0),
+            )
+            .expect("Internal bug in add_operator_product");
+        operator_powi(self, n, identity, |left, right| {
+            Ok((left * right).truncate(f64::MIN_POSITIVE))
+        })
+        .expect("Internal bug in operator_powi")
+    }
+
+    /// Returns whether the BosonOperator is hermitian.
+    ///
+    /// Symbolic coefficients are compared structurally.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the BosonOperator equals its hermitian conjugate.
+    pub fn is_hermitian(&self) -> bool {
+        self.iter().all(|(product, value)| {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            *self.get(&conjugated_product) == value.conj() * prefactor
+        })
+    }
+
+    /// Returns the hermitian part (A + A^dagger) / 2 of the BosonOperator A.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian part of the BosonOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn hermitian_part(&self) -> Self {
+        self.hermitian_split_part(1.0)
+    }
+
+    /// Returns the anti-hermitian part (A - A^dagger) / 2 of the BosonOperator A.
+    ///
+    /// The BosonOperator is the sum of its hermitian and anti-hermitian parts.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anti-hermitian part of the BosonOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn anti_hermitian_part(&self) -> Self {
+        self.hermitian_split_part(-1.0)
+    }
+
+    /// Returns (A + sign * A^dagger) / 2 for the BosonOperator A.
+    fn hermitian_split_part(&self, sign: f64) -> Self {
+        let mut part = self.empty_clone(Some(2 * self.len()));
+        for (product, value) in self.iter() {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            part.add_operator_product(product.clone(), value.clone() * 0.5)
+                .expect("Internal bug in add_operator_product");
+            part.add_operator_product(conjugated_product, value.conj() * (0.5 * sign * prefactor))
+                .expect("Internal bug in add_operator_product");
+        }
+        part
+    }
+
+    /// Converts the BosonOperator into a BosonHamiltonian if it is hermitian.
+    ///
+    /// In contrast to `BosonHamiltonian::try_from`, terms stored together with their hermitian conjugate are accepted
+    /// and the error lists all terms violating hermiticity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonHamiltonian)` - The BosonOperator as a BosonHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianTerms)` - The keys and residuals A - A^dagger of the terms violating hermiticity.
+    pub fn try_into_hamiltonian(&self) -> Result<BosonHamiltonian, StruqtureError> {
+        if !self.is_hermitian() {
+            return Err(non_hermitian_terms_error(self.anti_hermitian_part().iter()));
+        }
+        let mut hamiltonian = BosonHamiltonian::new();
+        for (product, value) in self.iter() {
+            // Only one term of every hermitian conjugate pair corresponds to a valid hermitian product
+            if let Ok(hermitian_product) = HermitianBosonProduct::new(
+                product.creators().copied(),
+                product.annihilators().copied(),
+            ) {
+                hamiltonian.add_operator_product(hermitian_product, value.clone())?;
+            }
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Transforms the BosonOperator on a one-dimensional periodic lattice to momentum space.
+    ///
+    /// Mode `m` is the orbital `m / lattice_size` on the site r = `m % lattice_size`,
+    /// so the modes of every orbital on the L sites of the lattice are consecutive. The operators are transformed with
+    /// b_r = 1/sqrt(L) Σ_n exp(i k_n r) b_(k_n) and b^†_r = 1/sqrt(L) Σ_n exp(-i k_n r) b^†_(k_n) with k_n = 2π n / L.
+    /// The momentum mode k_n of orbital o has the index n + L o, keeping the layout of the modes in real space.
+    /// Contributions that cancel, e.g. the terms of a translation invariant operator not conserving the momentum,
+    /// are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `lattice_size` - The number of sites L of the lattice.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonOperator in momentum space.
+    /// * `Err(StruqtureError::GenericError)` - The lattice size is zero or a term has too many contributions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::bosons::{BosonOperator, BosonProduct};
+    ///
+    /// // Hopping on a ring of two sites
+    /// let mut operator = BosonOperator::new();
+    /// for (i, j) in [(0, 1), (1, 0)] {
+    ///     operator
+    ///         .add_operator_product(BosonProduct::new([i], [j]).unwrap(), CalculatorComplex::from(1.0))
+    ///         .unwrap();
+    /// }
+    /// // The hopping is diagonal in momentum space with the energies 1 at k = 0 and -1 at k = π
+    /// let transformed = operator.fourier_transform(2).unwrap();
+    /// assert_eq!(transformed.len(), 2);
+    /// assert_eq!(transformed.get(&BosonProduct::new([0], [0]).unwrap()), &CalculatorComplex::from(1.0));
+    /// assert_eq!(transformed.get(&BosonProduct::new([1], [1]).unwrap()), &CalculatorComplex::from(-1.0));
+    /// ```
+    pub fn fourier_transform(&self, lattice_size: usize) -> Result<Self, StruqtureError> {
+        let mut transformed = Self::new();
+        for (key, value) in fourier_transform_terms(self.iter(), lattice_size)? {
+            transformed.add_operator_product(key, value)?;
+        }
+        Ok(transformed)
+    }
+
+    /// Returns the terms of the commutator with a BosonHamiltonian that do not cancel.
+    fn commutator_residual(
+        &self,
+        hamiltonian: &BosonHamiltonian,
+    ) -> Result<Vec<(BosonProduct, Complex64)>, StruqtureError> {
+        let hamiltonian = BosonOperator::from(hamiltonian.clone());
+        commutator_residual_terms(self.iter(), hamiltonian.iter(), |left, right| {
+            (left.clone() * right.clone())
+                .into_iter()
+                .map(|product| (product, Complex64::new(1.0, 0.0)))
+                .collect()
+        })
+    }
 }
 
 impl From<BosonHamiltonian> for BosonOperator {
@@ -310,6 +778,9 @@ impl From<BosonHamiltonian> for BosonOperator {
     /// * Internal error in add_operator_product.
     fn from(hamiltonian: BosonHamiltonian) -> Self {
         let mut internal = BosonOperator::new();
+        internal
+            .set_zero_term_policy(hamiltonian.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
         for (key, value) in hamiltonian.into_iter() {
             let bp = BosonProduct::get_key(&key);
             internal
@@ -318,7 +789,10 @@ impl From<BosonHamiltonian> for BosonOperator {
             if !key.is_natural_hermitian() {
                 let bp_conj = bp.hermitian_conjugate();
                 internal
-                    .add_operator_product(BosonProduct::get_key(&bp_conj.0), value * bp_conj.1)
+                    .add_operator_product(
+                        BosonProduct::get_key(&bp_conj.0),
+                        value.conj() * bp_conj.1,
+                    )
                     .expect("Internal error in add_operator_product.");
             }
         }
@@ -336,12 +810,14 @@ impl ops::Neg for BosonOperator {
     ///
     /// * `Self` - The BosonOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * -1.0);
         }
         BosonOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -415,13 +891,16 @@ where
     ///
     /// * `Self` - The BosonOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         BosonOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -445,6 +924,7 @@ impl ops::Mul<BosonOperator> for BosonOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, other: BosonOperator) -> Self {
         let mut boson_op = BosonOperator::new();
+        boson_op.zero_term_policy = self.zero_term_policy;
         for ((left_key, left_val), (right_key, right_val)) in
             self.into_iter().cartesian_product(other.iter())
         {
@@ -555,6 +1035,68 @@ impl fmt::Display for BosonOperator {
     }
 }
 
+impl CanonicalText for BosonOperator {
+    /// Returns the canonical text representation of the BosonOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document("BosonOperator", &[], &canonical_text_entries(self.iter()))
+    }
+
+    /// Creates a BosonOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "BosonOperator", &[])?;
+        let mut new_self = BosonOperator::new();
+        for (key, value) in parse_canonical_text_entries::<BosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for BosonOperator {
+    /// Returns the expression of the BosonOperator, e.g. `0.5*c0a1 - i*c1`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a BosonOperator from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonOperator represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the BosonOperator.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = BosonOperator::new();
+        for (key, value) in parse_expression_terms::<BosonProduct, CalculatorComplex>(expression)? {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
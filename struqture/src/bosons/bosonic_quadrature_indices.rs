@@ -0,0 +1,455 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::StruqtureError;
+use num_complex::Complex64;
+use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use tinyvec::TinyVec;
+
+/// A standard ordered product of bosonic position and momentum quadratures.
+///
+/// The QuadratureProduct represents $ \prod_i x_i^{n_i} p_i^{m_i} $ with the quadratures
+/// $ x_i = (b_i + b_i^{\dagger}) / \sqrt{2} $ and $ p_i = i (b_i^{\dagger} - b_i) / \sqrt{2} $, which fulfill $ [x_i, p_i] = i $.
+/// On every mode all position operators stand to the left of the momentum operators (standard ordering).
+/// Products of quadratures in a different order are brought into standard ordering by [QuadratureProduct::multiply].
+///
+/// # Example
+///
+/// ```rust
+/// use struqture::bosons::QuadratureProduct;
+///
+/// // Representing $ x_0^2 p_0 p_1 $
+/// let q_product = QuadratureProduct::new().x(0).x(0).p(0).p(1);
+/// assert_eq!(q_product.to_string(), "0X0X0P1P");
+/// assert_eq!(q_product.powers(0), (2, 1));
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct QuadratureProduct {
+    /// The mode, the power of the position and the power of the momentum quadrature for every mode, sorted by mode.
+    powers: TinyVec<[(usize, usize, usize); 2]>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for QuadratureProduct {
+    fn schema_name() -> String {
+        "QuadratureProduct".to_string()
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let tmp_schema = gen.subschema_for::<String>();
+        let mut obj = tmp_schema.into_object();
+        let meta = obj.metadata();
+        meta.description = Some("Represents standard ordered products of bosonic position (X) and momentum (P) quadratures by a string of modes followed by the quadratures acting on them, position quadratures before momentum quadratures. E.g. 0X0X0P1P.".to_string());
+
+        schemars::schema::Schema::Object(obj)
+    }
+}
+
+impl crate::MinSupportedVersion for QuadratureProduct {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+impl crate::SerializeCanonical for QuadratureProduct {}
+
+/// Implementing serde serialization writing directly to string.
+///
+impl Serialize for QuadratureProduct {
+    /// Serialization function for QuadratureProduct according to string type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - QuadratureProduct to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of QuadratureProduct.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let readable = serializer.is_human_readable();
+        if readable {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut sequence = serializer.serialize_seq(Some(self.powers.len()))?;
+            for item in self.powers.iter() {
+                sequence.serialize_element(item)?;
+            }
+            sequence.end()
+        }
+    }
+}
+
+/// Deserializing directly from string.
+///
+impl<'de> Deserialize<'de> for QuadratureProduct {
+    /// Deserialization function for QuadratureProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Serialized instance of QuadratureProduct to be deserialized.
+    /// * `deserializer` - Deserializer used for deserialization.
+    ///
+    /// # Returns
+    ///
+    /// `QuadratureProduct` - Deserialized instance of QuadratureProduct.
+    /// `D::Error` - Error in the deserialization process.
+    fn deserialize<D>(deserializer: D) -> Result<QuadratureProduct, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+        if human_readable {
+            struct TemporaryVisitor;
+            impl<'de> Visitor<'de> for TemporaryVisitor {
+                type Value = QuadratureProduct;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("String")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    QuadratureProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+
+                fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+                where
+                    E: Error,
+                {
+                    QuadratureProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+            }
+
+            deserializer.deserialize_str(TemporaryVisitor)
+        } else {
+            struct QuadratureProductVisitor;
+            impl<'de> serde::de::Visitor<'de> for QuadratureProductVisitor {
+                type Value = QuadratureProduct;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Formatter::write_str(formatter, "Identifier of QuadratureProduct variant")
+                }
+                // when variants are marked by String values
+                fn visit_seq<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+                where
+                    M: SeqAccess<'de>,
+                {
+                    let mut qp = QuadratureProduct::new();
+                    while let Some(item) = access.next_element()? {
+                        let entry: Entry = item;
+                        qp = qp.set_powers(entry.0 .0, entry.0 .1, entry.0 .2);
+                    }
+                    Ok(qp)
+                }
+            }
+            #[derive(Deserialize)]
+            #[serde(transparent)]
+            struct Entry((usize, usize, usize));
+            let qp_visitor = QuadratureProductVisitor;
+
+            deserializer.deserialize_seq(qp_visitor)
+        }
+    }
+}
+
+/// Functions for the QuadratureProduct
+///
+impl QuadratureProduct {
+    /// Creates a new (empty) QuadratureProduct, representing the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) QuadratureProduct.
+    pub fn new() -> Self {
+        QuadratureProduct {
+            powers: TinyVec::<[(usize, usize, usize); 2]>::new(),
+        }
+    }
+
+    /// Sets the powers of the position and momentum quadratures of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode to set the powers for.
+    /// * `x_power` - The power of the position quadrature of the mode.
+    /// * `p_power` - The power of the momentum quadrature of the mode.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The QuadratureProduct with the powers of the mode replaced.
+    pub fn set_powers(mut self, mode: usize, x_power: usize, p_power: usize) -> Self {
+        match self
+            .powers
+            .binary_search_by_key(&mode, |(existing_mode, _, _)| *existing_mode)
+        {
+            Ok(position) => {
+                if x_power == 0 && p_power == 0 {
+                    self.powers.remove(position);
+                } else {
+                    self.powers[position] = (mode, x_power, p_power);
+                }
+            }
+            Err(position) => {
+                if x_power != 0 || p_power != 0 {
+                    self.powers.insert(position, (mode, x_power, p_power));
+                }
+            }
+        }
+        self
+    }
+
+    /// Raises the power of the position quadrature of a mode by one.
+    ///
+    /// The position quadrature is placed to the left of the momentum quadratures of the same mode,
+    /// to multiply operators in a different order use [QuadratureProduct::multiply].
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode of the position quadrature.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The QuadratureProduct with the power of $ x_{mode} $ raised by one.
+    pub fn x(self, mode: usize) -> Self {
+        let (x_power, p_power) = self.powers(mode);
+        self.set_powers(mode, x_power + 1, p_power)
+    }
+
+    /// Raises the power of the momentum quadrature of a mode by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode of the momentum quadrature.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The QuadratureProduct with the power of $ p_{mode} $ raised by one.
+    pub fn p(self, mode: usize) -> Self {
+        let (x_power, p_power) = self.powers(mode);
+        self.set_powers(mode, x_power, p_power + 1)
+    }
+
+    /// Gets the powers of the position and momentum quadratures of a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode to get the powers for.
+    ///
+    /// # Returns
+    ///
+    /// * `(usize, usize)` - The powers of $ x_{mode} $ and $ p_{mode} $.
+    pub fn powers(&self, mode: usize) -> (usize, usize) {
+        match self
+            .powers
+            .binary_search_by_key(&mode, |(existing_mode, _, _)| *existing_mode)
+        {
+            Ok(position) => (self.powers[position].1, self.powers[position].2),
+            Err(_) => (0, 0),
+        }
+    }
+
+    /// Returns the iterator over the (mode, power of x, power of p) entries of the QuadratureProduct, sorted by mode.
+    ///
+    /// # Returns
+    ///
+    /// * `std::slice::Iter<(usize, usize, usize)>` - The entries of the QuadratureProduct.
+    pub fn iter(&self) -> std::slice::Iter<(usize, usize, usize)> {
+        self.powers.iter()
+    }
+
+    /// Returns the number of modes the QuadratureProduct acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of modes with non-zero powers.
+    pub fn len(&self) -> usize {
+        self.powers.len()
+    }
+
+    /// Returns whether the QuadratureProduct is the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the QuadratureProduct is empty.
+    pub fn is_empty(&self) -> bool {
+        self.powers.is_empty()
+    }
+
+    /// Returns the maximal number of modes the QuadratureProduct acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The largest mode plus one, zero for the identity.
+    pub fn current_number_modes(&self) -> usize {
+        self.powers.last().map(|(mode, _, _)| mode + 1).unwrap_or(0)
+    }
+
+    /// Returns the total number of quadrature operators in the QuadratureProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The sum of all powers.
+    pub fn degree(&self) -> usize {
+        self.powers
+            .iter()
+            .map(|(_, x_power, p_power)| x_power + p_power)
+            .sum()
+    }
+
+    /// Multiplies two QuadratureProducts and brings the result into standard ordering.
+    ///
+    /// Moving momentum quadratures of the left factor past position quadratures of the right factor uses
+    /// $ p^b x^c = \sum_k \binom{b}{k} \binom{c}{k} k! (-i)^k x^{c-k} p^{b-k} $ on every mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The left factor.
+    /// * `right` - The right factor.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(QuadratureProduct, Complex64)>` - The standard ordered products and their coefficients.
+    pub fn multiply(left: QuadratureProduct, right: QuadratureProduct) -> Vec<(Self, Complex64)> {
+        let mut result: Vec<(Self, Complex64)> = vec![(Self::new(), Complex64::new(1.0, 0.0))];
+        let mut modes: Vec<usize> = left
+            .iter()
+            .chain(right.iter())
+            .map(|(mode, _, _)| *mode)
+            .collect();
+        modes.sort_unstable();
+        modes.dedup();
+        for mode in modes {
+            let (left_x, left_p) = left.powers(mode);
+            let (right_x, right_p) = right.powers(mode);
+            let mut local_terms: Vec<(usize, usize, Complex64)> = Vec::new();
+            // coefficient of the k-th term binom(b, k) binom(c, k) k! (-i)^k, built up iteratively
+            let mut coefficient = Complex64::new(1.0, 0.0);
+            for k in 0..=left_p.min(right_x) {
+                if k > 0 {
+                    coefficient *= Complex64::new(0.0, -1.0)
+                        * ((left_p - k + 1) * (right_x - k + 1)) as f64
+                        / k as f64;
+                }
+                local_terms.push((left_x + right_x - k, left_p + right_p - k, coefficient));
+            }
+            result = result
+                .into_iter()
+                .flat_map(|(product, prefactor)| {
+                    local_terms.iter().map(move |(x_power, p_power, local)| {
+                        (
+                            product.clone().set_powers(mode, *x_power, *p_power),
+                            prefactor * local,
+                        )
+                    })
+                })
+                .collect();
+        }
+        result
+    }
+}
+
+impl FromStr for QuadratureProduct {
+    type Err = StruqtureError;
+    /// Constructs a QuadratureProduct from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The successfully converted QuadratureProduct.
+    /// * `Err(StruqtureError::FromStringFailed)` - The string is not a standard ordered product of quadratures preceded by unsigned integer modes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "I" {
+            return Ok(Self::new()); // If the string is identity then it's an empty QuadratureProduct
+        }
+        let mut qp = QuadratureProduct::new();
+        let mut index = String::new();
+        for character in s.chars() {
+            if character.is_ascii_digit() {
+                index.push(character);
+                continue;
+            }
+            let mode: usize = index
+                .parse()
+                .map_err(|_| StruqtureError::FromStringFailed {
+                    msg: format!("Using {} instead of unsigned integer as mode index", index),
+                })?;
+            let (x_power, p_power) = qp.powers(mode);
+            qp = match character {
+                'X' if p_power == 0 => qp.set_powers(mode, x_power + 1, p_power),
+                'X' => {
+                    return Err(StruqtureError::FromStringFailed {
+                        msg: format!(
+                            "Position quadrature of mode {} follows a momentum quadrature, the product is not standard ordered",
+                            mode
+                        ),
+                    })
+                }
+                'P' => qp.set_powers(mode, x_power, p_power + 1),
+                _ => {
+                    return Err(StruqtureError::FromStringFailed {
+                        msg: format!("Used operator {} that is not X or P", character),
+                    })
+                }
+            };
+            index.clear();
+        }
+        if index.is_empty() {
+            Ok(qp)
+        } else {
+            Err(StruqtureError::FromStringFailed {
+                msg: format!("Mode index {} is not followed by a quadrature", index),
+            })
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of QuadratureProduct.
+///
+impl fmt::Display for QuadratureProduct {
+    /// Formats the QuadratureProduct using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted QuadratureProduct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string: String = String::new();
+        if self.powers.is_empty() {
+            string.push('I');
+        } else {
+            for (mode, x_power, p_power) in self.powers.iter() {
+                for _ in 0..*x_power {
+                    string.push_str(format!("{}X", mode).as_str());
+                }
+                for _ in 0..*p_power {
+                    string.push_str(format!("{}P", mode).as_str());
+                }
+            }
+        }
+        write!(f, "{}", string)
+    }
+}
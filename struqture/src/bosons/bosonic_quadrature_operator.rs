@@ -0,0 +1,744 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{BosonOperator, BosonProduct, QuadratureProduct};
+use crate::{
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy,
+};
+use itertools::Itertools;
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::f64::consts::FRAC_1_SQRT_2;
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::{Entry, Iter, Keys, Values};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::IndexMap;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::{Entry, Iter, Keys, Values};
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::HashMap;
+
+/// BosonQuadratureOperators are combinations of QuadratureProducts with specific CalculatorComplex coefficients.
+///
+/// This is the representation of bosonic operators in the basis of the position quadratures
+/// $ x_i = (b_i + b_i^{\dagger}) / \sqrt{2} $ and momentum quadratures $ p_i = i (b_i^{\dagger} - b_i) / \sqrt{2} $.
+/// BosonQuadratureOperators can be converted exactly from and into BosonOperators, the ordering of the operators
+/// is handled by the conversions and by the multiplication.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::bosons::{BosonOperator, BosonQuadratureOperator, QuadratureProduct};
+///
+/// // Representing the harmonic oscillator $ (x_0^2 + p_0^2) / 2 $
+/// let mut bqo = BosonQuadratureOperator::new();
+/// bqo.add_operator_product(QuadratureProduct::new().x(0).x(0), CalculatorComplex::from(0.5)).unwrap();
+/// bqo.add_operator_product(QuadratureProduct::new().p(0).p(0), CalculatorComplex::from(0.5)).unwrap();
+///
+/// // which is $ b_0^{\dagger} b_0 + 1/2 $
+/// let bo = BosonOperator::from(bqo).truncate(1e-12);
+/// assert_eq!(bo.len(), 2);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "BosonQuadratureOperatorSerialize")]
+#[serde(into = "BosonQuadratureOperatorSerialize")]
+pub struct BosonQuadratureOperator {
+    /// The internal HashMap of QuadratureProducts and coefficients (CalculatorComplex)
+    #[cfg(feature = "indexed_map_iterators")]
+    internal_map: IndexMap<QuadratureProduct, CalculatorComplex>,
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    internal_map: HashMap<QuadratureProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
+}
+
+impl crate::MinSupportedVersion for BosonQuadratureOperator {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for BosonQuadratureOperator {
+    fn schema_name() -> String {
+        "BosonQuadratureOperator".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <BosonQuadratureOperatorSerialize>::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct BosonQuadratureOperatorSerialize {
+    items: Vec<(QuadratureProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
+    _struqture_version: StruqtureVersionSerializable,
+}
+
+impl Serialize for BosonQuadratureOperatorSerialize {
+    /// Serialization function for BosonQuadratureOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonQuadratureOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonQuadratureOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "BosonQuadratureOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
+impl From<BosonQuadratureOperatorSerialize> for BosonQuadratureOperator {
+    fn from(value: BosonQuadratureOperatorSerialize) -> Self {
+        let mut new_operator: BosonQuadratureOperator = value
+            .items
+            .into_iter()
+            .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
+            .collect();
+        new_operator.zero_term_policy = value.zero_term_policy;
+        new_operator
+    }
+}
+
+impl From<BosonQuadratureOperator> for BosonQuadratureOperatorSerialize {
+    fn from(value: BosonQuadratureOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
+        let new_operator: Vec<(QuadratureProduct, CalculatorFloat, CalculatorFloat)> = value
+            .into_iter()
+            .map(|(key, val)| (key, val.re, val.im))
+            .collect();
+        let current_version = StruqtureVersionSerializable {
+            major_version: 1,
+            minor_version: 8,
+        };
+        Self {
+            items: new_operator,
+            zero_term_policy,
+            _struqture_version: current_version,
+        }
+    }
+}
+
+impl crate::SerializeCanonical for BosonQuadratureOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = BosonQuadratureOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for BosonQuadratureOperator {
+    type Index = QuadratureProduct;
+    type Value = CalculatorComplex;
+    type IteratorType = Iter<'a, Self::Index, Self::Value>;
+    type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
+    type ValueIteratorType = Values<'a, Self::Index, Self::Value>;
+
+    // From trait
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> Self::IteratorType {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> Self::KeyIteratorType {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> Self::ValueIteratorType {
+        self.internal_map.values()
+    }
+
+    #[cfg(feature = "indexed_map_iterators")]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.shift_remove(key)
+    }
+
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.remove(key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        let mut empty_clone = match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the BosonQuadratureOperator with the given (QuadratureProduct key, CalculatorComplex value) pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The QuadratureProduct key to set in the BosonQuadratureOperator.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the BosonQuadratureOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        if !self.zero_term_policy.drops(&value) {
+            Ok(self.internal_map.insert(key, value))
+        } else {
+            match self.internal_map.entry(key) {
+                #[cfg(feature = "indexed_map_iterators")]
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                #[cfg(not(feature = "indexed_map_iterators"))]
+                Entry::Occupied(val) => Ok(Some(val.remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+}
+
+impl<'a> OperateOnModes<'a> for BosonQuadratureOperator {
+    // From trait
+    fn current_number_modes(&'a self) -> usize {
+        self.keys()
+            .map(|key| key.current_number_modes())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Gets the maximum index of the BosonQuadratureOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of bosons in the BosonQuadratureOperator.
+    fn number_modes(&'a self) -> usize {
+        self.current_number_modes()
+    }
+}
+
+/// Implements the default function (Default trait) of BosonQuadratureOperator (an empty BosonQuadratureOperator).
+///
+impl Default for BosonQuadratureOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the BosonQuadratureOperator
+///
+impl BosonQuadratureOperator {
+    /// Creates a new BosonQuadratureOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) BosonQuadratureOperator.
+    pub fn new() -> Self {
+        BosonQuadratureOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::new(),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Creates a new BosonQuadratureOperator with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the operator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) BosonQuadratureOperator.
+    pub fn with_capacity(capacity: usize) -> Self {
+        BosonQuadratureOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::with_capacity(capacity),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Returns the hermitian conjugate of the BosonQuadratureOperator in standard ordering.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian conjugate of Self.
+    pub fn hermitian_conjugate(&self) -> Self {
+        let mut conjugated = Self::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            // (x^a p^b)^dagger = p^b x^a on every mode, which is brought into standard ordering
+            let (momenta, positions) = product.iter().fold(
+                (QuadratureProduct::new(), QuadratureProduct::new()),
+                |(momenta, positions), (mode, x_power, p_power)| {
+                    (
+                        momenta.set_powers(*mode, 0, *p_power),
+                        positions.set_powers(*mode, *x_power, 0),
+                    )
+                },
+            );
+            for (ordered_product, prefactor) in QuadratureProduct::multiply(momenta, positions) {
+                conjugated
+                    .add_operator_product(ordered_product, value.conj() * prefactor)
+                    .expect("Internal error in add_operator_product");
+            }
+        }
+        conjugated
+    }
+
+    /// Returns the terms of a standard ordered product of quadratures in the unscaled ladder operators.
+    ///
+    /// The unscaled quadratures are $ \sqrt{2} x = b + b^{\dagger} $ and $ \sqrt{2} p = i (b^{\dagger} - b) $,
+    /// which keeps the coefficients of the normal ordering exact.
+    fn unscaled_boson_operator(product: &QuadratureProduct) -> BosonOperator {
+        let ladder_sum =
+            |mode: usize, creator: CalculatorComplex, annihilator: CalculatorComplex| {
+                let ladder_terms: BosonOperator = [
+                    (
+                        BosonProduct::new([mode], []).expect("Internal bug in BosonProduct::new"),
+                        creator,
+                    ),
+                    (
+                        BosonProduct::new([], [mode]).expect("Internal bug in BosonProduct::new"),
+                        annihilator,
+                    ),
+                ]
+                .into_iter()
+                .collect();
+                ladder_terms
+            };
+        let mut boson_operator: BosonOperator = [(
+            BosonProduct::new([], []).expect("Internal bug in BosonProduct::new"),
+            CalculatorComplex::from(1.0),
+        )]
+        .into_iter()
+        .collect();
+        for (mode, x_power, p_power) in product.iter() {
+            let position = ladder_sum(
+                *mode,
+                CalculatorComplex::from(1.0),
+                CalculatorComplex::from(1.0),
+            );
+            let momentum = ladder_sum(
+                *mode,
+                CalculatorComplex::new(0.0, 1.0),
+                CalculatorComplex::new(0.0, -1.0),
+            );
+            for _ in 0..*x_power {
+                boson_operator = boson_operator * position.clone();
+            }
+            for _ in 0..*p_power {
+                boson_operator = boson_operator * momentum.clone();
+            }
+        }
+        boson_operator
+    }
+}
+
+/// Returns the factor $ 2^{-n/2} $ relating products of n unscaled operators to the quadratures or ladder operators.
+fn sqrt_2_scaling(number_operators: usize) -> f64 {
+    let scaling = 0.5_f64.powi((number_operators / 2) as i32);
+    if number_operators % 2 == 1 {
+        scaling * FRAC_1_SQRT_2
+    } else {
+        scaling
+    }
+}
+
+impl From<BosonOperator> for BosonQuadratureOperator {
+    /// Converts a BosonOperator into a BosonQuadratureOperator.
+    ///
+    /// The ladder operators are replaced by $ b^{\dagger} = (x - i p) / \sqrt{2} $ and $ b = (x + i p) / \sqrt{2} $
+    /// and the resulting products are brought into standard ordering.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The BosonOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonOperator converted into a BosonQuadratureOperator.
+    fn from(value: BosonOperator) -> Self {
+        let mut new_operator = BosonQuadratureOperator::with_capacity(value.len());
+        for (boson_product, val) in value.into_iter() {
+            // Unscaled factors sqrt(2) b^dagger = x - i p and sqrt(2) b = x + i p
+            let factors = boson_product
+                .creators()
+                .map(|mode| (*mode, Complex64::new(0.0, -1.0)))
+                .chain(
+                    boson_product
+                        .annihilators()
+                        .map(|mode| (*mode, Complex64::new(0.0, 1.0))),
+                );
+            let mut terms: BTreeMap<QuadratureProduct, Complex64> = BTreeMap::new();
+            terms.insert(QuadratureProduct::new(), Complex64::new(1.0, 0.0));
+            for (mode, momentum_prefactor) in factors {
+                let mut new_terms: BTreeMap<QuadratureProduct, Complex64> = BTreeMap::new();
+                for (product, prefactor) in terms.into_iter() {
+                    for (factor, factor_prefactor) in [
+                        (QuadratureProduct::new().x(mode), Complex64::new(1.0, 0.0)),
+                        (QuadratureProduct::new().p(mode), momentum_prefactor),
+                    ] {
+                        for (new_product, new_prefactor) in
+                            QuadratureProduct::multiply(product.clone(), factor)
+                        {
+                            *new_terms
+                                .entry(new_product)
+                                .or_insert(Complex64::new(0.0, 0.0)) +=
+                                prefactor * factor_prefactor * new_prefactor;
+                        }
+                    }
+                }
+                terms = new_terms;
+            }
+            let scaling = sqrt_2_scaling(
+                boson_product.number_creators() + boson_product.number_annihilators(),
+            );
+            for (product, prefactor) in terms.into_iter() {
+                if prefactor != Complex64::new(0.0, 0.0) {
+                    new_operator
+                        .add_operator_product(product, val.clone() * (prefactor * scaling))
+                        .expect("Internal error in add_operator_product");
+                }
+            }
+        }
+        new_operator
+    }
+}
+
+impl From<BosonQuadratureOperator> for BosonOperator {
+    /// Converts a BosonQuadratureOperator into a normal ordered BosonOperator.
+    ///
+    /// The quadratures are replaced by $ x = (b + b^{\dagger}) / \sqrt{2} $ and $ p = i (b^{\dagger} - b) / \sqrt{2} $.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The BosonQuadratureOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonQuadratureOperator converted into a BosonOperator.
+    fn from(value: BosonQuadratureOperator) -> Self {
+        let mut new_operator = BosonOperator::with_capacity(value.len());
+        for (product, val) in value.into_iter() {
+            let scaling = sqrt_2_scaling(product.degree());
+            for (boson_product, prefactor) in
+                BosonQuadratureOperator::unscaled_boson_operator(&product).into_iter()
+            {
+                new_operator
+                    .add_operator_product(boson_product, val.clone() * prefactor * scaling)
+                    .expect("Internal error in add_operator_product");
+            }
+        }
+        new_operator
+    }
+}
+
+/// Implements the negative sign function of BosonQuadratureOperator.
+///
+impl ops::Neg for BosonQuadratureOperator {
+    type Output = BosonQuadratureOperator;
+    /// Implement minus sign for BosonQuadratureOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonQuadratureOperator * -1.
+    fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        let mut internal = self.internal_map.clone();
+        for key in self.keys() {
+            internal.insert(key.clone(), internal[key].clone() * -1.0);
+        }
+        BosonQuadratureOperator {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the plus function of BosonQuadratureOperator by BosonQuadratureOperator.
+///
+impl ops::Add<BosonQuadratureOperator> for BosonQuadratureOperator {
+    type Output = Self;
+    /// Implements `+` (add) for two BosonQuadratureOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonQuadratureOperator to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two BosonQuadratureOperators added together.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add(mut self, other: BosonQuadratureOperator) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, value)
+                .expect("Internal error in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the minus function of BosonQuadratureOperator by BosonQuadratureOperator.
+///
+impl ops::Sub<BosonQuadratureOperator> for BosonQuadratureOperator {
+    type Output = Self;
+    /// Implements `-` (subtract) for two BosonQuadratureOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonQuadratureOperator to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two BosonQuadratureOperators subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub(mut self, other: BosonQuadratureOperator) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, value * -1.0)
+                .expect("Internal error in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the multiplication function of BosonQuadratureOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::Mul<T> for BosonQuadratureOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implement `*` for BosonQuadratureOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The BosonQuadratureOperator multiplied by the CalculatorComplex/CalculatorFloat.
+    fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        let mut internal = self.internal_map.clone();
+        for key in self.keys() {
+            internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
+        }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
+        BosonQuadratureOperator {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the multiplication function of BosonQuadratureOperator by BosonQuadratureOperator.
+///
+impl ops::Mul<BosonQuadratureOperator> for BosonQuadratureOperator {
+    type Output = Self;
+    /// Implement `*` for BosonQuadratureOperator and BosonQuadratureOperator, bringing the products into standard ordering.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The BosonQuadratureOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two BosonQuadratureOperators multiplied.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn mul(self, other: BosonQuadratureOperator) -> Self {
+        let mut quadrature_op = BosonQuadratureOperator::new();
+        quadrature_op.zero_term_policy = self.zero_term_policy;
+        for ((left_key, left_val), (right_key, right_val)) in
+            self.into_iter().cartesian_product(other.iter())
+        {
+            let product = left_val.clone() * right_val;
+            for (product_key, prefactor) in QuadratureProduct::multiply(left_key, right_key.clone())
+            {
+                quadrature_op
+                    .add_operator_product(product_key, product.clone() * prefactor)
+                    .expect("Internal error in add_operator_product");
+            }
+        }
+        quadrature_op
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of BosonQuadratureOperator.
+///
+impl IntoIterator for BosonQuadratureOperator {
+    type Item = (QuadratureProduct, CalculatorComplex);
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    type IntoIter = std::collections::hash_map::IntoIter<QuadratureProduct, CalculatorComplex>;
+    #[cfg(feature = "indexed_map_iterators")]
+    type IntoIter = indexmap::map::IntoIter<QuadratureProduct, CalculatorComplex>;
+    /// Returns the BosonQuadratureOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The BosonQuadratureOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference BosonQuadratureOperator.
+///
+impl<'a> IntoIterator for &'a BosonQuadratureOperator {
+    type Item = (&'a QuadratureProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, QuadratureProduct, CalculatorComplex>;
+
+    /// Returns the reference BosonQuadratureOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference BosonQuadratureOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of BosonQuadratureOperator.
+///
+impl FromIterator<(QuadratureProduct, CalculatorComplex)> for BosonQuadratureOperator {
+    /// Returns the object in BosonQuadratureOperator form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the BosonQuadratureOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in BosonQuadratureOperator form.
+    fn from_iter<I: IntoIterator<Item = (QuadratureProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut bqo = BosonQuadratureOperator::new();
+        for (qp, cc) in iter {
+            bqo.add_operator_product(qp, cc)
+                .expect("Internal error in add_operator_product");
+        }
+        bqo
+    }
+}
+
+/// Implements the extend function (Extend trait) of BosonQuadratureOperator.
+///
+impl Extend<(QuadratureProduct, CalculatorComplex)> for BosonQuadratureOperator {
+    /// Extends the BosonQuadratureOperator by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the BosonQuadratureOperator.
+    fn extend<I: IntoIterator<Item = (QuadratureProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (qp, cc) in iter {
+            self.add_operator_product(qp, cc)
+                .expect("Internal error in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of BosonQuadratureOperator.
+///
+impl fmt::Display for BosonQuadratureOperator {
+    /// Formats the BosonQuadratureOperator using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted BosonQuadratureOperator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "BosonQuadratureOperator{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
@@ -12,9 +12,17 @@
 
 use super::{BosonOperator, OperateOnBosons};
 use crate::bosons::BosonProduct;
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use crate::metadata::merge_operand_metadata;
+use crate::{
+    canonical_number, canonical_text_document, canonical_text_entries, hilbert_space_dimension,
+    parse_canonical_number, parse_canonical_text_entries, sparse_matrix_memory,
+    split_canonical_text, CanonicalText, ModeIndex, OperateOnConstant, OperateOnDensityMatrix,
+    OperateOnModes, OperateOnState, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -49,7 +57,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(bo.get(&bp_1), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct BosonSystem {
@@ -57,10 +65,88 @@ pub struct BosonSystem {
     pub(crate) number_modes: Option<usize>,
     /// The BosonOperator representing the operator of the BosonSystem.
     pub(crate) operator: BosonOperator,
+    /// Provenance metadata of the BosonSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for BosonSystem {}
 
+impl Serialize for BosonSystem {
+    /// Serialization function for BosonSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - BosonSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of BosonSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for BosonSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a BosonSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - BosonSystem to be serialized.
+/// * `operator` - Serialization of the operator of the BosonSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of BosonSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &BosonSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("BosonSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for BosonSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for BosonSystem {
     type Index = BosonProduct;
 
@@ -99,15 +185,27 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                operator: BosonOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                operator: BosonOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the BosonSystem with the given (BosonProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -168,6 +266,13 @@ impl<'a> OperateOnDensityMatrix<'a> for BosonSystem {
 
 impl<'a> OperateOnState<'a> for BosonSystem {}
 
+impl<'a> OperateOnConstant<'a> for BosonSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        BosonProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for BosonSystem {
     /// Gets the number_modes input of the BosonSystem, or the current_number_modes if number_modes is None.
     ///
@@ -205,6 +310,7 @@ impl BosonSystem {
         BosonSystem {
             number_modes,
             operator: BosonOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -222,6 +328,7 @@ impl BosonSystem {
         BosonSystem {
             number_modes,
             operator: BosonOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -255,6 +362,7 @@ impl BosonSystem {
                     Ok(BosonSystem {
                         number_modes: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -263,6 +371,7 @@ impl BosonSystem {
             None => Ok(BosonSystem {
                 number_modes: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -312,7 +421,7 @@ impl ops::Neg for BosonSystem {
 ///
 impl<T, V> ops::Add<T> for BosonSystem
 where
-    T: IntoIterator<Item = (BosonProduct, V)>,
+    T: IntoIterator<Item = (BosonProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -327,6 +436,7 @@ where
     /// * `Ok(Self)` - The two BosonSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of BosonProduct exceeds that of the BosonSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -338,7 +448,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for BosonSystem
 where
-    T: IntoIterator<Item = (BosonProduct, V)>,
+    T: IntoIterator<Item = (BosonProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -353,6 +463,7 @@ where
     /// * `Ok(Self)` - The two BosonSystems subtracted together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of BosonProduct exceeds that of the BosonSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -400,9 +511,12 @@ impl ops::Mul<BosonSystem> for BosonSystem {
     ///
     /// * Internal error in add_operator_product.
     fn mul(self, other: BosonSystem) -> Self {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<BosonSystem>(&mut metadata, &other);
         BosonSystem {
             number_modes: Some(self.number_modes().max(other.number_modes())),
             operator: self.operator * other.operator,
+            metadata,
         }
     }
 }
@@ -509,3 +623,79 @@ impl fmt::Display for BosonSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for BosonSystem {
+    /// Returns the canonical text representation of the BosonSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the BosonSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "BosonSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a BosonSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The BosonSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a BosonSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the BosonSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(text, "BosonSystem", &["number_modes"])?;
+        let mut new_self = BosonSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in parse_canonical_text_entries::<BosonProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SystemDimension for BosonSystem {
+    /// Returns the dimension of the Hilbert space of the BosonSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(0, self.number_modes(), fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the BosonSystem.
+    ///
+    /// Every term of the BosonSystem has at most one non-zero entry per row of its sparse matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the BosonSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(dimension, self.len(), bytes_per_entry)
+    }
+}
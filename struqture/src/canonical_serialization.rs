@@ -0,0 +1,101 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialization of struqture objects with the items of all operators sorted by their keys.
+
+#[cfg(feature = "io")]
+use crate::StruqtureError;
+use serde::{Serialize, Serializer};
+
+/// Serialization of struqture objects with the items of all operators sorted by their keys,
+/// so that equal objects always produce identical output.
+///
+/// With the `io` feature, `to_json_canonical` and `to_bincode_canonical` serialize Self canonically to JSON and bincode.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let mut first = SpinOperator::new();
+/// first.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.5)).unwrap();
+/// first.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0)).unwrap();
+/// let mut second = SpinOperator::new();
+/// second.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0)).unwrap();
+/// second.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.5)).unwrap();
+///
+/// let mut first_json = Vec::new();
+/// first.serialize_canonical(&mut serde_json::Serializer::new(&mut first_json)).unwrap();
+/// let mut second_json = Vec::new();
+/// second.serialize_canonical(&mut serde_json::Serializer::new(&mut second_json)).unwrap();
+/// assert_eq!(first_json, second_json);
+/// ```
+pub trait SerializeCanonical: Serialize {
+    /// Serializes Self with the items of all operators sorted by their keys.
+    ///
+    /// Objects without operator items, like products, are serialized unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Canonical serialization of Self.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    /// Serializes Self to compact JSON with the items of all operators sorted by their keys.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The canonical JSON representation of Self.
+    /// * `Err(StruqtureError::SerializationError)` - Self could not be serialized.
+    #[cfg(feature = "io")]
+    fn to_json_canonical(&self) -> Result<String, StruqtureError> {
+        serde_json::to_string(&Canonical(self)).map_err(|err| StruqtureError::SerializationError {
+            format: "json".to_string(),
+            msg: err.to_string(),
+        })
+    }
+
+    /// Serializes Self to bincode with the items of all operators sorted by their keys.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The canonical bincode representation of Self.
+    /// * `Err(StruqtureError::SerializationError)` - Self could not be serialized.
+    #[cfg(feature = "io")]
+    fn to_bincode_canonical(&self) -> Result<Vec<u8>, StruqtureError> {
+        bincode::serialize(&Canonical(self)).map_err(|err| StruqtureError::SerializationError {
+            format: "bincode".to_string(),
+            msg: err.to_string(),
+        })
+    }
+}
+
+/// Serializes the wrapped object with [SerializeCanonical::serialize_canonical].
+///
+/// Used to serialize the operators contained in systems and open systems canonically.
+pub(crate) struct Canonical<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<T: SerializeCanonical + ?Sized> Serialize for Canonical<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_canonical(serializer)
+    }
+}
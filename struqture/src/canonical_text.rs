@@ -0,0 +1,351 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic, line-per-term plain-text representation of struqture objects.
+
+use crate::StruqtureError;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::str::FromStr;
+use tinyvec::TinyVec;
+
+/// Trait for a deterministic, line-per-term plain-text representation of struqture objects.
+///
+/// The canonical text is intended for committing model definitions to version control.
+/// The first line holds the type name, followed by header lines (e.g. `number_spins\t3`) and
+/// one tab-separated line per term. Terms are sorted, so the text does not depend on the
+/// insertion order, and floats are written in their shortest round-trip representation.
+/// Empty lines and lines starting with `#` are ignored when parsing.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let mut so = SpinOperator::new();
+/// so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(0.5, 0.0)).unwrap();
+/// so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from("theta")).unwrap();
+///
+/// let text = so.to_canonical_text();
+/// assert_eq!(text, "SpinOperator\n0X\ttheta\t0.0\n1Z\t0.5\t0.0\n");
+/// assert_eq!(SpinOperator::from_canonical_text(&text).unwrap(), so);
+/// ```
+pub trait CanonicalText: Sized {
+    /// Returns the canonical text representation of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of Self.
+    fn to_canonical_text(&self) -> String;
+
+    /// Creates Self from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The object represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of Self.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to Self.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError>;
+}
+
+/// Trait for formatting and parsing coefficients in the canonical text representation.
+pub(crate) trait CanonicalTextValue: Sized {
+    /// Returns the tab-separated fields representing the coefficient.
+    fn to_canonical_fields(&self) -> String;
+
+    /// Parses the coefficient from its fields.
+    fn from_canonical_fields(fields: &[&str]) -> Result<Self, StruqtureError>;
+}
+
+/// Formats a CalculatorFloat for the canonical text, normalizing negative zero.
+fn canonical_float(value: &CalculatorFloat) -> String {
+    match value {
+        CalculatorFloat::Float(x) if *x == 0.0 => "0.0".to_string(),
+        CalculatorFloat::Float(x) => format!("{:?}", x),
+        CalculatorFloat::Str(x) => x.clone(),
+    }
+}
+
+/// Parses a CalculatorFloat from the canonical text, any field that is not a number is symbolic.
+fn parse_canonical_float(field: &str) -> Result<CalculatorFloat, StruqtureError> {
+    if field.is_empty() {
+        return Err(StruqtureError::FromStringFailed {
+            msg: "Empty coefficient in canonical text".to_string(),
+        });
+    }
+    Ok(match f64::from_str(field) {
+        Ok(x) => CalculatorFloat::Float(x),
+        Err(_) => CalculatorFloat::Str(field.to_string()),
+    })
+}
+
+impl CanonicalTextValue for CalculatorFloat {
+    fn to_canonical_fields(&self) -> String {
+        canonical_float(self)
+    }
+
+    fn from_canonical_fields(fields: &[&str]) -> Result<Self, StruqtureError> {
+        match fields {
+            [value] => parse_canonical_float(value),
+            _ => Err(StruqtureError::FromStringFailed {
+                msg: format!("Expected one coefficient field, found {}", fields.len()),
+            }),
+        }
+    }
+}
+
+impl CanonicalTextValue for CalculatorComplex {
+    fn to_canonical_fields(&self) -> String {
+        format!(
+            "{}\t{}",
+            canonical_float(&self.re),
+            canonical_float(&self.im)
+        )
+    }
+
+    fn from_canonical_fields(fields: &[&str]) -> Result<Self, StruqtureError> {
+        match fields {
+            [re, im] => Ok(CalculatorComplex::new(
+                parse_canonical_float(re)?,
+                parse_canonical_float(im)?,
+            )),
+            _ => Err(StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Expected real and imaginary coefficient fields, found {} fields",
+                    fields.len()
+                ),
+            }),
+        }
+    }
+}
+
+/// Formats an optional number of spins or modes for a canonical text header.
+pub(crate) fn canonical_number(number: &Option<usize>) -> String {
+    match number {
+        Some(x) => x.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+/// Parses an optional number of spins or modes from a canonical text header.
+pub(crate) fn parse_canonical_number(field: &str) -> Result<Option<usize>, StruqtureError> {
+    match field {
+        "none" => Ok(None),
+        _ => usize::from_str(field)
+            .map(Some)
+            .map_err(|_| StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Using {} instead of unsigned integer or none as number",
+                    field
+                ),
+            }),
+    }
+}
+
+/// Formats a list of optional numbers of spins or modes for a canonical text header.
+pub(crate) fn canonical_numbers(numbers: &[Option<usize>]) -> String {
+    numbers
+        .iter()
+        .map(canonical_number)
+        .collect::<Vec<String>>()
+        .join("\t")
+}
+
+/// Parses a tab-separated list of optional numbers of spins or modes from a canonical text header.
+pub(crate) fn parse_canonical_numbers(
+    fields: &str,
+) -> Result<TinyVec<[Option<usize>; 2]>, StruqtureError> {
+    fields
+        .split('\t')
+        .filter(|field| !field.is_empty())
+        .map(parse_canonical_number)
+        .collect()
+}
+
+/// Parses a number of subsystems from a canonical text header.
+pub(crate) fn parse_canonical_count(field: &str) -> Result<usize, StruqtureError> {
+    usize::from_str(field).map_err(|_| StruqtureError::FromStringFailed {
+        msg: format!("Using {} instead of unsigned integer as count", field),
+    })
+}
+
+/// Returns the sorted canonical text lines of the terms of an operator.
+pub(crate) fn canonical_text_entries<'b, I, V>(
+    entries: impl Iterator<Item = (&'b I, &'b V)>,
+) -> Vec<String>
+where
+    I: std::fmt::Display + 'b,
+    V: CanonicalTextValue + 'b,
+{
+    let mut lines: Vec<String> = entries
+        .map(|(key, value)| format!("{}\t{}", key, value.to_canonical_fields()))
+        .collect();
+    lines.sort();
+    lines
+}
+
+/// Returns the sorted canonical text lines of the terms of a noise operator.
+pub(crate) fn canonical_noise_text_entries<'b, P, V>(
+    entries: impl Iterator<Item = (&'b (P, P), &'b V)>,
+) -> Vec<String>
+where
+    P: std::fmt::Display + 'b,
+    V: CanonicalTextValue + 'b,
+{
+    let mut lines: Vec<String> = entries
+        .map(|((left, right), value)| {
+            format!("{}\t{}\t{}", left, right, value.to_canonical_fields())
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+/// Parses the terms of an operator from canonical text lines.
+pub(crate) fn parse_canonical_text_entries<I, V>(
+    lines: &[&str],
+) -> Result<Vec<(I, V)>, StruqtureError>
+where
+    I: FromStr<Err = StruqtureError>,
+    V: CanonicalTextValue,
+{
+    lines
+        .iter()
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let key = I::from_str(fields[0])?;
+            let value = V::from_canonical_fields(&fields[1..])?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Parses the terms of a noise operator from canonical text lines.
+pub(crate) fn parse_canonical_noise_text_entries<P, V>(
+    lines: &[&str],
+) -> Result<Vec<((P, P), V)>, StruqtureError>
+where
+    P: FromStr<Err = StruqtureError>,
+    V: CanonicalTextValue,
+{
+    lines
+        .iter()
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 3 {
+                return Err(StruqtureError::FromStringFailed {
+                    msg: format!("Noise term {} is missing fields", line),
+                });
+            }
+            let left = P::from_str(fields[0])?;
+            let right = P::from_str(fields[1])?;
+            let value = V::from_canonical_fields(&fields[2..])?;
+            Ok(((left, right), value))
+        })
+        .collect()
+}
+
+/// Assembles a canonical text from the type name, the header lines and the term lines.
+pub(crate) fn canonical_text_document(
+    type_name: &str,
+    header: &[(&str, String)],
+    lines: &[String],
+) -> String {
+    let mut text = format!("{}\n", type_name);
+    for (name, value) in header {
+        if value.is_empty() {
+            text.push_str(&format!("{}\n", name));
+        } else {
+            text.push_str(&format!("{}\t{}\n", name, value));
+        }
+    }
+    for line in lines {
+        text.push_str(line);
+        text.push('\n');
+    }
+    text
+}
+
+/// Splits a canonical text into the values of the expected header lines and the term lines.
+///
+/// # Arguments
+///
+/// * `text` - The canonical text to split.
+/// * `type_name` - The type name expected in the first line.
+/// * `header_names` - The names of the header lines expected after the type name, in order.
+///
+/// # Returns
+///
+/// * `Ok((Vec<&str>, Vec<&str>))` - The values of the header lines and the term lines.
+/// * `Err(StruqtureError::FromStringFailed)` - The type name or a header line does not match.
+pub(crate) fn split_canonical_text<'t>(
+    text: &'t str,
+    type_name: &str,
+    header_names: &[&str],
+) -> Result<(Vec<&'t str>, Vec<&'t str>), StruqtureError> {
+    let mut lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+    match lines.next() {
+        Some(line) if line.trim() == type_name => (),
+        other => {
+            return Err(StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Expected canonical text of {}, found {}",
+                    type_name,
+                    other.unwrap_or("empty text")
+                ),
+            })
+        }
+    }
+    let mut header: Vec<&'t str> = Vec::with_capacity(header_names.len());
+    for name in header_names {
+        let line = lines.next().unwrap_or_default();
+        let (found_name, value) = line.split_once('\t').unwrap_or((line, ""));
+        if found_name != *name {
+            return Err(StruqtureError::FromStringFailed {
+                msg: format!("Expected header {} in canonical text, found {}", name, line),
+            });
+        }
+        header.push(value);
+    }
+    Ok((header, lines.collect()))
+}
+
+/// Splits the canonical text of an open system into the canonical texts of its system and noise.
+///
+/// # Arguments
+///
+/// * `text` - The canonical text to split.
+/// * `type_name` - The type name of the open system expected in the first line.
+/// * `noise_type_name` - The type name of the noise system starting the noise part.
+///
+/// # Returns
+///
+/// * `Ok((String, String))` - The canonical texts of the system and the noise.
+/// * `Err(StruqtureError::FromStringFailed)` - The type name does not match.
+pub(crate) fn split_canonical_open_system(
+    text: &str,
+    type_name: &str,
+    noise_type_name: &str,
+) -> Result<(String, String), StruqtureError> {
+    let (_, lines) = split_canonical_text(text, type_name, &[])?;
+    let split = lines
+        .iter()
+        .position(|line| line.trim() == noise_type_name)
+        .unwrap_or(lines.len());
+    Ok((lines[..split].join("\n"), lines[split..].join("\n")))
+}
@@ -0,0 +1,136 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mutable handles to the coefficients of operators returned by the `entry` functions.
+
+use crate::{AbsoluteValueTrait, ZeroTermPolicy};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::Entry as MapEntry;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::Entry as MapEntry;
+
+/// Mutable handle to the coefficient of a key in an operator, returned by the `entry` functions of the operators.
+///
+/// The handle dereferences to the coefficient, which is zero for keys that are not yet in the operator.
+/// When the handle is dropped, the term is removed (or not inserted) if its coefficient is dropped under the [ZeroTermPolicy]
+/// of the operator, unless another policy is set with [CoefficientEntry::with_zero_term_policy].
+/// Updating a coefficient through the handle hashes the key only once and does not clone the coefficient.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let mut so = SpinOperator::new();
+/// *so.entry(PauliProduct::new().z(0)) += CalculatorComplex::from(0.5);
+/// assert_eq!(so.get(&PauliProduct::new().z(0)), &CalculatorComplex::from(0.5));
+///
+/// *so.entry(PauliProduct::new().z(0)) -= CalculatorComplex::from(0.5);
+/// assert!(so.is_empty());
+/// ```
+pub struct CoefficientEntry<'m, K, V: AbsoluteValueTrait> {
+    /// The entry of the key in the internal map of the operator.
+    entry: Option<MapEntry<'m, K, V>>,
+    /// The coefficient of a key that is not yet in the operator.
+    vacant_value: Option<V>,
+    /// The policy deciding whether the term is dropped when the handle is dropped.
+    policy: ZeroTermPolicy,
+}
+
+impl<'m, K, V: AbsoluteValueTrait> CoefficientEntry<'m, K, V> {
+    /// Creates a new CoefficientEntry.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The entry of the key in the internal map of the operator.
+    /// * `zero` - The zero coefficient used for keys that are not yet in the operator.
+    /// * `policy` - The ZeroTermPolicy of the operator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new CoefficientEntry.
+    pub(crate) fn new(entry: MapEntry<'m, K, V>, zero: V, policy: ZeroTermPolicy) -> Self {
+        let vacant_value = match entry {
+            MapEntry::Occupied(_) => None,
+            MapEntry::Vacant(_) => Some(zero),
+        };
+        Self {
+            entry: Some(entry),
+            vacant_value,
+            policy,
+        }
+    }
+
+    /// Sets the policy deciding whether the term is dropped when the handle is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The ZeroTermPolicy applied to the final coefficient.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The handle using the policy.
+    pub fn with_zero_term_policy(mut self, policy: ZeroTermPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<'m, K, V: AbsoluteValueTrait> std::ops::Deref for CoefficientEntry<'m, K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        match &self.entry {
+            Some(MapEntry::Occupied(occupied)) => occupied.get(),
+            _ => self
+                .vacant_value
+                .as_ref()
+                .expect("Internal bug in CoefficientEntry"),
+        }
+    }
+}
+
+impl<'m, K, V: AbsoluteValueTrait> std::ops::DerefMut for CoefficientEntry<'m, K, V> {
+    fn deref_mut(&mut self) -> &mut V {
+        match &mut self.entry {
+            Some(MapEntry::Occupied(occupied)) => occupied.get_mut(),
+            _ => self
+                .vacant_value
+                .as_mut()
+                .expect("Internal bug in CoefficientEntry"),
+        }
+    }
+}
+
+impl<'m, K, V: AbsoluteValueTrait> Drop for CoefficientEntry<'m, K, V> {
+    fn drop(&mut self) {
+        match self.entry.take() {
+            Some(MapEntry::Occupied(occupied)) => {
+                if self.policy.drops(occupied.get()) {
+                    #[cfg(feature = "indexed_map_iterators")]
+                    let _ = occupied.shift_remove();
+                    #[cfg(not(feature = "indexed_map_iterators"))]
+                    let _ = occupied.remove();
+                }
+            }
+            Some(MapEntry::Vacant(vacant)) => {
+                if let Some(value) = self.vacant_value.take() {
+                    if !self.policy.drops(&value) {
+                        vacant.insert(value);
+                    }
+                }
+            }
+            None => (),
+        }
+    }
+}
@@ -13,14 +13,28 @@
 use super::{
     FermionOperator, FermionProduct, HermitianFermionProduct, ModeIndex, OperateOnFermions,
 };
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::linalg::hermitian_eigh;
+use crate::mappings::encoding::encode_fermion_hamiltonian;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
+use crate::qdrift::QDriftSampler;
 use crate::spins::SpinHamiltonian;
 use crate::{
-    GetValue, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, expression_from_terms,
+    hermitian_coefficient_1_norm, parse_canonical_text_entries, parse_expression_terms,
+    split_canonical_text, CanonicalText, GetValue, OperateOnConstant, OperateOnDensityMatrix,
+    OperateOnModes, OperateOnState, OperatorExpression, SpectralNormBound, StruqtureError,
+    StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
+use ndarray::Array2;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+use std::f64::consts::FRAC_1_SQRT_2;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -31,8 +45,9 @@ use indexmap::map::{Entry, Iter, Keys, Values};
 use indexmap::IndexMap;
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::hash_map::{Entry, Iter, Keys, Values};
-#[cfg(not(feature = "indexed_map_iterators"))]
-use std::collections::HashMap;
+
+/// Weights of the squares in `split_two_body_terms` below this fraction of the largest weight are dropped.
+const TWO_BODY_SPLITTING_TOLERANCE: f64 = 1e-12;
 
 /// FermionHamiltonians are combinations of FermionProducts with specific CalculatorComplex coefficients.
 ///
@@ -68,6 +83,8 @@ pub struct FermionHamiltonian {
     internal_map: IndexMap<HermitianFermionProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<HermitianFermionProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for FermionHamiltonian {}
@@ -83,27 +100,66 @@ impl schemars::JsonSchema for FermionHamiltonian {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct FermionHamiltonianSerialize {
     items: Vec<(HermitianFermionProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for FermionHamiltonianSerialize {
+    /// Serialization function for FermionHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "FermionHamiltonianSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<FermionHamiltonianSerialize> for FermionHamiltonian {
     fn from(value: FermionHamiltonianSerialize) -> Self {
-        let new_noise_op: FermionHamiltonian = value
+        let mut new_noise_op: FermionHamiltonian = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<FermionHamiltonian> for FermionHamiltonianSerialize {
     fn from(value: FermionHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(HermitianFermionProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -114,11 +170,21 @@ impl From<FermionHamiltonian> for FermionHamiltonianSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for FermionHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = FermionHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionHamiltonian {
     type Index = HermitianFermionProduct;
     type Value = CalculatorComplex;
@@ -163,10 +229,25 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionHamiltonian {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the FermionHamiltonian with the given (HermitianFermionProduct key, CalculatorComplex value) pair.
@@ -186,7 +267,7 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionHamiltonian {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value.re != CalculatorFloat::ZERO || value.im != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             // Catch on diagonals with non-zero imaginary values
             if key.is_natural_hermitian() && value.im != CalculatorFloat::ZERO {
                 Err(StruqtureError::NonHermitianOperator)
@@ -242,6 +323,13 @@ impl<'a> OperateOnState<'a> for FermionHamiltonian {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for FermionHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        HermitianFermionProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for FermionHamiltonian {
     /// Return maximum index in FermionHamiltonian internal_map.
     ///
@@ -294,6 +382,7 @@ impl FermionHamiltonian {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -312,7 +401,32 @@ impl FermionHamiltonian {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Creates the FermionHamiltonian counting the particles in the given modes, $\sum_i c_i^{\dagger} c_i$.
+    ///
+    /// Every mode that is given several times contributes its number operator several times.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes whose occupation is counted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The FermionHamiltonian with the number operator of every mode with coefficient one.
+    pub fn occupation_operator(modes: impl IntoIterator<Item = usize>) -> Self {
+        let mut new_operator = Self::new();
+        for mode in modes {
+            new_operator
+                .add_operator_product(
+                    HermitianFermionProduct::number(mode),
+                    CalculatorComplex::from(1.0),
+                )
+                .expect("Internal bug in add_operator_product");
         }
+        new_operator
     }
 
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
@@ -339,6 +453,288 @@ impl FermionHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Converts the FermionHamiltonian into a FermionOperator, adding the hermitian conjugates of all terms that are not naturally hermitian.
+    ///
+    /// # Returns
+    ///
+    /// * `FermionOperator` - The FermionHamiltonian as a FermionOperator.
+    pub fn to_operator(&self) -> FermionOperator {
+        FermionOperator::from(self.clone())
+    }
+
+    /// Decouples the two-particle terms of the FermionHamiltonian in the Hartree-Fock mean-field approximation.
+    ///
+    /// Every term c^†_p c^†_q c_r c_s is replaced by its linearization in the one-particle averages G_ij = ⟨c^†_i c_j⟩,
+    /// G_ps c^†_q c_r + G_qr c^†_p c_s - G_pr c^†_q c_s - G_qs c^†_p c_r - (G_ps G_qr - G_pr G_qs),
+    /// which has the same expectation value in a state with these averages.
+    /// The hermitian conjugates of the decoupled terms are added like in the FermionHamiltonian,
+    /// for naturally hermitian terms the hermitian part of the linearization is used.
+    /// Terms with at most two fermionic operators are kept unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `averages` - The one-particle averages ⟨c^†_i c_j⟩ with the FermionProduct c^†_i c_j as key. Missing averages are zero.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The quadratic mean-field FermionHamiltonian including the constant term.
+    /// * `Err(StruqtureError::GenericError)` - An average is not a one-particle average.
+    /// * `Err(StruqtureError::GenericError)` - A term is neither a two-particle term nor has at most two fermionic operators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use std::collections::HashMap;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonian, FermionProduct, HermitianFermionProduct};
+    ///
+    /// // Density-density interaction n_0 n_1 = -c^†_0 c^†_1 c_0 c_1
+    /// let mut hamiltonian = FermionHamiltonian::new();
+    /// hamiltonian
+    ///     .add_operator_product(HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(), CalculatorComplex::from(-1.0))
+    ///     .unwrap();
+    /// let averages = HashMap::from([
+    ///     (FermionProduct::new([0], [0]).unwrap(), Complex64::new(1.0, 0.0)),
+    ///     (FermionProduct::new([1], [1]).unwrap(), Complex64::new(0.5, 0.0)),
+    /// ]);
+    /// // Hartree terms 0.5 n_0 + n_1 - 0.5
+    /// let mean_field = hamiltonian.mean_field_decouple(&averages).unwrap();
+    /// assert_eq!(mean_field.get(&HermitianFermionProduct::new([0], [0]).unwrap()), &CalculatorComplex::from(0.5));
+    /// assert_eq!(mean_field.get(&HermitianFermionProduct::new([1], [1]).unwrap()), &CalculatorComplex::from(1.0));
+    /// assert_eq!(mean_field.get(&HermitianFermionProduct::new([], []).unwrap()), &CalculatorComplex::from(-0.5));
+    /// ```
+    pub fn mean_field_decouple(
+        &self,
+        averages: &HashMap<FermionProduct, Complex64>,
+    ) -> Result<Self, StruqtureError> {
+        if let Some(key) = averages
+            .keys()
+            .find(|key| key.number_creators() != 1 || key.number_annihilators() != 1)
+        {
+            return Err(StruqtureError::GenericError {
+                msg: format!("The average of {} is not a one-particle average", key),
+            });
+        }
+        let average = |creator: usize, annihilator: usize| -> Result<Complex64, StruqtureError> {
+            Ok(averages
+                .get(&FermionProduct::new([creator], [annihilator])?)
+                .copied()
+                .unwrap_or_default())
+        };
+
+        let mut decoupled = FermionHamiltonian::new();
+        for (product, value) in self.iter() {
+            let creators: Vec<usize> = product.creators().copied().collect();
+            let annihilators: Vec<usize> = product.annihilators().copied().collect();
+            match (creators.as_slice(), annihilators.as_slice()) {
+                ([p, q], [r, s]) => {
+                    let (g_ps, g_qr) = (average(*p, *s)?, average(*q, *r)?);
+                    let (g_pr, g_qs) = (average(*p, *r)?, average(*q, *s)?);
+                    let factor = if product.is_natural_hermitian() {
+                        0.5
+                    } else {
+                        1.0
+                    };
+                    let linearized_terms = [
+                        (g_ps, vec![*q], vec![*r]),
+                        (g_qr, vec![*p], vec![*s]),
+                        (-g_pr, vec![*q], vec![*s]),
+                        (-g_qs, vec![*p], vec![*r]),
+                        (-(g_ps * g_qr - g_pr * g_qs), vec![], vec![]),
+                    ];
+                    for (weight, term_creators, term_annihilators) in linearized_terms {
+                        add_with_hermitian_conjugate(
+                            &mut decoupled,
+                            &term_creators,
+                            &term_annihilators,
+                            value.clone() * CalculatorComplex::new(weight.re, weight.im) * factor,
+                        )?;
+                    }
+                }
+                _ if creators.len() + annihilators.len() <= 2 => {
+                    decoupled.add_operator_product(product.clone(), value.clone())?
+                }
+                _ => {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                        "The term {} can not be decoupled, only two-particle terms are supported",
+                        product
+                    ),
+                    })
+                }
+            }
+        }
+        Ok(decoupled)
+    }
+
+    /// Splits the two-particle terms of the FermionHamiltonian into a weighted sum of squares of quadratic operators.
+    ///
+    /// Every two-particle term c^†_p c^†_q c_r c_s is written with the one-body operators E_pr = c^†_p c_r as -E_pr E_qs plus one-body terms.
+    /// The symmetric matrix of the pair products E_pr E_qs in a basis of hermitian one-body operators is diagonalized,
+    /// so that the two-particle terms are Σ_l w_l O_l^2 with hermitian quadratic operators O_l and real weights w_l,
+    /// as used in Hubbard-Stratonovich transformations and low-rank Trotterizations.
+    /// The terms of the Hamiltonian that are not part of the squares, i.e. constant, one-body terms
+    /// and terms that do not conserve the particle number, are returned as the remainder.
+    /// Factors with weights below 1e-12 times the largest weight are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<(f64, Self)>, Self))` - The weights w_l with the quadratic operators O_l and the remainder R, with H = Σ_l w_l O_l^2 + R.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient of a two-particle term is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonian, HermitianFermionProduct};
+    ///
+    /// // Density-density interaction n_0 n_1 = -c^†_0 c^†_1 c_0 c_1
+    /// let mut hamiltonian = FermionHamiltonian::new();
+    /// hamiltonian
+    ///     .add_operator_product(HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(), CalculatorComplex::from(-1.0))
+    ///     .unwrap();
+    /// // n_0 n_1 = 1/2 ((n_0 + n_1) / sqrt(2))^2 - 1/2 ((n_0 - n_1) / sqrt(2))^2
+    /// let (factors, remainder) = hamiltonian.split_two_body_terms().unwrap();
+    /// assert_eq!(factors.len(), 2);
+    /// assert!((factors[0].0 + 0.5).abs() < 1e-12);
+    /// assert!((factors[1].0 - 0.5).abs() < 1e-12);
+    /// assert!(remainder.is_empty());
+    /// ```
+    pub fn split_two_body_terms(
+        &self,
+    ) -> Result<(Vec<(f64, FermionHamiltonian)>, FermionHamiltonian), StruqtureError> {
+        let operator = self.to_operator();
+        let mut two_body_terms: Vec<([usize; 4], Complex64)> = Vec::new();
+        for (product, value) in operator.iter() {
+            let creators: Vec<usize> = product.creators().copied().collect();
+            let annihilators: Vec<usize> = product.annihilators().copied().collect();
+            if let ([p, q], [r, s]) = (creators.as_slice(), annihilators.as_slice()) {
+                let value =
+                    Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?);
+                two_body_terms.push(([*p, *q, *r, *s], value));
+            }
+        }
+        let number_modes = match two_body_terms.iter().flat_map(|(modes, _)| modes).max() {
+            Some(mode) => mode + 1,
+            None => return Ok((Vec::new(), self.clone())),
+        };
+        let dimension = number_modes * number_modes;
+        let pair = |p: usize, r: usize| p * number_modes + r;
+
+        // c^†_p c^†_q c_r c_s = -E_pr E_qs + δ_qr E_ps, the one-body part ends up in the remainder
+        let mut pair_matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        for ([p, q, r, s], value) in two_body_terms {
+            pair_matrix[[pair(p, r), pair(q, s)]] -= value;
+        }
+        // Change to the hermitian one-body operators E_pp, X_pr = (E_pr + E_rp) / sqrt(2) with index (p, r)
+        // and Y_pr = i (E_rp - E_pr) / sqrt(2) with index (r, p) for p < r
+        let mut basis: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        for p in 0..number_modes {
+            basis[[pair(p, p), pair(p, p)]] = Complex64::new(1.0, 0.0);
+            for r in p + 1..number_modes {
+                basis[[pair(p, r), pair(p, r)]] = Complex64::new(FRAC_1_SQRT_2, 0.0);
+                basis[[pair(p, r), pair(r, p)]] = Complex64::new(0.0, FRAC_1_SQRT_2);
+                basis[[pair(r, p), pair(p, r)]] = Complex64::new(FRAC_1_SQRT_2, 0.0);
+                basis[[pair(r, p), pair(r, p)]] = Complex64::new(0.0, -FRAC_1_SQRT_2);
+            }
+        }
+        let transformed = basis.t().dot(&pair_matrix).dot(&basis);
+        // The antisymmetric part only contributes commutators, which are one-body terms, and the
+        // imaginary symmetric part is anti-hermitian and cancels in a hermitian Hamiltonian
+        let symmetric = Array2::from_shape_fn((dimension, dimension), |(k, l)| {
+            Complex64::new(0.5 * (transformed[[k, l]].re + transformed[[l, k]].re), 0.0)
+        });
+        let (weights, vectors) = hermitian_eigh(&symmetric);
+        let scale = weights
+            .iter()
+            .fold(0.0_f64, |largest, weight| largest.max(weight.abs()));
+
+        let mut factors: Vec<(f64, FermionHamiltonian)> = Vec::new();
+        let mut remainder = operator;
+        for (index, weight) in weights.iter().enumerate() {
+            if weight.abs() <= TWO_BODY_SPLITTING_TOLERANCE * scale {
+                continue;
+            }
+            // The eigenvectors of the real symmetric matrix are real up to rounding errors
+            let coefficient = |k: usize| vectors[[k, index]].re;
+            let mut quadratic = FermionHamiltonian::new();
+            for p in 0..number_modes {
+                quadratic.add_operator_product(
+                    HermitianFermionProduct::new([p], [p])?,
+                    CalculatorComplex::from(coefficient(pair(p, p))),
+                )?;
+                for r in p + 1..number_modes {
+                    quadratic.add_operator_product(
+                        HermitianFermionProduct::new([p], [r])?,
+                        CalculatorComplex::new(
+                            coefficient(pair(p, r)) * FRAC_1_SQRT_2,
+                            -coefficient(pair(r, p)) * FRAC_1_SQRT_2,
+                        ),
+                    )?;
+                }
+            }
+            let square = quadratic.to_operator() * quadratic.to_operator();
+            remainder = remainder - square * *weight;
+            factors.push((*weight, quadratic));
+        }
+        let remainder = remainder
+            .hermitian_part()
+            .try_into_hamiltonian()?
+            .truncate(TWO_BODY_SPLITTING_TOLERANCE * scale);
+        Ok((factors, remainder))
+    }
+
+    /// Creates a sampler drawing the terms of the FermionHamiltonian for the qDRIFT protocol.
+    ///
+    /// Every HermitianFermionProduct is drawn with a probability proportional to the absolute value of its coefficient,
+    /// together with the phase of the coefficient. The drawn term is the hermitian operator phase * P + phase^* * P^†
+    /// of the product P, or P for naturally hermitian products. See [crate::qdrift] for the protocol.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QDriftSampler<HermitianFermionProduct, Complex64>)` - The sampler with the normalization constant λ.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The FermionHamiltonian has no term with a non-zero coefficient.
+    pub fn qdrift_sampler(
+        &self,
+        seed: u64,
+    ) -> Result<QDriftSampler<HermitianFermionProduct, Complex64>, StruqtureError> {
+        let mut terms = Vec::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let value = Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?);
+            let weight = value.norm();
+            terms.push((product.clone(), value / weight, weight));
+        }
+        QDriftSampler::new(terms, seed)
+    }
+}
+
+/// Adds value * c^†_creators c_annihilators and its hermitian conjugate to a FermionHamiltonian.
+fn add_with_hermitian_conjugate(
+    hamiltonian: &mut FermionHamiltonian,
+    creators: &[usize],
+    annihilators: &[usize],
+    value: CalculatorComplex,
+) -> Result<(), StruqtureError> {
+    if creators == annihilators {
+        let key =
+            HermitianFermionProduct::new(creators.iter().copied(), annihilators.iter().copied())?;
+        return hamiltonian.add_operator_product(key, CalculatorComplex::new(value.re * 2.0, 0.0));
+    }
+    let (key, value) = HermitianFermionProduct::create_valid_pair(
+        creators.iter().copied(),
+        annihilators.iter().copied(),
+        value,
+    )?;
+    hamiltonian.add_operator_product(key, value)
 }
 
 impl TryFrom<FermionOperator> for FermionHamiltonian {
@@ -381,12 +777,14 @@ impl ops::Neg for FermionHamiltonian {
     ///
     /// * `Self` - The FermionHamiltonian * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * -1.0);
         }
         FermionHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -457,12 +855,15 @@ impl ops::Mul<CalculatorFloat> for FermionHamiltonian {
     ///
     /// * `Self` - The FermionHamiltonian multiplied by the CalculatorFloat.
     fn mul(self, other: CalculatorFloat) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         FermionHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -482,6 +883,7 @@ impl ops::Mul<CalculatorComplex> for FermionHamiltonian {
     /// * `Ok(FermionOperator)` - The FermionHamiltonian multiplied by the CalculatorComplex.
     fn mul(self, other: CalculatorComplex) -> Self::Output {
         let mut new_out = FermionOperator::with_capacity(self.len());
+        new_out.set_zero_term_policy(self.zero_term_policy)?;
         for (key, val) in self {
             if key.is_natural_hermitian() {
                 let new_key =
@@ -518,6 +920,7 @@ impl ops::Mul<FermionHamiltonian> for FermionHamiltonian {
     /// * `Ok(FermionOperator)` - The two FermionHamiltonians multiplied.
     fn mul(self, other: FermionHamiltonian) -> Self::Output {
         let mut op = FermionOperator::with_capacity(self.len() * other.len());
+        op.set_zero_term_policy(self.zero_term_policy)?;
         for (bps, vals) in self {
             for (bpo, valo) in other.iter() {
                 let fermion_products = bps.clone() * bpo.clone();
@@ -640,6 +1043,89 @@ impl fmt::Display for FermionHamiltonian {
     }
 }
 
+impl CanonicalText for FermionHamiltonian {
+    /// Returns the canonical text representation of the FermionHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionHamiltonian.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "FermionHamiltonian",
+            &[],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a FermionHamiltonian from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonian represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionHamiltonian.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionHamiltonian.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "FermionHamiltonian", &[])?;
+        let mut new_self = FermionHamiltonian::new();
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianFermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for FermionHamiltonian {
+    /// Returns the expression of the FermionHamiltonian, e.g. `0.5*c0a1 + 2*c1a1`.
+    ///
+    /// Only the stored terms are written, their hermitian conjugates are implied.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a FermionHamiltonian from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonian represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the FermionHamiltonian.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = FermionHamiltonian::new();
+        for (key, value) in
+            parse_expression_terms::<HermitianFermionProduct, CalculatorComplex>(expression)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for FermionHamiltonian {
+    /// Returns an upper bound of the spectral norm of the FermionHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The FermionHamiltonian contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        hermitian_coefficient_1_norm(self.iter())
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionHamiltonian {
     type Output = SpinHamiltonian;
 
@@ -678,6 +1164,53 @@ impl JordanWignerFermionToSpin for FermionHamiltonian {
     }
 }
 
+impl FermionToSpin for FermionHamiltonian {
+    type Output = SpinHamiltonian;
+
+    /// Implements FermionToSpin for a FermionHamiltonian.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the current number of modes of the FermionHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The spin Hamiltonian that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid or the transformed Hamiltonian is not hermitian.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        encode_fermion_hamiltonian(self, encoding, self.current_number_modes())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionHamiltonian {
+    type Output = SpinHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionHamiltonian.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the current number of modes of the FermionHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// `SpinHamiltonian` - The spin Hamiltonian that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,13 +11,31 @@
 // limitations under the License.
 
 use super::{
-    FermionHamiltonian, FermionSystem, HermitianFermionProduct, ModeIndex, OperateOnFermions,
+    FermionHamiltonian, FermionProduct, FermionSystem, HermitianFermionProduct, ModeIndex,
+    OperateOnFermions,
 };
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::linalg::hermitian_eigh;
+use crate::mappings::encoding::encode_fermion_hamiltonian;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
+use crate::metadata::merge_operand_metadata;
 use crate::spins::SpinHamiltonianSystem;
-use crate::{OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use crate::{
+    canonical_number, canonical_text_document, canonical_text_entries, hermitian_term_count,
+    hilbert_space_dimension, parse_canonical_number, parse_canonical_text_entries,
+    sparse_matrix_memory, split_canonical_text, CanonicalText, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnModes, OperateOnState, SpectralNormBound, StruqtureError,
+    SystemDimension, ZeroTermPolicy,
+};
+use ndarray::Array2;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -52,7 +70,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(fhs.get(&fp_0), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct FermionHamiltonianSystem {
@@ -60,10 +78,90 @@ pub struct FermionHamiltonianSystem {
     pub(crate) number_modes: Option<usize>,
     /// The FermionHamiltonian representing the Hamiltonian of the FermionHamiltonianSystem
     pub(crate) hamiltonian: FermionHamiltonian,
+    /// Provenance metadata of the FermionHamiltonianSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for FermionHamiltonianSystem {}
 
+impl Serialize for FermionHamiltonianSystem {
+    /// Serialization function for FermionHamiltonianSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionHamiltonianSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionHamiltonianSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.hamiltonian, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for FermionHamiltonianSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.hamiltonian), serializer)
+    }
+}
+
+/// Serializes a FermionHamiltonianSystem with the given serialization of its hamiltonian.
+///
+/// # Arguments
+///
+/// * `system` - FermionHamiltonianSystem to be serialized.
+/// * `hamiltonian` - Serialization of the hamiltonian of the FermionHamiltonianSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of FermionHamiltonianSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &FermionHamiltonianSystem,
+    hamiltonian: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer.serialize_struct(
+        "FermionHamiltonianSystem",
+        if skip_metadata { 2 } else { 3 },
+    )?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("hamiltonian", hamiltonian)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for FermionHamiltonianSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionHamiltonianSystem {
     type Index = HermitianFermionProduct;
     type Value = CalculatorComplex;
@@ -101,15 +199,27 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionHamiltonianSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                hamiltonian: FermionHamiltonian::with_capacity(cap),
+                hamiltonian: self.hamiltonian.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                hamiltonian: FermionHamiltonian::new(),
+                hamiltonian: self.hamiltonian.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.hamiltonian.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.hamiltonian.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the FermionHamiltonianSystem with the given (HermitianFermionProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -175,6 +285,13 @@ impl<'a> OperateOnState<'a> for FermionHamiltonianSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for FermionHamiltonianSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        HermitianFermionProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for FermionHamiltonianSystem {
     /// Gets the number_modes input of the FermionHamiltonianSystem, or the current_number_modes if number_modes is None.
     ///
@@ -200,6 +317,22 @@ impl<'a> OperateOnModes<'a> for FermionHamiltonianSystem {
 
 impl<'a> OperateOnFermions<'a> for FermionHamiltonianSystem {}
 
+/// The double-factorized form of the two-particle terms of a FermionHamiltonianSystem.
+///
+/// The two-particle terms are approximated by Σ_l w_l (Σ_k ε_lk b^†_lk b_lk)^2 with the modes
+/// b^†_lk = Σ_p U_l[(p, k)] c^†_p of the basis rotation U_l of every leaf l.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoubleFactorization {
+    /// The weights w_l of the leaves.
+    pub weights: Vec<f64>,
+    /// The kept eigenvalues ε_lk of the one-body operator of every leaf in ascending order.
+    pub eigenvalues: Vec<Vec<f64>>,
+    /// The basis rotations U_l of the leaves, with the eigenvector of every kept eigenvalue as a column.
+    pub leaf_matrices: Vec<Array2<Complex64>>,
+    /// The constant and one-body terms and the terms not conserving the particle number, which are not part of the leaves.
+    pub remainder: FermionHamiltonianSystem,
+}
+
 /// Functions for the FermionHamiltonianSystem
 ///
 impl FermionHamiltonianSystem {
@@ -212,6 +345,7 @@ impl FermionHamiltonianSystem {
         FermionHamiltonianSystem {
             number_modes,
             hamiltonian: FermionHamiltonian::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -229,6 +363,7 @@ impl FermionHamiltonianSystem {
         FermionHamiltonianSystem {
             number_modes,
             hamiltonian: FermionHamiltonian::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -262,6 +397,7 @@ impl FermionHamiltonianSystem {
                     Ok(FermionHamiltonianSystem {
                         number_modes: Some(x),
                         hamiltonian,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -270,10 +406,50 @@ impl FermionHamiltonianSystem {
             None => Ok(FermionHamiltonianSystem {
                 number_modes: None,
                 hamiltonian,
+                metadata: BTreeMap::new(),
             }),
         }
     }
 
+    /// Creates a FermionHamiltonianSystem with hopping terms on the edges of a graph.
+    ///
+    /// Every edge (i, j, t) adds t c_i^{\dagger} c_j + t^* c_j^{\dagger} c_i, an edge (i, i, ε) adds the on-site energy ε c_i^{\dagger} c_i.
+    /// Edges given several times add up their hopping amplitudes.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The edges (i, j, t) of the graph with the hopping amplitude t from mode j to mode i.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonianSystem with the hopping terms.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - An on-site energy is not real.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
+    ///
+    /// let chain = [(0, 1, (-1.0).into()), (1, 2, (-1.0).into())];
+    /// let system = FermionHamiltonianSystem::hopping_from_edges(&chain).unwrap();
+    /// assert_eq!(system.get(&HermitianFermionProduct::new([1], [2]).unwrap()), &(-1.0).into());
+    /// ```
+    pub fn hopping_from_edges(
+        edges: &[(usize, usize, CalculatorComplex)],
+    ) -> Result<Self, StruqtureError> {
+        let mut system = FermionHamiltonianSystem::new(None);
+        for (creator, annihilator, amplitude) in edges {
+            let (product, value) = HermitianFermionProduct::create_valid_pair(
+                [*creator],
+                [*annihilator],
+                amplitude.clone(),
+            )?;
+            system.add_operator_product(product, value)?;
+        }
+        Ok(system)
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -298,6 +474,245 @@ impl FermionHamiltonianSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Reduces the FermionHamiltonianSystem to an active space with a frozen, fully occupied core.
+    ///
+    /// Every term is projected onto the states in which the core orbitals are occupied and all orbitals
+    /// that are neither core nor active are empty. Terms acting only on core orbitals add up to the energy
+    /// offset, which is stored as the coefficient of the identity. Two-body terms with one pair of core
+    /// operators contribute effective one-body terms of the active orbitals.
+    /// The active orbitals are relabeled in the order they are given, `active_orbitals[i]` becomes mode i.
+    ///
+    /// # Arguments
+    ///
+    /// * `active_orbitals` - The orbitals kept in the reduced FermionHamiltonianSystem.
+    /// * `occupied_core` - The orbitals frozen in the occupied state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The reduced FermionHamiltonianSystem acting on `active_orbitals.len()` modes.
+    /// * `Err(StruqtureError::GenericError)` - An orbital is given more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
+    ///
+    /// // n_0 + 2 n_1 + 0.5 n_0 n_1, with c_0^dagger c_1^dagger c_0 c_1 = - n_0 n_1
+    /// let mut system = FermionHamiltonianSystem::new(None);
+    /// system.add_operator_product(HermitianFermionProduct::new([0], [0]).unwrap(), 1.0.into()).unwrap();
+    /// system.add_operator_product(HermitianFermionProduct::new([1], [1]).unwrap(), 2.0.into()).unwrap();
+    /// system.add_operator_product(HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(), (-0.5).into()).unwrap();
+    ///
+    /// let reduced = system.freeze_core_and_restrict(&[1], &[0]).unwrap();
+    /// assert_eq!(reduced.get(&HermitianFermionProduct::new([], []).unwrap()), &1.0.into());
+    /// assert_eq!(reduced.get(&HermitianFermionProduct::new([0], [0]).unwrap()), &2.5.into());
+    /// ```
+    pub fn freeze_core_and_restrict(
+        &self,
+        active_orbitals: &[usize],
+        occupied_core: &[usize],
+    ) -> Result<Self, StruqtureError> {
+        let mut orbitals: BTreeSet<usize> = BTreeSet::new();
+        for orbital in active_orbitals.iter().chain(occupied_core.iter()) {
+            if !orbitals.insert(*orbital) {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Orbital {} is given more than once in the active and core orbitals",
+                        orbital
+                    ),
+                });
+            }
+        }
+        let active: HashMap<usize, usize> = active_orbitals
+            .iter()
+            .enumerate()
+            .map(|(new_mode, orbital)| (*orbital, new_mode))
+            .collect();
+        let core: BTreeSet<usize> = occupied_core.iter().copied().collect();
+
+        let mut reduced = Self::new(Some(active_orbitals.len()));
+        for (product, value) in self.iter() {
+            if let Some((creators, annihilators, sign)) =
+                project_onto_active_space(product, &active, &core)
+            {
+                let (new_product, new_value) = HermitianFermionProduct::create_valid_pair(
+                    creators,
+                    annihilators,
+                    value.clone() * sign,
+                )?;
+                reduced.add_operator_product(new_product, new_value)?;
+            }
+        }
+        Ok(reduced)
+    }
+
+    /// Decouples the two-particle terms of the FermionHamiltonianSystem in the Hartree-Fock mean-field approximation.
+    ///
+    /// See [FermionHamiltonian::mean_field_decouple]. The number of modes and the metadata are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `averages` - The one-particle averages ⟨c^†_i c_j⟩ with the FermionProduct c^†_i c_j as key. Missing averages are zero.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The quadratic mean-field FermionHamiltonianSystem including the constant term.
+    /// * `Err(StruqtureError::GenericError)` - An average is not a one-particle average.
+    /// * `Err(StruqtureError::GenericError)` - A term is neither a two-particle term nor has at most two fermionic operators.
+    pub fn mean_field_decouple(
+        &self,
+        averages: &HashMap<FermionProduct, Complex64>,
+    ) -> Result<Self, StruqtureError> {
+        Ok(Self {
+            number_modes: self.number_modes,
+            hamiltonian: self.hamiltonian.mean_field_decouple(averages)?,
+            metadata: self.metadata.clone(),
+        })
+    }
+
+    /// Computes the double-factorized form of the two-particle terms of the FermionHamiltonianSystem.
+    ///
+    /// The first factorization writes the two-particle terms as a weighted sum of squares of one-body operators,
+    /// see [FermionHamiltonian::split_two_body_terms]. The second factorization diagonalizes the one-body operator of
+    /// every square, which gives the leaves of the double-factorized form used in resource estimates of quantum algorithms.
+    /// Leaves with a weight |w_l| below the threshold and eigenvalues with sqrt(|w_l|) |ε_lk| below the threshold are dropped,
+    /// so the leaves approximate the two-particle terms with an error controlled by the threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold below which leaves and eigenvalues are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DoubleFactorization)` - The leaves and the remainder with the number of modes and the metadata of the system.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient of a two-particle term is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
+    ///
+    /// // Density-density interaction n_0 n_1 = -c^†_0 c^†_1 c_0 c_1
+    /// let mut system = FermionHamiltonianSystem::new(Some(2));
+    /// system
+    ///     .add_operator_product(HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(), CalculatorComplex::from(-1.0))
+    ///     .unwrap();
+    /// let factorization = system.double_factorization(1e-10).unwrap();
+    /// assert_eq!(factorization.weights.len(), 2);
+    /// assert_eq!(factorization.leaf_matrices[0].dim(), (2, 2));
+    /// assert!(factorization.remainder.is_empty());
+    /// ```
+    pub fn double_factorization(
+        &self,
+        threshold: f64,
+    ) -> Result<DoubleFactorization, StruqtureError> {
+        let (factors, remainder) = self.hamiltonian.split_two_body_terms()?;
+        let number_modes = self.number_modes();
+        let mut factorization = DoubleFactorization {
+            weights: Vec::new(),
+            eigenvalues: Vec::new(),
+            leaf_matrices: Vec::new(),
+            remainder: Self {
+                number_modes: self.number_modes,
+                hamiltonian: remainder,
+                metadata: self.metadata.clone(),
+            },
+        };
+        for (weight, quadratic) in factors {
+            if weight.abs() < threshold {
+                continue;
+            }
+            let mut matrix: Array2<Complex64> = Array2::zeros((number_modes, number_modes));
+            for (product, value) in quadratic.iter() {
+                if let (Some(p), Some(r)) =
+                    (product.creators().next(), product.annihilators().next())
+                {
+                    let value =
+                        Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?);
+                    matrix[[*p, *r]] += value;
+                    if p != r {
+                        matrix[[*r, *p]] += value.conj();
+                    }
+                }
+            }
+            let (values, vectors) = hermitian_eigh(&matrix);
+            let kept: Vec<usize> = (0..number_modes)
+                .filter(|k| weight.abs().sqrt() * values[*k].abs() >= threshold)
+                .collect();
+            if kept.is_empty() {
+                continue;
+            }
+            factorization.weights.push(weight);
+            factorization
+                .eigenvalues
+                .push(kept.iter().map(|k| values[*k]).collect());
+            factorization.leaf_matrices.push(Array2::from_shape_fn(
+                (number_modes, kept.len()),
+                |(p, column)| vectors[[p, kept[column]]],
+            ));
+        }
+        Ok(factorization)
+    }
+}
+
+/// Projects a product onto the active space with occupied core orbitals and empty inactive orbitals.
+///
+/// The core operators of a nonvanishing product form a product of number operators $n_k$, which commutes
+/// with all other operators and is one in the projected space. The sign results from moving the core creators
+/// behind the active creators and the core annihilators, in reversed order, before the active annihilators.
+///
+/// Returns None if the projection vanishes, otherwise the relabeled active creators and annihilators and the sign.
+fn project_onto_active_space(
+    product: &HermitianFermionProduct,
+    active: &HashMap<usize, usize>,
+    core: &BTreeSet<usize>,
+) -> Option<(Vec<usize>, Vec<usize>, f64)> {
+    let core_creators: Vec<usize> = product
+        .creators()
+        .filter(|mode| core.contains(*mode))
+        .copied()
+        .collect();
+    let core_annihilators: Vec<usize> = product
+        .annihilators()
+        .filter(|mode| core.contains(*mode))
+        .copied()
+        .collect();
+    if core_creators != core_annihilators {
+        return None;
+    }
+    let relabel = |modes: std::slice::Iter<usize>| -> Option<Vec<usize>> {
+        modes
+            .filter(|mode| !core.contains(*mode))
+            .map(|mode| active.get(mode).copied())
+            .collect()
+    };
+    let creators = relabel(product.creators())?;
+    let annihilators = relabel(product.annihilators())?;
+
+    let number_core = core_creators.len();
+    let mut transpositions = number_core * number_core.saturating_sub(1) / 2;
+    let mut core_passed = 0;
+    for mode in product.creators() {
+        if core.contains(mode) {
+            core_passed += 1;
+        } else {
+            transpositions += core_passed;
+        }
+    }
+    let mut active_passed = 0;
+    for mode in product.annihilators() {
+        if core.contains(mode) {
+            transpositions += active_passed;
+        } else {
+            active_passed += 1;
+        }
+    }
+    let sign = if transpositions % 2 == 0 { 1.0 } else { -1.0 };
+    Some((creators, annihilators, sign))
 }
 
 /// Implements the negative sign function of FermionHamiltonianSystem.
@@ -319,7 +734,7 @@ impl ops::Neg for FermionHamiltonianSystem {
 ///
 impl<T, V> ops::Add<T> for FermionHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianFermionProduct, V)>,
+    T: IntoIterator<Item = (HermitianFermionProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -334,6 +749,7 @@ where
     /// * `Ok(Self)` - The two FermionHamiltonianSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Number of fermionic modes in entry exceeds number of fermionic modes in system.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -345,7 +761,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for FermionHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianFermionProduct, V)>,
+    T: IntoIterator<Item = (HermitianFermionProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -360,6 +776,7 @@ where
     /// * `Ok(Self)` - The two FermionHamiltonianSystems subtracted.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Number of fermionic modes in entry exceeds number of fermionic modes in system.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -403,6 +820,7 @@ impl ops::Mul<CalculatorComplex> for FermionHamiltonianSystem {
     fn mul(self, other: CalculatorComplex) -> Self::Output {
         let mut system = FermionSystem::new(self.number_modes);
         system.operator = (self.hamiltonian * other)?;
+        system.metadata = self.metadata;
         Ok(system)
     }
 }
@@ -422,9 +840,12 @@ impl ops::Mul<FermionHamiltonianSystem> for FermionHamiltonianSystem {
     /// * `Ok(FermionSystem)` - The two FermionHamiltonianSystems multiplied.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Number of fermionic modes in entry exceeds number of fermionic modes in system.
     fn mul(self, other: FermionHamiltonianSystem) -> Self::Output {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<FermionHamiltonianSystem>(&mut metadata, &other);
         Ok(FermionSystem {
             number_modes: Some(self.number_modes().max(other.number_modes())),
             operator: (self.hamiltonian * other.hamiltonian)?,
+            metadata,
         })
     }
 }
@@ -538,6 +959,56 @@ impl fmt::Display for FermionHamiltonianSystem {
     }
 }
 
+impl CanonicalText for FermionHamiltonianSystem {
+    /// Returns the canonical text representation of the FermionHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionHamiltonianSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "FermionHamiltonianSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a FermionHamiltonianSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionHamiltonianSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionHamiltonianSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionHamiltonianSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "FermionHamiltonianSystem", &["number_modes"])?;
+        let mut new_self = FermionHamiltonianSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianFermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for FermionHamiltonianSystem {
+    /// Returns an upper bound of the spectral norm of the FermionHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The FermionHamiltonianSystem contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.hamiltonian().spectral_norm_bound()
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionHamiltonianSystem {
     type Output = SpinHamiltonianSystem;
 
@@ -561,3 +1032,97 @@ impl JordanWignerFermionToSpin for FermionHamiltonianSystem {
         .expect("Internal bug in jordan_wigner for FermionHamiltonian. The number of spins in the resulting Hamiltonian should equal the number of modes of the FermionHamiltonian.")
     }
 }
+
+impl FermionToSpin for FermionHamiltonianSystem {
+    type Output = SpinHamiltonianSystem;
+
+    /// Implements FermionToSpin for a FermionHamiltonianSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the number of modes of the FermionHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonianSystem)` - The spin Hamiltonian system that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid or the transformed Hamiltonian is not hermitian.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinHamiltonianSystem::from_hamiltonian(
+            encode_fermion_hamiltonian(self.hamiltonian(), encoding, self.number_modes())?,
+            self.number_modes,
+        )
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionHamiltonianSystem {
+    type Output = SpinHamiltonianSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionHamiltonianSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the number of modes of the FermionHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// `SpinHamiltonianSystem` - The spin Hamiltonian system that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+impl SystemDimension for FermionHamiltonianSystem {
+    /// Returns the dimension of the Hilbert space of the FermionHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionHamiltonianSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_modes(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the FermionHamiltonianSystem.
+    ///
+    /// Terms that are not naturally hermitian also represent their hermitian conjugate and are counted twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionHamiltonianSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(
+            dimension,
+            hermitian_term_count(self.keys()),
+            bytes_per_entry,
+        )
+    }
+}
@@ -10,8 +10,12 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::FermionIndex;
-use crate::mappings::JordanWignerFermionToSpin;
+use super::{FermionHamiltonian, FermionIndex};
+use crate::mappings::encoding::{encode_fermion_hamiltonian, encode_fermion_product};
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
 use crate::prelude::*;
 use crate::spins::{PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator};
 use crate::{
@@ -70,6 +74,8 @@ impl schemars::JsonSchema for FermionProduct {
 
 impl crate::MinSupportedVersion for FermionProduct {}
 
+impl crate::SerializeCanonical for FermionProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for FermionProduct {
@@ -616,6 +622,8 @@ impl schemars::JsonSchema for HermitianFermionProduct {
 
 impl crate::MinSupportedVersion for HermitianFermionProduct {}
 
+impl crate::SerializeCanonical for HermitianFermionProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for HermitianFermionProduct {
@@ -1330,9 +1338,7 @@ impl JordanWignerFermionToSpin for FermionProduct {
         let mut previous = 0;
         for (index, site) in self.creators().enumerate() {
             if index % 2 != number_creators % 2 {
-                for i in previous..*site {
-                    spin_operator = spin_operator * PauliProduct::new().z(i)
-                }
+                spin_operator = spin_operator * _jordan_wigner_string(previous, *site);
             }
             spin_operator = spin_operator * _lowering_operator(site);
             previous = *site;
@@ -1341,9 +1347,7 @@ impl JordanWignerFermionToSpin for FermionProduct {
         previous = 0;
         for (index, site) in self.annihilators().enumerate() {
             if index % 2 != number_annihilators % 2 {
-                for i in previous..*site {
-                    spin_operator = spin_operator * PauliProduct::new().z(i)
-                }
+                spin_operator = spin_operator * _jordan_wigner_string(previous, *site);
             }
             spin_operator = spin_operator * _raising_operator(site);
             previous = *site;
@@ -1384,9 +1388,7 @@ impl JordanWignerFermionToSpin for HermitianFermionProduct {
         let mut previous = 0;
         for (index, site) in self.creators().enumerate() {
             if index % 2 != number_creators % 2 {
-                for i in previous..*site {
-                    spin_operator = spin_operator * PauliProduct::new().z(i)
-                }
+                spin_operator = spin_operator * _jordan_wigner_string(previous, *site);
             }
             spin_operator = spin_operator * _lowering_operator(site);
             previous = *site;
@@ -1395,9 +1397,7 @@ impl JordanWignerFermionToSpin for HermitianFermionProduct {
         previous = 0;
         for (index, site) in self.annihilators().enumerate() {
             if index % 2 != number_annihilators % 2 {
-                for i in previous..*site {
-                    spin_operator = spin_operator * PauliProduct::new().z(i)
-                }
+                spin_operator = spin_operator * _jordan_wigner_string(previous, *site);
             }
             spin_operator = spin_operator * _raising_operator(site);
             previous = *site;
@@ -1423,6 +1423,110 @@ transform is hermitian.",
     }
 }
 
+impl FermionToSpin for FermionProduct {
+    type Output = SpinOperator;
+
+    /// Implements FermionToSpin for a FermionProduct.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the current number of modes of the FermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        encode_fermion_product(self, encoding, self.current_number_modes())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionProduct {
+    type Output = SpinOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionProduct.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the current number of modes of the FermionProduct.
+    ///
+    /// # Returns
+    ///
+    /// `SpinOperator` - The spin operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+impl FermionToSpin for HermitianFermionProduct {
+    type Output = SpinHamiltonian;
+
+    /// Implements FermionToSpin for a HermitianFermionProduct.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the current number of modes of the HermitianFermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid or the transformed Hamiltonian is not hermitian.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        let mut hamiltonian = FermionHamiltonian::new();
+        hamiltonian.add_operator_product(self.clone(), CalculatorComplex::new(1.0, 0.0))?;
+        encode_fermion_hamiltonian(&hamiltonian, encoding, self.current_number_modes())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for HermitianFermionProduct {
+    type Output = SpinHamiltonian;
+
+    /// Implements BravyiKitaevFermionToSpin for a HermitianFermionProduct.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the current number of modes of the HermitianFermionProduct.
+    ///
+    /// # Returns
+    ///
+    /// `SpinHamiltonian` - The spin operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+/// Returns the Jordan-Wigner string of Z operators on the spins from `start` to `end` (exclusive).
+///
+/// The string is multiplied onto the transformed operator as one PauliProduct, so that the
+/// operator-level product is done once per string instead of once per spin.
+fn _jordan_wigner_string(start: usize, end: usize) -> PauliProduct {
+    (start..end).fold(PauliProduct::new(), |string, i| string.z(i))
+}
+
 fn _lowering_operator(i: &usize) -> SpinOperator {
     let mut out = SpinOperator::new();
     out.add_operator_product(PauliProduct::new().x(*i), CalculatorComplex::new(0.5, 0.0))
@@ -11,15 +11,22 @@
 // limitations under the License.
 
 use super::{FermionOperator, FermionProduct, OperateOnFermions};
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::encoding::encode_fermion_noise_operator;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
 use crate::spins::{DecoherenceOperator, SpinLindbladNoiseOperator};
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_noise_text_entries, canonical_text_document, noise_adjoint_violations,
+    noise_symmetrized_entries, parse_canonical_noise_text_entries, split_canonical_text,
+    CanonicalText, ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use itertools::Itertools;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -68,6 +75,8 @@ pub struct FermionLindbladNoiseOperator {
     internal_map: IndexMap<(FermionProduct, FermionProduct), CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<(FermionProduct, FermionProduct), CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for FermionLindbladNoiseOperator {}
@@ -83,7 +92,7 @@ impl schemars::JsonSchema for FermionLindbladNoiseOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct FermionLindbladNoiseOperatorSerialize {
@@ -94,25 +103,65 @@ struct FermionLindbladNoiseOperatorSerialize {
         CalculatorFloat,
         CalculatorFloat,
     )>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// The struqture version
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for FermionLindbladNoiseOperatorSerialize {
+    /// Serialization function for FermionLindbladNoiseOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionLindbladNoiseOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionLindbladNoiseOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "FermionLindbladNoiseOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<FermionLindbladNoiseOperatorSerialize> for FermionLindbladNoiseOperator {
     fn from(value: FermionLindbladNoiseOperatorSerialize) -> Self {
-        let new_noise_op: FermionLindbladNoiseOperator = value
+        let mut new_noise_op: FermionLindbladNoiseOperator = value
             .items
             .into_iter()
             .map(|(left, right, real, imag)| {
                 ((left, right), CalculatorComplex { re: real, im: imag })
             })
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<FermionLindbladNoiseOperator> for FermionLindbladNoiseOperatorSerialize {
     fn from(value: FermionLindbladNoiseOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(
             FermionProduct,
             FermionProduct,
@@ -128,11 +177,23 @@ impl From<FermionLindbladNoiseOperator> for FermionLindbladNoiseOperatorSerializ
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for FermionLindbladNoiseOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = FermionLindbladNoiseOperatorSerialize::from(self.clone());
+        serializable
+            .items
+            .sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionLindbladNoiseOperator {
     type Index = (FermionProduct, FermionProduct);
     type Value = CalculatorComplex;
@@ -177,10 +238,25 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionLindbladNoiseOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the FermionLindbladNoiseOperator with the given ((FermionProduct, FermionProduct) key, CalculatorComplex value) pair.
@@ -208,7 +284,7 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionLindbladNoiseOperator {
             return Err(StruqtureError::InvalidLindbladTerms);
         }
 
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -274,6 +350,7 @@ impl FermionLindbladNoiseOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -292,6 +369,7 @@ impl FermionLindbladNoiseOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -367,6 +445,61 @@ impl FermionLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the keys (A, B) whose coefficient is not the complex conjugate of the coefficient of (B, A).
+    ///
+    /// The Kossakowski matrix of a physical noise operator is self-adjoint, so every entry (A, B)
+    /// has to be matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(FermionProduct, FermionProduct)>` - The keys violating the self-adjoint structure.
+    pub fn adjoint_violations(&self) -> Vec<(FermionProduct, FermionProduct)> {
+        noise_adjoint_violations(self.iter())
+    }
+
+    /// Returns true if every entry (A, B) is matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the FermionLindbladNoiseOperator is self-adjoint.
+    pub fn is_self_adjoint(&self) -> bool {
+        self.adjoint_violations().is_empty()
+    }
+
+    /// Enforces the self-adjoint structure of the FermionLindbladNoiseOperator.
+    ///
+    /// The coefficient of every violating entry (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(FermionProduct, FermionProduct)>` - The keys whose coefficients were changed or added.
+    pub fn symmetrize(&mut self) -> Vec<(FermionProduct, FermionProduct)> {
+        let changes = noise_symmetrized_entries(self.iter());
+        let mut changed_keys: Vec<(FermionProduct, FermionProduct)> =
+            Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            self.set(key.clone(), value)
+                .expect("Internal bug in set for a key already contained in transposed form.");
+            changed_keys.push(key);
+        }
+        changed_keys
+    }
+
+    /// Returns the noise operator of the simultaneous action of self and another noise operator.
+    ///
+    /// Generators of the Lindblad equation add, so the result is the sum of both noise operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The noise operator acting together with self.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The sum of the generators.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladNoiseOperator.
@@ -379,6 +512,7 @@ impl ops::Neg for FermionLindbladNoiseOperator {
     ///
     /// * `Self` - The FermionOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -388,6 +522,7 @@ impl ops::Neg for FermionLindbladNoiseOperator {
         }
         FermionLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -469,6 +604,7 @@ where
     ///
     /// * `Self` - The FermionLindbladNoiseOperator multiplied by the CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -477,8 +613,10 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         FermionLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -596,6 +734,43 @@ impl fmt::Display for FermionLindbladNoiseOperator {
     }
 }
 
+impl CanonicalText for FermionLindbladNoiseOperator {
+    /// Returns the canonical text representation of the FermionLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionLindbladNoiseOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "FermionLindbladNoiseOperator",
+            &[],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a FermionLindbladNoiseOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladNoiseOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionLindbladNoiseOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionLindbladNoiseOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "FermionLindbladNoiseOperator", &[])?;
+        let mut new_self = FermionLindbladNoiseOperator::new();
+        for (key, value) in
+            parse_canonical_noise_text_entries::<FermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionLindbladNoiseOperator {
     type Output = SpinLindbladNoiseOperator;
 
@@ -629,6 +804,53 @@ impl JordanWignerFermionToSpin for FermionLindbladNoiseOperator {
     }
 }
 
+impl FermionToSpin for FermionLindbladNoiseOperator {
+    type Output = SpinLindbladNoiseOperator;
+
+    /// Implements FermionToSpin for a FermionLindbladNoiseOperator.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the current number of modes of the FermionLindbladNoiseOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladNoiseOperator)` - The spin noise operator that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        encode_fermion_noise_operator(self, encoding, self.current_number_modes())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionLindbladNoiseOperator {
+    type Output = SpinLindbladNoiseOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladNoiseOperator.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the current number of modes of the FermionLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinLindbladNoiseOperator` - The spin noise operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,11 +11,23 @@
 // limitations under the License.
 
 use super::{FermionLindbladNoiseOperator, OperateOnFermions};
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::encoding::encode_fermion_noise_operator;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
+use crate::metadata::merge_operand_metadata;
 use crate::spins::SpinLindbladNoiseSystem;
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use crate::{
+    canonical_noise_text_entries, canonical_number, canonical_text_document,
+    hilbert_space_dimension, noise_is_completely_positive, parse_canonical_noise_text_entries,
+    parse_canonical_number, sparse_matrix_memory, split_canonical_text, CanonicalText, ModeIndex,
+    OperateOnDensityMatrix, OperateOnModes, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::{
     fmt::{self, Write},
@@ -54,7 +66,7 @@ use super::FermionProduct;
 /// assert_eq!(system.get(&(bp_0.clone(),bp_0.clone())), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct FermionLindbladNoiseSystem {
@@ -62,10 +74,90 @@ pub struct FermionLindbladNoiseSystem {
     pub(crate) number_modes: Option<usize>,
     /// The FermionLindbladNoiseOperator representing the Lindblad noise terms of the FermionLindbladNoiseSystem.
     pub(crate) operator: FermionLindbladNoiseOperator,
+    /// Provenance metadata of the FermionLindbladNoiseSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for FermionLindbladNoiseSystem {}
 
+impl Serialize for FermionLindbladNoiseSystem {
+    /// Serialization function for FermionLindbladNoiseSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionLindbladNoiseSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionLindbladNoiseSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for FermionLindbladNoiseSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a FermionLindbladNoiseSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - FermionLindbladNoiseSystem to be serialized.
+/// * `operator` - Serialization of the operator of the FermionLindbladNoiseSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of FermionLindbladNoiseSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &FermionLindbladNoiseSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer.serialize_struct(
+        "FermionLindbladNoiseSystem",
+        if skip_metadata { 2 } else { 3 },
+    )?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for FermionLindbladNoiseSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionLindbladNoiseSystem {
     type Value = CalculatorComplex;
     type Index = (FermionProduct, FermionProduct);
@@ -103,15 +195,27 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionLindbladNoiseSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                operator: FermionLindbladNoiseOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                operator: FermionLindbladNoiseOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the FermionLindbladNoiseSystem with the given ((FermionProduct, FermionProduct) key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -210,6 +314,7 @@ impl FermionLindbladNoiseSystem {
         FermionLindbladNoiseSystem {
             number_modes,
             operator: FermionLindbladNoiseOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -227,6 +332,7 @@ impl FermionLindbladNoiseSystem {
         FermionLindbladNoiseSystem {
             number_modes,
             operator: FermionLindbladNoiseOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -260,6 +366,7 @@ impl FermionLindbladNoiseSystem {
                     Ok(FermionLindbladNoiseSystem {
                         number_modes: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -268,6 +375,7 @@ impl FermionLindbladNoiseSystem {
             None => Ok(FermionLindbladNoiseSystem {
                 number_modes: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -302,6 +410,22 @@ impl FermionLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns whether the noise is completely positive, i.e. whether its Kossakowski matrix is positive semidefinite.
+    ///
+    /// The Kossakowski matrix is constructed in the basis of all operator products appearing in the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues of the Kossakowski matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the FermionLindbladNoiseSystem is completely positive.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn is_completely_positive(&self, tolerance: f64) -> Result<bool, StruqtureError> {
+        noise_is_completely_positive(self.iter(), tolerance)
+    }
 }
 
 /// Implements the negative sign function of FermionLindbladNoiseSystem.
@@ -323,7 +447,7 @@ impl ops::Neg for FermionLindbladNoiseSystem {
 ///
 impl<T, V> ops::Add<T> for FermionLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((FermionProduct, FermionProduct), V)>,
+    T: IntoIterator<Item = ((FermionProduct, FermionProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -338,6 +462,7 @@ where
     /// * `Ok(Self)` - The two FermionLindbladNoiseSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of (FermionProduct, FermionProduct) exceeds that of the FermionLindbladNoiseSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -349,7 +474,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for FermionLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((FermionProduct, FermionProduct), V)>,
+    T: IntoIterator<Item = ((FermionProduct, FermionProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -364,6 +489,7 @@ where
     /// * `Ok(Self)` - The two FermionLindbladNoiseSystems subtracted.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of (FermionProduct, FermionProduct) exceeds that of the FermionLindbladNoiseSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -504,6 +630,44 @@ impl fmt::Display for FermionLindbladNoiseSystem {
     }
 }
 
+impl CanonicalText for FermionLindbladNoiseSystem {
+    /// Returns the canonical text representation of the FermionLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionLindbladNoiseSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "FermionLindbladNoiseSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a FermionLindbladNoiseSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladNoiseSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionLindbladNoiseSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionLindbladNoiseSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "FermionLindbladNoiseSystem", &["number_modes"])?;
+        let mut new_self = FermionLindbladNoiseSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_noise_text_entries::<FermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionLindbladNoiseSystem {
     type Output = SpinLindbladNoiseSystem;
 
@@ -527,3 +691,94 @@ impl JordanWignerFermionToSpin for FermionLindbladNoiseSystem {
             .expect("Internal bug in jordan_wigner for FermionLindbladNoiseOperator. The number of spins in the resulting SpinLindbladNoiseOperator should equal the number of modes of the FermionLindbladNoiseOperator.")
     }
 }
+
+impl FermionToSpin for FermionLindbladNoiseSystem {
+    type Output = SpinLindbladNoiseSystem;
+
+    /// Implements FermionToSpin for a FermionLindbladNoiseSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the number of modes of the FermionLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladNoiseSystem)` - The spin noise system that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinLindbladNoiseSystem::from_operator(
+            encode_fermion_noise_operator(self.operator(), encoding, self.number_modes())?,
+            self.number_modes,
+        )
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionLindbladNoiseSystem {
+    type Output = SpinLindbladNoiseSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladNoiseSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the number of modes of the FermionLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// `SpinLindbladNoiseSystem` - The spin noise system that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+impl SystemDimension for FermionLindbladNoiseSystem {
+    /// Returns the dimension of the Hilbert space of the FermionLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionLindbladNoiseSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_modes(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the FermionLindbladNoiseSystem.
+    ///
+    /// The estimate is for the sparse superoperator, to which every Lindblad term contributes at most three non-zero entries per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionLindbladNoiseSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Every Lindblad term contributes L ρ R^dagger and the two terms of its anticommutator
+        sparse_matrix_memory(dimension, 3 * self.len(), bytes_per_entry)
+    }
+}
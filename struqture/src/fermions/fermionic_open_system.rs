@@ -11,11 +11,21 @@
 // limitations under the License.
 
 use super::{FermionHamiltonianSystem, FermionLindbladNoiseSystem};
-use crate::mappings::JordanWignerFermionToSpin;
-use crate::spins::SpinLindbladOpenSystem;
-use crate::{OpenSystem, OperateOnDensityMatrix, OperateOnModes, StruqtureError};
+use crate::mappings::encoding::{encode_fermion_hamiltonian, encode_fermion_noise_operator};
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
+use crate::spins::{SpinHamiltonianSystem, SpinLindbladNoiseSystem, SpinLindbladOpenSystem};
+use crate::{
+    hermitian_term_count, hilbert_space_dimension, sparse_matrix_memory,
+    split_canonical_open_system, CanonicalText, OpenSystem, OperateOnDensityMatrix, OperateOnModes,
+    StruqtureError, SystemDimension,
+};
 use qoqo_calculator::CalculatorFloat;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::ops;
 
@@ -52,6 +62,28 @@ pub struct FermionLindbladOpenSystem {
 
 impl crate::MinSupportedVersion for FermionLindbladOpenSystem {}
 
+impl crate::SerializeCanonical for FermionLindbladOpenSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("FermionLindbladOpenSystem", 2)?;
+        state.serialize_field("system", &crate::Canonical(&self.system))?;
+        state.serialize_field("noise", &crate::Canonical(&self.noise))?;
+        state.end()
+    }
+}
+
+impl crate::OperateOnMetadata for FermionLindbladOpenSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.system.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.system.metadata
+    }
+}
+
 impl<'a> OpenSystem<'a> for FermionLindbladOpenSystem {
     type System = FermionHamiltonianSystem;
     type Noise = FermionLindbladNoiseSystem;
@@ -287,6 +319,44 @@ impl fmt::Display for FermionLindbladOpenSystem {
     }
 }
 
+impl CanonicalText for FermionLindbladOpenSystem {
+    /// Returns the canonical text representation of the FermionLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionLindbladOpenSystem.
+    fn to_canonical_text(&self) -> String {
+        format!(
+            "FermionLindbladOpenSystem\n{}{}",
+            self.system().to_canonical_text(),
+            self.noise().to_canonical_text()
+        )
+    }
+
+    /// Creates a FermionLindbladOpenSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionLindbladOpenSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionLindbladOpenSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionLindbladOpenSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (system, noise) = split_canonical_open_system(
+            text,
+            "FermionLindbladOpenSystem",
+            "FermionLindbladNoiseSystem",
+        )?;
+        Self::group(
+            FermionHamiltonianSystem::from_canonical_text(&system)?,
+            FermionLindbladNoiseSystem::from_canonical_text(&noise)?,
+        )
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionLindbladOpenSystem {
     type Output = SpinLindbladOpenSystem;
 
@@ -305,3 +375,102 @@ impl JordanWignerFermionToSpin for FermionLindbladOpenSystem {
             .expect("Internal bug in jordan_wigner() for FermionHamiltonianSystem or FermionLindbladNoiseSystem. The number of modes in the fermionic system should equal the number of spins in the spin system.")
     }
 }
+
+impl FermionToSpin for FermionLindbladOpenSystem {
+    type Output = SpinLindbladOpenSystem;
+
+    /// Implements FermionToSpin for a FermionLindbladOpenSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding of system and noise are constructed for the number of modes of the FermionLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinLindbladOpenSystem)` - The spin open system that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid or the transformed Hamiltonian is not hermitian.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        let number_modes = self.number_modes();
+        let spin_system = SpinHamiltonianSystem::from_hamiltonian(
+            encode_fermion_hamiltonian(self.system().hamiltonian(), encoding, number_modes)?,
+            self.system().number_modes,
+        )?;
+        let spin_noise = SpinLindbladNoiseSystem::from_operator(
+            encode_fermion_noise_operator(self.noise().operator(), encoding, number_modes)?,
+            self.noise().number_modes,
+        )?;
+        SpinLindbladOpenSystem::group(spin_system, spin_noise)
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionLindbladOpenSystem {
+    type Output = SpinLindbladOpenSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionLindbladOpenSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets of system and noise are constructed for the number of modes of the FermionLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// `SpinLindbladOpenSystem` - The spin open system that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+impl SystemDimension for FermionLindbladOpenSystem {
+    /// Returns the dimension of the Hilbert space of the FermionLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionLindbladOpenSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_modes(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the FermionLindbladOpenSystem.
+    ///
+    /// The estimate is for the sparse superoperator of the Hamiltonian and the noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionLindbladOpenSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Hamiltonian terms contribute to the commutator twice, Lindblad terms three times
+        let entries_per_row =
+            2 * hermitian_term_count(self.system().keys()) + 3 * self.noise().len();
+        sparse_matrix_memory(dimension, entries_per_row, bytes_per_entry)
+    }
+}
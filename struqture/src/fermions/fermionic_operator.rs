@@ -10,17 +10,30 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{FermionHamiltonian, OperateOnFermions};
-use crate::fermions::FermionProduct;
-use crate::mappings::JordanWignerFermionToSpin;
+use super::{FermionHamiltonian, FermionIndex, OperateOnFermions};
+use crate::basis::{fixed_particle_number_states, rank_fixed_particle_number_state};
+use crate::fermions::{FermionProduct, HermitianFermionProduct};
+use crate::mappings::encoding::encode_fermion_operator;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
 use crate::spins::SpinOperator;
 use crate::{
-    GetValue, ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, commutator_residual_terms,
+    expression_from_terms, fourier_transform_terms, non_hermitian_terms_error, operator_powi,
+    parse_canonical_text_entries, parse_expression_terms, split_canonical_text, CanonicalText,
+    CoefficientEntry, CooSparseMatrix, GetValue, ModeIndex, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnModes, OperateOnState, OperatorExpression, SpectralNormBound,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy,
+    MINIMUM_STRUQTURE_VERSION,
 };
 // use itertools::Itertools;
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -67,6 +80,8 @@ pub struct FermionOperator {
     internal_map: IndexMap<FermionProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<FermionProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 impl crate::MinSupportedVersion for FermionOperator {}
 
@@ -81,27 +96,66 @@ impl schemars::JsonSchema for FermionOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct FermionOperatorSerialize {
     items: Vec<(FermionProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for FermionOperatorSerialize {
+    /// Serialization function for FermionOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "FermionOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<FermionOperatorSerialize> for FermionOperator {
     fn from(value: FermionOperatorSerialize) -> Self {
-        let new_noise_op: FermionOperator = value
+        let mut new_noise_op: FermionOperator = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<FermionOperator> for FermionOperatorSerialize {
     fn from(value: FermionOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(FermionProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -112,11 +166,21 @@ impl From<FermionOperator> for FermionOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for FermionOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = FermionOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionOperator {
     type Index = FermionProduct;
     type Value = CalculatorComplex;
@@ -161,10 +225,25 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the FermionOperator with the given (FermionProduct key, CalculatorComplex value) pair.
@@ -183,7 +262,7 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -199,6 +278,13 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionOperator {
 
 impl<'a> OperateOnState<'a> for FermionOperator {}
 
+impl<'a> OperateOnConstant<'a> for FermionOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        FermionProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for FermionOperator {
     // From trait
     fn current_number_modes(&'a self) -> usize {
@@ -248,6 +334,7 @@ impl FermionOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -266,9 +353,51 @@ impl FermionOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
+    /// Creates the FermionOperator counting the particles in the given modes, $\sum_i c_i^{\dagger} c_i$.
+    ///
+    /// Every mode that is given several times contributes its number operator several times.
+    ///
+    /// # Arguments
+    ///
+    /// * `modes` - The modes whose occupation is counted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The FermionOperator with the number operator of every mode with coefficient one.
+    pub fn occupation_operator(modes: impl IntoIterator<Item = usize>) -> Self {
+        let mut new_operator = Self::new();
+        for mode in modes {
+            new_operator
+                .add_operator_product(FermionProduct::number(mode), CalculatorComplex::from(1.0))
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+
+    /// Returns a mutable handle to the coefficient of a key, see [CoefficientEntry].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The FermionProduct whose coefficient is accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `CoefficientEntry<FermionProduct, CalculatorComplex>` - The handle to the coefficient, which is zero if the key is not in the FermionOperator.
+    pub fn entry(
+        &mut self,
+        key: FermionProduct,
+    ) -> CoefficientEntry<FermionProduct, CalculatorComplex> {
+        CoefficientEntry::new(
+            self.internal_map.entry(key),
+            CalculatorComplex::ZERO,
+            self.zero_term_policy,
+        )
+    }
+
     /// Separate self into an operator with the terms of given number of creation and annihilation operators and an operator with the remaining operations
     ///
     /// # Arguments
@@ -293,6 +422,542 @@ impl FermionOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the largest change of the particle number caused by a term of the FermionOperator.
+    ///
+    /// A term with c creators and a annihilators changes the particle number by c - a.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The maximum of |c - a| over all terms, 0 if the FermionOperator conserves the particle number.
+    pub fn max_particle_number_change(&self) -> usize {
+        self.keys()
+            .map(|prod| prod.creators().len().abs_diff(prod.annihilators().len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the changes of the particle number caused by the terms of the FermionOperator.
+    ///
+    /// For every change d in the returned set, the FermionOperator couples the sector with N particles to the sector
+    /// with N + d particles. The FermionOperator is block-diagonal in the particle number sectors if the set only contains 0.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<isize>` - The particle number changes c - a of all terms, sorted.
+    pub fn particle_number_sectors(&self) -> BTreeSet<isize> {
+        self.keys()
+            .map(|prod| prod.creators().len() as isize - prod.annihilators().len() as isize)
+            .collect()
+    }
+
+    /// Constructs the sparse matrix of the FermionOperator in the sector with a fixed number of particles.
+    ///
+    /// The basis states of the sector are the occupation number states with `number_particles` occupied modes,
+    /// encoded as integers where bit j is the occupation of mode j. They are sorted by increasing integer value as in
+    /// [crate::basis::fixed_particle_number_states], so the matrix is the block of the full Jordan-Wigner matrix
+    /// in the sector, with dimension binomial(number_modes, number_particles).
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The number of fermionic modes.
+    /// * `number_particles` - The number of particles in the sector.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The matrix of the FermionOperator in the sector, entries sorted by row and column.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - The FermionOperator acts on more modes than number_modes.
+    /// * `Err(StruqtureError::GenericError)` - The FermionOperator does not conserve the particle number or the sector does not exist.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn sparse_matrix_in_sector(
+        &self,
+        number_modes: usize,
+        number_particles: usize,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        if self.current_number_modes() > number_modes {
+            return Err(StruqtureError::NumberModesExceeded);
+        }
+        if self.max_particle_number_change() != 0 {
+            return Err(StruqtureError::GenericError {
+                msg: "The FermionOperator does not conserve the particle number".to_string(),
+            });
+        }
+        let states = fixed_particle_number_states(number_modes, number_particles)?;
+        let mut terms: Vec<(&FermionProduct, Complex64)> = Vec::with_capacity(self.len());
+        for (prod, value) in self.iter() {
+            terms.push((
+                prod,
+                Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?),
+            ));
+        }
+        let mut entries: BTreeMap<(usize, usize), Complex64> = BTreeMap::new();
+        for (column, state) in states.enumerate() {
+            for (prod, value) in terms.iter() {
+                if let Some((new_state, sign)) = apply_fermion_product(prod, state) {
+                    // The particle number is conserved, so the new state is part of the sector
+                    let row = rank_fixed_particle_number_state(new_state);
+                    *entries
+                        .entry((row, column))
+                        .or_insert(Complex64::new(0.0, 0.0)) += *value * sign;
+                }
+            }
+        }
+        let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+        let mut rows: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+        for ((row, column), value) in entries {
+            if value != Complex64::new(0.0, 0.0) {
+                values.push(value);
+                rows.push(row);
+                columns.push(column);
+            }
+        }
+        Ok((values, (rows, columns)))
+    }
+
+    /// Separate self into an operator with the excitations of the given ranks and an operator with the remaining terms.
+    ///
+    /// The excitation ranks are relative to the reference determinant with the given occupied orbitals,
+    /// see [crate::fermions::FermionIndex::excitation_rank]. For example, `ranks = [1, 2]` selects the singles and doubles.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupied` - The orbitals occupied in the reference determinant.
+    /// * `ranks` - The excitation ranks to filter for.
+    ///
+    /// # Returns
+    ///
+    /// `Ok((separated, remainder))` - Operator with the excitations of the given ranks and Operator with all other contributions.
+    pub fn separate_by_excitation_rank(
+        &self,
+        occupied: &[usize],
+        ranks: &[usize],
+    ) -> Result<(Self, Self), StruqtureError> {
+        let mut separated = Self::default();
+        let mut remainder = Self::default();
+        for (prod, val) in self.iter() {
+            match prod.excitation_rank(occupied) {
+                Some(rank) if ranks.contains(&rank) => {
+                    separated.add_operator_product(prod.clone(), val.clone())?
+                }
+                _ => remainder.add_operator_product(prod.clone(), val.clone())?,
+            }
+        }
+        Ok((separated, remainder))
+    }
+
+    /// Classify the terms of self by their excitation rank relative to a reference determinant.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupied` - The orbitals occupied in the reference determinant.
+    ///
+    /// # Returns
+    ///
+    /// `Ok((excitations, remainder))` - Operators with the excitations of each rank, sorted by rank, and Operator with the terms that are not pure excitations.
+    pub fn group_by_excitation_rank(
+        &self,
+        occupied: &[usize],
+    ) -> Result<(BTreeMap<usize, Self>, Self), StruqtureError> {
+        let mut excitations: BTreeMap<usize, Self> = BTreeMap::new();
+        let mut remainder = Self::default();
+        for (prod, val) in self.iter() {
+            match prod.excitation_rank(occupied) {
+                Some(rank) => excitations
+                    .entry(rank)
+                    .or_default()
+                    .add_operator_product(prod.clone(), val.clone())?,
+                None => remainder.add_operator_product(prod.clone(), val.clone())?,
+            }
+        }
+        Ok((excitations, remainder))
+    }
+
+    /// Creates the anti-hermitian generators of the single and double excitations of a reference determinant, as used by UCCSD ansätze.
+    ///
+    /// The single excitation from the occupied orbital i to the virtual orbital a has the generator
+    /// $c_a^{\dagger} c_i - c_i^{\dagger} c_a$ and the amplitude `t_a_i`.
+    /// The double excitation from the occupied orbitals i < j to the virtual orbitals a < b has the generator
+    /// $c_a^{\dagger} c_b^{\dagger} c_j c_i - c_i^{\dagger} c_j^{\dagger} c_b c_a$ and the amplitude `t_a_b_i_j`.
+    /// The UCCSD generator is the sum of all generators multiplied by their symbolic amplitudes.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupied` - The orbitals occupied in the reference determinant.
+    /// * `virtual_orbitals` - The orbitals empty in the reference determinant.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Self)>)` - The names of the amplitudes and the generators, singles before doubles.
+    /// * `Err(StruqtureError::GenericError)` - An orbital is given more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorFloat;
+    /// use struqture::fermions::FermionOperator;
+    /// use struqture::prelude::*;
+    ///
+    /// let excitations = FermionOperator::singles_doubles_excitations(&[0, 1], &[2, 3]).unwrap();
+    /// assert_eq!(excitations.len(), 5);
+    /// assert_eq!(excitations[4].0, "t_2_3_0_1");
+    ///
+    /// let mut uccsd = FermionOperator::new();
+    /// for (amplitude, generator) in excitations {
+    ///     uccsd = uccsd + generator * CalculatorFloat::from(amplitude);
+    /// }
+    /// assert_eq!(uccsd.len(), 10);
+    /// ```
+    pub fn singles_doubles_excitations(
+        occupied: &[usize],
+        virtual_orbitals: &[usize],
+    ) -> Result<Vec<(String, Self)>, StruqtureError> {
+        let mut orbitals: BTreeSet<usize> = BTreeSet::new();
+        for orbital in occupied.iter().chain(virtual_orbitals.iter()) {
+            if !orbitals.insert(*orbital) {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Orbital {} is given more than once in the occupied and virtual orbitals",
+                        orbital
+                    ),
+                });
+            }
+        }
+        let mut occupied = occupied.to_vec();
+        occupied.sort_unstable();
+        let mut virtual_orbitals = virtual_orbitals.to_vec();
+        virtual_orbitals.sort_unstable();
+
+        let mut generators: Vec<(String, Self)> = Vec::new();
+        for i in occupied.iter() {
+            for a in virtual_orbitals.iter() {
+                generators.push((
+                    format!("t_{}_{}", a, i),
+                    Self::excitation_generator(&[*a], &[*i])?,
+                ));
+            }
+        }
+        for (position_j, j) in occupied.iter().enumerate() {
+            for i in occupied[..position_j].iter() {
+                for (position_b, b) in virtual_orbitals.iter().enumerate() {
+                    for a in virtual_orbitals[..position_b].iter() {
+                        generators.push((
+                            format!("t_{}_{}_{}_{}", a, b, i, j),
+                            Self::excitation_generator(&[*a, *b], &[*i, *j])?,
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(generators)
+    }
+
+    /// Returns the generator T - T^dagger of the excitation T = c_{a_1}^dagger ... c_{a_n}^dagger c_{i_n} ... c_{i_1}.
+    fn excitation_generator(
+        virtual_orbitals: &[usize],
+        occupied: &[usize],
+    ) -> Result<Self, StruqtureError> {
+        let (excitation, sign) = FermionProduct::create_valid_pair(
+            virtual_orbitals.iter().copied(),
+            occupied.iter().rev().copied(),
+            CalculatorComplex::from(1.0),
+        )?;
+        let (deexcitation, prefactor) = excitation.hermitian_conjugate();
+        let mut generator = Self::with_capacity(2);
+        generator.add_operator_product(excitation, sign.clone())?;
+        generator.add_operator_product(deexcitation, sign * (-prefactor))?;
+        Ok(generator)
+    }
+
+    /// Returns true if the FermionOperator commutes with a FermionHamiltonian, i.e. if it is a conserved quantity of the FermionHamiltonian.
+    ///
+    /// The commutator [A, H] is evaluated term by term without constructing matrices. Symbolic terms of the commutator
+    /// only vanish when they cancel structurally, numerical terms when they cancel up to floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The FermionHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the commutator [A, H] vanishes.
+    pub fn commutes_with(&self, hamiltonian: &FermionHamiltonian) -> bool {
+        self.commutator_residual(hamiltonian)
+            .map(|residual| residual.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Returns the sum of the absolute values of the coefficients of the commutator [A, H] with a FermionHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The FermionHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The 1-norm of the commutator, 0.0 if the FermionOperator is conserved.
+    /// * `Err(StruqtureError::GenericError)` - Symbolic terms of the commutator do not cancel.
+    pub fn symmetry_violation_norm(
+        &self,
+        hamiltonian: &FermionHamiltonian,
+    ) -> Result<f64, StruqtureError> {
+        Ok(self
+            .commutator_residual(hamiltonian)?
+            .iter()
+            .map(|(_, value)| value.norm())
+            .sum())
+    }
+
+    /// Raises the FermionOperator to a non-negative integer power.
+    ///
+    /// The power is computed by repeated squaring. Terms whose coefficients cancel exactly are removed
+    /// after every multiplication to keep the number of terms manageable.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power, `powi(0)` is the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The FermionOperator raised to the power n.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn powi(&self, n: usize) -> Self {
+        let mut identity = FermionOperator::new();
+        identity
+            .add_operator_product(
+                FermionProduct::new([], []).expect("Internal bug in FermionProduct::new"),
+                CalculatorComplex::from(1.0),
+            )
+            .expect("Internal bug in add_operator_product");
+        operator_powi(self, n, identity, |left, right| {
+            Ok((left * right).truncate(f64::MIN_POSITIVE))
+        })
+        .expect("Internal bug in operator_powi")
+    }
+
+    /// Returns whether the FermionOperator is hermitian.
+    ///
+    /// Symbolic coefficients are compared structurally.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the FermionOperator equals its hermitian conjugate.
+    pub fn is_hermitian(&self) -> bool {
+        self.iter().all(|(product, value)| {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            *self.get(&conjugated_product) == value.conj() * prefactor
+        })
+    }
+
+    /// Returns the hermitian part (A + A^dagger) / 2 of the FermionOperator A.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian part of the FermionOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn hermitian_part(&self) -> Self {
+        self.hermitian_split_part(1.0)
+    }
+
+    /// Returns the anti-hermitian part (A - A^dagger) / 2 of the FermionOperator A.
+    ///
+    /// The FermionOperator is the sum of its hermitian and anti-hermitian parts.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anti-hermitian part of the FermionOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn anti_hermitian_part(&self) -> Self {
+        self.hermitian_split_part(-1.0)
+    }
+
+    /// Returns (A + sign * A^dagger) / 2 for the FermionOperator A.
+    fn hermitian_split_part(&self, sign: f64) -> Self {
+        let mut part = self.empty_clone(Some(2 * self.len()));
+        for (product, value) in self.iter() {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            part.add_operator_product(product.clone(), value.clone() * 0.5)
+                .expect("Internal bug in add_operator_product");
+            part.add_operator_product(conjugated_product, value.conj() * (0.5 * sign * prefactor))
+                .expect("Internal bug in add_operator_product");
+        }
+        part
+    }
+
+    /// Converts the FermionOperator into a FermionHamiltonian if it is hermitian.
+    ///
+    /// In contrast to `FermionHamiltonian::try_from`, terms stored together with their hermitian conjugate are accepted
+    /// and the error lists all terms violating hermiticity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionHamiltonian)` - The FermionOperator as a FermionHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianTerms)` - The keys and residuals A - A^dagger of the terms violating hermiticity.
+    pub fn try_into_hamiltonian(&self) -> Result<FermionHamiltonian, StruqtureError> {
+        if !self.is_hermitian() {
+            return Err(non_hermitian_terms_error(self.anti_hermitian_part().iter()));
+        }
+        let mut hamiltonian = FermionHamiltonian::new();
+        for (product, value) in self.iter() {
+            // Only one term of every hermitian conjugate pair corresponds to a valid hermitian product
+            if let Ok(hermitian_product) = HermitianFermionProduct::new(
+                product.creators().copied(),
+                product.annihilators().copied(),
+            ) {
+                hamiltonian.add_operator_product(hermitian_product, value.clone())?;
+            }
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Returns the expectation value of the FermionOperator in a state with the given mode occupations using Wick's theorem.
+    ///
+    /// The state is a Slater determinant or a thermal mean-field state that is diagonal in the fermionic modes,
+    /// with the only non-vanishing contractions ⟨c^†_p c_p⟩ = n_p. Every FermionProduct is normal ordered, so
+    /// a term c^†_{a_1} ... c^†_{a_k} c_{b_1} ... c_{b_l} contributes only if the creators and annihilators act on the same modes.
+    /// The single full contraction pairs c^†_{a_i} with c_{a_i}, has k(k-1)/2 crossings and gives (-1)^{k(k-1)/2} n_{a_1} ... n_{a_k}.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupation` - The occupations n_p of the fermionic modes, between 0.0 and 1.0.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CalculatorComplex)` - The expectation value, symbolic if the coefficients are symbolic.
+    /// * `Err(StruqtureError::GenericError)` - An occupation is not between 0.0 and 1.0.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on a mode without a given occupation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionOperator, FermionProduct};
+    ///
+    /// let mut operator = FermionOperator::new();
+    /// operator
+    ///     .add_operator_product(FermionProduct::new([0, 1], [0, 1]).unwrap(), CalculatorComplex::from(2.0))
+    ///     .unwrap();
+    /// // c^†_0 c^†_1 c_0 c_1 = -n_0 n_1
+    /// let expectation = operator.wick_expectation(&[1.0, 0.5]).unwrap();
+    /// assert_eq!(expectation, CalculatorComplex::from(-1.0));
+    /// ```
+    pub fn wick_expectation(
+        &self,
+        occupation: &[f64],
+    ) -> Result<CalculatorComplex, StruqtureError> {
+        if let Some(invalid) = occupation.iter().find(|n| !(0.0..=1.0).contains(*n)) {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Occupation {} is not between 0.0 and 1.0", invalid),
+            });
+        }
+        let mut expectation = CalculatorComplex::from(0.0);
+        for (product, value) in self.iter() {
+            let contraction = wick_contraction(product, occupation)?;
+            if contraction != 0.0 {
+                expectation = expectation + value.clone() * contraction;
+            }
+        }
+        Ok(expectation)
+    }
+
+    /// Transforms the FermionOperator on a one-dimensional periodic lattice to momentum space.
+    ///
+    /// Mode `m` is the orbital `m / lattice_size` on the site r = `m % lattice_size`,
+    /// so the modes of every orbital on the L sites of the lattice are consecutive. The operators are transformed with
+    /// c_r = 1/sqrt(L) Σ_n exp(i k_n r) c_(k_n) and c^†_r = 1/sqrt(L) Σ_n exp(-i k_n r) c^†_(k_n) with k_n = 2π n / L.
+    /// The momentum mode k_n of orbital o has the index n + L o, keeping the layout of the modes in real space.
+    /// Contributions that cancel, e.g. the terms of a translation invariant operator not conserving the momentum,
+    /// are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `lattice_size` - The number of sites L of the lattice.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator in momentum space.
+    /// * `Err(StruqtureError::GenericError)` - The lattice size is zero or a term has too many contributions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionOperator, FermionProduct};
+    ///
+    /// // Hopping on a ring of two sites
+    /// let mut operator = FermionOperator::new();
+    /// for (i, j) in [(0, 1), (1, 0)] {
+    ///     operator
+    ///         .add_operator_product(FermionProduct::new([i], [j]).unwrap(), CalculatorComplex::from(1.0))
+    ///         .unwrap();
+    /// }
+    /// // The hopping is diagonal in momentum space with the energies 1 at k = 0 and -1 at k = π
+    /// let transformed = operator.fourier_transform(2).unwrap();
+    /// assert_eq!(transformed.len(), 2);
+    /// assert_eq!(transformed.get(&FermionProduct::new([0], [0]).unwrap()), &CalculatorComplex::from(1.0));
+    /// assert_eq!(transformed.get(&FermionProduct::new([1], [1]).unwrap()), &CalculatorComplex::from(-1.0));
+    /// ```
+    pub fn fourier_transform(&self, lattice_size: usize) -> Result<Self, StruqtureError> {
+        let mut transformed = Self::new();
+        for (key, value) in fourier_transform_terms(self.iter(), lattice_size)? {
+            transformed.add_operator_product(key, value)?;
+        }
+        Ok(transformed)
+    }
+
+    /// Returns the terms of the commutator with a FermionHamiltonian that do not cancel.
+    fn commutator_residual(
+        &self,
+        hamiltonian: &FermionHamiltonian,
+    ) -> Result<Vec<(FermionProduct, Complex64)>, StruqtureError> {
+        let hamiltonian = FermionOperator::from(hamiltonian.clone());
+        commutator_residual_terms(self.iter(), hamiltonian.iter(), |left, right| {
+            (left.clone() * right.clone())
+                .into_iter()
+                .map(|(product, prefactor)| (product, Complex64::new(prefactor, 0.0)))
+                .collect()
+        })
+    }
+}
+
+/// Returns the full Wick contraction of a normal ordered FermionProduct in a state diagonal in the modes.
+fn wick_contraction(product: &FermionProduct, occupation: &[f64]) -> Result<f64, StruqtureError> {
+    if let Some(mode) = product
+        .creators()
+        .chain(product.annihilators())
+        .find(|mode| **mode >= occupation.len())
+    {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "No occupation given for mode {}, only {} occupations are given",
+                mode,
+                occupation.len()
+            ),
+        });
+    }
+    if !product.creators().eq(product.annihilators()) {
+        return Ok(0.0);
+    }
+    let number_pairs = product.number_creators();
+    let sign = if (number_pairs * number_pairs.saturating_sub(1) / 2) % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    };
+    Ok(product
+        .creators()
+        .fold(sign, |contraction, mode| contraction * occupation[*mode]))
 }
 
 impl From<FermionHamiltonian> for FermionOperator {
@@ -311,6 +976,9 @@ impl From<FermionHamiltonian> for FermionOperator {
     /// * Internal error in add_operator_product.
     fn from(hamiltonian: FermionHamiltonian) -> Self {
         let mut internal = FermionOperator::new();
+        internal
+            .set_zero_term_policy(hamiltonian.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
         for (key, value) in hamiltonian.into_iter() {
             let bp = FermionProduct::get_key(&key);
             internal
@@ -319,7 +987,10 @@ impl From<FermionHamiltonian> for FermionOperator {
             if !key.is_natural_hermitian() {
                 let bp_conj = bp.hermitian_conjugate();
                 internal
-                    .add_operator_product(FermionProduct::get_key(&bp_conj.0), value * bp_conj.1)
+                    .add_operator_product(
+                        FermionProduct::get_key(&bp_conj.0),
+                        value.conj() * bp_conj.1,
+                    )
                     .expect("Internal error in add_operator_product");
             }
         }
@@ -337,12 +1008,14 @@ impl ops::Neg for FermionOperator {
     ///
     /// * `Self` - The FermionOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * -1.0);
         }
         FermionOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -416,13 +1089,16 @@ where
     ///
     /// * `Self` - The FermionOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         FermionOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -446,6 +1122,7 @@ impl ops::Mul<FermionOperator> for FermionOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, other: FermionOperator) -> Self {
         let mut op = FermionOperator::with_capacity(self.len() * other.len());
+        op.zero_term_policy = self.zero_term_policy;
         for (bps, vals) in self {
             for (bpo, valo) in other.iter() {
                 let fermion_products = bps.clone() * bpo.clone();
@@ -563,6 +1240,82 @@ impl fmt::Display for FermionOperator {
     }
 }
 
+impl CanonicalText for FermionOperator {
+    /// Returns the canonical text representation of the FermionOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document("FermionOperator", &[], &canonical_text_entries(self.iter()))
+    }
+
+    /// Creates a FermionOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "FermionOperator", &[])?;
+        let mut new_self = FermionOperator::new();
+        for (key, value) in
+            parse_canonical_text_entries::<FermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for FermionOperator {
+    /// Returns the expression of the FermionOperator, e.g. `0.5*c0a1 - i*c1`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a FermionOperator from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionOperator represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the FermionOperator.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = FermionOperator::new();
+        for (key, value) in parse_expression_terms::<FermionProduct, CalculatorComplex>(expression)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for FermionOperator {
+    /// Returns an upper bound of the spectral norm of the FermionOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The FermionOperator contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.coefficient_1_norm()
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionOperator {
     type Output = SpinOperator;
 
@@ -583,6 +1336,86 @@ impl JordanWignerFermionToSpin for FermionOperator {
     }
 }
 
+impl FermionToSpin for FermionOperator {
+    type Output = SpinOperator;
+
+    /// Implements FermionToSpin for a FermionOperator.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the current number of modes of the FermionOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        encode_fermion_operator(self, encoding, self.current_number_modes())
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionOperator {
+    type Output = SpinOperator;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionOperator.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the current number of modes of the FermionOperator.
+    ///
+    /// # Returns
+    ///
+    /// `SpinOperator` - The spin operator that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+/// Applies a FermionProduct to an occupation number state.
+///
+/// The annihilators are applied before the creators, from the right, with the Jordan-Wigner sign
+/// of the number of occupied modes with a lower index.
+///
+/// # Returns
+///
+/// * `Some((usize, f64))` - The resulting state and its sign.
+/// * `None` - The FermionProduct annihilates the state.
+fn apply_fermion_product(prod: &FermionProduct, state: usize) -> Option<(usize, f64)> {
+    let mut state = state;
+    let mut sign = 1.0;
+    for annihilator in prod.annihilators().rev().copied() {
+        if state & (1 << annihilator) == 0 {
+            return None;
+        }
+        state ^= 1 << annihilator;
+        if (state & ((1 << annihilator) - 1)).count_ones() % 2 == 1 {
+            sign = -sign;
+        }
+    }
+    for creator in prod.creators().rev().copied() {
+        if state & (1 << creator) != 0 {
+            return None;
+        }
+        if (state & ((1 << creator) - 1)).count_ones() % 2 == 1 {
+            sign = -sign;
+        }
+        state |= 1 << creator;
+    }
+    Some((state, sign))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
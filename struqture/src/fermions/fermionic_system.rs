@@ -12,11 +12,24 @@
 
 use super::{FermionOperator, OperateOnFermions};
 use crate::fermions::FermionProduct;
-use crate::mappings::JordanWignerFermionToSpin;
+use crate::mappings::encoding::encode_fermion_operator;
+use crate::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerFermionToSpin,
+};
+use crate::metadata::merge_operand_metadata;
 use crate::spins::SpinSystem;
-use crate::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError};
+use crate::{
+    canonical_number, canonical_text_document, canonical_text_entries, hilbert_space_dimension,
+    parse_canonical_number, parse_canonical_text_entries, sparse_matrix_memory,
+    split_canonical_text, CanonicalText, ModeIndex, OperateOnConstant, OperateOnDensityMatrix,
+    OperateOnModes, OperateOnState, SpectralNormBound, StruqtureError, SystemDimension,
+    ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -51,7 +64,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(fo.get(&fp_1), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct FermionSystem {
@@ -59,10 +72,88 @@ pub struct FermionSystem {
     pub(crate) number_modes: Option<usize>,
     /// The FermionOperator representing the operator of the FermionSystem.
     pub(crate) operator: FermionOperator,
+    /// Provenance metadata of the FermionSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for FermionSystem {}
 
+impl Serialize for FermionSystem {
+    /// Serialization function for FermionSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - FermionSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of FermionSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for FermionSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a FermionSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - FermionSystem to be serialized.
+/// * `operator` - Serialization of the operator of the FermionSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of FermionSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &FermionSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("FermionSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_modes", &system.number_modes)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for FermionSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for FermionSystem {
     type Index = FermionProduct;
     type Value = CalculatorComplex;
@@ -100,15 +191,27 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionSystem {
         match capacity {
             Some(cap) => Self {
                 number_modes: self.number_modes,
-                operator: FermionOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_modes: self.number_modes,
-                operator: FermionOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the FermionOperator with the given (FermionProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -169,6 +272,13 @@ impl<'a> OperateOnDensityMatrix<'a> for FermionSystem {
 
 impl<'a> OperateOnState<'a> for FermionSystem {}
 
+impl<'a> OperateOnConstant<'a> for FermionSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        FermionProduct::default()
+    }
+}
+
 impl<'a> OperateOnModes<'a> for FermionSystem {
     /// Gets the number_modes input of the FermionSystem, or the current_number_modes if number_modes is None.
     ///
@@ -206,6 +316,7 @@ impl FermionSystem {
         FermionSystem {
             number_modes,
             operator: FermionOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -223,6 +334,7 @@ impl FermionSystem {
         FermionSystem {
             number_modes,
             operator: FermionOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -256,6 +368,7 @@ impl FermionSystem {
                     Ok(FermionSystem {
                         number_modes: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberModesExceeded)
@@ -264,6 +377,7 @@ impl FermionSystem {
             None => Ok(FermionSystem {
                 number_modes: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -313,7 +427,7 @@ impl ops::Neg for FermionSystem {
 ///
 impl<T, V> ops::Add<T> for FermionSystem
 where
-    T: IntoIterator<Item = (FermionProduct, V)>,
+    T: IntoIterator<Item = (FermionProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -328,6 +442,7 @@ where
     /// * `Ok(Self)` - The two FermionSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of FermionProduct exceeds that of the FermionSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -339,7 +454,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for FermionSystem
 where
-    T: IntoIterator<Item = (FermionProduct, V)>,
+    T: IntoIterator<Item = (FermionProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -354,6 +469,7 @@ where
     /// * `Ok(Self)` - The two FermionSystems subtracted.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of FermionProduct exceeds that of the FermionSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -401,9 +517,12 @@ impl ops::Mul<FermionSystem> for FermionSystem {
     ///
     /// * Internal error in add_operator_product.
     fn mul(self, other: FermionSystem) -> Self {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<FermionSystem>(&mut metadata, &other);
         FermionSystem {
             number_modes: Some(self.number_modes().max(other.number_modes())),
             operator: self.operator * other.operator,
+            metadata,
         }
     }
 }
@@ -511,6 +630,55 @@ impl fmt::Display for FermionSystem {
     }
 }
 
+impl CanonicalText for FermionSystem {
+    /// Returns the canonical text representation of the FermionSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the FermionSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "FermionSystem",
+            &[("number_modes", canonical_number(&self.number_modes))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a FermionSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The FermionSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a FermionSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the FermionSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(text, "FermionSystem", &["number_modes"])?;
+        let mut new_self = FermionSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_text_entries::<FermionProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for FermionSystem {
+    /// Returns an upper bound of the spectral norm of the FermionSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The FermionSystem contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.operator().spectral_norm_bound()
+    }
+}
+
 impl JordanWignerFermionToSpin for FermionSystem {
     type Output = SpinSystem;
 
@@ -533,3 +701,93 @@ impl JordanWignerFermionToSpin for FermionSystem {
             .expect("Internal bug in jordan_wigner for FermionSystem. The number of spins in the resulting SpinSystem should equal the number of modes of the FermionSystem.")
     }
 }
+
+impl FermionToSpin for FermionSystem {
+    type Output = SpinSystem;
+
+    /// Implements FermionToSpin for a FermionSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The sets of the encoding are constructed for the number of modes of the FermionSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinSystem)` - The spin system that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError> {
+        SpinSystem::from_operator(
+            encode_fermion_operator(self.operator(), encoding, self.number_modes())?,
+            self.number_modes,
+        )
+    }
+}
+
+impl BravyiKitaevFermionToSpin for FermionSystem {
+    type Output = SpinSystem;
+
+    /// Implements BravyiKitaevFermionToSpin for a FermionSystem.
+    ///
+    /// The convention used is that |0> represents an empty fermionic state (spin-orbital),
+    /// and |1> represents an occupied fermionic state.
+    /// The update sets are constructed for the number of modes of the FermionSystem.
+    ///
+    /// # Returns
+    ///
+    /// `SpinSystem` - The spin system that results from the transformation.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in the sets of the BravyiKitaevEncoding.
+    fn bravyi_kitaev(&self) -> Self::Output {
+        self.fermion_to_spin(&BravyiKitaevEncoding)
+            .expect("Internal bug in the sets of the BravyiKitaevEncoding.")
+    }
+}
+
+impl SystemDimension for FermionSystem {
+    /// Returns the dimension of the Hilbert space of the FermionSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_modes(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the FermionSystem.
+    ///
+    /// Every term of the FermionSystem has at most one non-zero entry per row of its sparse matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the FermionSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(dimension, self.len(), bytes_per_entry)
+    }
+}
@@ -27,9 +27,12 @@ mod fermionic_noise_system;
 mod fermionic_open_system;
 mod fermionic_operator;
 mod fermionic_system;
+mod reduced_density_matrices;
+mod spin_orbital_indices;
 use std::str::FromStr;
 
 pub use fermionic_hamiltonian::FermionHamiltonian;
+pub use fermionic_hamiltonian_system::DoubleFactorization;
 pub use fermionic_hamiltonian_system::FermionHamiltonianSystem;
 pub use fermionic_noise_operator::FermionLindbladNoiseOperator;
 pub use fermionic_noise_system::FermionLindbladNoiseSystem;
@@ -40,6 +43,10 @@ pub use fermionic_system::FermionSystem;
 use crate::{ModeIndex, OperateOnDensityMatrix, SymmetricIndex};
 pub use fermionic_indices::{FermionProduct, HermitianFermionProduct};
 use qoqo_calculator::CalculatorComplex;
+pub use reduced_density_matrices::{OneRDM, RdmIndex, TwoRDM};
+pub use spin_orbital_indices::{
+    SpinOrbital, SpinOrbitalFermionProduct, SpinOrbitalOrdering, SpinProjection,
+};
 
 /// Signal Trait for specifying that a type can be used a fermionic index.
 ///
@@ -57,6 +64,30 @@ pub trait FermionIndex:
     + FromStr
     + Default
 {
+    /// Returns the excitation rank of Self relative to a reference determinant.
+    ///
+    /// Self is an excitation of rank n when it moves n fermions from occupied to virtual orbitals of the reference determinant,
+    /// i.e. when it has n creators acting on virtual orbitals and n annihilators acting on occupied orbitals.
+    /// The identity has excitation rank 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupied` - The orbitals occupied in the reference determinant.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The excitation rank of Self.
+    /// * `None` - Self is not a pure excitation of the reference determinant.
+    fn excitation_rank(&self, occupied: &[usize]) -> Option<usize> {
+        if self.number_creators() == self.number_annihilators()
+            && self.creators().all(|index| !occupied.contains(index))
+            && self.annihilators().all(|index| occupied.contains(index))
+        {
+            Some(self.number_creators())
+        } else {
+            None
+        }
+    }
 }
 
 /// Trait for operations on fermions.
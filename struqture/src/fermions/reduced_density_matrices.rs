@@ -0,0 +1,310 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{FermionHamiltonianSystem, HermitianFermionProduct};
+use crate::{ModeIndex, OperateOnDensityMatrix, StruqtureError, SymmetricIndex};
+use ndarray::{Array2, Array4};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+
+/// Fermionic one-particle reduced density matrix γ_{pq} = ⟨c^†_p c_q⟩.
+///
+/// The mode indices are the indices of the fermionic modes in struqture.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::Array2;
+/// use num_complex::Complex64;
+/// use struqture::fermions::OneRDM;
+///
+/// // Mode 0 is occupied, mode 1 is empty
+/// let mut matrix: Array2<Complex64> = Array2::zeros((2, 2));
+/// matrix[(0, 0)] = 1.0.into();
+/// let one_rdm = OneRDM::new(matrix).unwrap();
+/// assert_eq!(one_rdm.number_modes(), 2);
+/// assert_eq!(one_rdm.get(0, 0), Some(Complex64::new(1.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OneRDM {
+    /// The matrix γ_{pq} = ⟨c^†_p c_q⟩.
+    matrix: Array2<Complex64>,
+}
+
+impl OneRDM {
+    /// Creates a new OneRDM.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The matrix γ with entries γ[(p, q)] = ⟨c^†_p c_q⟩.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new OneRDM.
+    /// * `Err(StruqtureError::GenericError)` - The matrix is not square.
+    pub fn new(matrix: Array2<Complex64>) -> Result<Self, StruqtureError> {
+        let (rows, columns) = matrix.dim();
+        if rows != columns {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The one-particle RDM must be square but has shape ({}, {})",
+                    rows, columns
+                ),
+            });
+        }
+        Ok(OneRDM { matrix })
+    }
+
+    /// Returns the number of fermionic modes of the OneRDM.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of modes.
+    pub fn number_modes(&self) -> usize {
+        self.matrix.nrows()
+    }
+
+    /// Returns the entry ⟨c^†_p c_q⟩ of the OneRDM.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The index of the creator.
+    /// * `q` - The index of the annihilator.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Complex64)` - The entry of the OneRDM.
+    /// * `None` - An index exceeds the number of modes.
+    pub fn get(&self, p: usize, q: usize) -> Option<Complex64> {
+        self.matrix.get((p, q)).copied()
+    }
+
+    /// Returns the matrix of the OneRDM.
+    ///
+    /// # Returns
+    ///
+    /// * `&Array2<Complex64>` - The matrix γ[(p, q)] = ⟨c^†_p c_q⟩.
+    pub fn matrix(&self) -> &Array2<Complex64> {
+        &self.matrix
+    }
+}
+
+/// Fermionic two-particle reduced density matrix Γ_{pqrs} = ⟨c^†_p c^†_q c_r c_s⟩.
+///
+/// The mode indices are the indices of the fermionic modes in struqture. The operators are in the
+/// order of a FermionProduct, so the term c^†_p c^†_q c_r c_s with p < q and r < s corresponds
+/// to the entry Γ[(p, q, r, s)].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoRDM {
+    /// The tensor Γ_{pqrs} = ⟨c^†_p c^†_q c_r c_s⟩.
+    tensor: Array4<Complex64>,
+}
+
+impl TwoRDM {
+    /// Creates a new TwoRDM.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor Γ with entries Γ[(p, q, r, s)] = ⟨c^†_p c^†_q c_r c_s⟩.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new TwoRDM.
+    /// * `Err(StruqtureError::GenericError)` - The tensor does not have the same dimension along all axes.
+    pub fn new(tensor: Array4<Complex64>) -> Result<Self, StruqtureError> {
+        let shape = tensor.dim();
+        if shape.1 != shape.0 || shape.2 != shape.0 || shape.3 != shape.0 {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The two-particle RDM must have the same dimension along all axes but has shape {:?}",
+                    shape
+                ),
+            });
+        }
+        Ok(TwoRDM { tensor })
+    }
+
+    /// Returns the number of fermionic modes of the TwoRDM.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of modes.
+    pub fn number_modes(&self) -> usize {
+        self.tensor.dim().0
+    }
+
+    /// Returns the entry ⟨c^†_p c^†_q c_r c_s⟩ of the TwoRDM.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The index of the first creator.
+    /// * `q` - The index of the second creator.
+    /// * `r` - The index of the first annihilator.
+    /// * `s` - The index of the second annihilator.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Complex64)` - The entry of the TwoRDM.
+    /// * `None` - An index exceeds the number of modes.
+    pub fn get(&self, p: usize, q: usize, r: usize, s: usize) -> Option<Complex64> {
+        self.tensor.get((p, q, r, s)).copied()
+    }
+
+    /// Returns the tensor of the TwoRDM.
+    ///
+    /// # Returns
+    ///
+    /// * `&Array4<Complex64>` - The tensor Γ[(p, q, r, s)] = ⟨c^†_p c^†_q c_r c_s⟩.
+    pub fn tensor(&self) -> &Array4<Complex64> {
+        &self.tensor
+    }
+}
+
+/// Entry of a reduced density matrix giving the expectation value of a fermionic term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RdmIndex {
+    /// The identity term, with expectation value one.
+    Identity,
+    /// The entry (p, q) of the OneRDM.
+    One(usize, usize),
+    /// The entry (p, q, r, s) of the TwoRDM.
+    Two(usize, usize, usize, usize),
+}
+
+impl RdmIndex {
+    /// Returns the RDM entry of the expectation value of a particle-number conserving fermionic term.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The creator indices of the term in the order of the operators.
+    /// * `annihilators` - The annihilator indices of the term in the order of the operators.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The RDM entry of the term.
+    /// * `Err(StruqtureError::GenericError)` - The term is not a zero-, one- or two-body term conserving the particle number.
+    fn from_modes(creators: &[usize], annihilators: &[usize]) -> Result<Self, StruqtureError> {
+        match (creators, annihilators) {
+            ([], []) => Ok(RdmIndex::Identity),
+            ([p], [q]) => Ok(RdmIndex::One(*p, *q)),
+            ([p, q], [r, s]) => Ok(RdmIndex::Two(*p, *q, *r, *s)),
+            _ => Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The term with creators {:?} and annihilators {:?} is not a particle-number conserving term with at most two bodies",
+                    creators, annihilators
+                ),
+            }),
+        }
+    }
+
+    /// Returns the expectation value of the RDM entry.
+    fn expectation_value(
+        &self,
+        one_rdm: &OneRDM,
+        two_rdm: &TwoRDM,
+    ) -> Result<Complex64, StruqtureError> {
+        let value = match self {
+            RdmIndex::Identity => Some(Complex64::new(1.0, 0.0)),
+            RdmIndex::One(p, q) => one_rdm.get(*p, *q),
+            RdmIndex::Two(p, q, r, s) => two_rdm.get(*p, *q, *r, *s),
+        };
+        value.ok_or_else(|| StruqtureError::GenericError {
+            msg: format!(
+                "RDM entry {:?} exceeds the number of modes of the RDM",
+                self
+            ),
+        })
+    }
+}
+
+impl FermionHamiltonianSystem {
+    /// Returns the RDM entries of the terms of the FermionHamiltonianSystem.
+    ///
+    /// Every term c P + c^* P^† is mapped to the entry giving the expectation value of P,
+    /// the entry of P^† is the one with creators and annihilators exchanged and reversed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<HermitianFermionProduct, RdmIndex>)` - The RDM entry of every term.
+    /// * `Err(StruqtureError::GenericError)` - A term is not a zero-, one- or two-body term conserving the particle number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct, RdmIndex};
+    ///
+    /// let mut system = FermionHamiltonianSystem::new(None);
+    /// let hopping = HermitianFermionProduct::new([0], [1]).unwrap();
+    /// system.add_operator_product(hopping.clone(), CalculatorComplex::from(-1.0)).unwrap();
+    /// let map = system.rdm_index_map().unwrap();
+    /// assert_eq!(map.get(&hopping), Some(&RdmIndex::One(0, 1)));
+    /// ```
+    pub fn rdm_index_map(
+        &self,
+    ) -> Result<HashMap<HermitianFermionProduct, RdmIndex>, StruqtureError> {
+        self.keys()
+            .map(|product| {
+                let creators: Vec<usize> = product.creators().copied().collect();
+                let annihilators: Vec<usize> = product.annihilators().copied().collect();
+                Ok((
+                    product.clone(),
+                    RdmIndex::from_modes(&creators, &annihilators)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Computes the energy of the FermionHamiltonianSystem from the one- and two-particle reduced density matrices of a state.
+    ///
+    /// Every term c P + c^* P^† contributes c ⟨P⟩ + c^* ⟨P^†⟩, with both expectation values taken from the RDMs.
+    /// For RDMs of a physical state the energy is real. Only the real part is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `one_rdm` - The one-particle reduced density matrix of the state.
+    /// * `two_rdm` - The two-particle reduced density matrix of the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The energy of the state.
+    /// * `Err(StruqtureError::GenericError)` - A term is not a zero-, one- or two-body term conserving the particle number or exceeds the modes of the RDMs.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn energy_from_rdms(
+        &self,
+        one_rdm: &OneRDM,
+        two_rdm: &TwoRDM,
+    ) -> Result<f64, StruqtureError> {
+        let mut energy = Complex64::new(0.0, 0.0);
+        for (product, index) in self.rdm_index_map()? {
+            let coefficient = complex_coefficient(self.get(&product))?;
+            energy += coefficient * index.expectation_value(one_rdm, two_rdm)?;
+            if !product.is_natural_hermitian() {
+                let creators: Vec<usize> = product.annihilators().rev().copied().collect();
+                let annihilators: Vec<usize> = product.creators().rev().copied().collect();
+                let conjugated = RdmIndex::from_modes(&creators, &annihilators)?;
+                energy += coefficient.conj() * conjugated.expectation_value(one_rdm, two_rdm)?;
+            }
+        }
+        Ok(energy.re)
+    }
+}
+
+/// Converts a CalculatorComplex coefficient to a Complex64.
+fn complex_coefficient(value: &CalculatorComplex) -> Result<Complex64, StruqtureError> {
+    Ok(Complex64::new(
+        value.re.clone().try_into()?,
+        value.im.clone().try_into()?,
+    ))
+}
@@ -0,0 +1,388 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::FermionProduct;
+use crate::labeling::IndexLabeling;
+use crate::{ModeIndex, StruqtureError};
+use qoqo_calculator::CalculatorComplex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The spin projection σ of a spin orbital.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum SpinProjection {
+    /// Spin up, σ = 0.
+    Up,
+    /// Spin down, σ = 1.
+    Down,
+}
+
+impl SpinProjection {
+    /// Returns the integer σ of the spin projection.
+    fn sigma(&self) -> usize {
+        match self {
+            SpinProjection::Up => 0,
+            SpinProjection::Down => 1,
+        }
+    }
+}
+
+/// A spin orbital labeled by its spatial orbital and its spin projection.
+pub type SpinOrbital = (usize, SpinProjection);
+
+/// The convention for flattening spin orbitals into the modes of fermionic products.
+///
+/// As an [IndexLabeling], the ordering translates spin orbitals into modes and back.
+/// The mapping between two orderings is returned by [IndexLabeling::relabeling]
+/// and can be used to relabel fermionic systems.
+///
+/// # Example
+/// ```
+/// use struqture::fermions::{SpinOrbitalOrdering, SpinProjection};
+/// use struqture::labeling::IndexLabeling;
+///
+/// let blocked = SpinOrbitalOrdering::Blocked { number_orbitals: 4 };
+/// assert_eq!(blocked.to_index(&(1, SpinProjection::Down)).unwrap(), 5);
+/// assert_eq!(
+///     SpinOrbitalOrdering::Interleaved.to_index(&(1, SpinProjection::Down)).unwrap(),
+///     3
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub enum SpinOrbitalOrdering {
+    /// Spin orbital (i, σ) is mode 2 * i + σ, the convention of the fermionic models in struqture.
+    #[default]
+    Interleaved,
+    /// Spin orbital (i, σ) is mode i + σ * number_orbitals, all spin up orbitals precede all spin down orbitals.
+    Blocked {
+        /// The number of spatial orbitals.
+        number_orbitals: usize,
+    },
+}
+
+impl IndexLabeling for SpinOrbitalOrdering {
+    type Label = SpinOrbital;
+
+    // From trait
+    fn to_index(&self, label: &SpinOrbital) -> Result<usize, StruqtureError> {
+        let (orbital, spin) = *label;
+        match self {
+            SpinOrbitalOrdering::Interleaved => Ok(2 * orbital + spin.sigma()),
+            SpinOrbitalOrdering::Blocked { number_orbitals } => {
+                if orbital < *number_orbitals {
+                    Ok(orbital + spin.sigma() * number_orbitals)
+                } else {
+                    Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Orbital {} exceeds the {} spatial orbitals of the blocked ordering",
+                            orbital, number_orbitals
+                        ),
+                    })
+                }
+            }
+        }
+    }
+
+    // From trait
+    fn to_label(&self, index: usize) -> Result<SpinOrbital, StruqtureError> {
+        let (orbital, sigma) = match self {
+            SpinOrbitalOrdering::Interleaved => (index / 2, index % 2),
+            SpinOrbitalOrdering::Blocked { number_orbitals } => {
+                if index >= 2 * number_orbitals {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Mode {} exceeds the {} spin orbitals of the blocked ordering",
+                            index,
+                            2 * number_orbitals
+                        ),
+                    });
+                }
+                (index % number_orbitals, index / number_orbitals)
+            }
+        };
+        let spin = if sigma == 0 {
+            SpinProjection::Up
+        } else {
+            SpinProjection::Down
+        };
+        Ok((orbital, spin))
+    }
+}
+
+/// A product of fermionic creators and annihilators acting on spin orbitals.
+///
+/// Every operator acts on a spin orbital (i, σ) instead of a flat mode index.
+/// Like [FermionProduct], the creators and the annihilators are each sorted in increasing order
+/// of their spin orbitals, comparing first the spatial orbital and then the spin projection.
+/// The product is converted to a [FermionProduct] with a [SpinOrbitalOrdering].
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::fermions::{
+///     FermionProduct, SpinOrbitalFermionProduct, SpinOrbitalOrdering, SpinProjection,
+/// };
+///
+/// let up = SpinProjection::Up;
+/// let down = SpinProjection::Down;
+/// let product = SpinOrbitalFermionProduct::new([(0, up), (0, down)], [(1, down), (1, up)]);
+/// assert!(product.is_err());
+/// let (product, sign) = SpinOrbitalFermionProduct::create_valid_pair(
+///     [(0, up), (0, down)],
+///     [(1, down), (1, up)],
+///     CalculatorComplex::from(1.0),
+/// )
+/// .unwrap();
+/// assert_eq!(sign, CalculatorComplex::from(-1.0));
+/// assert_eq!(product.to_string(), "c0uc0da1ua1d");
+///
+/// let (flat, sign) = product.to_fermion_product(SpinOrbitalOrdering::Interleaved).unwrap();
+/// assert_eq!(flat, FermionProduct::new([0, 1], [2, 3]).unwrap());
+/// assert_eq!(sign, CalculatorComplex::from(1.0));
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct SpinOrbitalFermionProduct {
+    /// The ordered list of spin orbitals of the creators.
+    creators: Vec<SpinOrbital>,
+    /// The ordered list of spin orbitals of the annihilators.
+    annihilators: Vec<SpinOrbital>,
+}
+
+/// Sorts spin orbitals and returns the number of transpositions, None if a spin orbital appears twice.
+fn sort_spin_orbitals(spin_orbitals: Vec<SpinOrbital>) -> Option<(Vec<SpinOrbital>, usize)> {
+    let mut sorted = spin_orbitals;
+    let mut parity: usize = 0;
+    for outer_counter in 0..sorted.len() {
+        for inner_counter in (0..outer_counter).rev() {
+            match sorted[inner_counter].cmp(&sorted[inner_counter + 1]) {
+                std::cmp::Ordering::Greater => {
+                    sorted.swap(inner_counter, inner_counter + 1);
+                    parity += 1;
+                }
+                std::cmp::Ordering::Equal => return None,
+                std::cmp::Ordering::Less => break,
+            }
+        }
+    }
+    Some((sorted, parity))
+}
+
+impl SpinOrbitalFermionProduct {
+    /// Creates a new SpinOrbitalFermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The spin orbitals of the creators, in increasing order.
+    /// * `annihilators` - The spin orbitals of the annihilators, in increasing order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new SpinOrbitalFermionProduct.
+    /// * `Err(StruqtureError::IncorrectlyOrderedIndices)` - The spin orbitals are not in strictly increasing order.
+    pub fn new(
+        creators: impl IntoIterator<Item = SpinOrbital>,
+        annihilators: impl IntoIterator<Item = SpinOrbital>,
+    ) -> Result<Self, StruqtureError> {
+        let creators: Vec<SpinOrbital> = creators.into_iter().collect();
+        let annihilators: Vec<SpinOrbital> = annihilators.into_iter().collect();
+        if !(creators.windows(2).all(|w| w[0] < w[1])
+            && annihilators.windows(2).all(|w| w[0] < w[1]))
+        {
+            return Err(StruqtureError::IncorrectlyOrderedIndices);
+        }
+        Ok(Self {
+            creators,
+            annihilators,
+        })
+    }
+
+    /// Creates a SpinOrbitalFermionProduct from spin orbitals in arbitrary order.
+    ///
+    /// # Arguments
+    ///
+    /// * `creators` - The spin orbitals of the creators.
+    /// * `annihilators` - The spin orbitals of the annihilators.
+    /// * `value` - The coefficient of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, CalculatorComplex))` - The sorted product and the coefficient including the sign of the reordering.
+    /// * `Err(StruqtureError::IndicesContainDoubles)` - A spin orbital appears twice in the creators or the annihilators.
+    pub fn create_valid_pair(
+        creators: impl IntoIterator<Item = SpinOrbital>,
+        annihilators: impl IntoIterator<Item = SpinOrbital>,
+        value: CalculatorComplex,
+    ) -> Result<(Self, CalculatorComplex), StruqtureError> {
+        let (creators, parity_c) = sort_spin_orbitals(creators.into_iter().collect())
+            .ok_or(StruqtureError::IndicesContainDoubles {})?;
+        let (annihilators, parity_a) = sort_spin_orbitals(annihilators.into_iter().collect())
+            .ok_or(StruqtureError::IndicesContainDoubles {})?;
+        let value = if (parity_c + parity_a) % 2 != 0 {
+            value * -1.0
+        } else {
+            value
+        };
+        Ok((
+            Self {
+                creators,
+                annihilators,
+            },
+            value,
+        ))
+    }
+
+    /// Returns the spin orbitals of the creators.
+    ///
+    /// # Returns
+    ///
+    /// * `std::slice::Iter<SpinOrbital>` - The spin orbitals of the creators.
+    pub fn creators(&self) -> std::slice::Iter<SpinOrbital> {
+        self.creators.iter()
+    }
+
+    /// Returns the spin orbitals of the annihilators.
+    ///
+    /// # Returns
+    ///
+    /// * `std::slice::Iter<SpinOrbital>` - The spin orbitals of the annihilators.
+    pub fn annihilators(&self) -> std::slice::Iter<SpinOrbital> {
+        self.annihilators.iter()
+    }
+
+    /// Converts the SpinOrbitalFermionProduct into a FermionProduct acting on flat modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `ordering` - The convention for flattening the spin orbitals.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((FermionProduct, CalculatorComplex))` - The FermionProduct and the sign resulting from reordering the modes.
+    /// * `Err(StruqtureError::GenericError)` - A spin orbital does not fit into the ordering.
+    pub fn to_fermion_product(
+        &self,
+        ordering: SpinOrbitalOrdering,
+    ) -> Result<(FermionProduct, CalculatorComplex), StruqtureError> {
+        FermionProduct::create_valid_pair(
+            ordering.to_indices(self.creators())?,
+            ordering.to_indices(self.annihilators())?,
+            CalculatorComplex::from(1.0),
+        )
+    }
+
+    /// Converts a FermionProduct acting on flat modes into a SpinOrbitalFermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The FermionProduct to convert.
+    /// * `ordering` - The convention for flattening the spin orbitals.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, CalculatorComplex))` - The SpinOrbitalFermionProduct and the sign resulting from reordering the spin orbitals.
+    /// * `Err(StruqtureError::GenericError)` - A mode does not fit into the ordering.
+    pub fn from_fermion_product(
+        product: &FermionProduct,
+        ordering: SpinOrbitalOrdering,
+    ) -> Result<(Self, CalculatorComplex), StruqtureError> {
+        Self::create_valid_pair(
+            ordering.to_labels(product.creators().copied())?,
+            ordering.to_labels(product.annihilators().copied())?,
+            CalculatorComplex::from(1.0),
+        )
+    }
+}
+
+/// Writes a spin orbital in the string representation of SpinOrbitalFermionProducts, e.g. `0u`.
+fn write_spin_orbital(string: &mut String, operator: char, spin_orbital: &SpinOrbital) {
+    let spin = match spin_orbital.1 {
+        SpinProjection::Up => 'u',
+        SpinProjection::Down => 'd',
+    };
+    string.push_str(&format!("{}{}{}", operator, spin_orbital.0, spin));
+}
+
+impl fmt::Display for SpinOrbitalFermionProduct {
+    /// Formats the SpinOrbitalFermionProduct using the given formatter.
+    ///
+    /// Every operator is written as `c` or `a`, followed by the spatial orbital and `u` or `d` for the spin, e.g. `c0uc1da1u`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinOrbitalFermionProduct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string = String::new();
+        if self.creators.is_empty() && self.annihilators.is_empty() {
+            string.push('I');
+        }
+        for spin_orbital in self.creators() {
+            write_spin_orbital(&mut string, 'c', spin_orbital);
+        }
+        for spin_orbital in self.annihilators() {
+            write_spin_orbital(&mut string, 'a', spin_orbital);
+        }
+        write!(f, "{}", string)
+    }
+}
+
+impl FromStr for SpinOrbitalFermionProduct {
+    type Err = StruqtureError;
+
+    /// Constructs a SpinOrbitalFermionProduct from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to convert, e.g. `c0uc1da1u`, or `I` for the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The successfully converted SpinOrbitalFermionProduct.
+    /// * `Err(StruqtureError::FromStringFailed)` - The string is not a valid product of spin orbital operators.
+    /// * `Err(StruqtureError::IncorrectlyOrderedIndices)` - The spin orbitals are not in strictly increasing order.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "I" {
+            return Ok(Self::default());
+        }
+        let failed = || StruqtureError::FromStringFailed {
+            msg: format!("{} is not a valid SpinOrbitalFermionProduct", s),
+        };
+        let mut creators: Vec<SpinOrbital> = Vec::new();
+        let mut annihilators: Vec<SpinOrbital> = Vec::new();
+        let mut remaining = s;
+        while let Some(operator) = remaining.chars().next() {
+            let body = &remaining[operator.len_utf8()..];
+            let spin_position = body
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(failed)?;
+            let orbital = usize::from_str(&body[..spin_position]).map_err(|_| failed())?;
+            let spin = match body[spin_position..].chars().next() {
+                Some('u') => SpinProjection::Up,
+                Some('d') => SpinProjection::Down,
+                _ => return Err(failed()),
+            };
+            match operator {
+                'c' if annihilators.is_empty() => creators.push((orbital, spin)),
+                'a' => annihilators.push((orbital, spin)),
+                _ => return Err(failed()),
+            }
+            remaining = &body[spin_position + 1..];
+        }
+        Self::new(creators, annihilators)
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Incremental construction of Hamiltonians with a very large number of terms.
+//!
+//! A [HamiltonianBuilder] accepts terms in batches and keeps at most a fixed number of distinct terms in memory.
+//! When the budget is exceeded, the terms in memory are written as a sorted run to a spill directory.
+//! At `finish()` the runs are merged, summing the coefficients of equal terms, into the final system.
+//!
+//! The runs written to the spill directory act as checkpoints: a builder that was interrupted can be
+//! recreated with [HamiltonianBuilder::resume], which picks up all runs already on disk.
+//!
+//! # Example
+//! ```
+//! use struqture::hamiltonian_builder::HamiltonianBuilder;
+//! use struqture::prelude::*;
+//! use struqture::spins::PauliProduct;
+//! use qoqo_calculator::CalculatorFloat;
+//!
+//! let directory = std::env::temp_dir().join("struqture_builder_doc_example");
+//! let mut builder = HamiltonianBuilder::<PauliProduct, CalculatorFloat>::new(&directory, 1).unwrap();
+//! builder.add_terms(vec![
+//!     (PauliProduct::new().z(0), 1.0.into()),
+//!     (PauliProduct::new().x(1), 2.0.into()),
+//!     (PauliProduct::new().z(0), 0.5.into()),
+//! ]).unwrap();
+//! let system = builder.finish(Some(2)).unwrap();
+//! assert_eq!(system.get(&PauliProduct::new().z(0)), &CalculatorFloat::from(1.5));
+//! assert_eq!(system.len(), 2);
+//! ```
+
+use crate::fermions::{FermionHamiltonian, FermionHamiltonianSystem, HermitianFermionProduct};
+use crate::spins::{PauliProduct, SpinHamiltonian, SpinHamiltonianSystem};
+use crate::{OperateOnDensityMatrix, StruqtureError};
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::ops::Add;
+use std::path::{Path, PathBuf};
+
+/// File name prefix of the sorted runs in the spill directory.
+const RUN_PREFIX: &str = "struqture_run_";
+/// File name extension of completely written runs.
+const RUN_EXTENSION: &str = "bin";
+
+/// Builder accumulating the terms of a Hamiltonian with a bounded number of terms in memory.
+///
+/// The index type `I` is the product type of the Hamiltonian and `V` the coefficient type.
+/// The final system is created by `finish`, which is available for
+/// `HamiltonianBuilder<PauliProduct, CalculatorFloat>` (creating a [SpinHamiltonianSystem]) and
+/// `HamiltonianBuilder<HermitianFermionProduct, CalculatorComplex>` (creating a [FermionHamiltonianSystem]).
+#[derive(Debug)]
+pub struct HamiltonianBuilder<I, V> {
+    /// The directory the sorted runs are written to.
+    spill_directory: PathBuf,
+    /// The maximum number of distinct terms kept in memory before spilling.
+    max_terms_in_memory: usize,
+    /// The terms not yet written to disk.
+    buffer: BTreeMap<I, V>,
+    /// The sorted runs written to the spill directory.
+    runs: Vec<PathBuf>,
+}
+
+impl<I, V> HamiltonianBuilder<I, V>
+where
+    I: Ord + Clone + Serialize + DeserializeOwned + 'static,
+    V: Add<V, Output = V> + Clone + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates a new HamiltonianBuilder spilling to an empty spill directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `spill_directory` - The directory the sorted runs are written to, created if it does not exist.
+    /// * `max_terms_in_memory` - The maximum number of distinct terms kept in memory before spilling to disk.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new HamiltonianBuilder.
+    /// * `Err(StruqtureError::GenericError)` - The spill directory could not be created or already contains runs.
+    pub fn new<P: AsRef<Path>>(
+        spill_directory: P,
+        max_terms_in_memory: usize,
+    ) -> Result<Self, StruqtureError> {
+        let builder = Self::resume(spill_directory, max_terms_in_memory)?;
+        if builder.runs.is_empty() {
+            Ok(builder)
+        } else {
+            Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Spill directory {} already contains runs, use resume to continue a previous build",
+                    builder.spill_directory.display()
+                ),
+            })
+        }
+    }
+
+    /// Creates a HamiltonianBuilder continuing from the runs already present in the spill directory.
+    ///
+    /// Runs that were not completely written (e.g. because the process was interrupted while spilling) are ignored.
+    /// The terms of those runs have to be added again.
+    ///
+    /// # Arguments
+    ///
+    /// * `spill_directory` - The directory the sorted runs are written to, created if it does not exist.
+    /// * `max_terms_in_memory` - The maximum number of distinct terms kept in memory before spilling to disk.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The HamiltonianBuilder containing the terms of all complete runs in the spill directory.
+    /// * `Err(StruqtureError::GenericError)` - The spill directory could not be created or read.
+    pub fn resume<P: AsRef<Path>>(
+        spill_directory: P,
+        max_terms_in_memory: usize,
+    ) -> Result<Self, StruqtureError> {
+        let spill_directory = spill_directory.as_ref().to_path_buf();
+        fs::create_dir_all(&spill_directory).map_err(spill_error)?;
+        let mut runs: Vec<PathBuf> = Vec::new();
+        for entry in fs::read_dir(&spill_directory).map_err(spill_error)? {
+            let path = entry.map_err(spill_error)?.path();
+            let is_run = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.starts_with(RUN_PREFIX))
+                .unwrap_or(false)
+                && path.extension().and_then(|ext| ext.to_str()) == Some(RUN_EXTENSION);
+            if is_run {
+                runs.push(path);
+            }
+        }
+        runs.sort();
+        Ok(Self {
+            spill_directory,
+            max_terms_in_memory: max_terms_in_memory.max(1),
+            buffer: BTreeMap::new(),
+            runs,
+        })
+    }
+
+    /// Adds a single term, summing its coefficient with the coefficient of an equal term in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The product of the term.
+    /// * `value` - The coefficient of the term.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The term was added.
+    /// * `Err(StruqtureError::GenericError)` - The terms in memory could not be spilled to disk.
+    pub fn add_term(&mut self, key: I, value: V) -> Result<(), StruqtureError> {
+        let value = match self.buffer.remove(&key) {
+            Some(old_value) => old_value + value,
+            None => value,
+        };
+        self.buffer.insert(key, value);
+        if self.buffer.len() > self.max_terms_in_memory {
+            self.checkpoint()?;
+        }
+        Ok(())
+    }
+
+    /// Adds a batch of terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The terms to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The terms were added.
+    /// * `Err(StruqtureError::GenericError)` - The terms in memory could not be spilled to disk.
+    pub fn add_terms<T: IntoIterator<Item = (I, V)>>(
+        &mut self,
+        terms: T,
+    ) -> Result<(), StruqtureError> {
+        for (key, value) in terms {
+            self.add_term(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the terms in memory as a sorted run to the spill directory.
+    ///
+    /// After a checkpoint all terms added so far are on disk and can be recovered with `resume`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The terms in memory were written, or there were none.
+    /// * `Err(StruqtureError::GenericError)` - The run could not be written.
+    pub fn checkpoint(&mut self) -> Result<(), StruqtureError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let path = self.spill_directory.join(format!(
+            "{}{:08}.{}",
+            RUN_PREFIX,
+            self.next_run_number(),
+            RUN_EXTENSION
+        ));
+        // Writing to a temporary file first ensures that only complete runs carry the run extension
+        let temporary_path = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&temporary_path).map_err(spill_error)?);
+        bincode::serialize_into(&mut writer, &(self.buffer.len() as u64)).map_err(spill_error)?;
+        for term in self.buffer.iter() {
+            bincode::serialize_into(&mut writer, &term).map_err(spill_error)?;
+        }
+        writer
+            .into_inner()
+            .map_err(spill_error)?
+            .sync_all()
+            .map_err(spill_error)?;
+        fs::rename(&temporary_path, &path).map_err(spill_error)?;
+        self.buffer.clear();
+        self.runs.push(path);
+        Ok(())
+    }
+
+    /// Returns the number of sorted runs written to the spill directory.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of runs on disk.
+    pub fn number_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Returns the number of distinct terms currently held in memory.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms not yet spilled to disk.
+    pub fn number_terms_in_memory(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of the next run, one larger than the number of the last run on disk.
+    fn next_run_number(&self) -> usize {
+        self.runs
+            .last()
+            .and_then(|path| path.file_stem())
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.trim_start_matches(RUN_PREFIX).parse::<usize>().ok())
+            .map(|number| number + 1)
+            .unwrap_or(0)
+    }
+
+    /// Merges the runs on disk and the terms in memory and passes the summed terms in sorted order to `consume`.
+    ///
+    /// The runs are removed from the spill directory once all terms were consumed.
+    fn merge<F>(self, mut consume: F) -> Result<(), StruqtureError>
+    where
+        F: FnMut(I, V) -> Result<(), StruqtureError>,
+    {
+        let mut sources: Vec<Box<dyn Iterator<Item = Result<(I, V), StruqtureError>>>> =
+            Vec::with_capacity(self.runs.len() + 1);
+        for path in self.runs.iter() {
+            sources.push(Box::new(RunReader::<I, V>::open(path)?));
+        }
+        sources.push(Box::new(self.buffer.into_iter().map(Ok)));
+
+        let mut heads: Vec<Option<V>> = Vec::with_capacity(sources.len());
+        let mut heap: BinaryHeap<Reverse<(I, usize)>> = BinaryHeap::with_capacity(sources.len());
+        for (index, source) in sources.iter_mut().enumerate() {
+            match source.next().transpose()? {
+                Some((key, value)) => {
+                    heap.push(Reverse((key, index)));
+                    heads.push(Some(value));
+                }
+                None => heads.push(None),
+            }
+        }
+
+        let mut current: Option<(I, V)> = None;
+        while let Some(Reverse((key, index))) = heap.pop() {
+            let value = heads[index]
+                .take()
+                .expect("Internal bug in HamiltonianBuilder merge");
+            if let Some((next_key, next_value)) = sources[index].next().transpose()? {
+                heap.push(Reverse((next_key, index)));
+                heads[index] = Some(next_value);
+            }
+            current = match current {
+                Some((current_key, current_value)) if current_key == key => {
+                    Some((current_key, current_value + value))
+                }
+                Some((current_key, current_value)) => {
+                    consume(current_key, current_value)?;
+                    Some((key, value))
+                }
+                None => Some((key, value)),
+            };
+        }
+        if let Some((key, value)) = current {
+            consume(key, value)?;
+        }
+
+        drop(sources);
+        for path in self.runs.iter() {
+            fs::remove_file(path).map_err(spill_error)?;
+        }
+        Ok(())
+    }
+}
+
+impl HamiltonianBuilder<PauliProduct, CalculatorFloat> {
+    /// Merges all added terms into a SpinHamiltonianSystem and removes the runs from the spill directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The optional number of spins of the SpinHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonianSystem)` - The SpinHamiltonianSystem with the summed terms.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Number of spins in entry exceeds number of spins in system.
+    /// * `Err(StruqtureError::GenericError)` - The runs could not be read or removed.
+    pub fn finish(
+        self,
+        number_spins: Option<usize>,
+    ) -> Result<SpinHamiltonianSystem, StruqtureError> {
+        let mut hamiltonian = SpinHamiltonian::new();
+        self.merge(|key, value| hamiltonian.add_operator_product(key, value))?;
+        SpinHamiltonianSystem::from_hamiltonian(hamiltonian, number_spins)
+    }
+}
+
+impl HamiltonianBuilder<HermitianFermionProduct, CalculatorComplex> {
+    /// Merges all added terms into a FermionHamiltonianSystem and removes the runs from the spill directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_modes` - The optional number of fermionic modes of the FermionHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionHamiltonianSystem)` - The FermionHamiltonianSystem with the summed terms.
+    /// * `Err(StruqtureError::NumberModesExceeded)` - Number of modes in entry exceeds number of modes in system.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - A summed term is a diagonal product with a complex coefficient.
+    /// * `Err(StruqtureError::GenericError)` - The runs could not be read or removed.
+    pub fn finish(
+        self,
+        number_modes: Option<usize>,
+    ) -> Result<FermionHamiltonianSystem, StruqtureError> {
+        let mut hamiltonian = FermionHamiltonian::new();
+        self.merge(|key, value| hamiltonian.add_operator_product(key, value))?;
+        FermionHamiltonianSystem::from_hamiltonian(hamiltonian, number_modes)
+    }
+}
+
+/// Sequential reader of the terms of a sorted run.
+struct RunReader<I, V> {
+    /// The buffered reader of the run file.
+    reader: BufReader<File>,
+    /// The number of terms not read yet.
+    remaining: u64,
+    /// Marker for the term types.
+    marker: std::marker::PhantomData<(I, V)>,
+}
+
+impl<I: DeserializeOwned, V: DeserializeOwned> RunReader<I, V> {
+    /// Opens a run and reads the number of terms it contains.
+    fn open(path: &Path) -> Result<Self, StruqtureError> {
+        let mut reader = BufReader::new(File::open(path).map_err(spill_error)?);
+        let remaining: u64 = bincode::deserialize_from(&mut reader).map_err(spill_error)?;
+        Ok(Self {
+            reader,
+            remaining,
+            marker: std::marker::PhantomData,
+        })
+    }
+}
+
+impl<I: DeserializeOwned, V: DeserializeOwned> Iterator for RunReader<I, V> {
+    type Item = Result<(I, V), StruqtureError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(bincode::deserialize_from(&mut self.reader).map_err(spill_error))
+    }
+}
+
+/// Converts errors accessing the spill directory into a StruqtureError.
+fn spill_error(error: impl std::fmt::Display) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!(
+            "Could not access spill directory of HamiltonianBuilder: {}",
+            error
+        ),
+    }
+}
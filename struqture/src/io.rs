@@ -0,0 +1,289 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of serialized struqture objects between serialization formats.
+//!
+//! struqture objects serialize differently in human readable formats (JSON) and compact formats
+//! (bincode). The functions in this module always deserialize the input into the struqture type,
+//! so every conversion validates the data (including the struqture version) on a single code path.
+//!
+//! # Example
+//! ```
+//! use struqture::io::{convert, SerializationFormat};
+//! use struqture::prelude::*;
+//! use struqture::spins::{PauliProduct, SpinSystem};
+//!
+//! let mut system = SpinSystem::new(Some(1));
+//! system.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+//! let json = serde_json::to_vec(&system).unwrap();
+//! let compact = convert::<SpinSystem>(&json, SerializationFormat::Json, SerializationFormat::Bincode).unwrap();
+//! assert_eq!(bincode::deserialize::<SpinSystem>(&compact).unwrap(), system);
+//! ```
+
+use crate::bosons::*;
+use crate::fermions::*;
+use crate::mixed_systems::*;
+use crate::spins::*;
+use crate::StruqtureError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// Serialization formats supported by the conversion functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerializationFormat {
+    /// Compact (single line) JSON, human readable serialization.
+    Json,
+    /// Pretty printed JSON, human readable serialization.
+    JsonPretty,
+    /// bincode, compact binary serialization.
+    Bincode,
+    /// MessagePack, compact binary serialization readable outside of Rust.
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+    /// CBOR, compact binary serialization readable outside of Rust.
+    #[cfg(feature = "cbor")]
+    Cbor,
+}
+
+impl fmt::Display for SerializationFormat {
+    /// Formats the SerializationFormat using the name accepted by `from_str`.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The name of the format.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerializationFormat::Json => write!(f, "json"),
+            SerializationFormat::JsonPretty => write!(f, "json_pretty"),
+            SerializationFormat::Bincode => write!(f, "bincode"),
+            #[cfg(feature = "msgpack")]
+            SerializationFormat::MessagePack => write!(f, "msgpack"),
+            #[cfg(feature = "cbor")]
+            SerializationFormat::Cbor => write!(f, "cbor"),
+        }
+    }
+}
+
+impl FromStr for SerializationFormat {
+    type Err = StruqtureError;
+    /// Constructs a SerializationFormat from its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - One of "json", "json_pretty", "bincode", "msgpack" (feature `msgpack`) or "cbor" (feature `cbor`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SerializationFormat.
+    /// * `Err(StruqtureError::ParsingError)` - The name is not a supported format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(SerializationFormat::Json),
+            "json_pretty" => Ok(SerializationFormat::JsonPretty),
+            "bincode" => Ok(SerializationFormat::Bincode),
+            #[cfg(feature = "msgpack")]
+            "msgpack" => Ok(SerializationFormat::MessagePack),
+            #[cfg(feature = "cbor")]
+            "cbor" => Ok(SerializationFormat::Cbor),
+            _ => Err(StruqtureError::ParsingError {
+                target_type: "SerializationFormat".to_string(),
+                msg: format!(
+                    "Unknown format {}, expected json, json_pretty, bincode, msgpack or cbor.",
+                    s
+                ),
+            }),
+        }
+    }
+}
+
+/// Serializes a struqture object in the given format.
+///
+/// # Arguments
+///
+/// * `value` - The object to serialize.
+/// * `format` - The serialization format.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The serialized object.
+/// * `Err(StruqtureError::SerializationError)` - The object could not be serialized.
+pub fn serialize<T: Serialize>(
+    value: &T,
+    format: SerializationFormat,
+) -> Result<Vec<u8>, StruqtureError> {
+    let result = match format {
+        SerializationFormat::Json => serde_json::to_vec(value).map_err(|err| err.to_string()),
+        SerializationFormat::JsonPretty => {
+            serde_json::to_vec_pretty(value).map_err(|err| err.to_string())
+        }
+        SerializationFormat::Bincode => bincode::serialize(value).map_err(|err| err.to_string()),
+        #[cfg(feature = "msgpack")]
+        SerializationFormat::MessagePack => {
+            rmp_serde::to_vec_named(value).map_err(|err| err.to_string())
+        }
+        #[cfg(feature = "cbor")]
+        SerializationFormat::Cbor => {
+            let mut serialized = Vec::new();
+            ciborium::into_writer(value, &mut serialized)
+                .map(|_| serialized)
+                .map_err(|err| err.to_string())
+        }
+    };
+    result.map_err(|msg| StruqtureError::SerializationError {
+        format: format.to_string(),
+        msg,
+    })
+}
+
+/// Deserializes a struqture object from the given format.
+///
+/// # Arguments
+///
+/// * `input` - The serialized object.
+/// * `format` - The serialization format of the input.
+///
+/// # Returns
+///
+/// * `Ok(T)` - The deserialized object.
+/// * `Err(StruqtureError::SerializationError)` - The input could not be deserialized.
+pub fn deserialize<T: DeserializeOwned>(
+    input: &[u8],
+    format: SerializationFormat,
+) -> Result<T, StruqtureError> {
+    let result = match format {
+        SerializationFormat::Json | SerializationFormat::JsonPretty => {
+            serde_json::from_slice(input).map_err(|err| err.to_string())
+        }
+        SerializationFormat::Bincode => bincode::deserialize(input).map_err(|err| err.to_string()),
+        #[cfg(feature = "msgpack")]
+        SerializationFormat::MessagePack => {
+            rmp_serde::from_slice(input).map_err(|err| err.to_string())
+        }
+        #[cfg(feature = "cbor")]
+        SerializationFormat::Cbor => ciborium::from_reader(input).map_err(|err| err.to_string()),
+    };
+    result.map_err(|msg| StruqtureError::SerializationError {
+        format: format.to_string(),
+        msg,
+    })
+}
+
+/// Converts a serialized struqture object between two serialization formats.
+///
+/// # Arguments
+///
+/// * `input` - The serialized object.
+/// * `from_format` - The serialization format of the input.
+/// * `to_format` - The serialization format of the output.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The object serialized in the output format.
+/// * `Err(StruqtureError::SerializationError)` - The input could not be deserialized into T or the output could not be serialized.
+pub fn convert<T: Serialize + DeserializeOwned>(
+    input: &[u8],
+    from_format: SerializationFormat,
+    to_format: SerializationFormat,
+) -> Result<Vec<u8>, StruqtureError> {
+    let value: T = deserialize(input, from_format)?;
+    serialize(&value, to_format)
+}
+
+/// Generates the dispatch from a type name to `convert` for all serializable struqture types.
+macro_rules! convert_by_name {
+    ($type_name:expr, $input:expr, $from:expr, $to:expr, [$($struqture_type:ident),* $(,)?]) => {
+        match $type_name {
+            $(stringify!($struqture_type) => convert::<$struqture_type>($input, $from, $to),)*
+            _ => Err(StruqtureError::GenericError {
+                msg: format!("Unknown struqture type {}.", $type_name),
+            }),
+        }
+    };
+}
+
+/// Converts a serialized struqture object, whose type is given by name, between two serialization formats.
+///
+/// This is the entry point for tools that only know the type name at runtime, such as the Python interface.
+///
+/// # Arguments
+///
+/// * `type_name` - The name of the struqture type, e.g. "SpinSystem".
+/// * `input` - The serialized object.
+/// * `from_format` - The serialization format of the input.
+/// * `to_format` - The serialization format of the output.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The object serialized in the output format.
+/// * `Err(StruqtureError::GenericError)` - The type name is not a serializable struqture type.
+/// * `Err(StruqtureError::SerializationError)` - The input could not be deserialized or the output could not be serialized.
+pub fn convert_by_type_name(
+    type_name: &str,
+    input: &[u8],
+    from_format: SerializationFormat,
+    to_format: SerializationFormat,
+) -> Result<Vec<u8>, StruqtureError> {
+    convert_by_name!(
+        type_name,
+        input,
+        from_format,
+        to_format,
+        [
+            PauliProduct,
+            DecoherenceProduct,
+            PlusMinusProduct,
+            SpinOperator,
+            SpinHamiltonian,
+            DecoherenceOperator,
+            PlusMinusOperator,
+            PlusMinusHamiltonian,
+            SpinLindbladNoiseOperator,
+            PlusMinusLindbladNoiseOperator,
+            SpinSystem,
+            SpinHamiltonianSystem,
+            SpinLindbladNoiseSystem,
+            SpinLindbladOpenSystem,
+            BosonProduct,
+            HermitianBosonProduct,
+            BosonOperator,
+            BosonHamiltonian,
+            BosonLindbladNoiseOperator,
+            BosonSystem,
+            BosonHamiltonianSystem,
+            BosonLindbladNoiseSystem,
+            BosonLindbladOpenSystem,
+            FermionProduct,
+            HermitianFermionProduct,
+            FermionOperator,
+            FermionHamiltonian,
+            FermionLindbladNoiseOperator,
+            FermionSystem,
+            FermionHamiltonianSystem,
+            FermionLindbladNoiseSystem,
+            FermionLindbladOpenSystem,
+            MixedProduct,
+            HermitianMixedProduct,
+            MixedDecoherenceProduct,
+            MixedPlusMinusProduct,
+            MixedOperator,
+            MixedHamiltonian,
+            MixedPlusMinusOperator,
+            MixedLindbladNoiseOperator,
+            MixedSystem,
+            MixedHamiltonianSystem,
+            MixedLindbladNoiseSystem,
+            MixedLindbladOpenSystem,
+        ]
+    )
+}
@@ -0,0 +1,608 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Labeling of modes and spins with the conventions of external sources.
+//!
+//! Struqture indexes modes and spins with 0-based integers. Other codes label them differently,
+//! e.g. FCIDUMP files number the orbitals starting from one. An [IndexLabeling] translates between
+//! the external labels and the internal indices in a single place, instead of shifting indices by hand
+//! whenever a product is constructed or read out.
+//! [IndexOffset] covers integer labels shifted by a constant, including negative labels,
+//! and [LabelMap] covers arbitrary labels listed in the order of the internal indices.
+//!
+//! Products and systems that have already been constructed are moved to other indices with `relabel`,
+//! for example with the mapping between two labelings returned by [IndexLabeling::relabeling].
+//! Systems are symmetrized under a group of index permutations, e.g. the translations of a lattice,
+//! with `project_onto_symmetry_sector`.
+//!
+//! # Example
+//! ```
+//! use qoqo_calculator::CalculatorComplex;
+//! use struqture::fermions::{FermionProduct, FermionSystem};
+//! use struqture::labeling::{IndexLabeling, IndexOffset, LabelMap};
+//! use struqture::prelude::*;
+//!
+//! // The orbitals of an FCIDUMP file start at one
+//! let fcidump = IndexOffset::one_based();
+//! let product =
+//!     FermionProduct::new(fcidump.to_indices(&[1, 2]).unwrap(), fcidump.to_indices(&[2]).unwrap())
+//!         .unwrap();
+//! assert_eq!(product, FermionProduct::new([0, 1], [1]).unwrap());
+//! assert_eq!(fcidump.to_labels(product.creators().copied()).unwrap(), vec![1, 2]);
+//!
+//! // Reorder the orbitals to the order used by another code
+//! let mut system = FermionSystem::new(Some(3));
+//! system.add_operator_product(product, CalculatorComplex::from(1.0)).unwrap();
+//! let ours = LabelMap::new(["1s", "2s", "2p"]).unwrap();
+//! let theirs = LabelMap::new(["2p", "1s", "2s"]).unwrap();
+//! let relabeled = system.relabel(&ours.relabeling(&theirs, 0..3).unwrap()).unwrap();
+//! assert_eq!(
+//!     relabeled.get(&FermionProduct::new([1, 2], [2]).unwrap()),
+//!     &CalculatorComplex::from(1.0)
+//! );
+//! ```
+
+use crate::bosons::{BosonHamiltonianSystem, BosonSystem};
+use crate::fermions::{FermionHamiltonianSystem, FermionSystem};
+use crate::spins::{SpinHamiltonianSystem, SpinSystem};
+use crate::{ModeIndex, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Trait for translating between external labels and the internal 0-based indices of modes and spins.
+pub trait IndexLabeling {
+    /// The type of the external labels.
+    type Label;
+
+    /// Returns the internal index of a label.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The external label.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The internal index.
+    /// * `Err(StruqtureError::GenericError)` - The label does not correspond to an internal index.
+    fn to_index(&self, label: &Self::Label) -> Result<usize, StruqtureError>;
+
+    /// Returns the label of an internal index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The internal index.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Label)` - The external label.
+    /// * `Err(StruqtureError::GenericError)` - The index has no label.
+    fn to_label(&self, index: usize) -> Result<Self::Label, StruqtureError>;
+
+    /// Returns the internal indices of several labels.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - The external labels.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<usize>)` - The internal indices in the order of the labels.
+    /// * `Err(StruqtureError::GenericError)` - A label does not correspond to an internal index.
+    fn to_indices<'b>(
+        &self,
+        labels: impl IntoIterator<Item = &'b Self::Label>,
+    ) -> Result<Vec<usize>, StruqtureError>
+    where
+        Self::Label: 'b,
+    {
+        labels
+            .into_iter()
+            .map(|label| self.to_index(label))
+            .collect()
+    }
+
+    /// Returns the labels of several internal indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - The internal indices.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Self::Label>)` - The external labels in the order of the indices.
+    /// * `Err(StruqtureError::GenericError)` - An index has no label.
+    fn to_labels(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<Vec<Self::Label>, StruqtureError> {
+        indices
+            .into_iter()
+            .map(|index| self.to_label(index))
+            .collect()
+    }
+
+    /// Returns the mapping of internal indices of Self onto the internal indices of the same labels in another labeling.
+    ///
+    /// The mapping can be passed to the `relabel` functions of products and systems.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The labeling the indices are mapped to.
+    /// * `indices` - The internal indices of Self that are mapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<usize, usize>)` - The {index in Self: index in target} mapping.
+    /// * `Err(StruqtureError::GenericError)` - An index has no label in Self or its label is missing in target.
+    fn relabeling<T>(
+        &self,
+        target: &T,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Result<HashMap<usize, usize>, StruqtureError>
+    where
+        T: IndexLabeling<Label = Self::Label>,
+    {
+        indices
+            .into_iter()
+            .map(|index| Ok((index, target.to_index(&self.to_label(index)?)?)))
+            .collect()
+    }
+}
+
+/// Integer labels that are shifted from the internal indices by a constant offset.
+///
+/// The label of internal index `i` is `i + offset`.
+/// For example, one-based labels use an offset of one and labels starting at -2 an offset of -2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IndexOffset {
+    /// The label of the internal index 0.
+    offset: isize,
+}
+
+impl IndexOffset {
+    /// Creates a new IndexOffset.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The label of the internal index 0.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new IndexOffset.
+    pub fn new(offset: isize) -> Self {
+        Self { offset }
+    }
+
+    /// Creates the IndexOffset of labels starting from one, as used by FCIDUMP files.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The IndexOffset with offset one.
+    pub fn one_based() -> Self {
+        Self { offset: 1 }
+    }
+
+    /// Returns the offset of the labels.
+    ///
+    /// # Returns
+    ///
+    /// * `isize` - The label of the internal index 0.
+    pub fn offset(&self) -> isize {
+        self.offset
+    }
+}
+
+impl IndexLabeling for IndexOffset {
+    type Label = isize;
+
+    // From trait
+    fn to_index(&self, label: &isize) -> Result<usize, StruqtureError> {
+        label
+            .checked_sub(self.offset)
+            .and_then(|index| usize::try_from(index).ok())
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "Label {} lies below the first label {} of the IndexOffset",
+                    label, self.offset
+                ),
+            })
+    }
+
+    // From trait
+    fn to_label(&self, index: usize) -> Result<isize, StruqtureError> {
+        isize::try_from(index)
+            .ok()
+            .and_then(|index| index.checked_add(self.offset))
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "Index {} with offset {} exceeds the range of labels",
+                    index, self.offset
+                ),
+            })
+    }
+}
+
+/// Arbitrary labels given in the order of the internal indices.
+///
+/// The label at position `i` of the list is the label of internal index `i`.
+#[derive(Debug, Clone)]
+pub struct LabelMap<L> {
+    /// The labels in the order of the internal indices.
+    labels: Vec<L>,
+    /// The internal index of every label.
+    indices: HashMap<L, usize>,
+}
+
+impl<L> LabelMap<L>
+where
+    L: Hash + Eq + Clone + Debug,
+{
+    /// Creates a new LabelMap.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - The labels in the order of the internal indices.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new LabelMap.
+    /// * `Err(StruqtureError::GenericError)` - A label appears more than once.
+    pub fn new(labels: impl IntoIterator<Item = L>) -> Result<Self, StruqtureError> {
+        let labels: Vec<L> = labels.into_iter().collect();
+        let mut indices: HashMap<L, usize> = HashMap::with_capacity(labels.len());
+        for (index, label) in labels.iter().enumerate() {
+            if indices.insert(label.clone(), index).is_some() {
+                return Err(StruqtureError::GenericError {
+                    msg: format!("Label {:?} appears more than once in the LabelMap", label),
+                });
+            }
+        }
+        Ok(Self { labels, indices })
+    }
+
+    /// Returns the labels in the order of the internal indices.
+    ///
+    /// # Returns
+    ///
+    /// * `&[L]` - The labels.
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    /// Returns the number of labels.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of labeled indices.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns whether the LabelMap contains no labels.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the LabelMap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl<L> IndexLabeling for LabelMap<L>
+where
+    L: Hash + Eq + Clone + Debug,
+{
+    type Label = L;
+
+    // From trait
+    fn to_index(&self, label: &L) -> Result<usize, StruqtureError> {
+        self.indices
+            .get(label)
+            .copied()
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!("Label {:?} is not contained in the LabelMap", label),
+            })
+    }
+
+    // From trait
+    fn to_label(&self, index: usize) -> Result<L, StruqtureError> {
+        self.labels
+            .get(index)
+            .cloned()
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "Index {} exceeds the {} labels of the LabelMap",
+                    index,
+                    self.labels.len()
+                ),
+            })
+    }
+}
+
+/// Returns the new index of an index under a relabeling, indices not contained in the mapping are unchanged.
+pub(crate) fn relabeled_index(mapping: &HashMap<usize, usize>, index: usize) -> usize {
+    *mapping.get(&index).unwrap_or(&index)
+}
+
+/// Checks that a relabeling maps different indices onto different indices.
+///
+/// # Arguments
+///
+/// * `indices` - The indices that are relabeled.
+/// * `mapping` - The {old index: new index} mapping, indices not contained in it are unchanged.
+///
+/// # Returns
+///
+/// * `Ok(())` - The relabeling is one-to-one on the indices.
+/// * `Err(StruqtureError::GenericError)` - Two different indices are mapped onto the same index.
+pub(crate) fn check_relabeling(
+    indices: impl IntoIterator<Item = usize>,
+    mapping: &HashMap<usize, usize>,
+) -> Result<(), StruqtureError> {
+    let indices: BTreeSet<usize> = indices.into_iter().collect();
+    let mut images: BTreeSet<usize> = BTreeSet::new();
+    for index in indices {
+        let image = relabeled_index(mapping, index);
+        if !images.insert(image) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The relabeling maps several indices onto index {}, it must be one-to-one",
+                    image
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Relabels the modes of a term and transforms its coefficient accordingly.
+fn relabel_mode_term<I: ModeIndex>(
+    index: &I,
+    value: &CalculatorComplex,
+    mapping: &HashMap<usize, usize>,
+) -> Result<(I, CalculatorComplex), StruqtureError> {
+    I::create_valid_pair(
+        index
+            .creators()
+            .map(|mode| relabeled_index(mapping, *mode))
+            .collect::<Vec<usize>>(),
+        index
+            .annihilators()
+            .map(|mode| relabeled_index(mapping, *mode))
+            .collect::<Vec<usize>>(),
+        value.clone(),
+    )
+}
+
+macro_rules! impl_relabel_mode_system {
+    ($system:ident) => {
+        impl $system {
+            /// Relabels the modes of the system according to a mapping of indices.
+            ///
+            /// The mapping does not have to be a permutation, e.g. it can shift all indices.
+            /// Modes that are not contained in the mapping keep their index.
+            /// The number of modes of the system is unchanged.
+            ///
+            /// # Arguments
+            ///
+            /// * `mapping` - The {old index: new index} mapping to use.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` - The system with the modes relabeled.
+            /// * `Err(StruqtureError::GenericError)` - Two different modes are mapped onto the same mode.
+            /// * `Err(StruqtureError)` - A relabeled mode exceeds the number of modes of the system.
+            pub fn relabel(&self, mapping: &HashMap<usize, usize>) -> Result<Self, StruqtureError> {
+                check_relabeling(
+                    self.keys()
+                        .flat_map(|key| key.creators().chain(key.annihilators()).copied()),
+                    mapping,
+                )?;
+                let mut relabeled = $system::new(self.number_modes);
+                for (key, value) in self.iter() {
+                    let (new_key, new_value) = relabel_mode_term(key, value, mapping)?;
+                    relabeled.add_operator_product(new_key, new_value)?;
+                }
+                Ok(relabeled)
+            }
+        }
+    };
+}
+
+macro_rules! impl_relabel_spin_system {
+    ($system:ident) => {
+        impl $system {
+            /// Relabels the spins of the system according to a mapping of indices.
+            ///
+            /// The mapping does not have to be a permutation, e.g. it can shift all indices.
+            /// Spins that are not contained in the mapping keep their index.
+            /// The number of spins of the system is unchanged.
+            ///
+            /// # Arguments
+            ///
+            /// * `mapping` - The {old index: new index} mapping to use.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` - The system with the spins relabeled.
+            /// * `Err(StruqtureError::GenericError)` - Two different spins are mapped onto the same spin.
+            /// * `Err(StruqtureError)` - A relabeled spin exceeds the number of spins of the system.
+            pub fn relabel(&self, mapping: &HashMap<usize, usize>) -> Result<Self, StruqtureError> {
+                check_relabeling(
+                    self.keys()
+                        .flat_map(|key| key.iter().map(|(index, _)| *index)),
+                    mapping,
+                )?;
+                let mut relabeled = $system::new(self.number_spins);
+                for (key, value) in self.iter() {
+                    relabeled.add_operator_product(key.remap_qubits(mapping), value.clone())?;
+                }
+                Ok(relabeled)
+            }
+        }
+    };
+}
+
+impl_relabel_mode_system!(BosonSystem);
+impl_relabel_mode_system!(BosonHamiltonianSystem);
+impl_relabel_mode_system!(FermionSystem);
+impl_relabel_mode_system!(FermionHamiltonianSystem);
+impl_relabel_spin_system!(SpinSystem);
+impl_relabel_spin_system!(SpinHamiltonianSystem);
+
+/// Tolerance for the comparison of the characters of group elements.
+const CHARACTER_TOLERANCE: f64 = 1e-10;
+
+/// Returns all elements of the group generated by index permutations together with their characters.
+///
+/// The characters of the generators define a one-dimensional representation of the group,
+/// the character of every other element is the product of the characters of the generators composing it.
+///
+/// # Arguments
+///
+/// * `generator_permutations` - The generators of the group, `generator[i]` is the new index of index `i`.
+/// * `characters` - The characters of the generators, complex numbers with absolute value one.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(HashMap<usize, usize>, Complex64)>)` - The {old index: new index} mapping and the character of every group element.
+/// * `Err(StruqtureError::GenericError)` - The numbers of generators and characters differ.
+/// * `Err(StruqtureError::GenericError)` - A generator is not a permutation of the same indices as the others.
+/// * `Err(StruqtureError::GenericError)` - The characters are not a one-dimensional unitary representation of the group.
+pub(crate) fn symmetry_group_elements(
+    generator_permutations: &[Vec<usize>],
+    characters: &[Complex64],
+) -> Result<Vec<(HashMap<usize, usize>, Complex64)>, StruqtureError> {
+    if generator_permutations.len() != characters.len() {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "{} generators are given with {} characters",
+                generator_permutations.len(),
+                characters.len()
+            ),
+        });
+    }
+    let number_indices = generator_permutations.first().map_or(0, |g| g.len());
+    for generator in generator_permutations {
+        let images: BTreeSet<usize> = generator.iter().copied().collect();
+        if generator.len() != number_indices
+            || images.len() != number_indices
+            || images.iter().any(|image| *image >= number_indices)
+        {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Generator {:?} is not a permutation of the indices 0..{}",
+                    generator, number_indices
+                ),
+            });
+        }
+    }
+    if let Some(character) = characters
+        .iter()
+        .find(|character| (character.norm() - 1.0).abs() > CHARACTER_TOLERANCE)
+    {
+        return Err(StruqtureError::GenericError {
+            msg: format!("Character {} does not have absolute value one", character),
+        });
+    }
+
+    let identity: Vec<usize> = (0..number_indices).collect();
+    let mut elements: HashMap<Vec<usize>, Complex64> =
+        HashMap::from([(identity.clone(), Complex64::new(1.0, 0.0))]);
+    let mut unvisited: Vec<Vec<usize>> = vec![identity];
+    while let Some(element) = unvisited.pop() {
+        let element_character = elements[&element];
+        for (generator, generator_character) in generator_permutations.iter().zip(characters) {
+            let product: Vec<usize> = element.iter().map(|index| generator[*index]).collect();
+            let product_character = element_character * generator_character;
+            match elements.get(&product) {
+                Some(character) => {
+                    if (character - product_character).norm() > CHARACTER_TOLERANCE {
+                        return Err(StruqtureError::GenericError {
+                            msg: format!(
+                                "The characters are not a one-dimensional representation, permutation {:?} has the characters {} and {}",
+                                product, character, product_character
+                            ),
+                        });
+                    }
+                }
+                None => {
+                    elements.insert(product.clone(), product_character);
+                    unvisited.push(product);
+                }
+            }
+        }
+    }
+    Ok(elements
+        .into_iter()
+        .map(|(permutation, character)| (permutation.into_iter().enumerate().collect(), character))
+        .collect())
+}
+
+macro_rules! impl_project_onto_symmetry_sector {
+    ($system:ident, $relabel_term:expr) => {
+        impl $system {
+            /// Projects the system onto a symmetry sector of a group of index permutations.
+            ///
+            /// The projection is P(O) = 1/|G| Σ_g χ(g)^* g(O), where g(O) relabels the indices of O with the permutation g.
+            /// The group G is generated by the given permutations and the characters χ of the generators define
+            /// a one-dimensional representation, e.g. the phases exp(i k) of the translations of a lattice with momentum k.
+            /// The projected system transforms as g(P(O)) = χ(g) P(O). Coefficients that cancel in the sum can leave
+            /// numerical residues, which can be removed with `truncate`.
+            ///
+            /// # Arguments
+            ///
+            /// * `generator_permutations` - The generators of the group, `generator[i]` is the new index of index `i`. Indices not covered by the generators are unchanged.
+            /// * `characters` - The characters of the generators, complex numbers with absolute value one.
+            ///
+            /// # Returns
+            ///
+            /// * `Ok(Self)` - The projected system.
+            /// * `Err(StruqtureError::GenericError)` - The generators or characters are invalid.
+            /// * `Err(StruqtureError)` - A permuted index exceeds the size of the system.
+            pub fn project_onto_symmetry_sector(
+                &self,
+                generator_permutations: &[Vec<usize>],
+                characters: &[Complex64],
+            ) -> Result<Self, StruqtureError> {
+                let group = symmetry_group_elements(generator_permutations, characters)?;
+                let weight = 1.0 / group.len() as f64;
+                let mut projected = self.empty_clone(Some(self.len()));
+                for (mapping, character) in group.iter() {
+                    let prefactor =
+                        CalculatorComplex::new(weight * character.re, -weight * character.im);
+                    for (key, value) in self.iter() {
+                        let (new_key, new_value) = $relabel_term(key, value, mapping)?;
+                        projected.add_operator_product(new_key, new_value * prefactor.clone())?;
+                    }
+                }
+                Ok(projected)
+            }
+        }
+    };
+}
+
+/// Relabels the spins of a term, the coefficient is unchanged.
+fn relabel_spin_term<I: SpinIndex>(
+    index: &I,
+    value: &CalculatorComplex,
+    mapping: &HashMap<usize, usize>,
+) -> Result<(I, CalculatorComplex), StruqtureError> {
+    Ok((index.remap_qubits(mapping), value.clone()))
+}
+
+impl_project_onto_symmetry_sector!(BosonSystem, relabel_mode_term);
+impl_project_onto_symmetry_sector!(FermionSystem, relabel_mode_term);
+impl_project_onto_symmetry_sector!(SpinSystem, relabel_spin_term);
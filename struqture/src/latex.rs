@@ -0,0 +1,622 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! LaTeX representation of struqture products, operators and systems.
+//!
+//! The Display output of struqture objects is designed for debugging and round-trips through strings.
+//! [ToLatex] instead produces formulas for notebooks and papers, e.g. `0.5\, \sigma^z_{0} \sigma^z_{1}`.
+//! Spins are written with Pauli matrices \sigma (S for spin-S operators), bosons with b and fermions with c.
+//! In mixed systems, the index of every operator is preceded by the index of its subsystem.
+//! Terms of Hamiltonians that are not naturally hermitian are followed by their hermitian conjugate (h.c.)
+//! and the terms of noise operators are written as \mathcal{D}[L, R] for the pair of operators (L, R).
+//!
+//! The number of terms, their order and the formatting of the coefficients are configured with [LatexFormat].
+//!
+//! # Example
+//! ```
+//! use struqture::latex::{LatexFormat, LatexOrdering, ToLatex};
+//! use struqture::prelude::*;
+//! use struqture::spins::{PauliProduct, SpinHamiltonian};
+//!
+//! let mut sh = SpinHamiltonian::new();
+//! sh.add_operator_product(PauliProduct::new().z(0).z(1), 0.5.into()).unwrap();
+//! sh.add_operator_product(PauliProduct::new().x(0), (-2.0).into()).unwrap();
+//! assert_eq!(sh.to_latex(), r"-2\, \sigma^x_{0} + 0.5\, \sigma^z_{0} \sigma^z_{1}");
+//!
+//! let format = LatexFormat {
+//!     max_terms: Some(1),
+//!     ordering: LatexOrdering::Magnitude,
+//!     precision: Some(2),
+//! };
+//! assert_eq!(sh.to_latex_with(&format), r"-2.00\, \sigma^x_{0} + \dots");
+//! ```
+
+use crate::bosons::*;
+use crate::fermions::*;
+use crate::mixed_systems::*;
+use crate::spins::*;
+use crate::{ModeIndex, OperateOnDensityMatrix, SpinIndex, SymmetricIndex};
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+
+/// Order of the terms in the LaTeX representation of an operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatexOrdering {
+    /// Terms are sorted by their index, in the order of the canonical text representation.
+    #[default]
+    Index,
+    /// Terms are sorted by the absolute value of their coefficient, largest first. Symbolic coefficients come last.
+    Magnitude,
+}
+
+/// Configuration of the LaTeX representation of operators.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LatexFormat {
+    /// The maximal number of terms written, further terms are replaced by \dots. All terms are written for None.
+    pub max_terms: Option<usize>,
+    /// The order of the terms.
+    pub ordering: LatexOrdering,
+    /// The number of decimal places of numeric coefficients. The shortest representation is used for None.
+    pub precision: Option<usize>,
+}
+
+/// Trait for the LaTeX representation of struqture objects.
+pub trait ToLatex {
+    /// Returns the LaTeX representation of Self with the default format.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The LaTeX representation of Self.
+    fn to_latex(&self) -> String {
+        self.to_latex_with(&LatexFormat::default())
+    }
+
+    /// Returns the LaTeX representation of Self with a given format.
+    ///
+    /// The format only applies to objects with coefficients, products are always written completely.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The number of terms, their order and the formatting of the coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The LaTeX representation of Self.
+    fn to_latex_with(&self, format: &LatexFormat) -> String;
+}
+
+/// Trait for the operator factors of products, shared by the products of single and mixed systems.
+trait LatexFactors {
+    /// Returns the LaTeX factors of the product, with the subsystem index in mixed systems.
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String>;
+}
+
+/// Returns the subscript of an operator acting on a spin or mode.
+fn latex_index(index: usize, subsystem: Option<usize>) -> String {
+    match subsystem {
+        Some(subsystem) => format!("{{{},{}}}", subsystem, index),
+        None => format!("{{{}}}", index),
+    }
+}
+
+/// Returns the LaTeX representation of a product from its factors.
+fn latex_product(factors: Vec<String>) -> String {
+    if factors.is_empty() {
+        r"\mathbb{1}".to_string()
+    } else {
+        factors.join(" ")
+    }
+}
+
+impl LatexFactors for PauliProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        self.iter()
+            .filter_map(|(index, operator)| {
+                let symbol = match operator {
+                    SingleSpinOperator::Identity => return None,
+                    SingleSpinOperator::X => r"\sigma^x",
+                    SingleSpinOperator::Y => r"\sigma^y",
+                    SingleSpinOperator::Z => r"\sigma^z",
+                };
+                Some(format!("{}_{}", symbol, latex_index(*index, subsystem)))
+            })
+            .collect()
+    }
+}
+
+impl LatexFactors for DecoherenceProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        self.iter()
+            .filter_map(|(index, operator)| {
+                let symbol = match operator {
+                    SingleDecoherenceOperator::Identity => return None,
+                    SingleDecoherenceOperator::X => r"\sigma^x",
+                    SingleDecoherenceOperator::IY => r"i\sigma^y",
+                    SingleDecoherenceOperator::Z => r"\sigma^z",
+                };
+                Some(format!("{}_{}", symbol, latex_index(*index, subsystem)))
+            })
+            .collect()
+    }
+}
+
+impl LatexFactors for PlusMinusProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        self.iter()
+            .filter_map(|(index, operator)| {
+                let symbol = match operator {
+                    SinglePlusMinusOperator::Identity => return None,
+                    SinglePlusMinusOperator::Plus => r"\sigma^+",
+                    SinglePlusMinusOperator::Minus => r"\sigma^-",
+                    SinglePlusMinusOperator::Z => r"\sigma^z",
+                };
+                Some(format!("{}_{}", symbol, latex_index(*index, subsystem)))
+            })
+            .collect()
+    }
+}
+
+impl LatexFactors for SpinSProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        self.iter()
+            .filter_map(|(index, operator)| {
+                let symbol = match operator {
+                    SingleSpinSOperator::Identity => return None,
+                    SingleSpinSOperator::X => "S^x",
+                    SingleSpinSOperator::Y => "S^y",
+                    SingleSpinSOperator::Z => "S^z",
+                    SingleSpinSOperator::Plus => "S^+",
+                    SingleSpinSOperator::Minus => "S^-",
+                };
+                Some(format!("{}_{}", symbol, latex_index(*index, subsystem)))
+            })
+            .collect()
+    }
+}
+
+/// Returns the LaTeX factors of a normal ordered product of creation and annihilation operators.
+fn ladder_factors<T: ModeIndex>(
+    product: &T,
+    symbol: &str,
+    subsystem: Option<usize>,
+) -> Vec<String> {
+    product
+        .creators()
+        .map(|index| format!(r"{}^{{\dagger}}_{}", symbol, latex_index(*index, subsystem)))
+        .chain(
+            product
+                .annihilators()
+                .map(|index| format!("{}_{}", symbol, latex_index(*index, subsystem))),
+        )
+        .collect()
+}
+
+impl LatexFactors for BosonProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        ladder_factors(self, "b", subsystem)
+    }
+}
+
+impl LatexFactors for HermitianBosonProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        ladder_factors(self, "b", subsystem)
+    }
+}
+
+impl LatexFactors for FermionProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        ladder_factors(self, "c", subsystem)
+    }
+}
+
+impl LatexFactors for HermitianFermionProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        ladder_factors(self, "c", subsystem)
+    }
+}
+
+impl LatexFactors for QuadratureProduct {
+    fn latex_factors(&self, subsystem: Option<usize>) -> Vec<String> {
+        let power = |symbol: &str, mode: usize, exponent: usize| match exponent {
+            1 => format!("{}_{}", symbol, latex_index(mode, subsystem)),
+            _ => format!(
+                "{}_{}^{{{}}}",
+                symbol,
+                latex_index(mode, subsystem),
+                exponent
+            ),
+        };
+        let mut factors: Vec<String> = Vec::new();
+        for (mode, x_power, p_power) in self.iter() {
+            if *x_power > 0 {
+                factors.push(power("x", *mode, *x_power));
+            }
+            if *p_power > 0 {
+                factors.push(power("p", *mode, *p_power));
+            }
+        }
+        factors
+    }
+}
+
+/// Implements LatexFactors for mixed products by numbering the subsystems of every type.
+macro_rules! impl_latex_factors_mixed {
+    ($($product:ident),* $(,)?) => {
+        $(
+            impl LatexFactors for $product {
+                fn latex_factors(&self, _subsystem: Option<usize>) -> Vec<String> {
+                    let mut factors: Vec<String> = Vec::new();
+                    for (subsystem, spins) in self.spins().enumerate() {
+                        factors.extend(spins.latex_factors(Some(subsystem)));
+                    }
+                    for (subsystem, bosons) in self.bosons().enumerate() {
+                        factors.extend(bosons.latex_factors(Some(subsystem)));
+                    }
+                    for (subsystem, fermions) in self.fermions().enumerate() {
+                        factors.extend(fermions.latex_factors(Some(subsystem)));
+                    }
+                    factors
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_factors_mixed!(
+    MixedProduct,
+    HermitianMixedProduct,
+    MixedDecoherenceProduct,
+    MixedPlusMinusProduct,
+);
+
+/// Formats a numeric coefficient with the precision of the format.
+fn latex_float(value: f64, precision: Option<usize>) -> String {
+    // Negative zero is written as zero
+    let value = if value == 0.0 { 0.0 } else { value };
+    match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    }
+}
+
+/// Formats a symbolic coefficient, expressions that are not a single name are put in parentheses.
+fn latex_symbol(expression: &str) -> String {
+    if expression
+        .chars()
+        .all(|character| character.is_alphanumeric() || character == '_')
+    {
+        expression.to_string()
+    } else {
+        format!("({})", expression)
+    }
+}
+
+/// Formats a CalculatorFloat that is part of a complex coefficient.
+fn latex_part(value: &CalculatorFloat, precision: Option<usize>) -> String {
+    match value {
+        CalculatorFloat::Float(x) => latex_float(*x, precision),
+        CalculatorFloat::Str(expression) => latex_symbol(expression),
+    }
+}
+
+/// Formats a coefficient given by its real and imaginary part.
+///
+/// Returns whether the coefficient is a negative number, in which case the absolute value is formatted,
+/// so that the sign can be written between the terms.
+fn latex_coefficient(
+    re: &CalculatorFloat,
+    im: &CalculatorFloat,
+    precision: Option<usize>,
+) -> (bool, String) {
+    match (re, im) {
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) if *im == 0.0 => {
+            (*re < 0.0, latex_float(re.abs(), precision))
+        }
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) if *re == 0.0 => {
+            (*im < 0.0, format!("{}i", latex_float(im.abs(), precision)))
+        }
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => (
+            false,
+            format!(
+                "({} {} {}i)",
+                latex_float(*re, precision),
+                if *im < 0.0 { "-" } else { "+" },
+                latex_float(im.abs(), precision)
+            ),
+        ),
+        (re, CalculatorFloat::Float(im)) if *im == 0.0 => (false, latex_part(re, precision)),
+        (CalculatorFloat::Float(re), im) if *re == 0.0 => {
+            (false, format!("{}i", latex_part(im, precision)))
+        }
+        (re, im) => (
+            false,
+            format!(
+                "({} + {}i)",
+                latex_part(re, precision),
+                latex_part(im, precision)
+            ),
+        ),
+    }
+}
+
+/// Returns the absolute value of a coefficient, None for symbolic coefficients.
+fn coefficient_magnitude(re: &CalculatorFloat, im: &CalculatorFloat) -> Option<f64> {
+    match (re, im) {
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => Some(re.hypot(*im)),
+        _ => None,
+    }
+}
+
+/// Trait for the coefficients of the terms of an operator.
+trait LatexCoefficient {
+    /// Returns the real and imaginary part of the coefficient.
+    fn parts(&self) -> (CalculatorFloat, CalculatorFloat);
+}
+
+impl LatexCoefficient for CalculatorFloat {
+    fn parts(&self) -> (CalculatorFloat, CalculatorFloat) {
+        (self.clone(), CalculatorFloat::ZERO)
+    }
+}
+
+impl LatexCoefficient for CalculatorComplex {
+    fn parts(&self) -> (CalculatorFloat, CalculatorFloat) {
+        (self.re.clone(), self.im.clone())
+    }
+}
+
+/// A single term of an operator prepared for the LaTeX representation.
+struct LatexTerm {
+    /// The text of the index used for sorting by index.
+    index: String,
+    /// The absolute value of the coefficient, None for symbolic coefficients.
+    magnitude: Option<f64>,
+    /// Whether the term is subtracted.
+    negative: bool,
+    /// The term without its sign.
+    text: String,
+}
+
+impl LatexTerm {
+    /// Creates the LaTeX term of a product with a coefficient.
+    ///
+    /// Terms that are not naturally hermitian in Hamiltonians are followed by their hermitian conjugate.
+    fn new<V: LatexCoefficient>(
+        index: String,
+        product: String,
+        coefficient: &V,
+        add_hermitian_conjugate: bool,
+        format: &LatexFormat,
+    ) -> Self {
+        let (re, im) = coefficient.parts();
+        let (negative, body) = latex_coefficient(&re, &im, format.precision);
+        let text = if product == r"\mathbb{1}" {
+            body
+        } else {
+            format!(r"{}\, {}", body, product)
+        };
+        let (negative, text) = if add_hermitian_conjugate {
+            let sign = if negative { "-" } else { "" };
+            (false, format!(r"({}{} + \mathrm{{h.c.}})", sign, text))
+        } else {
+            (negative, text)
+        };
+        LatexTerm {
+            index,
+            magnitude: coefficient_magnitude(&re, &im),
+            negative,
+            text,
+        }
+    }
+}
+
+/// Joins the terms of an operator with the ordering and number of terms of the format.
+fn latex_sum(mut terms: Vec<LatexTerm>, format: &LatexFormat) -> String {
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    match format.ordering {
+        LatexOrdering::Index => terms.sort_by(|a, b| a.index.cmp(&b.index)),
+        LatexOrdering::Magnitude => terms.sort_by(|a, b| match (a.magnitude, b.magnitude) {
+            (Some(x), Some(y)) => y.total_cmp(&x).then_with(|| a.index.cmp(&b.index)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.index.cmp(&b.index),
+        }),
+    }
+    let number_terms = terms.len();
+    let shown = format.max_terms.unwrap_or(number_terms).min(number_terms);
+    let mut text = String::new();
+    for (position, term) in terms.iter().take(shown).enumerate() {
+        match (position, term.negative) {
+            (0, true) => text.push('-'),
+            (0, false) => (),
+            (_, true) => text.push_str(" - "),
+            (_, false) => text.push_str(" + "),
+        }
+        text.push_str(&term.text);
+    }
+    if shown < number_terms {
+        if shown > 0 {
+            text.push_str(" + ");
+        }
+        text.push_str(r"\dots");
+    }
+    text
+}
+
+/// Implements ToLatex for products.
+macro_rules! impl_latex_product {
+    ($($product:ident),* $(,)?) => {
+        $(
+            impl ToLatex for $product {
+                fn to_latex_with(&self, _format: &LatexFormat) -> String {
+                    latex_product(self.latex_factors(None))
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_product!(
+    PauliProduct,
+    DecoherenceProduct,
+    PlusMinusProduct,
+    SpinSProduct,
+    BosonProduct,
+    HermitianBosonProduct,
+    QuadratureProduct,
+    FermionProduct,
+    HermitianFermionProduct,
+    MixedProduct,
+    HermitianMixedProduct,
+    MixedDecoherenceProduct,
+    MixedPlusMinusProduct,
+);
+
+/// Returns the LaTeX representation of the terms of an operator.
+///
+/// The closure states for every product whether it represents the product and its hermitian conjugate.
+fn latex_operator<'b, K, V>(
+    entries: impl Iterator<Item = (&'b K, &'b V)>,
+    format: &LatexFormat,
+    add_hermitian_conjugate: impl Fn(&K) -> bool,
+) -> String
+where
+    K: ToLatex + std::fmt::Display + 'b,
+    V: LatexCoefficient + 'b,
+{
+    let terms = entries
+        .map(|(product, value)| {
+            LatexTerm::new(
+                product.to_string(),
+                product.to_latex(),
+                value,
+                add_hermitian_conjugate(product),
+                format,
+            )
+        })
+        .collect();
+    latex_sum(terms, format)
+}
+
+/// Implements ToLatex for operators.
+macro_rules! impl_latex_operator {
+    ($($operator:ident),* $(,)?) => {
+        $(
+            impl ToLatex for $operator {
+                fn to_latex_with(&self, format: &LatexFormat) -> String {
+                    latex_operator(self.iter(), format, |_| false)
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_operator!(
+    SpinOperator,
+    SpinHamiltonian,
+    DecoherenceOperator,
+    PlusMinusOperator,
+    SpinSOperator,
+    BosonOperator,
+    BosonQuadratureOperator,
+    FermionOperator,
+    MixedOperator,
+    MixedPlusMinusOperator,
+);
+
+/// Implements ToLatex for Hamiltonians, where products that are not naturally hermitian
+/// represent the product and its hermitian conjugate.
+macro_rules! impl_latex_hamiltonian {
+    ($($hamiltonian:ident),* $(,)?) => {
+        $(
+            impl ToLatex for $hamiltonian {
+                fn to_latex_with(&self, format: &LatexFormat) -> String {
+                    latex_operator(self.iter(), format, |product| !product.is_natural_hermitian())
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_hamiltonian!(
+    PlusMinusHamiltonian,
+    SpinSHamiltonian,
+    BosonHamiltonian,
+    FermionHamiltonian,
+    MixedHamiltonian,
+);
+
+/// Implements ToLatex for noise operators, writing every pair of operators as \mathcal{D}[L, R].
+macro_rules! impl_latex_noise_operator {
+    ($($operator:ident),* $(,)?) => {
+        $(
+            impl ToLatex for $operator {
+                fn to_latex_with(&self, format: &LatexFormat) -> String {
+                    let terms = self
+                        .iter()
+                        .map(|((left, right), value)| {
+                            LatexTerm::new(
+                                format!("{}\t{}", left, right),
+                                format!(
+                                    r"\mathcal{{D}}[{}, {}]",
+                                    left.to_latex(),
+                                    right.to_latex()
+                                ),
+                                value,
+                                false,
+                                format,
+                            )
+                        })
+                        .collect();
+                    latex_sum(terms, format)
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_noise_operator!(
+    SpinLindbladNoiseOperator,
+    PlusMinusLindbladNoiseOperator,
+    BosonLindbladNoiseOperator,
+    FermionLindbladNoiseOperator,
+    MixedLindbladNoiseOperator,
+);
+
+/// Implements ToLatex for systems by the LaTeX representation of their operator.
+macro_rules! impl_latex_system {
+    ($($system:ident: $operator:ident),* $(,)?) => {
+        $(
+            impl ToLatex for $system {
+                fn to_latex_with(&self, format: &LatexFormat) -> String {
+                    self.$operator().to_latex_with(format)
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_system!(
+    SpinSystem: operator,
+    SpinHamiltonianSystem: hamiltonian,
+    SpinLindbladNoiseSystem: operator,
+    BosonSystem: operator,
+    BosonHamiltonianSystem: hamiltonian,
+    BosonLindbladNoiseSystem: operator,
+    FermionSystem: operator,
+    FermionHamiltonianSystem: hamiltonian,
+    FermionLindbladNoiseSystem: operator,
+    MixedSystem: operator,
+    MixedHamiltonianSystem: hamiltonian,
+    MixedLindbladNoiseSystem: operator,
+);
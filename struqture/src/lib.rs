@@ -17,7 +17,8 @@
 // #![warn(private_doc_tests)]
 // #![deny(missing_debug_implementations)]
 
-use num_complex::Complex64;
+use ndarray::Array2;
+use num_complex::{Complex32, Complex64};
 use qoqo_calculator::CalculatorComplex;
 use qoqo_calculator::CalculatorError;
 use qoqo_calculator::CalculatorFloat;
@@ -222,6 +223,12 @@ pub enum StruqtureError {
         "Key is naturally hermitian (on-diagonal term), but its corresponding value is not real."
     )]
     NonHermitianOperator,
+    /// Error when an operator is converted into a Hamiltonian, but is not hermitian.
+    #[error("The operator is not hermitian, terms with a non-vanishing residual A - A^dagger: {terms:?}")]
+    NonHermitianTerms {
+        /// The keys and the residuals A - A^dagger of the terms violating hermiticity.
+        terms: Vec<(String, String)>,
+    },
     /// Error when parsing from str
     #[error("Error parsing str into {target_type}: {msg}")]
     ParsingError { target_type: String, msg: String },
@@ -243,6 +250,14 @@ pub enum StruqtureError {
     /// Error when trying to insert identities into noise operators
     #[error("Lindblad operators need to be traceless.")]
     InvalidLindbladTerms,
+    /// Error when (de)serializing a struqture object in one of the formats of the io module.
+    #[error("Error in {format} serialization: {msg}")]
+    SerializationError {
+        /// The serialization format
+        format: String,
+        /// Error message
+        msg: String,
+    },
     /// Gerneric Error in struqture.
     #[error("Error occured: {msg}")]
     GenericError {
@@ -261,6 +276,134 @@ pub type CooSparseMatrix = (Vec<Complex64>, (Vec<usize>, Vec<usize>));
 /// Input in the form (value_vector, (row_index_vector, column_index_vector))
 pub type CooSparseMatrixReal = (Vec<f64>, (Vec<usize>, Vec<usize>));
 
+/// Complex sparse matrix in coordinate (COO) format with single precision (complex64) values.
+///
+/// Input in the form (value_vector, (row_index_vector, column_index_vector))
+pub type CooSparseMatrixF32 = (Vec<Complex32>, (Vec<usize>, Vec<usize>));
+
+/// Complex sparse matrix in compressed sparse row (CSR) format.
+///
+/// Input in the form (value_vector, column_index_vector, row_pointer_vector), the order expected by scipy.sparse.csr_matrix.
+/// The values and column indices of row `i` are stored at positions `row_pointer[i]..row_pointer[i + 1]`,
+/// with the column indices of each row sorted in ascending order.
+pub type CsrSparseMatrix = (Vec<Complex64>, Vec<usize>, Vec<usize>);
+
+/// Complex sparse matrix in compressed sparse row (CSR) format with single precision (complex64) values.
+///
+/// Input in the form (value_vector, column_index_vector, row_pointer_vector), see [CsrSparseMatrix].
+pub type CsrSparseMatrixF32 = (Vec<Complex32>, Vec<usize>, Vec<usize>);
+
+/// Diagnostics of the rounding when converting double precision values to single precision.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RoundingDiagnostics {
+    /// The largest absolute difference between a value and its single precision representation.
+    pub max_absolute_error: f64,
+    /// The largest relative difference between a non-zero value and its single precision representation.
+    pub max_relative_error: f64,
+    /// The number of non-zero values that are rounded to zero in single precision.
+    pub number_underflows: usize,
+}
+
+/// Converts double precision complex values to single precision.
+///
+/// # Arguments
+///
+/// * `values` - The double precision values to convert.
+///
+/// # Returns
+///
+/// * `Ok((Vec<Complex32>, RoundingDiagnostics))` - The single precision values and the diagnostics of the rounding.
+/// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+pub fn to_single_precision(
+    values: &[Complex64],
+) -> Result<(Vec<Complex32>, RoundingDiagnostics), StruqtureError> {
+    let mut diagnostics = RoundingDiagnostics::default();
+    let mut converted: Vec<Complex32> = Vec::with_capacity(values.len());
+    for value in values.iter() {
+        let single = Complex32::new(value.re as f32, value.im as f32);
+        if !single.is_finite() && value.is_finite() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Value {} exceeds the range of single precision floats.",
+                    value
+                ),
+            });
+        }
+        let absolute_error = (value - Complex64::new(single.re as f64, single.im as f64)).norm();
+        diagnostics.max_absolute_error = diagnostics.max_absolute_error.max(absolute_error);
+        if value.norm() > 0.0 {
+            diagnostics.max_relative_error = diagnostics
+                .max_relative_error
+                .max(absolute_error / value.norm());
+            if single.norm() == 0.0 {
+                diagnostics.number_underflows += 1;
+            }
+        }
+        converted.push(single);
+    }
+    Ok((converted, diagnostics))
+}
+
+/// Converts a double precision COO sparse matrix to single precision.
+///
+/// # Arguments
+///
+/// * `matrix` - The double precision sparse matrix in COO format.
+///
+/// # Returns
+///
+/// * `Ok((CooSparseMatrixF32, RoundingDiagnostics))` - The single precision matrix and the diagnostics of the rounding.
+/// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+pub fn coo_to_single_precision(
+    matrix: CooSparseMatrix,
+) -> Result<(CooSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+    let (values, (rows, columns)) = matrix;
+    let (values, diagnostics) = to_single_precision(&values)?;
+    Ok(((values, (rows, columns)), diagnostics))
+}
+
+/// Converts a double precision CSR sparse matrix to single precision.
+///
+/// # Arguments
+///
+/// * `matrix` - The double precision sparse matrix in CSR format.
+///
+/// # Returns
+///
+/// * `Ok((CsrSparseMatrixF32, RoundingDiagnostics))` - The single precision matrix and the diagnostics of the rounding.
+/// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+pub fn csr_to_single_precision(
+    matrix: CsrSparseMatrix,
+) -> Result<(CsrSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+    let (values, columns, row_pointers) = matrix;
+    let (values, diagnostics) = to_single_precision(&values)?;
+    Ok(((values, columns, row_pointers), diagnostics))
+}
+
+/// Converts a double precision dense matrix to single precision.
+///
+/// # Arguments
+///
+/// * `matrix` - The double precision dense matrix.
+///
+/// # Returns
+///
+/// * `Ok((Array2<Complex32>, RoundingDiagnostics))` - The single precision matrix and the diagnostics of the rounding.
+/// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+pub fn dense_to_single_precision(
+    matrix: ndarray::Array2<Complex64>,
+) -> Result<(ndarray::Array2<Complex32>, RoundingDiagnostics), StruqtureError> {
+    let shape = matrix.raw_dim();
+    let values: Vec<Complex64> = matrix.into_iter().collect();
+    let (values, diagnostics) = to_single_precision(&values)?;
+    let converted = ndarray::Array2::from_shape_vec(shape, values).map_err(|err| {
+        StruqtureError::GenericError {
+            msg: format!("Could not construct single precision matrix: {}", err),
+        }
+    })?;
+    Ok((converted, diagnostics))
+}
+
 /// Trait for all hermitian indices
 pub trait SymmetricIndex:
     std::hash::Hash + Eq + Sized + Clone + std::fmt::Debug + std::fmt::Display + FromStr + Default
@@ -385,6 +528,24 @@ where
     /// * `Self` -  The new object with the qubits remapped from Self.
     fn remap_qubits(&self, mapping: &HashMap<usize, usize>) -> Self;
 
+    /// Relabels the qubits in a clone instance of Self.
+    ///
+    /// In contrast to [SpinIndex::remap_qubits], the relabeling is checked to map different qubits onto different qubits.
+    /// Qubits that are not contained in the mapping keep their index.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The map containing the {qubit: qubit} mapping to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new object with the qubits relabeled.
+    /// * `Err(StruqtureError::GenericError)` - Two different qubits are mapped onto the same qubit.
+    fn relabel(&self, mapping: &HashMap<usize, usize>) -> Result<Self, StruqtureError> {
+        labeling::check_relabeling(self.iter().map(|(index, _)| *index), mapping)?;
+        Ok(self.remap_qubits(mapping))
+    }
+
     /// Implements multiplication function for a Self typed object by a Self typed object.
     ///
     /// # Arguments
@@ -468,6 +629,31 @@ pub trait ModeIndex:
         value: CalculatorComplex,
     ) -> Result<(Self, CalculatorComplex), StruqtureError>;
 
+    /// Creates the number operator $c_i^{\dagger} c_i$ acting on a single mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode the number operator acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The index with one creator and one annihilator acting on mode.
+    fn number(mode: usize) -> Self {
+        Self::new([mode], [mode]).expect("A number operator is a valid index for all mode types")
+    }
+
+    /// Returns whether Self is a density term.
+    ///
+    /// A density term is a product of number operators up to normal ordering, e.g. $c_0^{\dagger} c_1^{\dagger} c_0 c_1$.
+    /// It has the same creator and annihilator indices and is diagonal in the occupation number basis.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether Self is a non-empty product with identical creator and annihilator indices.
+    fn is_density_term(&self) -> bool {
+        self.number_creators() > 0 && self.creators().eq(self.annihilators())
+    }
+
     /// Returns the maximal number of modes the Index (operator product) acts on.
     ///
     /// A ModeIndex acts on a state space of unknown dimension.
@@ -536,6 +722,35 @@ pub trait ModeIndex:
             )?;
         Ok((remapped_index, new_coeff))
     }
+
+    /// Relabels modes according to an input dictionary.
+    ///
+    /// In contrast to [ModeIndex::remap_modes], the dictionary does not have to be a permutation,
+    /// e.g. it can shift all indices by an offset. Modes that are not contained in the dictionary keep their index.
+    ///
+    /// # Arguments
+    ///
+    /// `mapping` - The dictionary specifying the {old index: new index} relabeling.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, CalculatorComplex))` - The instance of Self with modes relabeled, and the sign resulting from symmetry/antisymmetry.
+    /// * `Err(StruqtureError::GenericError)` - Two different modes are mapped onto the same mode.
+    fn relabel(
+        &self,
+        mapping: &HashMap<usize, usize>,
+    ) -> Result<(Self, CalculatorComplex), StruqtureError> {
+        labeling::check_relabeling(self.creators().chain(self.annihilators()).copied(), mapping)?;
+        Self::create_valid_pair(
+            self.creators()
+                .map(|mode| labeling::relabeled_index(mapping, *mode))
+                .collect::<Vec<usize>>(),
+            self.annihilators()
+                .map(|mode| labeling::relabeled_index(mapping, *mode))
+                .collect::<Vec<usize>>(),
+            CalculatorComplex::from(1.0),
+        )
+    }
 }
 
 /// Trait for transforming value stored at index I when using index of different type T to read out value
@@ -655,6 +870,390 @@ impl ConjugationTrait for CalculatorFloat {
     }
 }
 
+/// Helper trait to check whether values contain symbolic parts.
+/// Should eventually be ported to qoqo_calculator like this
+/// and be implemented for CalculatorFloat, CaclulatorComplex, f64 and Complexf64
+pub trait SymbolicTrait {
+    /// Returns true if any part of the value is symbolic.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the value contains a symbolic part.
+    fn is_symbolic(&self) -> bool;
+}
+
+impl SymbolicTrait for CalculatorComplex {
+    fn is_symbolic(&self) -> bool {
+        self.re.is_symbolic() || self.im.is_symbolic()
+    }
+}
+
+impl SymbolicTrait for CalculatorFloat {
+    fn is_symbolic(&self) -> bool {
+        matches!(self, CalculatorFloat::Str(_))
+    }
+}
+
+/// Helper trait to compare values within a tolerance.
+pub trait ApproxEqTrait {
+    /// Returns whether Self and other are equal within the given tolerances.
+    ///
+    /// Numerical values are equal when `|self - other| <= max(rtol * max(|self|, |other|), atol)`.
+    /// Symbolic values need to match exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to compare to.
+    /// * `atol` - The absolute tolerance.
+    /// * `rtol` - The relative tolerance.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the values are approximately equal.
+    fn approx_eq(&self, other: &Self, atol: f64, rtol: f64) -> bool;
+}
+
+impl ApproxEqTrait for CalculatorComplex {
+    fn approx_eq(&self, other: &Self, atol: f64, rtol: f64) -> bool {
+        match (&self.re, &self.im, &other.re, &other.im) {
+            (
+                CalculatorFloat::Float(re),
+                CalculatorFloat::Float(im),
+                CalculatorFloat::Float(other_re),
+                CalculatorFloat::Float(other_im),
+            ) => {
+                let left = Complex64::new(*re, *im);
+                let right = Complex64::new(*other_re, *other_im);
+                (left - right).norm() <= (rtol * left.norm().max(right.norm())).max(atol)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl ApproxEqTrait for CalculatorFloat {
+    fn approx_eq(&self, other: &Self, atol: f64, rtol: f64) -> bool {
+        match (self, other) {
+            (CalculatorFloat::Float(left), CalculatorFloat::Float(right)) => {
+                (left - right).abs() <= (rtol * left.abs().max(right.abs())).max(atol)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+/// Helper trait to compute the absolute value of coefficients.
+pub trait AbsoluteValueTrait {
+    /// Returns the absolute value of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The absolute value of Self.
+    /// * `Err(StruqtureError::GenericError)` - Self is symbolic.
+    fn absolute_value(&self) -> Result<f64, StruqtureError>;
+}
+
+impl AbsoluteValueTrait for CalculatorComplex {
+    fn absolute_value(&self) -> Result<f64, StruqtureError> {
+        match (&self.re, &self.im) {
+            (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => {
+                Ok(Complex64::new(*re, *im).norm())
+            }
+            _ => Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot compute the absolute value of symbolic coefficient {}",
+                    self
+                ),
+            }),
+        }
+    }
+}
+
+impl AbsoluteValueTrait for CalculatorFloat {
+    fn absolute_value(&self) -> Result<f64, StruqtureError> {
+        match self {
+            CalculatorFloat::Float(x) => Ok(x.abs()),
+            CalculatorFloat::Str(_) => Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot compute the absolute value of symbolic coefficient {}",
+                    self
+                ),
+            }),
+        }
+    }
+}
+
+/// Order of the terms returned by [OperateOnDensityMatrix::sorted_terms].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermOrdering {
+    /// Ascending order of the indices.
+    Index,
+    /// Descending absolute value of the coefficients, terms with equal absolute values in ascending order of the indices.
+    Magnitude,
+}
+
+/// Helper trait to differentiate coefficients with respect to symbolic parameters.
+pub trait DerivativeTrait: Sized {
+    /// Returns the derivative of Self with respect to a symbolic parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The name of the symbolic parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The derivative of Self.
+    /// * `Err(StruqtureError::GenericError)` - A symbolic expression cannot be differentiated.
+    fn derivative(&self, parameter: &str) -> Result<Self, StruqtureError>;
+}
+
+impl DerivativeTrait for CalculatorComplex {
+    fn derivative(&self, parameter: &str) -> Result<Self, StruqtureError> {
+        Ok(CalculatorComplex::new(
+            symbolic_derivative::differentiate(&self.re, parameter)?,
+            symbolic_derivative::differentiate(&self.im, parameter)?,
+        ))
+    }
+}
+
+impl DerivativeTrait for CalculatorFloat {
+    fn derivative(&self, parameter: &str) -> Result<Self, StruqtureError> {
+        symbolic_derivative::differentiate(self, parameter)
+    }
+}
+
+/// Returns the keys (A, B) of a Lindblad noise operator whose coefficient is not the complex conjugate
+/// of the coefficient of the key (B, A), in the order of the entries.
+///
+/// # Arguments
+///
+/// * `entries` - The entries of the noise operator.
+///
+/// # Returns
+///
+/// * `Vec<(P, P)>` - The keys violating the self-adjoint structure.
+pub(crate) fn noise_adjoint_violations<'b, P>(
+    entries: impl Iterator<Item = (&'b (P, P), &'b CalculatorComplex)>,
+) -> Vec<(P, P)>
+where
+    P: Clone + Eq + std::hash::Hash + 'b,
+{
+    let entries: Vec<(&(P, P), &CalculatorComplex)> = entries.collect();
+    let map: HashMap<&(P, P), &CalculatorComplex> = entries.iter().cloned().collect();
+    let zero = CalculatorComplex::ZERO;
+    let mut violations: Vec<(P, P)> = Vec::new();
+    for ((left, right), value) in entries.iter() {
+        let transposed = (right.clone(), left.clone());
+        let partner = map.get(&transposed).copied().unwrap_or(&zero);
+        if **value != partner.conj() {
+            violations.push((left.clone(), right.clone()));
+        }
+    }
+    violations
+}
+
+/// Returns the entries that need to be set to make a Lindblad noise operator self-adjoint.
+///
+/// The coefficient of the key (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2 for all keys violating
+/// the self-adjoint structure, and the transposed key (B, A) is added if it is missing.
+///
+/// # Arguments
+///
+/// * `entries` - The entries of the noise operator.
+///
+/// # Returns
+///
+/// * `Vec<((P, P), CalculatorComplex)>` - The keys and new coefficients to set.
+pub(crate) fn noise_symmetrized_entries<'b, P>(
+    entries: impl Iterator<Item = (&'b (P, P), &'b CalculatorComplex)>,
+) -> Vec<((P, P), CalculatorComplex)>
+where
+    P: Clone + Eq + std::hash::Hash + 'b,
+{
+    let entries: Vec<(&(P, P), &CalculatorComplex)> = entries.collect();
+    let map: HashMap<&(P, P), &CalculatorComplex> = entries.iter().cloned().collect();
+    let zero = CalculatorComplex::ZERO;
+    let mut changes: Vec<((P, P), CalculatorComplex)> = Vec::new();
+    for (left, right) in noise_adjoint_violations(entries.iter().cloned()) {
+        let transposed = (right.clone(), left.clone());
+        let value = map
+            .get(&(left.clone(), right.clone()))
+            .copied()
+            .unwrap_or(&zero);
+        let partner = map.get(&transposed).copied();
+        let new_value = (value.clone() + partner.unwrap_or(&zero).conj()) * 0.5;
+        if partner.is_none() {
+            changes.push((transposed, new_value.conj()));
+        }
+        changes.push(((left, right), new_value));
+    }
+    changes
+}
+
+/// Checks whether the Kossakowski matrix of a Lindblad noise operator is positive semidefinite.
+///
+/// The matrix is built in the basis of all operator products appearing in the keys. Since the
+/// products are linearly independent, the noise is completely positive if and only if this matrix
+/// is self-adjoint and has no negative eigenvalues.
+///
+/// # Arguments
+///
+/// * `entries` - The entries of the noise operator.
+/// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues.
+///
+/// # Returns
+///
+/// * `Ok(bool)` - Whether the Kossakowski matrix is positive semidefinite within the tolerance.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+pub(crate) fn noise_is_completely_positive<'b, P>(
+    entries: impl Iterator<Item = (&'b (P, P), &'b CalculatorComplex)>,
+    tolerance: f64,
+) -> Result<bool, StruqtureError>
+where
+    P: Eq + std::hash::Hash + 'b,
+{
+    let mut basis: HashMap<&P, usize> = HashMap::new();
+    let mut values: Vec<(usize, usize, Complex64)> = Vec::new();
+    for ((left, right), value) in entries {
+        let next = basis.len();
+        let row = *basis.entry(left).or_insert(next);
+        let next = basis.len();
+        let column = *basis.entry(right).or_insert(next);
+        values.push((
+            row,
+            column,
+            Complex64::new(*value.re.float()?, *value.im.float()?),
+        ));
+    }
+    let mut matrix: Array2<Complex64> = Array2::zeros((basis.len(), basis.len()));
+    for (row, column, value) in values {
+        matrix[[row, column]] = value;
+    }
+    let is_self_adjoint = matrix
+        .indexed_iter()
+        .all(|((row, column), value)| (value - matrix[[column, row]].conj()).norm() <= tolerance);
+    if !is_self_adjoint {
+        return Ok(false);
+    }
+    let (eigenvalues, _) = linalg::hermitian_eigh(&matrix);
+    Ok(eigenvalues
+        .iter()
+        .all(|eigenvalue| *eigenvalue >= -tolerance))
+}
+
+/// Raises an operator to a non-negative integer power by repeated squaring.
+///
+/// # Arguments
+///
+/// * `operator` - The operator to raise to the power.
+/// * `exponent` - The power.
+/// * `identity` - The identity operator, returned for a vanishing exponent.
+/// * `multiply` - Multiplies two operators and removes the terms that cancelled in the product.
+///
+/// # Returns
+///
+/// * `Ok(T)` - The operator raised to the power.
+/// * `Err(StruqtureError)` - The multiplication failed.
+pub(crate) fn operator_powi<T: Clone>(
+    operator: &T,
+    exponent: usize,
+    identity: T,
+    multiply: impl Fn(T, T) -> Result<T, StruqtureError>,
+) -> Result<T, StruqtureError> {
+    let mut result = identity;
+    let mut base = operator.clone();
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = multiply(result, base.clone())?;
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = multiply(base.clone(), base)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Serialization of struqture objects in compact binary formats that can be read outside of Rust.
+///
+/// bincode is compact but has no implementations outside of Rust. MessagePack (feature `msgpack`)
+/// and CBOR (feature `cbor`) are self-describing binary formats with implementations in most languages.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "msgpack")]
+/// # {
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let mut operator = SpinOperator::new();
+/// operator.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.5)).unwrap();
+///
+/// let serialized = operator.to_msgpack().unwrap();
+/// assert_eq!(SpinOperator::from_msgpack(&serialized).unwrap(), operator);
+/// # }
+/// ```
+pub trait SerializeBinary: serde::Serialize + serde::de::DeserializeOwned {
+    /// Serializes Self to MessagePack.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The MessagePack representation of Self.
+    /// * `Err(StruqtureError::SerializationError)` - Self could not be serialized.
+    #[cfg(feature = "msgpack")]
+    fn to_msgpack(&self) -> Result<Vec<u8>, StruqtureError> {
+        io::serialize(self, io::SerializationFormat::MessagePack)
+    }
+
+    /// Deserializes Self from MessagePack.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The MessagePack representation of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The deserialized object.
+    /// * `Err(StruqtureError::SerializationError)` - The input could not be deserialized.
+    #[cfg(feature = "msgpack")]
+    fn from_msgpack(input: &[u8]) -> Result<Self, StruqtureError> {
+        io::deserialize(input, io::SerializationFormat::MessagePack)
+    }
+
+    /// Serializes Self to CBOR.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The CBOR representation of Self.
+    /// * `Err(StruqtureError::SerializationError)` - Self could not be serialized.
+    #[cfg(feature = "cbor")]
+    fn to_cbor(&self) -> Result<Vec<u8>, StruqtureError> {
+        io::serialize(self, io::SerializationFormat::Cbor)
+    }
+
+    /// Deserializes Self from CBOR.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The CBOR representation of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The deserialized object.
+    /// * `Err(StruqtureError::SerializationError)` - The input could not be deserialized.
+    #[cfg(feature = "cbor")]
+    fn from_cbor(input: &[u8]) -> Result<Self, StruqtureError> {
+        io::deserialize(input, io::SerializationFormat::Cbor)
+    }
+}
+
+impl<T: serde::Serialize + serde::de::DeserializeOwned + MinSupportedVersion> SerializeBinary
+    for T
+{
+}
+
 /// Trait for all objects that can act on a quantum density matrix like a superoperator.
 ///
 /// # Example
@@ -789,6 +1388,47 @@ where
     /// * `Self` - An empty clone with the same properties as Self, with the given capacity.
     fn empty_clone(&self, capacity: Option<usize>) -> Self;
 
+    /// Returns the ZeroTermPolicy of Self.
+    ///
+    /// The policy decides which terms are dropped by `set`, `add_operator_product` and the arithmetic of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `ZeroTermPolicy` - The policy of Self, [ZeroTermPolicy::DropExactZeros] unless set otherwise.
+    fn zero_term_policy(&self) -> ZeroTermPolicy;
+
+    /// Sets the ZeroTermPolicy of Self.
+    ///
+    /// Terms already in Self that are dropped under the new policy are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The ZeroTermPolicy deciding which terms are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The policy was set.
+    /// * `Err(StruqtureError::GenericError)` - The threshold of the policy is negative or NaN.
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError>;
+
+    /// Sets the threshold up to which terms are dropped from Self.
+    ///
+    /// Terms whose coefficient has an absolute value smaller than or equal to `epsilon` are dropped
+    /// by `set`, `add_operator_product` and the arithmetic of Self, and are removed from Self.
+    /// By default only exact zeros are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - The threshold up to which terms are dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The threshold was set.
+    /// * `Err(StruqtureError::GenericError)` - The threshold is negative or NaN.
+    fn set_zero_threshold(&mut self, epsilon: f64) -> Result<(), StruqtureError> {
+        self.set_zero_term_policy(ZeroTermPolicy::DropBelowThreshold(epsilon))
+    }
+
     // Document locally
     fn set(
         &mut self,
@@ -807,27 +1447,866 @@ where
         Ok(())
     }
 
-    /// Truncates Self by returning a copy without entries under a threshold.
+    /// Overwrites an existing entry or sets a new entry in Self, removing the term if the value is dropped under a policy.
     ///
-    /// Entries with an absolute value under the threshold are removed from the copy of the object that is returned.
-    /// For entries with complex coefficients the threshold is applied to real and imaginary part separately.
-    /// All symbolic values are considered to be above the threshold.
+    /// `set` drops values under the stored [OperateOnDensityMatrix::zero_term_policy] of Self,
+    /// this function additionally drops the values dropped under the given policy.
     ///
     /// # Arguments
     ///
-    /// * `threshold` - The threshold for inclusion.
+    /// * `key` - The key to set in Self.
+    /// * `value` - The corresponding value to set for the key in Self.
+    /// * `policy` - The ZeroTermPolicy deciding whether the term is dropped.
     ///
     /// # Returns
     ///
-    /// * `Self` - The truncated version of Self.
-    fn truncate(&'a self, threshold: f64) -> Self {
-        let mut new_self = self.empty_clone(Some(self.len()));
-        new_self.extend(self.iter().filter_map(|(k, v)| {
-            v.truncate(threshold)
+    /// * `Ok(Some(Self::Value))` - The key existed, this is the value it had before it was set or removed.
+    /// * `Ok(None)` - The key did not exist.
+    /// * `Err(StruqtureError::GenericError)` - The threshold of the policy is negative or NaN.
+    /// * `Err(StruqtureError)` - The value could not be set in Self.
+    fn set_with_zero_term_policy(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+        policy: ZeroTermPolicy,
+    ) -> Result<Option<Self::Value>, StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        policy.validate()?;
+        if policy.drops(&value) {
+            Ok(self.remove(&key))
+        } else {
+            self.set(key, value)
+        }
+    }
+
+    /// Adds a new (key, value) pair to the existing entries, removing the term if the sum is dropped under a policy.
+    ///
+    /// The given policy applies in addition to the stored [OperateOnDensityMatrix::zero_term_policy] of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to add to Self.
+    /// * `value` - The corresponding value to add for the key in Self.
+    /// * `policy` - The ZeroTermPolicy deciding whether the term is dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The (key, value) pair was successfully added.
+    /// * `Err(StruqtureError::GenericError)` - The threshold of the policy is negative or NaN.
+    /// * `Err(StruqtureError)` - The value could not be set in Self.
+    fn add_operator_product_with_zero_term_policy(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+        policy: ZeroTermPolicy,
+    ) -> Result<(), StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        let old = self.get(&key).clone();
+        self.set_with_zero_term_policy(key, value + old, policy)?;
+        Ok(())
+    }
+
+    /// Adds another object to a copy of Self, merging coefficients of shared keys according to a policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The object to add to Self.
+    /// * `policy` - The MergePolicy deciding how coefficients of keys present in both objects are merged.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The merged object.
+    /// * `Err(StruqtureError::GenericError)` - Conflicting symbolic coefficients with `MergePolicy::ErrorOnSymbolConflict`.
+    /// * `Err(StruqtureError)` - The entries of other could not be added to Self.
+    fn add_with_policy(
+        &'a self,
+        other: &'a Self,
+        policy: MergePolicy,
+    ) -> Result<Self, StruqtureError>
+    where
+        Self::Index: std::hash::Hash + Eq,
+        Self::Value: SymbolicTrait + PartialEq + std::fmt::Display,
+    {
+        let left_keys: std::collections::HashSet<&Self::Index> = self.keys().collect();
+        let mut new_self = self.clone();
+        for (key, value) in other.iter() {
+            let contained = left_keys.contains(key);
+            match policy {
+                MergePolicy::Sum => (),
+                MergePolicy::PreferLeft => {
+                    if contained {
+                        continue;
+                    }
+                }
+                MergePolicy::ErrorOnSymbolConflict => {
+                    let left_value = self.get(key);
+                    if contained
+                        && (left_value.is_symbolic() || value.is_symbolic())
+                        && left_value != value
+                    {
+                        return Err(StruqtureError::GenericError {
+                            msg: format!(
+                                "Conflicting symbolic coefficients {} and {} for the same key",
+                                left_value, value
+                            ),
+                        });
+                    }
+                }
+            }
+            new_self.add_operator_product(key.clone(), value.clone())?;
+        }
+        Ok(new_self)
+    }
+
+    /// Truncates Self by returning a copy without entries under a threshold.
+    ///
+    /// Entries with an absolute value under the threshold are removed from the copy of the object that is returned.
+    /// For entries with complex coefficients the threshold is applied to real and imaginary part separately.
+    /// All symbolic values are considered to be above the threshold.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The truncated version of Self.
+    fn truncate(&'a self, threshold: f64) -> Self {
+        let mut new_self = self.empty_clone(Some(self.len()));
+        new_self.extend(self.iter().filter_map(|(k, v)| {
+            v.truncate(threshold)
                 .map(|v_truncated| (k.clone(), v_truncated))
         }));
         new_self
     }
+
+    /// Truncates Self like [OperateOnDensityMatrix::truncate] and returns the discarded remainder as well.
+    ///
+    /// The remainder contains the removed terms and, for complex coefficients, the removed real or
+    /// imaginary parts of kept terms, so that the sum of the kept and the discarded parts is Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `(Self, Self)` - The kept and the discarded terms.
+    fn truncate_with_remainder(&'a self, threshold: f64) -> (Self, Self)
+    where
+        Self::Value: PartialEq,
+    {
+        let mut kept_terms: Vec<(Self::Index, Self::Value)> = Vec::with_capacity(self.len());
+        let mut discarded_terms: Vec<(Self::Index, Self::Value)> = Vec::new();
+        for (key, value) in self.iter() {
+            match value.truncate(threshold) {
+                Some(truncated) => {
+                    if &truncated != value {
+                        discarded_terms
+                            .push((key.clone(), value.clone() + truncated.clone() * -1.0));
+                    }
+                    kept_terms.push((key.clone(), truncated));
+                }
+                None => discarded_terms.push((key.clone(), value.clone())),
+            }
+        }
+        let mut kept = self.empty_clone(Some(kept_terms.len()));
+        kept.extend(kept_terms);
+        let mut discarded = self.empty_clone(Some(discarded_terms.len()));
+        discarded.extend(discarded_terms);
+        (kept, discarded)
+    }
+
+    /// Returns whether Self and other are equal within the given tolerances.
+    ///
+    /// Keys missing in one of the objects are treated as having a zero coefficient, and
+    /// coefficients are compared with [ApproxEqTrait::approx_eq]. All other properties of the objects,
+    /// such as the number of spins of a system, need to match exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The object to compare to.
+    /// * `atol` - The absolute tolerance.
+    /// * `rtol` - The relative tolerance.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the objects are approximately equal.
+    fn approx_eq(&'a self, other: &'a Self, atol: f64, rtol: f64) -> bool
+    where
+        Self::Value: ApproxEqTrait,
+    {
+        self.empty_clone(None) == other.empty_clone(None)
+            && self
+                .iter()
+                .all(|(key, value)| value.approx_eq(other.get(key), atol, rtol))
+            && other
+                .iter()
+                .all(|(key, value)| self.get(key).approx_eq(value, atol, rtol))
+    }
+
+    /// Truncates Self by keeping the smallest set of largest terms reaching a fraction of the total weight.
+    ///
+    /// The terms are sorted by the absolute value of their coefficients and added to the kept part
+    /// until the 1-norm of its coefficients reaches the given fraction of the 1-norm of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, Self))` - The kept and the dropped terms.
+    /// * `Err(StruqtureError::GenericError)` - The fraction is not between 0.0 and 1.0.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn truncate_to_weight(&'a self, fraction: f64) -> Result<(Self, Self), StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Fraction {} is not between 0.0 and 1.0", fraction),
+            });
+        }
+        let mut weighted_entries = self
+            .iter()
+            .map(|(key, value)| Ok((key, value, value.absolute_value()?)))
+            .collect::<Result<Vec<(&Self::Index, &Self::Value, f64)>, StruqtureError>>()?;
+        weighted_entries.sort_by(|(_, _, left), (_, _, right)| right.total_cmp(left));
+        let target = fraction
+            * weighted_entries
+                .iter()
+                .map(|(_, _, weight)| weight)
+                .sum::<f64>();
+
+        let mut kept = self.empty_clone(None);
+        let mut dropped = self.empty_clone(None);
+        let mut cumulative_weight = 0.0;
+        for (key, value, weight) in weighted_entries {
+            if cumulative_weight < target {
+                cumulative_weight += weight;
+                kept.set(key.clone(), value.clone())?;
+            } else {
+                dropped.set(key.clone(), value.clone())?;
+            }
+        }
+        Ok((kept, dropped))
+    }
+
+    /// Returns the 1-norm of the coefficients of Self, the sum of their absolute values.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The sum of the absolute values of the coefficients.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn coefficient_1_norm(&'a self) -> Result<f64, StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        self.values()
+            .try_fold(0.0, |sum, value| Ok(sum + value.absolute_value()?))
+    }
+
+    /// Returns the max-norm (infinity-norm) of the coefficients of Self, the largest absolute value.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The largest absolute value of the coefficients, 0.0 for an empty object.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn coefficient_max_norm(&'a self) -> Result<f64, StruqtureError>
+    where
+        Self::Value: AbsoluteValueTrait,
+    {
+        self.values()
+            .try_fold(0.0, |max: f64, value| Ok(max.max(value.absolute_value()?)))
+    }
+
+    /// Returns the terms of Self in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `ordering` - The order of the terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(&Self::Index, &Self::Value)>)` - The sorted terms.
+    /// * `Err(StruqtureError::GenericError)` - The terms are sorted by magnitude and Self contains symbolic coefficients.
+    fn sorted_terms(
+        &'a self,
+        ordering: TermOrdering,
+    ) -> Result<Vec<(&'a Self::Index, &'a Self::Value)>, StruqtureError>
+    where
+        Self::Index: Ord,
+        Self::Value: AbsoluteValueTrait,
+    {
+        match ordering {
+            TermOrdering::Index => {
+                let mut terms: Vec<(&Self::Index, &Self::Value)> = self.iter().collect();
+                terms.sort_by(|(left, _), (right, _)| left.cmp(right));
+                Ok(terms)
+            }
+            TermOrdering::Magnitude => {
+                let mut weighted_terms = self
+                    .iter()
+                    .map(|(key, value)| Ok((value.absolute_value()?, key, value)))
+                    .collect::<Result<Vec<(f64, &Self::Index, &Self::Value)>, StruqtureError>>()?;
+                weighted_terms.sort_by(|(left_weight, left, _), (right_weight, right, _)| {
+                    right_weight
+                        .total_cmp(left_weight)
+                        .then_with(|| left.cmp(right))
+                });
+                Ok(weighted_terms
+                    .into_iter()
+                    .map(|(_, key, value)| (key, value))
+                    .collect())
+            }
+        }
+    }
+
+    /// Returns the terms of Self with the largest absolute values of their coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of terms to return, all terms are returned if Self has fewer terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(&Self::Index, &Self::Value)>)` - The k largest terms, largest first.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn largest_terms(
+        &'a self,
+        k: usize,
+    ) -> Result<Vec<(&'a Self::Index, &'a Self::Value)>, StruqtureError>
+    where
+        Self::Index: Ord,
+        Self::Value: AbsoluteValueTrait,
+    {
+        let mut terms = self.sorted_terms(TermOrdering::Magnitude)?;
+        terms.truncate(k);
+        Ok(terms)
+    }
+
+    /// Returns a copy of Self with every coefficient replaced by the result of a function.
+    ///
+    /// Terms mapped to a coefficient of zero are removed,
+    /// which allows to apply cutoffs while mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function computing the new coefficient from the key and the old coefficient.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The copy of Self with the mapped coefficients.
+    /// * `Err(StruqtureError)` - A mapped coefficient cannot be set, e.g. a non-real coefficient of a naturally hermitian term in a Hamiltonian.
+    fn map_values(
+        &'a self,
+        mut function: impl FnMut(&Self::Index, &Self::Value) -> Self::Value,
+    ) -> Result<Self, StruqtureError> {
+        let mut new_self = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            new_self.set(key.clone(), function(key, value))?;
+        }
+        Ok(new_self)
+    }
+
+    /// Returns a copy of Self containing only the terms for which the predicate is true.
+    ///
+    /// All other properties of Self, such as the number of spins of a system, are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function deciding from the key and the value whether a term is kept.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The filtered copy of Self.
+    fn filter_terms(
+        &'a self,
+        mut predicate: impl FnMut(&Self::Index, &Self::Value) -> bool,
+    ) -> Self {
+        let mut new_self = self.empty_clone(None);
+        new_self.extend(
+            self.iter()
+                .filter(|(key, value)| predicate(key, value))
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        new_self
+    }
+
+    /// Removes all terms for which the predicate is false from Self.
+    ///
+    /// The kept terms are moved and not cloned.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function deciding from the key and the value whether a term is kept.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in set.
+    fn retain(&mut self, mut predicate: impl FnMut(&Self::Index, &Self::Value) -> bool) {
+        let empty = self.empty_clone(None);
+        let old_self = std::mem::replace(self, empty);
+        for (key, value) in old_self.into_iter() {
+            if predicate(&key, &value) {
+                self.set(key, value)
+                    .expect("Internal error in set when retaining terms");
+            }
+        }
+    }
+
+    /// Returns the derivative of Self with respect to a symbolic parameter.
+    ///
+    /// Every coefficient is differentiated symbolically, terms with a vanishing derivative are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The name of the symbolic parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The derivative of Self.
+    /// * `Err(StruqtureError::GenericError)` - A coefficient cannot be differentiated.
+    fn derivative(&'a self, parameter: &str) -> Result<Self, StruqtureError>
+    where
+        Self::Value: DerivativeTrait,
+    {
+        let mut derivative = self.empty_clone(Some(self.len()));
+        for (key, value) in self.iter() {
+            if let Some(value_derivative) = value.derivative(parameter)?.truncate(f64::MIN_POSITIVE)
+            {
+                derivative.set(key.clone(), value_derivative)?;
+            }
+        }
+        Ok(derivative)
+    }
+}
+
+/// Trait for a cheap upper bound of the spectral norm of operators.
+///
+/// The bound follows from the triangle inequality, as every product of Pauli matrices,
+/// spin ladder operators or fermionic creators and annihilators has a spectral norm of at most one.
+/// It is not implemented for bosonic operators, as bosonic ladder operators are unbounded.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonian};
+///
+/// let mut sh = SpinHamiltonian::new();
+/// sh.add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(-1.0)).unwrap();
+/// sh.add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.5)).unwrap();
+///
+/// assert_eq!(sh.coefficient_1_norm().unwrap(), 1.5);
+/// assert_eq!(sh.coefficient_max_norm().unwrap(), 1.0);
+/// assert_eq!(sh.spectral_norm_bound().unwrap(), 1.5);
+/// ```
+pub trait SpectralNormBound {
+    /// Returns an upper bound of the spectral norm of Self.
+    ///
+    /// The bound is the sum of the absolute values of the coefficients. Terms of hermitian operators that are
+    /// not naturally hermitian represent the term and its hermitian conjugate and are counted twice.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError>;
+}
+
+/// Returns the sum of the absolute values of the coefficients of a hermitian operator.
+///
+/// Terms that are not naturally hermitian are counted twice, as they also represent their hermitian conjugate.
+pub(crate) fn hermitian_coefficient_1_norm<'b, I, V>(
+    entries: impl Iterator<Item = (&'b I, &'b V)>,
+) -> Result<f64, StruqtureError>
+where
+    I: SymmetricIndex + 'b,
+    V: AbsoluteValueTrait + 'b,
+{
+    entries.try_fold(0.0, |sum, (key, value)| {
+        let multiplicity = if key.is_natural_hermitian() { 1.0 } else { 2.0 };
+        Ok(sum + multiplicity * value.absolute_value()?)
+    })
+}
+
+/// Returns the error listing the terms of an operator A that violate hermiticity.
+///
+/// # Arguments
+///
+/// * `anti_hermitian_part` - The terms of the anti-hermitian part (A - A^dagger) / 2 of the operator.
+///
+/// # Returns
+///
+/// * `StruqtureError::NonHermitianTerms` - The keys and residuals A - A^dagger, sorted by key.
+pub(crate) fn non_hermitian_terms_error<'b, K>(
+    anti_hermitian_part: impl Iterator<Item = (&'b K, &'b CalculatorComplex)>,
+) -> StruqtureError
+where
+    K: std::fmt::Display + 'b,
+{
+    let mut terms: Vec<(String, String)> = anti_hermitian_part
+        .filter(|(_, value)| **value != CalculatorComplex::ZERO)
+        .map(|(key, value)| (key.to_string(), (value.clone() * 2.0).to_string()))
+        .collect();
+    terms.sort();
+    StruqtureError::NonHermitianTerms { terms }
+}
+
+/// Splits a coefficient into its numerical value and its symbolic factor.
+///
+/// Numerical coefficients have no symbolic factor, symbolic coefficients are kept as a whole.
+fn split_symbolic_factor(value: &CalculatorComplex) -> (Complex64, Option<String>) {
+    match (&value.re, &value.im) {
+        (CalculatorFloat::Float(re), CalculatorFloat::Float(im)) => {
+            (Complex64::new(*re, *im), None)
+        }
+        _ => (Complex64::new(1.0, 0.0), Some(value.to_string())),
+    }
+}
+
+/// Returns the terms of the commutator [A, B] = AB - BA of two operators that do not cancel.
+///
+/// The commutator is computed term by term. Contributions with the same key and the same symbolic
+/// factors are summed numerically, so that symbolic terms only vanish when they cancel structurally.
+/// A sum is treated as vanishing when it is zero up to the floating point precision of its contributions.
+///
+/// # Arguments
+///
+/// * `left` - The terms of the operator A.
+/// * `right` - The terms of the operator B.
+/// * `multiply` - Multiplies two keys, returning the resulting keys with their numerical prefactors.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(K, Complex64)>)` - The numerical terms of the commutator that do not cancel.
+/// * `Err(StruqtureError::GenericError)` - Symbolic terms of the commutator do not cancel.
+pub(crate) fn commutator_residual_terms<'b, K>(
+    left: impl Iterator<Item = (&'b K, &'b CalculatorComplex)>,
+    right: impl Iterator<Item = (&'b K, &'b CalculatorComplex)>,
+    multiply: impl Fn(&K, &K) -> Vec<(K, Complex64)>,
+) -> Result<Vec<(K, Complex64)>, StruqtureError>
+where
+    K: std::hash::Hash + Eq + Clone + std::fmt::Display + 'b,
+{
+    let right: Vec<(&K, &CalculatorComplex)> = right.collect();
+    // Sum of the contributions and sum of their absolute values for every key and symbolic factors
+    let mut sums: HashMap<(K, Vec<String>), (Complex64, f64)> = HashMap::new();
+    for (left_key, left_value) in left {
+        let (left_number, left_symbol) = split_symbolic_factor(left_value);
+        for (right_key, right_value) in right.iter() {
+            let (right_number, right_symbol) = split_symbolic_factor(right_value);
+            let mut symbols: Vec<String> = left_symbol
+                .iter()
+                .chain(right_symbol.iter())
+                .cloned()
+                .collect();
+            symbols.sort();
+            let factor = left_number * right_number;
+            let forward = multiply(left_key, right_key).into_iter();
+            let backward = multiply(right_key, left_key)
+                .into_iter()
+                .map(|(key, prefactor)| (key, -prefactor));
+            for (key, prefactor) in forward.chain(backward) {
+                let contribution = factor * prefactor;
+                let sum = sums
+                    .entry((key, symbols.clone()))
+                    .or_insert((Complex64::new(0.0, 0.0), 0.0));
+                sum.0 += contribution;
+                sum.1 += contribution.norm();
+            }
+        }
+    }
+    let mut residual: Vec<(K, Complex64)> = Vec::new();
+    for ((key, symbols), (sum, magnitude)) in sums {
+        if sum.norm() <= 4.0 * f64::EPSILON * magnitude {
+            continue;
+        }
+        if !symbols.is_empty() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Symbolic term {} with coefficient ({}) * {} of the commutator does not cancel",
+                    key,
+                    symbols.join(") * ("),
+                    sum
+                ),
+            });
+        }
+        residual.push((key, sum));
+    }
+    Ok(residual)
+}
+
+/// Relative size below which the sum of the contributions to a term of a Fourier transform is treated as vanishing.
+const FOURIER_CANCELLATION_TOLERANCE: f64 = 1e-12;
+
+/// Returns the terms of a mode operator on a one-dimensional periodic lattice transformed to momentum space.
+///
+/// Mode `m` is the orbital `m / lattice_size` on the site `r = m % lattice_size`. It is transformed with
+/// c_r = 1/sqrt(L) Σ_n exp(2πi n r / L) c_n and c^†_r = 1/sqrt(L) Σ_n exp(-2πi n r / L) c^†_n,
+/// where the momentum mode `n + lattice_size * (m / lattice_size)` has the momentum k = 2π n / L.
+/// Contributions with the same key and the same symbolic coefficient are summed numerically and dropped
+/// when they cancel up to the floating point precision of the contributions.
+/// Products with a repeated fermionic mode vanish and are skipped.
+///
+/// # Arguments
+///
+/// * `terms` - The terms of the operator in real space.
+/// * `lattice_size` - The number of sites L of the lattice.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(I, CalculatorComplex)>)` - The terms of the operator in momentum space.
+/// * `Err(StruqtureError::GenericError)` - The lattice size is zero or a term has too many contributions.
+pub(crate) fn fourier_transform_terms<'b, I>(
+    terms: impl Iterator<Item = (&'b I, &'b CalculatorComplex)>,
+    lattice_size: usize,
+) -> Result<Vec<(I, CalculatorComplex)>, StruqtureError>
+where
+    I: ModeIndex + 'b,
+{
+    if lattice_size == 0 {
+        return Err(StruqtureError::GenericError {
+            msg: "The lattice of a Fourier transform must have at least one site".to_string(),
+        });
+    }
+    // Rounding errors of the cosine and sine at multiples of π/2 are set to zero so that e.g. exp(iπ) = -1 exactly
+    let exact = |x: f64| if x.abs() <= f64::EPSILON { 0.0 } else { x };
+    let phases: Vec<Complex64> = (0..lattice_size)
+        .map(|m| {
+            let (sin, cos) =
+                (2.0 * std::f64::consts::PI * m as f64 / lattice_size as f64).sin_cos();
+            Complex64::new(exact(cos), exact(sin))
+        })
+        .collect();
+    // Sum of the contributions and sum of their absolute values for every key and symbolic coefficient
+    let mut sums: HashMap<(I, Option<String>), (Complex64, f64)> = HashMap::new();
+    let mut symbolic_values: HashMap<String, CalculatorComplex> = HashMap::new();
+    for (index, value) in terms {
+        let (number, symbol) = split_symbolic_factor(value);
+        if let Some(symbol) = symbol.as_ref() {
+            symbolic_values.insert(symbol.clone(), value.clone());
+        }
+        let creators: Vec<usize> = index.creators().copied().collect();
+        let annihilators: Vec<usize> = index.annihilators().copied().collect();
+        let number_operators = creators.len() + annihilators.len();
+        let number_contributions = u32::try_from(number_operators)
+            .ok()
+            .and_then(|exponent| lattice_size.checked_pow(exponent))
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "The Fourier transform of term {} has too many contributions",
+                    index
+                ),
+            })?;
+        let prefactor = number * (1.0 / (lattice_size as f64).sqrt()).powi(number_operators as i32);
+        let mut momenta: Vec<usize> = vec![0; number_operators];
+        for _ in 0..number_contributions {
+            let mut exponent = 0;
+            for (mode, momentum) in creators.iter().zip(momenta.iter()) {
+                exponent += lattice_size - (momentum * (mode % lattice_size)) % lattice_size;
+            }
+            for (mode, momentum) in annihilators.iter().zip(momenta[creators.len()..].iter()) {
+                exponent += momentum * (mode % lattice_size);
+            }
+            let momentum_mode =
+                |mode: &usize, momentum: &usize| momentum + lattice_size * (mode / lattice_size);
+            let transformed = I::create_valid_pair(
+                creators
+                    .iter()
+                    .zip(momenta.iter())
+                    .map(|(mode, momentum)| momentum_mode(mode, momentum)),
+                annihilators
+                    .iter()
+                    .zip(momenta[creators.len()..].iter())
+                    .map(|(mode, momentum)| momentum_mode(mode, momentum)),
+                CalculatorComplex::from(1.0),
+            );
+            match transformed {
+                Ok((key, sign)) => {
+                    let sign: f64 = sign.re.try_into()?;
+                    let contribution = prefactor * phases[exponent % lattice_size] * sign;
+                    let sum = sums
+                        .entry((key, symbol.clone()))
+                        .or_insert((Complex64::new(0.0, 0.0), 0.0));
+                    sum.0 += contribution;
+                    sum.1 += contribution.norm();
+                }
+                Err(StruqtureError::IndicesContainDoubles) => (),
+                Err(error) => return Err(error),
+            }
+            // Advance to the next combination of momenta
+            for momentum in momenta.iter_mut() {
+                *momentum += 1;
+                if *momentum < lattice_size {
+                    break;
+                }
+                *momentum = 0;
+            }
+        }
+    }
+    Ok(sums
+        .into_iter()
+        .filter(|(_, (sum, magnitude))| sum.norm() > FOURIER_CANCELLATION_TOLERANCE * magnitude)
+        .map(|((key, symbol), (sum, _))| {
+            let value = CalculatorComplex::new(sum.re, sum.im);
+            match symbol {
+                Some(symbol) => (key, symbolic_values[&symbol].clone() * value),
+                None => (key, value),
+            }
+        })
+        .collect())
+}
+
+/// Trait for estimating the size of the matrix representations of systems before computing them.
+///
+/// Every spin and every fermionic mode contributes a factor of two to the dimension of the Hilbert space,
+/// every bosonic mode the cutoff of its Fock space. The memory estimate is an upper bound of the
+/// non-zero entries of the sparse matrix export, i.e. the sparse matrix of operators and Hamiltonians
+/// and the sparse superoperator of noise and open systems, as every term has at most one non-zero entry per row.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinSystem};
+///
+/// let mut system = SpinSystem::new(Some(3));
+/// system.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0)).unwrap();
+///
+/// assert_eq!(system.hilbert_space_dimension(&[]).unwrap(), 8);
+/// assert_eq!(system.superoperator_dimension(&[]).unwrap(), 64);
+/// assert_eq!(system.estimated_matrix_memory(&[], 16).unwrap(), 128);
+/// ```
+pub trait SystemDimension {
+    /// Returns the dimension of the Hilbert space of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of Self, in the order of the bosonic subsystems.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError>;
+
+    /// Returns the dimension of the space of density matrices of Self, the square of the Hilbert space dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of Self, in the order of the bosonic subsystems.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the superoperator.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn superoperator_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        dimension
+            .checked_mul(dimension)
+            .ok_or_else(|| dimension_overflow_error("superoperator dimension"))
+    }
+
+    /// Returns an upper bound of the memory needed for the non-zero entries of the sparse matrix export of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of Self, in the order of the bosonic subsystems.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry, e.g. 16 for Complex64 values or 24 including a usize index.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError>;
+}
+
+/// Returns the dimension of a Hilbert space of two-level systems and bosonic modes.
+///
+/// # Arguments
+///
+/// * `number_two_level_systems` - The number of spins and fermionic modes.
+/// * `number_bosonic_modes` - The number of bosonic modes.
+/// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The dimension of the Hilbert space.
+/// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+/// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+pub(crate) fn hilbert_space_dimension(
+    number_two_level_systems: usize,
+    number_bosonic_modes: usize,
+    fock_cutoffs: &[usize],
+) -> Result<usize, StruqtureError> {
+    if fock_cutoffs.len() != number_bosonic_modes {
+        return Err(StruqtureError::MissmatchedNumberModes);
+    }
+    let two_level_dimension = u32::try_from(number_two_level_systems)
+        .ok()
+        .and_then(|exponent| 2_usize.checked_pow(exponent))
+        .ok_or_else(|| dimension_overflow_error("Hilbert space dimension"))?;
+    fock_cutoffs
+        .iter()
+        .try_fold(two_level_dimension, |dimension, cutoff| {
+            dimension.checked_mul(*cutoff)
+        })
+        .ok_or_else(|| dimension_overflow_error("Hilbert space dimension"))
+}
+
+/// Returns an upper bound of the memory of the non-zero entries of a sparse matrix.
+///
+/// # Arguments
+///
+/// * `dimension` - The dimension of the matrix.
+/// * `entries_per_row` - The upper bound of the non-zero entries per row.
+/// * `bytes_per_entry` - The memory of a single non-zero entry.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - The upper bound of the memory in bytes.
+/// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+pub(crate) fn sparse_matrix_memory(
+    dimension: usize,
+    entries_per_row: usize,
+    bytes_per_entry: usize,
+) -> Result<usize, StruqtureError> {
+    dimension
+        .checked_mul(entries_per_row.min(dimension))
+        .and_then(|entries| entries.checked_mul(bytes_per_entry))
+        .ok_or_else(|| dimension_overflow_error("estimated matrix memory"))
+}
+
+/// Returns the number of terms represented by the keys of a hermitian operator.
+///
+/// Keys that are not naturally hermitian also represent their hermitian conjugate and are counted twice.
+pub(crate) fn hermitian_term_count<'b, I>(keys: impl Iterator<Item = &'b I>) -> usize
+where
+    I: SymmetricIndex + 'b,
+{
+    keys.map(|key| if key.is_natural_hermitian() { 1 } else { 2 })
+        .sum()
+}
+
+/// Returns the error for a size estimate that does not fit into usize.
+fn dimension_overflow_error(quantity: &str) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!("The {} overflows usize", quantity),
+    }
 }
 
 /// Trait for representing complete open systems
@@ -923,6 +2402,134 @@ where
     /// * `&Self::System` - The Self::System of the OpenSystem object.
     fn system_mut(&mut self) -> &mut Self::System;
 
+    /// Returns the value of a key in the Self::System of the OpenSystem object.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the system term.
+    ///
+    /// # Returns
+    ///
+    /// * `&Value` - The value of the key, zero if the key is not in the system.
+    fn system_get(
+        &self,
+        key: &<<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Index,
+    ) -> &<<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value {
+        self.system().get(key)
+    }
+
+    /// Returns the value of a key in the Self::Noise of the OpenSystem object.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the noise term.
+    ///
+    /// # Returns
+    ///
+    /// * `&Value` - The value of the key, zero if the key is not in the noise.
+    fn noise_get(
+        &self,
+        key: &<<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Index,
+    ) -> &<<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value {
+        self.noise().get(key)
+    }
+
+    /// Removes a key from the Self::System of the OpenSystem object.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the system term to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Value)` - Key existed, this is the value it had before it was removed.
+    /// * `None` - Key did not exist.
+    fn system_remove(
+        &mut self,
+        key: &<<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Index,
+    ) -> Option<<<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value> {
+        self.system_mut().remove(key)
+    }
+
+    /// Removes a key from the Self::Noise of the OpenSystem object.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the noise term to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Value)` - Key existed, this is the value it had before it was removed.
+    /// * `None` - Key did not exist.
+    fn noise_remove(
+        &mut self,
+        key: &<<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Index,
+    ) -> Option<<<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value> {
+        self.noise_mut().remove(key)
+    }
+
+    /// Returns the keys of the Self::System of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `KeyIteratorType` - The keys of the system.
+    fn system_keys(
+        &'a self,
+    ) -> <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::KeyIteratorType {
+        self.system().keys()
+    }
+
+    /// Returns the keys of the Self::Noise of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `KeyIteratorType` - The keys of the noise.
+    fn noise_keys(
+        &'a self,
+    ) -> <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::KeyIteratorType {
+        self.noise().keys()
+    }
+
+    /// Returns the values of the Self::System of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `ValueIteratorType` - The values of the system.
+    fn system_values(
+        &'a self,
+    ) -> <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::ValueIteratorType {
+        self.system().values()
+    }
+
+    /// Returns the values of the Self::Noise of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `ValueIteratorType` - The values of the noise.
+    fn noise_values(
+        &'a self,
+    ) -> <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::ValueIteratorType {
+        self.noise().values()
+    }
+
+    /// Returns the number of terms in the Self::System of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the system.
+    fn system_len(&'a self) -> usize {
+        self.system().len()
+    }
+
+    /// Returns the number of terms in the Self::Noise of the OpenSystem object.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms in the noise.
+    fn noise_len(&'a self) -> usize {
+        self.noise().len()
+    }
+
     /// Returns a tuple of the system (Self::System) and the noise (Self::Noise) of the OpenSystem.
     ///
     /// # Returns
@@ -963,6 +2570,103 @@ where
         Self::group(new_system, new_noise)
             .expect("Internal error: System and Noise size unexpectedly do not match")
     }
+
+    /// Truncates Self like [OpenSystem::truncate] and returns the discarded remainder as well.
+    ///
+    /// The system and the noise are truncated separately, see [OperateOnDensityMatrix::truncate_with_remainder].
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The threshold for inclusion.
+    ///
+    /// # Returns
+    ///
+    /// * `(Self, Self)` - The kept and the discarded terms.
+    fn truncate_with_remainder(&'a self, threshold: f64) -> (Self, Self)
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value: PartialEq,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value: PartialEq,
+    {
+        let (kept_system, discarded_system) = self.system().truncate_with_remainder(threshold);
+        let (kept_noise, discarded_noise) = self.noise().truncate_with_remainder(threshold);
+        (
+            Self::group(kept_system, kept_noise)
+                .expect("Internal error: System and Noise size unexpectedly do not match"),
+            Self::group(discarded_system, discarded_noise)
+                .expect("Internal error: System and Noise size unexpectedly do not match"),
+        )
+    }
+
+    /// Truncates the system and the noise of Self by keeping the smallest sets of largest terms reaching a fraction of their total weight.
+    ///
+    /// The system and the noise are truncated separately, see [OperateOnDensityMatrix::truncate_to_weight].
+    ///
+    /// # Arguments
+    ///
+    /// * `fraction` - The fraction of the coefficient 1-norm to keep, between 0.0 and 1.0.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Self, Self))` - The kept and the dropped terms.
+    /// * `Err(StruqtureError::GenericError)` - The fraction is not between 0.0 and 1.0.
+    /// * `Err(StruqtureError::GenericError)` - Self contains symbolic coefficients.
+    fn truncate_to_weight(&'a self, fraction: f64) -> Result<(Self, Self), StruqtureError>
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value: AbsoluteValueTrait,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value: AbsoluteValueTrait,
+    {
+        let (kept_system, dropped_system) = self.system().truncate_to_weight(fraction)?;
+        let (kept_noise, dropped_noise) = self.noise().truncate_to_weight(fraction)?;
+        Ok((
+            Self::group(kept_system, kept_noise)?,
+            Self::group(dropped_system, dropped_noise)?,
+        ))
+    }
+
+    /// Returns whether Self and other are equal within the given tolerances.
+    ///
+    /// The systems and the noises are compared separately, see [OperateOnDensityMatrix::approx_eq].
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The open system to compare to.
+    /// * `atol` - The absolute tolerance.
+    /// * `rtol` - The relative tolerance.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the open systems are approximately equal.
+    fn approx_eq(&'a self, other: &'a Self, atol: f64, rtol: f64) -> bool
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value: ApproxEqTrait,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value: ApproxEqTrait,
+    {
+        self.system().approx_eq(other.system(), atol, rtol)
+            && self.noise().approx_eq(other.noise(), atol, rtol)
+    }
+
+    /// Returns the derivative of Self with respect to a symbolic parameter.
+    ///
+    /// The system and the noise are differentiated separately, see [OperateOnDensityMatrix::derivative].
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The name of the symbolic parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The derivative of Self.
+    /// * `Err(StruqtureError::GenericError)` - A coefficient cannot be differentiated.
+    fn derivative(&'a self, parameter: &str) -> Result<Self, StruqtureError>
+    where
+        <<Self as OpenSystem<'a>>::System as OperateOnDensityMatrix<'a>>::Value: DerivativeTrait,
+        <<Self as OpenSystem<'a>>::Noise as OperateOnDensityMatrix<'a>>::Value: DerivativeTrait,
+    {
+        Self::group(
+            self.system().derivative(parameter)?,
+            self.noise().derivative(parameter)?,
+        )
+    }
 }
 
 /// Trait for all objects that can act on a quantum state like an operator.
@@ -1059,12 +2763,52 @@ pub trait OperateOnModes<'a>: PartialEq + Clone + Mul<CalculatorFloat> + Add + S
 /// Shorthand type notation for a tuple of lists of indices of creators and annihilators
 type CreatorsAnnihilators = (TinyVec<[usize; 2]>, TinyVec<[usize; 2]>);
 
+pub mod basis;
 pub mod bosons;
+mod canonical_serialization;
+mod canonical_text;
+mod coefficient_entry;
 pub mod fermions;
+#[cfg(feature = "io")]
+pub mod hamiltonian_builder;
+#[cfg(feature = "io")]
+pub mod io;
+pub mod labeling;
+pub mod latex;
+mod linalg;
 pub mod mappings;
+mod merge_policy;
+mod metadata;
+#[cfg(feature = "io")]
+pub mod migrate;
 pub mod mixed_systems;
+pub mod models;
+mod operate_on_constant;
+mod operator_expression;
 pub mod prelude;
+pub mod qdrift;
 pub mod spins;
+mod symbolic_derivative;
+pub mod time_dependent;
+pub mod transforms;
+mod zero_term_policy;
+
+pub(crate) use canonical_serialization::Canonical;
+pub use canonical_serialization::SerializeCanonical;
+pub use canonical_text::CanonicalText;
+pub(crate) use canonical_text::{
+    canonical_noise_text_entries, canonical_number, canonical_numbers, canonical_text_document,
+    canonical_text_entries, parse_canonical_count, parse_canonical_noise_text_entries,
+    parse_canonical_number, parse_canonical_numbers, parse_canonical_text_entries,
+    split_canonical_open_system, split_canonical_text,
+};
+pub use coefficient_entry::CoefficientEntry;
+pub use merge_policy::MergePolicy;
+pub use metadata::{MetadataMergePolicy, OperateOnMetadata};
+pub use operate_on_constant::OperateOnConstant;
+pub use operator_expression::OperatorExpression;
+pub(crate) use operator_expression::{expression_from_terms, parse_expression_terms};
+pub use zero_term_policy::ZeroTermPolicy;
 
 /// Shorhand type for TinyVec representation of creators or annihilators
 #[cfg(test)]
@@ -0,0 +1,389 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Small dense linear algebra helpers used by the numerical analysis functions of struqture.
+//!
+//! struqture does not depend on a LAPACK backend, the routines here are intended for the
+//! small matrices of systems with a handful of spins.
+
+use crate::CooSparseMatrix;
+use ndarray::Array2;
+use num_complex::Complex64;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Maximal number of Jacobi sweeps before the diagonalization is stopped.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Maximal number of terms of the Taylor series in the matrix exponential.
+const MAX_TAYLOR_TERMS: usize = 30;
+
+/// Maximal number of square roots taken in the matrix logarithm.
+const MAX_SQUARE_ROOTS: i32 = 60;
+
+/// Maximal number of Denman-Beavers iterations for a matrix square root.
+const MAX_DENMAN_BEAVERS_ITERATIONS: usize = 100;
+
+/// Maximal number of terms of the series in the matrix logarithm.
+const MAX_LOGARITHM_TERMS: usize = 100;
+
+/// Entries of the matrix exponential with an absolute value below this threshold are dropped.
+const SPARSE_EXPONENTIAL_THRESHOLD: f64 = 1e-15;
+
+/// Sparse matrix stored as a map from the column index to the value for every row.
+type SparseRows = Vec<HashMap<usize, Complex64>>;
+
+/// Diagonalizes a Hermitian matrix with the cyclic Jacobi method.
+///
+/// # Arguments
+///
+/// * `matrix` - The Hermitian matrix to diagonalize.
+///
+/// # Returns
+///
+/// * `(Vec<f64>, Array2<Complex64>)` - The eigenvalues in ascending order and the eigenvectors as the corresponding columns of the unitary matrix.
+pub(crate) fn hermitian_eigh(matrix: &Array2<Complex64>) -> (Vec<f64>, Array2<Complex64>) {
+    let dimension = matrix.nrows();
+    let mut a = matrix.clone();
+    let mut vectors: Array2<Complex64> = Array2::eye(dimension);
+    let norm: f64 = a.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal: f64 = (0..dimension)
+            .flat_map(|p| (0..dimension).filter(move |q| *q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[[p, q]].norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        if off_diagonal <= f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..dimension {
+            for q in p + 1..dimension {
+                let (magnitude, phase) = a[[p, q]].to_polar();
+                if magnitude == 0.0 {
+                    continue;
+                }
+                // The phase rotation makes a[p, q] real, the real Jacobi rotation then eliminates it
+                let theta = (a[[q, q]].re - a[[p, p]].re) / (2.0 * magnitude);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                let rotation = Complex64::from_polar(1.0, -phase);
+                let u_pp = Complex64::new(c, 0.0);
+                let u_pq = Complex64::new(s, 0.0);
+                let u_qp = rotation * (-s);
+                let u_qq = rotation * c;
+                for k in 0..dimension {
+                    let (a_kp, a_kq) = (a[[k, p]], a[[k, q]]);
+                    a[[k, p]] = a_kp * u_pp + a_kq * u_qp;
+                    a[[k, q]] = a_kp * u_pq + a_kq * u_qq;
+                    let (v_kp, v_kq) = (vectors[[k, p]], vectors[[k, q]]);
+                    vectors[[k, p]] = v_kp * u_pp + v_kq * u_qp;
+                    vectors[[k, q]] = v_kp * u_pq + v_kq * u_qq;
+                }
+                for k in 0..dimension {
+                    let (a_pk, a_qk) = (a[[p, k]], a[[q, k]]);
+                    a[[p, k]] = u_pp.conj() * a_pk + u_qp.conj() * a_qk;
+                    a[[q, k]] = u_pq.conj() * a_pk + u_qq.conj() * a_qk;
+                }
+                a[[p, q]] = Complex64::new(0.0, 0.0);
+                a[[q, p]] = Complex64::new(0.0, 0.0);
+            }
+        }
+    }
+    let mut order: Vec<usize> = (0..dimension).collect();
+    order.sort_by(|i, j| a[[*i, *i]].re.total_cmp(&a[[*j, *j]].re));
+    let values = order.iter().map(|i| a[[*i, *i]].re).collect();
+    let sorted_vectors = Array2::from_shape_fn((dimension, dimension), |(row, column)| {
+        vectors[[row, order[column]]]
+    });
+    (values, sorted_vectors)
+}
+
+/// Computes the Cholesky decomposition M = L L^{\dagger} of a Hermitian positive definite matrix.
+///
+/// # Arguments
+///
+/// * `matrix` - The Hermitian matrix to decompose.
+/// * `tolerance` - Pivots below the tolerance (relative to the largest diagonal entry) are treated as vanishing.
+///
+/// # Returns
+///
+/// * `Some(Array2<Complex64>)` - The lower triangular matrix L.
+/// * `None` - The matrix is not positive definite.
+pub(crate) fn cholesky_decomposition(
+    matrix: &Array2<Complex64>,
+    tolerance: f64,
+) -> Option<Array2<Complex64>> {
+    let dimension = matrix.nrows();
+    let scale = (0..dimension)
+        .map(|i| matrix[[i, i]].re.abs())
+        .fold(0.0, f64::max);
+    let mut lower: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    for column in 0..dimension {
+        let pivot = matrix[[column, column]].re
+            - (0..column)
+                .map(|k| lower[[column, k]].norm_sqr())
+                .sum::<f64>();
+        if pivot <= tolerance * scale {
+            return None;
+        }
+        let diagonal = pivot.sqrt();
+        lower[[column, column]] = Complex64::new(diagonal, 0.0);
+        for row in column + 1..dimension {
+            let sum: Complex64 = (0..column)
+                .map(|k| lower[[row, k]] * lower[[column, k]].conj())
+                .sum();
+            lower[[row, column]] = (matrix[[row, column]] - sum) / diagonal;
+        }
+    }
+    Some(lower)
+}
+
+/// Groups sorted eigenvalues into degenerate levels.
+///
+/// # Arguments
+///
+/// * `values` - The eigenvalues in ascending order.
+/// * `tolerance` - Neighbouring eigenvalues closer than the tolerance belong to the same level.
+///
+/// # Returns
+///
+/// * `Vec<Range<usize>>` - The index ranges of the degenerate levels.
+pub(crate) fn degenerate_levels(values: &[f64], tolerance: f64) -> Vec<Range<usize>> {
+    let mut levels: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+    for index in 1..=values.len() {
+        if index == values.len() || values[index] - values[index - 1] > tolerance {
+            levels.push(start..index);
+            start = index;
+        }
+    }
+    levels
+}
+
+/// Computes the exponential exp(t A) of a sparse matrix by scaling and squaring.
+///
+/// The matrix is scaled by 2^{-s} so that its 1-norm is below 1/2, exponentiated with a Taylor
+/// series and squared s times. Entries with an absolute value below 1e-15 are dropped from the result.
+///
+/// # Arguments
+///
+/// * `matrix` - The matrix A in COO form, duplicate entries are summed.
+/// * `dimension` - The dimension of the square matrix.
+/// * `time` - The factor t in the exponent.
+///
+/// # Returns
+///
+/// * `CooSparseMatrix` - The matrix exponential in COO form, sorted by rows and columns.
+pub(crate) fn sparse_exponential(
+    matrix: CooSparseMatrix,
+    dimension: usize,
+    time: f64,
+) -> CooSparseMatrix {
+    let (values, (rows, columns)) = matrix;
+    let mut generator: SparseRows = vec![HashMap::new(); dimension];
+    for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+        *generator[row]
+            .entry(column)
+            .or_insert(Complex64::new(0.0, 0.0)) += value * time;
+    }
+    let norm = sparse_one_norm(&generator, dimension);
+    let squarings = if norm > 0.5 {
+        (norm / 0.5).log2().ceil() as u32
+    } else {
+        0
+    };
+    let scale = 0.5_f64.powi(squarings as i32);
+    for row in generator.iter_mut() {
+        for value in row.values_mut() {
+            *value *= scale;
+        }
+    }
+
+    let identity: SparseRows = (0..dimension)
+        .map(|row| HashMap::from([(row, Complex64::new(1.0, 0.0))]))
+        .collect();
+    let mut exponential = identity.clone();
+    let mut term = identity;
+    for order in 1..=MAX_TAYLOR_TERMS {
+        term = sparse_product(&term, &generator);
+        for row in term.iter_mut() {
+            for value in row.values_mut() {
+                *value /= order as f64;
+            }
+        }
+        for (exponential_row, term_row) in exponential.iter_mut().zip(term.iter()) {
+            for (column, value) in term_row.iter() {
+                *exponential_row
+                    .entry(*column)
+                    .or_insert(Complex64::new(0.0, 0.0)) += value;
+            }
+        }
+        if sparse_one_norm(&term, dimension) <= f64::EPSILON {
+            break;
+        }
+    }
+    for _ in 0..squarings {
+        exponential = sparse_product(&exponential, &exponential);
+    }
+
+    let mut values: Vec<Complex64> = Vec::new();
+    let mut rows: Vec<usize> = Vec::new();
+    let mut columns: Vec<usize> = Vec::new();
+    for (row, entries) in exponential.into_iter().enumerate() {
+        let mut entries: Vec<(usize, Complex64)> = entries
+            .into_iter()
+            .filter(|(_, value)| value.norm() >= SPARSE_EXPONENTIAL_THRESHOLD)
+            .collect();
+        entries.sort_unstable_by_key(|(column, _)| *column);
+        for (column, value) in entries {
+            values.push(value);
+            rows.push(row);
+            columns.push(column);
+        }
+    }
+    (values, (rows, columns))
+}
+
+/// Returns the product of two sparse matrices.
+fn sparse_product(left: &SparseRows, right: &SparseRows) -> SparseRows {
+    left.iter()
+        .map(|left_row| {
+            let mut product_row: HashMap<usize, Complex64> = HashMap::new();
+            for (inner, left_value) in left_row.iter() {
+                for (column, right_value) in right[*inner].iter() {
+                    *product_row
+                        .entry(*column)
+                        .or_insert(Complex64::new(0.0, 0.0)) += left_value * right_value;
+                }
+            }
+            product_row
+        })
+        .collect()
+}
+
+/// Returns the 1-norm (maximal absolute column sum) of a sparse matrix.
+fn sparse_one_norm(matrix: &SparseRows, dimension: usize) -> f64 {
+    let mut column_sums = vec![0.0; dimension];
+    for row in matrix.iter() {
+        for (column, value) in row.iter() {
+            column_sums[*column] += value.norm();
+        }
+    }
+    column_sums.into_iter().fold(0.0, f64::max)
+}
+
+/// Computes the principal logarithm of a dense matrix by inverse scaling and squaring.
+///
+/// Square roots of the matrix are taken with the Denman-Beavers iteration until the 1-norm of
+/// the distance to the identity is below 1/4, the logarithm of the root is evaluated with the
+/// series of log(I + X) and scaled back by the number of square roots.
+///
+/// # Arguments
+///
+/// * `matrix` - The square matrix.
+///
+/// # Returns
+///
+/// * `Some(Array2<Complex64>)` - The principal logarithm of the matrix.
+/// * `None` - The matrix is singular or the square roots do not converge, e.g. for eigenvalues on the negative real axis.
+pub(crate) fn dense_logarithm(matrix: &Array2<Complex64>) -> Option<Array2<Complex64>> {
+    let dimension = matrix.nrows();
+    let identity: Array2<Complex64> = Array2::eye(dimension);
+    let mut root = matrix.clone();
+    let mut square_roots: i32 = 0;
+    while dense_one_norm(&(&root - &identity)) > 0.25 {
+        if square_roots == MAX_SQUARE_ROOTS {
+            return None;
+        }
+        root = dense_square_root(&root)?;
+        square_roots += 1;
+    }
+    let difference = &root - &identity;
+    let mut logarithm: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    let mut power = identity;
+    for order in 1..=MAX_LOGARITHM_TERMS {
+        power = power.dot(&difference);
+        let sign = if order % 2 == 1 { 1.0 } else { -1.0 };
+        logarithm = logarithm + &power * Complex64::new(sign / order as f64, 0.0);
+        if dense_one_norm(&power) <= f64::EPSILON {
+            break;
+        }
+    }
+    Some(logarithm * Complex64::new(2.0_f64.powi(square_roots), 0.0))
+}
+
+/// Computes the principal square root of a dense matrix with the Denman-Beavers iteration.
+fn dense_square_root(matrix: &Array2<Complex64>) -> Option<Array2<Complex64>> {
+    let dimension = matrix.nrows();
+    let mut root = matrix.clone();
+    let mut inverse_root: Array2<Complex64> = Array2::eye(dimension);
+    for _ in 0..MAX_DENMAN_BEAVERS_ITERATIONS {
+        let next_root = (&root + &dense_inverse(&inverse_root)?) * Complex64::new(0.5, 0.0);
+        let next_inverse_root = (&inverse_root + &dense_inverse(&root)?) * Complex64::new(0.5, 0.0);
+        let change = dense_one_norm(&(&next_root - &root));
+        root = next_root;
+        inverse_root = next_inverse_root;
+        if change <= 1e-14 * dense_one_norm(&root) {
+            return Some(root);
+        }
+    }
+    None
+}
+
+/// Computes the inverse of a dense matrix by Gauss-Jordan elimination with partial pivoting.
+fn dense_inverse(matrix: &Array2<Complex64>) -> Option<Array2<Complex64>> {
+    let dimension = matrix.nrows();
+    let scale = dense_one_norm(matrix);
+    let mut a = matrix.clone();
+    let mut inverse: Array2<Complex64> = Array2::eye(dimension);
+    for column in 0..dimension {
+        let pivot = (column..dimension)
+            .max_by(|i, j| a[[*i, column]].norm().total_cmp(&a[[*j, column]].norm()))?;
+        if a[[pivot, column]].norm() <= f64::EPSILON * scale {
+            return None;
+        }
+        for k in 0..dimension {
+            a.swap([column, k], [pivot, k]);
+            inverse.swap([column, k], [pivot, k]);
+        }
+        let factor = Complex64::new(1.0, 0.0) / a[[column, column]];
+        for k in 0..dimension {
+            a[[column, k]] *= factor;
+            inverse[[column, k]] *= factor;
+        }
+        for row in 0..dimension {
+            if row == column {
+                continue;
+            }
+            let factor = a[[row, column]];
+            if factor.norm() == 0.0 {
+                continue;
+            }
+            for k in 0..dimension {
+                let (a_ck, inverse_ck) = (a[[column, k]], inverse[[column, k]]);
+                a[[row, k]] -= factor * a_ck;
+                inverse[[row, k]] -= factor * inverse_ck;
+            }
+        }
+    }
+    Some(inverse)
+}
+
+/// Returns the 1-norm (maximal absolute column sum) of a dense matrix.
+fn dense_one_norm(matrix: &Array2<Complex64>) -> f64 {
+    matrix
+        .columns()
+        .into_iter()
+        .map(|column| column.iter().map(|value| value.norm()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
@@ -0,0 +1,85 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bravyi-Kitaev mapping between fermionic operators and spin operators.
+//!
+//! The Fenwick tree formulation of the mapping is used, which is valid for an arbitrary number of modes.
+//! Qubit j stores the parity of the occupations of the modes (j & (j + 1)) to j.
+//! The convention used is to treat the qubit state $|0 \rangle$ as empty, and the state $|1\rangle$
+//! as occupied by a fermion (for a single mode). The corresponding mapping is given by
+//!
+//! BK(a_p^{dagger}) = X_{U(p)} Z_{P(p)} * 1/2 - i Y_p X_{U(p) \ p} Z_{R(p)} * 1/2
+//! BK(a_p) = X_{U(p)} Z_{P(p)} * 1/2 + i Y_p X_{U(p) \ p} Z_{R(p)} * 1/2
+//!
+//! where U(p) is the update set of qubits that store the occupation of mode p, P(p) is the parity set of qubits
+//! storing the parity of all modes smaller than p and R(p) is the remainder set, the symmetric difference of P(p)
+//! and the occupation set of p, without p itself.
+//!
+//! As the update set depends on the total number of modes, the transformation of operators uses
+//! the number of modes of the whole object (`number_modes` for systems, `current_number_modes` otherwise).
+
+use super::FermionToSpinEncoding;
+
+pub trait BravyiKitaevFermionToSpin {
+    /// The Output type for the BravyiKitaev transformation
+    ///
+    /// For a FermionProduct or FermionOperator it will be a SpinOperator
+    /// For a HermitianFermionProduct or FermionHamiltonian it will be a SpinHamiltonian
+    /// For a FermionLindbladNoiseOperator it will be a SpinLindbladNoiseOperator
+    /// For a FermionLindbladOpenSystem it will be a SpinLindbladOpenSystem etc.
+    type Output;
+
+    /// Transform the given fermionic object into a spin object using
+    /// the Bravyi Kitaev mapping.
+    fn bravyi_kitaev(&self) -> Self::Output;
+}
+
+/// The Bravyi-Kitaev encoding of fermionic modes into spins in the Fenwick tree formulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BravyiKitaevEncoding;
+
+impl FermionToSpinEncoding for BravyiKitaevEncoding {
+    // From trait
+    fn update_set(&self, mode: usize, number_modes: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::new();
+        let mut index = mode + 1;
+        while index <= number_modes {
+            indices.push(index - 1);
+            index += index & index.wrapping_neg();
+        }
+        indices
+    }
+
+    // From trait
+    fn parity_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = Vec::new();
+        let mut index = mode;
+        while index > 0 {
+            indices.push(index - 1);
+            index &= index - 1;
+        }
+        indices
+    }
+
+    // From trait
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = vec![mode];
+        let mut index = mode + 1;
+        let parent = index & (index - 1);
+        index -= 1;
+        while index != parent {
+            indices.push(index - 1);
+            index &= index - 1;
+        }
+        indices
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generalized binary-tree encodings of fermionic modes into spins.
+//!
+//! A binary-tree encoding is characterized by three sets of qubits for every mode p:
+//! the update set U(p) of qubits that store the occupation of mode p,
+//! the parity set P(p) of qubits storing the parity of all modes smaller than p
+//! and the occupation set O(p) of qubits whose parity is the occupation of mode p.
+//! The convention used is to treat the qubit state $|0 \rangle$ as empty, and the state $|1\rangle$
+//! as occupied by a fermion (for a single mode). The corresponding mapping is given by
+//!
+//! E(a_p^{dagger}) = X_{U(p)} Z_{P(p)} * 1/2 - i Y_p X_{U(p) \ p} Z_{R(p)} * 1/2
+//! E(a_p) = X_{U(p)} Z_{P(p)} * 1/2 + i Y_p X_{U(p) \ p} Z_{R(p)} * 1/2
+//!
+//! where R(p) is the remainder set, the symmetric difference of P(p) and O(p), without p itself.
+//!
+//! As the sets can depend on the total number of modes, the transformation of operators uses
+//! the number of modes of the whole object (`number_modes` for systems, `current_number_modes` otherwise).
+
+use crate::fermions::{
+    FermionHamiltonian, FermionLindbladNoiseOperator, FermionOperator, FermionProduct,
+};
+use crate::prelude::*;
+use crate::spins::{
+    DecoherenceOperator, PauliProduct, SingleSpinOperator, SpinHamiltonian,
+    SpinLindbladNoiseOperator, SpinOperator,
+};
+use crate::StruqtureError;
+use qoqo_calculator::CalculatorComplex;
+
+/// Trait for encodings of fermionic modes into spins.
+///
+/// Custom binary-tree encodings only need to provide the update, parity and occupation sets,
+/// other encodings can overwrite `ladder_operator` directly.
+/// The sets are checked when they are used, invalid sets lead to an error instead of a wrong operator.
+pub trait FermionToSpinEncoding {
+    /// Returns the update set of a mode, the qubits whose value depends on the occupation of the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which to construct the set.
+    /// * `number_modes` - The total number of modes of the transformed object.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The qubits in the update set, including the qubit `mode`.
+    fn update_set(&self, mode: usize, number_modes: usize) -> Vec<usize>;
+
+    /// Returns the parity set of a mode, the qubits storing the parity of all modes smaller than the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which to construct the set.
+    /// * `number_modes` - The total number of modes of the transformed object.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The qubits in the parity set.
+    fn parity_set(&self, mode: usize, number_modes: usize) -> Vec<usize>;
+
+    /// Returns the occupation set of a mode, the qubits whose parity is the occupation of the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode for which to construct the set.
+    /// * `number_modes` - The total number of modes of the transformed object.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The qubits in the occupation set, including the qubit `mode`.
+    fn occupation_set(&self, mode: usize, number_modes: usize) -> Vec<usize>;
+
+    /// Returns the encoded creation (or annihilation) operator acting on a mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode the creation or annihilation operator acts on.
+    /// * `number_modes` - The total number of modes of the transformed object.
+    /// * `creator` - Whether to transform a creation (true) or an annihilation operator (false).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The transformed operator.
+    /// * `Err(StruqtureError::GenericError)` - The update, parity or occupation set of the mode is invalid.
+    fn ladder_operator(
+        &self,
+        mode: usize,
+        number_modes: usize,
+        creator: bool,
+    ) -> Result<SpinOperator, StruqtureError> {
+        let update = self.update_set(mode, number_modes);
+        let parity = self.parity_set(mode, number_modes);
+        let occupation = self.occupation_set(mode, number_modes);
+        check_encoding_set(&update, "update", mode, number_modes, true)?;
+        check_encoding_set(&parity, "parity", mode, number_modes, false)?;
+        check_encoding_set(&occupation, "occupation", mode, number_modes, true)?;
+
+        let mut majorana_sum = PauliProduct::new();
+        let mut majorana_difference = PauliProduct::new().y(mode);
+        for index in update.iter() {
+            majorana_sum = majorana_sum.set_pauli(*index, SingleSpinOperator::X);
+            if *index != mode {
+                majorana_difference = majorana_difference.set_pauli(*index, SingleSpinOperator::X);
+            }
+        }
+        for index in parity.iter() {
+            majorana_sum = majorana_sum.set_pauli(*index, SingleSpinOperator::Z);
+            if !occupation.contains(index) {
+                majorana_difference = majorana_difference.set_pauli(*index, SingleSpinOperator::Z);
+            }
+        }
+        for index in occupation.iter() {
+            if *index != mode && !parity.contains(index) {
+                majorana_difference = majorana_difference.set_pauli(*index, SingleSpinOperator::Z);
+            }
+        }
+
+        let sign = if creator { -0.5 } else { 0.5 };
+        let mut out = SpinOperator::new();
+        out.add_operator_product(majorana_sum, CalculatorComplex::new(0.5, 0.0))?;
+        out.add_operator_product(majorana_difference, CalculatorComplex::new(0.0, sign))?;
+        Ok(out)
+    }
+}
+
+/// Checks a set of qubits returned by a FermionToSpinEncoding.
+///
+/// # Arguments
+///
+/// * `set` - The qubits in the set.
+/// * `name` - The name of the set used in the error message.
+/// * `mode` - The mode for which the set was constructed.
+/// * `number_modes` - The total number of modes of the transformed object.
+/// * `contains_mode` - Whether the set has to contain the qubit `mode` (true) or must not contain it (false).
+///
+/// # Returns
+///
+/// * `Ok(())` - The set is valid.
+/// * `Err(StruqtureError::GenericError)` - The set contains a qubit outside of the number of modes or (does not) contain the qubit `mode`.
+fn check_encoding_set(
+    set: &[usize],
+    name: &str,
+    mode: usize,
+    number_modes: usize,
+    contains_mode: bool,
+) -> Result<(), StruqtureError> {
+    if let Some(index) = set.iter().find(|index| **index >= number_modes) {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The {} set of mode {} contains qubit {}, which is outside of the {} modes of the transformed object",
+                name, mode, index, number_modes
+            ),
+        });
+    }
+    if set.contains(&mode) != contains_mode {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The {} set of mode {} {} the qubit of the mode",
+                name,
+                mode,
+                if contains_mode {
+                    "does not contain"
+                } else {
+                    "must not contain"
+                }
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub trait FermionToSpin {
+    /// The Output type for the transformation with a FermionToSpinEncoding
+    ///
+    /// For a FermionProduct or FermionOperator it will be a SpinOperator
+    /// For a HermitianFermionProduct or FermionHamiltonian it will be a SpinHamiltonian
+    /// For a FermionLindbladNoiseOperator it will be a SpinLindbladNoiseOperator
+    /// For a FermionLindbladOpenSystem it will be a SpinLindbladOpenSystem etc.
+    type Output;
+
+    /// Transform the given fermionic object into a spin object using
+    /// the given encoding.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the fermionic modes into spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self::Output)` - The spin object that results from the transformation.
+    /// * `Err(StruqtureError)` - The sets of the encoding are invalid or the result does not have the type of Self::Output.
+    fn fermion_to_spin<E: FermionToSpinEncoding + ?Sized>(
+        &self,
+        encoding: &E,
+    ) -> Result<Self::Output, StruqtureError>;
+}
+
+/// Transforms a FermionProduct with an encoding for a given number of modes.
+///
+/// # Arguments
+///
+/// * `product` - The FermionProduct to transform.
+/// * `encoding` - The encoding of the modes into spins.
+/// * `number_modes` - The total number of modes used to construct the encoding.
+///
+/// # Returns
+///
+/// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+/// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+pub(crate) fn encode_fermion_product<E: FermionToSpinEncoding + ?Sized>(
+    product: &FermionProduct,
+    encoding: &E,
+    number_modes: usize,
+) -> Result<SpinOperator, StruqtureError> {
+    let mut spin_operator = SpinOperator::new();
+    spin_operator.add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))?;
+    for site in product.creators() {
+        spin_operator = spin_operator * encoding.ladder_operator(*site, number_modes, true)?;
+    }
+    for site in product.annihilators() {
+        spin_operator = spin_operator * encoding.ladder_operator(*site, number_modes, false)?;
+    }
+    Ok(spin_operator)
+}
+
+/// Transforms a FermionOperator with an encoding for a given number of modes.
+///
+/// # Arguments
+///
+/// * `operator` - The FermionOperator to transform.
+/// * `encoding` - The encoding of the modes into spins.
+/// * `number_modes` - The total number of modes used to construct the encoding.
+///
+/// # Returns
+///
+/// * `Ok(SpinOperator)` - The spin operator that results from the transformation.
+/// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+pub(crate) fn encode_fermion_operator<E: FermionToSpinEncoding + ?Sized>(
+    operator: &FermionOperator,
+    encoding: &E,
+    number_modes: usize,
+) -> Result<SpinOperator, StruqtureError> {
+    let mut out = SpinOperator::new();
+    for (fp, coeff) in operator.iter() {
+        out = out + encode_fermion_product(fp, encoding, number_modes)? * coeff.clone();
+    }
+    Ok(out)
+}
+
+/// Transforms a FermionHamiltonian with an encoding for a given number of modes.
+///
+/// # Arguments
+///
+/// * `hamiltonian` - The FermionHamiltonian to transform.
+/// * `encoding` - The encoding of the modes into spins.
+/// * `number_modes` - The total number of modes used to construct the encoding.
+///
+/// # Returns
+///
+/// * `Ok(SpinHamiltonian)` - The spin Hamiltonian that results from the transformation.
+/// * `Err(StruqtureError)` - The sets of the encoding are invalid or the transformed operator is not hermitian.
+pub(crate) fn encode_fermion_hamiltonian<E: FermionToSpinEncoding + ?Sized>(
+    hamiltonian: &FermionHamiltonian,
+    encoding: &E,
+    number_modes: usize,
+) -> Result<SpinHamiltonian, StruqtureError> {
+    let mut spin_operator = SpinOperator::new();
+    for (hfp, coeff) in hamiltonian.iter() {
+        let fp = FermionProduct::new(hfp.creators().cloned(), hfp.annihilators().cloned())?;
+        spin_operator =
+            spin_operator + encode_fermion_product(&fp, encoding, number_modes)? * coeff.clone();
+        if !hfp.is_natural_hermitian() {
+            let (fp_conj, conjugate_sign) = fp.hermitian_conjugate();
+            spin_operator = spin_operator
+                + encode_fermion_product(&fp_conj, encoding, number_modes)?
+                    * (coeff.conj() * conjugate_sign);
+        }
+    }
+    SpinHamiltonian::try_from(spin_operator)
+}
+
+/// Transforms a FermionLindbladNoiseOperator with an encoding for a given number of modes.
+///
+/// # Arguments
+///
+/// * `noise` - The FermionLindbladNoiseOperator to transform.
+/// * `encoding` - The encoding of the modes into spins.
+/// * `number_modes` - The total number of modes used to construct the encoding.
+///
+/// # Returns
+///
+/// * `Ok(SpinLindbladNoiseOperator)` - The spin noise operator that results from the transformation.
+/// * `Err(StruqtureError)` - The sets of the encoding are invalid.
+pub(crate) fn encode_fermion_noise_operator<E: FermionToSpinEncoding + ?Sized>(
+    noise: &FermionLindbladNoiseOperator,
+    encoding: &E,
+    number_modes: usize,
+) -> Result<SpinLindbladNoiseOperator, StruqtureError> {
+    let mut out = SpinLindbladNoiseOperator::new();
+    for ((left, right), coeff) in noise.iter() {
+        let decoherence_operator_left =
+            DecoherenceOperator::from(encode_fermion_product(left, encoding, number_modes)?);
+        let decoherence_operator_right =
+            DecoherenceOperator::from(encode_fermion_product(right, encoding, number_modes)?);
+        out.add_noise_from_full_operators(
+            &decoherence_operator_left,
+            &decoherence_operator_right,
+            coeff.into(),
+        )?;
+    }
+    Ok(out)
+}
@@ -18,6 +18,8 @@
 //! JW(a_p^{dagger}) = ( \prod_{i = 1}^{p - 1} Z_i )(X_p - i Y_p)*1/2
 //! JW(a_p) = ( \prod_{i = 1}^{p - 1} Z_i )(X_p + i Y_p)*1/2
 
+use super::FermionToSpinEncoding;
+
 pub trait JordanWignerFermionToSpin {
     /// The Output type for the JordanWigner transformation
     ///
@@ -45,3 +47,26 @@ pub trait JordanWignerSpinToFermion {
     /// the Jordan Wigner mapping.
     fn jordan_wigner(&self) -> Self::Output;
 }
+
+/// The Jordan-Wigner encoding of fermionic modes into spins.
+///
+/// Transforming with this encoding gives the same result as the `jordan_wigner` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JordanWignerEncoding;
+
+impl FermionToSpinEncoding for JordanWignerEncoding {
+    // From trait
+    fn update_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        vec![mode]
+    }
+
+    // From trait
+    fn parity_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        (0..mode).collect()
+    }
+
+    // From trait
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        vec![mode]
+    }
+}
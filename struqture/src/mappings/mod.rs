@@ -12,7 +12,13 @@
 
 //! Module for representing mappings between systems of bosons, fermions and spins.
 
+pub mod bravyi_kitaev;
+pub mod encoding;
 pub mod jordan_wigner;
+pub mod parity;
 
-pub use jordan_wigner::JordanWignerFermionToSpin;
+pub use bravyi_kitaev::{BravyiKitaevEncoding, BravyiKitaevFermionToSpin};
+pub use encoding::{FermionToSpin, FermionToSpinEncoding};
 pub use jordan_wigner::JordanWignerSpinToFermion;
+pub use jordan_wigner::{JordanWignerEncoding, JordanWignerFermionToSpin};
+pub use parity::ParityEncoding;
@@ -0,0 +1,53 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parity mapping between fermionic operators and spin operators.
+//!
+//! Qubit j stores the parity of the occupations of the modes 0 to j.
+//! The convention used is to treat the qubit state $|0 \rangle$ as empty, and the state $|1\rangle$
+//! as occupied by a fermion (for a single mode). The corresponding mapping is given by
+//!
+//! P(a_p^{dagger}) = ( \prod_{i = p + 1}^{N - 1} X_i )(X_p Z_{p - 1} - i Y_p)*1/2
+//! P(a_p) = ( \prod_{i = p + 1}^{N - 1} X_i )(X_p Z_{p - 1} + i Y_p)*1/2
+//!
+//! where N is the total number of modes.
+
+use super::FermionToSpinEncoding;
+
+/// The parity encoding of fermionic modes into spins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParityEncoding;
+
+impl FermionToSpinEncoding for ParityEncoding {
+    // From trait
+    fn update_set(&self, mode: usize, number_modes: usize) -> Vec<usize> {
+        (mode..number_modes.max(mode + 1)).collect()
+    }
+
+    // From trait
+    fn parity_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        if mode == 0 {
+            Vec::new()
+        } else {
+            vec![mode - 1]
+        }
+    }
+
+    // From trait
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        if mode == 0 {
+            vec![mode]
+        } else {
+            vec![mode, mode - 1]
+        }
+    }
+}
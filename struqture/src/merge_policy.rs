@@ -0,0 +1,73 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Policies for merging the coefficients of two objects that contain the same key.
+
+use crate::StruqtureError;
+use std::str::FromStr;
+
+/// Policy for merging the coefficients of two objects that contain the same key.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+/// use struqture::MergePolicy;
+///
+/// let mut left = SpinOperator::new();
+/// left.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from("a")).unwrap();
+/// let mut right = SpinOperator::new();
+/// right.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from("b")).unwrap();
+///
+/// assert!(left.add_with_policy(&right, MergePolicy::ErrorOnSymbolConflict).is_err());
+/// assert_eq!(left.add_with_policy(&right, MergePolicy::PreferLeft).unwrap(), left);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MergePolicy {
+    /// Coefficients of the same key are added, equivalent to `+`.
+    #[default]
+    Sum,
+    /// The coefficient of the left object is kept for keys contained in both objects.
+    PreferLeft,
+    /// Returns an error when both objects contain different symbolic coefficients for the same key.
+    /// All other coefficients are added.
+    ErrorOnSymbolConflict,
+}
+
+impl FromStr for MergePolicy {
+    type Err = StruqtureError;
+
+    /// Constructs a MergePolicy from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - One of "sum", "prefer_left" or "error_on_symbol_conflict".
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The policy corresponding to the string.
+    /// * `Err(StruqtureError::FromStringFailed)` - The string is not a known merge policy.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(MergePolicy::Sum),
+            "prefer_left" => Ok(MergePolicy::PreferLeft),
+            "error_on_symbol_conflict" => Ok(MergePolicy::ErrorOnSymbolConflict),
+            _ => Err(StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Unknown merge policy {}, expected one of sum, prefer_left, error_on_symbol_conflict",
+                    s
+                ),
+            }),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Provenance metadata of struqture systems.
+
+use crate::StruqtureError;
+use std::any::Any;
+use std::collections::BTreeMap;
+
+/// Policy deciding how the metadata of two systems is merged.
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use struqture::spins::SpinHamiltonianSystem;
+/// use struqture::MetadataMergePolicy;
+///
+/// let mut left = SpinHamiltonianSystem::new(None);
+/// left.set_metadata("basis", "sto-3g");
+/// let mut right = SpinHamiltonianSystem::new(None);
+/// right.set_metadata("basis", "cc-pvdz");
+/// right.set_metadata("bond_length", "0.74");
+///
+/// assert!(left.merge_metadata(right.metadata(), MetadataMergePolicy::ErrorOnConflict).is_err());
+/// left.merge_metadata(right.metadata(), MetadataMergePolicy::PreferLeft).unwrap();
+/// assert_eq!(left.get_metadata("basis"), Some("sto-3g"));
+/// assert_eq!(left.get_metadata("bond_length"), Some("0.74"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MetadataMergePolicy {
+    /// The value of the left system is kept for keys contained in both systems.
+    #[default]
+    PreferLeft,
+    /// The value of the right system replaces the value of the left system for keys contained in both systems.
+    PreferRight,
+    /// Returns an error when both systems contain different values for the same key.
+    ErrorOnConflict,
+}
+
+impl MetadataMergePolicy {
+    /// Merges other metadata into metadata under the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The metadata of the left system, which the other metadata is merged into.
+    /// * `other` - The metadata of the right system.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The metadata was merged.
+    /// * `Err(StruqtureError::GenericError)` - Conflicting values with `MetadataMergePolicy::ErrorOnConflict`, the metadata is unchanged.
+    pub(crate) fn merge(
+        &self,
+        metadata: &mut BTreeMap<String, String>,
+        other: &BTreeMap<String, String>,
+    ) -> Result<(), StruqtureError> {
+        if *self == MetadataMergePolicy::ErrorOnConflict {
+            for (key, value) in other {
+                if let Some(existing) = metadata.get(key) {
+                    if existing != value {
+                        return Err(StruqtureError::GenericError {
+                            msg: format!(
+                                "Conflicting metadata for key {}: {} and {}",
+                                key, existing, value
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        for (key, value) in other {
+            if *self == MetadataMergePolicy::PreferRight || !metadata.contains_key(key) {
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Trait for systems carrying provenance metadata, e.g. the geometry, basis set or generation parameters.
+///
+/// The metadata is a map from String keys to String values. It is part of the serialized system and is compared by `==`.
+/// Empty metadata is left out of human-readable formats like JSON, so files written without metadata can still be read
+/// and systems without metadata are written as before. It is kept by `empty_clone`, and the arithmetic of two systems
+/// merges the metadata of both operands with [MetadataMergePolicy::PreferLeft], keeping the value of the left operand for keys
+/// contained in both. Operators and other iterables of terms carry no metadata, adding them to a system keeps its metadata.
+/// Other policies are applied explicitly with [OperateOnMetadata::merge_metadata].
+/// Open systems store their metadata in the Hamiltonian system.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+///
+/// let mut left = SpinHamiltonianSystem::new(None);
+/// left.set_metadata("basis", "sto-3g");
+/// let mut right = SpinHamiltonianSystem::new(None);
+/// right.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(1.0)).unwrap();
+/// right.set_metadata("basis", "cc-pvdz");
+/// right.set_metadata("bond_length", "0.74");
+///
+/// let sum = (left + right).unwrap();
+/// assert_eq!(sum.get_metadata("basis"), Some("sto-3g"));
+/// assert_eq!(sum.get_metadata("bond_length"), Some("0.74"));
+/// ```
+pub trait OperateOnMetadata {
+    /// Returns the metadata of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `&BTreeMap<String, String>` - The metadata of Self.
+    fn metadata(&self) -> &BTreeMap<String, String>;
+
+    /// Returns a mutable reference to the metadata of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `&mut BTreeMap<String, String>` - The metadata of Self.
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String>;
+
+    /// Returns the metadata value of a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the metadata entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` - The value of the key.
+    /// * `None` - Self has no metadata entry for the key.
+    fn get_metadata(&self, key: &str) -> Option<&str> {
+        self.metadata().get(key).map(|value| value.as_str())
+    }
+
+    /// Sets the metadata value of a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the metadata entry.
+    /// * `value` - The value of the metadata entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The previous value of the key.
+    /// * `None` - Self had no metadata entry for the key.
+    fn set_metadata(&mut self, key: &str, value: &str) -> Option<String> {
+        self.metadata_mut()
+            .insert(key.to_string(), value.to_string())
+    }
+
+    /// Removes the metadata entry of a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the metadata entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The removed value of the key.
+    /// * `None` - Self had no metadata entry for the key.
+    fn remove_metadata(&mut self, key: &str) -> Option<String> {
+        self.metadata_mut().remove(key)
+    }
+
+    /// Merges other metadata into the metadata of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The metadata to merge into Self.
+    /// * `policy` - The MetadataMergePolicy deciding which value is kept for keys contained in both.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The metadata was merged.
+    /// * `Err(StruqtureError::GenericError)` - Conflicting values with `MetadataMergePolicy::ErrorOnConflict`, the metadata of Self is unchanged.
+    fn merge_metadata(
+        &mut self,
+        other: &BTreeMap<String, String>,
+        policy: MetadataMergePolicy,
+    ) -> Result<(), StruqtureError> {
+        policy.merge(self.metadata_mut(), other)
+    }
+}
+
+/// Merges the metadata of the right operand of an arithmetic operation into the metadata of the result.
+///
+/// The arithmetic of systems merges with [MetadataMergePolicy::PreferLeft]. The right operand of `+` and `-` can be
+/// any iterable of terms, only systems of type `S` carry metadata, all other operands leave the metadata unchanged.
+///
+/// # Arguments
+///
+/// * `metadata` - The metadata of the left operand, which the metadata of the right operand is merged into.
+/// * `operand` - The right operand.
+pub(crate) fn merge_operand_metadata<S: OperateOnMetadata + 'static>(
+    metadata: &mut BTreeMap<String, String>,
+    operand: &dyn Any,
+) {
+    if let Some(system) = operand.downcast_ref::<S>() {
+        // Merging with PreferLeft keeps the existing values and never returns an error
+        let _ = MetadataMergePolicy::PreferLeft.merge(metadata, system.metadata());
+    }
+}
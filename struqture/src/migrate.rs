@@ -0,0 +1,763 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migration of serialized struqture objects between struqture versions.
+//!
+//! struqture 1.x records in every serialized operator the minimal struqture version (`_struqture_version`)
+//! required to read it. The serialization of a type does not change between 1.x minor versions,
+//! the record only prevents older versions from reading data they do not know.
+//! Data written by struqture 1.x can therefore be migrated to any 1.x version by lowering the records.
+//!
+//! struqture 2.x objects carry a `serialisation_meta` with their type name instead of the version record and
+//! replace the systems of struqture 1.x by operators (e.g. `PauliHamiltonian` instead of `SpinHamiltonianSystem`).
+//! They are migrated to the corresponding struqture 1.x type, validated by deserializing them with this crate.
+//! In the other direction, [Struqture2Json] exports every struqture 1.x type to its struqture 2.x counterpart
+//! and [migrate_json_by_type_name] migrates serialized objects in both directions.
+//!
+//! # Example
+//! ```
+//! use struqture::migrate::{json_version, migrate_json};
+//! use struqture::spins::SpinHamiltonianSystem;
+//! use struqture::prelude::*;
+//!
+//! let input = r#"{"items":[["0Z",1.0]],"serialisation_meta":{"type_name":"PauliHamiltonian","min_version":[2,0,0],"version":"2.0.0"}}"#;
+//! assert_eq!(json_version(input).unwrap(), (2, 0));
+//!
+//! let migrated = migrate_json(input, (1, 0)).unwrap();
+//! let system: SpinHamiltonianSystem = serde_json::from_str(&migrated).unwrap();
+//! assert_eq!(system.len(), 1);
+//! ```
+
+use crate::bosons::*;
+use crate::fermions::*;
+use crate::io::{convert_by_type_name, SerializationFormat};
+use crate::mixed_systems::*;
+use crate::spins::*;
+use crate::{StruqtureError, STRUQTURE_VERSION};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Name of the field recording the minimal struqture 1.x version of a serialized operator.
+const VERSION_FIELD: &str = "_struqture_version";
+/// Name of the field holding the type name and version of a serialized struqture 2.x object.
+const META_FIELD: &str = "serialisation_meta";
+
+/// Layout of the struqture 1.x type corresponding to a struqture 2.x type.
+#[derive(Debug, Clone, Copy)]
+enum Layout {
+    /// The type has the same layout in both versions.
+    Operator,
+    /// The struqture 2.x operator is the `operator_field` of a struqture 1.x system with the number of
+    /// particles in `number_field`.
+    System {
+        number_field: &'static str,
+        operator_field: &'static str,
+    },
+    /// The struqture 2.x mixed operator is the `operator_field` of a struqture 1.x mixed system.
+    MixedSystem { operator_field: &'static str },
+    /// The system and noise of the open system are migrated separately.
+    OpenSystem,
+}
+
+/// Returns the name and layout of the struqture 1.x counterpart of a struqture 2.x type.
+fn struqture_1_counterpart(type_name: &str) -> Option<(&'static str, Layout)> {
+    let spin_system = |operator_field| Layout::System {
+        number_field: "number_spins",
+        operator_field,
+    };
+    let mode_system = |operator_field| Layout::System {
+        number_field: "number_modes",
+        operator_field,
+    };
+    let counterpart = match type_name {
+        "PauliOperator" => ("SpinSystem", spin_system("operator")),
+        "PauliHamiltonian" => ("SpinHamiltonianSystem", spin_system("hamiltonian")),
+        "PauliLindbladNoiseOperator" => ("SpinLindbladNoiseSystem", spin_system("operator")),
+        "PauliLindbladOpenSystem" => ("SpinLindbladOpenSystem", Layout::OpenSystem),
+        "DecoherenceOperator" => ("DecoherenceOperator", Layout::Operator),
+        "PlusMinusOperator" => ("PlusMinusOperator", Layout::Operator),
+        "PlusMinusLindbladNoiseOperator" => ("PlusMinusLindbladNoiseOperator", Layout::Operator),
+        "BosonOperator" => ("BosonSystem", mode_system("operator")),
+        "BosonHamiltonian" => ("BosonHamiltonianSystem", mode_system("hamiltonian")),
+        "BosonLindbladNoiseOperator" => ("BosonLindbladNoiseSystem", mode_system("operator")),
+        "BosonLindbladOpenSystem" => ("BosonLindbladOpenSystem", Layout::OpenSystem),
+        "FermionOperator" => ("FermionSystem", mode_system("operator")),
+        "FermionHamiltonian" => ("FermionHamiltonianSystem", mode_system("hamiltonian")),
+        "FermionLindbladNoiseOperator" => ("FermionLindbladNoiseSystem", mode_system("operator")),
+        "FermionLindbladOpenSystem" => ("FermionLindbladOpenSystem", Layout::OpenSystem),
+        "MixedOperator" => (
+            "MixedSystem",
+            Layout::MixedSystem {
+                operator_field: "operator",
+            },
+        ),
+        "MixedHamiltonian" => (
+            "MixedHamiltonianSystem",
+            Layout::MixedSystem {
+                operator_field: "hamiltonian",
+            },
+        ),
+        "MixedLindbladNoiseOperator" => (
+            "MixedLindbladNoiseSystem",
+            Layout::MixedSystem {
+                operator_field: "operator",
+            },
+        ),
+        "MixedLindbladOpenSystem" => ("MixedLindbladOpenSystem", Layout::OpenSystem),
+        "MixedPlusMinusOperator" => ("MixedPlusMinusOperator", Layout::Operator),
+        _ => return None,
+    };
+    Some(counterpart)
+}
+
+/// Returns the struqture version of a serialized struqture object.
+///
+/// For struqture 1.x objects this is the highest minimal version recorded in the object,
+/// for struqture 2.x objects the minimal version in the serialization metadata.
+/// Objects without any version record (e.g. products) are reported as version 1.0.
+///
+/// # Arguments
+///
+/// * `input` - The JSON representation of the struqture object.
+///
+/// # Returns
+///
+/// * `Ok((u32, u32))` - The major and minor version of the object.
+/// * `Err(StruqtureError::SerializationError)` - The input is not valid JSON.
+/// * `Err(StruqtureError::GenericError)` - A version record is malformed.
+pub fn json_version(input: &str) -> Result<(u32, u32), StruqtureError> {
+    let value = parse_json(input)?;
+    match struqture_2_meta(&value) {
+        Some(meta) => {
+            let min_version = meta
+                .get("min_version")
+                .and_then(|version| version.as_array())
+                .ok_or_else(|| migration_error("serialisation_meta without min_version"))?;
+            Ok((
+                version_number(min_version.first())?,
+                version_number(min_version.get(1))?,
+            ))
+        }
+        None => {
+            let mut versions: Vec<(u32, u32)> = Vec::new();
+            collect_version_records(&value, &mut versions)?;
+            Ok(versions.into_iter().max().unwrap_or((1, 0)))
+        }
+    }
+}
+
+/// Returns the major and minor version of this struqture library, the default target of a migration.
+///
+/// # Returns
+///
+/// * `(u32, u32)` - The major and minor version of struqture.
+pub fn library_version() -> (u32, u32) {
+    let mut split = STRUQTURE_VERSION.split('.').map(|number| {
+        number
+            .parse::<u32>()
+            .expect("Internal error: Version not conforming to semver")
+    });
+    let major_version = split
+        .next()
+        .expect("Internal error: Version not conforming to semver");
+    let minor_version = split
+        .next()
+        .expect("Internal error: Version not conforming to semver");
+    (major_version, minor_version)
+}
+
+/// Migrates the JSON representation of a struqture object to the given struqture version.
+///
+/// struqture 1.x objects migrated to 1.x keep their type, every version record above the target version is lowered to the target.
+/// struqture 2.x objects are converted to the corresponding struqture 1.x type, e.g. a `PauliOperator` to a
+/// `SpinSystem` without a fixed number of spins, and validated by deserializing them.
+/// struqture 2.x objects migrated to 2.x are returned unchanged.
+/// The migration of struqture 1.x objects to 2.x requires the type name, see [migrate_json_by_type_name].
+///
+/// # Arguments
+///
+/// * `input` - The JSON representation of the struqture object.
+/// * `target_version` - The major and minor struqture version the output is read with.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The compact JSON representation readable by the target version.
+/// * `Err(StruqtureError::SerializationError)` - The input is not valid JSON or not a valid struqture object.
+/// * `Err(StruqtureError::GenericError)` - The target version is not supported, or the struqture 2.x type is unknown.
+pub fn migrate_json(input: &str, target_version: (u32, u32)) -> Result<String, StruqtureError> {
+    let value = parse_json(input)?;
+    let migrated =
+        match (target_version.0, struqture_2_meta(&value).is_some()) {
+            (1, true) => {
+                let (type_name, value) = struqture_1_from_2(value)?;
+                validated_struqture_1(type_name, value, target_version.1)?
+            }
+            (1, false) => {
+                let mut value = value;
+                lower_version_records(&mut value, target_version.1)?;
+                value
+            }
+            (2, true) => value,
+            (2, false) => return Err(migration_error(
+                "the migration of a struqture 1.x object to struqture 2.x requires its type name",
+            )),
+            _ => return Err(unsupported_version_error(target_version)),
+        };
+    serde_json::to_string(&migrated).map_err(json_error)
+}
+
+/// Migrates the JSON representation of a struqture object of a known struqture 1.x type to the given struqture version.
+///
+/// In addition to [migrate_json], struqture 1.x objects are converted to the corresponding struqture 2.x type,
+/// e.g. a `SpinHamiltonianSystem` to a `PauliHamiltonian`. The number of spins or modes of struqture 1.x systems
+/// is not part of the struqture 2.x types and is dropped. struqture 2.x objects migrated to struqture 1.x
+/// are converted to the given type, e.g. a `PauliOperator` to a `SpinOperator` instead of a `SpinSystem`.
+///
+/// # Arguments
+///
+/// * `type_name` - The name of the struqture 1.x type, e.g. "SpinHamiltonianSystem".
+/// * `input` - The JSON representation of the struqture object.
+/// * `target_version` - The major and minor struqture version the output is read with.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The compact JSON representation readable by the target version.
+/// * `Err(StruqtureError::SerializationError)` - The input is not valid JSON or not a valid object of the type.
+/// * `Err(StruqtureError::GenericError)` - The target version is not supported, or the type has no counterpart in the target version.
+pub fn migrate_json_by_type_name(
+    type_name: &str,
+    input: &str,
+    target_version: (u32, u32),
+) -> Result<String, StruqtureError> {
+    let value = parse_json(input)?;
+    let migrated = match (target_version.0, struqture_2_meta(&value).is_some()) {
+        (1, true) => {
+            let (struqture_1_name, value) = struqture_1_from_2(value)?;
+            let value = if struqture_1_name == type_name {
+                value
+            } else {
+                // The operator of a struqture 1.x system is requested instead of the system
+                value
+                    .get("operator")
+                    .or_else(|| value.get("hamiltonian"))
+                    .cloned()
+                    .ok_or_else(|| {
+                        migration_error(&format!(
+                            "a {} cannot be migrated to a {}",
+                            struqture_1_name, type_name
+                        ))
+                    })?
+            };
+            validated_struqture_1(type_name, value, target_version.1)?
+        }
+        (1, false) => validated_struqture_1(type_name, value, target_version.1)?,
+        (2, true) => value,
+        (2, false) => {
+            let value = validated_struqture_1(type_name, value, u32::MAX)?;
+            struqture_2_from_1(type_name, value, target_version.1)?
+        }
+        _ => return Err(unsupported_version_error(target_version)),
+    };
+    serde_json::to_string(&migrated).map_err(json_error)
+}
+
+/// Validates a struqture 1.x object by deserializing it and lowers its version records to the target minor version.
+fn validated_struqture_1(
+    type_name: &str,
+    value: Value,
+    target_minor: u32,
+) -> Result<Value, StruqtureError> {
+    let serialized = serde_json::to_vec(&value).map_err(json_error)?;
+    let validated = convert_by_type_name(
+        type_name,
+        &serialized,
+        SerializationFormat::Json,
+        SerializationFormat::Json,
+    )?;
+    let mut value: Value = serde_json::from_slice(&validated).map_err(json_error)?;
+    lower_version_records(&mut value, target_minor)?;
+    Ok(value)
+}
+
+/// Conversion of struqture objects to and from the JSON representation of struqture 2.x.
+///
+/// # Example
+/// ```
+/// use struqture::migrate::Struqture2Json;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+///
+/// let mut system = SpinHamiltonianSystem::new(None);
+/// system.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+///
+/// let json = system.to_struqture_2_json().unwrap();
+/// assert!(json.contains("PauliHamiltonian"));
+/// assert_eq!(SpinHamiltonianSystem::from_struqture_2_json(&json).unwrap(), system);
+/// ```
+pub trait Struqture2Json: Serialize + DeserializeOwned {
+    /// Returns the name of the struqture 1.x type.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The name of the type, e.g. "SpinHamiltonianSystem".
+    fn struqture_1_type_name() -> &'static str;
+
+    /// Serializes Self to the JSON representation of the corresponding struqture 2.x type.
+    ///
+    /// The number of spins or modes of systems is not part of the struqture 2.x types and is dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The struqture 2.x JSON representation of Self.
+    /// * `Err(StruqtureError::SerializationError)` - Self could not be serialized.
+    /// * `Err(StruqtureError::GenericError)` - The type has no struqture 2.x counterpart.
+    fn to_struqture_2_json(&self) -> Result<String, StruqtureError> {
+        let value = serde_json::to_value(self).map_err(json_error)?;
+        let converted = struqture_2_from_1(Self::struqture_1_type_name(), value, 0)?;
+        serde_json::to_string(&converted).map_err(json_error)
+    }
+
+    /// Deserializes Self from the JSON representation of the corresponding struqture 2.x type.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The struqture 2.x (or struqture 1.x) JSON representation.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The deserialized object.
+    /// * `Err(StruqtureError::SerializationError)` - The input is not a valid representation of Self.
+    /// * `Err(StruqtureError::GenericError)` - The struqture 2.x type cannot be converted to Self.
+    fn from_struqture_2_json(input: &str) -> Result<Self, StruqtureError> {
+        let migrated =
+            migrate_json_by_type_name(Self::struqture_1_type_name(), input, library_version())?;
+        serde_json::from_str(&migrated).map_err(json_error)
+    }
+}
+
+/// Implements Struqture2Json for struqture types with a struqture 2.x counterpart.
+macro_rules! impl_struqture_2_json {
+    ($($struqture_type:ident),* $(,)?) => {
+        $(
+            impl Struqture2Json for $struqture_type {
+                fn struqture_1_type_name() -> &'static str {
+                    stringify!($struqture_type)
+                }
+            }
+        )*
+    };
+}
+
+impl_struqture_2_json!(
+    PauliProduct,
+    DecoherenceProduct,
+    PlusMinusProduct,
+    SpinOperator,
+    SpinHamiltonian,
+    DecoherenceOperator,
+    PlusMinusOperator,
+    SpinLindbladNoiseOperator,
+    PlusMinusLindbladNoiseOperator,
+    SpinSystem,
+    SpinHamiltonianSystem,
+    SpinLindbladNoiseSystem,
+    SpinLindbladOpenSystem,
+    BosonProduct,
+    HermitianBosonProduct,
+    BosonOperator,
+    BosonHamiltonian,
+    BosonLindbladNoiseOperator,
+    BosonSystem,
+    BosonHamiltonianSystem,
+    BosonLindbladNoiseSystem,
+    BosonLindbladOpenSystem,
+    FermionProduct,
+    HermitianFermionProduct,
+    FermionOperator,
+    FermionHamiltonian,
+    FermionLindbladNoiseOperator,
+    FermionSystem,
+    FermionHamiltonianSystem,
+    FermionLindbladNoiseSystem,
+    FermionLindbladOpenSystem,
+    MixedProduct,
+    HermitianMixedProduct,
+    MixedDecoherenceProduct,
+    MixedPlusMinusProduct,
+    MixedOperator,
+    MixedHamiltonian,
+    MixedPlusMinusOperator,
+    MixedLindbladNoiseOperator,
+    MixedSystem,
+    MixedHamiltonianSystem,
+    MixedLindbladNoiseSystem,
+    MixedLindbladOpenSystem,
+);
+
+/// Converts a struqture 2.x object to the corresponding struqture 1.x object.
+///
+/// Returns the name of the struqture 1.x type and the converted value.
+fn struqture_1_from_2(value: Value) -> Result<(&'static str, Value), StruqtureError> {
+    let type_name = struqture_2_meta(&value)
+        .and_then(|meta| meta.get("type_name"))
+        .and_then(|name| name.as_str())
+        .ok_or_else(|| migration_error("serialisation_meta without type_name"))?;
+    let (struqture_1_name, layout) = struqture_1_counterpart(type_name).ok_or_else(|| {
+        migration_error(&format!(
+            "struqture 2.x type {} has no struqture 1.x counterpart",
+            type_name
+        ))
+    })?;
+    let mut fields = match value {
+        Value::Object(fields) => fields,
+        _ => return Err(migration_error("struqture 2.x object is not a JSON object")),
+    };
+    fields.remove(META_FIELD);
+    let converted = match layout {
+        Layout::Operator => Value::Object(with_version_record(fields)),
+        Layout::System {
+            number_field,
+            operator_field,
+        } => {
+            let mut system = Map::new();
+            system.insert(number_field.to_string(), Value::Null);
+            system.insert(
+                operator_field.to_string(),
+                Value::Object(with_version_record(fields)),
+            );
+            Value::Object(system)
+        }
+        Layout::MixedSystem { operator_field } => {
+            let mut system = Map::new();
+            for (number_field, count_field) in [
+                ("number_spins", "n_spins"),
+                ("number_bosons", "n_bosons"),
+                ("number_fermions", "n_fermions"),
+            ] {
+                let count = fields
+                    .get(count_field)
+                    .and_then(|count| count.as_u64())
+                    .ok_or_else(|| {
+                        migration_error(&format!("mixed operator without {}", count_field))
+                    })?;
+                system.insert(
+                    number_field.to_string(),
+                    Value::Array(vec![Value::Null; count as usize]),
+                );
+            }
+            system.insert(
+                operator_field.to_string(),
+                Value::Object(with_version_record(fields)),
+            );
+            Value::Object(system)
+        }
+        Layout::OpenSystem => {
+            let mut system = Map::new();
+            for field in ["system", "noise"] {
+                let part = fields
+                    .remove(field)
+                    .ok_or_else(|| migration_error(&format!("open system without {}", field)))?;
+                system.insert(field.to_string(), struqture_1_from_2(part)?.1);
+            }
+            Value::Object(system)
+        }
+    };
+    Ok((struqture_1_name, converted))
+}
+
+/// struqture 2.x counterpart of a struqture 1.x type.
+#[derive(Debug, Clone, Copy)]
+enum Struqture2Counterpart {
+    /// Products are serialized identically and without metadata in both versions.
+    Product,
+    /// The operator has the same layout in both versions, with the name of the struqture 2.x type.
+    Operator(&'static str),
+    /// The struqture 1.x system is replaced by its operator in the given field, with the name of the struqture 2.x type.
+    System(&'static str, &'static str),
+    /// The names of the struqture 2.x open system and of its Hamiltonian and noise operator.
+    OpenSystem(&'static str, &'static str, &'static str),
+}
+
+/// Returns the struqture 2.x counterpart of a struqture 1.x type.
+fn struqture_2_counterpart(type_name: &str) -> Option<Struqture2Counterpart> {
+    use Struqture2Counterpart::*;
+    let counterpart = match type_name {
+        "PauliProduct"
+        | "DecoherenceProduct"
+        | "PlusMinusProduct"
+        | "BosonProduct"
+        | "HermitianBosonProduct"
+        | "FermionProduct"
+        | "HermitianFermionProduct"
+        | "MixedProduct"
+        | "HermitianMixedProduct"
+        | "MixedDecoherenceProduct"
+        | "MixedPlusMinusProduct" => Product,
+        "SpinOperator" => Operator("PauliOperator"),
+        "SpinHamiltonian" => Operator("PauliHamiltonian"),
+        "SpinLindbladNoiseOperator" => Operator("PauliLindbladNoiseOperator"),
+        "DecoherenceOperator" => Operator("DecoherenceOperator"),
+        "PlusMinusOperator" => Operator("PlusMinusOperator"),
+        "PlusMinusLindbladNoiseOperator" => Operator("PlusMinusLindbladNoiseOperator"),
+        "SpinSystem" => System("operator", "PauliOperator"),
+        "SpinHamiltonianSystem" => System("hamiltonian", "PauliHamiltonian"),
+        "SpinLindbladNoiseSystem" => System("operator", "PauliLindbladNoiseOperator"),
+        "SpinLindbladOpenSystem" => OpenSystem(
+            "PauliLindbladOpenSystem",
+            "PauliHamiltonian",
+            "PauliLindbladNoiseOperator",
+        ),
+        "BosonOperator" => Operator("BosonOperator"),
+        "BosonHamiltonian" => Operator("BosonHamiltonian"),
+        "BosonLindbladNoiseOperator" => Operator("BosonLindbladNoiseOperator"),
+        "BosonSystem" => System("operator", "BosonOperator"),
+        "BosonHamiltonianSystem" => System("hamiltonian", "BosonHamiltonian"),
+        "BosonLindbladNoiseSystem" => System("operator", "BosonLindbladNoiseOperator"),
+        "BosonLindbladOpenSystem" => OpenSystem(
+            "BosonLindbladOpenSystem",
+            "BosonHamiltonian",
+            "BosonLindbladNoiseOperator",
+        ),
+        "FermionOperator" => Operator("FermionOperator"),
+        "FermionHamiltonian" => Operator("FermionHamiltonian"),
+        "FermionLindbladNoiseOperator" => Operator("FermionLindbladNoiseOperator"),
+        "FermionSystem" => System("operator", "FermionOperator"),
+        "FermionHamiltonianSystem" => System("hamiltonian", "FermionHamiltonian"),
+        "FermionLindbladNoiseSystem" => System("operator", "FermionLindbladNoiseOperator"),
+        "FermionLindbladOpenSystem" => OpenSystem(
+            "FermionLindbladOpenSystem",
+            "FermionHamiltonian",
+            "FermionLindbladNoiseOperator",
+        ),
+        "MixedOperator" => Operator("MixedOperator"),
+        "MixedHamiltonian" => Operator("MixedHamiltonian"),
+        "MixedLindbladNoiseOperator" => Operator("MixedLindbladNoiseOperator"),
+        "MixedPlusMinusOperator" => Operator("MixedPlusMinusOperator"),
+        "MixedSystem" => System("operator", "MixedOperator"),
+        "MixedHamiltonianSystem" => System("hamiltonian", "MixedHamiltonian"),
+        "MixedLindbladNoiseSystem" => System("operator", "MixedLindbladNoiseOperator"),
+        "MixedLindbladOpenSystem" => OpenSystem(
+            "MixedLindbladOpenSystem",
+            "MixedHamiltonian",
+            "MixedLindbladNoiseOperator",
+        ),
+        _ => return None,
+    };
+    Some(counterpart)
+}
+
+/// Converts a struqture 1.x object of the given type to the corresponding struqture 2.x object.
+fn struqture_2_from_1(
+    type_name: &str,
+    value: Value,
+    target_minor: u32,
+) -> Result<Value, StruqtureError> {
+    let counterpart = struqture_2_counterpart(type_name).ok_or_else(|| {
+        migration_error(&format!(
+            "struqture 1.x type {} has no struqture 2.x counterpart",
+            type_name
+        ))
+    })?;
+    match counterpart {
+        Struqture2Counterpart::Product => Ok(value),
+        Struqture2Counterpart::Operator(name) => struqture_2_operator(name, value, target_minor),
+        Struqture2Counterpart::System(operator_field, name) => {
+            struqture_2_operator(name, take_field(value, operator_field)?, target_minor)
+        }
+        Struqture2Counterpart::OpenSystem(name, system_name, noise_name) => {
+            let mut fields = match value {
+                Value::Object(fields) => fields,
+                _ => return Err(migration_error("struqture 1.x object is not a JSON object")),
+            };
+            let mut open_system = Map::new();
+            for (field, operator_field, operator_name) in [
+                ("system", "hamiltonian", system_name),
+                ("noise", "operator", noise_name),
+            ] {
+                let part = fields
+                    .remove(field)
+                    .ok_or_else(|| migration_error(&format!("open system without {}", field)))?;
+                open_system.insert(
+                    field.to_string(),
+                    struqture_2_operator(
+                        operator_name,
+                        take_field(part, operator_field)?,
+                        target_minor,
+                    )?,
+                );
+            }
+            open_system.insert(
+                META_FIELD.to_string(),
+                serialisation_meta(name, target_minor),
+            );
+            Ok(Value::Object(open_system))
+        }
+    }
+}
+
+/// Replaces the version record of a struqture 1.x operator by the serialization metadata of struqture 2.x.
+fn struqture_2_operator(
+    type_name: &str,
+    value: Value,
+    target_minor: u32,
+) -> Result<Value, StruqtureError> {
+    let mut fields = match value {
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(migration_error(
+                "struqture 1.x operator is not a JSON object",
+            ))
+        }
+    };
+    fields.remove(VERSION_FIELD);
+    fields.insert(
+        META_FIELD.to_string(),
+        serialisation_meta(type_name, target_minor),
+    );
+    Ok(Value::Object(fields))
+}
+
+/// Creates the struqture 2.x serialization metadata of a type written for the target minor version.
+fn serialisation_meta(type_name: &str, target_minor: u32) -> Value {
+    let mut meta = Map::new();
+    meta.insert("type_name".to_string(), Value::from(type_name));
+    meta.insert("min_version".to_string(), Value::from(vec![2, 0, 0]));
+    meta.insert(
+        "version".to_string(),
+        Value::from(format!("2.{}.0", target_minor)),
+    );
+    Value::Object(meta)
+}
+
+/// Removes a field from a JSON object and returns it.
+fn take_field(value: Value, field: &str) -> Result<Value, StruqtureError> {
+    match value {
+        Value::Object(mut fields) => fields
+            .remove(field)
+            .ok_or_else(|| migration_error(&format!("object without field {}", field))),
+        _ => Err(migration_error("struqture 1.x object is not a JSON object")),
+    }
+}
+
+/// Returns the serialization metadata if the value is a struqture 2.x object.
+fn struqture_2_meta(value: &Value) -> Option<&Map<String, Value>> {
+    value.get(META_FIELD).and_then(|meta| meta.as_object())
+}
+
+/// Adds a struqture 1.0 version record to the fields of an operator.
+fn with_version_record(mut fields: Map<String, Value>) -> Map<String, Value> {
+    fields.insert(VERSION_FIELD.to_string(), version_record(1, 0));
+    fields
+}
+
+/// Creates the JSON representation of a struqture 1.x version record.
+fn version_record(major_version: u32, minor_version: u32) -> Value {
+    let mut record = Map::new();
+    record.insert("major_version".to_string(), Value::from(major_version));
+    record.insert("minor_version".to_string(), Value::from(minor_version));
+    Value::Object(record)
+}
+
+/// Collects the (major, minor) versions of all version records in the value.
+fn collect_version_records(
+    value: &Value,
+    versions: &mut Vec<(u32, u32)>,
+) -> Result<(), StruqtureError> {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter() {
+                if key == VERSION_FIELD {
+                    versions.push((
+                        version_number(field.get("major_version"))?,
+                        version_number(field.get("minor_version"))?,
+                    ));
+                } else {
+                    collect_version_records(field, versions)?;
+                }
+            }
+        }
+        Value::Array(entries) => {
+            for entry in entries.iter() {
+                collect_version_records(entry, versions)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Lowers all struqture 1.x version records in the value to at most the target minor version.
+fn lower_version_records(value: &mut Value, target_minor: u32) -> Result<(), StruqtureError> {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if key == VERSION_FIELD {
+                    let major_version = version_number(field.get("major_version"))?;
+                    let minor_version = version_number(field.get("minor_version"))?;
+                    if major_version != 1 {
+                        return Err(migration_error(&format!(
+                            "version record {}.{} is not a struqture 1.x version",
+                            major_version, minor_version
+                        )));
+                    }
+                    *field = version_record(1, minor_version.min(target_minor));
+                } else {
+                    lower_version_records(field, target_minor)?;
+                }
+            }
+        }
+        Value::Array(entries) => {
+            for entry in entries.iter_mut() {
+                lower_version_records(entry, target_minor)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Reads one component of a version.
+fn version_number(value: Option<&Value>) -> Result<u32, StruqtureError> {
+    value
+        .and_then(|number| number.as_u64())
+        .and_then(|number| u32::try_from(number).ok())
+        .ok_or_else(|| migration_error("malformed version record"))
+}
+
+/// Parses the input as JSON.
+fn parse_json(input: &str) -> Result<Value, StruqtureError> {
+    serde_json::from_str(input).map_err(json_error)
+}
+
+/// Converts a serde_json error into a StruqtureError.
+fn json_error(error: serde_json::Error) -> StruqtureError {
+    StruqtureError::SerializationError {
+        format: SerializationFormat::Json.to_string(),
+        msg: error.to_string(),
+    }
+}
+
+/// Creates the StruqtureError for target versions that are not supported.
+fn unsupported_version_error(target_version: (u32, u32)) -> StruqtureError {
+    migration_error(&format!(
+        "migration to struqture {}.{} is not supported",
+        target_version.0, target_version.1
+    ))
+}
+
+/// Creates the StruqtureError for inputs that cannot be migrated.
+fn migration_error(msg: &str) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!("Cannot migrate struqture object: {}", msg),
+    }
+}
@@ -11,12 +11,19 @@
 // limitations under the License.
 
 use super::{HermitianMixedProduct, MixedIndex, MixedOperator, OperateOnMixedSystems};
+use crate::bosons::BosonProduct;
+use crate::fermions::FermionProduct;
+use crate::spins::PauliProduct;
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, hermitian_coefficient_1_norm,
+    parse_canonical_count, parse_canonical_text_entries, split_canonical_text, CanonicalText,
+    ModeIndex, OperateOnConstant, OperateOnDensityMatrix, OperateOnState, SpectralNormBound,
+    SpinIndex, StruqtureError, StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy,
+    MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -71,6 +78,8 @@ pub struct MixedHamiltonian {
     n_bosons: usize,
     /// Number of Fermion subsystems
     n_fermions: usize,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for MixedHamiltonian {}
@@ -86,7 +95,7 @@ impl schemars::JsonSchema for MixedHamiltonian {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct MixedHamiltonianSerialize {
@@ -94,9 +103,49 @@ struct MixedHamiltonianSerialize {
     n_spins: usize,
     n_bosons: usize,
     n_fermions: usize,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for MixedHamiltonianSerialize {
+    /// Serialization function for MixedHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "MixedHamiltonianSerialize",
+            if skip_zero_term_policy { 5 } else { 6 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("n_spins", &self.n_spins)?;
+        state.serialize_field("n_bosons", &self.n_bosons)?;
+        state.serialize_field("n_fermions", &self.n_fermions)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<MixedHamiltonianSerialize> for MixedHamiltonian {
     fn from(value: MixedHamiltonianSerialize) -> Self {
         let mut new_noise_op =
@@ -105,12 +154,14 @@ impl From<MixedHamiltonianSerialize> for MixedHamiltonian {
             let _ =
                 new_noise_op.add_operator_product(key.clone(), CalculatorComplex::new(real, imag));
         }
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<MixedHamiltonian> for MixedHamiltonianSerialize {
     fn from(value: MixedHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(HermitianMixedProduct, CalculatorFloat, CalculatorFloat)> = value
             .clone()
             .into_iter()
@@ -125,11 +176,21 @@ impl From<MixedHamiltonian> for MixedHamiltonianSerialize {
             n_spins: value.n_spins,
             n_bosons: value.n_bosons,
             n_fermions: value.n_fermions,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for MixedHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = MixedHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedHamiltonian {
     type Index = HermitianMixedProduct;
     type Value = CalculatorComplex;
@@ -174,10 +235,25 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedHamiltonian {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(self.n_spins, self.n_bosons, self.n_fermions, cap),
             None => Self::new(self.n_spins, self.n_bosons, self.n_fermions),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the MixedHamiltonian with the given (HermitianMixedProduct key, CalculatorComplex value) pair.
@@ -211,7 +287,7 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedHamiltonian {
                 actual_number_fermion_subsystems: key.fermions().len(),
             });
         }
-        if value.re != CalculatorFloat::ZERO || value.im != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             // Catch on diagonals with non-zero imaginary values
             if key.is_natural_hermitian() && value.im != CalculatorFloat::ZERO {
                 Err(StruqtureError::NonHermitianOperator)
@@ -237,6 +313,18 @@ impl<'a> OperateOnState<'a> for MixedHamiltonian {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for MixedHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        HermitianMixedProduct::new(
+            vec![PauliProduct::new(); self.n_spins],
+            vec![BosonProduct::default(); self.n_bosons],
+            vec![FermionProduct::default(); self.n_fermions],
+        )
+        .expect("Internal bug in creating the identity HermitianMixedProduct")
+    }
+}
+
 impl<'a> OperateOnMixedSystems<'a> for MixedHamiltonian {
     // From trait
     fn number_spins(&self) -> Vec<usize> {
@@ -324,6 +412,7 @@ impl MixedHamiltonian {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -353,12 +442,50 @@ impl MixedHamiltonian {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
         }
     }
 
+    /// Converts the MixedHamiltonian into a MixedOperator, adding the hermitian conjugates of all terms that are not naturally hermitian.
+    ///
+    /// # Returns
+    ///
+    /// * `MixedOperator` - The MixedHamiltonian as a MixedOperator.
+    pub fn to_operator(&self) -> MixedOperator {
+        MixedOperator::from(self.clone())
+    }
+
+    /// Creates the MixedHamiltonian of the hermitian part (A + A^dagger) / 2 of a MixedOperator A.
+    ///
+    /// Every term is symmetrized with [crate::mixed_systems::MixedProduct::symmetrize], so that hermitian conjugate pairs
+    /// are folded into a single HermitianMixedProduct. The anti-hermitian part of the operator is discarded,
+    /// use `MixedOperator::try_into_hamiltonian` to reject non-hermitian operators instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The MixedOperator to fold into a MixedHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The hermitian part of the operator as a MixedHamiltonian.
+    /// * `Err(StruqtureError)` - Could not symmetrize a term or add it to the MixedHamiltonian.
+    pub fn from_operator(operator: &MixedOperator) -> Result<Self, StruqtureError> {
+        let mut hamiltonian = Self::with_capacity(
+            operator.number_spins().len(),
+            operator.number_bosonic_modes().len(),
+            operator.number_fermionic_modes().len(),
+            operator.len(),
+        );
+        for (product, value) in operator.iter() {
+            let (hermitian_product, coefficient) = product.symmetrize(value.clone() * 0.5)?;
+            hamiltonian.add_operator_product(hermitian_product, coefficient)?;
+        }
+        Ok(hamiltonian)
+    }
+
     // /// Separate self into an operator with the terms of given number of spins, bosons and fermions and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -400,6 +527,7 @@ impl ops::Neg for MixedHamiltonian {
     ///
     /// * `Self` - The MixedHamiltonian * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
         let n_bosons = self.n_bosons;
@@ -409,6 +537,7 @@ impl ops::Neg for MixedHamiltonian {
         }
         MixedHamiltonian {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -487,6 +616,7 @@ where
     ///
     /// * `Self` - The MixedHamiltonian multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
@@ -495,8 +625,10 @@ where
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         MixedHamiltonian {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -520,12 +652,79 @@ impl ops::Mul<MixedHamiltonian> for MixedHamiltonian {
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
     fn mul(self, other: MixedHamiltonian) -> Self::Output {
+        if self.n_spins != other.n_spins
+            || self.n_bosons != other.n_bosons
+            || self.n_fermions != other.n_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: other.n_spins,
+                actual_number_boson_subsystems: other.n_bosons,
+                actual_number_fermion_subsystems: other.n_fermions,
+            });
+        }
         let mut op = MixedOperator::with_capacity(
             self.n_spins,
             self.n_bosons,
             self.n_fermions,
             self.len() * other.len(),
         );
+        op.set_zero_term_policy(self.zero_term_policy)?;
+        for (bps, vals) in self {
+            for (bpo, valo) in other.iter() {
+                let mixed_products = (bps.clone() * bpo.clone())?;
+                let coefficient = Into::<CalculatorComplex>::into(valo) * vals.clone();
+                for (b, coeff) in mixed_products {
+                    op.add_operator_product(b, coefficient.clone() * coeff)?;
+                }
+            }
+        }
+        Ok(op)
+    }
+}
+
+/// Implements the multiplication function of MixedHamiltonian by MixedOperator.
+///
+impl ops::Mul<MixedOperator> for MixedHamiltonian {
+    type Output = Result<MixedOperator, StruqtureError>;
+    /// Implement `*` for MixedHamiltonian and MixedOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The MixedHamiltonian multiplied by the MixedOperator.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in the MixedHamiltonian and the MixedOperator do not match.
+    fn mul(self, other: MixedOperator) -> Self::Output {
+        let (other_spins, other_bosons, other_fermions) = (
+            other.number_spins().len(),
+            other.number_bosonic_modes().len(),
+            other.number_fermionic_modes().len(),
+        );
+        if self.n_spins != other_spins
+            || self.n_bosons != other_bosons
+            || self.n_fermions != other_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: other_spins,
+                actual_number_boson_subsystems: other_bosons,
+                actual_number_fermion_subsystems: other_fermions,
+            });
+        }
+        let mut op = MixedOperator::with_capacity(
+            self.n_spins,
+            self.n_bosons,
+            self.n_fermions,
+            self.len() * other.len(),
+        );
+        op.set_zero_term_policy(self.zero_term_policy)?;
         for (bps, vals) in self {
             for (bpo, valo) in other.iter() {
                 let mixed_products = (bps.clone() * bpo.clone())?;
@@ -659,6 +858,81 @@ impl fmt::Display for MixedHamiltonian {
     }
 }
 
+impl CanonicalText for MixedHamiltonian {
+    /// Returns the canonical text representation of the MixedHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedHamiltonian.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedHamiltonian",
+            &[
+                ("number_spin_subsystems", self.n_spins.to_string()),
+                ("number_bosonic_subsystems", self.n_bosons.to_string()),
+                ("number_fermionic_subsystems", self.n_fermions.to_string()),
+            ],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedHamiltonian from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedHamiltonian represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedHamiltonian.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedHamiltonian.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedHamiltonian",
+            &[
+                "number_spin_subsystems",
+                "number_bosonic_subsystems",
+                "number_fermionic_subsystems",
+            ],
+        )?;
+        let mut new_self = MixedHamiltonian::new(
+            parse_canonical_count(header[0])?,
+            parse_canonical_count(header[1])?,
+            parse_canonical_count(header[2])?,
+        );
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianMixedProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for MixedHamiltonian {
+    /// Returns an upper bound of the spectral norm of the MixedHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The MixedHamiltonian contains symbolic coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on bosonic modes.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        if self.keys().any(|key| {
+            key.bosons()
+                .any(|boson| boson.number_creators() + boson.number_annihilators() > 0)
+        }) {
+            return Err(StruqtureError::GenericError {
+                msg: "The spectral norm of operators acting on bosonic modes is unbounded"
+                    .to_string(),
+            });
+        }
+        hermitian_coefficient_1_norm(self.iter())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
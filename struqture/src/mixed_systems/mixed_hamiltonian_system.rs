@@ -11,15 +11,33 @@
 // limitations under the License.
 
 use super::{
-    HermitianMixedProduct, HermitianOperateOnMixedSystems, MixedHamiltonian, MixedSystem,
-    OperateOnMixedSystems,
+    acts_on_subsystem, acts_only_on_subsystem, check_subsystem_index, rearrange_key,
+    rearrange_number_subsystems, HermitianMixedProduct, HermitianOperateOnMixedSystems,
+    MixedHamiltonian, MixedProductBuilder, MixedSubsystemKind, MixedSystem, OperateOnMixedSystems,
+    SubsystemRearrangement,
 };
+use crate::bosons::{
+    BosonHamiltonian, BosonHamiltonianSystem, BosonProduct, HermitianBosonProduct,
+};
+use crate::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionProduct, HermitianFermionProduct,
+};
+use crate::metadata::merge_operand_metadata;
 #[cfg(feature = "json_schema")]
 use crate::mixed_systems::TinyVecDef;
 use crate::prelude::*;
-use crate::{OperateOnDensityMatrix, OperateOnState, StruqtureError};
+use crate::spins::{SpinHamiltonian, SpinHamiltonianSystem};
+use crate::{
+    canonical_numbers, canonical_text_document, canonical_text_entries, hermitian_term_count,
+    hilbert_space_dimension, parse_canonical_numbers, parse_canonical_text_entries,
+    sparse_matrix_memory, split_canonical_text, CanonicalText, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnState, SpectralNormBound, StruqtureError, SystemDimension,
+    ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -62,7 +80,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 ///     .unwrap();
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 pub struct MixedHamiltonianSystem {
     /// The number of spins in each subsystem
     pub(crate) number_spins: TinyVec<[Option<usize>; 2]>,
@@ -72,6 +90,9 @@ pub struct MixedHamiltonianSystem {
     pub(crate) number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedHamiltonian representing the Hamiltonian of the MixedHamiltonianSystem
     pub(crate) hamiltonian: MixedHamiltonian,
+    /// Provenance metadata of the MixedHamiltonianSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 #[cfg(feature = "json_schema")]
@@ -101,10 +122,90 @@ struct SchemaHelperMixedHamiltonianSystem {
     number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedHamiltonian representing the Hamiltonian of the MixedHamiltonianSystem
     pub(crate) hamiltonian: MixedHamiltonian,
+    /// Provenance metadata of the MixedHamiltonianSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for MixedHamiltonianSystem {}
 
+impl Serialize for MixedHamiltonianSystem {
+    /// Serialization function for MixedHamiltonianSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedHamiltonianSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedHamiltonianSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.hamiltonian, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for MixedHamiltonianSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.hamiltonian), serializer)
+    }
+}
+
+/// Serializes a MixedHamiltonianSystem with the given serialization of its hamiltonian.
+///
+/// # Arguments
+///
+/// * `system` - MixedHamiltonianSystem to be serialized.
+/// * `hamiltonian` - Serialization of the hamiltonian of the MixedHamiltonianSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of MixedHamiltonianSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &MixedHamiltonianSystem,
+    hamiltonian: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("MixedHamiltonianSystem", if skip_metadata { 4 } else { 5 })?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("number_bosons", &system.number_bosons)?;
+    state.serialize_field("number_fermions", &system.number_fermions)?;
+    state.serialize_field("hamiltonian", hamiltonian)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for MixedHamiltonianSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedHamiltonianSystem {
     type Index = HermitianMixedProduct;
     type Value = CalculatorComplex;
@@ -144,26 +245,29 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedHamiltonianSystem {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                hamiltonian: MixedHamiltonian::with_capacity(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                    cap,
-                ),
+                hamiltonian: self.hamiltonian.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                hamiltonian: MixedHamiltonian::new(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                ),
+                hamiltonian: self.hamiltonian.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.hamiltonian.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.hamiltonian.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the MixedHamiltonianSystem with the given (HermitianMixedProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -281,6 +385,13 @@ impl<'a> OperateOnState<'a> for MixedHamiltonianSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for MixedHamiltonianSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        self.hamiltonian.identity_index()
+    }
+}
+
 impl<'a> OperateOnMixedSystems<'a> for MixedHamiltonianSystem {
     // From trait
     fn number_spins(&self) -> Vec<usize> {
@@ -386,6 +497,7 @@ impl MixedHamiltonianSystem {
             number_bosons,
             number_fermions,
             hamiltonian,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -421,6 +533,7 @@ impl MixedHamiltonianSystem {
             number_bosons,
             number_fermions,
             hamiltonian,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -486,12 +599,411 @@ impl MixedHamiltonianSystem {
                 number_bosons,
                 number_fermions,
                 hamiltonian,
+                metadata: BTreeMap::new(),
             })
         } else {
             Err(StruqtureError::NumberSpinsExceeded)
         }
     }
 
+    /// Creates a MixedHamiltonianSystem with a single spin subsystem from a SpinHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The SpinHamiltonianSystem acting on the spin subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedHamiltonianSystem with the terms of the SpinHamiltonianSystem.
+    pub fn from_spin_hamiltonian_system(system: SpinHamiltonianSystem) -> Self {
+        Self::embed([system], [], [])
+    }
+
+    /// Creates a MixedHamiltonianSystem with a single bosonic subsystem from a BosonHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The BosonHamiltonianSystem acting on the bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedHamiltonianSystem with the terms of the BosonHamiltonianSystem.
+    pub fn from_boson_hamiltonian_system(system: BosonHamiltonianSystem) -> Self {
+        Self::embed([], [system], [])
+    }
+
+    /// Creates a MixedHamiltonianSystem with a single fermionic subsystem from a FermionHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The FermionHamiltonianSystem acting on the fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedHamiltonianSystem with the terms of the FermionHamiltonianSystem.
+    pub fn from_fermion_hamiltonian_system(system: FermionHamiltonianSystem) -> Self {
+        Self::embed([], [], [system])
+    }
+
+    /// Creates a MixedHamiltonianSystem from independent systems acting on one subsystem each.
+    ///
+    /// Every term of an input system acts on its own subsystem and as the identity on all other subsystems,
+    /// the MixedHamiltonianSystem is the sum of the input systems. The number of spins and modes of the subsystems is taken from the input systems.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin_systems` - The SpinHamiltonianSystems, one for each spin subsystem.
+    /// * `boson_systems` - The BosonHamiltonianSystems, one for each bosonic subsystem.
+    /// * `fermion_systems` - The FermionHamiltonianSystems, one for each fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedHamiltonianSystem of the combined subsystems.
+    pub fn embed(
+        spin_systems: impl IntoIterator<Item = SpinHamiltonianSystem>,
+        boson_systems: impl IntoIterator<Item = BosonHamiltonianSystem>,
+        fermion_systems: impl IntoIterator<Item = FermionHamiltonianSystem>,
+    ) -> Self {
+        let spin_systems: Vec<SpinHamiltonianSystem> = spin_systems.into_iter().collect();
+        let boson_systems: Vec<BosonHamiltonianSystem> = boson_systems.into_iter().collect();
+        let fermion_systems: Vec<FermionHamiltonianSystem> = fermion_systems.into_iter().collect();
+        let mut embedded = MixedHamiltonianSystem::new(
+            spin_systems.iter().map(|system| system.number_spins),
+            boson_systems.iter().map(|system| system.number_modes),
+            fermion_systems.iter().map(|system| system.number_modes),
+        );
+        let builder = embedded.product_builder();
+        let mut add = |key: Result<_, StruqtureError>, value: CalculatorComplex| {
+            key.and_then(|key| embedded.add_operator_product(key, value))
+                .expect("Internal bug in embed. The terms of the input systems should fit into the subsystems of the MixedHamiltonianSystem.")
+        };
+        for (index, system) in spin_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(
+                    builder.clone().spin(index, product).build_hermitian(),
+                    CalculatorComplex::from(value),
+                );
+            }
+        }
+        for (index, system) in boson_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(
+                    BosonProduct::new(product.creators().copied(), product.annihilators().copied())
+                        .and_then(|product| {
+                            builder.clone().boson(index, product).build_hermitian()
+                        }),
+                    value,
+                );
+            }
+        }
+        for (index, system) in fermion_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(
+                    FermionProduct::new(
+                        product.creators().copied(),
+                        product.annihilators().copied(),
+                    )
+                    .and_then(|product| builder.clone().fermion(index, product).build_hermitian()),
+                    value,
+                );
+            }
+        }
+        embedded
+    }
+
+    /// Returns a MixedProductBuilder with the number of subsystems of the MixedHamiltonianSystem.
+    ///
+    /// The HermitianMixedProduct keys of the MixedHamiltonianSystem can be built with `build_hermitian`.
+    ///
+    /// # Returns
+    ///
+    /// * `MixedProductBuilder` - The builder acting as the identity on all subsystems.
+    pub fn product_builder(&self) -> MixedProductBuilder {
+        MixedProductBuilder::new(
+            self.number_spins.len(),
+            self.number_bosons.len(),
+            self.number_fermions.len(),
+        )
+    }
+
+    /// Returns the terms of the MixedHamiltonianSystem that act non-trivially on one subsystem.
+    ///
+    /// The terms may act on other subsystems as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the subsystem.
+    /// * `index` - The index of the subsystem among the subsystems of the same kind.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator)` - Iterator over the HermitianMixedProducts acting on the subsystem and their coefficients.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of subsystems of the kind.
+    pub fn terms_acting_on_subsystem(
+        &self,
+        kind: MixedSubsystemKind,
+        index: usize,
+    ) -> Result<impl Iterator<Item = (&HermitianMixedProduct, &CalculatorComplex)>, StruqtureError>
+    {
+        check_subsystem_index(
+            kind,
+            index,
+            (
+                self.number_spins.len(),
+                self.number_bosons.len(),
+                self.number_fermions.len(),
+            ),
+        )?;
+        Ok(self
+            .iter()
+            .filter(move |(key, _)| acts_on_subsystem(*key, kind, index)))
+    }
+
+    /// Returns the SpinHamiltonian acting on one spin subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The Hamiltonian acting on the spin subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems.
+    pub fn spin_subsystem_operator(&self, index: usize) -> Result<SpinHamiltonian, StruqtureError> {
+        let kind = MixedSubsystemKind::Spin;
+        check_subsystem_index(kind, index, (self.number_spins.len(), 0, 0))?;
+        let mut hamiltonian = SpinHamiltonian::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            hamiltonian.add_operator_product(
+                key.spins().nth(index).cloned().unwrap_or_default(),
+                value.re.clone(),
+            )?;
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Returns the BosonHamiltonian acting on one bosonic subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonHamiltonian)` - The Hamiltonian acting on the bosonic subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems.
+    pub fn boson_subsystem_operator(
+        &self,
+        index: usize,
+    ) -> Result<BosonHamiltonian, StruqtureError> {
+        let kind = MixedSubsystemKind::Boson;
+        check_subsystem_index(kind, index, (0, self.number_bosons.len(), 0))?;
+        let mut hamiltonian = BosonHamiltonian::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            let product = key.bosons().nth(index).cloned().unwrap_or_default();
+            hamiltonian.add_operator_product(
+                HermitianBosonProduct::new(
+                    product.creators().copied(),
+                    product.annihilators().copied(),
+                )?,
+                value.clone(),
+            )?;
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Returns the FermionHamiltonian acting on one fermionic subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionHamiltonian)` - The Hamiltonian acting on the fermionic subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems.
+    pub fn fermion_subsystem_operator(
+        &self,
+        index: usize,
+    ) -> Result<FermionHamiltonian, StruqtureError> {
+        let kind = MixedSubsystemKind::Fermion;
+        check_subsystem_index(kind, index, (0, 0, self.number_fermions.len()))?;
+        let mut hamiltonian = FermionHamiltonian::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            let product = key.fermions().nth(index).cloned().unwrap_or_default();
+            hamiltonian.add_operator_product(
+                HermitianFermionProduct::new(
+                    product.creators().copied(),
+                    product.annihilators().copied(),
+                )?,
+                value.clone(),
+            )?;
+        }
+        Ok(hamiltonian)
+    }
+
+    /// Appends a spin subsystem to the MixedHamiltonianSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins in the new spin subsystem.
+    pub fn add_spin_subsystem(&mut self, number_spins: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Append(number_spins),
+        )
+        .expect("Internal bug in add_spin_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a bosonic subsystem to the MixedHamiltonianSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_bosons` - The number of boson modes in the new bosonic subsystem.
+    pub fn add_boson_subsystem(&mut self, number_bosons: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Append(number_bosons),
+        )
+        .expect("Internal bug in add_boson_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a fermionic subsystem to the MixedHamiltonianSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_fermions` - The number of fermion modes in the new fermionic subsystem.
+    pub fn add_fermion_subsystem(&mut self, number_fermions: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Append(number_fermions),
+        )
+        .expect("Internal bug in add_fermion_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Removes a spin subsystem from the MixedHamiltonianSystem.
+    ///
+    /// The following spin subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedHamiltonianSystem, in that case the MixedHamiltonianSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The spin subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems or a term acts on the subsystem.
+    pub fn remove_spin_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a bosonic subsystem from the MixedHamiltonianSystem.
+    ///
+    /// The following bosonic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedHamiltonianSystem, in that case the MixedHamiltonianSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The bosonic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems or a term acts on the subsystem.
+    pub fn remove_boson_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a fermionic subsystem from the MixedHamiltonianSystem.
+    ///
+    /// The following fermionic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedHamiltonianSystem, in that case the MixedHamiltonianSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The fermionic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems or a term acts on the subsystem.
+    pub fn remove_fermion_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Reorders the subsystems of one kind of the MixedHamiltonianSystem, rewriting all terms accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the reordered subsystems.
+    /// * `order` - The new order of the subsystems, the new subsystem i is the previous subsystem order\[i\].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The subsystems were reordered.
+    /// * `Err(StruqtureError::GenericError)` - The order is not a permutation of the subsystems of the kind.
+    pub fn permute_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        order: &[usize],
+    ) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(kind, SubsystemRearrangement::Permute(order))
+    }
+
+    /// Rearranges the subsystems of one kind of the MixedHamiltonianSystem, rewriting all terms.
+    ///
+    /// The MixedHamiltonianSystem is only changed when the rearrangement succeeds.
+    pub(crate) fn rearrange_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        rearrangement: SubsystemRearrangement,
+    ) -> Result<(), StruqtureError> {
+        let (number_spins, number_bosons, number_fermions) = rearrange_number_subsystems(
+            kind,
+            rearrangement,
+            &self.number_spins,
+            &self.number_bosons,
+            &self.number_fermions,
+        )?;
+        let mut rearranged = MixedHamiltonianSystem::with_capacity(
+            number_spins,
+            number_bosons,
+            number_fermions,
+            self.len(),
+        );
+        for (key, value) in self.iter() {
+            let (key, value) = rearrange_key(key, value.clone(), kind, rearrangement)?;
+            rearranged.add_operator_product(key, value)?;
+        }
+        *self = rearranged;
+        Ok(())
+    }
+
     // /// Separate self into an operator with the terms of given number of spins, bosons and fermions and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -542,7 +1054,7 @@ impl ops::Neg for MixedHamiltonianSystem {
 ///
 impl<T, V> ops::Add<T> for MixedHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianMixedProduct, V)>,
+    T: IntoIterator<Item = (HermitianMixedProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -558,6 +1070,7 @@ where
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -569,7 +1082,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for MixedHamiltonianSystem
 where
-    T: IntoIterator<Item = (HermitianMixedProduct, V)>,
+    T: IntoIterator<Item = (HermitianMixedProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -585,6 +1098,7 @@ where
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -650,6 +1164,61 @@ impl ops::Mul<MixedHamiltonianSystem> for MixedHamiltonianSystem {
             self.number_fermions.clone(),
             capacity,
         );
+        spin_op.set_zero_term_policy(self.zero_term_policy())?;
+        spin_op.metadata = self.metadata.clone();
+        merge_operand_metadata::<MixedHamiltonianSystem>(&mut spin_op.metadata, &other);
+        for (pps, vals) in self {
+            for (ppo, valo) in other.iter() {
+                let products = (pps.clone() * ppo.clone())?;
+                for (ppp, coefficient) in products {
+                    let coefficient =
+                        Into::<CalculatorComplex>::into(valo) * vals.clone() * coefficient;
+                    spin_op.add_operator_product(ppp, coefficient)?;
+                }
+            }
+        }
+        Ok(spin_op)
+    }
+}
+
+/// Implements the multiplication function of MixedHamiltonianSystem by MixedSystem.
+///
+impl ops::Mul<MixedSystem> for MixedHamiltonianSystem {
+    type Output = Result<MixedSystem, StruqtureError>;
+    /// Implement `*` for MixedHamiltonianSystem and MixedSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedSystem to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedSystem)` - The MixedHamiltonianSystem multiplied by the MixedSystem.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
+    fn mul(self, other: MixedSystem) -> Self::Output {
+        if self.number_spins.len() != other.number_spins.len()
+            || self.number_bosons.len() != other.number_bosons.len()
+            || self.number_fermions.len() != other.number_fermions.len()
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.number_spins.len(),
+                target_number_boson_subsystems: self.number_bosons.len(),
+                target_number_fermion_subsystems: self.number_fermions.len(),
+                actual_number_spin_subsystems: other.number_spins.len(),
+                actual_number_boson_subsystems: other.number_bosons.len(),
+                actual_number_fermion_subsystems: other.number_fermions.len(),
+            });
+        }
+        let capacity = self.len() * other.len();
+        let mut spin_op = MixedSystem::with_capacity(
+            self.number_spins.clone(),
+            self.number_bosons.clone(),
+            self.number_fermions.clone(),
+            capacity,
+        );
+        spin_op.set_zero_term_policy(self.zero_term_policy())?;
+        spin_op.metadata = self.metadata.clone();
+        merge_operand_metadata::<MixedSystem>(&mut spin_op.metadata, &other);
         for (pps, vals) in self {
             for (ppo, valo) in other.iter() {
                 let products = (pps.clone() * ppo.clone())?;
@@ -810,3 +1379,124 @@ impl fmt::Display for MixedHamiltonianSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for MixedHamiltonianSystem {
+    /// Returns the canonical text representation of the MixedHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedHamiltonianSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedHamiltonianSystem",
+            &[
+                ("number_spins", canonical_numbers(&self.number_spins)),
+                (
+                    "number_bosonic_modes",
+                    canonical_numbers(&self.number_bosons),
+                ),
+                (
+                    "number_fermionic_modes",
+                    canonical_numbers(&self.number_fermions),
+                ),
+            ],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedHamiltonianSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedHamiltonianSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedHamiltonianSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedHamiltonianSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedHamiltonianSystem",
+            &[
+                "number_spins",
+                "number_bosonic_modes",
+                "number_fermionic_modes",
+            ],
+        )?;
+        let mut new_self = MixedHamiltonianSystem::new(
+            parse_canonical_numbers(header[0])?,
+            parse_canonical_numbers(header[1])?,
+            parse_canonical_numbers(header[2])?,
+        );
+        for (key, value) in
+            parse_canonical_text_entries::<HermitianMixedProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for MixedHamiltonianSystem {
+    /// Returns an upper bound of the spectral norm of the MixedHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The MixedHamiltonianSystem contains symbolic coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on bosonic modes.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.hamiltonian().spectral_norm_bound()
+    }
+}
+
+impl SystemDimension for MixedHamiltonianSystem {
+    /// Returns the dimension of the Hilbert space of the MixedHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(
+            self.number_spins().iter().sum::<usize>()
+                + self.number_fermionic_modes().iter().sum::<usize>(),
+            self.number_bosonic_modes().iter().sum(),
+            fock_cutoffs,
+        )
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the MixedHamiltonianSystem.
+    ///
+    /// Terms that are not naturally hermitian also represent their hermitian conjugate and are counted twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedHamiltonianSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(
+            dimension,
+            hermitian_term_count(self.keys()),
+            bytes_per_entry,
+        )
+    }
+}
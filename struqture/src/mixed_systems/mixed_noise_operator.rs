@@ -13,10 +13,14 @@
 use super::{MixedDecoherenceProduct, MixedIndex, OperateOnMixedSystems};
 use crate::prelude::*;
 use crate::{
-    OperateOnDensityMatrix, StruqtureError, StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_noise_text_entries, canonical_text_document, noise_adjoint_violations,
+    noise_symmetrized_entries, parse_canonical_count, parse_canonical_noise_text_entries,
+    split_canonical_text, CanonicalText, OperateOnDensityMatrix, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -73,6 +77,8 @@ pub struct MixedLindbladNoiseOperator {
     n_bosons: usize,
     /// Number of Fermion subsystems
     n_fermions: usize,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for MixedLindbladNoiseOperator {}
@@ -88,7 +94,7 @@ impl schemars::JsonSchema for MixedLindbladNoiseOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct MixedLindbladNoiseOperatorSerialize {
@@ -102,10 +108,51 @@ struct MixedLindbladNoiseOperatorSerialize {
     n_spins: usize,
     n_bosons: usize,
     n_fermions: usize,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// The struqture version
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for MixedLindbladNoiseOperatorSerialize {
+    /// Serialization function for MixedLindbladNoiseOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedLindbladNoiseOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedLindbladNoiseOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "MixedLindbladNoiseOperatorSerialize",
+            if skip_zero_term_policy { 5 } else { 6 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("n_spins", &self.n_spins)?;
+        state.serialize_field("n_bosons", &self.n_bosons)?;
+        state.serialize_field("n_fermions", &self.n_fermions)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<MixedLindbladNoiseOperatorSerialize> for MixedLindbladNoiseOperator {
     fn from(value: MixedLindbladNoiseOperatorSerialize) -> Self {
         let mut new_noise_op =
@@ -118,12 +165,14 @@ impl From<MixedLindbladNoiseOperatorSerialize> for MixedLindbladNoiseOperator {
                 )
                 .expect("Internal bug in add_operator_product");
         }
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<MixedLindbladNoiseOperator> for MixedLindbladNoiseOperatorSerialize {
     fn from(value: MixedLindbladNoiseOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(
             MixedDecoherenceProduct,
             MixedDecoherenceProduct,
@@ -143,11 +192,23 @@ impl From<MixedLindbladNoiseOperator> for MixedLindbladNoiseOperatorSerialize {
             n_spins: value.n_spins,
             n_bosons: value.n_bosons,
             n_fermions: value.n_fermions,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for MixedLindbladNoiseOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = MixedLindbladNoiseOperatorSerialize::from(self.clone());
+        serializable
+            .items
+            .sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedLindbladNoiseOperator {
     type Index = (MixedDecoherenceProduct, MixedDecoherenceProduct);
     type Value = CalculatorComplex;
@@ -195,10 +256,25 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedLindbladNoiseOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(self.n_spins, self.n_bosons, self.n_fermions, cap),
             None => Self::new(self.n_spins, self.n_bosons, self.n_fermions),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the MixedLindbladNoiseOperator with the given ((MixedDecoherenceProduct, MixedDecoherenceProduct) key, CalculatorComplex value) pair.
@@ -244,7 +320,7 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedLindbladNoiseOperator {
                 actual_number_fermion_subsystems: key.1.fermions().len(),
             });
         }
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -368,6 +444,7 @@ impl MixedLindbladNoiseOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -397,6 +474,7 @@ impl MixedLindbladNoiseOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -439,6 +517,66 @@ impl MixedLindbladNoiseOperator {
     //     }
     //     Ok((separated, remainder))
     // }
+
+    /// Returns the keys (A, B) whose coefficient is not the complex conjugate of the coefficient of (B, A).
+    ///
+    /// The Kossakowski matrix of a physical noise operator is self-adjoint, so every entry (A, B)
+    /// has to be matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(MixedDecoherenceProduct, MixedDecoherenceProduct)>` - The keys violating the self-adjoint structure.
+    pub fn adjoint_violations(&self) -> Vec<(MixedDecoherenceProduct, MixedDecoherenceProduct)> {
+        noise_adjoint_violations(self.iter())
+    }
+
+    /// Returns true if every entry (A, B) is matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the MixedLindbladNoiseOperator is self-adjoint.
+    pub fn is_self_adjoint(&self) -> bool {
+        self.adjoint_violations().is_empty()
+    }
+
+    /// Enforces the self-adjoint structure of the MixedLindbladNoiseOperator.
+    ///
+    /// The coefficient of every violating entry (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(MixedDecoherenceProduct, MixedDecoherenceProduct)>` - The keys whose coefficients were changed or added.
+    pub fn symmetrize(&mut self) -> Vec<(MixedDecoherenceProduct, MixedDecoherenceProduct)> {
+        let changes = noise_symmetrized_entries(self.iter());
+        let mut changed_keys: Vec<(MixedDecoherenceProduct, MixedDecoherenceProduct)> =
+            Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            self.set(key.clone(), value)
+                .expect("Internal bug in set for a key already contained in transposed form.");
+            changed_keys.push(key);
+        }
+        changed_keys
+    }
+
+    /// Returns the noise operator of the simultaneous action of self and another noise operator.
+    ///
+    /// Generators of the Lindblad equation add, so the result is the sum of both noise operators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The noise operator acting together with self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The sum of the generators.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in self and other do not match.
+    pub fn compose(&self, other: &Self) -> Result<Self, StruqtureError> {
+        let mut composed = self.clone();
+        for (key, value) in other.iter() {
+            composed.add_operator_product(key.clone(), value.clone())?;
+        }
+        Ok(composed)
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladNoiseOperator.
@@ -451,6 +589,7 @@ impl ops::Neg for MixedLindbladNoiseOperator {
     ///
     /// * `Self` - The MixedLindbladNoiseOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -463,6 +602,7 @@ impl ops::Neg for MixedLindbladNoiseOperator {
         }
         MixedLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -547,6 +687,7 @@ where
     ///
     /// * `Self` - The MixedLindbladNoiseOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -558,8 +699,10 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         MixedLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -723,6 +866,61 @@ impl fmt::Display for MixedLindbladNoiseOperator {
     }
 }
 
+impl CanonicalText for MixedLindbladNoiseOperator {
+    /// Returns the canonical text representation of the MixedLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedLindbladNoiseOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedLindbladNoiseOperator",
+            &[
+                ("number_spin_subsystems", self.n_spins.to_string()),
+                ("number_bosonic_subsystems", self.n_bosons.to_string()),
+                ("number_fermionic_subsystems", self.n_fermions.to_string()),
+            ],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedLindbladNoiseOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladNoiseOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedLindbladNoiseOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedLindbladNoiseOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedLindbladNoiseOperator",
+            &[
+                "number_spin_subsystems",
+                "number_bosonic_subsystems",
+                "number_fermionic_subsystems",
+            ],
+        )?;
+        let mut new_self = MixedLindbladNoiseOperator::new(
+            parse_canonical_count(header[0])?,
+            parse_canonical_count(header[1])?,
+            parse_canonical_count(header[2])?,
+        );
+        for (key, value) in parse_canonical_noise_text_entries::<
+            MixedDecoherenceProduct,
+            CalculatorComplex,
+        >(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,12 +11,21 @@
 // limitations under the License.
 
 use super::{
-    MixedDecoherenceProduct, MixedIndex, MixedLindbladNoiseOperator, OperateOnMixedSystems,
+    rearrange_key, rearrange_number_subsystems, MixedDecoherenceProduct, MixedIndex,
+    MixedLindbladNoiseOperator, MixedSubsystemKind, OperateOnMixedSystems, SubsystemRearrangement,
 };
+use crate::metadata::merge_operand_metadata;
 use crate::prelude::*;
-use crate::{OperateOnDensityMatrix, StruqtureError};
+use crate::{
+    canonical_noise_text_entries, canonical_numbers, canonical_text_document,
+    hilbert_space_dimension, noise_is_completely_positive, parse_canonical_noise_text_entries,
+    parse_canonical_numbers, sparse_matrix_memory, split_canonical_text, CanonicalText,
+    OperateOnDensityMatrix, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::{
     fmt::{self, Write},
@@ -73,7 +82,7 @@ pub(crate) struct TinyVecDef(Vec<Option<usize>>);
 /// assert_eq!(system.get(&(pp_0z_c0a1.clone(), pp_0z_c0a1.clone())), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 pub struct MixedLindbladNoiseSystem {
     /// The number of spins in each subsystem
     pub(crate) number_spins: TinyVec<[Option<usize>; 2]>,
@@ -83,6 +92,9 @@ pub struct MixedLindbladNoiseSystem {
     pub(crate) number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedLindbladNoiseOperator representing the Lindblad noise terms of the MixedLindbladNoiseSystem.
     pub(crate) operator: MixedLindbladNoiseOperator,
+    /// Provenance metadata of the MixedLindbladNoiseSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 #[cfg(feature = "json_schema")]
@@ -112,10 +124,92 @@ struct SchemaHelperMixedLindbladNoiseSystem {
     number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedLindbladNoiseOperator representing the Lindblad noise terms of the MixedLindbladNoiseSystem.
     pub(crate) operator: MixedLindbladNoiseOperator,
+    /// Provenance metadata of the MixedLindbladNoiseSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for MixedLindbladNoiseSystem {}
 
+impl Serialize for MixedLindbladNoiseSystem {
+    /// Serialization function for MixedLindbladNoiseSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedLindbladNoiseSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedLindbladNoiseSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for MixedLindbladNoiseSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a MixedLindbladNoiseSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - MixedLindbladNoiseSystem to be serialized.
+/// * `operator` - Serialization of the operator of the MixedLindbladNoiseSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of MixedLindbladNoiseSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &MixedLindbladNoiseSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer.serialize_struct(
+        "MixedLindbladNoiseSystem",
+        if skip_metadata { 4 } else { 5 },
+    )?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("number_bosons", &system.number_bosons)?;
+    state.serialize_field("number_fermions", &system.number_fermions)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for MixedLindbladNoiseSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedLindbladNoiseSystem {
     type Value = CalculatorComplex;
     type Index = (MixedDecoherenceProduct, MixedDecoherenceProduct);
@@ -155,26 +249,29 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedLindbladNoiseSystem {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                operator: MixedLindbladNoiseOperator::with_capacity(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                    cap,
-                ),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                operator: MixedLindbladNoiseOperator::new(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                ),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the MixedLindbladNoiseSystem with the given ((MixedDecoherenceProduct, MixedDecoherenceProduct) key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -475,6 +572,7 @@ impl MixedLindbladNoiseSystem {
             number_bosons,
             number_fermions,
             operator,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -511,6 +609,7 @@ impl MixedLindbladNoiseSystem {
             number_bosons,
             number_fermions,
             operator,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -576,12 +675,177 @@ impl MixedLindbladNoiseSystem {
                 number_bosons,
                 number_fermions,
                 operator,
+                metadata: BTreeMap::new(),
             })
         } else {
             Err(StruqtureError::NumberSpinsExceeded)
         }
     }
 
+    /// Appends a spin subsystem to the MixedLindbladNoiseSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins in the new spin subsystem.
+    pub fn add_spin_subsystem(&mut self, number_spins: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Append(number_spins),
+        )
+        .expect("Internal bug in add_spin_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a bosonic subsystem to the MixedLindbladNoiseSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_bosons` - The number of boson modes in the new bosonic subsystem.
+    pub fn add_boson_subsystem(&mut self, number_bosons: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Append(number_bosons),
+        )
+        .expect("Internal bug in add_boson_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a fermionic subsystem to the MixedLindbladNoiseSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_fermions` - The number of fermion modes in the new fermionic subsystem.
+    pub fn add_fermion_subsystem(&mut self, number_fermions: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Append(number_fermions),
+        )
+        .expect("Internal bug in add_fermion_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Removes a spin subsystem from the MixedLindbladNoiseSystem.
+    ///
+    /// The following spin subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladNoiseSystem, in that case the MixedLindbladNoiseSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The spin subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems or a term acts on the subsystem.
+    pub fn remove_spin_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a bosonic subsystem from the MixedLindbladNoiseSystem.
+    ///
+    /// The following bosonic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladNoiseSystem, in that case the MixedLindbladNoiseSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The bosonic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems or a term acts on the subsystem.
+    pub fn remove_boson_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a fermionic subsystem from the MixedLindbladNoiseSystem.
+    ///
+    /// The following fermionic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladNoiseSystem, in that case the MixedLindbladNoiseSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The fermionic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems or a term acts on the subsystem.
+    pub fn remove_fermion_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Reorders the subsystems of one kind of the MixedLindbladNoiseSystem, rewriting all terms accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the reordered subsystems.
+    /// * `order` - The new order of the subsystems, the new subsystem i is the previous subsystem order\[i\].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The subsystems were reordered.
+    /// * `Err(StruqtureError::GenericError)` - The order is not a permutation of the subsystems of the kind.
+    pub fn permute_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        order: &[usize],
+    ) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(kind, SubsystemRearrangement::Permute(order))
+    }
+
+    /// Rearranges the subsystems of one kind of the MixedLindbladNoiseSystem, rewriting all terms.
+    ///
+    /// The MixedLindbladNoiseSystem is only changed when the rearrangement succeeds.
+    pub(crate) fn rearrange_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        rearrangement: SubsystemRearrangement,
+    ) -> Result<(), StruqtureError> {
+        let (number_spins, number_bosons, number_fermions) = rearrange_number_subsystems(
+            kind,
+            rearrangement,
+            &self.number_spins,
+            &self.number_bosons,
+            &self.number_fermions,
+        )?;
+        let mut rearranged = MixedLindbladNoiseSystem::with_capacity(
+            number_spins,
+            number_bosons,
+            number_fermions,
+            self.len(),
+        );
+        for ((left, right), value) in self.iter() {
+            let (left, value) = rearrange_key(left, value.clone(), kind, rearrangement)?;
+            let (right, value) = rearrange_key(right, value, kind, rearrangement)?;
+            rearranged.add_operator_product((left, right), value)?;
+        }
+        *self = rearranged;
+        Ok(())
+    }
+
+    /// Returns whether the noise is completely positive, i.e. whether its Kossakowski matrix is positive semidefinite.
+    ///
+    /// The Kossakowski matrix is constructed in the basis of all operator products appearing in the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues of the Kossakowski matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the MixedLindbladNoiseSystem is completely positive.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn is_completely_positive(&self, tolerance: f64) -> Result<bool, StruqtureError> {
+        noise_is_completely_positive(self.iter(), tolerance)
+    }
+
     // /// Separate self into an operator with the terms of given number of qubits and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -639,7 +903,7 @@ impl ops::Neg for MixedLindbladNoiseSystem {
 ///
 impl<T, V> ops::Add<T> for MixedLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((MixedDecoherenceProduct, MixedDecoherenceProduct), V)>,
+    T: IntoIterator<Item = ((MixedDecoherenceProduct, MixedDecoherenceProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -654,6 +918,7 @@ where
     /// * `Ok(Self)` - The two MixedLindbladNoiseSystems added together.
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -665,7 +930,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for MixedLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((MixedDecoherenceProduct, MixedDecoherenceProduct), V)>,
+    T: IntoIterator<Item = ((MixedDecoherenceProduct, MixedDecoherenceProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -680,6 +945,7 @@ where
     /// * `Ok(Self)` - The two MixedLindbladNoiseSystems subtracted.
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -887,3 +1153,110 @@ impl fmt::Display for MixedLindbladNoiseSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for MixedLindbladNoiseSystem {
+    /// Returns the canonical text representation of the MixedLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedLindbladNoiseSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedLindbladNoiseSystem",
+            &[
+                ("number_spins", canonical_numbers(&self.number_spins)),
+                (
+                    "number_bosonic_modes",
+                    canonical_numbers(&self.number_bosons),
+                ),
+                (
+                    "number_fermionic_modes",
+                    canonical_numbers(&self.number_fermions),
+                ),
+            ],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedLindbladNoiseSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladNoiseSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedLindbladNoiseSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedLindbladNoiseSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedLindbladNoiseSystem",
+            &[
+                "number_spins",
+                "number_bosonic_modes",
+                "number_fermionic_modes",
+            ],
+        )?;
+        let mut new_self = MixedLindbladNoiseSystem::new(
+            parse_canonical_numbers(header[0])?,
+            parse_canonical_numbers(header[1])?,
+            parse_canonical_numbers(header[2])?,
+        );
+        for (key, value) in parse_canonical_noise_text_entries::<
+            MixedDecoherenceProduct,
+            CalculatorComplex,
+        >(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SystemDimension for MixedLindbladNoiseSystem {
+    /// Returns the dimension of the Hilbert space of the MixedLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(
+            self.number_spins().iter().sum::<usize>()
+                + self.number_fermionic_modes().iter().sum::<usize>(),
+            self.number_bosonic_modes().iter().sum(),
+            fock_cutoffs,
+        )
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the MixedLindbladNoiseSystem.
+    ///
+    /// The estimate is for the sparse superoperator, to which every Lindblad term contributes at most three non-zero entries per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedLindbladNoiseSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Every Lindblad term contributes L ρ R^dagger and the two terms of its anticommutator
+        sparse_matrix_memory(dimension, 3 * self.len(), bytes_per_entry)
+    }
+}
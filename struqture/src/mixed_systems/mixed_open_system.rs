@@ -10,10 +10,19 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedHamiltonianSystem, MixedLindbladNoiseSystem, OperateOnMixedSystems};
-use crate::{OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use super::{
+    shift_key, MixedHamiltonianSystem, MixedLindbladNoiseSystem, MixedSubsystemKind,
+    OperateOnMixedSystems, SubsystemRearrangement,
+};
+use crate::{
+    hermitian_term_count, hilbert_space_dimension, sparse_matrix_memory,
+    split_canonical_open_system, CanonicalText, MetadataMergePolicy, OpenSystem,
+    OperateOnDensityMatrix, StruqtureError, SystemDimension,
+};
 use qoqo_calculator::CalculatorFloat;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::ops;
 use tinyvec::TinyVec;
@@ -64,6 +73,28 @@ pub struct MixedLindbladOpenSystem {
 
 impl crate::MinSupportedVersion for MixedLindbladOpenSystem {}
 
+impl crate::SerializeCanonical for MixedLindbladOpenSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("MixedLindbladOpenSystem", 2)?;
+        state.serialize_field("system", &crate::Canonical(&self.system))?;
+        state.serialize_field("noise", &crate::Canonical(&self.noise))?;
+        state.end()
+    }
+}
+
+impl crate::OperateOnMetadata for MixedLindbladOpenSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.system.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.system.metadata
+    }
+}
+
 impl<'a> OpenSystem<'a> for MixedLindbladOpenSystem {
     type System = MixedHamiltonianSystem;
     type Noise = MixedLindbladNoiseSystem;
@@ -330,6 +361,247 @@ impl MixedLindbladOpenSystem {
             noise: MixedLindbladNoiseSystem::new(number_spins, number_bosons, number_fermions),
         }
     }
+
+    /// Appends a spin subsystem to the MixedLindbladOpenSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins in the new spin subsystem.
+    pub fn add_spin_subsystem(&mut self, number_spins: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Append(number_spins),
+        )
+        .expect("Internal bug in add_spin_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a bosonic subsystem to the MixedLindbladOpenSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_bosons` - The number of boson modes in the new bosonic subsystem.
+    pub fn add_boson_subsystem(&mut self, number_bosons: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Append(number_bosons),
+        )
+        .expect("Internal bug in add_boson_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a fermionic subsystem to the MixedLindbladOpenSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_fermions` - The number of fermion modes in the new fermionic subsystem.
+    pub fn add_fermion_subsystem(&mut self, number_fermions: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Append(number_fermions),
+        )
+        .expect("Internal bug in add_fermion_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Removes a spin subsystem from the MixedLindbladOpenSystem.
+    ///
+    /// The following spin subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladOpenSystem, in that case the MixedLindbladOpenSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The spin subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems or a term acts on the subsystem.
+    pub fn remove_spin_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a bosonic subsystem from the MixedLindbladOpenSystem.
+    ///
+    /// The following bosonic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladOpenSystem, in that case the MixedLindbladOpenSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The bosonic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems or a term acts on the subsystem.
+    pub fn remove_boson_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a fermionic subsystem from the MixedLindbladOpenSystem.
+    ///
+    /// The following fermionic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedLindbladOpenSystem, in that case the MixedLindbladOpenSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The fermionic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems or a term acts on the subsystem.
+    pub fn remove_fermion_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Reorders the subsystems of one kind of the MixedLindbladOpenSystem, rewriting all terms accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the reordered subsystems.
+    /// * `order` - The new order of the subsystems, the new subsystem i is the previous subsystem order\[i\].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The subsystems were reordered.
+    /// * `Err(StruqtureError::GenericError)` - The order is not a permutation of the subsystems of the kind.
+    pub fn permute_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        order: &[usize],
+    ) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(kind, SubsystemRearrangement::Permute(order))
+    }
+
+    /// Combines two MixedLindbladOpenSystems acting on disjoint sets of spins and modes into one MixedLindbladOpenSystem.
+    ///
+    /// Both MixedLindbladOpenSystems must have the same number of subsystems of every kind. The spins and modes of all
+    /// subsystems of `other` are shifted by the same `index_offset`, which must not be smaller than the number of spins
+    /// or modes of any subsystem of `self`. The Hamiltonian and the noise of the result are the sums of the shifted
+    /// Hamiltonians and noises of both MixedLindbladOpenSystems.
+    /// The number of spins or modes of a subsystem of the result is only fixed if it is fixed for both MixedLindbladOpenSystems.
+    /// The metadata of both MixedLindbladOpenSystems is merged, keeping the values of `self` for keys contained in both.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedLindbladOpenSystem acting on the spins and modes starting at `index_offset`.
+    /// * `index_offset` - The index of the combined system that spin or mode 0 of every subsystem of `other` is shifted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The combined MixedLindbladOpenSystem.
+    /// * `Err(StruqtureError::GenericError)` - The systems have different numbers of subsystems or the shifted indices of `other` overlap with those of `self`.
+    pub fn direct_sum(&self, other: &Self, index_offset: usize) -> Result<Self, StruqtureError> {
+        let self_numbers = [
+            self.number_spins(),
+            self.number_bosonic_modes(),
+            self.number_fermionic_modes(),
+        ];
+        let other_numbers = [
+            other.number_spins(),
+            other.number_bosonic_modes(),
+            other.number_fermionic_modes(),
+        ];
+        for (kind, (self_number, other_number)) in [
+            MixedSubsystemKind::Spin,
+            MixedSubsystemKind::Boson,
+            MixedSubsystemKind::Fermion,
+        ]
+        .iter()
+        .zip(self_numbers.iter().zip(other_numbers.iter()))
+        {
+            if self_number.len() != other_number.len() {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "The systems have {} and {} {:?} subsystems, a direct sum needs the same subsystems",
+                        self_number.len(),
+                        other_number.len(),
+                        kind
+                    ),
+                });
+            }
+            if let Some(number) = self_number.iter().find(|number| **number > index_offset) {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Index offset {} is smaller than the size {} of a {:?} subsystem of the first system, the indices of both systems would overlap",
+                        index_offset, number, kind
+                    ),
+                });
+            }
+        }
+        let combine = |self_fixed: &[Option<usize>],
+                       other_fixed: &[Option<usize>],
+                       other_number: &[usize]|
+         -> TinyVec<[Option<usize>; 2]> {
+            self_fixed
+                .iter()
+                .zip(other_fixed.iter().zip(other_number.iter()))
+                .map(|(self_fixed, (other_fixed, other_number))| {
+                    self_fixed
+                        .and(*other_fixed)
+                        .map(|_| index_offset + other_number)
+                })
+                .collect()
+        };
+        let number_spins = combine(
+            &self.system.number_spins,
+            &other.system.number_spins,
+            &other_numbers[0],
+        );
+        let number_bosons = combine(
+            &self.system.number_bosons,
+            &other.system.number_bosons,
+            &other_numbers[1],
+        );
+        let number_fermions = combine(
+            &self.system.number_fermions,
+            &other.system.number_fermions,
+            &other_numbers[2],
+        );
+        let mut combined = self.clone();
+        MetadataMergePolicy::PreferLeft
+            .merge(&mut combined.system.metadata, &other.system.metadata)?;
+        combined.system.number_spins = number_spins.clone();
+        combined.system.number_bosons = number_bosons.clone();
+        combined.system.number_fermions = number_fermions.clone();
+        combined.noise.number_spins = number_spins;
+        combined.noise.number_bosons = number_bosons;
+        combined.noise.number_fermions = number_fermions;
+        for (key, value) in other.system.iter() {
+            let (key, value) = shift_key(key, value.clone(), index_offset)?;
+            combined.system.add_operator_product(key, value)?;
+        }
+        for ((left, right), value) in other.noise.iter() {
+            let (left, value) = shift_key(left, value.clone(), index_offset)?;
+            let (right, value) = shift_key(right, value, index_offset)?;
+            combined.noise.add_operator_product((left, right), value)?;
+        }
+        Ok(combined)
+    }
+
+    /// Rearranges the subsystems of one kind of the system and the noise of the MixedLindbladOpenSystem.
+    ///
+    /// The MixedLindbladOpenSystem is only changed when the rearrangement succeeds for both.
+    fn rearrange_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        rearrangement: SubsystemRearrangement,
+    ) -> Result<(), StruqtureError> {
+        let mut system = self.system.clone();
+        system.rearrange_subsystems(kind, rearrangement)?;
+        let mut noise = self.noise.clone();
+        noise.rearrange_subsystems(kind, rearrangement)?;
+        self.system = system;
+        self.noise = noise;
+        Ok(())
+    }
 }
 
 /// Implements the negative sign function of MixedLindbladOpenSystem.
@@ -442,3 +714,89 @@ impl fmt::Display for MixedLindbladOpenSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for MixedLindbladOpenSystem {
+    /// Returns the canonical text representation of the MixedLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedLindbladOpenSystem.
+    fn to_canonical_text(&self) -> String {
+        format!(
+            "MixedLindbladOpenSystem\n{}{}",
+            self.system().to_canonical_text(),
+            self.noise().to_canonical_text()
+        )
+    }
+
+    /// Creates a MixedLindbladOpenSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedLindbladOpenSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedLindbladOpenSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedLindbladOpenSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (system, noise) = split_canonical_open_system(
+            text,
+            "MixedLindbladOpenSystem",
+            "MixedLindbladNoiseSystem",
+        )?;
+        Self::group(
+            MixedHamiltonianSystem::from_canonical_text(&system)?,
+            MixedLindbladNoiseSystem::from_canonical_text(&noise)?,
+        )
+    }
+}
+
+impl SystemDimension for MixedLindbladOpenSystem {
+    /// Returns the dimension of the Hilbert space of the MixedLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(
+            self.number_spins().iter().sum::<usize>()
+                + self.number_fermionic_modes().iter().sum::<usize>(),
+            self.number_bosonic_modes().iter().sum(),
+            fock_cutoffs,
+        )
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the MixedLindbladOpenSystem.
+    ///
+    /// The estimate is for the sparse superoperator of the Hamiltonian and the noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedLindbladOpenSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Hamiltonian terms contribute to the commutator twice, Lindblad terms three times
+        let entries_per_row =
+            2 * hermitian_term_count(self.system().keys()) + 3 * self.noise().len();
+        sparse_matrix_memory(dimension, entries_per_row, bytes_per_entry)
+    }
+}
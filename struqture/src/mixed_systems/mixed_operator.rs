@@ -10,13 +10,22 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{MixedIndex, MixedProduct, OperateOnMixedSystems};
+use super::{
+    HermitianMixedProduct, MixedHamiltonian, MixedIndex, MixedProduct, OperateOnMixedSystems,
+};
+use crate::bosons::BosonProduct;
+use crate::fermions::FermionProduct;
+use crate::spins::PauliProduct;
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, non_hermitian_terms_error, operator_powi,
+    parse_canonical_count, parse_canonical_text_entries, split_canonical_text, CanonicalText,
+    CoefficientEntry, ModeIndex, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    SpectralNormBound, SpinIndex, StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+    ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -69,6 +78,8 @@ pub struct MixedOperator {
     n_bosons: usize,
     /// Number of Fermion subsystems
     n_fermions: usize,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for MixedOperator {}
@@ -84,7 +95,7 @@ impl schemars::JsonSchema for MixedOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct MixedOperatorSerialize {
@@ -92,9 +103,49 @@ struct MixedOperatorSerialize {
     n_spins: usize,
     n_bosons: usize,
     n_fermions: usize,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for MixedOperatorSerialize {
+    /// Serialization function for MixedOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "MixedOperatorSerialize",
+            if skip_zero_term_policy { 5 } else { 6 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("n_spins", &self.n_spins)?;
+        state.serialize_field("n_bosons", &self.n_bosons)?;
+        state.serialize_field("n_fermions", &self.n_fermions)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<MixedOperatorSerialize> for MixedOperator {
     fn from(value: MixedOperatorSerialize) -> Self {
         let mut new_noise_op = MixedOperator::new(value.n_spins, value.n_bosons, value.n_fermions);
@@ -102,12 +153,14 @@ impl From<MixedOperatorSerialize> for MixedOperator {
             let _ =
                 new_noise_op.add_operator_product(key.clone(), CalculatorComplex::new(real, imag));
         }
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<MixedOperator> for MixedOperatorSerialize {
     fn from(value: MixedOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(MixedProduct, CalculatorFloat, CalculatorFloat)> = value
             .clone()
             .into_iter()
@@ -122,11 +175,21 @@ impl From<MixedOperator> for MixedOperatorSerialize {
             n_spins: value.n_spins,
             n_bosons: value.n_bosons,
             n_fermions: value.n_fermions,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for MixedOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = MixedOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedOperator {
     type Index = MixedProduct;
     type Value = CalculatorComplex;
@@ -171,10 +234,25 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(self.n_spins, self.n_bosons, self.n_fermions, cap),
             None => Self::new(self.n_spins, self.n_bosons, self.n_fermions),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the MixedOperator with the given (MixedProduct key, CalculatorComplex value) pair.
@@ -207,7 +285,7 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedOperator {
                 actual_number_fermion_subsystems: key.fermions().len(),
             });
         }
-        if value.re != CalculatorFloat::ZERO || value.im != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             // Catch on diagonals with non-zero imaginary values
             Ok(self.internal_map.insert(key, value))
         } else {
@@ -229,6 +307,18 @@ impl<'a> OperateOnState<'a> for MixedOperator {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for MixedOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        MixedProduct::new(
+            vec![PauliProduct::new(); self.n_spins],
+            vec![BosonProduct::default(); self.n_bosons],
+            vec![FermionProduct::default(); self.n_fermions],
+        )
+        .expect("Internal bug in creating the identity MixedProduct")
+    }
+}
+
 impl<'a> OperateOnMixedSystems<'a> for MixedOperator {
     // From trait
     fn number_spins(&self) -> Vec<usize> {
@@ -316,6 +406,7 @@ impl MixedOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -345,12 +436,157 @@ impl MixedOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
         }
     }
 
+    /// Returns a mutable handle to the coefficient of a key, see [CoefficientEntry].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The MixedProduct whose coefficient is accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CoefficientEntry<MixedProduct, CalculatorComplex>)` - The handle to the coefficient, which is zero if the key is not in the MixedOperator.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in key does not match the MixedOperator.
+    pub fn entry(
+        &mut self,
+        key: MixedProduct,
+    ) -> Result<CoefficientEntry<MixedProduct, CalculatorComplex>, StruqtureError> {
+        if key.spins().len() != self.n_spins
+            || key.bosons().len() != self.n_bosons
+            || key.fermions().len() != self.n_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: key.spins().len(),
+                actual_number_boson_subsystems: key.bosons().len(),
+                actual_number_fermion_subsystems: key.fermions().len(),
+            });
+        }
+        Ok(CoefficientEntry::new(
+            self.internal_map.entry(key),
+            CalculatorComplex::ZERO,
+            self.zero_term_policy,
+        ))
+    }
+
+    /// Raises the MixedOperator to a non-negative integer power.
+    ///
+    /// The power is computed by repeated squaring. Terms whose coefficients cancel exactly are removed
+    /// after every multiplication to keep the number of terms manageable.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power, `powi(0)` is the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedOperator raised to the power n.
+    /// * `Err(StruqtureError)` - The multiplication of the MixedOperators failed.
+    pub fn powi(&self, n: usize) -> Result<Self, StruqtureError> {
+        let mut identity = MixedOperator::new(self.n_spins, self.n_bosons, self.n_fermions);
+        identity.add_operator_product(
+            MixedProduct::new(
+                vec![PauliProduct::new(); self.n_spins],
+                vec![BosonProduct::new([], [])?; self.n_bosons],
+                vec![FermionProduct::new([], [])?; self.n_fermions],
+            )?,
+            CalculatorComplex::from(1.0),
+        )?;
+        operator_powi(self, n, identity, |left, right| {
+            Ok((left * right)?.truncate(f64::MIN_POSITIVE))
+        })
+    }
+
+    /// Returns whether the MixedOperator is hermitian.
+    ///
+    /// Symbolic coefficients are compared structurally.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the MixedOperator equals its hermitian conjugate.
+    pub fn is_hermitian(&self) -> bool {
+        self.iter().all(|(product, value)| {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            *self.get(&conjugated_product) == value.conj() * prefactor
+        })
+    }
+
+    /// Returns the hermitian part (A + A^dagger) / 2 of the MixedOperator A.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian part of the MixedOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn hermitian_part(&self) -> Self {
+        self.hermitian_split_part(1.0)
+    }
+
+    /// Returns the anti-hermitian part (A - A^dagger) / 2 of the MixedOperator A.
+    ///
+    /// The MixedOperator is the sum of its hermitian and anti-hermitian parts.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anti-hermitian part of the MixedOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn anti_hermitian_part(&self) -> Self {
+        self.hermitian_split_part(-1.0)
+    }
+
+    /// Returns (A + sign * A^dagger) / 2 for the MixedOperator A.
+    fn hermitian_split_part(&self, sign: f64) -> Self {
+        let mut part = self.empty_clone(Some(2 * self.len()));
+        for (product, value) in self.iter() {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            part.add_operator_product(product.clone(), value.clone() * 0.5)
+                .expect("Internal bug in add_operator_product");
+            part.add_operator_product(conjugated_product, value.conj() * (0.5 * sign * prefactor))
+                .expect("Internal bug in add_operator_product");
+        }
+        part
+    }
+
+    /// Converts the MixedOperator into a MixedHamiltonian if it is hermitian.
+    ///
+    /// In contrast to `MixedHamiltonian::try_from`, terms stored together with their hermitian conjugate are accepted
+    /// and the error lists all terms violating hermiticity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedHamiltonian)` - The MixedOperator as a MixedHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianTerms)` - The keys and residuals A - A^dagger of the terms violating hermiticity.
+    pub fn try_into_hamiltonian(&self) -> Result<MixedHamiltonian, StruqtureError> {
+        if !self.is_hermitian() {
+            return Err(non_hermitian_terms_error(self.anti_hermitian_part().iter()));
+        }
+        let mut hamiltonian = MixedHamiltonian::new(self.n_spins, self.n_bosons, self.n_fermions);
+        for (product, value) in self.iter() {
+            // Only one term of every hermitian conjugate pair corresponds to a valid hermitian product
+            if let Ok(hermitian_product) = HermitianMixedProduct::new(
+                product.spins().cloned(),
+                product.bosons().cloned(),
+                product.fermions().cloned(),
+            ) {
+                hamiltonian.add_operator_product(hermitian_product, value.clone())?;
+            }
+        }
+        Ok(hamiltonian)
+    }
+
     // /// Separate self into an operator with the terms of given number of spins, bosons and fermions and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -382,6 +618,51 @@ impl MixedOperator {
     // }
 }
 
+impl From<MixedHamiltonian> for MixedOperator {
+    /// Converts a MixedHamiltonian into a MixedOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The MixedHamiltonian to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedHamiltonian converted into a MixedOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn from(hamiltonian: MixedHamiltonian) -> Self {
+        let mut internal = MixedOperator::with_capacity(
+            hamiltonian.number_spins().len(),
+            hamiltonian.number_bosonic_modes().len(),
+            hamiltonian.number_fermionic_modes().len(),
+            2 * hamiltonian.len(),
+        );
+        internal
+            .set_zero_term_policy(hamiltonian.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
+        for (key, value) in hamiltonian.into_iter() {
+            let product = MixedProduct::new(
+                key.spins().cloned(),
+                key.bosons().cloned(),
+                key.fermions().cloned(),
+            )
+            .expect("Internal error in MixedProduct::new");
+            if !key.is_natural_hermitian() {
+                let (conjugated_product, prefactor) = product.hermitian_conjugate();
+                internal
+                    .add_operator_product(conjugated_product, value.conj() * prefactor)
+                    .expect("Internal error in add_operator_product");
+            }
+            internal
+                .add_operator_product(product, value)
+                .expect("Internal error in add_operator_product");
+        }
+        internal
+    }
+}
+
 /// Implements the negative sign function of MixedOperator.
 ///
 impl ops::Neg for MixedOperator {
@@ -392,6 +673,7 @@ impl ops::Neg for MixedOperator {
     ///
     /// * `Self` - The MixedOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
         let n_bosons = self.n_bosons;
@@ -401,6 +683,7 @@ impl ops::Neg for MixedOperator {
         }
         MixedOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -477,6 +760,7 @@ where
     ///
     /// * `Self` - The MixedOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
@@ -485,8 +769,10 @@ where
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         MixedOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -515,6 +801,60 @@ impl ops::Mul<MixedOperator> for MixedOperator {
             self.n_fermions,
             self.len() * other.len(),
         );
+        op.zero_term_policy = self.zero_term_policy;
+        for (bps, vals) in self {
+            for (bpo, valo) in other.iter() {
+                let mixed_products = (bps.clone() * bpo.clone())?;
+                let coefficient = Into::<CalculatorComplex>::into(valo) * vals.clone();
+                for (b, coeff) in mixed_products {
+                    op.add_operator_product(b, coefficient.clone() * coeff)?;
+                }
+            }
+        }
+        Ok(op)
+    }
+}
+
+/// Implements the multiplication function of MixedOperator by MixedHamiltonian.
+///
+impl ops::Mul<MixedHamiltonian> for MixedOperator {
+    type Output = Result<MixedOperator, StruqtureError>;
+    /// Implement `*` for MixedOperator and MixedHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedHamiltonian to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedOperator)` - The MixedOperator multiplied by the MixedHamiltonian.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in the MixedOperator and the MixedHamiltonian do not match.
+    fn mul(self, other: MixedHamiltonian) -> Self::Output {
+        let (other_spins, other_bosons, other_fermions) = (
+            other.number_spins().len(),
+            other.number_bosonic_modes().len(),
+            other.number_fermionic_modes().len(),
+        );
+        if self.n_spins != other_spins
+            || self.n_bosons != other_bosons
+            || self.n_fermions != other_fermions
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.n_spins,
+                target_number_boson_subsystems: self.n_bosons,
+                target_number_fermion_subsystems: self.n_fermions,
+                actual_number_spin_subsystems: other_spins,
+                actual_number_boson_subsystems: other_bosons,
+                actual_number_fermion_subsystems: other_fermions,
+            });
+        }
+        let mut op = MixedOperator::with_capacity(
+            self.n_spins,
+            self.n_bosons,
+            self.n_fermions,
+            self.len() * other.len(),
+        );
+        op.zero_term_policy = self.zero_term_policy;
         for (bps, vals) in self {
             for (bpo, valo) in other.iter() {
                 let mixed_products = (bps.clone() * bpo.clone())?;
@@ -643,6 +983,80 @@ impl fmt::Display for MixedOperator {
     }
 }
 
+impl CanonicalText for MixedOperator {
+    /// Returns the canonical text representation of the MixedOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedOperator",
+            &[
+                ("number_spin_subsystems", self.n_spins.to_string()),
+                ("number_bosonic_subsystems", self.n_bosons.to_string()),
+                ("number_fermionic_subsystems", self.n_fermions.to_string()),
+            ],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedOperator",
+            &[
+                "number_spin_subsystems",
+                "number_bosonic_subsystems",
+                "number_fermionic_subsystems",
+            ],
+        )?;
+        let mut new_self = MixedOperator::new(
+            parse_canonical_count(header[0])?,
+            parse_canonical_count(header[1])?,
+            parse_canonical_count(header[2])?,
+        );
+        for (key, value) in parse_canonical_text_entries::<MixedProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for MixedOperator {
+    /// Returns an upper bound of the spectral norm of the MixedOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The MixedOperator contains symbolic coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on bosonic modes.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        if self.keys().any(|key| {
+            key.bosons()
+                .any(|boson| boson.number_creators() + boson.number_annihilators() > 0)
+        }) {
+            return Err(StruqtureError::GenericError {
+                msg: "The spectral norm of operators acting on bosonic modes is unbounded"
+                    .to_string(),
+            });
+        }
+        self.coefficient_1_norm()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
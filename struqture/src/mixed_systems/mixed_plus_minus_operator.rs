@@ -11,13 +11,19 @@
 // limitations under the License.
 
 use super::{MixedOperator, MixedPlusMinusProduct, MixedProduct, OperateOnMixedSystems};
+use crate::bosons::BosonProduct;
+use crate::fermions::FermionProduct;
+use crate::spins::PlusMinusProduct;
 use crate::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, parse_canonical_count,
+    parse_canonical_text_entries, split_canonical_text, CanonicalText, ModeIndex,
+    OperateOnConstant, OperateOnDensityMatrix, OperateOnState, SpectralNormBound, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -70,6 +76,8 @@ pub struct MixedPlusMinusOperator {
     n_bosons: usize,
     /// Number of Fermion subsystems
     n_fermions: usize,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for MixedPlusMinusOperator {
@@ -89,7 +97,7 @@ impl schemars::JsonSchema for MixedPlusMinusOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct MixedPlusMinusOperatorSerialize {
@@ -97,9 +105,49 @@ struct MixedPlusMinusOperatorSerialize {
     n_spins: usize,
     n_bosons: usize,
     n_fermions: usize,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for MixedPlusMinusOperatorSerialize {
+    /// Serialization function for MixedPlusMinusOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedPlusMinusOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedPlusMinusOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "MixedPlusMinusOperatorSerialize",
+            if skip_zero_term_policy { 5 } else { 6 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("n_spins", &self.n_spins)?;
+        state.serialize_field("n_bosons", &self.n_bosons)?;
+        state.serialize_field("n_fermions", &self.n_fermions)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<MixedPlusMinusOperatorSerialize> for MixedPlusMinusOperator {
     fn from(value: MixedPlusMinusOperatorSerialize) -> Self {
         let mut new_noise_op =
@@ -108,12 +156,14 @@ impl From<MixedPlusMinusOperatorSerialize> for MixedPlusMinusOperator {
             let _ =
                 new_noise_op.add_operator_product(key.clone(), CalculatorComplex::new(real, imag));
         }
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<MixedPlusMinusOperator> for MixedPlusMinusOperatorSerialize {
     fn from(value: MixedPlusMinusOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(MixedPlusMinusProduct, CalculatorFloat, CalculatorFloat)> = value
             .clone()
             .into_iter()
@@ -128,11 +178,21 @@ impl From<MixedPlusMinusOperator> for MixedPlusMinusOperatorSerialize {
             n_spins: value.n_spins,
             n_bosons: value.n_bosons,
             n_fermions: value.n_fermions,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for MixedPlusMinusOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = MixedPlusMinusOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedPlusMinusOperator {
     type Index = MixedPlusMinusProduct;
     type Value = CalculatorComplex;
@@ -177,10 +237,25 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedPlusMinusOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(self.n_spins, self.n_bosons, self.n_fermions, cap),
             None => Self::new(self.n_spins, self.n_bosons, self.n_fermions),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the MixedPlusMinusOperator with the given (MixedPlusMinusProduct key, CalculatorComplex value) pair.
@@ -213,7 +288,7 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedPlusMinusOperator {
                 actual_number_fermion_subsystems: key.fermions().len(),
             });
         }
-        if value.re != CalculatorFloat::ZERO || value.im != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             // Catch on diagonals with non-zero imaginary values
             Ok(self.internal_map.insert(key, value))
         } else {
@@ -235,6 +310,17 @@ impl<'a> OperateOnState<'a> for MixedPlusMinusOperator {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for MixedPlusMinusOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        MixedPlusMinusProduct::new(
+            vec![PlusMinusProduct::new(); self.n_spins],
+            vec![BosonProduct::default(); self.n_bosons],
+            vec![FermionProduct::default(); self.n_fermions],
+        )
+    }
+}
+
 impl<'a> OperateOnMixedSystems<'a> for MixedPlusMinusOperator {
     // From trait
     fn number_spins(&self) -> Vec<usize> {
@@ -322,6 +408,7 @@ impl MixedPlusMinusOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -351,6 +438,7 @@ impl MixedPlusMinusOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
             n_spins,
             n_bosons,
             n_fermions,
@@ -457,6 +545,7 @@ impl ops::Neg for MixedPlusMinusOperator {
     ///
     /// * `Self` - The MixedPlusMinusOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
         let n_bosons = self.n_bosons;
@@ -466,6 +555,7 @@ impl ops::Neg for MixedPlusMinusOperator {
         }
         MixedPlusMinusOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -542,6 +632,7 @@ where
     ///
     /// * `Self` - The MixedPlusMinusOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         let n_spins = self.n_spins;
@@ -550,8 +641,10 @@ where
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         MixedPlusMinusOperator {
             internal_map: internal,
+            zero_term_policy,
             n_spins,
             n_bosons,
             n_fermions,
@@ -679,6 +772,81 @@ impl fmt::Display for MixedPlusMinusOperator {
     }
 }
 
+impl CanonicalText for MixedPlusMinusOperator {
+    /// Returns the canonical text representation of the MixedPlusMinusOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedPlusMinusOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedPlusMinusOperator",
+            &[
+                ("number_spin_subsystems", self.n_spins.to_string()),
+                ("number_bosonic_subsystems", self.n_bosons.to_string()),
+                ("number_fermionic_subsystems", self.n_fermions.to_string()),
+            ],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedPlusMinusOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedPlusMinusOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedPlusMinusOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedPlusMinusOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedPlusMinusOperator",
+            &[
+                "number_spin_subsystems",
+                "number_bosonic_subsystems",
+                "number_fermionic_subsystems",
+            ],
+        )?;
+        let mut new_self = MixedPlusMinusOperator::new(
+            parse_canonical_count(header[0])?,
+            parse_canonical_count(header[1])?,
+            parse_canonical_count(header[2])?,
+        );
+        for (key, value) in
+            parse_canonical_text_entries::<MixedPlusMinusProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for MixedPlusMinusOperator {
+    /// Returns an upper bound of the spectral norm of the MixedPlusMinusOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The MixedPlusMinusOperator contains symbolic coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on bosonic modes.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        if self.keys().any(|key| {
+            key.bosons()
+                .any(|boson| boson.number_creators() + boson.number_annihilators() > 0)
+        }) {
+            return Err(StruqtureError::GenericError {
+                msg: "The spectral norm of operators acting on bosonic modes is unbounded"
+                    .to_string(),
+            });
+        }
+        self.coefficient_1_norm()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
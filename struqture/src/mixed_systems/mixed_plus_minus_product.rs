@@ -79,6 +79,8 @@ impl crate::MinSupportedVersion for MixedPlusMinusProduct {
     }
 }
 
+impl crate::SerializeCanonical for MixedPlusMinusProduct {}
+
 impl Serialize for MixedPlusMinusProduct {
     /// Serialization function for MixedPlusMinusProduct according to string type.
     ///
@@ -74,6 +74,8 @@ impl schemars::JsonSchema for MixedProduct {
 
 impl crate::MinSupportedVersion for MixedProduct {}
 
+impl crate::SerializeCanonical for MixedProduct {}
+
 impl Serialize for MixedProduct {
     /// Serialization function for MixedProduct according to string type.
     ///
@@ -436,6 +438,65 @@ impl SymmetricIndex for MixedProduct {
     }
 }
 
+impl MixedProduct {
+    /// Returns the HermitianMixedProduct and coefficient representing `value * self + h.c.`.
+    ///
+    /// Exactly one MixedProduct of every hermitian conjugate pair is a valid HermitianMixedProduct.
+    /// When self is the other one, its hermitian conjugate is used as key and the coefficient is
+    /// conjugated and multiplied with the sign picked up by the hermitian conjugation.
+    /// For naturally hermitian products only the real part of the value contributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The coefficient of self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((HermitianMixedProduct, CalculatorComplex))` - The key and coefficient of `value * self + h.c.` in a MixedHamiltonian.
+    /// * `Err(StruqtureError)` - Neither self nor its hermitian conjugate is a valid HermitianMixedProduct.
+    pub fn symmetrize(
+        &self,
+        value: qoqo_calculator::CalculatorComplex,
+    ) -> Result<(HermitianMixedProduct, qoqo_calculator::CalculatorComplex), StruqtureError> {
+        if let Ok(hermitian_product) = HermitianMixedProduct::new(
+            self.spins.iter().cloned(),
+            self.bosons.iter().cloned(),
+            self.fermions.iter().cloned(),
+        ) {
+            if self.is_natural_hermitian() {
+                // self^dagger = self, so value * self + h.c. = 2 Re(value) * self
+                let real_value = qoqo_calculator::CalculatorComplex::new(value.re * 2.0, 0.0);
+                return Ok((hermitian_product, real_value));
+            }
+            return Ok((hermitian_product, value));
+        }
+        let (conjugated_product, prefactor) = self.hermitian_conjugate();
+        let hermitian_product = HermitianMixedProduct::new(
+            conjugated_product.spins.iter().cloned(),
+            conjugated_product.bosons.iter().cloned(),
+            conjugated_product.fermions.iter().cloned(),
+        )?;
+        Ok((hermitian_product, value.conj() * prefactor))
+    }
+
+    /// Returns the HermitianMixedProduct and coefficient representing `i * (value * self - h.c.)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The coefficient of self.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((HermitianMixedProduct, CalculatorComplex))` - The key and coefficient of `i * (value * self - h.c.)` in a MixedHamiltonian.
+    /// * `Err(StruqtureError)` - Neither self nor its hermitian conjugate is a valid HermitianMixedProduct.
+    pub fn antisymmetrize(
+        &self,
+        value: qoqo_calculator::CalculatorComplex,
+    ) -> Result<(HermitianMixedProduct, qoqo_calculator::CalculatorComplex), StruqtureError> {
+        self.symmetrize(value * qoqo_calculator::CalculatorComplex::new(0.0, 1.0))
+    }
+}
+
 /// Implements the multiplication function of MixedProduct by MixedProduct.
 ///
 impl Mul<MixedProduct> for MixedProduct {
@@ -0,0 +1,202 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{HermitianMixedProduct, MixedIndex, MixedProduct};
+use crate::bosons::BosonProduct;
+use crate::fermions::FermionProduct;
+use crate::spins::PauliProduct;
+use crate::StruqtureError;
+use std::collections::BTreeMap;
+
+/// Builder for MixedProducts and HermitianMixedProducts with a fixed number of subsystems.
+///
+/// Only the subsystems the product acts on need to be set, all other subsystems are the identity.
+/// The number of subsystems is validated when the product is built.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use struqture::bosons::BosonProduct;
+/// use struqture::mixed_systems::{MixedProduct, MixedProductBuilder};
+/// use struqture::spins::PauliProduct;
+///
+/// let mp = MixedProductBuilder::new(2, 1, 0)
+///     .spin(1, PauliProduct::new().x(0))
+///     .boson(0, BosonProduct::new([0], [1]).unwrap())
+///     .build()
+///     .unwrap();
+/// assert_eq!(
+///     mp,
+///     MixedProduct::new(
+///         [PauliProduct::new(), PauliProduct::new().x(0)],
+///         [BosonProduct::new([0], [1]).unwrap()],
+///         [],
+///     )
+///     .unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MixedProductBuilder {
+    /// The number of spin subsystems.
+    number_spin_subsystems: usize,
+    /// The number of bosonic subsystems.
+    number_boson_subsystems: usize,
+    /// The number of fermionic subsystems.
+    number_fermion_subsystems: usize,
+    /// The PauliProducts of the spin subsystems that have been set.
+    spins: BTreeMap<usize, PauliProduct>,
+    /// The BosonProducts of the bosonic subsystems that have been set.
+    bosons: BTreeMap<usize, BosonProduct>,
+    /// The FermionProducts of the fermionic subsystems that have been set.
+    fermions: BTreeMap<usize, FermionProduct>,
+}
+
+impl MixedProductBuilder {
+    /// Creates a new MixedProductBuilder acting as the identity on all subsystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spin_subsystems` - The number of spin subsystems of the product.
+    /// * `number_boson_subsystems` - The number of bosonic subsystems of the product.
+    /// * `number_fermion_subsystems` - The number of fermionic subsystems of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new MixedProductBuilder.
+    pub fn new(
+        number_spin_subsystems: usize,
+        number_boson_subsystems: usize,
+        number_fermion_subsystems: usize,
+    ) -> Self {
+        MixedProductBuilder {
+            number_spin_subsystems,
+            number_boson_subsystems,
+            number_fermion_subsystems,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the PauliProduct acting on a spin subsystem.
+    ///
+    /// Setting the same subsystem again replaces the previous PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem.
+    /// * `product` - The PauliProduct acting on the subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The builder with the subsystem set.
+    pub fn spin(mut self, index: usize, product: PauliProduct) -> Self {
+        self.spins.insert(index, product);
+        self
+    }
+
+    /// Sets the BosonProduct acting on a bosonic subsystem.
+    ///
+    /// Setting the same subsystem again replaces the previous BosonProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem.
+    /// * `product` - The BosonProduct acting on the subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The builder with the subsystem set.
+    pub fn boson(mut self, index: usize, product: BosonProduct) -> Self {
+        self.bosons.insert(index, product);
+        self
+    }
+
+    /// Sets the FermionProduct acting on a fermionic subsystem.
+    ///
+    /// Setting the same subsystem again replaces the previous FermionProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem.
+    /// * `product` - The FermionProduct acting on the subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The builder with the subsystem set.
+    pub fn fermion(mut self, index: usize, product: FermionProduct) -> Self {
+        self.fermions.insert(index, product);
+        self
+    }
+
+    /// Builds the MixedProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedProduct)` - The MixedProduct acting on the subsystems that have been set.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - A subsystem index exceeds the number of subsystems of the builder.
+    pub fn build(&self) -> Result<MixedProduct, StruqtureError> {
+        self.build_index()
+    }
+
+    /// Builds the HermitianMixedProduct, for example to use it as a key of a MixedHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HermitianMixedProduct)` - The HermitianMixedProduct acting on the subsystems that have been set.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - A subsystem index exceeds the number of subsystems of the builder.
+    /// * `Err(StruqtureError::CreatorsAnnihilatorsMinimumIndex)` - The product is not in the hermitian ordering of HermitianMixedProduct.
+    pub fn build_hermitian(&self) -> Result<HermitianMixedProduct, StruqtureError> {
+        self.build_index()
+    }
+
+    /// Validates the subsystem indices and builds the mixed index.
+    fn build_index<T>(&self) -> Result<T, StruqtureError>
+    where
+        T: MixedIndex<
+            SpinIndexType = PauliProduct,
+            BosonicIndexType = BosonProduct,
+            FermionicIndexType = FermionProduct,
+        >,
+    {
+        let actual = |set: Option<&usize>, number: usize| set.map_or(number, |i| number.max(i + 1));
+        let actual_spins = actual(self.spins.keys().next_back(), self.number_spin_subsystems);
+        let actual_bosons = actual(self.bosons.keys().next_back(), self.number_boson_subsystems);
+        let actual_fermions = actual(
+            self.fermions.keys().next_back(),
+            self.number_fermion_subsystems,
+        );
+        if (actual_spins, actual_bosons, actual_fermions)
+            != (
+                self.number_spin_subsystems,
+                self.number_boson_subsystems,
+                self.number_fermion_subsystems,
+            )
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.number_spin_subsystems,
+                target_number_boson_subsystems: self.number_boson_subsystems,
+                target_number_fermion_subsystems: self.number_fermion_subsystems,
+                actual_number_spin_subsystems: actual_spins,
+                actual_number_boson_subsystems: actual_bosons,
+                actual_number_fermion_subsystems: actual_fermions,
+            });
+        }
+        T::new(
+            (0..self.number_spin_subsystems)
+                .map(|i| self.spins.get(&i).cloned().unwrap_or_default()),
+            (0..self.number_boson_subsystems)
+                .map(|i| self.bosons.get(&i).cloned().unwrap_or_default()),
+            (0..self.number_fermion_subsystems)
+                .map(|i| self.fermions.get(&i).cloned().unwrap_or_default()),
+        )
+    }
+}
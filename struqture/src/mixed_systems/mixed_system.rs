@@ -10,13 +10,29 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{HermitianOperateOnMixedSystems, MixedOperator, MixedProduct, OperateOnMixedSystems};
+use super::{
+    acts_on_subsystem, acts_only_on_subsystem, check_subsystem_index, rearrange_key,
+    rearrange_number_subsystems, HermitianOperateOnMixedSystems, MixedHamiltonianSystem,
+    MixedOperator, MixedProduct, MixedProductBuilder, MixedSubsystemKind, OperateOnMixedSystems,
+    SubsystemRearrangement,
+};
+use crate::bosons::{BosonOperator, BosonSystem};
+use crate::fermions::{FermionOperator, FermionSystem};
+use crate::metadata::merge_operand_metadata;
 #[cfg(feature = "json_schema")]
 use crate::mixed_systems::TinyVecDef;
 use crate::prelude::*;
-use crate::{OperateOnDensityMatrix, OperateOnState, StruqtureError};
+use crate::spins::{SpinOperator, SpinSystem};
+use crate::{
+    canonical_numbers, canonical_text_document, canonical_text_entries, hilbert_space_dimension,
+    parse_canonical_numbers, parse_canonical_text_entries, sparse_matrix_memory,
+    split_canonical_text, CanonicalText, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    SpectralNormBound, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -67,7 +83,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 ///
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 pub struct MixedSystem {
     /// The number of spins in each subsystem
     pub(crate) number_spins: TinyVec<[Option<usize>; 2]>,
@@ -77,6 +93,9 @@ pub struct MixedSystem {
     pub(crate) number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedOperator representing the Hamiltonian of the MixedSystem
     pub(crate) operator: MixedOperator,
+    /// Provenance metadata of the MixedSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 #[cfg(feature = "json_schema")]
@@ -106,10 +125,90 @@ struct SchemaHelperMixedSystem {
     number_fermions: TinyVec<[Option<usize>; 2]>,
     /// The MixedOperator representing the Hamiltonian of the MixedSystem
     pub(crate) operator: MixedOperator,
+    /// Provenance metadata of the MixedSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for MixedSystem {}
 
+impl Serialize for MixedSystem {
+    /// Serialization function for MixedSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - MixedSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of MixedSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for MixedSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a MixedSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - MixedSystem to be serialized.
+/// * `operator` - Serialization of the operator of the MixedSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of MixedSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &MixedSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("MixedSystem", if skip_metadata { 4 } else { 5 })?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("number_bosons", &system.number_bosons)?;
+    state.serialize_field("number_fermions", &system.number_fermions)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for MixedSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for MixedSystem {
     type Index = MixedProduct;
     type Value = CalculatorComplex;
@@ -149,26 +248,29 @@ impl<'a> OperateOnDensityMatrix<'a> for MixedSystem {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                operator: MixedOperator::with_capacity(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                    cap,
-                ),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins.clone(),
                 number_bosons: self.number_bosons.clone(),
                 number_fermions: self.number_fermions.clone(),
-                operator: MixedOperator::new(
-                    self.number_spins.len(),
-                    self.number_bosons.len(),
-                    self.number_fermions.len(),
-                ),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the MixedSystem with the given (MixedProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -284,6 +386,13 @@ impl<'a> OperateOnState<'a> for MixedSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for MixedSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        self.operator.identity_index()
+    }
+}
+
 impl<'a> OperateOnMixedSystems<'a> for MixedSystem {
     // From trait
     fn number_spins(&self) -> Vec<usize> {
@@ -390,6 +499,7 @@ impl MixedSystem {
             number_bosons,
             number_fermions,
             operator,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -425,6 +535,7 @@ impl MixedSystem {
             number_bosons,
             number_fermions,
             operator,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -490,12 +601,379 @@ impl MixedSystem {
                 number_bosons,
                 number_fermions,
                 operator,
+                metadata: BTreeMap::new(),
             })
         } else {
             Err(StruqtureError::NumberSpinsExceeded)
         }
     }
 
+    /// Creates a MixedSystem with a single spin subsystem from a SpinSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The SpinSystem acting on the spin subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedSystem with the terms of the SpinSystem.
+    pub fn from_spin_system(system: SpinSystem) -> Self {
+        Self::embed([system], [], [])
+    }
+
+    /// Creates a MixedSystem with a single bosonic subsystem from a BosonSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The BosonSystem acting on the bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedSystem with the terms of the BosonSystem.
+    pub fn from_boson_system(system: BosonSystem) -> Self {
+        Self::embed([], [system], [])
+    }
+
+    /// Creates a MixedSystem with a single fermionic subsystem from a FermionSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The FermionSystem acting on the fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedSystem with the terms of the FermionSystem.
+    pub fn from_fermion_system(system: FermionSystem) -> Self {
+        Self::embed([], [], [system])
+    }
+
+    /// Creates a MixedSystem from independent systems acting on one subsystem each.
+    ///
+    /// Every term of an input system acts on its own subsystem and as the identity on all other subsystems,
+    /// the MixedSystem is the sum of the input systems. The number of spins and modes of the subsystems is taken from the input systems.
+    ///
+    /// # Arguments
+    ///
+    /// * `spin_systems` - The SpinSystems, one for each spin subsystem.
+    /// * `boson_systems` - The BosonSystems, one for each bosonic subsystem.
+    /// * `fermion_systems` - The FermionSystems, one for each fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The MixedSystem of the combined subsystems.
+    pub fn embed(
+        spin_systems: impl IntoIterator<Item = SpinSystem>,
+        boson_systems: impl IntoIterator<Item = BosonSystem>,
+        fermion_systems: impl IntoIterator<Item = FermionSystem>,
+    ) -> Self {
+        let spin_systems: Vec<SpinSystem> = spin_systems.into_iter().collect();
+        let boson_systems: Vec<BosonSystem> = boson_systems.into_iter().collect();
+        let fermion_systems: Vec<FermionSystem> = fermion_systems.into_iter().collect();
+        let mut embedded = MixedSystem::new(
+            spin_systems.iter().map(|system| system.number_spins),
+            boson_systems.iter().map(|system| system.number_modes),
+            fermion_systems.iter().map(|system| system.number_modes),
+        );
+        let builder = embedded.product_builder();
+        let mut add = |key: Result<_, StruqtureError>, value: CalculatorComplex| {
+            key.and_then(|key| embedded.add_operator_product(key, value))
+                .expect("Internal bug in embed. The terms of the input systems should fit into the subsystems of the MixedSystem.")
+        };
+        for (index, system) in spin_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(builder.clone().spin(index, product).build(), value);
+            }
+        }
+        for (index, system) in boson_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(builder.clone().boson(index, product).build(), value);
+            }
+        }
+        for (index, system) in fermion_systems.into_iter().enumerate() {
+            for (product, value) in system {
+                add(builder.clone().fermion(index, product).build(), value);
+            }
+        }
+        embedded
+    }
+
+    /// Returns a MixedProductBuilder with the number of subsystems of the MixedSystem.
+    ///
+    /// The MixedProduct keys of the MixedSystem can be built with `build`.
+    ///
+    /// # Returns
+    ///
+    /// * `MixedProductBuilder` - The builder acting as the identity on all subsystems.
+    pub fn product_builder(&self) -> MixedProductBuilder {
+        MixedProductBuilder::new(
+            self.number_spins.len(),
+            self.number_bosons.len(),
+            self.number_fermions.len(),
+        )
+    }
+
+    /// Returns the terms of the MixedSystem that act non-trivially on one subsystem.
+    ///
+    /// The terms may act on other subsystems as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the subsystem.
+    /// * `index` - The index of the subsystem among the subsystems of the same kind.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator)` - Iterator over the MixedProducts acting on the subsystem and their coefficients.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of subsystems of the kind.
+    pub fn terms_acting_on_subsystem(
+        &self,
+        kind: MixedSubsystemKind,
+        index: usize,
+    ) -> Result<impl Iterator<Item = (&MixedProduct, &CalculatorComplex)>, StruqtureError> {
+        check_subsystem_index(
+            kind,
+            index,
+            (
+                self.number_spins.len(),
+                self.number_bosons.len(),
+                self.number_fermions.len(),
+            ),
+        )?;
+        Ok(self
+            .iter()
+            .filter(move |(key, _)| acts_on_subsystem(*key, kind, index)))
+    }
+
+    /// Returns the SpinOperator acting on one spin subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The operator acting on the spin subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems.
+    pub fn spin_subsystem_operator(&self, index: usize) -> Result<SpinOperator, StruqtureError> {
+        let kind = MixedSubsystemKind::Spin;
+        check_subsystem_index(kind, index, (self.number_spins.len(), 0, 0))?;
+        let mut operator = SpinOperator::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            operator.add_operator_product(
+                key.spins().nth(index).cloned().unwrap_or_default(),
+                value.clone(),
+            )?;
+        }
+        Ok(operator)
+    }
+
+    /// Returns the BosonOperator acting on one bosonic subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BosonOperator)` - The operator acting on the bosonic subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems.
+    pub fn boson_subsystem_operator(&self, index: usize) -> Result<BosonOperator, StruqtureError> {
+        let kind = MixedSubsystemKind::Boson;
+        check_subsystem_index(kind, index, (0, self.number_bosons.len(), 0))?;
+        let mut operator = BosonOperator::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            operator.add_operator_product(
+                key.bosons().nth(index).cloned().unwrap_or_default(),
+                value.clone(),
+            )?;
+        }
+        Ok(operator)
+    }
+
+    /// Returns the FermionOperator acting on one fermionic subsystem, formed by the terms that act as the identity on all other subsystems.
+    ///
+    /// A term acting as the identity on all subsystems is included as the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FermionOperator)` - The operator acting on the fermionic subsystem.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems.
+    pub fn fermion_subsystem_operator(
+        &self,
+        index: usize,
+    ) -> Result<FermionOperator, StruqtureError> {
+        let kind = MixedSubsystemKind::Fermion;
+        check_subsystem_index(kind, index, (0, 0, self.number_fermions.len()))?;
+        let mut operator = FermionOperator::new();
+        for (key, value) in self
+            .iter()
+            .filter(|(key, _)| acts_only_on_subsystem(*key, kind, index))
+        {
+            operator.add_operator_product(
+                key.fermions().nth(index).cloned().unwrap_or_default(),
+                value.clone(),
+            )?;
+        }
+        Ok(operator)
+    }
+
+    /// Appends a spin subsystem to the MixedSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins in the new spin subsystem.
+    pub fn add_spin_subsystem(&mut self, number_spins: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Append(number_spins),
+        )
+        .expect("Internal bug in add_spin_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a bosonic subsystem to the MixedSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_bosons` - The number of boson modes in the new bosonic subsystem.
+    pub fn add_boson_subsystem(&mut self, number_bosons: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Append(number_bosons),
+        )
+        .expect("Internal bug in add_boson_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Appends a fermionic subsystem to the MixedSystem, on which all terms act as the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_fermions` - The number of fermion modes in the new fermionic subsystem.
+    pub fn add_fermion_subsystem(&mut self, number_fermions: Option<usize>) {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Append(number_fermions),
+        )
+        .expect("Internal bug in add_fermion_subsystem. Appending a subsystem on which all terms act as the identity cannot fail.")
+    }
+
+    /// Removes a spin subsystem from the MixedSystem.
+    ///
+    /// The following spin subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedSystem, in that case the MixedSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The spin subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of spin subsystems or a term acts on the subsystem.
+    pub fn remove_spin_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Spin,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a bosonic subsystem from the MixedSystem.
+    ///
+    /// The following bosonic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedSystem, in that case the MixedSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the bosonic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The bosonic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of bosonic subsystems or a term acts on the subsystem.
+    pub fn remove_boson_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Boson,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Removes a fermionic subsystem from the MixedSystem.
+    ///
+    /// The following fermionic subsystems are shifted down by one index. Removing a subsystem that terms act on
+    /// non-trivially would change the MixedSystem, in that case the MixedSystem is left unchanged and an error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the fermionic subsystem to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The fermionic subsystem was removed.
+    /// * `Err(StruqtureError::GenericError)` - The index exceeds the number of fermionic subsystems or a term acts on the subsystem.
+    pub fn remove_fermion_subsystem(&mut self, index: usize) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(
+            MixedSubsystemKind::Fermion,
+            SubsystemRearrangement::Remove(index),
+        )
+    }
+
+    /// Reorders the subsystems of one kind of the MixedSystem, rewriting all terms accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the reordered subsystems.
+    /// * `order` - The new order of the subsystems, the new subsystem i is the previous subsystem order\[i\].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The subsystems were reordered.
+    /// * `Err(StruqtureError::GenericError)` - The order is not a permutation of the subsystems of the kind.
+    pub fn permute_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        order: &[usize],
+    ) -> Result<(), StruqtureError> {
+        self.rearrange_subsystems(kind, SubsystemRearrangement::Permute(order))
+    }
+
+    /// Rearranges the subsystems of one kind of the MixedSystem, rewriting all terms.
+    ///
+    /// The MixedSystem is only changed when the rearrangement succeeds.
+    pub(crate) fn rearrange_subsystems(
+        &mut self,
+        kind: MixedSubsystemKind,
+        rearrangement: SubsystemRearrangement,
+    ) -> Result<(), StruqtureError> {
+        let (number_spins, number_bosons, number_fermions) = rearrange_number_subsystems(
+            kind,
+            rearrangement,
+            &self.number_spins,
+            &self.number_bosons,
+            &self.number_fermions,
+        )?;
+        let mut rearranged =
+            MixedSystem::with_capacity(number_spins, number_bosons, number_fermions, self.len());
+        for (key, value) in self.iter() {
+            let (key, value) = rearrange_key(key, value.clone(), kind, rearrangement)?;
+            rearranged.add_operator_product(key, value)?;
+        }
+        *self = rearranged;
+        Ok(())
+    }
+
     // /// Separate self into an operator with the terms of given number of spins, bosons and fermions and an operator with the remaining operations
     // ///
     // /// # Arguments
@@ -546,7 +1024,7 @@ impl ops::Neg for MixedSystem {
 ///
 impl<T, V> ops::Add<T> for MixedSystem
 where
-    T: IntoIterator<Item = (MixedProduct, V)>,
+    T: IntoIterator<Item = (MixedProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -561,6 +1039,7 @@ where
     /// * `Ok(Self)` - The two MixedSystems added together.
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -572,7 +1051,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for MixedSystem
 where
-    T: IntoIterator<Item = (MixedProduct, V)>,
+    T: IntoIterator<Item = (MixedProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -587,6 +1066,7 @@ where
     /// * `Ok(Self)` - The two MixedSystems subtracted.
     /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -652,6 +1132,61 @@ impl ops::Mul<MixedSystem> for MixedSystem {
             self.number_fermions.clone(),
             capacity,
         );
+        spin_op.set_zero_term_policy(self.zero_term_policy())?;
+        spin_op.metadata = self.metadata.clone();
+        merge_operand_metadata::<MixedSystem>(&mut spin_op.metadata, &other);
+        for (pps, vals) in self {
+            for (ppo, valo) in other.iter() {
+                let products = (pps.clone() * ppo.clone())?;
+                for (ppp, coefficient) in products {
+                    let coefficient =
+                        Into::<CalculatorComplex>::into(valo) * vals.clone() * coefficient;
+                    spin_op.add_operator_product(ppp, coefficient)?;
+                }
+            }
+        }
+        Ok(spin_op)
+    }
+}
+
+/// Implements the multiplication function of MixedSystem by MixedHamiltonianSystem.
+///
+impl ops::Mul<MixedHamiltonianSystem> for MixedSystem {
+    type Output = Result<MixedSystem, StruqtureError>;
+    /// Implement `*` for MixedSystem and MixedHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The MixedHamiltonianSystem to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MixedSystem)` - The MixedSystem multiplied by the MixedHamiltonianSystem.
+    /// * `Err(StruqtureError::MissmatchedNumberSubsystems)` - Number of subsystems in system and key do not match.
+    fn mul(self, other: MixedHamiltonianSystem) -> Self::Output {
+        if self.number_spins.len() != other.number_spins.len()
+            || self.number_bosons.len() != other.number_bosons.len()
+            || self.number_fermions.len() != other.number_fermions.len()
+        {
+            return Err(StruqtureError::MissmatchedNumberSubsystems {
+                target_number_spin_subsystems: self.number_spins.len(),
+                target_number_boson_subsystems: self.number_bosons.len(),
+                target_number_fermion_subsystems: self.number_fermions.len(),
+                actual_number_spin_subsystems: other.number_spins.len(),
+                actual_number_boson_subsystems: other.number_bosons.len(),
+                actual_number_fermion_subsystems: other.number_fermions.len(),
+            });
+        }
+        let capacity = self.len() * other.len();
+        let mut spin_op = MixedSystem::with_capacity(
+            self.number_spins.clone(),
+            self.number_bosons.clone(),
+            self.number_fermions.clone(),
+            capacity,
+        );
+        spin_op.set_zero_term_policy(self.zero_term_policy())?;
+        spin_op.metadata = self.metadata.clone();
+        merge_operand_metadata::<MixedHamiltonianSystem>(&mut spin_op.metadata, &other);
         for (pps, vals) in self {
             for (ppo, valo) in other.iter() {
                 let products = (pps.clone() * ppo.clone())?;
@@ -806,3 +1341,119 @@ impl fmt::Display for MixedSystem {
         write!(f, "{}", output)
     }
 }
+
+impl CanonicalText for MixedSystem {
+    /// Returns the canonical text representation of the MixedSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the MixedSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "MixedSystem",
+            &[
+                ("number_spins", canonical_numbers(&self.number_spins)),
+                (
+                    "number_bosonic_modes",
+                    canonical_numbers(&self.number_bosons),
+                ),
+                (
+                    "number_fermionic_modes",
+                    canonical_numbers(&self.number_fermions),
+                ),
+            ],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a MixedSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The MixedSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a MixedSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the MixedSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(
+            text,
+            "MixedSystem",
+            &[
+                "number_spins",
+                "number_bosonic_modes",
+                "number_fermionic_modes",
+            ],
+        )?;
+        let mut new_self = MixedSystem::new(
+            parse_canonical_numbers(header[0])?,
+            parse_canonical_numbers(header[1])?,
+            parse_canonical_numbers(header[2])?,
+        );
+        for (key, value) in parse_canonical_text_entries::<MixedProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for MixedSystem {
+    /// Returns an upper bound of the spectral norm of the MixedSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The MixedSystem contains symbolic coefficients.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on bosonic modes.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.operator().spectral_norm_bound()
+    }
+}
+
+impl SystemDimension for MixedSystem {
+    /// Returns the dimension of the Hilbert space of the MixedSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(
+            self.number_spins().iter().sum::<usize>()
+                + self.number_fermionic_modes().iter().sum::<usize>(),
+            self.number_bosonic_modes().iter().sum(),
+            fock_cutoffs,
+        )
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the MixedSystem.
+    ///
+    /// Every term of the MixedSystem has at most one non-zero entry per row of its sparse matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoff of every bosonic mode of the MixedSystem.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - The number of cutoffs does not match the number of bosonic modes.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(dimension, self.len(), bytes_per_entry)
+    }
+}
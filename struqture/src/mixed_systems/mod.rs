@@ -35,6 +35,7 @@ mod mixed_operator;
 mod mixed_plus_minus_operator;
 mod mixed_plus_minus_product;
 mod mixed_product;
+mod mixed_product_builder;
 mod mixed_system;
 
 use crate::{
@@ -44,6 +45,7 @@ use crate::{
 #[cfg(feature = "json_schema")]
 use mixed_noise_system::TinyVecDef;
 use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 pub use mixed_decoherence_product::MixedDecoherenceProduct;
@@ -57,8 +59,320 @@ pub use mixed_operator::MixedOperator;
 pub use mixed_plus_minus_operator::MixedPlusMinusOperator;
 pub use mixed_plus_minus_product::MixedPlusMinusProduct;
 pub use mixed_product::MixedProduct;
+pub use mixed_product_builder::MixedProductBuilder;
 pub use mixed_system::MixedSystem;
 
+/// The kind of a subsystem of a mixed system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MixedSubsystemKind {
+    /// A spin subsystem.
+    Spin,
+    /// A bosonic subsystem.
+    Boson,
+    /// A fermionic subsystem.
+    Fermion,
+}
+
+/// Checks that a subsystem index is smaller than the number of subsystems of the given kind.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the subsystem.
+/// * `index` - The index of the subsystem.
+/// * `number_subsystems` - The number of spin, boson and fermion subsystems.
+///
+/// # Returns
+///
+/// * `Ok(())` - The subsystem exists.
+/// * `Err(StruqtureError::GenericError)` - The index exceeds the number of subsystems of the kind.
+pub(crate) fn check_subsystem_index(
+    kind: MixedSubsystemKind,
+    index: usize,
+    number_subsystems: (usize, usize, usize),
+) -> Result<(), StruqtureError> {
+    let number = match kind {
+        MixedSubsystemKind::Spin => number_subsystems.0,
+        MixedSubsystemKind::Boson => number_subsystems.1,
+        MixedSubsystemKind::Fermion => number_subsystems.2,
+    };
+    if index >= number {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Subsystem index {} exceeds the number of {:?} subsystems {}",
+                index, kind, number
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Returns true if the mixed index acts non-trivially on the given subsystem.
+pub(crate) fn acts_on_subsystem<T: MixedIndex>(
+    key: &T,
+    kind: MixedSubsystemKind,
+    index: usize,
+) -> bool {
+    match kind {
+        MixedSubsystemKind::Spin => key
+            .spins()
+            .nth(index)
+            .map(|s| !s.is_empty())
+            .unwrap_or(false),
+        MixedSubsystemKind::Boson => key
+            .bosons()
+            .nth(index)
+            .map(|b| b.number_creators() + b.number_annihilators() > 0)
+            .unwrap_or(false),
+        MixedSubsystemKind::Fermion => key
+            .fermions()
+            .nth(index)
+            .map(|f| f.number_creators() + f.number_annihilators() > 0)
+            .unwrap_or(false),
+    }
+}
+
+/// Returns true if the mixed index acts as the identity on all subsystems except the given subsystem.
+pub(crate) fn acts_only_on_subsystem<T: MixedIndex>(
+    key: &T,
+    kind: MixedSubsystemKind,
+    index: usize,
+) -> bool {
+    let is_target = |target_kind: MixedSubsystemKind, target_index: usize| {
+        target_kind == kind && target_index == index
+    };
+    key.spins()
+        .enumerate()
+        .all(|(i, s)| is_target(MixedSubsystemKind::Spin, i) || s.is_empty())
+        && key.bosons().enumerate().all(|(i, b)| {
+            is_target(MixedSubsystemKind::Boson, i)
+                || b.number_creators() + b.number_annihilators() == 0
+        })
+        && key.fermions().enumerate().all(|(i, f)| {
+            is_target(MixedSubsystemKind::Fermion, i)
+                || f.number_creators() + f.number_annihilators() == 0
+        })
+}
+
+/// The number of spins, boson modes and fermion modes in each subsystem of a mixed system.
+type NumberSubsystems = (Vec<Option<usize>>, Vec<Option<usize>>, Vec<Option<usize>>);
+
+/// Rearrangement of the subsystems of one kind of a mixed system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubsystemRearrangement<'a> {
+    /// Appends a subsystem on which all terms act as the identity.
+    Append(Option<usize>),
+    /// Removes the subsystem with the given index.
+    Remove(usize),
+    /// Reorders the subsystems, the new subsystem i is the previous subsystem order\[i\].
+    Permute(&'a [usize]),
+}
+
+impl SubsystemRearrangement<'_> {
+    /// Rearranges a list with one entry per subsystem.
+    fn apply<I: Clone>(&self, items: &[I], appended: I) -> Vec<I> {
+        match self {
+            SubsystemRearrangement::Append(_) => {
+                let mut rearranged = items.to_vec();
+                rearranged.push(appended);
+                rearranged
+            }
+            SubsystemRearrangement::Remove(index) => items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i != index)
+                .map(|(_, item)| item.clone())
+                .collect(),
+            SubsystemRearrangement::Permute(order) => {
+                order.iter().map(|i| items[*i].clone()).collect()
+            }
+        }
+    }
+}
+
+/// Validates a rearrangement of subsystems and returns the rearranged number of spins and modes of the subsystems.
+///
+/// # Arguments
+///
+/// * `kind` - The kind of the rearranged subsystems.
+/// * `rearrangement` - The rearrangement of the subsystems.
+/// * `number_spins` - The number of spins in each spin subsystem.
+/// * `number_bosons` - The number of boson modes in each bosonic subsystem.
+/// * `number_fermions` - The number of fermion modes in each fermionic subsystem.
+///
+/// # Returns
+///
+/// * `Ok((number_spins, number_bosons, number_fermions))` - The rearranged number of spins and modes.
+/// * `Err(StruqtureError::GenericError)` - The removed subsystem does not exist or the order is not a permutation of the subsystems.
+pub(crate) fn rearrange_number_subsystems(
+    kind: MixedSubsystemKind,
+    rearrangement: SubsystemRearrangement,
+    number_spins: &[Option<usize>],
+    number_bosons: &[Option<usize>],
+    number_fermions: &[Option<usize>],
+) -> Result<NumberSubsystems, StruqtureError> {
+    let number_subsystems = match kind {
+        MixedSubsystemKind::Spin => number_spins.len(),
+        MixedSubsystemKind::Boson => number_bosons.len(),
+        MixedSubsystemKind::Fermion => number_fermions.len(),
+    };
+    match rearrangement {
+        SubsystemRearrangement::Append(_) => (),
+        SubsystemRearrangement::Remove(index) => check_subsystem_index(
+            kind,
+            index,
+            (number_subsystems, number_subsystems, number_subsystems),
+        )?,
+        SubsystemRearrangement::Permute(order) => {
+            let mut sorted_order = order.to_vec();
+            sorted_order.sort_unstable();
+            if sorted_order != (0..number_subsystems).collect::<Vec<usize>>() {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Order {:?} is not a permutation of the {} {:?} subsystems",
+                        order, number_subsystems, kind
+                    ),
+                });
+            }
+        }
+    }
+    let appended = match rearrangement {
+        SubsystemRearrangement::Append(number) => number,
+        _ => None,
+    };
+    let rearrange = |numbers: &[Option<usize>], target_kind: MixedSubsystemKind| {
+        if target_kind == kind {
+            rearrangement.apply(numbers, appended)
+        } else {
+            numbers.to_vec()
+        }
+    };
+    Ok((
+        rearrange(number_spins, MixedSubsystemKind::Spin),
+        rearrange(number_bosons, MixedSubsystemKind::Boson),
+        rearrange(number_fermions, MixedSubsystemKind::Fermion),
+    ))
+}
+
+/// Rearranges the subsystems of a mixed index.
+///
+/// The rearrangement must have been validated with `rearrange_number_subsystems`.
+/// The rearranged index is brought into its valid form, transforming the value accordingly.
+///
+/// # Arguments
+///
+/// * `key` - The mixed index to rearrange.
+/// * `value` - The coefficient of the mixed index.
+/// * `kind` - The kind of the rearranged subsystems.
+/// * `rearrangement` - The rearrangement of the subsystems.
+///
+/// # Returns
+///
+/// * `Ok((T, CalculatorComplex))` - The rearranged mixed index and the transformed value.
+/// * `Err(StruqtureError::GenericError)` - The mixed index acts on a removed subsystem.
+pub(crate) fn rearrange_key<T: MixedIndex>(
+    key: &T,
+    value: CalculatorComplex,
+    kind: MixedSubsystemKind,
+    rearrangement: SubsystemRearrangement,
+) -> Result<(T, CalculatorComplex), StruqtureError> {
+    if let SubsystemRearrangement::Remove(index) = rearrangement {
+        if acts_on_subsystem(key, kind, index) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot remove {:?} subsystem {}, term {} acts on it",
+                    kind, index, key
+                ),
+            });
+        }
+    }
+    let spins: Vec<T::SpinIndexType> = key.spins().cloned().collect();
+    let bosons: Vec<T::BosonicIndexType> = key.bosons().cloned().collect();
+    let fermions: Vec<T::FermionicIndexType> = key.fermions().cloned().collect();
+    match kind {
+        MixedSubsystemKind::Spin => T::create_valid_pair(
+            rearrangement.apply(&spins, Default::default()),
+            bosons,
+            fermions,
+            value,
+        ),
+        MixedSubsystemKind::Boson => T::create_valid_pair(
+            spins,
+            rearrangement.apply(&bosons, Default::default()),
+            fermions,
+            value,
+        ),
+        MixedSubsystemKind::Fermion => T::create_valid_pair(
+            spins,
+            bosons,
+            rearrangement.apply(&fermions, Default::default()),
+            value,
+        ),
+    }
+}
+
+/// Shifts the spins and modes of all subsystems of a mixed index by the same offset.
+///
+/// The shifted index is brought into its valid form, transforming the value accordingly.
+///
+/// # Arguments
+///
+/// * `key` - The mixed index to shift.
+/// * `value` - The coefficient of the mixed index.
+/// * `offset` - The offset added to every spin and mode index.
+///
+/// # Returns
+///
+/// * `Ok((T, CalculatorComplex))` - The shifted mixed index and the transformed value.
+/// * `Err(StruqtureError)` - The shifted index could not be constructed.
+pub(crate) fn shift_key<T: MixedIndex>(
+    key: &T,
+    value: CalculatorComplex,
+    offset: usize,
+) -> Result<(T, CalculatorComplex), StruqtureError>
+where
+    T::SpinIndexType: SpinIndex,
+    T::BosonicIndexType: ModeIndex,
+    T::FermionicIndexType: ModeIndex,
+{
+    let spins: Vec<T::SpinIndexType> = key
+        .spins()
+        .map(|spins| {
+            let mapping: HashMap<usize, usize> = spins
+                .iter()
+                .map(|(index, _)| (*index, index + offset))
+                .collect();
+            spins.remap_qubits(&mapping)
+        })
+        .collect();
+    let mut value = value;
+    let mut bosons: Vec<T::BosonicIndexType> = Vec::with_capacity(key.bosons().len());
+    for modes in key.bosons() {
+        let (shifted, prefactor) = shift_modes(modes, offset)?;
+        value = value * prefactor;
+        bosons.push(shifted);
+    }
+    let mut fermions: Vec<T::FermionicIndexType> = Vec::with_capacity(key.fermions().len());
+    for modes in key.fermions() {
+        let (shifted, prefactor) = shift_modes(modes, offset)?;
+        value = value * prefactor;
+        fermions.push(shifted);
+    }
+    T::create_valid_pair(spins, bosons, fermions, value)
+}
+
+/// Shifts the modes of a mode index by an offset, returning the shifted index and the resulting prefactor.
+fn shift_modes<I: ModeIndex>(
+    modes: &I,
+    offset: usize,
+) -> Result<(I, CalculatorComplex), StruqtureError> {
+    let mapping: HashMap<usize, usize> = modes
+        .creators()
+        .chain(modes.annihilators())
+        .map(|mode| (*mode, mode + offset))
+        .collect();
+    modes.relabel(&mapping)
+}
+
 /// Trait for all index types requires converting between index types
 pub trait MixedIndex:
     std::hash::Hash
@@ -0,0 +1,58 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::bosons::{BosonHamiltonianSystem, HermitianBosonProduct};
+use crate::prelude::*;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+
+/// Creates the Hamiltonian of the Bose-Hubbard chain.
+///
+/// H = - t \sum_{i=0}^{n-2} (b_i^{\dagger} b_{i+1} + h.c.) + u / 2 \sum_{i=0}^{n-1} n_i (n_i - 1)
+///
+/// # Arguments
+///
+/// * `number_modes` - The number of sites in the chain.
+/// * `t` - The hopping between neighbouring sites.
+/// * `u` - The on-site interaction.
+///
+/// # Returns
+///
+/// * `BosonHamiltonianSystem` - The Hamiltonian of the chain.
+pub fn bose_hubbard(
+    number_modes: usize,
+    t: impl Into<CalculatorFloat>,
+    u: impl Into<CalculatorFloat>,
+) -> BosonHamiltonianSystem {
+    let hopping = CalculatorComplex::from(-t.into());
+    // n_i (n_i - 1) = b_i^{dagger} b_i^{dagger} b_i b_i
+    let interaction = CalculatorComplex::from(u.into() * 0.5);
+    let mut hamiltonian = BosonHamiltonianSystem::new(Some(number_modes));
+    for mode in 0..number_modes {
+        if mode + 1 < number_modes {
+            hamiltonian
+                .add_operator_product(
+                    HermitianBosonProduct::new([mode], [mode + 1])
+                        .expect("Internal bug in HermitianBosonProduct::new."),
+                    hopping.clone(),
+                )
+                .expect("Internal bug in add_operator_product.");
+        }
+        hamiltonian
+            .add_operator_product(
+                HermitianBosonProduct::new([mode, mode], [mode, mode])
+                    .expect("Internal bug in HermitianBosonProduct::new."),
+                interaction.clone(),
+            )
+            .expect("Internal bug in add_operator_product.");
+    }
+    hamiltonian
+}
@@ -0,0 +1,253 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+use crate::spins::{PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator};
+use crate::StruqtureError;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::{BTreeSet, HashMap};
+
+/// The three Cartesian components of the collective spin.
+const COMPONENTS: [SingleSpinOperator; 3] = [
+    SingleSpinOperator::X,
+    SingleSpinOperator::Y,
+    SingleSpinOperator::Z,
+];
+
+/// Returns the collective spin component J_a = 1/2 \sum_i a_i as a SpinOperator.
+fn collective_spin_operator(qubits: &[usize], component: SingleSpinOperator) -> SpinOperator {
+    let mut operator = SpinOperator::new();
+    for qubit in qubits.iter().collect::<BTreeSet<&usize>>() {
+        operator
+            .add_operator_product(
+                PauliProduct::new().set_pauli(*qubit, component),
+                CalculatorComplex::from(0.5),
+            )
+            .expect("Internal bug in add_operator_product.");
+    }
+    operator
+}
+
+/// Returns the symmetrized product (J_a J_b + J_b J_a) / 2 of two collective spin components.
+fn symmetrized_product(
+    qubits: &[usize],
+    first: SingleSpinOperator,
+    second: SingleSpinOperator,
+) -> SpinHamiltonian {
+    let first_operator = collective_spin_operator(qubits, first);
+    let second_operator = collective_spin_operator(qubits, second);
+    let product = (first_operator.clone() * second_operator.clone()
+        + second_operator * first_operator)
+        * CalculatorComplex::from(0.5);
+    SpinHamiltonian::try_from(product)
+        .expect("Internal bug in symmetrized collective spin product.")
+}
+
+/// Creates the x-component of the collective spin of a set of qubits.
+///
+/// J_x = 1/2 \sum_{i} X_i
+///
+/// # Arguments
+///
+/// * `qubits` - The qubits forming the collective spin. Duplicates are ignored.
+///
+/// # Returns
+///
+/// * `SpinHamiltonian` - The collective spin operator.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::models::collective_spin_x;
+/// use struqture::prelude::*;
+/// use struqture::spins::PauliProduct;
+///
+/// let jx = collective_spin_x(&[0, 2]);
+/// assert_eq!(jx.len(), 2);
+/// assert_eq!(jx.get(&PauliProduct::new().x(2)), &CalculatorFloat::from(0.5));
+/// ```
+pub fn collective_spin_x(qubits: &[usize]) -> SpinHamiltonian {
+    SpinHamiltonian::try_from(collective_spin_operator(qubits, SingleSpinOperator::X))
+        .expect("Internal bug in collective spin construction.")
+}
+
+/// Creates the y-component of the collective spin of a set of qubits.
+///
+/// J_y = 1/2 \sum_{i} Y_i
+///
+/// # Arguments
+///
+/// * `qubits` - The qubits forming the collective spin. Duplicates are ignored.
+///
+/// # Returns
+///
+/// * `SpinHamiltonian` - The collective spin operator.
+pub fn collective_spin_y(qubits: &[usize]) -> SpinHamiltonian {
+    SpinHamiltonian::try_from(collective_spin_operator(qubits, SingleSpinOperator::Y))
+        .expect("Internal bug in collective spin construction.")
+}
+
+/// Creates the z-component of the collective spin of a set of qubits.
+///
+/// J_z = 1/2 \sum_{i} Z_i
+///
+/// # Arguments
+///
+/// * `qubits` - The qubits forming the collective spin. Duplicates are ignored.
+///
+/// # Returns
+///
+/// * `SpinHamiltonian` - The collective spin operator.
+pub fn collective_spin_z(qubits: &[usize]) -> SpinHamiltonian {
+    SpinHamiltonian::try_from(collective_spin_operator(qubits, SingleSpinOperator::Z))
+        .expect("Internal bug in collective spin construction.")
+}
+
+/// Creates the square of the total collective spin of a set of qubits.
+///
+/// J^2 = J_x^2 + J_y^2 + J_z^2 = 3n/4 + 1/2 \sum_{i<j} (X_i X_j + Y_i Y_j + Z_i Z_j)
+///
+/// # Arguments
+///
+/// * `qubits` - The qubits forming the collective spin. Duplicates are ignored.
+///
+/// # Returns
+///
+/// * `SpinHamiltonian` - The squared collective spin operator.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::models::collective_spin_squared;
+/// use struqture::prelude::*;
+/// use struqture::spins::PauliProduct;
+///
+/// let j_squared = collective_spin_squared(&[0, 1]);
+/// assert_eq!(j_squared.get(&PauliProduct::new()), &CalculatorFloat::from(1.5));
+/// assert_eq!(j_squared.get(&PauliProduct::new().z(0).z(1)), &CalculatorFloat::from(0.5));
+/// ```
+pub fn collective_spin_squared(qubits: &[usize]) -> SpinHamiltonian {
+    let mut squared = SpinHamiltonian::new();
+    for component in COMPONENTS {
+        squared = squared + symmetrized_product(qubits, component, component);
+    }
+    squared
+}
+
+/// Evaluates the expectation value of a SpinHamiltonian from expectation values of PauliProducts.
+///
+/// The expectation value of the identity (empty PauliProduct) defaults to one when it is not
+/// provided.
+///
+/// # Arguments
+///
+/// * `operator` - The SpinHamiltonian to evaluate.
+/// * `expectation_values` - The expectation values of the PauliProducts of the operator.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The expectation value of the operator.
+/// * `Err(StruqtureError::GenericError)` - The expectation value of a PauliProduct is missing.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient of the operator is symbolic.
+pub fn pauli_expectation_value(
+    operator: &SpinHamiltonian,
+    expectation_values: &HashMap<PauliProduct, f64>,
+) -> Result<f64, StruqtureError> {
+    let mut expectation_value = 0.0;
+    for (product, coefficient) in operator.iter() {
+        let product_value = match expectation_values.get(product) {
+            Some(value) => *value,
+            None if product.is_empty() => 1.0,
+            None => {
+                return Err(StruqtureError::GenericError {
+                    msg: format!("Expectation value of PauliProduct {} is missing.", product),
+                })
+            }
+        };
+        expectation_value += *coefficient.float()? * product_value;
+    }
+    Ok(expectation_value)
+}
+
+/// Evaluates the Wineland spin-squeezing parameter of a set of qubits.
+///
+/// ξ^2 = n min_{⊥} Var(J_⊥) / |<J>|^2
+///
+/// where the minimum is taken over all directions orthogonal to the mean collective spin <J>.
+/// Values below one indicate a spin-squeezed state.
+///
+/// # Arguments
+///
+/// * `qubits` - The qubits forming the collective spin. Duplicates are ignored.
+/// * `expectation_values` - The expectation values of all single-qubit and two-qubit PauliProducts on the qubits.
+///
+/// # Returns
+///
+/// * `Ok(f64)` - The spin-squeezing parameter.
+/// * `Err(StruqtureError::GenericError)` - The expectation value of a PauliProduct is missing.
+/// * `Err(StruqtureError::GenericError)` - The mean collective spin vanishes.
+pub fn spin_squeezing_parameter(
+    qubits: &[usize],
+    expectation_values: &HashMap<PauliProduct, f64>,
+) -> Result<f64, StruqtureError> {
+    let number_qubits = qubits.iter().collect::<BTreeSet<&usize>>().len() as f64;
+    let mut mean = [0.0; 3];
+    for (index, component) in COMPONENTS.into_iter().enumerate() {
+        let operator = SpinHamiltonian::try_from(collective_spin_operator(qubits, component))?;
+        mean[index] = pauli_expectation_value(&operator, expectation_values)?;
+    }
+    let mut covariance = [[0.0; 3]; 3];
+    for (first_index, first) in COMPONENTS.into_iter().enumerate() {
+        for (second_index, second) in COMPONENTS.into_iter().enumerate().skip(first_index) {
+            let value = pauli_expectation_value(
+                &symmetrized_product(qubits, first, second),
+                expectation_values,
+            )? - mean[first_index] * mean[second_index];
+            covariance[first_index][second_index] = value;
+            covariance[second_index][first_index] = value;
+        }
+    }
+
+    let mean_norm = mean.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if mean_norm <= f64::EPSILON {
+        return Err(StruqtureError::GenericError {
+            msg: "Spin-squeezing parameter is undefined for a vanishing mean collective spin."
+                .to_string(),
+        });
+    }
+    let direction = mean.map(|x| x / mean_norm);
+    // Orthonormal basis (u, v) of the plane orthogonal to the mean spin direction
+    let axis = (0..3)
+        .min_by(|a, b| direction[*a].abs().total_cmp(&direction[*b].abs()))
+        .unwrap_or(0);
+    let mut u = [0.0; 3];
+    u[axis] = 1.0;
+    u = [0, 1, 2].map(|i| u[i] - direction[axis] * direction[i]);
+    let u_norm = u.iter().map(|x| x * x).sum::<f64>().sqrt();
+    u = u.map(|x| x / u_norm);
+    let v = [
+        direction[1] * u[2] - direction[2] * u[1],
+        direction[2] * u[0] - direction[0] * u[2],
+        direction[0] * u[1] - direction[1] * u[0],
+    ];
+    let quadratic_form = |a: &[f64; 3], b: &[f64; 3]| -> f64 {
+        (0..3)
+            .map(|i| (0..3).map(|j| a[i] * covariance[i][j] * b[j]).sum::<f64>())
+            .sum()
+    };
+    let uu = quadratic_form(&u, &u);
+    let vv = quadratic_form(&v, &v);
+    let uv = quadratic_form(&u, &v);
+    let minimal_variance = (uu + vv) / 2.0 - (((uu - vv) / 2.0).powi(2) + uv * uv).sqrt();
+
+    Ok(number_qubits * minimal_variance / (mean_norm * mean_norm))
+}
@@ -0,0 +1,79 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
+use crate::prelude::*;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+
+/// Creates the Hamiltonian of the Fermi-Hubbard model on a rectangular lattice.
+///
+/// H = - t \sum_{<i,j>, \sigma} (c_{i \sigma}^{\dagger} c_{j \sigma} + h.c.) + u \sum_i n_{i \uparrow} n_{i \downarrow}
+///
+/// The lattice site (x, y) has the index i = x + nx * y and the spin-orbital (i, \sigma) is mapped
+/// to the fermionic mode 2 * i + \sigma, with \sigma = 0 for spin up and \sigma = 1 for spin down.
+///
+/// # Arguments
+///
+/// * `nx` - The number of lattice sites in x-direction.
+/// * `ny` - The number of lattice sites in y-direction (1 for a chain).
+/// * `t` - The hopping between neighbouring sites.
+/// * `u` - The on-site interaction.
+///
+/// # Returns
+///
+/// * `FermionHamiltonianSystem` - The Hamiltonian with 2 * nx * ny modes.
+pub fn fermi_hubbard(
+    nx: usize,
+    ny: usize,
+    t: impl Into<CalculatorFloat>,
+    u: impl Into<CalculatorFloat>,
+) -> FermionHamiltonianSystem {
+    let hopping = CalculatorComplex::from(-t.into());
+    // n_a n_b = - c_a^{dagger} c_b^{dagger} c_a c_b for a != b
+    let interaction = CalculatorComplex::from(-u.into());
+    let number_sites = nx * ny;
+    let mut hamiltonian = FermionHamiltonianSystem::new(Some(2 * number_sites));
+    for y in 0..ny {
+        for x in 0..nx {
+            let site = x + nx * y;
+            let mut neighbours: Vec<usize> = Vec::new();
+            if x + 1 < nx {
+                neighbours.push(site + 1);
+            }
+            if y + 1 < ny {
+                neighbours.push(site + nx);
+            }
+            for neighbour in neighbours {
+                for spin in 0..2 {
+                    hamiltonian
+                        .add_operator_product(
+                            HermitianFermionProduct::new([2 * site + spin], [2 * neighbour + spin])
+                                .expect("Internal bug in HermitianFermionProduct::new."),
+                            hopping.clone(),
+                        )
+                        .expect("Internal bug in add_operator_product.");
+                }
+            }
+            hamiltonian
+                .add_operator_product(
+                    HermitianFermionProduct::new(
+                        [2 * site, 2 * site + 1],
+                        [2 * site, 2 * site + 1],
+                    )
+                    .expect("Internal bug in HermitianFermionProduct::new."),
+                    interaction.clone(),
+                )
+                .expect("Internal bug in add_operator_product.");
+        }
+    }
+    hamiltonian
+}
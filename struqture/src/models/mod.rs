@@ -0,0 +1,34 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Module for constructing the Hamiltonians of common lattice models.
+//!
+//! The builders return the corresponding HamiltonianSystem with the number of spins or modes
+//! fixed to the size of the lattice. All parameters can be symbolic.
+//! Open boundary conditions are used for all lattices.
+//!
+//! Additionally, the module provides collective spin observables (J_x, J_y, J_z, J^2) on subsets
+//! of qubits and helpers to evaluate them, and the spin-squeezing parameter, from measured
+//! expectation values of PauliProducts.
+
+mod boson_models;
+mod collective_spin;
+mod fermion_models;
+mod spin_models;
+
+pub use boson_models::bose_hubbard;
+pub use collective_spin::{
+    collective_spin_squared, collective_spin_x, collective_spin_y, collective_spin_z,
+    pauli_expectation_value, spin_squeezing_parameter,
+};
+pub use fermion_models::fermi_hubbard;
+pub use spin_models::{heisenberg_chain, transverse_field_ising};
@@ -0,0 +1,100 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+use crate::spins::{PauliProduct, SpinHamiltonianSystem};
+use qoqo_calculator::CalculatorFloat;
+
+/// Creates the Hamiltonian of the transverse field Ising chain.
+///
+/// H = - j \sum_{i=0}^{n-2} Z_i Z_{i+1} - h \sum_{i=0}^{n-1} X_i
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins in the chain.
+/// * `j` - The Ising coupling between neighbouring spins.
+/// * `h` - The transverse field.
+///
+/// # Returns
+///
+/// * `SpinHamiltonianSystem` - The Hamiltonian of the chain.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::models::transverse_field_ising;
+/// use struqture::prelude::*;
+/// use struqture::spins::PauliProduct;
+///
+/// let hamiltonian = transverse_field_ising(3, 1.0, 0.5);
+/// assert_eq!(hamiltonian.number_spins(), 3);
+/// assert_eq!(hamiltonian.get(&PauliProduct::new().z(0).z(1)), &CalculatorFloat::from(-1.0));
+/// assert_eq!(hamiltonian.get(&PauliProduct::new().x(2)), &CalculatorFloat::from(-0.5));
+/// ```
+pub fn transverse_field_ising(
+    number_spins: usize,
+    j: impl Into<CalculatorFloat>,
+    h: impl Into<CalculatorFloat>,
+) -> SpinHamiltonianSystem {
+    let j: CalculatorFloat = j.into();
+    let h: CalculatorFloat = h.into();
+    let mut hamiltonian = SpinHamiltonianSystem::new(Some(number_spins));
+    for spin in 0..number_spins {
+        if spin + 1 < number_spins {
+            hamiltonian
+                .add_operator_product(PauliProduct::new().z(spin).z(spin + 1), -j.clone())
+                .expect("Internal bug in add_operator_product.");
+        }
+        hamiltonian
+            .add_operator_product(PauliProduct::new().x(spin), -h.clone())
+            .expect("Internal bug in add_operator_product.");
+    }
+    hamiltonian
+}
+
+/// Creates the Hamiltonian of the anisotropic Heisenberg (XYZ) chain.
+///
+/// H = \sum_{i=0}^{n-2} jx X_i X_{i+1} + jy Y_i Y_{i+1} + jz Z_i Z_{i+1}
+///
+/// # Arguments
+///
+/// * `number_spins` - The number of spins in the chain.
+/// * `jx` - The coupling of the X components of neighbouring spins.
+/// * `jy` - The coupling of the Y components of neighbouring spins.
+/// * `jz` - The coupling of the Z components of neighbouring spins.
+///
+/// # Returns
+///
+/// * `SpinHamiltonianSystem` - The Hamiltonian of the chain.
+pub fn heisenberg_chain(
+    number_spins: usize,
+    jx: impl Into<CalculatorFloat>,
+    jy: impl Into<CalculatorFloat>,
+    jz: impl Into<CalculatorFloat>,
+) -> SpinHamiltonianSystem {
+    let jx: CalculatorFloat = jx.into();
+    let jy: CalculatorFloat = jy.into();
+    let jz: CalculatorFloat = jz.into();
+    let mut hamiltonian = SpinHamiltonianSystem::new(Some(number_spins));
+    for spin in 0..number_spins.saturating_sub(1) {
+        hamiltonian
+            .add_operator_product(PauliProduct::new().x(spin).x(spin + 1), jx.clone())
+            .expect("Internal bug in add_operator_product.");
+        hamiltonian
+            .add_operator_product(PauliProduct::new().y(spin).y(spin + 1), jy.clone())
+            .expect("Internal bug in add_operator_product.");
+        hamiltonian
+            .add_operator_product(PauliProduct::new().z(spin).z(spin + 1), jz.clone())
+            .expect("Internal bug in add_operator_product.");
+    }
+    hamiltonian
+}
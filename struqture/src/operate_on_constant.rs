@@ -0,0 +1,115 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Constant energy offsets of operators and Hamiltonians.
+
+use crate::{OperateOnDensityMatrix, StruqtureError, TruncateTrait};
+use std::ops::{Add, Mul};
+
+/// Trait for operators and Hamiltonians with a constant energy offset.
+///
+/// The constant term is the coefficient of the identity index. It is stored like any other term,
+/// so it takes part in arithmetic, serialization and matrix export.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonian};
+///
+/// let mut sh = SpinHamiltonian::new();
+/// sh.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5)).unwrap();
+/// sh.set_constant(CalculatorFloat::from(-1.0)).unwrap();
+///
+/// // Functions provided in this :
+/// assert_eq!(sh.constant(), &CalculatorFloat::from(-1.0));
+/// assert_eq!(sh.get(&PauliProduct::new()), &CalculatorFloat::from(-1.0));
+/// assert_eq!(sh.without_constant().len(), 1);
+/// ```
+///
+pub trait OperateOnConstant<'a>:
+    OperateOnDensityMatrix<'a>
+    + IntoIterator<Item = (Self::Index, Self::Value)>
+    + FromIterator<(Self::Index, Self::Value)>
+    + Extend<(Self::Index, Self::Value)>
+    + PartialEq
+    + Clone
+where
+    Self: 'a,
+    &'a Self: IntoIterator,
+    Self::Index: Clone,
+    Self::Value: Mul<f64, Output = Self::Value>,
+    Self::Value: Add<Self::Value, Output = Self::Value>,
+    Self::Value: Clone,
+    Self::Value: TruncateTrait,
+    Self::IteratorType: ExactSizeIterator<Item = (&'a Self::Index, &'a Self::Value)>,
+    Self::KeyIteratorType: ExactSizeIterator<Item = &'a Self::Index>,
+    Self::ValueIteratorType: ExactSizeIterator<Item = &'a Self::Value>,
+{
+    /// Returns the identity index of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::Index` - The index acting as the identity on all subsystems of Self.
+    fn identity_index(&self) -> Self::Index;
+
+    /// Returns the constant term of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `&Self::Value` - The coefficient of the identity index, zero if it is not set.
+    fn constant(&self) -> &Self::Value {
+        self.get(&self.identity_index())
+    }
+
+    /// Overwrites the constant term of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The new constant term.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Self::Value))` - The constant term was previously set to Self::Value.
+    /// * `Ok(None)` - The constant term was not previously set.
+    /// * `Err(StruqtureError)` - The identity index could not be set in Self.
+    fn set_constant(&mut self, value: Self::Value) -> Result<Option<Self::Value>, StruqtureError> {
+        let identity = self.identity_index();
+        self.set(identity, value)
+    }
+
+    /// Adds a value to the constant term of Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value added to the constant term.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The value was successfully added.
+    /// * `Err(StruqtureError)` - The identity index could not be added to Self.
+    fn add_constant(&mut self, value: Self::Value) -> Result<(), StruqtureError> {
+        let identity = self.identity_index();
+        self.add_operator_product(identity, value)
+    }
+
+    /// Returns a copy of Self without the constant term.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - Self with the identity index removed.
+    fn without_constant(&self) -> Self {
+        let mut new_self = self.clone();
+        new_self.remove(&self.identity_index());
+        new_self
+    }
+}
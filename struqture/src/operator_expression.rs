@@ -0,0 +1,311 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Human-readable sums of terms for writing and parsing operators.
+
+use crate::StruqtureError;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::str::FromStr;
+
+/// Trait for writing and parsing operators as human-readable sums of terms.
+///
+/// An expression is a sum of terms `coefficient*product`, for example `0.5*0X1Z - 1e-2*3Y + 2*theta*0Z`.
+/// The product is written in the string representation of the index, e.g. `0X1Z` for PauliProducts
+/// or `c0a1` for FermionProducts, and is always the last factor of a term. The factors before it form the
+/// coefficient: numbers, symbolic expressions and the imaginary unit `i`. A term without factors before the
+/// product has the coefficient one. Terms are separated by `+` or `-` outside of parentheses,
+/// whitespace is ignored and terms with the same product are added.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+///
+/// let so = SpinOperator::from_expression("0.5*0X1Z - 1e-2*3Y + 2*theta*0Z + i*2X").unwrap();
+/// assert_eq!(so.get(&PauliProduct::new().y(3)), &CalculatorComplex::from(-0.01));
+/// assert_eq!(so.get(&PauliProduct::new().z(0)), &CalculatorComplex::from("2*theta"));
+/// assert_eq!(so.get(&PauliProduct::new().x(2)), &CalculatorComplex::new(0.0, 1.0));
+///
+/// assert_eq!(SpinOperator::from_expression(&so.to_expression()).unwrap(), so);
+/// ```
+pub trait OperatorExpression: Sized {
+    /// Returns the expression of Self.
+    ///
+    /// Complex coefficients are written as separate terms for the real and imaginary part.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String;
+
+    /// Creates Self from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The operator represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to Self.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError>;
+}
+
+/// Trait for splitting coefficients into their real and imaginary part in expressions.
+pub(crate) trait ExpressionValue: Sized {
+    /// Returns the real and imaginary part of the coefficient.
+    fn expression_parts(&self) -> (CalculatorFloat, CalculatorFloat);
+
+    /// Creates the coefficient from its real and imaginary part.
+    fn from_expression_parts(
+        re: CalculatorFloat,
+        im: CalculatorFloat,
+    ) -> Result<Self, StruqtureError>;
+}
+
+impl ExpressionValue for CalculatorFloat {
+    fn expression_parts(&self) -> (CalculatorFloat, CalculatorFloat) {
+        (self.clone(), CalculatorFloat::ZERO)
+    }
+
+    fn from_expression_parts(
+        re: CalculatorFloat,
+        im: CalculatorFloat,
+    ) -> Result<Self, StruqtureError> {
+        if im == CalculatorFloat::ZERO {
+            Ok(re)
+        } else {
+            Err(StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Imaginary coefficient {} for an operator with real coefficients",
+                    im
+                ),
+            })
+        }
+    }
+}
+
+impl ExpressionValue for CalculatorComplex {
+    fn expression_parts(&self) -> (CalculatorFloat, CalculatorFloat) {
+        (self.re.clone(), self.im.clone())
+    }
+
+    fn from_expression_parts(
+        re: CalculatorFloat,
+        im: CalculatorFloat,
+    ) -> Result<Self, StruqtureError> {
+        Ok(CalculatorComplex::new(re, im))
+    }
+}
+
+/// Returns the expression of the terms of an operator, sorted by the string representation of their products.
+pub(crate) fn expression_from_terms<'b, I, V>(
+    entries: impl Iterator<Item = (&'b I, &'b V)>,
+) -> String
+where
+    I: std::fmt::Display + 'b,
+    V: ExpressionValue + 'b,
+{
+    let mut terms: Vec<(String, CalculatorFloat, &str)> = Vec::new();
+    for (key, value) in entries {
+        let (re, im) = value.expression_parts();
+        let product = key.to_string();
+        if im == CalculatorFloat::ZERO {
+            terms.push((product, re, ""));
+        } else {
+            if re != CalculatorFloat::ZERO {
+                terms.push((product.clone(), re, ""));
+            }
+            terms.push((product, im, "i*"));
+        }
+    }
+    terms.sort_by(|(left, _, left_unit), (right, _, right_unit)| {
+        left.cmp(right).then_with(|| left_unit.cmp(right_unit))
+    });
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    let mut expression = String::new();
+    for (position, (product, coefficient, unit)) in terms.iter().enumerate() {
+        let (negative, body) = match coefficient {
+            CalculatorFloat::Float(x) => {
+                (x.is_sign_negative() && *x != 0.0, format!("{:?}", x.abs()))
+            }
+            CalculatorFloat::Str(x) => (false, format!("({})", x)),
+        };
+        match (position, negative) {
+            (0, true) => expression.push('-'),
+            (0, false) => (),
+            (_, true) => expression.push_str(" - "),
+            (_, false) => expression.push_str(" + "),
+        }
+        expression.push_str(&format!("{}*{}{}", body, unit, product));
+    }
+    expression
+}
+
+/// Splits a string at the given separator characters outside of parentheses.
+///
+/// Signs of exponents in numbers like `1e-2` do not separate the string.
+/// Returns the separator preceding every part, None for the first part.
+fn split_outside_parentheses(
+    string: &str,
+    separators: &[char],
+) -> Result<Vec<(Option<char>, String)>, StruqtureError> {
+    let characters: Vec<char> = string.chars().collect();
+    let mut parts: Vec<(Option<char>, String)> = Vec::new();
+    let mut current = String::new();
+    let mut separator: Option<char> = None;
+    let mut depth: usize = 0;
+    for (position, character) in characters.iter().enumerate() {
+        match character {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| StruqtureError::FromStringFailed {
+                        msg: format!("Unmatched closing parenthesis in {}", string),
+                    })?
+            }
+            _ => (),
+        }
+        let is_exponent = position >= 2
+            && matches!(characters[position - 1], 'e' | 'E')
+            && is_mantissa(&characters[..position - 1]);
+        if depth == 0 && separators.contains(character) && !is_exponent {
+            if position > 0 {
+                parts.push((separator, current));
+                current = String::new();
+            }
+            separator = Some(*character);
+        } else {
+            current.push(*character);
+        }
+    }
+    if depth != 0 {
+        return Err(StruqtureError::FromStringFailed {
+            msg: format!("Unmatched opening parenthesis in {}", string),
+        });
+    }
+    parts.push((separator, current));
+    Ok(parts)
+}
+
+/// Checks whether the characters end in the mantissa of a number like `1.5` in `1.5e-2`.
+fn is_mantissa(characters: &[char]) -> bool {
+    let start = characters
+        .iter()
+        .rposition(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '.'))
+        .map_or(0, |position| position + 1);
+    let token = &characters[start..];
+    !token.is_empty()
+        && token.iter().any(|c| c.is_ascii_digit())
+        && token.iter().all(|c| c.is_ascii_digit() || *c == '.')
+}
+
+/// Removes parentheses enclosing a whole factor.
+fn strip_parentheses(factor: &str) -> &str {
+    if !(factor.starts_with('(') && factor.ends_with(')')) {
+        return factor;
+    }
+    let mut depth: usize = 0;
+    for (position, character) in factor.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+        // The opening parenthesis is closed before the end of the factor, e.g. (a)*(b)
+        if depth == 0 && position + 1 < factor.len() {
+            return factor;
+        }
+    }
+    &factor[1..factor.len() - 1]
+}
+
+/// Negates a coefficient of an expression.
+fn negate_expression_coefficient(coefficient: CalculatorFloat) -> CalculatorFloat {
+    match coefficient {
+        CalculatorFloat::Float(x) => CalculatorFloat::Float(-x),
+        CalculatorFloat::Str(x) => CalculatorFloat::Str(format!("-({})", x)),
+    }
+}
+
+/// Parses the terms of an operator from an expression.
+pub(crate) fn parse_expression_terms<I, V>(expression: &str) -> Result<Vec<(I, V)>, StruqtureError>
+where
+    I: FromStr<Err = StruqtureError>,
+    V: ExpressionValue,
+{
+    let compact: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact == "0" {
+        return Ok(Vec::new());
+    }
+    let mut terms: Vec<(I, V)> = Vec::new();
+    for (sign, term) in split_outside_parentheses(&compact, &['+', '-'])? {
+        let factors = split_outside_parentheses(&term, &['*'])?;
+        let (product, coefficient_factors) = match factors.split_last() {
+            Some((product, coefficient_factors))
+                if !product.1.is_empty()
+                    && coefficient_factors
+                        .iter()
+                        .all(|(_, factor)| !factor.is_empty()) =>
+            {
+                (product, coefficient_factors)
+            }
+            _ => {
+                return Err(StruqtureError::FromStringFailed {
+                    msg: format!("Term {} of expression {} is incomplete", term, expression),
+                })
+            }
+        };
+        let key = I::from_str(&product.1)?;
+
+        let imaginary_units = coefficient_factors
+            .iter()
+            .filter(|(_, factor)| factor == "i")
+            .count();
+        let factors: Vec<&str> = coefficient_factors
+            .iter()
+            .map(|(_, factor)| factor.as_str())
+            .filter(|factor| *factor != "i")
+            .collect();
+        let numbers: Option<Vec<f64>> = factors
+            .iter()
+            .map(|factor| f64::from_str(strip_parentheses(factor)).ok())
+            .collect();
+        let mut coefficient = match (numbers, factors.as_slice()) {
+            (Some(numbers), _) => CalculatorFloat::Float(numbers.iter().product()),
+            (None, [factor]) => CalculatorFloat::Str(strip_parentheses(factor).to_string()),
+            (None, _) => CalculatorFloat::Str(factors.join("*")),
+        };
+        if sign == Some('-') {
+            coefficient = negate_expression_coefficient(coefficient);
+        }
+        let (re, im) = match imaginary_units % 4 {
+            0 => (coefficient, CalculatorFloat::ZERO),
+            1 => (CalculatorFloat::ZERO, coefficient),
+            2 => (
+                negate_expression_coefficient(coefficient),
+                CalculatorFloat::ZERO,
+            ),
+            _ => (
+                CalculatorFloat::ZERO,
+                negate_expression_coefficient(coefficient),
+            ),
+        };
+        terms.push((key, V::from_expression_parts(re, im)?));
+    }
+    Ok(terms)
+}
@@ -10,18 +10,33 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use crate::latex::ToLatex;
+#[cfg(feature = "io")]
+pub use crate::migrate::Struqture2Json;
 pub use crate::mixed_systems::MixedIndex;
 pub use crate::mixed_systems::OperateOnMixedSystems;
 pub use crate::spins::OperateOnSpins;
+pub use crate::spins::SpinLocality;
 pub use crate::spins::ToSparseMatrixOperator;
 pub use crate::spins::ToSparseMatrixSuperOperator;
+pub use crate::AbsoluteValueTrait;
+pub use crate::ApproxEqTrait;
+pub use crate::CanonicalText;
 pub use crate::ConjugationTrait;
+pub use crate::DerivativeTrait;
 pub use crate::GetValue;
 pub use crate::ModeIndex;
 pub use crate::OpenSystem;
+pub use crate::OperateOnConstant;
 pub use crate::OperateOnDensityMatrix;
+pub use crate::OperateOnMetadata;
 pub use crate::OperateOnModes;
 pub use crate::OperateOnState;
+pub use crate::OperatorExpression;
+pub use crate::SerializeBinary;
+pub use crate::SerializeCanonical;
+pub use crate::SpectralNormBound;
 pub use crate::SpinIndex;
 pub use crate::SymmetricIndex;
+pub use crate::SystemDimension;
 pub use crate::TruncateTrait;
@@ -0,0 +1,148 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random sampling of Hamiltonian terms for the qDRIFT protocol.
+//!
+//! qDRIFT writes a Hamiltonian as H = Σ_j h_j H_j with h_j > 0 and replaces the time evolution
+//! exp(-iHt) by a product of N evolutions exp(-i λ t / N H_j), where every H_j is drawn independently
+//! with the probability p_j = h_j / λ and λ = Σ_j h_j is the normalization constant.
+//! A [QDriftSampler] is created from a Hamiltonian with `qdrift_sampler` and yields the drawn terms
+//! together with the unit factor H_j = (coefficient / |coefficient|) term.
+//! The random numbers are generated from the seed with SplitMix64, so a seed always gives the same sequence of terms.
+//!
+//! # Example
+//! ```
+//! use struqture::prelude::*;
+//! use struqture::spins::{PauliProduct, SpinHamiltonian};
+//!
+//! let mut hamiltonian = SpinHamiltonian::new();
+//! hamiltonian.add_operator_product(PauliProduct::new().z(0), 3.0.into()).unwrap();
+//! hamiltonian.add_operator_product(PauliProduct::new().x(1), (-1.0).into()).unwrap();
+//!
+//! let sampler = hamiltonian.qdrift_sampler(42).unwrap();
+//! assert_eq!(sampler.lambda(), 4.0);
+//! for (product, sign) in sampler.take(10) {
+//!     // Evolve with exp(-i * lambda * t / 10 * sign * product)
+//!     if product == PauliProduct::new().z(0) {
+//!         assert_eq!(sign, 1.0);
+//!     } else {
+//!         assert_eq!(sign, -1.0);
+//!     }
+//! }
+//! ```
+
+use crate::StruqtureError;
+
+/// Iterator drawing the terms of a Hamiltonian with probabilities proportional to the absolute values of their coefficients.
+///
+/// Every item is a key of the Hamiltonian together with its coefficient divided by the absolute value of the coefficient.
+/// The iterator never ends, the number of drawn terms is chosen with e.g. `take`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QDriftSampler<I, V> {
+    /// The keys of the terms with their unit factors, sorted by key.
+    terms: Vec<(I, V)>,
+    /// The cumulative sums of the absolute values of the coefficients.
+    cumulative_weights: Vec<f64>,
+    /// The state of the SplitMix64 random number generator.
+    state: u64,
+}
+
+impl<I, V> QDriftSampler<I, V>
+where
+    I: Ord,
+{
+    /// Creates a new QDriftSampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `terms` - The keys of the terms with their unit factors and the absolute values of their coefficients.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new QDriftSampler.
+    /// * `Err(StruqtureError::GenericError)` - No term has a non-zero finite coefficient.
+    pub(crate) fn new(mut terms: Vec<(I, V, f64)>, seed: u64) -> Result<Self, StruqtureError> {
+        terms.retain(|(_, _, weight)| *weight > 0.0);
+        if terms.is_empty() || terms.iter().any(|(_, _, weight)| !weight.is_finite()) {
+            return Err(StruqtureError::GenericError {
+                msg: "qDRIFT sampling needs at least one term and finite coefficients".to_string(),
+            });
+        }
+        // Sorting the keys makes the sampled sequence independent of the order of the internal map
+        terms.sort_by(|left, right| left.0.cmp(&right.0));
+        let mut total = 0.0;
+        let mut cumulative_weights = Vec::with_capacity(terms.len());
+        let mut sorted_terms = Vec::with_capacity(terms.len());
+        for (key, factor, weight) in terms {
+            total += weight;
+            cumulative_weights.push(total);
+            sorted_terms.push((key, factor));
+        }
+        Ok(QDriftSampler {
+            terms: sorted_terms,
+            cumulative_weights,
+            state: seed,
+        })
+    }
+
+    /// Returns the normalization constant λ, the sum of the absolute values of the coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The normalization constant λ.
+    pub fn lambda(&self) -> f64 {
+        self.cumulative_weights.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the number of terms that can be drawn.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms with a non-zero coefficient.
+    pub fn number_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the next random number of the SplitMix64 generator, uniformly distributed in [0, 1).
+    fn next_uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // The upper 53 bits fill the mantissa of the float
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+impl<I, V> Iterator for QDriftSampler<I, V>
+where
+    I: Ord + Clone,
+    V: Clone,
+{
+    type Item = (I, V);
+
+    /// Draws the next term.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((I, V))` - The key of the drawn term and its coefficient divided by its absolute value.
+    fn next(&mut self) -> Option<Self::Item> {
+        let target = self.next_uniform() * self.lambda();
+        let index = self
+            .cumulative_weights
+            .partition_point(|weight| *weight <= target)
+            .min(self.terms.len() - 1);
+        Some(self.terms[index].clone())
+    }
+}
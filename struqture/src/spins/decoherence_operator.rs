@@ -15,11 +15,14 @@ use crate::fermions::FermionOperator;
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::spins::DecoherenceProduct;
 use crate::{
-    OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, parse_canonical_text_entries,
+    split_canonical_text, CanonicalText, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    SpectralNormBound, SpinIndex, StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+    ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -67,6 +70,8 @@ pub struct DecoherenceOperator {
     internal_map: IndexMap<DecoherenceProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<DecoherenceProduct, CalculatorComplex>,
+    /// The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for DecoherenceOperator {}
@@ -82,28 +87,68 @@ impl schemars::JsonSchema for DecoherenceOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct DecoherenceOperatorSerialize {
     /// The internal map representing the noise terms
     items: Vec<(DecoherenceProduct, CalculatorFloat, CalculatorFloat)>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for DecoherenceOperatorSerialize {
+    /// Serialization function for DecoherenceOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - DecoherenceOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of DecoherenceOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "DecoherenceOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<DecoherenceOperatorSerialize> for DecoherenceOperator {
     fn from(value: DecoherenceOperatorSerialize) -> Self {
-        let new_noise_op: DecoherenceOperator = value
+        let mut new_noise_op: DecoherenceOperator = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<DecoherenceOperator> for DecoherenceOperatorSerialize {
     fn from(value: DecoherenceOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(DecoherenceProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -114,11 +159,21 @@ impl From<DecoherenceOperator> for DecoherenceOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for DecoherenceOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = DecoherenceOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for DecoherenceOperator {
     type IteratorType = Iter<'a, Self::Index, Self::Value>;
     type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
@@ -163,10 +218,25 @@ impl<'a> OperateOnDensityMatrix<'a> for DecoherenceOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the DecoherenceOperator with the given (DecoherenceProduct key, CalculatorComplex value) pair.
@@ -186,7 +256,7 @@ impl<'a> OperateOnDensityMatrix<'a> for DecoherenceOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -214,6 +284,13 @@ impl<'a> OperateOnState<'a> for DecoherenceOperator {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for DecoherenceOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        DecoherenceProduct::new()
+    }
+}
+
 impl<'a> OperateOnSpins<'a> for DecoherenceOperator {
     /// Returns maximum index in DecoherenceOperator internal_map.
     ///
@@ -268,6 +345,7 @@ impl DecoherenceOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -286,6 +364,7 @@ impl DecoherenceOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -325,6 +404,7 @@ impl ops::Neg for DecoherenceOperator {
     ///
     /// * `Self` - The DecoherenceOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -334,6 +414,7 @@ impl ops::Neg for DecoherenceOperator {
         }
         DecoherenceOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -407,6 +488,7 @@ where
     ///
     /// * `Self` - The DecoherenceOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -415,8 +497,10 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         DecoherenceOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -436,6 +520,7 @@ impl ops::Mul<DecoherenceOperator> for DecoherenceOperator {
     /// * `Self` - The two DecoherenceOperators multiplied.
     fn mul(self, other: DecoherenceOperator) -> Self {
         let mut spin_op = DecoherenceOperator::with_capacity(self.len() * other.len());
+        spin_op.zero_term_policy = self.zero_term_policy;
         for (pps, vals) in self {
             for (ppo, valo) in other.iter() {
                 let (ppp, coefficient) = pps.clone() * ppo.clone();
@@ -545,6 +630,55 @@ impl fmt::Display for DecoherenceOperator {
     }
 }
 
+impl CanonicalText for DecoherenceOperator {
+    /// Returns the canonical text representation of the DecoherenceOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the DecoherenceOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "DecoherenceOperator",
+            &[],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a DecoherenceOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The DecoherenceOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a DecoherenceOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the DecoherenceOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "DecoherenceOperator", &[])?;
+        let mut new_self = DecoherenceOperator::new();
+        for (key, value) in
+            parse_canonical_text_entries::<DecoherenceProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for DecoherenceOperator {
+    /// Returns an upper bound of the spectral norm of the DecoherenceOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The DecoherenceOperator contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.coefficient_1_norm()
+    }
+}
+
 impl From<SpinOperator> for DecoherenceOperator {
     /// Converts a SpinOperator into a DecoherenceProduct.
     ///
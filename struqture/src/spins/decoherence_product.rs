@@ -272,6 +272,8 @@ impl schemars::JsonSchema for DecoherenceProduct {
 
 impl crate::MinSupportedVersion for DecoherenceProduct {}
 
+impl crate::SerializeCanonical for DecoherenceProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for DecoherenceProduct {
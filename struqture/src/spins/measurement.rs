@@ -0,0 +1,448 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PauliProduct, SingleSpinOperator, SpinHamiltonianSystem, SpinOperator};
+use crate::{AbsoluteValueTrait, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::cmp::Reverse;
+
+/// Single-qubit rotation mapping the measured Pauli basis of a qubit onto the Z basis.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BasisRotation {
+    /// Hadamard gate H, used to measure σ^x.
+    Hadamard,
+    /// S† gate followed by a Hadamard gate H, used to measure σ^y.
+    SDaggerHadamard,
+}
+
+/// Measurement setting of a group of qubit-wise commuting terms of a SpinOperator.
+///
+/// All terms of the group are measured at once by applying the basis rotations
+/// and reading out every qubit in the Z basis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasurementGroup {
+    /// The Pauli basis measured on every qubit the group acts on, sorted by qubit.
+    pub basis: Vec<(usize, SingleSpinOperator)>,
+    /// The rotations applied before the readout, qubits measured in the Z basis are not rotated.
+    pub rotations: Vec<(usize, BasisRotation)>,
+    /// The terms of the group, with the qubits whose Z-parity gives the value of the term and the coefficient of the term.
+    pub readout: Vec<(PauliProduct, Vec<usize>, CalculatorComplex)>,
+}
+
+/// Strategy distributing the measurement shots over the groups of [SpinOperator::measurement_circuits_info].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShotAllocationStrategy {
+    /// Every group acting on at least one qubit receives the same number of shots.
+    Uniform,
+    /// The shots of a group are proportional to the sum of the absolute values of its coefficients.
+    CoefficientWeight,
+    /// The shots of a group are proportional to the square root of the given single-shot variance
+    /// of its estimator, including the covariances between its terms, which minimizes the total variance.
+    Variances(Vec<f64>),
+}
+
+/// Number of shots assigned to one group of qubit-wise commuting terms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShotAllocation {
+    /// The measurement setting of the group.
+    pub group: MeasurementGroup,
+    /// The number of shots used to measure the group.
+    pub shots: usize,
+}
+
+impl MeasurementGroup {
+    /// Returns whether a PauliProduct can be measured in the basis of the group.
+    fn is_compatible(&self, product: &PauliProduct) -> bool {
+        product.iter().all(|(qubit, operator)| {
+            match self
+                .basis
+                .binary_search_by_key(qubit, |(measured_qubit, _)| *measured_qubit)
+            {
+                Ok(position) => self.basis[position].1 == *operator,
+                Err(_) => true,
+            }
+        })
+    }
+
+    /// Adds a PauliProduct compatible with the basis of the group to the group.
+    fn push(&mut self, product: &PauliProduct, value: &CalculatorComplex) {
+        let mut qubits = Vec::with_capacity(product.len());
+        for (qubit, operator) in product.iter() {
+            if let Err(position) = self
+                .basis
+                .binary_search_by_key(qubit, |(measured_qubit, _)| *measured_qubit)
+            {
+                self.basis.insert(position, (*qubit, *operator));
+                match operator {
+                    SingleSpinOperator::X => self.rotations.push((*qubit, BasisRotation::Hadamard)),
+                    SingleSpinOperator::Y => self
+                        .rotations
+                        .push((*qubit, BasisRotation::SDaggerHadamard)),
+                    _ => (),
+                }
+            }
+            qubits.push(*qubit);
+        }
+        self.readout.push((product.clone(), qubits, value.clone()));
+    }
+}
+
+impl SpinOperator {
+    /// Groups the terms of self into qubit-wise commuting sets and returns the measurement setting of every set.
+    ///
+    /// Two terms are qubit-wise commuting when they act with the same Pauli operator on every qubit they share.
+    /// The terms are assigned greedily to the first compatible group, starting with the terms acting on the most qubits.
+    /// The identity term is compatible with every group and has an empty readout.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<MeasurementGroup>` - The basis rotations and readout of every group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{BasisRotation, PauliProduct, SpinOperator};
+    ///
+    /// let mut so = SpinOperator::new();
+    /// so.add_operator_product(PauliProduct::new().x(0).y(1), 1.0.into()).unwrap();
+    /// so.add_operator_product(PauliProduct::new().x(0), 0.5.into()).unwrap();
+    /// so.add_operator_product(PauliProduct::new().z(0), 0.5.into()).unwrap();
+    ///
+    /// let groups = so.measurement_circuits_info();
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(
+    ///     groups[0].rotations,
+    ///     vec![(0, BasisRotation::Hadamard), (1, BasisRotation::SDaggerHadamard)]
+    /// );
+    /// assert_eq!(groups[0].readout[1].1, vec![0]);
+    /// assert!(groups[1].rotations.is_empty());
+    /// ```
+    pub fn measurement_circuits_info(&self) -> Vec<MeasurementGroup> {
+        let mut terms: Vec<(&PauliProduct, &CalculatorComplex)> = self.iter().collect();
+        terms.sort_by_key(|(product, _)| (Reverse(product.len()), *product));
+
+        let mut groups: Vec<MeasurementGroup> = Vec::new();
+        for (product, value) in terms {
+            match groups.iter_mut().find(|group| group.is_compatible(product)) {
+                Some(group) => group.push(product, value),
+                None => {
+                    let mut group = MeasurementGroup {
+                        basis: Vec::new(),
+                        rotations: Vec::new(),
+                        readout: Vec::new(),
+                    };
+                    group.push(product, value);
+                    groups.push(group);
+                }
+            }
+        }
+        for group in groups.iter_mut() {
+            group.rotations.sort();
+        }
+        groups
+    }
+    /// Distributes a number of measurement shots over the qubit-wise commuting groups of self.
+    ///
+    /// The groups are the ones returned by [SpinOperator::measurement_circuits_info]. Groups only containing
+    /// the identity term do not need to be measured and receive no shots. The rounding to integer shots
+    /// uses the largest remainder method, so that the shots add up to `total_shots`.
+    ///
+    /// # Arguments
+    ///
+    /// * `total_shots` - The total number of shots to distribute.
+    /// * `strategy` - The strategy weighting the groups.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ShotAllocation>)` - The groups with their number of shots.
+    /// * `Err(StruqtureError::GenericError)` - The number of variances does not match the number of groups or a variance is negative.
+    /// * `Err(StruqtureError::GenericError)` - A coefficient is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, ShotAllocationStrategy, SpinOperator};
+    ///
+    /// let mut so = SpinOperator::new();
+    /// so.add_operator_product(PauliProduct::new().x(0), 3.0.into()).unwrap();
+    /// so.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+    ///
+    /// let plan = so
+    ///     .shot_allocation(1000, ShotAllocationStrategy::CoefficientWeight)
+    ///     .unwrap();
+    /// assert_eq!(plan[0].shots, 750);
+    /// assert_eq!(plan[1].shots, 250);
+    /// ```
+    pub fn shot_allocation(
+        &self,
+        total_shots: usize,
+        strategy: ShotAllocationStrategy,
+    ) -> Result<Vec<ShotAllocation>, StruqtureError> {
+        let groups = self.measurement_circuits_info();
+        let weights: Vec<f64> = match strategy {
+            ShotAllocationStrategy::Uniform => groups
+                .iter()
+                .map(|group| if group.basis.is_empty() { 0.0 } else { 1.0 })
+                .collect(),
+            ShotAllocationStrategy::CoefficientWeight => groups
+                .iter()
+                .map(|group| {
+                    group
+                        .readout
+                        .iter()
+                        .filter(|(_, qubits, _)| !qubits.is_empty())
+                        .try_fold(0.0, |sum, (_, _, value)| Ok(sum + value.absolute_value()?))
+                })
+                .collect::<Result<Vec<f64>, StruqtureError>>()?,
+            ShotAllocationStrategy::Variances(variances) => {
+                if variances.len() != groups.len() {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Got {} variances for {} measurement groups",
+                            variances.len(),
+                            groups.len()
+                        ),
+                    });
+                }
+                if let Some(variance) = variances
+                    .iter()
+                    .find(|variance| !variance.is_finite() || **variance < 0.0)
+                {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!("Variance {} is not a finite non-negative number", variance),
+                    });
+                }
+                groups
+                    .iter()
+                    .zip(variances.iter())
+                    .map(|(group, variance)| {
+                        if group.basis.is_empty() {
+                            0.0
+                        } else {
+                            variance.sqrt()
+                        }
+                    })
+                    .collect()
+            }
+        };
+        let shots = largest_remainder_allocation(total_shots, &weights);
+        Ok(groups
+            .into_iter()
+            .zip(shots)
+            .map(|(group, shots)| ShotAllocation { group, shots })
+            .collect())
+    }
+
+    /// Estimates the expectation value of self from measured bitstrings.
+    ///
+    /// Bit i of a bitstring is the outcome of qubit i, with 0 for the eigenvalue +1 and 1 for the eigenvalue -1
+    /// of the Pauli operator measured on the qubit. Every term must be diagonal in the measured basis,
+    /// i.e. act on every qubit with the Pauli operator measured on that qubit.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - The measured bitstrings with the number of times they were measured.
+    /// * `basis` - The Pauli operator measured on every qubit, qubits that are not listed are measured in the Z basis.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Complex64, f64))` - The estimated expectation value and its standard error.
+    /// * `Err(StruqtureError::GenericError)` - A term is not diagonal in the basis, acts on a qubit beyond the bitstrings or no samples are given.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SingleSpinOperator, SpinOperator};
+    ///
+    /// let mut so = SpinOperator::new();
+    /// so.add_operator_product(PauliProduct::new().x(0).x(1), 1.0.into()).unwrap();
+    ///
+    /// // Measuring both qubits in the X basis, half of the outcomes have even parity
+    /// let basis = [(0, SingleSpinOperator::X), (1, SingleSpinOperator::X)];
+    /// let samples = [(0b00, 30), (0b11, 20), (0b01, 50)];
+    /// let (value, _) = so.expectation_from_samples(&samples, &basis).unwrap();
+    /// assert_eq!(value, Complex64::new(0.0, 0.0));
+    /// ```
+    pub fn expectation_from_samples(
+        &self,
+        samples: &[(u64, usize)],
+        basis: &[(usize, SingleSpinOperator)],
+    ) -> Result<(Complex64, f64), StruqtureError> {
+        let terms = self
+            .iter()
+            .map(|(product, value)| {
+                Ok((
+                    product,
+                    Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?),
+                ))
+            })
+            .collect::<Result<Vec<(&PauliProduct, Complex64)>, StruqtureError>>()?;
+        sample_estimate(&terms, samples, basis)
+    }
+}
+
+/// Distributes an integer total proportionally to weights with the largest remainder method.
+///
+/// # Arguments
+///
+/// * `total` - The integer total to distribute.
+/// * `weights` - The non-negative weights, nothing is distributed if all weights vanish.
+///
+/// # Returns
+///
+/// * `Vec<usize>` - The integer share of every weight.
+fn largest_remainder_allocation(total: usize, weights: &[f64]) -> Vec<usize> {
+    let weight_sum: f64 = weights.iter().sum();
+    if weight_sum <= 0.0 || !weight_sum.is_finite() {
+        return vec![0; weights.len()];
+    }
+    let exact: Vec<f64> = weights
+        .iter()
+        .map(|weight| total as f64 * weight / weight_sum)
+        .collect();
+    let mut shares: Vec<usize> = exact.iter().map(|share| share.floor() as usize).collect();
+    let mut order: Vec<usize> = (0..weights.len()).filter(|i| weights[*i] > 0.0).collect();
+    order.sort_by(|i, j| {
+        (exact[*j] - exact[*j].floor()).total_cmp(&(exact[*i] - exact[*i].floor()))
+    });
+    let remaining = total.saturating_sub(shares.iter().sum());
+    for index in order.into_iter().cycle().take(remaining) {
+        shares[index] += 1;
+    }
+    shares
+}
+
+impl SpinHamiltonianSystem {
+    /// Estimates the energy of self from measured bitstrings.
+    ///
+    /// The bitstrings and the basis follow the conventions of [SpinOperator::expectation_from_samples].
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - The measured bitstrings with the number of times they were measured.
+    /// * `basis` - The Pauli operator measured on every qubit, qubits that are not listed are measured in the Z basis.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((f64, f64))` - The estimated energy and its standard error.
+    /// * `Err(StruqtureError::GenericError)` - A term is not diagonal in the basis, acts on a qubit beyond the bitstrings or no samples are given.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn expectation_from_samples(
+        &self,
+        samples: &[(u64, usize)],
+        basis: &[(usize, SingleSpinOperator)],
+    ) -> Result<(f64, f64), StruqtureError> {
+        let terms = self
+            .iter()
+            .map(|(product, value)| {
+                let value: f64 = value.clone().try_into()?;
+                Ok((product, Complex64::new(value, 0.0)))
+            })
+            .collect::<Result<Vec<(&PauliProduct, Complex64)>, StruqtureError>>()?;
+        let (value, standard_error) = sample_estimate(&terms, samples, basis)?;
+        Ok((value.re, standard_error))
+    }
+}
+
+/// Estimates the expectation value of a sum of Pauli products from measured bitstrings.
+///
+/// # Arguments
+///
+/// * `terms` - The Pauli products with their coefficients.
+/// * `samples` - The measured bitstrings with the number of times they were measured.
+/// * `basis` - The Pauli operator measured on every qubit, qubits that are not listed are measured in the Z basis.
+///
+/// # Returns
+///
+/// * `Ok((Complex64, f64))` - The sample mean and its standard error.
+/// * `Err(StruqtureError::GenericError)` - A term is not diagonal in the basis, acts on a qubit beyond the bitstrings or no samples are given.
+pub(crate) fn sample_estimate(
+    terms: &[(&PauliProduct, Complex64)],
+    samples: &[(u64, usize)],
+    basis: &[(usize, SingleSpinOperator)],
+) -> Result<(Complex64, f64), StruqtureError> {
+    let mut masks: Vec<(u64, Complex64)> = Vec::with_capacity(terms.len());
+    for (product, value) in terms {
+        let mut mask = 0_u64;
+        for (qubit, operator) in product.iter() {
+            let measured = basis
+                .iter()
+                .find(|(measured_qubit, _)| measured_qubit == qubit)
+                .map_or(SingleSpinOperator::Z, |(_, measured)| *measured);
+            if measured != *operator {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Term {} is not diagonal in the measured basis {:?} of qubit {}",
+                        product, measured, qubit
+                    ),
+                });
+            }
+            if *qubit >= u64::BITS as usize {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Term {} acts on qubit {} beyond the {} bits of the samples",
+                        product,
+                        qubit,
+                        u64::BITS
+                    ),
+                });
+            }
+            mask |= 1 << qubit;
+        }
+        masks.push((mask, *value));
+    }
+
+    let number_samples: usize = samples.iter().map(|(_, count)| count).sum();
+    if number_samples == 0 {
+        return Err(StruqtureError::GenericError {
+            msg: "The expectation value can not be estimated without samples".to_string(),
+        });
+    }
+    let outcomes: Vec<(Complex64, f64)> = samples
+        .iter()
+        .map(|(bitstring, count)| {
+            let outcome: Complex64 = masks
+                .iter()
+                .map(|(mask, value)| {
+                    if (bitstring & mask).count_ones() % 2 == 0 {
+                        *value
+                    } else {
+                        -value
+                    }
+                })
+                .sum();
+            (outcome, *count as f64)
+        })
+        .collect();
+    let mean: Complex64 = outcomes
+        .iter()
+        .map(|(outcome, count)| *outcome * *count)
+        .sum::<Complex64>()
+        / number_samples as f64;
+    let standard_error = if number_samples > 1 {
+        let variance: f64 = outcomes
+            .iter()
+            .map(|(outcome, count)| (*outcome - mean).norm_sqr() * *count)
+            .sum::<f64>()
+            / (number_samples - 1) as f64;
+        (variance / number_samples as f64).sqrt()
+    } else {
+        0.0
+    };
+    Ok((mean, standard_error))
+}
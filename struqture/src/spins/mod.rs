@@ -15,7 +15,7 @@
 use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use num_complex::{Complex, Complex64};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::convert::TryInto;
 use std::iter::IntoIterator;
 use std::ops::{Add, Mul, Sub};
@@ -26,6 +26,9 @@ pub use decoherence_product::*;
 mod pauli_product;
 pub use pauli_product::*;
 
+mod packed_pauli_product;
+pub use packed_pauli_product::*;
+
 mod decoherence_operator;
 pub use decoherence_operator::*;
 
@@ -56,10 +59,47 @@ pub use plus_minus_product::*;
 mod plus_minus_operator;
 pub use plus_minus_operator::*;
 
+mod plus_minus_hamiltonian;
+pub use plus_minus_hamiltonian::*;
+
 mod plus_minus_noise_operator;
 pub use plus_minus_noise_operator::*;
 
-use crate::CooSparseMatrix;
+mod plus_minus_open_system;
+pub use plus_minus_open_system::*;
+
+mod spin_s_product;
+pub use spin_s_product::*;
+
+mod spin_s_operator;
+pub use spin_s_operator::*;
+
+mod spin_s_hamiltonian;
+pub use spin_s_hamiltonian::*;
+
+mod perturbation;
+pub use perturbation::*;
+
+mod symbolic_superoperator;
+pub use symbolic_superoperator::*;
+
+mod moments;
+pub use moments::*;
+
+mod observables;
+pub use observables::*;
+
+mod measurement;
+pub use measurement::*;
+
+#[cfg(feature = "npz")]
+mod sparse_pauli_npz;
+
+use crate::linalg::sparse_exponential;
+use crate::{
+    coo_to_single_precision, csr_to_single_precision, dense_to_single_precision, CooSparseMatrix,
+    CooSparseMatrixF32, CsrSparseMatrix, CsrSparseMatrixF32, RoundingDiagnostics,
+};
 
 /// Trait for non-Hermitian operations on spins.
 ///
@@ -104,6 +144,71 @@ pub trait OperateOnSpins<'a>: PartialEq + Clone + Mul<CalculatorFloat> + Add + S
     fn current_number_spins(&self) -> usize;
 }
 
+/// Trait for the weight and locality statistics of spin operators.
+///
+/// The weight of a term is the number of spins it acts on non-trivially. For noise operators, the
+/// term (L_l, L_r) acts on the union of the spins of the left and right operator products.
+///
+/// # Example
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use std::collections::{BTreeMap, BTreeSet};
+/// use struqture::spins::{PauliProduct, SpinLocality, SpinOperator};
+///
+/// let mut so = SpinOperator::new();
+/// so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.2)).unwrap();
+/// so.add_operator_product(PauliProduct::new().x(1).x(3), CalculatorComplex::from(0.5)).unwrap();
+///
+/// assert_eq!(so.weight_distribution(), BTreeMap::from([(1, 1), (2, 1)]));
+/// assert_eq!(so.max_weight(), 2);
+/// assert_eq!(so.support(), BTreeSet::from([0, 1, 3]));
+/// ```
+///
+pub trait SpinLocality {
+    /// Returns the indices of the spins acted on by every term of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<BTreeSet<usize>>` - The spin indices of every term, in the iteration order of Self.
+    fn term_supports(&self) -> Vec<BTreeSet<usize>>;
+
+    /// Returns the number of terms of Self for every weight.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeMap<usize, usize>` - The number of terms (value) acting on a given number of spins (key).
+    fn weight_distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution: BTreeMap<usize, usize> = BTreeMap::new();
+        for support in self.term_supports() {
+            *distribution.entry(support.len()).or_insert(0) += 1;
+        }
+        distribution
+    }
+
+    /// Returns the largest weight of the terms of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The largest number of spins a single term acts on, 0 if Self is empty.
+    fn max_weight(&self) -> usize {
+        self.term_supports()
+            .iter()
+            .map(|support| support.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the indices of all spins Self acts on.
+    ///
+    /// # Returns
+    ///
+    /// * `BTreeSet<usize>` - The union of the spin indices of all terms.
+    fn support(&self) -> BTreeSet<usize> {
+        self.term_supports().into_iter().flatten().collect()
+    }
+}
+
 pub trait ToSparseMatrixOperator<'a>:
     ToSparseMatrixSuperOperator<'a>
     + OperateOnSpins<'a>
@@ -180,6 +285,130 @@ where
         Ok((values, (rows, columns)))
     }
 
+    /// Constructs the sparse matrix representation of the operator-like object in CSR form.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation of the operator-like object.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_csr(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let dimension = match number_spins {
+            None => 2usize.pow(self.number_spins() as u32),
+            Some(num_spins) => 2usize.pow(num_spins as u32),
+        };
+
+        let capacity = dimension;
+        let mut values: Vec<Complex64> = Vec::with_capacity(capacity);
+        let mut columns: Vec<usize> = Vec::with_capacity(capacity);
+        let mut row_pointers: Vec<usize> = Vec::with_capacity(dimension + 1);
+        row_pointers.push(0);
+
+        for row in 0..dimension {
+            let mut entries: Vec<(usize, Complex64)> = self
+                .sparse_matrix_entries_on_row(row)?
+                .into_iter()
+                .collect();
+            entries.sort_unstable_by_key(|(col, _)| *col);
+            for (col, val) in entries.into_iter() {
+                columns.push(col);
+                values.push(val);
+            }
+            row_pointers.push(values.len());
+        }
+        Ok((values, columns, row_pointers))
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in COO form with single precision values.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(((Vec<Complex32>, (Vec<usize>, Vec<usize>)), RoundingDiagnostics))` - The matrix representation of the operator-like object and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn sparse_matrix_coo_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(CooSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+        coo_to_single_precision(self.sparse_matrix_coo(number_spins)?)
+    }
+
+    /// Constructs the sparse matrix representation of the operator-like object in CSR form with single precision values.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(((Vec<Complex32>, Vec<usize>, Vec<usize>), RoundingDiagnostics))` - The values, column indices and row pointers of the matrix representation of the operator-like object and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn sparse_matrix_csr_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(CsrSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+        csr_to_single_precision(self.sparse_matrix_csr(number_spins)?)
+    }
+
+    /// Constructs the dense matrix representation of the operator-like object.
+    ///
+    /// Intended for small systems, the matrix has 2^N x 2^N entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The dense matrix representation of the operator-like object.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn to_dense_matrix(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<ndarray::Array2<Complex64>, StruqtureError> {
+        let dimension = match number_spins {
+            None => 2usize.pow(self.number_spins() as u32),
+            Some(num_spins) => 2usize.pow(num_spins as u32),
+        };
+        let mut matrix = ndarray::Array2::<Complex64>::zeros((dimension, dimension));
+        for row in 0..dimension {
+            for (column, val) in self.sparse_matrix_entries_on_row(row)?.into_iter() {
+                matrix[[row, column]] = val;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Constructs the dense matrix representation of the operator-like object with single precision values.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Array2<Complex32>, RoundingDiagnostics))` - The dense matrix representation of the operator-like object and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn to_dense_matrix_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(ndarray::Array2<num_complex::Complex32>, RoundingDiagnostics), StruqtureError>
+    {
+        dense_to_single_precision(self.to_dense_matrix(number_spins)?)
+    }
+
     /// Constructs the sparse matrix entries for one row of the sparse matrix.
     ///
     /// # Arguments
@@ -425,6 +654,207 @@ pub trait ToSparseMatrixSuperOperator<'a>: OperateOnSpins<'a> + PartialEq + Clon
         Ok((values, (rows, columns)))
     }
 
+    /// Constructs the sparse matrix representation of the superoperator in CSR representation.
+    ///
+    /// The superoperator for the operator O is defined as the Matrix S so that
+    /// `flatten(-i [O, p]) = S flatten(p)` wher `[,]` is the commutator, `p` is a matrix
+    /// and `flatten` flattens a matrix into a vector in row-major form.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation of Self.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn sparse_matrix_superoperator_csr(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let number_spins = match number_spins {
+            None => self.number_spins(),
+            Some(num_spins) => num_spins,
+        };
+        let dimension = 2usize.pow(number_spins as u32);
+        let capacity = dimension;
+        let mut values: Vec<Complex64> = Vec::with_capacity(capacity);
+        let mut columns: Vec<usize> = Vec::with_capacity(capacity);
+        let mut row_pointers: Vec<usize> = Vec::with_capacity(dimension.pow(2) + 1);
+        row_pointers.push(0);
+
+        for row in 0..dimension.pow(2) {
+            let mut entries: Vec<(usize, Complex64)> = self
+                .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
+                .into_iter()
+                .collect();
+            entries.sort_unstable_by_key(|(col, _)| *col);
+            for (col, val) in entries.into_iter() {
+                columns.push(col);
+                values.push(val);
+            }
+            row_pointers.push(values.len());
+        }
+        Ok((values, columns, row_pointers))
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in COO representation with single precision values.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in COO form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(((Vec<Complex32>, (Vec<usize>, Vec<usize>)), RoundingDiagnostics))` - The matrix representation of Self and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn sparse_matrix_superoperator_coo_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(CooSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+        coo_to_single_precision(self.sparse_matrix_superoperator_coo(number_spins)?)
+    }
+
+    /// Constructs the sparse matrix representation of the superoperator in CSR representation with single precision values.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix in CSR form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(((Vec<Complex32>, Vec<usize>, Vec<usize>), RoundingDiagnostics))` - The values, column indices and row pointers of the matrix representation of Self and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn sparse_matrix_superoperator_csr_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(CsrSparseMatrixF32, RoundingDiagnostics), StruqtureError> {
+        csr_to_single_precision(self.sparse_matrix_superoperator_csr(number_spins)?)
+    }
+
+    /// Constructs the superoperator of the quantum channel generated by the superoperator for a given time.
+    ///
+    /// For the superoperator S of Self (see `sparse_matrix_superoperator`) the channel is
+    /// `flatten(p(t)) = exp(t S) flatten(p(0))`. The exponential is computed by scaling and squaring
+    /// on the sparse data, so the method is intended for small systems (the channel of N spins
+    /// has up to 16^N entries). Entries with an absolute value below 1e-15 are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time for which the channel is constructed.
+    /// * `number_spins` - The number of spins for which to construct the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The channel superoperator in COO form, sorted by rows and columns.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn channel_superoperator_coo(
+        &'a self,
+        time: f64,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let number_spins = match number_spins {
+            None => self.number_spins(),
+            Some(num_spins) => num_spins,
+        };
+        let dimension = 2usize.pow(number_spins as u32).pow(2);
+        Ok(sparse_exponential(
+            self.sparse_matrix_superoperator_coo(Some(number_spins))?,
+            dimension,
+            time,
+        ))
+    }
+
+    /// Constructs the dense matrix of the quantum channel generated by the superoperator for a given time.
+    ///
+    /// The dense equivalent of `channel_superoperator_coo`, using the row-major flattening of
+    /// `to_dense_superoperator_matrix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time for which the channel is constructed.
+    /// * `number_spins` - The number of spins for which to construct the channel.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The dense matrix exp(t S) of the channel.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn channel_superoperator_dense(
+        &'a self,
+        time: f64,
+        number_spins: Option<usize>,
+    ) -> Result<ndarray::Array2<Complex64>, StruqtureError> {
+        let number_spins = match number_spins {
+            None => self.number_spins(),
+            Some(num_spins) => num_spins,
+        };
+        let dimension = 2usize.pow(number_spins as u32).pow(2);
+        let (values, (rows, columns)) = self.channel_superoperator_coo(time, Some(number_spins))?;
+        let mut matrix = ndarray::Array2::<Complex64>::zeros((dimension, dimension));
+        for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+            matrix[[row, column]] = value;
+        }
+        Ok(matrix)
+    }
+
+    /// Constructs the dense matrix representation of the superoperator.
+    ///
+    /// The density matrix p is flattened in row-major order, i.e. the entry p[i, j] is found at index
+    /// `i * 2^N + j` of `flatten(p)`, and the returned matrix S fulfills `flatten(L(p)) = S flatten(p)`.
+    /// Intended for small systems, the matrix has 4^N x 4^N entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Array2<Complex64>)` - The dense matrix representation of the superoperator.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    fn to_dense_superoperator_matrix(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<ndarray::Array2<Complex64>, StruqtureError> {
+        let number_spins = match number_spins {
+            None => self.number_spins(),
+            Some(num_spins) => num_spins,
+        };
+        let dimension = 2usize.pow(number_spins as u32).pow(2);
+        let mut matrix = ndarray::Array2::<Complex64>::zeros((dimension, dimension));
+        for row in 0..dimension {
+            for (column, val) in self
+                .sparse_matrix_superoperator_entries_on_row(row, number_spins)?
+                .into_iter()
+            {
+                matrix[[row, column]] = val;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Constructs the dense matrix representation of the superoperator with single precision values.
+    ///
+    /// Uses the same row-major flattening of the density matrix as `to_dense_superoperator_matrix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the dense matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Array2<Complex32>, RoundingDiagnostics))` - The dense matrix representation of the superoperator and the diagnostics of the rounding to single precision.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    /// * `Err(StruqtureError::GenericError)` - A value exceeds the range of single precision floats.
+    fn to_dense_superoperator_matrix_f32(
+        &'a self,
+        number_spins: Option<usize>,
+    ) -> Result<(ndarray::Array2<num_complex::Complex32>, RoundingDiagnostics), StruqtureError>
+    {
+        dense_to_single_precision(self.to_dense_superoperator_matrix(number_spins)?)
+    }
+
     /// Constructs the sparse matrix entries for one row of the sparse matrix superoperator.
     ///
     /// # Arguments
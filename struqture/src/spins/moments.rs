@@ -0,0 +1,308 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::measurement::sample_estimate;
+use super::{PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator};
+use crate::{OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+
+/// Source of the expectation values of PauliProducts used to compute moments of spin operators.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinExpectationSource, SpinOperator};
+///
+/// let mut so = SpinOperator::new();
+/// so.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+/// let state = [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)];
+/// let moments = so.moments(2, &SpinExpectationSource::StateVector(&state)).unwrap();
+/// assert_eq!(moments, vec![Complex64::new(1.0, 0.0); 3]);
+/// ```
+///
+pub enum SpinExpectationSource<'a> {
+    /// A state vector in the computational basis, where spin `i` corresponds to bit `i` of the basis index.
+    StateVector(&'a [Complex64]),
+    /// A callback returning the expectation value of a PauliProduct, e.g. estimated from measurements.
+    Callback(&'a dyn Fn(&PauliProduct) -> Result<Complex64, StruqtureError>),
+    /// Measured bitstrings with the number of times they were measured and the Pauli operator measured on every qubit,
+    /// following the conventions of [SpinOperator::expectation_from_samples].
+    Samples(&'a [(u64, usize)], &'a [(usize, SingleSpinOperator)]),
+}
+
+impl SpinExpectationSource<'_> {
+    /// Computes the expectation value of a SpinOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The SpinOperator to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Complex64)` - The expectation value.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The state vector does not fit the operator or a term is not diagonal in the measured basis.
+    /// * `Err(StruqtureError)` - The callback failed.
+    pub fn expectation_value(&self, operator: &SpinOperator) -> Result<Complex64, StruqtureError> {
+        let mut expectation = Complex64::new(0.0, 0.0);
+        for (product, value) in operator.iter() {
+            let coefficient = Complex64::new(*value.re.float()?, *value.im.float()?);
+            expectation += coefficient * self.product_expectation_value(product)?;
+        }
+        Ok(expectation)
+    }
+
+    /// Computes the expectation value of a single PauliProduct.
+    fn product_expectation_value(
+        &self,
+        product: &PauliProduct,
+    ) -> Result<Complex64, StruqtureError> {
+        let state = match self {
+            SpinExpectationSource::Callback(callback) => return callback(product),
+            SpinExpectationSource::Samples(samples, basis) => {
+                return sample_estimate(&[(product, Complex64::new(1.0, 0.0))], samples, basis)
+                    .map(|(expectation, _)| expectation)
+            }
+            SpinExpectationSource::StateVector(state) => state,
+        };
+        if !state.len().is_power_of_two() || state.len() < 1 << product.current_number_spins() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "State vector of length {} does not describe the {} spins of {}",
+                    state.len(),
+                    product.current_number_spins(),
+                    product
+                ),
+            });
+        }
+        // P |c> = prefactor(r) |r> with r = c ^ flip_mask, where every Y contributes -i (+i) and every Z
+        // contributes 1 (-1) for spin r_i = 0 (1)
+        let mut flip_mask: usize = 0;
+        let mut sign_mask: usize = 0;
+        let mut phase = Complex64::new(1.0, 0.0);
+        for (index, pauli) in product.iter() {
+            match pauli {
+                SingleSpinOperator::X => flip_mask |= 1 << index,
+                SingleSpinOperator::Y => {
+                    flip_mask |= 1 << index;
+                    sign_mask |= 1 << index;
+                    phase *= Complex64::new(0.0, -1.0);
+                }
+                SingleSpinOperator::Z => sign_mask |= 1 << index,
+                SingleSpinOperator::Identity => (),
+            }
+        }
+        let mut expectation = Complex64::new(0.0, 0.0);
+        for (row, amplitude) in state.iter().enumerate() {
+            let contribution = amplitude.conj() * state[row ^ flip_mask];
+            if (row & sign_mask).count_ones() % 2 == 0 {
+                expectation += contribution;
+            } else {
+                expectation -= contribution;
+            }
+        }
+        Ok(phase * expectation)
+    }
+}
+
+impl SpinOperator {
+    /// Computes the moments ⟨A^n⟩ of the SpinOperator A for n = 0, ..., up_to_order.
+    ///
+    /// The powers are built by successive multiplication, removing terms that cancel exactly.
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_order` - The highest order of the moments.
+    /// * `source` - The state vector or callback providing expectation values of PauliProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The moments, starting with the normalization ⟨A^0⟩.
+    /// * `Err(StruqtureError)` - An expectation value could not be computed.
+    pub fn moments(
+        &self,
+        up_to_order: usize,
+        source: &SpinExpectationSource,
+    ) -> Result<Vec<Complex64>, StruqtureError> {
+        let mut power = SpinOperator::new();
+        power.add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))?;
+        let mut moments: Vec<Complex64> = Vec::with_capacity(up_to_order + 1);
+        for order in 0..=up_to_order {
+            moments.push(source.expectation_value(&power)?);
+            if order < up_to_order {
+                power = (power * self.clone()).truncate(f64::MIN_POSITIVE);
+            }
+        }
+        Ok(moments)
+    }
+
+    /// Computes the cumulants of the SpinOperator for n = 1, ..., up_to_order.
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_order` - The highest order of the cumulants.
+    /// * `source` - The state vector or callback providing expectation values of PauliProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The cumulants, starting with the mean.
+    /// * `Err(StruqtureError)` - An expectation value could not be computed or the normalization vanishes.
+    pub fn cumulants(
+        &self,
+        up_to_order: usize,
+        source: &SpinExpectationSource,
+    ) -> Result<Vec<Complex64>, StruqtureError> {
+        cumulants_from_moments(&self.moments(up_to_order, source)?)
+    }
+
+    /// Returns the variance operator A^† A - |⟨A⟩|^2 of the SpinOperator A.
+    ///
+    /// For a hermitian operator H this is H^2 - ⟨H⟩^2, whose expectation value in the state
+    /// the mean ⟨H⟩ was taken in is the variance of H.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The state vector, samples or callback providing the expectation value ⟨A⟩.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinOperator)` - The variance operator.
+    /// * `Err(StruqtureError)` - The expectation value could not be computed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_complex::Complex64;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinExpectationSource, SpinOperator};
+    ///
+    /// let mut so = SpinOperator::new();
+    /// so.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+    /// let state = [Complex64::new(0.6, 0.0), Complex64::new(0.8, 0.0)];
+    /// let source = SpinExpectationSource::StateVector(&state);
+    ///
+    /// // Z^2 = 1 and ⟨Z⟩ = 0.36 - 0.64
+    /// let variance = so.variance_operator(&source).unwrap();
+    /// assert_eq!(variance.len(), 1);
+    /// assert!((so.estimate_variance(&source).unwrap() - (1.0 - 0.28 * 0.28)).abs() < 1e-12);
+    /// ```
+    pub fn variance_operator(
+        &self,
+        source: &SpinExpectationSource,
+    ) -> Result<SpinOperator, StruqtureError> {
+        let mean = source.expectation_value(self)?;
+        let mut variance = (self.hermitian_conjugate() * self.clone()).truncate(f64::MIN_POSITIVE);
+        variance.add_operator_product(
+            PauliProduct::new(),
+            CalculatorComplex::from(-mean.norm_sqr()),
+        )?;
+        Ok(variance)
+    }
+
+    /// Estimates the variance ⟨A^† A⟩ - |⟨A⟩|^2 of the SpinOperator A.
+    ///
+    /// The standard error of the mean of N independent single-shot measurements of A is sqrt(variance / N).
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The state vector, samples or callback providing the expectation values.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The variance.
+    /// * `Err(StruqtureError)` - An expectation value could not be computed.
+    pub fn estimate_variance(&self, source: &SpinExpectationSource) -> Result<f64, StruqtureError> {
+        Ok(source
+            .expectation_value(&self.variance_operator(source)?)?
+            .re)
+    }
+}
+
+impl SpinHamiltonian {
+    /// Computes the moments ⟨H^n⟩ of the SpinHamiltonian H for n = 0, ..., up_to_order.
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_order` - The highest order of the moments.
+    /// * `source` - The state vector or callback providing expectation values of PauliProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The moments, starting with the normalization ⟨H^0⟩.
+    /// * `Err(StruqtureError)` - An expectation value could not be computed.
+    pub fn moments(
+        &self,
+        up_to_order: usize,
+        source: &SpinExpectationSource,
+    ) -> Result<Vec<Complex64>, StruqtureError> {
+        SpinOperator::from(self.clone()).moments(up_to_order, source)
+    }
+
+    /// Computes the cumulants of the SpinHamiltonian for n = 1, ..., up_to_order.
+    ///
+    /// # Arguments
+    ///
+    /// * `up_to_order` - The highest order of the cumulants.
+    /// * `source` - The state vector or callback providing expectation values of PauliProducts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Complex64>)` - The cumulants, starting with the mean.
+    /// * `Err(StruqtureError)` - An expectation value could not be computed or the normalization vanishes.
+    pub fn cumulants(
+        &self,
+        up_to_order: usize,
+        source: &SpinExpectationSource,
+    ) -> Result<Vec<Complex64>, StruqtureError> {
+        SpinOperator::from(self.clone()).cumulants(up_to_order, source)
+    }
+}
+
+/// Converts the moments μ_0, ..., μ_N into the cumulants κ_1, ..., κ_N.
+///
+/// The moments are normalized by μ_0 and the cumulants follow from the recursion
+/// κ_n = μ_n - sum_{m=1}^{n-1} binomial(n-1, m-1) κ_m μ_{n-m}.
+///
+/// # Arguments
+///
+/// * `moments` - The moments, starting with the normalization μ_0.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Complex64>)` - The cumulants, starting with the mean κ_1.
+/// * `Err(StruqtureError::GenericError)` - No moments were given or the normalization μ_0 vanishes.
+pub fn cumulants_from_moments(moments: &[Complex64]) -> Result<Vec<Complex64>, StruqtureError> {
+    let normalization = match moments.first() {
+        Some(normalization) if normalization.norm() > 0.0 => *normalization,
+        _ => {
+            return Err(StruqtureError::GenericError {
+                msg: "Cumulants require a non-vanishing normalization moment".to_string(),
+            })
+        }
+    };
+    let moments: Vec<Complex64> = moments.iter().map(|x| x / normalization).collect();
+    let mut cumulants: Vec<Complex64> = Vec::with_capacity(moments.len() - 1);
+    for n in 1..moments.len() {
+        let mut binomial = 1.0;
+        let mut cumulant = moments[n];
+        for m in 1..n {
+            cumulant -= cumulants[m - 1] * moments[n - m] * binomial;
+            // binomial(n-1, m) from binomial(n-1, m-1)
+            binomial *= (n - m) as f64 / m as f64;
+        }
+        cumulants.push(cumulant);
+    }
+    Ok(cumulants)
+}
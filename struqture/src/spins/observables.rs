@@ -0,0 +1,135 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PauliProduct, SingleSpinOperator, SpinOperator};
+use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use qoqo_calculator::CalculatorComplex;
+
+/// The three Pauli components of the spin vector.
+const SPIN_COMPONENTS: [SingleSpinOperator; 3] = [
+    SingleSpinOperator::X,
+    SingleSpinOperator::Y,
+    SingleSpinOperator::Z,
+];
+
+impl SpinOperator {
+    /// Creates the correlation operator S^a_i S^a_j of one component of the spin operators S^a = σ^a / 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the first spin.
+    /// * `j` - The index of the second spin.
+    /// * `basis` - The component a of the spin operators.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The correlation operator, proportional to the identity for `i == j`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SingleSpinOperator, SpinOperator};
+    ///
+    /// let so = SpinOperator::correlation(0, 2, SingleSpinOperator::Z);
+    /// assert_eq!(so.get(&PauliProduct::new().z(0).z(2)), &0.25.into());
+    /// ```
+    pub fn correlation(i: usize, j: usize, basis: SingleSpinOperator) -> Self {
+        let product = if i == j || basis == SingleSpinOperator::Identity {
+            PauliProduct::new()
+        } else {
+            PauliProduct::new().set_pauli(i, basis).set_pauli(j, basis)
+        };
+        let mut correlation = SpinOperator::new();
+        correlation
+            .add_operator_product(product, CalculatorComplex::from(0.25))
+            .expect("Internal bug in add_operator_product");
+        correlation
+    }
+
+    /// Creates the isotropic correlation operator S_i · S_j = \sum_a S^a_i S^a_j with S^a = σ^a / 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the first spin.
+    /// * `j` - The index of the second spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The correlation operator, 3/4 times the identity for `i == j`.
+    pub fn heisenberg_correlation(i: usize, j: usize) -> Self {
+        let mut correlation = SpinOperator::new();
+        for component in SPIN_COMPONENTS {
+            correlation.extend(SpinOperator::correlation(i, j, component));
+        }
+        correlation
+    }
+}
+
+/// Creates the static spin structure factor S(k) = 1/N \sum_{i,j} e^{i k·(r_i - r_j)} S_i · S_j.
+///
+/// Spin `i` is located at `positions[i]`. Since every pair of spins appears in both orders,
+/// the phases combine to cos(k·(r_i - r_j)) and the structure factor is hermitian.
+///
+/// # Arguments
+///
+/// * `k` - The wave vector.
+/// * `positions` - The positions of the N spins, with the same dimension as the wave vector.
+///
+/// # Returns
+///
+/// * `Ok(SpinOperator)` - The structure factor.
+/// * `Err(StruqtureError::GenericError)` - No positions are given or a position does not have the dimension of the wave vector.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use struqture::spins::{structure_factor, PauliProduct};
+///
+/// // The uniform structure factor of two spins is the total spin squared divided by two
+/// let sf = structure_factor(&[0.0], &[vec![0.0], vec![1.0]]).unwrap();
+/// assert_eq!(sf.get(&PauliProduct::new()), &0.75.into());
+/// assert_eq!(sf.get(&PauliProduct::new().x(0).x(1)), &0.25.into());
+/// ```
+pub fn structure_factor(k: &[f64], positions: &[Vec<f64>]) -> Result<SpinOperator, StruqtureError> {
+    if positions.is_empty() {
+        return Err(StruqtureError::GenericError {
+            msg: "The structure factor requires the position of at least one spin".to_string(),
+        });
+    }
+    if let Some(position) = positions.iter().find(|position| position.len() != k.len()) {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Position {:?} does not have the dimension {} of the wave vector",
+                position,
+                k.len()
+            ),
+        });
+    }
+    let normalization = 1.0 / positions.len() as f64;
+    let mut factor = SpinOperator::new();
+    for (i, position_i) in positions.iter().enumerate() {
+        for (j, position_j) in positions.iter().enumerate() {
+            let phase: f64 = k
+                .iter()
+                .zip(position_i.iter().zip(position_j.iter()))
+                .map(|(k_a, (r_i, r_j))| k_a * (r_i - r_j))
+                .sum();
+            let prefactor = CalculatorComplex::from(normalization * phase.cos());
+            for (product, value) in SpinOperator::heisenberg_correlation(i, j) {
+                factor.add_operator_product(product, value * prefactor.clone())?;
+            }
+        }
+    }
+    Ok(factor)
+}
@@ -0,0 +1,499 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PauliProduct, SingleSpinOperator};
+use crate::{to_single_precision, RoundingDiagnostics, SpinIndex, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::ops::Mul;
+
+/// The number of spins packed into one word of the bitmasks.
+const SPINS_PER_WORD: usize = 64;
+
+/// Flat representation of the terms of a spin operator in contiguous arrays.
+///
+/// The first entry holds the x- and z-bitmasks of the Pauli products of all terms, with the same number of
+/// words per term (see [PackedPauliProduct]), the second and third entries the real and imaginary parts of the coefficients.
+pub type FlatPauliArrays = ((Vec<u64>, Vec<u64>), Vec<f64>, Vec<f64>);
+
+/// Flat representation of the terms of a spin operator with single precision coefficients, see [FlatPauliArrays].
+pub type FlatPauliArraysF32 = ((Vec<u64>, Vec<u64>), Vec<f32>, Vec<f32>);
+
+/// The powers i^0, i^1, i^2 and i^3 of the imaginary unit.
+const PHASES: [Complex64; 4] = [
+    Complex64::new(1.0, 0.0),
+    Complex64::new(0.0, 1.0),
+    Complex64::new(-1.0, 0.0),
+    Complex64::new(0.0, -1.0),
+];
+
+/// PackedPauliProducts are PauliProducts stored as two bitmasks.
+///
+/// Bit `j` of word `w` of the x-mask (z-mask) is set if the product acts with X or Y (Z or Y) on spin `64 * w + j`.
+/// Multiplying two PackedPauliProducts and checking whether they commute scales with the number of words
+/// instead of the number of Pauli operators, which makes the representation favourable for long products.
+///
+/// # Example
+///
+/// ```
+/// use num_complex::Complex64;
+/// use struqture::spins::{PackedPauliProduct, PauliProduct};
+///
+/// let left = PackedPauliProduct::from(&PauliProduct::new().x(0).z(70));
+/// let right = PackedPauliProduct::from(&PauliProduct::new().y(0));
+/// let (product, factor) = left * right;
+/// assert_eq!(PauliProduct::from(product), PauliProduct::new().z(0).z(70));
+/// assert_eq!(factor, Complex64::new(0.0, 1.0));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PackedPauliProduct {
+    /// The words of the bitmask of spins acted on by X or Y.
+    x_mask: Vec<u64>,
+    /// The words of the bitmask of spins acted on by Z or Y.
+    z_mask: Vec<u64>,
+}
+
+impl PackedPauliProduct {
+    /// Creates a new PackedPauliProduct (the identity).
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PackedPauliProduct.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the words of the x-bitmask of the PackedPauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `&[u64]` - The bitmask of spins acted on by X or Y, without trailing zero words.
+    pub fn x_mask(&self) -> &[u64] {
+        &self.x_mask
+    }
+
+    /// Returns the words of the z-bitmask of the PackedPauliProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `&[u64]` - The bitmask of spins acted on by Z or Y, without trailing zero words.
+    pub fn z_mask(&self) -> &[u64] {
+        &self.z_mask
+    }
+
+    /// Creates a PackedPauliProduct from the words of its bitmasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_mask` - The bitmask of spins acted on by X or Y.
+    /// * `z_mask` - The bitmask of spins acted on by Z or Y.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PackedPauliProduct with the given bitmasks.
+    /// * `Err(StruqtureError::GenericError)` - The bitmasks have a different number of words.
+    pub fn from_masks(x_mask: &[u64], z_mask: &[u64]) -> Result<Self, StruqtureError> {
+        if x_mask.len() != z_mask.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The x-bitmask has {} words, but the z-bitmask has {} words",
+                    x_mask.len(),
+                    z_mask.len()
+                ),
+            });
+        }
+        let mut packed = PackedPauliProduct {
+            x_mask: x_mask.to_vec(),
+            z_mask: z_mask.to_vec(),
+        };
+        packed.trim();
+        Ok(packed)
+    }
+
+    /// Gets the Pauli operator acting on a spin.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `SingleSpinOperator` - The Pauli operator acting on the spin (Identity if there is none).
+    pub fn get(&self, index: usize) -> SingleSpinOperator {
+        let word = index / SPINS_PER_WORD;
+        let bit = index % SPINS_PER_WORD;
+        let x = self.x_mask.get(word).map_or(0, |w| (w >> bit) & 1);
+        let z = self.z_mask.get(word).map_or(0, |w| (w >> bit) & 1);
+        match (x, z) {
+            (0, 0) => SingleSpinOperator::Identity,
+            (_, 0) => SingleSpinOperator::X,
+            (0, _) => SingleSpinOperator::Z,
+            _ => SingleSpinOperator::Y,
+        }
+    }
+
+    /// Sets the Pauli operator acting on a spin, replacing the previous operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    /// * `pauli` - The Pauli operator acting on the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliProduct with the operator set.
+    pub fn set_pauli(mut self, index: usize, pauli: SingleSpinOperator) -> Self {
+        let word = index / SPINS_PER_WORD;
+        let bit = 1_u64 << (index % SPINS_PER_WORD);
+        if word >= self.x_mask.len() {
+            self.x_mask.resize(word + 1, 0);
+            self.z_mask.resize(word + 1, 0);
+        }
+        let (x, z) = match pauli {
+            SingleSpinOperator::Identity => (false, false),
+            SingleSpinOperator::X => (true, false),
+            SingleSpinOperator::Y => (true, true),
+            SingleSpinOperator::Z => (false, true),
+        };
+        for (mask, set) in [(&mut self.x_mask, x), (&mut self.z_mask, z)] {
+            if set {
+                mask[word] |= bit;
+            } else {
+                mask[word] &= !bit;
+            }
+        }
+        self.trim();
+        self
+    }
+
+    /// Returns the number of spins the PackedPauliProduct acts on non-trivially.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of non-identity Pauli operators.
+    pub fn weight(&self) -> usize {
+        self.x_mask
+            .iter()
+            .zip(self.z_mask.iter())
+            .map(|(x, z)| (x | z).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns whether the PackedPauliProduct commutes with another PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PackedPauliProduct to check.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the products commute (and not anticommute).
+    pub fn commutes_with(&self, other: &PackedPauliProduct) -> bool {
+        let anticommuting: u32 = self
+            .x_mask
+            .iter()
+            .zip(self.z_mask.iter())
+            .zip(other.x_mask.iter().zip(other.z_mask.iter()))
+            .map(|((x1, z1), (x2, z2))| ((x1 & z2) ^ (z1 & x2)).count_ones())
+            .sum();
+        anticommuting % 2 == 0
+    }
+
+    /// Removes trailing words that are zero in both bitmasks.
+    fn trim(&mut self) {
+        while self.x_mask.last() == Some(&0) && self.z_mask.last() == Some(&0) {
+            self.x_mask.pop();
+            self.z_mask.pop();
+        }
+    }
+}
+
+/// Implements the multiplication function of PackedPauliProduct by PackedPauliProduct.
+///
+impl Mul<PackedPauliProduct> for PackedPauliProduct {
+    type Output = (Self, Complex64);
+    /// Implement `*` for PackedPauliProduct and PackedPauliProduct.
+    ///
+    /// With Y = i X Z, the product of the Pauli operators (x1, z1) and (x2, z2) is
+    /// i^(x1 z1 + x2 z2 + 2 z1 x2 - x3 z3) (x3, z3) with x3 = x1 ^ x2 and z3 = z1 ^ z2,
+    /// so the phase follows from population counts of the bitmasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The PackedPauliProduct to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `(Self, Complex64)` - The two PackedPauliProducts multiplied and the resulting prefactor.
+    fn mul(self, rhs: PackedPauliProduct) -> Self::Output {
+        let number_words = self.x_mask.len().max(rhs.x_mask.len());
+        let word_of = |mask: &[u64], word: usize| mask.get(word).copied().unwrap_or(0);
+        let mut x_mask: Vec<u64> = Vec::with_capacity(number_words);
+        let mut z_mask: Vec<u64> = Vec::with_capacity(number_words);
+        let mut exponent: u32 = 0;
+        for word in 0..number_words {
+            let (x1, z1) = (word_of(&self.x_mask, word), word_of(&self.z_mask, word));
+            let (x2, z2) = (word_of(&rhs.x_mask, word), word_of(&rhs.z_mask, word));
+            let (x3, z3) = (x1 ^ x2, z1 ^ z2);
+            // -1 is added as 3 modulo 4
+            exponent = (exponent
+                + (x1 & z1).count_ones()
+                + (x2 & z2).count_ones()
+                + 2 * (z1 & x2).count_ones()
+                + 3 * (x3 & z3).count_ones())
+                % 4;
+            x_mask.push(x3);
+            z_mask.push(z3);
+        }
+        let mut product = PackedPauliProduct { x_mask, z_mask };
+        product.trim();
+        (product, PHASES[exponent as usize])
+    }
+}
+
+impl From<&PauliProduct> for PackedPauliProduct {
+    /// Converts a PauliProduct into a PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliProduct acting with the same Pauli operators.
+    fn from(product: &PauliProduct) -> Self {
+        product
+            .iter()
+            .fold(PackedPauliProduct::new(), |packed, (index, pauli)| {
+                packed.set_pauli(*index, *pauli)
+            })
+    }
+}
+
+impl From<PauliProduct> for PackedPauliProduct {
+    /// Converts a PauliProduct into a PackedPauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `product` - The PauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PackedPauliProduct acting with the same Pauli operators.
+    fn from(product: PauliProduct) -> Self {
+        PackedPauliProduct::from(&product)
+    }
+}
+
+impl From<&PackedPauliProduct> for PauliProduct {
+    /// Converts a PackedPauliProduct into a PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - The PackedPauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PauliProduct acting with the same Pauli operators.
+    fn from(packed: &PackedPauliProduct) -> Self {
+        let mut product = PauliProduct::with_capacity(packed.weight());
+        for (word, (x, z)) in packed.x_mask.iter().zip(packed.z_mask.iter()).enumerate() {
+            let mut occupied = x | z;
+            while occupied != 0 {
+                let bit = occupied.trailing_zeros() as usize;
+                occupied &= occupied - 1;
+                product = product.set_pauli(
+                    word * SPINS_PER_WORD + bit,
+                    packed.get(word * SPINS_PER_WORD + bit),
+                );
+            }
+        }
+        product
+    }
+}
+
+impl From<PackedPauliProduct> for PauliProduct {
+    /// Converts a PackedPauliProduct into a PauliProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - The PackedPauliProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PauliProduct acting with the same Pauli operators.
+    fn from(packed: PackedPauliProduct) -> Self {
+        PauliProduct::from(&packed)
+    }
+}
+
+/// Multiplies every Pauli term of a left operator with every Pauli term of a right operator.
+///
+/// With the `packed_pauli_products` feature every PauliProduct is converted to a PackedPauliProduct
+/// once per operator-level product instead of once per multiplied pair of terms.
+///
+/// # Arguments
+///
+/// * `left` - The terms of the left operator.
+/// * `right` - The terms of the right operator.
+/// * `add_term` - Called with the product and the coefficient of every pair of terms.
+pub(crate) fn multiply_pauli_terms<'a, 'b, L, R>(
+    left: impl IntoIterator<Item = (&'a PauliProduct, &'a L)>,
+    right: impl IntoIterator<Item = (&'b PauliProduct, &'b R)>,
+    mut add_term: impl FnMut(PauliProduct, CalculatorComplex),
+) where
+    L: Clone + Into<CalculatorComplex> + 'a,
+    R: Clone + Into<CalculatorComplex> + 'b,
+{
+    #[cfg(feature = "packed_pauli_products")]
+    {
+        let right: Vec<(PackedPauliProduct, CalculatorComplex)> = right
+            .into_iter()
+            .map(|(product, value)| (PackedPauliProduct::from(product), value.clone().into()))
+            .collect();
+        for (product, value) in left {
+            let packed = PackedPauliProduct::from(product);
+            let value: CalculatorComplex = value.clone().into();
+            for (other, other_value) in right.iter() {
+                let (result, factor) = packed.clone() * other.clone();
+                add_term(
+                    PauliProduct::from(result),
+                    other_value.clone() * factor * value.clone(),
+                );
+            }
+        }
+    }
+    #[cfg(not(feature = "packed_pauli_products"))]
+    {
+        let right: Vec<(&PauliProduct, CalculatorComplex)> = right
+            .into_iter()
+            .map(|(product, value)| (product, value.clone().into()))
+            .collect();
+        for (product, value) in left {
+            let value: CalculatorComplex = value.clone().into();
+            for (other, other_value) in right.iter() {
+                let (result, factor) = product.clone() * (*other).clone();
+                add_term(result, other_value.clone() * factor * value.clone());
+            }
+        }
+    }
+}
+
+/// Converts the terms of a spin operator into flat arrays, see [FlatPauliArrays].
+///
+/// The terms are sorted by their PauliProduct, so the arrays do not depend on the insertion order.
+/// Every term uses the number of words needed by the term acting on the spin with the highest index.
+pub(crate) fn to_flat_pauli_arrays<'b>(
+    terms: impl Iterator<Item = (&'b PauliProduct, Complex64)>,
+) -> FlatPauliArrays {
+    let mut terms: Vec<(&PauliProduct, Complex64)> = terms.collect();
+    terms.sort_by(|(left, _), (right, _)| left.cmp(right));
+    let packed_terms: Vec<(PackedPauliProduct, Complex64)> = terms
+        .into_iter()
+        .map(|(product, value)| (PackedPauliProduct::from(product), value))
+        .collect();
+    let number_words = packed_terms
+        .iter()
+        .map(|(packed, _)| packed.x_mask.len())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let mut x_masks: Vec<u64> = vec![0; number_words * packed_terms.len()];
+    let mut z_masks: Vec<u64> = vec![0; number_words * packed_terms.len()];
+    let mut real: Vec<f64> = Vec::with_capacity(packed_terms.len());
+    let mut imag: Vec<f64> = Vec::with_capacity(packed_terms.len());
+    for (term, (packed, value)) in packed_terms.iter().enumerate() {
+        let start = term * number_words;
+        x_masks[start..start + packed.x_mask.len()].copy_from_slice(&packed.x_mask);
+        z_masks[start..start + packed.z_mask.len()].copy_from_slice(&packed.z_mask);
+        real.push(value.re);
+        imag.push(value.im);
+    }
+    ((x_masks, z_masks), real, imag)
+}
+
+/// Converts the coefficients of flat arrays to single precision, see [FlatPauliArraysF32].
+///
+/// # Arguments
+///
+/// * `arrays` - The flat arrays with double precision coefficients.
+///
+/// # Returns
+///
+/// * `Ok((FlatPauliArraysF32, RoundingDiagnostics))` - The flat arrays with single precision coefficients and the diagnostics of the rounding.
+/// * `Err(StruqtureError::GenericError)` - A coefficient exceeds the range of single precision floats.
+pub(crate) fn flat_pauli_arrays_to_single_precision(
+    arrays: FlatPauliArrays,
+) -> Result<(FlatPauliArraysF32, RoundingDiagnostics), StruqtureError> {
+    let (masks, real, imag) = arrays;
+    let values: Vec<Complex64> = real
+        .into_iter()
+        .zip(imag)
+        .map(|(re, im)| Complex64::new(re, im))
+        .collect();
+    let (values, diagnostics) = to_single_precision(&values)?;
+    let (real, imag) = values.into_iter().map(|value| (value.re, value.im)).unzip();
+    Ok(((masks, real, imag), diagnostics))
+}
+
+/// Converts flat arrays into the terms of a spin operator, see [FlatPauliArrays].
+///
+/// # Arguments
+///
+/// * `x_masks` - The x-bitmasks of all terms, with the same number of words per term.
+/// * `z_masks` - The z-bitmasks of all terms, with the same number of words per term.
+/// * `real` - The real parts of the coefficients.
+/// * `imag` - The imaginary parts of the coefficients.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(PauliProduct, Complex64)>)` - The terms of the spin operator.
+/// * `Err(StruqtureError::GenericError)` - The lengths of the arrays do not fit together.
+pub(crate) fn from_flat_pauli_arrays(
+    x_masks: &[u64],
+    z_masks: &[u64],
+    real: &[f64],
+    imag: &[f64],
+) -> Result<Vec<(PauliProduct, Complex64)>, StruqtureError> {
+    let number_terms = real.len();
+    if imag.len() != number_terms
+        || x_masks.len() != z_masks.len()
+        || (number_terms == 0 && !x_masks.is_empty())
+        || (number_terms > 0 && (x_masks.is_empty() || x_masks.len() % number_terms != 0))
+    {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Flat arrays with {} x-words, {} z-words, {} real parts and {} imaginary parts do not describe the same terms",
+                x_masks.len(),
+                z_masks.len(),
+                real.len(),
+                imag.len()
+            ),
+        });
+    }
+    if number_terms == 0 {
+        return Ok(Vec::new());
+    }
+    let number_words = x_masks.len() / number_terms;
+    x_masks
+        .chunks(number_words)
+        .zip(z_masks.chunks(number_words))
+        .zip(real.iter().zip(imag.iter()))
+        .map(|((x_mask, z_mask), (re, im))| {
+            Ok((
+                PauliProduct::from(PackedPauliProduct::from_masks(x_mask, z_mask)?),
+                Complex64::new(*re, *im),
+            ))
+        })
+        .collect()
+}
@@ -21,7 +21,7 @@ use serde::de::{Deserializer, Error, SeqAccess, Visitor};
 use serde::ser::{SerializeSeq, Serializer};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt;
 use std::hash::Hash;
 use std::iter::{FromIterator, IntoIterator};
@@ -243,6 +243,8 @@ impl schemars::JsonSchema for PauliProduct {
 }
 impl crate::MinSupportedVersion for PauliProduct {}
 
+impl crate::SerializeCanonical for PauliProduct {}
+
 /// Deserializing directly from string.
 ///
 impl<'de> Deserialize<'de> for PauliProduct {
@@ -618,6 +620,29 @@ impl PauliProduct {
             items: TinyVec::<[(usize, SingleSpinOperator); 5]>::with_capacity(cap),
         }
     }
+
+    /// Returns the PauliProduct on the remaining spins after tracing out the given spins.
+    ///
+    /// The remaining spins are relabeled in ascending order starting from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `traced` - The spins that are traced out.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(PauliProduct)` - The PauliProduct acts as the identity on the traced spins, the relabeled PauliProduct.
+    /// * `None` - The PauliProduct acts on a traced spin, its partial trace vanishes.
+    pub(crate) fn trace_out(&self, traced: &BTreeSet<usize>) -> Option<PauliProduct> {
+        if self.iter().any(|(index, _)| traced.contains(index)) {
+            return None;
+        }
+        Some(
+            self.iter()
+                .map(|(index, pauli)| (index - traced.range(..*index).count(), *pauli))
+                .collect(),
+        )
+    }
 }
 
 /// Implements the default function (Default trait) of PauliProduct (an empty PauliProduct).
@@ -0,0 +1,203 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    OperateOnSpins, PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinHamiltonianSystem,
+    ToSparseMatrixOperator,
+};
+use crate::linalg::{degenerate_levels, hermitian_eigh};
+use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use ndarray::{s, Array2};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+
+/// Maximal number of spins for which the perturbation analysis is carried out on dense matrices.
+const MAX_PERTURBATION_SPINS: usize = 8;
+
+/// Perturbative corrections to one (possibly degenerate) energy level of an unperturbed Hamiltonian.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerturbedLevel {
+    /// The energy of the level in the unperturbed Hamiltonian.
+    pub unperturbed_energy: f64,
+    /// The degeneracy of the level in the unperturbed Hamiltonian.
+    pub degeneracy: usize,
+    /// The first-order energy corrections of the states of the level in ascending order.
+    pub first_order_corrections: Vec<f64>,
+    /// The second-order energy corrections of the states, in the order of the first-order corrections.
+    pub second_order_corrections: Vec<f64>,
+    /// The effective Hamiltonian of the level to second order, acting on the full Hilbert space and vanishing outside the level.
+    pub effective_hamiltonian: SpinHamiltonian,
+}
+
+/// Computes the first- and second-order perturbative corrections to the energy levels of a spin Hamiltonian.
+///
+/// The unperturbed Hamiltonian H0 is diagonalized numerically and its eigenvalues are grouped
+/// into degenerate levels. For a level with energy E0 and projector P onto its eigenspace, the
+/// effective Hamiltonian to second order is
+///
+/// H_eff = E0 P + P V P + \sum_{m \notin P} P V |m><m| V P / (E0 - E_m).
+///
+/// The first-order corrections are the eigenvalues of P V P. The second-order corrections are the
+/// expectation values of the second-order term in the corresponding eigenstates of P V P,
+/// diagonalized within sets of states that remain degenerate at first order.
+/// Since the matrices are treated as dense, the analysis is restricted to small systems.
+///
+/// # Arguments
+///
+/// * `unperturbed` - The unperturbed Hamiltonian H0.
+/// * `perturbation` - The perturbation V.
+/// * `degeneracy_tolerance` - Energies closer than the tolerance are treated as degenerate.
+///
+/// # Returns
+///
+/// * `Ok(Vec<PerturbedLevel>)` - The corrections for all levels of H0 in ascending order of the unperturbed energy.
+/// * `Err(StruqtureError::GenericError)` - The system has too many spins for the dense analysis.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+pub fn perturbation_analysis(
+    unperturbed: &SpinHamiltonianSystem,
+    perturbation: &SpinHamiltonianSystem,
+    degeneracy_tolerance: f64,
+) -> Result<Vec<PerturbedLevel>, StruqtureError> {
+    let number_spins = unperturbed.number_spins().max(perturbation.number_spins());
+    if number_spins > MAX_PERTURBATION_SPINS {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "Perturbation analysis is only supported for up to {} spins, the system has {} spins.",
+                MAX_PERTURBATION_SPINS, number_spins
+            ),
+        });
+    }
+    let dimension = 2_usize.pow(number_spins as u32);
+    let h0 = dense_matrix(unperturbed, number_spins)?;
+    let (energies, states) = hermitian_eigh(&h0);
+    // The perturbation in the eigenbasis of the unperturbed Hamiltonian
+    let v = states
+        .t()
+        .mapv(|x| x.conj())
+        .dot(&dense_matrix(perturbation, number_spins)?)
+        .dot(&states);
+
+    let mut levels: Vec<PerturbedLevel> = Vec::new();
+    for level in degenerate_levels(&energies, degeneracy_tolerance) {
+        let degeneracy = level.len();
+        let energy = energies[level.clone()].iter().sum::<f64>() / degeneracy as f64;
+        let first_order = v.slice(s![level.clone(), level.clone()]).to_owned();
+        let mut second_order: Array2<Complex64> = Array2::zeros((degeneracy, degeneracy));
+        for m in (0..dimension).filter(|m| !level.contains(m)) {
+            let denominator = energy - energies[m];
+            for (i, k) in level.clone().enumerate() {
+                for (j, l) in level.clone().enumerate() {
+                    second_order[[i, j]] += v[[k, m]] * v[[m, l]] / denominator;
+                }
+            }
+        }
+
+        let (first_order_corrections, first_order_states) = hermitian_eigh(&first_order);
+        let mut second_order_corrections: Vec<f64> = Vec::with_capacity(degeneracy);
+        for sublevel in degenerate_levels(&first_order_corrections, degeneracy_tolerance) {
+            let sublevel_states = first_order_states.slice(s![.., sublevel]);
+            let (corrections, _) = hermitian_eigh(
+                &sublevel_states
+                    .t()
+                    .mapv(|x| x.conj())
+                    .dot(&second_order)
+                    .dot(&sublevel_states),
+            );
+            second_order_corrections.extend(corrections);
+        }
+
+        let level_states = states.slice(s![.., level]);
+        let effective = Array2::<Complex64>::eye(degeneracy) * energy + first_order + second_order;
+        let effective_hamiltonian = pauli_decomposition(
+            &level_states
+                .dot(&effective)
+                .dot(&level_states.t().mapv(|x| x.conj())),
+            number_spins,
+        );
+        levels.push(PerturbedLevel {
+            unperturbed_energy: energy,
+            degeneracy,
+            first_order_corrections,
+            second_order_corrections,
+            effective_hamiltonian,
+        });
+    }
+    Ok(levels)
+}
+
+/// Returns the dense matrix representation of a SpinHamiltonianSystem.
+fn dense_matrix(
+    hamiltonian: &SpinHamiltonianSystem,
+    number_spins: usize,
+) -> Result<Array2<Complex64>, StruqtureError> {
+    let dimension = 2_usize.pow(number_spins as u32);
+    let mut matrix: Array2<Complex64> = Array2::zeros((dimension, dimension));
+    for ((row, column), value) in hamiltonian.sparse_matrix(Some(number_spins))? {
+        matrix[[row, column]] = value;
+    }
+    Ok(matrix)
+}
+
+/// Decomposes a dense Hermitian matrix into PauliProducts.
+///
+/// Coefficients with an absolute value below 1e-14 are dropped.
+pub(crate) fn pauli_decomposition(
+    matrix: &Array2<Complex64>,
+    number_spins: usize,
+) -> SpinHamiltonian {
+    let dimension = 2_usize.pow(number_spins as u32);
+    let mut hamiltonian = SpinHamiltonian::new();
+    for string in 0..4_usize.pow(number_spins as u32) {
+        let paulis: Vec<SingleSpinOperator> = (0..number_spins)
+            .map(|spin| match (string / 4_usize.pow(spin as u32)) % 4 {
+                1 => SingleSpinOperator::X,
+                2 => SingleSpinOperator::Y,
+                3 => SingleSpinOperator::Z,
+                _ => SingleSpinOperator::Identity,
+            })
+            .collect();
+        let flip_mask = paulis
+            .iter()
+            .enumerate()
+            .filter(|(_, pauli)| matches!(pauli, SingleSpinOperator::X | SingleSpinOperator::Y))
+            .fold(0, |mask, (spin, _)| mask | (1 << spin));
+        // Tr(M P) = \sum_j M[j, j ^ flip] <j ^ flip| P |j>
+        let mut trace = Complex64::new(0.0, 0.0);
+        for column in 0..dimension {
+            let mut phase = Complex64::new(1.0, 0.0);
+            for (spin, pauli) in paulis.iter().enumerate() {
+                let bit = (column >> spin) & 1;
+                phase *= match (pauli, bit) {
+                    (SingleSpinOperator::Y, 0) => Complex64::new(0.0, 1.0),
+                    (SingleSpinOperator::Y, _) => Complex64::new(0.0, -1.0),
+                    (SingleSpinOperator::Z, 1) => Complex64::new(-1.0, 0.0),
+                    _ => Complex64::new(1.0, 0.0),
+                };
+            }
+            trace += matrix[[column, column ^ flip_mask]] * phase;
+        }
+        let coefficient = trace.re / dimension as f64;
+        if coefficient.abs() > 1e-14 {
+            let product = paulis
+                .into_iter()
+                .enumerate()
+                .filter(|(_, pauli)| *pauli != SingleSpinOperator::Identity)
+                .fold(PauliProduct::new(), |product, (spin, pauli)| {
+                    product.set_pauli(spin, pauli)
+                });
+            hamiltonian
+                .add_operator_product(product, CalculatorFloat::from(coefficient))
+                .expect("Internal bug in add_operator_product.");
+        }
+    }
+    hamiltonian
+}
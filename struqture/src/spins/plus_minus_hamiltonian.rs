@@ -0,0 +1,860 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    PauliProduct, PlusMinusOperator, PlusMinusProduct, SpinHamiltonian, SpinHamiltonianSystem,
+};
+use crate::{
+    canonical_text_document, canonical_text_entries, hermitian_coefficient_1_norm,
+    parse_canonical_text_entries, split_canonical_text, CanonicalText, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnState, SpectralNormBound, StruqtureError,
+    StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy,
+};
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::{Entry, Iter, Keys, Values};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::IndexMap;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::{Entry, Iter, Keys, Values};
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::HashMap;
+
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+/// PlusMinusHamiltonians are hermitian combinations of PlusMinusProducts with specific CalculatorComplex coefficients.
+///
+/// PlusMinusHamiltonian is the hermitian equivalent of PlusMinusOperator. Every PlusMinusProduct
+/// that is not naturally hermitian (contains a + or - operator) represents the product and its
+/// hermitian conjugate, c p + c^* p^\dagger. Only one of the two products is stored: the smaller one
+/// in the ordering of PlusMinusProducts, so for example 0+1- is stored instead of 0-1+.
+/// Products that are added in the other form are replaced by their hermitian conjugate and the
+/// coefficient is conjugated. Naturally hermitian products (only Z operators) need real coefficients.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{PlusMinusProduct, PlusMinusHamiltonian};
+///
+/// let mut ph = PlusMinusHamiltonian::new();
+///
+/// // Representing the hamiltonian $ 1/2 (\sigma_0^{+} \sigma_1^{-} + h.c.) + 1/5 \sigma_0^{z} $
+/// let pp_0p1m = PlusMinusProduct::new().plus(0).minus(1);
+/// let pp_0z = PlusMinusProduct::new().z(0);
+/// ph.add_operator_product(pp_0p1m.clone(), CalculatorComplex::from(0.5)).unwrap();
+/// ph.add_operator_product(pp_0z.clone(), CalculatorComplex::from(0.2)).unwrap();
+///
+/// // Access what you set:
+/// assert_eq!(ph.get(&pp_0p1m), &CalculatorComplex::from(0.5));
+/// assert_eq!(ph.get(&pp_0z), &CalculatorComplex::from(0.2));
+///
+/// // The hermitian conjugate is stored in the canonical form
+/// let pp_0m1p = PlusMinusProduct::new().minus(0).plus(1);
+/// ph.add_operator_product(pp_0m1p, CalculatorComplex::new(0.0, 1.0)).unwrap();
+/// assert_eq!(ph.get(&pp_0p1m), &CalculatorComplex::new(0.5, -1.0));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "PlusMinusHamiltonianSerialize")]
+#[serde(into = "PlusMinusHamiltonianSerialize")]
+pub struct PlusMinusHamiltonian {
+    // The internal HashMap of canonical PlusMinusProducts and coefficients (CalculatorComplex)
+    #[cfg(feature = "indexed_map_iterators")]
+    internal_map: IndexMap<PlusMinusProduct, CalculatorComplex>,
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    internal_map: HashMap<PlusMinusProduct, CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
+}
+
+impl crate::MinSupportedVersion for PlusMinusHamiltonian {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for PlusMinusHamiltonian {
+    fn schema_name() -> String {
+        "PlusMinusHamiltonian".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <PlusMinusHamiltonianSerialize>::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct PlusMinusHamiltonianSerialize {
+    items: Vec<(PlusMinusProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
+    _struqture_version: StruqtureVersionSerializable,
+}
+
+impl Serialize for PlusMinusHamiltonianSerialize {
+    /// Serialization function for PlusMinusHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - PlusMinusHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of PlusMinusHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "PlusMinusHamiltonianSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
+impl TryFrom<PlusMinusHamiltonianSerialize> for PlusMinusHamiltonian {
+    type Error = StruqtureError;
+    fn try_from(value: PlusMinusHamiltonianSerialize) -> Result<Self, Self::Error> {
+        let mut new_hamiltonian = PlusMinusHamiltonian::with_capacity(value.items.len());
+        for (key, real, imag) in value.items {
+            new_hamiltonian.add_operator_product(key, CalculatorComplex { re: real, im: imag })?;
+        }
+        new_hamiltonian.zero_term_policy = value.zero_term_policy;
+        Ok(new_hamiltonian)
+    }
+}
+
+impl From<PlusMinusHamiltonian> for PlusMinusHamiltonianSerialize {
+    fn from(value: PlusMinusHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
+        let new_hamiltonian: Vec<(PlusMinusProduct, CalculatorFloat, CalculatorFloat)> = value
+            .into_iter()
+            .map(|(key, val)| (key, val.re, val.im))
+            .collect();
+        let current_version = StruqtureVersionSerializable {
+            major_version: 1,
+            minor_version: 8,
+        };
+        Self {
+            items: new_hamiltonian,
+            zero_term_policy,
+            _struqture_version: current_version,
+        }
+    }
+}
+
+impl crate::SerializeCanonical for PlusMinusHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = PlusMinusHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
+/// Returns the canonical form of a (PlusMinusProduct, coefficient) pair.
+///
+/// The canonical product is the smaller one of the product and its hermitian conjugate,
+/// the coefficient is conjugated when the product is replaced.
+fn canonical_term(
+    key: PlusMinusProduct,
+    value: CalculatorComplex,
+) -> (PlusMinusProduct, CalculatorComplex) {
+    let (conjugated_key, _) = key.hermitian_conjugate();
+    if conjugated_key < key {
+        (conjugated_key, value.conj())
+    } else {
+        (key, value)
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for PlusMinusHamiltonian {
+    type IteratorType = Iter<'a, Self::Index, Self::Value>;
+    type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
+    type ValueIteratorType = Values<'a, Self::Index, Self::Value>;
+    type Value = CalculatorComplex;
+    type Index = PlusMinusProduct;
+
+    /// Gets the coefficient of a canonical PlusMinusProduct key.
+    ///
+    /// The coefficient of the hermitian conjugate of a stored key is the complex conjugate of the
+    /// stored coefficient and is not returned by this function.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The canonical PlusMinusProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `&CalculatorComplex` - The coefficient of the key, zero if the key is not stored.
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> Self::IteratorType {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> Self::KeyIteratorType {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> Self::ValueIteratorType {
+        self.internal_map.values()
+    }
+
+    #[cfg(feature = "indexed_map_iterators")]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        let (key, _) = canonical_term(key.clone(), CalculatorComplex::ZERO);
+        self.internal_map.shift_remove(&key)
+    }
+
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        let (key, _) = canonical_term(key.clone(), CalculatorComplex::ZERO);
+        self.internal_map.remove(&key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        let mut empty_clone = match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the PlusMinusHamiltonian with the given (PlusMinusProduct key, CalculatorComplex value) pair.
+    ///
+    /// A key that is not canonical is replaced by its hermitian conjugate and the value is conjugated.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PlusMinusProduct key to set in the PlusMinusHamiltonian.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        let (key, value) = canonical_term(key, value);
+        if !self.zero_term_policy.drops(&value) {
+            // Catch on diagonals with non-zero imaginary values
+            if key.is_natural_hermitian() && value.im != CalculatorFloat::ZERO {
+                Err(StruqtureError::NonHermitianOperator)
+            } else {
+                Ok(self.internal_map.insert(key, value))
+            }
+        } else {
+            match self.internal_map.entry(key) {
+                #[cfg(feature = "indexed_map_iterators")]
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                #[cfg(not(feature = "indexed_map_iterators"))]
+                Entry::Occupied(val) => Ok(Some(val.remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+
+    /// Adds a new (PlusMinusProduct key, CalculatorComplex value) pair to the PlusMinusHamiltonian.
+    ///
+    /// A key that is not canonical is replaced by its hermitian conjugate and the value is conjugated.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PlusMinusProduct key to added to the PlusMinusHamiltonian.
+    /// * `value` - The corresponding CalculatorComplex value to add for the key in the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The (key, value) pair was successfully added.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
+    fn add_operator_product(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<(), StruqtureError> {
+        let (key, value) = canonical_term(key, value);
+        let new_val = value + self.get(&key).clone();
+        if key.is_natural_hermitian() && new_val.im != CalculatorFloat::ZERO {
+            Err(StruqtureError::NonHermitianOperator)
+        } else {
+            self.set(key, new_val)?;
+            Ok(())
+        }
+    }
+}
+
+impl<'a> OperateOnState<'a> for PlusMinusHamiltonian {
+    /// Returns the hermitian conjugate of the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian conjugate of Self.
+    fn hermitian_conjugate(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<'a> OperateOnConstant<'a> for PlusMinusHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PlusMinusProduct::new()
+    }
+}
+
+/// Implements the default function (Default trait) of PlusMinusHamiltonian (an empty PlusMinusHamiltonian).
+///
+impl Default for PlusMinusHamiltonian {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the PlusMinusHamiltonian
+///
+impl PlusMinusHamiltonian {
+    /// Creates a new PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PlusMinusHamiltonian.
+    pub fn new() -> Self {
+        PlusMinusHamiltonian {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::new(),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Creates a new PlusMinusHamiltonian with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the hamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PlusMinusHamiltonian.
+    pub fn with_capacity(capacity: usize) -> Self {
+        PlusMinusHamiltonian {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::with_capacity(capacity),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Converts the PlusMinusHamiltonian into a SpinHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The optional number of spins of the SpinHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonianSystem)` - The PlusMinusHamiltonian converted into a SpinHamiltonianSystem.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - Number of spins in entry exceeds number of spins in system.
+    pub fn to_spin_hamiltonian_system(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<SpinHamiltonianSystem, StruqtureError> {
+        SpinHamiltonianSystem::from_hamiltonian(SpinHamiltonian::from(self.clone()), number_spins)
+    }
+}
+
+impl From<PlusMinusHamiltonian> for PlusMinusOperator {
+    /// Converts a PlusMinusHamiltonian into a PlusMinusOperator, writing out the hermitian conjugate terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The PlusMinusHamiltonian to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusHamiltonian converted into a PlusMinusOperator.
+    fn from(value: PlusMinusHamiltonian) -> Self {
+        let mut new_operator = PlusMinusOperator::with_capacity(2 * value.len());
+        new_operator
+            .set_zero_term_policy(value.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
+        for (product, val) in value.into_iter() {
+            if !product.is_natural_hermitian() {
+                let (conjugated_product, _) = product.hermitian_conjugate();
+                new_operator
+                    .add_operator_product(conjugated_product, val.conj())
+                    .expect("Internal bug in add_operator_product");
+            }
+            new_operator
+                .add_operator_product(product, val)
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+}
+
+impl TryFrom<PlusMinusOperator> for PlusMinusHamiltonian {
+    type Error = StruqtureError;
+
+    /// Tries to convert a PlusMinusOperator into a PlusMinusHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The PlusMinusOperator to try to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusOperator converted into a PlusMinusHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - The coefficients of a product and its hermitian conjugate are not complex conjugates, or a naturally hermitian product has a complex coefficient.
+    fn try_from(value: PlusMinusOperator) -> Result<Self, Self::Error> {
+        let mut new_hamiltonian = PlusMinusHamiltonian::with_capacity(value.len());
+        for (product, val) in value.iter() {
+            let (conjugated_product, _) = product.hermitian_conjugate();
+            if product.is_natural_hermitian() {
+                new_hamiltonian.add_operator_product(product.clone(), val.clone())?;
+            } else if value.get(&conjugated_product) != &val.conj() {
+                return Err(StruqtureError::NonHermitianOperator);
+            } else if product < &conjugated_product {
+                new_hamiltonian.add_operator_product(product.clone(), val.clone())?;
+            }
+        }
+        Ok(new_hamiltonian)
+    }
+}
+
+impl From<SpinHamiltonian> for PlusMinusHamiltonian {
+    /// Converts a SpinHamiltonian into a PlusMinusHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinHamiltonian to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinHamiltonian converted into a PlusMinusHamiltonian.
+    fn from(value: SpinHamiltonian) -> Self {
+        let mut new_hamiltonian = PlusMinusHamiltonian::with_capacity(value.len());
+        // The transscribed operator is hermitian by construction, only the canonical half of the terms is kept
+        for (product, val) in PlusMinusOperator::from(value).into_iter() {
+            if product.is_natural_hermitian() {
+                new_hamiltonian
+                    .add_operator_product(product, CalculatorComplex::from(val.re))
+                    .expect("Internal bug in add_operator_product");
+            } else if product < product.hermitian_conjugate().0 {
+                new_hamiltonian
+                    .add_operator_product(product, val)
+                    .expect("Internal bug in add_operator_product");
+            }
+        }
+        new_hamiltonian
+    }
+}
+
+impl From<PlusMinusHamiltonian> for SpinHamiltonian {
+    /// Converts a PlusMinusHamiltonian into a SpinHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The PlusMinusHamiltonian to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusHamiltonian converted into a SpinHamiltonian.
+    fn from(value: PlusMinusHamiltonian) -> Self {
+        let mut new_hamiltonian = SpinHamiltonian::with_capacity(2 * value.len());
+        for (product, val) in value.into_iter() {
+            // c p + c^* p^\dagger contributes 2 Re(c a) for every PauliProduct P with prefactor a in p
+            let multiplicity = if product.is_natural_hermitian() {
+                1.0
+            } else {
+                2.0
+            };
+            let transscribed_vector: Vec<(PauliProduct, Complex64)> = product.into();
+            for (transscribed_product, prefactor) in transscribed_vector {
+                new_hamiltonian
+                    .add_operator_product(
+                        transscribed_product,
+                        (val.clone() * prefactor).re * multiplicity,
+                    )
+                    .expect("Internal bug in add_operator_product");
+            }
+        }
+        new_hamiltonian
+    }
+}
+
+impl From<SpinHamiltonianSystem> for PlusMinusHamiltonian {
+    /// Converts a SpinHamiltonianSystem into a PlusMinusHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinHamiltonianSystem to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinHamiltonianSystem converted into a PlusMinusHamiltonian.
+    fn from(value: SpinHamiltonianSystem) -> Self {
+        PlusMinusHamiltonian::from(value.hamiltonian().clone())
+    }
+}
+
+/// Implements the negative sign function of PlusMinusHamiltonian.
+///
+impl ops::Neg for PlusMinusHamiltonian {
+    type Output = PlusMinusHamiltonian;
+    /// Implement minus sign for PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusHamiltonian * -1.
+    fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val.neg());
+        }
+        PlusMinusHamiltonian {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the plus function of PlusMinusHamiltonian by PlusMinusHamiltonian.
+///
+impl<T, V> ops::Add<T> for PlusMinusHamiltonian
+where
+    T: IntoIterator<Item = (PlusMinusProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Result<Self, StruqtureError>;
+    /// Implements `+` (add) for two PlusMinusHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusHamiltonian to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two PlusMinusHamiltonians added together.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
+    fn add(mut self, other: T) -> Self::Output {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))?;
+        }
+        Ok(self)
+    }
+}
+
+/// Implements the minus function of PlusMinusHamiltonian by PlusMinusHamiltonian.
+///
+impl<T, V> ops::Sub<T> for PlusMinusHamiltonian
+where
+    T: IntoIterator<Item = (PlusMinusProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Result<Self, StruqtureError>;
+    /// Implements `-` (subtract) for two PlusMinusHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusHamiltonian to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two PlusMinusHamiltonians subtracted.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian (on-diagonal term), but its corresponding value is not real.
+    fn sub(mut self, other: T) -> Self::Output {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)?;
+        }
+        Ok(self)
+    }
+}
+
+/// Implements the multiplication function of PlusMinusHamiltonian by CalculatorFloat.
+///
+impl ops::Mul<CalculatorFloat> for PlusMinusHamiltonian {
+    type Output = Self;
+    /// Implement `*` for PlusMinusHamiltonian and CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusHamiltonian multiplied by the CalculatorFloat.
+    fn mul(self, other: CalculatorFloat) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val * other.clone());
+        }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
+        PlusMinusHamiltonian {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the multiplication function of PlusMinusHamiltonian by CalculatorComplex.
+///
+impl ops::Mul<CalculatorComplex> for PlusMinusHamiltonian {
+    type Output = PlusMinusOperator;
+    /// Implement `*` for PlusMinusHamiltonian and CalculatorComplex.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `PlusMinusOperator` - The PlusMinusHamiltonian multiplied by the CalculatorComplex.
+    fn mul(self, other: CalculatorComplex) -> Self::Output {
+        PlusMinusOperator::from(self) * other
+    }
+}
+
+/// Implements the multiplication function of PlusMinusHamiltonian by PlusMinusHamiltonian.
+///
+impl ops::Mul<PlusMinusHamiltonian> for PlusMinusHamiltonian {
+    type Output = PlusMinusOperator;
+    /// Implement `*` for PlusMinusHamiltonian and PlusMinusHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusHamiltonian to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `PlusMinusOperator` - The two PlusMinusHamiltonians multiplied.
+    fn mul(self, other: PlusMinusHamiltonian) -> Self::Output {
+        PlusMinusOperator::from(self) * PlusMinusOperator::from(other)
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of PlusMinusHamiltonian.
+///
+impl IntoIterator for PlusMinusHamiltonian {
+    type Item = (PlusMinusProduct, CalculatorComplex);
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    type IntoIter = std::collections::hash_map::IntoIter<PlusMinusProduct, CalculatorComplex>;
+    #[cfg(feature = "indexed_map_iterators")]
+    type IntoIter = indexmap::map::IntoIter<PlusMinusProduct, CalculatorComplex>;
+
+    /// Returns the PlusMinusHamiltonian in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The PlusMinusHamiltonian in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference PlusMinusHamiltonian.
+///
+impl<'a> IntoIterator for &'a PlusMinusHamiltonian {
+    type Item = (&'a PlusMinusProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, PlusMinusProduct, CalculatorComplex>;
+
+    /// Returns the reference PlusMinusHamiltonian in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference PlusMinusHamiltonian in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of PlusMinusHamiltonian.
+///
+impl FromIterator<(PlusMinusProduct, CalculatorComplex)> for PlusMinusHamiltonian {
+    /// Returns the object in PlusMinusHamiltonian form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in PlusMinusHamiltonian form.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    fn from_iter<I: IntoIterator<Item = (PlusMinusProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut so = PlusMinusHamiltonian::new();
+        for (pp, cc) in iter {
+            so.add_operator_product(pp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+        so
+    }
+}
+
+/// Implements the extend function (Extend trait) of PlusMinusHamiltonian.
+///
+impl Extend<(PlusMinusProduct, CalculatorComplex)> for PlusMinusHamiltonian {
+    /// Extends the PlusMinusHamiltonian by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the PlusMinusHamiltonian.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    fn extend<I: IntoIterator<Item = (PlusMinusProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (pp, cc) in iter {
+            self.add_operator_product(pp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of PlusMinusHamiltonian.
+///
+impl fmt::Display for PlusMinusHamiltonian {
+    /// Formats the PlusMinusHamiltonian using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted PlusMinusHamiltonian.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "PlusMinusHamiltonian{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
+
+impl CanonicalText for PlusMinusHamiltonian {
+    /// Returns the canonical text representation of the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the PlusMinusHamiltonian.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "PlusMinusHamiltonian",
+            &[],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a PlusMinusHamiltonian from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusHamiltonian represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a PlusMinusHamiltonian.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the PlusMinusHamiltonian.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "PlusMinusHamiltonian", &[])?;
+        let mut new_self = PlusMinusHamiltonian::new();
+        for (key, value) in
+            parse_canonical_text_entries::<PlusMinusProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for PlusMinusHamiltonian {
+    /// Returns an upper bound of the spectral norm of the PlusMinusHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The PlusMinusHamiltonian contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        hermitian_coefficient_1_norm(self.iter())
+    }
+}
@@ -13,12 +13,19 @@
 use super::{DecoherenceProduct, SpinLindbladNoiseOperator};
 use crate::fermions::FermionLindbladNoiseOperator;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{PlusMinusOperator, PlusMinusProduct};
-use crate::{OperateOnDensityMatrix, StruqtureError, StruqtureVersionSerializable};
+use crate::spins::{PlusMinusOperator, PlusMinusProduct, SpinLocality};
+use crate::{
+    canonical_noise_text_entries, canonical_text_document, noise_adjoint_violations,
+    noise_symmetrized_entries, parse_canonical_noise_text_entries, split_canonical_text,
+    CanonicalText, OperateOnDensityMatrix, StruqtureError, StruqtureVersionSerializable,
+    ZeroTermPolicy,
+};
 use itertools::Itertools;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeSet;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -65,6 +72,8 @@ pub struct PlusMinusLindbladNoiseOperator {
     internal_map: IndexMap<(PlusMinusProduct, PlusMinusProduct), CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<(PlusMinusProduct, PlusMinusProduct), CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for PlusMinusLindbladNoiseOperator {
@@ -84,7 +93,7 @@ impl schemars::JsonSchema for PlusMinusLindbladNoiseOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct PlusMinusLindbladNoiseOperatorSerialize {
@@ -95,25 +104,65 @@ struct PlusMinusLindbladNoiseOperatorSerialize {
         CalculatorFloat,
         CalculatorFloat,
     )>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// The struqture version
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for PlusMinusLindbladNoiseOperatorSerialize {
+    /// Serialization function for PlusMinusLindbladNoiseOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - PlusMinusLindbladNoiseOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of PlusMinusLindbladNoiseOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "PlusMinusLindbladNoiseOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<PlusMinusLindbladNoiseOperatorSerialize> for PlusMinusLindbladNoiseOperator {
     fn from(value: PlusMinusLindbladNoiseOperatorSerialize) -> Self {
-        let new_noise_op: PlusMinusLindbladNoiseOperator = value
+        let mut new_noise_op: PlusMinusLindbladNoiseOperator = value
             .items
             .into_iter()
             .map(|(left, right, real, imag)| {
                 ((left, right), CalculatorComplex { re: real, im: imag })
             })
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<PlusMinusLindbladNoiseOperator> for PlusMinusLindbladNoiseOperatorSerialize {
     fn from(value: PlusMinusLindbladNoiseOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(
             PlusMinusProduct,
             PlusMinusProduct,
@@ -129,11 +178,23 @@ impl From<PlusMinusLindbladNoiseOperator> for PlusMinusLindbladNoiseOperatorSeri
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for PlusMinusLindbladNoiseOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = PlusMinusLindbladNoiseOperatorSerialize::from(self.clone());
+        serializable
+            .items
+            .sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for PlusMinusLindbladNoiseOperator {
     type Index = (PlusMinusProduct, PlusMinusProduct);
     type Value = CalculatorComplex;
@@ -178,10 +239,25 @@ impl<'a> OperateOnDensityMatrix<'a> for PlusMinusLindbladNoiseOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the PlusMinusLindbladNoiseOperator with the given ((PlusMinusProduct, PlusMinusProduct) key, CalculatorComplex value) pair.
@@ -200,7 +276,7 @@ impl<'a> OperateOnDensityMatrix<'a> for PlusMinusLindbladNoiseOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -236,6 +312,7 @@ impl PlusMinusLindbladNoiseOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -254,6 +331,7 @@ impl PlusMinusLindbladNoiseOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -347,6 +425,46 @@ impl PlusMinusLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the keys (A, B) whose coefficient is not the complex conjugate of the coefficient of (B, A).
+    ///
+    /// The Kossakowski matrix of a physical noise operator is self-adjoint, so every entry (A, B)
+    /// has to be matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(PlusMinusProduct, PlusMinusProduct)>` - The keys violating the self-adjoint structure.
+    pub fn adjoint_violations(&self) -> Vec<(PlusMinusProduct, PlusMinusProduct)> {
+        noise_adjoint_violations(self.iter())
+    }
+
+    /// Returns true if every entry (A, B) is matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the PlusMinusLindbladNoiseOperator is self-adjoint.
+    pub fn is_self_adjoint(&self) -> bool {
+        self.adjoint_violations().is_empty()
+    }
+
+    /// Enforces the self-adjoint structure of the PlusMinusLindbladNoiseOperator.
+    ///
+    /// The coefficient of every violating entry (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(PlusMinusProduct, PlusMinusProduct)>` - The keys whose coefficients were changed or added.
+    pub fn symmetrize(&mut self) -> Vec<(PlusMinusProduct, PlusMinusProduct)> {
+        let changes = noise_symmetrized_entries(self.iter());
+        let mut changed_keys: Vec<(PlusMinusProduct, PlusMinusProduct)> =
+            Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            self.set(key.clone(), value)
+                .expect("Internal bug in set for a key already contained in transposed form.");
+            changed_keys.push(key);
+        }
+        changed_keys
+    }
 }
 
 impl From<PlusMinusLindbladNoiseOperator> for SpinLindbladNoiseOperator {
@@ -428,12 +546,14 @@ impl ops::Neg for PlusMinusLindbladNoiseOperator {
     ///
     /// * `Self` - The PlusMinusLindbladNoiseOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for (key, val) in self {
             internal.insert(key.clone(), val.neg());
         }
         PlusMinusLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -515,13 +635,16 @@ where
     ///
     /// * `Self` - The PlusMinusLindbladNoiseOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         PlusMinusLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -647,6 +770,57 @@ impl fmt::Display for PlusMinusLindbladNoiseOperator {
     }
 }
 
+impl CanonicalText for PlusMinusLindbladNoiseOperator {
+    /// Returns the canonical text representation of the PlusMinusLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the PlusMinusLindbladNoiseOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "PlusMinusLindbladNoiseOperator",
+            &[],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a PlusMinusLindbladNoiseOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladNoiseOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a PlusMinusLindbladNoiseOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the PlusMinusLindbladNoiseOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "PlusMinusLindbladNoiseOperator", &[])?;
+        let mut new_self = PlusMinusLindbladNoiseOperator::new();
+        for (key, value) in
+            parse_canonical_noise_text_entries::<PlusMinusProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpinLocality for PlusMinusLindbladNoiseOperator {
+    // From trait
+    fn term_supports(&self) -> Vec<BTreeSet<usize>> {
+        self.keys()
+            .map(|(left, right)| {
+                left.iter()
+                    .chain(right.iter())
+                    .map(|(index, _)| *index)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
 impl JordanWignerSpinToFermion for PlusMinusLindbladNoiseOperator {
     type Output = FermionLindbladNoiseOperator;
 
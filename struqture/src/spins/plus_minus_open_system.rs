@@ -0,0 +1,331 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    PlusMinusLindbladNoiseOperator, PlusMinusOperator, SpinHamiltonian, SpinHamiltonianSystem,
+    SpinLindbladNoiseOperator, SpinLindbladNoiseSystem, SpinLindbladOpenSystem,
+};
+use crate::{
+    split_canonical_open_system, CanonicalText, OpenSystem, OperateOnDensityMatrix, StruqtureError,
+};
+use qoqo_calculator::CalculatorFloat;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+use std::ops;
+
+/// PlusMinusLindbladOpenSystems are representations of open systems of spins in the PlusMinus basis, where a system (PlusMinusOperator) interacts with the environment via noise (PlusMinusLindbladNoiseOperator).
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{PlusMinusProduct, PlusMinusLindbladOpenSystem};
+///
+/// let mut system = PlusMinusLindbladOpenSystem::new();
+///
+/// let pp_0 = PlusMinusProduct::new().minus(0);
+/// let pp_1 = PlusMinusProduct::new().z(1);
+/// system.system_mut().set(pp_1.clone(), CalculatorComplex::from(0.5)).unwrap();
+/// system.noise_mut().set((pp_0.clone(), pp_0.clone()), CalculatorComplex::from(0.2)).unwrap();
+///
+/// // Access what you set:
+/// assert_eq!(system.system().get(&pp_1), &CalculatorComplex::from(0.5));
+/// assert_eq!(system.noise().get(&(pp_0.clone(), pp_0.clone())), &CalculatorComplex::from(0.2));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+pub struct PlusMinusLindbladOpenSystem {
+    /// The PlusMinusOperator representing the system terms of the open system
+    system: PlusMinusOperator,
+    /// The PlusMinusLindbladNoiseOperator representing the noise terms of the open system
+    noise: PlusMinusLindbladNoiseOperator,
+}
+
+impl crate::MinSupportedVersion for PlusMinusLindbladOpenSystem {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 1, 0)
+    }
+}
+
+impl crate::SerializeCanonical for PlusMinusLindbladOpenSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PlusMinusLindbladOpenSystem", 2)?;
+        state.serialize_field("system", &crate::Canonical(&self.system))?;
+        state.serialize_field("noise", &crate::Canonical(&self.noise))?;
+        state.end()
+    }
+}
+
+impl<'a> OpenSystem<'a> for PlusMinusLindbladOpenSystem {
+    type System = PlusMinusOperator;
+    type Noise = PlusMinusLindbladNoiseOperator;
+
+    // From trait
+    fn noise(&self) -> &Self::Noise {
+        &self.noise
+    }
+
+    // From trait
+    fn system(&self) -> &Self::System {
+        &self.system
+    }
+
+    // From trait
+    fn noise_mut(&mut self) -> &mut Self::Noise {
+        &mut self.noise
+    }
+
+    // From trait
+    fn system_mut(&mut self) -> &mut Self::System {
+        &mut self.system
+    }
+
+    // From trait
+    fn ungroup(self) -> (Self::System, Self::Noise) {
+        (self.system, self.noise)
+    }
+
+    /// Takes a tuple of a system (PlusMinusOperator) and a noise term (PlusMinusLindbladNoiseOperator) and combines them to be a PlusMinusLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - The PlusMinusOperator to have in the PlusMinusLindbladOpenSystem.
+    /// * `noise` - The PlusMinusLindbladNoiseOperator to have in the PlusMinusLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladOpenSystem with input system and noise terms.
+    fn group(system: Self::System, noise: Self::Noise) -> Result<Self, StruqtureError> {
+        Ok(Self { system, noise })
+    }
+
+    // From trait
+    fn empty_clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the PlusMinusLindbladOpenSystem
+///
+impl PlusMinusLindbladOpenSystem {
+    /// Creates a new PlusMinusLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) PlusMinusLindbladOpenSystem.
+    pub fn new() -> Self {
+        PlusMinusLindbladOpenSystem {
+            system: PlusMinusOperator::new(),
+            noise: PlusMinusLindbladNoiseOperator::new(),
+        }
+    }
+}
+
+impl From<SpinLindbladOpenSystem> for PlusMinusLindbladOpenSystem {
+    /// Converts a SpinLindbladOpenSystem into a PlusMinusLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinLindbladOpenSystem to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinLindbladOpenSystem converted into a PlusMinusLindbladOpenSystem.
+    fn from(value: SpinLindbladOpenSystem) -> Self {
+        PlusMinusLindbladOpenSystem {
+            system: PlusMinusOperator::from(value.system().hamiltonian().clone()),
+            noise: PlusMinusLindbladNoiseOperator::from(value.noise().operator().clone()),
+        }
+    }
+}
+
+impl TryFrom<PlusMinusLindbladOpenSystem> for SpinLindbladOpenSystem {
+    type Error = StruqtureError;
+
+    /// Tries to convert a PlusMinusLindbladOpenSystem into a SpinLindbladOpenSystem without a fixed number of spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The PlusMinusLindbladOpenSystem to try to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladOpenSystem converted into a SpinLindbladOpenSystem.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - The system part is not hermitian.
+    fn try_from(value: PlusMinusLindbladOpenSystem) -> Result<Self, Self::Error> {
+        let (system, noise) = value.ungroup();
+        SpinLindbladOpenSystem::group(
+            SpinHamiltonianSystem::from_hamiltonian(SpinHamiltonian::try_from(system)?, None)?,
+            SpinLindbladNoiseSystem::from_operator(SpinLindbladNoiseOperator::from(noise), None)?,
+        )
+    }
+}
+
+/// Implements the negative sign function of PlusMinusLindbladOpenSystem.
+///
+impl ops::Neg for PlusMinusLindbladOpenSystem {
+    type Output = Self;
+    /// Implement minus sign for PlusMinusLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusLindbladOpenSystem * -1.
+    fn neg(self) -> Self {
+        let (self_sys, self_noise) = self.ungroup();
+        Self {
+            system: -self_sys,
+            noise: -self_noise,
+        }
+    }
+}
+
+/// Implements the plus function of PlusMinusLindbladOpenSystem by PlusMinusLindbladOpenSystem.
+///
+impl ops::Add<PlusMinusLindbladOpenSystem> for PlusMinusLindbladOpenSystem {
+    type Output = Self;
+    /// Implements `+` (add) for two PlusMinusLindbladOpenSystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusLindbladOpenSystem to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PlusMinusLindbladOpenSystems added together.
+    fn add(self, other: PlusMinusLindbladOpenSystem) -> Self::Output {
+        let (self_sys, self_noise) = self.ungroup();
+        let (other_sys, other_noise) = other.ungroup();
+        Self {
+            system: self_sys + other_sys,
+            noise: self_noise + other_noise,
+        }
+    }
+}
+
+/// Implements the minus function of PlusMinusLindbladOpenSystem by PlusMinusLindbladOpenSystem.
+///
+impl ops::Sub<PlusMinusLindbladOpenSystem> for PlusMinusLindbladOpenSystem {
+    type Output = Self;
+    /// Implements `-` (subtract) for two PlusMinusLindbladOpenSystems.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusLindbladOpenSystem to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PlusMinusLindbladOpenSystems subtracted.
+    fn sub(self, other: PlusMinusLindbladOpenSystem) -> Self::Output {
+        let (self_sys, self_noise) = self.ungroup();
+        let (other_sys, other_noise) = other.ungroup();
+        Self {
+            system: self_sys - other_sys,
+            noise: self_noise - other_noise,
+        }
+    }
+}
+
+/// Implements the multiplication function of PlusMinusLindbladOpenSystem by CalculatorFloat.
+///
+impl ops::Mul<CalculatorFloat> for PlusMinusLindbladOpenSystem {
+    type Output = Self;
+
+    /// Implement `*` for PlusMinusLindbladOpenSystem and CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The PlusMinusLindbladOpenSystem multiplied by the CalculatorFloat.
+    fn mul(self, rhs: CalculatorFloat) -> Self::Output {
+        Self {
+            system: self.system * rhs.clone(),
+            noise: self.noise * rhs,
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of PlusMinusLindbladOpenSystem.
+///
+impl fmt::Display for PlusMinusLindbladOpenSystem {
+    /// Formats the PlusMinusLindbladOpenSystem using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted PlusMinusLindbladOpenSystem.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "PlusMinusLindbladOpenSystem{\n".to_string();
+        output.push_str("System: {\n");
+        for (key, val) in self.system.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push_str("}\n");
+        output.push_str("Noise: {\n");
+        for ((row, column), val) in self.noise.iter() {
+            writeln!(output, "({}, {}): {},", row, column, val)?;
+        }
+        output.push_str("}\n");
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
+
+impl CanonicalText for PlusMinusLindbladOpenSystem {
+    /// Returns the canonical text representation of the PlusMinusLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the PlusMinusLindbladOpenSystem.
+    fn to_canonical_text(&self) -> String {
+        format!(
+            "PlusMinusLindbladOpenSystem\n{}{}",
+            self.system().to_canonical_text(),
+            self.noise().to_canonical_text()
+        )
+    }
+
+    /// Creates a PlusMinusLindbladOpenSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusLindbladOpenSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a PlusMinusLindbladOpenSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the PlusMinusLindbladOpenSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (system, noise) = split_canonical_open_system(
+            text,
+            "PlusMinusLindbladOpenSystem",
+            "PlusMinusLindbladNoiseOperator",
+        )?;
+        Self::group(
+            PlusMinusOperator::from_canonical_text(&system)?,
+            PlusMinusLindbladNoiseOperator::from_canonical_text(&noise)?,
+        )
+    }
+}
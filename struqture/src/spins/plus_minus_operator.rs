@@ -15,12 +15,15 @@ use crate::fermions::FermionOperator;
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::spins::{PlusMinusProduct, SpinHamiltonian};
 use crate::{
-    OperateOnDensityMatrix, OperateOnState, StruqtureError, StruqtureVersionSerializable,
-    SymmetricIndex,
+    canonical_text_document, canonical_text_entries, parse_canonical_text_entries,
+    split_canonical_text, CanonicalText, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    SpectralNormBound, StruqtureError, StruqtureVersionSerializable, SymmetricIndex,
+    ZeroTermPolicy,
 };
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 
 #[cfg(feature = "indexed_map_iterators")]
 use indexmap::map::{Entry, Iter, Keys, Values};
@@ -68,6 +71,8 @@ pub struct PlusMinusOperator {
     internal_map: IndexMap<PlusMinusProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<PlusMinusProduct, CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for PlusMinusOperator {
@@ -87,27 +92,66 @@ impl schemars::JsonSchema for PlusMinusOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct PlusMinusOperatorSerialize {
     items: Vec<(PlusMinusProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for PlusMinusOperatorSerialize {
+    /// Serialization function for PlusMinusOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - PlusMinusOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of PlusMinusOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "PlusMinusOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<PlusMinusOperatorSerialize> for PlusMinusOperator {
     fn from(value: PlusMinusOperatorSerialize) -> Self {
-        let new_noise_op: PlusMinusOperator = value
+        let mut new_noise_op: PlusMinusOperator = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<PlusMinusOperator> for PlusMinusOperatorSerialize {
     fn from(value: PlusMinusOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(PlusMinusProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -118,11 +162,21 @@ impl From<PlusMinusOperator> for PlusMinusOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for PlusMinusOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = PlusMinusOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for PlusMinusOperator {
     type IteratorType = Iter<'a, Self::Index, Self::Value>;
     type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
@@ -167,10 +221,25 @@ impl<'a> OperateOnDensityMatrix<'a> for PlusMinusOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the PlusMinusOperator with the given (PlusMinusProduct key, CalculatorComplex value) pair.
@@ -189,7 +258,7 @@ impl<'a> OperateOnDensityMatrix<'a> for PlusMinusOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -217,6 +286,13 @@ impl<'a> OperateOnState<'a> for PlusMinusOperator {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for PlusMinusOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PlusMinusProduct::new()
+    }
+}
+
 /// Implements the default function (Default trait) of PlusMinusOperator (an empty PlusMinusOperator).
 ///
 impl Default for PlusMinusOperator {
@@ -239,6 +315,7 @@ impl PlusMinusOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -257,6 +334,7 @@ impl PlusMinusOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -438,6 +516,7 @@ impl ops::Neg for PlusMinusOperator {
     ///
     /// * `Self` - The PlusMinusOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -447,6 +526,7 @@ impl ops::Neg for PlusMinusOperator {
         }
         PlusMinusOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -528,6 +608,7 @@ where
     ///
     /// * `Self` - The PlusMinusOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -536,12 +617,51 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         PlusMinusOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
 
+/// Implements the multiplication function of PlusMinusOperator by PlusMinusOperator.
+///
+impl ops::Mul<PlusMinusOperator> for PlusMinusOperator {
+    type Output = Self;
+    /// Implement `*` for PlusMinusOperator and PlusMinusOperator.
+    ///
+    /// The product is expressed in the +/-/Z basis again, using + * - = 1/2 (1 + Z),
+    /// - * + = 1/2 (1 - Z) and + * + = - * - = 0 on each spin.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The PlusMinusOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two PlusMinusOperators multiplied.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn mul(self, other: PlusMinusOperator) -> Self {
+        let mut plus_minus_op = PlusMinusOperator::with_capacity(self.len() * other.len());
+        plus_minus_op.zero_term_policy = self.zero_term_policy;
+        for (pps, vals) in self {
+            for (ppo, valo) in other.iter() {
+                for (ppp, coefficient) in PlusMinusProduct::multiply(pps.clone(), ppo.clone()) {
+                    let coefficient = valo.clone() * coefficient * vals.clone();
+                    plus_minus_op
+                        .add_operator_product(ppp, coefficient)
+                        .expect("Internal bug in add_operator_product");
+                }
+            }
+        }
+        plus_minus_op
+    }
+}
+
 /// Implements the into_iter function (IntoIterator trait) of PlusMinusOperator.
 ///
 impl IntoIterator for PlusMinusOperator {
@@ -646,6 +766,55 @@ impl fmt::Display for PlusMinusOperator {
     }
 }
 
+impl CanonicalText for PlusMinusOperator {
+    /// Returns the canonical text representation of the PlusMinusOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the PlusMinusOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "PlusMinusOperator",
+            &[],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a PlusMinusOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The PlusMinusOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a PlusMinusOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the PlusMinusOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "PlusMinusOperator", &[])?;
+        let mut new_self = PlusMinusOperator::new();
+        for (key, value) in
+            parse_canonical_text_entries::<PlusMinusProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for PlusMinusOperator {
+    /// Returns an upper bound of the spectral norm of the PlusMinusOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The PlusMinusOperator contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.coefficient_1_norm()
+    }
+}
+
 impl JordanWignerSpinToFermion for PlusMinusOperator {
     type Output = FermionOperator;
 
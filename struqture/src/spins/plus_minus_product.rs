@@ -457,6 +457,8 @@ impl crate::MinSupportedVersion for PlusMinusProduct {
     }
 }
 
+impl crate::SerializeCanonical for PlusMinusProduct {}
+
 /// Implementing serde serialization writing directly to string.
 ///
 impl Serialize for PlusMinusProduct {
@@ -831,6 +833,56 @@ impl PlusMinusProduct {
             items: TinyVec::<[(usize, SinglePlusMinusOperator); 5]>::with_capacity(cap),
         }
     }
+
+    /// Implements multiplication function for a PlusMinusProduct by a PlusMinusProduct.
+    ///
+    /// Since the product of two SinglePlusMinusOperators on the same spin can be a sum
+    /// (e.g. + * - = 1/2 (1 + Z)), the product is returned as a list of terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left-hand PlusMinusProduct to be multiplied.
+    /// * `right` - Right-hand PlusMinusProduct to be multiplied.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(Self, Complex64)>` - The PlusMinusProducts and prefactors of the product, empty if the product vanishes.
+    pub fn multiply(left: PlusMinusProduct, right: PlusMinusProduct) -> Vec<(Self, Complex64)> {
+        let mut indices: Vec<usize> = left
+            .iter()
+            .chain(right.iter())
+            .map(|(index, _)| *index)
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let mut terms: Vec<(PlusMinusProduct, Complex64)> = vec![(
+            PlusMinusProduct::with_capacity(indices.len()),
+            Complex64::new(1.0, 0.0),
+        )];
+        for index in indices {
+            let single_terms = SinglePlusMinusOperator::multiply(
+                left.get(&index)
+                    .copied()
+                    .unwrap_or(SinglePlusMinusOperator::Identity),
+                right
+                    .get(&index)
+                    .copied()
+                    .unwrap_or(SinglePlusMinusOperator::Identity),
+            );
+            terms = terms
+                .into_iter()
+                .flat_map(|(product, prefactor)| {
+                    single_terms.iter().map(move |(single, single_prefactor)| {
+                        (
+                            product.clone().set_pauli(index, *single),
+                            prefactor * single_prefactor,
+                        )
+                    })
+                })
+                .collect();
+        }
+        terms
+    }
 }
 
 impl SymmetricIndex for PlusMinusProduct {
@@ -0,0 +1,380 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sparse Pauli list binary format of SpinOperators in NumPy `.npz` archives.
+
+use super::{PauliProduct, SingleSpinOperator, SpinOperator};
+use crate::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::io::{Read, Seek, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// The magic string at the start of every `.npy` file.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+/// The number of qubits packed into one word of the masks.
+const QUBITS_PER_WORD: usize = 64;
+/// The NumPy type description of little-endian unsigned 64-bit integers.
+const DESCR_U64: &str = "<u8";
+/// The NumPy type description of little-endian double precision complex numbers.
+const DESCR_COMPLEX: &str = "<c16";
+
+impl SpinOperator {
+    /// Writes the SpinOperator to a NumPy `.npz` archive in the sparse Pauli list format.
+    ///
+    /// The archive contains four uncompressed `.npy` arrays:
+    ///
+    /// * `number_qubits` - `uint64` array of shape `(1,)` with the number of qubits `n`.
+    /// * `z_mask` - `uint64` array of shape `(number_terms, ceil(n / 64))`. Bit `j` of word `w` is set if the term acts with Z or Y on qubit `64 * w + j`.
+    /// * `x_mask` - `uint64` array of the same shape, with the bit set if the term acts with X or Y on the qubit.
+    /// * `coefficients` - `complex128` array of shape `(number_terms,)` with the coefficient of every term.
+    ///
+    /// A qubit with both bits set is acted on by the Pauli Y operator, no additional phase is associated with the masks.
+    /// The archive can be read with `numpy.load` and written with `numpy.savez`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer the archive is written to, e.g. a `std::fs::File`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The archive was written.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The archive could not be written.
+    pub fn to_npz<W: Write + Seek>(&self, writer: W) -> Result<(), StruqtureError> {
+        let number_qubits = self
+            .keys()
+            .map(|product| product.current_number_spins())
+            .max()
+            .unwrap_or(0);
+        let number_words = (number_qubits + QUBITS_PER_WORD - 1) / QUBITS_PER_WORD;
+        let mut z_mask: Vec<u64> = vec![0; self.len() * number_words];
+        let mut x_mask: Vec<u64> = vec![0; self.len() * number_words];
+        let mut coefficients: Vec<Complex64> = Vec::with_capacity(self.len());
+        for (term, (product, value)) in self.iter().enumerate() {
+            for (index, pauli) in product.iter() {
+                let word = term * number_words + index / QUBITS_PER_WORD;
+                let bit = 1_u64 << (index % QUBITS_PER_WORD);
+                match pauli {
+                    SingleSpinOperator::X => x_mask[word] |= bit,
+                    SingleSpinOperator::Y => {
+                        x_mask[word] |= bit;
+                        z_mask[word] |= bit;
+                    }
+                    SingleSpinOperator::Z => z_mask[word] |= bit,
+                    SingleSpinOperator::Identity => (),
+                }
+            }
+            coefficients.push(Complex64::new(*value.re.float()?, *value.im.float()?));
+        }
+
+        let mut archive = ZipWriter::new(writer);
+        let arrays = [
+            (
+                "number_qubits",
+                npy_bytes(DESCR_U64, &[1], u64_bytes(&[number_qubits as u64])),
+            ),
+            (
+                "z_mask",
+                npy_bytes(DESCR_U64, &[self.len(), number_words], u64_bytes(&z_mask)),
+            ),
+            (
+                "x_mask",
+                npy_bytes(DESCR_U64, &[self.len(), number_words], u64_bytes(&x_mask)),
+            ),
+            (
+                "coefficients",
+                npy_bytes(DESCR_COMPLEX, &[self.len()], complex_bytes(&coefficients)),
+            ),
+        ];
+        for (name, bytes) in arrays.iter() {
+            archive
+                .start_file(
+                    format!("{}.npy", name),
+                    FileOptions::default().compression_method(CompressionMethod::Stored),
+                )
+                .map_err(npz_error)?;
+            archive.write_all(bytes).map_err(npz_error)?;
+        }
+        archive.finish().map_err(npz_error)?;
+        Ok(())
+    }
+
+    /// Reads a SpinOperator from a NumPy `.npz` archive in the sparse Pauli list format.
+    ///
+    /// See [SpinOperator::to_npz] for the layout of the archive. Coefficients of repeated Pauli products are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader the archive is read from, e.g. a `std::fs::File`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator stored in the archive.
+    /// * `Err(StruqtureError::GenericError)` - The archive could not be read or does not follow the layout.
+    pub fn from_npz<R: Read + Seek>(reader: R) -> Result<Self, StruqtureError> {
+        let mut archive = ZipArchive::new(reader).map_err(npz_error)?;
+        let number_qubits = read_npy(&mut archive, "number_qubits", DESCR_U64)?;
+        let number_qubits = match number_qubits.shape.as_slice() {
+            [] | [1] => u64_values(&number_qubits.data)[0] as usize,
+            _ => return Err(layout_error("number_qubits must contain a single value")),
+        };
+        let number_words = (number_qubits + QUBITS_PER_WORD - 1) / QUBITS_PER_WORD;
+        let z_mask = read_npy(&mut archive, "z_mask", DESCR_U64)?.row_major_words()?;
+        let x_mask = read_npy(&mut archive, "x_mask", DESCR_U64)?.row_major_words()?;
+        let coefficients = read_npy(&mut archive, "coefficients", DESCR_COMPLEX)?;
+        if coefficients.shape.len() != 1 {
+            return Err(layout_error("coefficients must be one-dimensional"));
+        }
+        let coefficients = complex_values(&coefficients.data);
+        let number_terms = coefficients.len();
+        for (mask, name) in [(&z_mask, "z_mask"), (&x_mask, "x_mask")] {
+            if mask.0 != number_terms || mask.1 != number_words {
+                return Err(layout_error(&format!(
+                    "{} must have shape ({}, {}), found ({}, {})",
+                    name, number_terms, number_words, mask.0, mask.1
+                )));
+            }
+        }
+
+        let mut operator = SpinOperator::with_capacity(number_terms);
+        for (term, coefficient) in coefficients.into_iter().enumerate() {
+            let mut product = PauliProduct::new();
+            for word in 0..number_words {
+                let z_word = z_mask.2[term * number_words + word];
+                let x_word = x_mask.2[term * number_words + word];
+                for bit in 0..QUBITS_PER_WORD {
+                    let pauli = match ((z_word >> bit) & 1, (x_word >> bit) & 1) {
+                        (0, 0) => continue,
+                        (0, _) => SingleSpinOperator::X,
+                        (_, 0) => SingleSpinOperator::Z,
+                        _ => SingleSpinOperator::Y,
+                    };
+                    let index = word * QUBITS_PER_WORD + bit;
+                    if index >= number_qubits {
+                        return Err(layout_error(&format!(
+                            "Term {} acts on qubit {} beyond number_qubits {}",
+                            term, index, number_qubits
+                        )));
+                    }
+                    product = product.set_pauli(index, pauli);
+                }
+            }
+            operator.add_operator_product(
+                product,
+                CalculatorComplex::new(coefficient.re, coefficient.im),
+            )?;
+        }
+        Ok(operator)
+    }
+}
+
+/// A single array read from a `.npy` file.
+struct NpyArray {
+    /// The shape of the array.
+    shape: Vec<usize>,
+    /// Whether the data is stored in column-major order.
+    fortran_order: bool,
+    /// The raw little-endian data of the array.
+    data: Vec<u8>,
+}
+
+impl NpyArray {
+    /// Returns the (rows, columns, row-major words) of a two-dimensional `uint64` array.
+    fn row_major_words(&self) -> Result<(usize, usize, Vec<u64>), StruqtureError> {
+        let (rows, columns) = match self.shape.as_slice() {
+            [rows, columns] => (*rows, *columns),
+            _ => return Err(layout_error("Pauli masks must be two-dimensional")),
+        };
+        let words = u64_values(&self.data);
+        if !self.fortran_order {
+            return Ok((rows, columns, words));
+        }
+        let mut row_major = vec![0; words.len()];
+        for row in 0..rows {
+            for column in 0..columns {
+                row_major[row * columns + column] = words[column * rows + row];
+            }
+        }
+        Ok((rows, columns, row_major))
+    }
+}
+
+/// Reads an array with a given type description from a `.npz` archive.
+fn read_npy<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    name: &str,
+    descr: &str,
+) -> Result<NpyArray, StruqtureError> {
+    let mut bytes: Vec<u8> = Vec::new();
+    archive
+        .by_name(&format!("{}.npy", name))
+        .map_err(npz_error)?
+        .read_to_end(&mut bytes)
+        .map_err(npz_error)?;
+    if bytes.len() < 10 || &bytes[..6] != NPY_MAGIC {
+        return Err(layout_error(&format!("{} is not a .npy file", name)));
+    }
+    let (header_length, offset) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        _ => {
+            return Err(layout_error(&format!(
+                "{} has an unsupported .npy version",
+                name
+            )))
+        }
+    };
+    let header = bytes
+        .get(offset..offset + header_length)
+        .and_then(|header| std::str::from_utf8(header).ok())
+        .ok_or_else(|| layout_error(&format!("{} has an invalid .npy header", name)))?;
+
+    let found_descr = header_value(header, "descr")?.trim_matches(|c: char| c == '\'' || c == '"');
+    if found_descr != descr {
+        return Err(layout_error(&format!(
+            "{} must have dtype {}, found {}",
+            name, descr, found_descr
+        )));
+    }
+    let fortran_order = header_value(header, "fortran_order")? == "True";
+    let shape = header_value(header, "shape")?
+        .trim_matches(|c: char| c == '(' || c == ')')
+        .split(',')
+        .map(|dimension| dimension.trim())
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| {
+            dimension
+                .parse::<usize>()
+                .map_err(|_| layout_error(&format!("{} has an invalid shape", name)))
+        })
+        .collect::<Result<Vec<usize>, StruqtureError>>()?;
+    let data = bytes.split_off(offset + header_length);
+    let element_size = if descr == DESCR_COMPLEX { 16 } else { 8 };
+    if data.len() != shape.iter().product::<usize>() * element_size {
+        return Err(layout_error(&format!(
+            "{} contains {} bytes of data, which does not match its shape {:?}",
+            name,
+            data.len(),
+            shape
+        )));
+    }
+    Ok(NpyArray {
+        shape,
+        fortran_order,
+        data,
+    })
+}
+
+/// Returns the value of a key of the Python dictionary literal in a `.npy` header.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, StruqtureError> {
+    let start = header
+        .find(&format!("'{}':", key))
+        .map(|position| position + key.len() + 3)
+        .ok_or_else(|| layout_error(&format!("The .npy header does not contain {}", key)))?;
+    let value = header[start..].trim_start();
+    let end = if value.starts_with('(') {
+        value.find(')').map(|position| position + 1)
+    } else {
+        value.find(|c: char| c == ',' || c == '}')
+    }
+    .ok_or_else(|| layout_error(&format!("The .npy header has an invalid value of {}", key)))?;
+    Ok(value[..end].trim())
+}
+
+/// Serializes an array into the bytes of a `.npy` file in C order.
+fn npy_bytes(descr: &str, shape: &[usize], data: Vec<u8>) -> Vec<u8> {
+    let shape = match shape {
+        [length] => format!("({},)", length),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|dimension| dimension.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape
+    );
+    // The header is padded with spaces and a final newline so that the data is 64-byte aligned
+    let unpadded_length = NPY_MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded_length % 64) % 64));
+    header.push('\n');
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(NPY_MAGIC.len() + 4 + header.len() + data.len());
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend(data);
+    bytes
+}
+
+/// Converts unsigned 64-bit integers into little-endian bytes.
+fn u64_bytes(values: &[u64]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+/// Converts complex numbers into little-endian bytes of the real and imaginary parts.
+fn complex_bytes(values: &[Complex64]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| [value.re.to_le_bytes(), value.im.to_le_bytes()])
+        .flatten()
+        .collect()
+}
+
+/// Converts little-endian bytes into unsigned 64-bit integers.
+fn u64_values(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("Internal bug in u64_values")))
+        .collect()
+}
+
+/// Converts little-endian bytes of real and imaginary parts into complex numbers.
+fn complex_values(bytes: &[u8]) -> Vec<Complex64> {
+    bytes
+        .chunks_exact(16)
+        .map(|chunk| {
+            let (re, im) = chunk.split_at(8);
+            Complex64::new(
+                f64::from_le_bytes(re.try_into().expect("Internal bug in complex_values")),
+                f64::from_le_bytes(im.try_into().expect("Internal bug in complex_values")),
+            )
+        })
+        .collect()
+}
+
+/// Converts errors of the zip archive into a StruqtureError.
+fn npz_error(error: impl std::fmt::Display) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!("Could not access .npz archive: {}", error),
+    }
+}
+
+/// Creates the StruqtureError for archives not following the sparse Pauli list layout.
+fn layout_error(msg: &str) -> StruqtureError {
+    StruqtureError::GenericError {
+        msg: format!("Invalid sparse Pauli list archive: {}", msg),
+    }
+}
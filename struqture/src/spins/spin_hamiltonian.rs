@@ -10,14 +10,24 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::packed_pauli_product::{
+    flat_pauli_arrays_to_single_precision, from_flat_pauli_arrays, multiply_pauli_terms,
+    to_flat_pauli_arrays,
+};
 use super::{OperateOnSpins, SpinOperator, ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
 use crate::fermions::{FermionHamiltonian, FermionOperator};
 use crate::mappings::JordanWignerSpinToFermion;
 use crate::prelude::*;
-use crate::spins::{HermitianOperateOnSpins, PauliProduct, SpinIndex};
+use crate::qdrift::QDriftSampler;
+use crate::spins::{
+    FlatPauliArrays, FlatPauliArraysF32, HermitianOperateOnSpins, PauliProduct, SpinIndex,
+};
 use crate::{
-    CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, expression_from_terms,
+    parse_canonical_text_entries, parse_expression_terms, split_canonical_text, CanonicalText,
+    CoefficientEntry, CooSparseMatrix, GetValue, OperateOnConstant, OperateOnDensityMatrix,
+    OperateOnState, OperatorExpression, RoundingDiagnostics, SpectralNormBound, StruqtureError,
+    StruqtureVersionSerializable, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 #[cfg(feature = "indexed_map_iterators")]
 use indexmap::map::{Entry, Iter, Keys, Values};
@@ -25,9 +35,11 @@ use indexmap::map::{Entry, Iter, Keys, Values};
 use indexmap::IndexMap;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::hash_map::{Entry, Iter, Keys, Values};
+use std::collections::BTreeSet;
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::HashMap;
 use std::fmt::{self, Write};
@@ -68,6 +80,8 @@ pub struct SpinHamiltonian {
     internal_map: IndexMap<PauliProduct, CalculatorFloat>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<PauliProduct, CalculatorFloat>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for SpinHamiltonian {}
@@ -83,7 +97,7 @@ impl schemars::JsonSchema for SpinHamiltonian {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 /// # SpinHamiltonian
 /// SpinHamiltonians are combinations of PauliProducts with specific CalculatorFloat coefficients.
@@ -93,18 +107,58 @@ impl schemars::JsonSchema for SpinHamiltonian {
 struct SpinHamiltonianSerialize {
     /// List of all non-zero entries in the SpinHamiltonian in the form (PauliProduct, real weight).
     items: Vec<(PauliProduct, CalculatorFloat)>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for SpinHamiltonianSerialize {
+    /// Serialization function for SpinHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "SpinHamiltonianSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<SpinHamiltonianSerialize> for SpinHamiltonian {
     fn from(value: SpinHamiltonianSerialize) -> Self {
-        let new_noise_op: SpinHamiltonian = value.items.into_iter().collect();
+        let mut new_noise_op: SpinHamiltonian = value.items.into_iter().collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<SpinHamiltonian> for SpinHamiltonianSerialize {
     fn from(value: SpinHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(PauliProduct, CalculatorFloat)> = value.into_iter().collect();
         let current_version = StruqtureVersionSerializable {
             major_version: MINIMUM_STRUQTURE_VERSION.0,
@@ -112,11 +166,21 @@ impl From<SpinHamiltonian> for SpinHamiltonianSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for SpinHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = SpinHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinHamiltonian {
     type Index = PauliProduct;
     type Value = CalculatorFloat;
@@ -161,10 +225,25 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinHamiltonian {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the SpinHamiltonian with the given (PauliProduct key, CalculatorFloat value) pair.
@@ -183,7 +262,7 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinHamiltonian {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorFloat::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -204,6 +283,13 @@ impl<'a> OperateOnState<'a> for SpinHamiltonian {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for SpinHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PauliProduct::new()
+    }
+}
+
 impl<'a> OperateOnSpins<'a> for SpinHamiltonian {
     /// Gets the maximum index of the SpinHamiltonian.
     ///
@@ -283,6 +369,7 @@ impl SpinHamiltonian {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -301,9 +388,91 @@ impl SpinHamiltonian {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
+    /// Returns a mutable handle to the coefficient of a key, see [CoefficientEntry].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PauliProduct whose coefficient is accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `CoefficientEntry<PauliProduct, CalculatorFloat>` - The handle to the coefficient, which is zero if the key is not in the SpinHamiltonian.
+    pub fn entry(&mut self, key: PauliProduct) -> CoefficientEntry<PauliProduct, CalculatorFloat> {
+        CoefficientEntry::new(
+            self.internal_map.entry(key),
+            CalculatorFloat::ZERO,
+            self.zero_term_policy,
+        )
+    }
+
+    /// Converts the SpinHamiltonian into flat arrays of bitmasks and coefficients, see [FlatPauliArrays].
+    ///
+    /// The terms are sorted by their PauliProduct. The contiguous arrays can be handed to numerical kernels
+    /// without per-term overhead and converted back with [SpinHamiltonian::from_flat_arrays].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FlatPauliArrays)` - The bitmasks of the terms, the real parts and the imaginary parts of the coefficients, which are all zero.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn to_flat_arrays(&self) -> Result<FlatPauliArrays, StruqtureError> {
+        let mut terms: Vec<(&PauliProduct, Complex64)> = Vec::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            terms.push((product, Complex64::new(value.clone().try_into()?, 0.0)));
+        }
+        Ok(to_flat_pauli_arrays(terms.into_iter()))
+    }
+
+    /// Converts the SpinHamiltonian into flat arrays with single precision coefficients, see [FlatPauliArraysF32].
+    ///
+    /// The same as [SpinHamiltonian::to_flat_arrays] with the coefficients rounded to single precision.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((FlatPauliArraysF32, RoundingDiagnostics))` - The flat arrays and the diagnostics of the rounding to single precision.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - A coefficient exceeds the range of single precision floats.
+    pub fn to_flat_arrays_f32(
+        &self,
+    ) -> Result<(FlatPauliArraysF32, RoundingDiagnostics), StruqtureError> {
+        flat_pauli_arrays_to_single_precision(self.to_flat_arrays()?)
+    }
+
+    /// Creates a SpinHamiltonian from flat arrays of bitmasks and coefficients, see [FlatPauliArrays].
+    ///
+    /// Terms with the same PauliProduct are summed.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_masks` - The x-bitmasks of all terms, with the same number of words per term.
+    /// * `z_masks` - The z-bitmasks of all terms, with the same number of words per term.
+    /// * `real` - The real parts of the coefficients.
+    /// * `imag` - The imaginary parts of the coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonian with the terms of the arrays.
+    /// * `Err(StruqtureError::GenericError)` - The lengths of the arrays do not fit together.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - A coefficient has a non-zero imaginary part.
+    pub fn from_flat_arrays(
+        x_masks: &[u64],
+        z_masks: &[u64],
+        real: &[f64],
+        imag: &[f64],
+    ) -> Result<Self, StruqtureError> {
+        let mut operator = Self::with_capacity(real.len());
+        for (product, value) in from_flat_pauli_arrays(x_masks, z_masks, real, imag)? {
+            if value.im != 0.0 {
+                return Err(StruqtureError::NonHermitianOperator);
+            }
+            operator.add_operator_product(product, CalculatorFloat::from(value.re))?;
+        }
+        Ok(operator)
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -328,6 +497,41 @@ impl SpinHamiltonian {
         }
         Ok((separated, remainder))
     }
+
+    /// Converts the SpinHamiltonian into a SpinOperator, adding the hermitian conjugates of all terms that are not naturally hermitian.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The SpinHamiltonian as a SpinOperator.
+    pub fn to_operator(&self) -> SpinOperator {
+        SpinOperator::from(self.clone())
+    }
+
+    /// Creates a sampler drawing the terms of the SpinHamiltonian for the qDRIFT protocol.
+    ///
+    /// Every PauliProduct is drawn with a probability proportional to the absolute value of its coefficient,
+    /// together with the sign of the coefficient. See [crate::qdrift] for the protocol.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(QDriftSampler<PauliProduct, f64>)` - The sampler with the normalization constant λ.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The SpinHamiltonian has no term with a non-zero coefficient.
+    pub fn qdrift_sampler(
+        &self,
+        seed: u64,
+    ) -> Result<QDriftSampler<PauliProduct, f64>, StruqtureError> {
+        let mut terms = Vec::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let value = f64::try_from(value.clone())?;
+            terms.push((product.clone(), value.signum(), value.abs()));
+        }
+        QDriftSampler::new(terms, seed)
+    }
 }
 
 impl TryFrom<SpinOperator> for SpinHamiltonian {
@@ -366,12 +570,14 @@ impl ops::Neg for SpinHamiltonian {
     ///
     /// * `Self` - The SpinHamiltonian * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * -1.0);
         }
         SpinHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -450,12 +656,15 @@ impl ops::Mul<CalculatorFloat> for SpinHamiltonian {
     ///
     /// * `Self` - The SpinHamiltonian multiplied by the CalculatorFloat.
     fn mul(self, other: CalculatorFloat) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let mut internal = self.internal_map.clone();
         for key in self.keys() {
             internal.insert(key.clone(), internal[key].clone() * other.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         SpinHamiltonian {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -479,6 +688,9 @@ impl ops::Mul<CalculatorComplex> for SpinHamiltonian {
     /// * Internal bug in set.
     fn mul(self, other: CalculatorComplex) -> Self::Output {
         let mut new_out = SpinOperator::with_capacity(self.len());
+        new_out
+            .set_zero_term_policy(self.zero_term_policy)
+            .expect("Internal bug in set_zero_term_policy");
         for (key, val) in self {
             new_out
                 .set(key, other.clone() * val)
@@ -507,16 +719,14 @@ impl ops::Mul<SpinHamiltonian> for SpinHamiltonian {
     /// * Internal bug in add_operator_product.
     fn mul(self, other: SpinHamiltonian) -> Self::Output {
         let mut spin_op = SpinOperator::with_capacity(self.len() * other.len());
-        for (pps, vals) in self {
-            for (ppo, valo) in other.iter() {
-                let (ppp, coefficient) = pps.clone() * ppo.clone();
-                let coefficient =
-                    Into::<CalculatorComplex>::into(valo) * vals.clone() * coefficient;
-                spin_op
-                    .add_operator_product(ppp, coefficient)
-                    .expect("Internal bug in add_operator_product");
-            }
-        }
+        spin_op
+            .set_zero_term_policy(self.zero_term_policy)
+            .expect("Internal bug in set_zero_term_policy");
+        multiply_pauli_terms(self.iter(), other.iter(), |ppp, coefficient| {
+            spin_op
+                .add_operator_product(ppp, coefficient)
+                .expect("Internal bug in add_operator_product");
+        });
         spin_op
     }
 }
@@ -625,6 +835,88 @@ impl fmt::Display for SpinHamiltonian {
     }
 }
 
+impl CanonicalText for SpinHamiltonian {
+    /// Returns the canonical text representation of the SpinHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinHamiltonian.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document("SpinHamiltonian", &[], &canonical_text_entries(self.iter()))
+    }
+
+    /// Creates a SpinHamiltonian from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonian represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinHamiltonian.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinHamiltonian.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "SpinHamiltonian", &[])?;
+        let mut new_self = SpinHamiltonian::new();
+        for (key, value) in parse_canonical_text_entries::<PauliProduct, CalculatorFloat>(&lines)? {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for SpinHamiltonian {
+    /// Returns the expression of the SpinHamiltonian, e.g. `0.5*0X1Z - 1e-2*3Y`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a SpinHamiltonian from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonian represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the SpinHamiltonian.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = SpinHamiltonian::new();
+        for (key, value) in parse_expression_terms::<PauliProduct, CalculatorFloat>(expression)? {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for SpinHamiltonian {
+    /// Returns an upper bound of the spectral norm of the SpinHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The SpinHamiltonian contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.coefficient_1_norm()
+    }
+}
+
+impl SpinLocality for SpinHamiltonian {
+    // From trait
+    fn term_supports(&self) -> Vec<BTreeSet<usize>> {
+        self.keys()
+            .map(|product| product.iter().map(|(index, _)| *index).collect())
+            .collect()
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinHamiltonian {
     type Output = FermionHamiltonian;
 
@@ -13,18 +13,28 @@
 use super::{HermitianOperateOnSpins, OperateOnSpins, SpinSystem};
 use crate::fermions::FermionHamiltonianSystem;
 use crate::mappings::JordanWignerSpinToFermion;
+use crate::metadata::merge_operand_metadata;
 use crate::spins::{
-    PauliProduct, SpinHamiltonian, ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+    PauliProduct, SingleSpinOperator, SpinHamiltonian, SymbolicSuperOperator,
+    ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+};
+use crate::{
+    canonical_number, canonical_text_document, canonical_text_entries, hermitian_term_count,
+    hilbert_space_dimension, parse_canonical_number, parse_canonical_text_entries,
+    sparse_matrix_memory, split_canonical_text, CanonicalText, CooSparseMatrix, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnState, SpectralNormBound, SpinIndex, StruqtureError,
+    SystemDimension, ZeroTermPolicy,
 };
-use crate::{CooSparseMatrix, OperateOnDensityMatrix, OperateOnState, SpinIndex, StruqtureError};
 #[cfg(feature = "indexed_map_iterators")]
 use indexmap::map::{Iter, Keys, Values};
+use ndarray::{Array1, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::hash_map::{Iter, Keys, Values};
-
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -52,17 +62,95 @@ use std::ops;
 /// assert_eq!(system.get(&pp_0z), &CalculatorFloat::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct SpinHamiltonianSystem {
     /// The number of spins in the SpinHamiltonianSystem
     pub(crate) number_spins: Option<usize>,
     /// The SpinHamiltonian representing the Hamiltonian of the SpinHamiltonianSystem
     pub(crate) hamiltonian: SpinHamiltonian,
+    /// Provenance metadata of the SpinHamiltonianSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for SpinHamiltonianSystem {}
 
+impl Serialize for SpinHamiltonianSystem {
+    /// Serialization function for SpinHamiltonianSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinHamiltonianSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinHamiltonianSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.hamiltonian, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for SpinHamiltonianSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.hamiltonian), serializer)
+    }
+}
+
+/// Serializes a SpinHamiltonianSystem with the given serialization of its hamiltonian.
+///
+/// # Arguments
+///
+/// * `system` - SpinHamiltonianSystem to be serialized.
+/// * `hamiltonian` - Serialization of the hamiltonian of the SpinHamiltonianSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of SpinHamiltonianSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &SpinHamiltonianSystem,
+    hamiltonian: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state =
+        serializer.serialize_struct("SpinHamiltonianSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("hamiltonian", hamiltonian)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for SpinHamiltonianSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinHamiltonianSystem {
     type Index = PauliProduct;
     type Value = CalculatorFloat;
@@ -100,15 +188,27 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinHamiltonianSystem {
         match capacity {
             Some(cap) => Self {
                 number_spins: self.number_spins,
-                hamiltonian: SpinHamiltonian::with_capacity(cap),
+                hamiltonian: self.hamiltonian.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins,
-                hamiltonian: SpinHamiltonian::new(),
+                hamiltonian: self.hamiltonian.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.hamiltonian.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.hamiltonian.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the SpinHamiltonianSystem with the given (PauliProduct key, CalculatorFloat value) pair.
     ///
     /// # Arguments
@@ -174,6 +274,13 @@ impl<'a> OperateOnState<'a> for SpinHamiltonianSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for SpinHamiltonianSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PauliProduct::new()
+    }
+}
+
 impl<'a> OperateOnSpins<'a> for SpinHamiltonianSystem {
     /// Gets the number_spins input of the SpinHamiltonianSystem, or the current_number_spins if number_spins is None.
     ///
@@ -229,6 +336,42 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinHamiltonianSystem {
 
 /// Functions for the SpinHamiltonianSystem
 ///
+/// Two-spin interaction placed on every edge of a graph by [SpinHamiltonianSystem::from_edges].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauliCoupling {
+    /// Ising interaction σ^z_i σ^z_j.
+    Ising,
+    /// XY interaction σ^x_i σ^x_j + σ^y_i σ^y_j.
+    XY,
+    /// Heisenberg interaction σ^x_i σ^x_j + σ^y_i σ^y_j + σ^z_i σ^z_j.
+    Heisenberg,
+    /// Interaction σ^a_i σ^b_j with the given Pauli operators a on the first and b on the second spin of the edge.
+    Pair(SingleSpinOperator, SingleSpinOperator),
+}
+
+impl PauliCoupling {
+    /// Returns the PauliProducts of the interaction between two spins.
+    fn products(&self, first: usize, second: usize) -> Vec<PauliProduct> {
+        let pairs = match self {
+            PauliCoupling::Ising => vec![(SingleSpinOperator::Z, SingleSpinOperator::Z)],
+            PauliCoupling::XY => vec![
+                (SingleSpinOperator::X, SingleSpinOperator::X),
+                (SingleSpinOperator::Y, SingleSpinOperator::Y),
+            ],
+            PauliCoupling::Heisenberg => vec![
+                (SingleSpinOperator::X, SingleSpinOperator::X),
+                (SingleSpinOperator::Y, SingleSpinOperator::Y),
+                (SingleSpinOperator::Z, SingleSpinOperator::Z),
+            ],
+            PauliCoupling::Pair(a, b) => vec![(*a, *b)],
+        };
+        pairs
+            .into_iter()
+            .map(|(a, b)| PauliProduct::new().set_pauli(first, a).set_pauli(second, b))
+            .collect()
+    }
+}
+
 impl SpinHamiltonianSystem {
     /// Creates a new SpinHamiltonianSystem.
     ///
@@ -243,6 +386,7 @@ impl SpinHamiltonianSystem {
         SpinHamiltonianSystem {
             number_spins,
             hamiltonian: SpinHamiltonian::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -260,6 +404,7 @@ impl SpinHamiltonianSystem {
         SpinHamiltonianSystem {
             number_spins,
             hamiltonian: SpinHamiltonian::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -303,6 +448,7 @@ impl SpinHamiltonianSystem {
                     Ok(SpinHamiltonianSystem {
                         number_spins: Some(x),
                         hamiltonian,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberSpinsExceeded)
@@ -311,10 +457,281 @@ impl SpinHamiltonianSystem {
             None => Ok(SpinHamiltonianSystem {
                 number_spins: None,
                 hamiltonian,
+                metadata: BTreeMap::new(),
             }),
         }
     }
 
+    /// Creates a SpinHamiltonianSystem with the same two-spin interaction on every edge of a graph.
+    ///
+    /// Edges given several times add up their coupling strengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges` - The edges (i, j, J) of the graph with the coupling strength J between spins i and j.
+    /// * `interaction` - The interaction placed on every edge.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonianSystem \sum_{(i, j, J)} J σ^a_i σ^b_j.
+    /// * `Err(StruqtureError::GenericError)` - An edge connects a spin to itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliCoupling, PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// let ring = [(0, 1, 1.0.into()), (1, 2, 1.0.into()), (2, 0, 1.0.into())];
+    /// let system = SpinHamiltonianSystem::from_edges(&ring, PauliCoupling::Ising).unwrap();
+    /// assert_eq!(system.get(&PauliProduct::new().z(0).z(2)), &1.0.into());
+    /// ```
+    pub fn from_edges(
+        edges: &[(usize, usize, CalculatorFloat)],
+        interaction: PauliCoupling,
+    ) -> Result<Self, StruqtureError> {
+        let mut system = SpinHamiltonianSystem::new(None);
+        for (first, second, strength) in edges {
+            if first == second {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Edge ({}, {}) connects spin {} to itself",
+                        first, second, first
+                    ),
+                });
+            }
+            for product in interaction.products(*first, *second) {
+                system.add_operator_product(product, strength.clone())?;
+            }
+        }
+        Ok(system)
+    }
+
+    /// Creates a SpinHamiltonianSystem from the matrix Q of a quadratic unconstrained binary optimization (QUBO) problem.
+    ///
+    /// The cost function C(x) = \sum_{i,j} Q_{ij} x_i x_j of the binary variables x_i is mapped to spins
+    /// with x_i = (1 - σ^z_i) / 2, so that x_i = 0 corresponds to the state |0⟩ of spin i.
+    /// Constant contributions are collected in the identity term.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The square QUBO matrix, which does not need to be symmetric.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonianSystem with one spin per binary variable.
+    /// * `Err(StruqtureError::GenericError)` - The matrix is not square.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// // C(x) = x_0 - 2 x_0 x_1
+    /// let system = SpinHamiltonianSystem::from_qubo(&array![[1.0, -2.0], [0.0, 0.0]]).unwrap();
+    /// assert_eq!(system.get(&PauliProduct::new()), &0.0.into());
+    /// assert_eq!(system.get(&PauliProduct::new().z(0)), &0.0.into());
+    /// assert_eq!(system.get(&PauliProduct::new().z(1)), &0.5.into());
+    /// assert_eq!(system.get(&PauliProduct::new().z(0).z(1)), &(-0.5).into());
+    /// ```
+    pub fn from_qubo(matrix: &Array2<f64>) -> Result<Self, StruqtureError> {
+        let (rows, columns) = matrix.dim();
+        if rows != columns {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The QUBO matrix must be square but has shape ({}, {})",
+                    rows, columns
+                ),
+            });
+        }
+        let mut system = SpinHamiltonianSystem::new(Some(rows));
+        for ((i, j), value) in matrix.indexed_iter() {
+            system.add_binary_product(i, j, CalculatorFloat::from(*value))?;
+        }
+        Ok(system)
+    }
+
+    /// Adds a quadratic penalty for violating the linear equality constraint \sum_i w_i x_i = t of binary variables.
+    ///
+    /// The penalty P (\sum_i w_i x_i - t)^2 vanishes for all assignments fulfilling the constraint,
+    /// using the same mapping x_i = (1 - σ^z_i) / 2 of binary variables to spins as [SpinHamiltonianSystem::from_qubo].
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The weights (i, w_i) of the binary variables in the constraint. Weights of the same variable add up.
+    /// * `target` - The target value t of the constraint.
+    /// * `penalty` - The strength P of the penalty.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The penalty was added to self.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - A weighted variable exceeds the number of spins of self.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// // Exactly one of the two variables is set: 2 (x_0 + x_1 - 1)^2 = 1 + σ^z_0 σ^z_1
+    /// let mut system = SpinHamiltonianSystem::new(Some(2));
+    /// system.add_equality_constraint(&[(0, 1.0), (1, 1.0)], 1.0, 2.0.into()).unwrap();
+    /// assert_eq!(system.get(&PauliProduct::new()), &1.0.into());
+    /// assert_eq!(system.get(&PauliProduct::new().z(0).z(1)), &1.0.into());
+    /// assert_eq!(system.len(), 2);
+    /// ```
+    pub fn add_equality_constraint(
+        &mut self,
+        weights: &[(usize, f64)],
+        target: f64,
+        penalty: CalculatorFloat,
+    ) -> Result<(), StruqtureError> {
+        let mut combined: BTreeMap<usize, f64> = BTreeMap::new();
+        for (index, weight) in weights {
+            *combined.entry(*index).or_insert(0.0) += weight;
+        }
+        for (i, weight_i) in combined.iter() {
+            for (j, weight_j) in combined.iter() {
+                self.add_binary_product(*i, *j, penalty.clone() * (weight_i * weight_j))?;
+            }
+            self.add_binary_product(*i, *i, penalty.clone() * (-2.0 * target * weight_i))?;
+        }
+        self.add_constant(penalty * (target * target))
+    }
+
+    /// Returns the Ising model of a SpinHamiltonianSystem containing only σ^z terms.
+    ///
+    /// The Hamiltonian is written as H = \sum_{i<j} J_{ij} σ^z_i σ^z_j + \sum_i h_i σ^z_i + c.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Array2<f64>, Array1<f64>, f64))` - The upper triangular coupling matrix J, the fields h and the offset c.
+    /// * `Err(StruqtureError::GenericError)` - A term is not diagonal or acts on more than two spins.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// let mut system = SpinHamiltonianSystem::new(Some(2));
+    /// system.add_operator_product(PauliProduct::new().z(1).z(0), 2.0.into()).unwrap();
+    /// system.add_operator_product(PauliProduct::new().z(1), 0.5.into()).unwrap();
+    /// let (couplings, fields, offset) = system.to_ising().unwrap();
+    /// assert_eq!(couplings, array![[0.0, 2.0], [0.0, 0.0]]);
+    /// assert_eq!(fields, array![0.0, 0.5]);
+    /// assert_eq!(offset, 0.0);
+    /// ```
+    pub fn to_ising(&self) -> Result<(Array2<f64>, Array1<f64>, f64), StruqtureError> {
+        let number_spins = self.number_spins();
+        let mut couplings: Array2<f64> = Array2::zeros((number_spins, number_spins));
+        let mut fields: Array1<f64> = Array1::zeros(number_spins);
+        let mut offset = 0.0;
+        for (product, value) in self.iter() {
+            if product
+                .iter()
+                .any(|(_, operator)| *operator != SingleSpinOperator::Z)
+            {
+                return Err(StruqtureError::GenericError {
+                    msg: format!("Term {} is not diagonal in the Z basis", product),
+                });
+            }
+            let value: f64 = value.clone().try_into()?;
+            let spins: Vec<usize> = product.iter().map(|(index, _)| *index).collect();
+            match spins.as_slice() {
+                [] => offset += value,
+                [i] => fields[*i] += value,
+                [i, j] => couplings[(*i.min(j), *i.max(j))] += value,
+                _ => {
+                    return Err(StruqtureError::GenericError {
+                        msg: format!(
+                            "Term {} acts on more than two spins and has no Ising representation",
+                            product
+                        ),
+                    })
+                }
+            }
+        }
+        Ok((couplings, fields, offset))
+    }
+
+    /// Returns the QUBO matrix of a SpinHamiltonianSystem containing only σ^z terms.
+    ///
+    /// This is the inverse of [SpinHamiltonianSystem::from_qubo] with σ^z_i = 1 - 2 x_i,
+    /// so that H = \sum_{i<=j} Q_{ij} x_i x_j + c for all assignments of the binary variables x_i.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Array2<f64>, f64))` - The upper triangular QUBO matrix Q and the offset c.
+    /// * `Err(StruqtureError::GenericError)` - A term is not diagonal or acts on more than two spins.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// let mut system = SpinHamiltonianSystem::new(Some(1));
+    /// system.add_operator_product(PauliProduct::new().z(0), 0.5.into()).unwrap();
+    /// let (qubo, offset) = system.to_qubo().unwrap();
+    /// assert_eq!(qubo, array![[-1.0]]);
+    /// assert_eq!(offset, 0.5);
+    /// ```
+    pub fn to_qubo(&self) -> Result<(Array2<f64>, f64), StruqtureError> {
+        let (couplings, fields, mut offset) = self.to_ising()?;
+        let mut qubo: Array2<f64> = Array2::zeros(couplings.dim());
+        for ((i, j), coupling) in couplings.indexed_iter() {
+            if i < j {
+                qubo[(i, j)] += 4.0 * coupling;
+                qubo[(i, i)] -= 2.0 * coupling;
+                qubo[(j, j)] -= 2.0 * coupling;
+                offset += coupling;
+            }
+        }
+        for (i, field) in fields.iter().enumerate() {
+            qubo[(i, i)] -= 2.0 * field;
+            offset += field;
+        }
+        Ok((qubo, offset))
+    }
+
+    /// Adds the product c x_i x_j of two binary variables x_i = (1 - σ^z_i) / 2, which reduces to c x_i for i == j.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The index of the first binary variable.
+    /// * `j` - The index of the second binary variable.
+    /// * `coefficient` - The coefficient c of the product.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The product was added to self.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - A variable exceeds the number of spins of self.
+    fn add_binary_product(
+        &mut self,
+        i: usize,
+        j: usize,
+        coefficient: CalculatorFloat,
+    ) -> Result<(), StruqtureError> {
+        if i == j {
+            let half = coefficient * 0.5;
+            self.add_operator_product(PauliProduct::new().z(i), -half.clone())?;
+            self.add_constant(half)
+        } else {
+            let quarter = coefficient * 0.25;
+            self.add_operator_product(PauliProduct::new().z(i).z(j), quarter.clone())?;
+            self.add_operator_product(PauliProduct::new().z(i), -quarter.clone())?;
+            self.add_operator_product(PauliProduct::new().z(j), -quarter.clone())?;
+            self.add_constant(quarter)
+        }
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -339,6 +756,221 @@ impl SpinHamiltonianSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Partitions the SpinHamiltonianSystem into blocks of nearby spins and the coupling between the blocks.
+    ///
+    /// Two spins belong to the same block when they are connected by a chain of spins with a distance of at most `max_distance`
+    /// between neighbouring spins of the chain. Every term acting only on the spins of one block is added to the sub-Hamiltonian
+    /// of this block, all other terms, including the constant term, form the coupling remainder.
+    /// The sub-Hamiltonians are sorted by the smallest spin of their block, blocks without terms are omitted.
+    /// The sub-Hamiltonians and the remainder keep the number of spins and the metadata of the system.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_distance` - The largest distance between neighbouring spins of a block.
+    /// * `geometry` - The coordinates of every spin, `geometry[i]` is the position of spin i.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Self>, Self))` - The sub-Hamiltonians of the blocks and the coupling remainder.
+    /// * `Err(StruqtureError::GenericError)` - The coordinates of the spins have different dimensions.
+    /// * `Err(StruqtureError::GenericError)` - A term acts on a spin without coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorFloat;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+    ///
+    /// // Two pairs of spins at a distance of 5 from each other
+    /// let geometry = vec![vec![0.0], vec![1.0], vec![6.0], vec![7.0]];
+    /// let mut system = SpinHamiltonianSystem::new(Some(4));
+    /// for (i, j) in [(0, 1), (1, 2), (2, 3)] {
+    ///     system.add_operator_product(PauliProduct::new().z(i).z(j), CalculatorFloat::from(1.0)).unwrap();
+    /// }
+    /// let (blocks, remainder) = system.partition_by_locality(1.5, &geometry).unwrap();
+    /// assert_eq!(blocks.len(), 2);
+    /// assert_eq!(blocks[1].get(&PauliProduct::new().z(2).z(3)), &CalculatorFloat::from(1.0));
+    /// assert_eq!(remainder.get(&PauliProduct::new().z(1).z(2)), &CalculatorFloat::from(1.0));
+    /// ```
+    pub fn partition_by_locality(
+        &self,
+        max_distance: f64,
+        geometry: &[Vec<f64>],
+    ) -> Result<(Vec<Self>, Self), StruqtureError> {
+        let dimension = geometry.first().map_or(0, |coordinates| coordinates.len());
+        if let Some(coordinates) = geometry
+            .iter()
+            .find(|coordinates| coordinates.len() != dimension)
+        {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "The coordinates {:?} do not have the dimension {} of the first spin",
+                    coordinates, dimension
+                ),
+            });
+        }
+        if let Some(spin) = self
+            .keys()
+            .flat_map(|product| product.iter().map(|(spin, _)| *spin))
+            .find(|spin| *spin >= geometry.len())
+        {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "No coordinates are given for spin {}, only {} coordinates are given",
+                    spin,
+                    geometry.len()
+                ),
+            });
+        }
+
+        let mut parents: Vec<usize> = (0..geometry.len()).collect();
+        for i in 0..geometry.len() {
+            for j in i + 1..geometry.len() {
+                let distance = geometry[i]
+                    .iter()
+                    .zip(geometry[j].iter())
+                    .map(|(x, y)| (x - y) * (x - y))
+                    .sum::<f64>()
+                    .sqrt();
+                if distance <= max_distance {
+                    let (root_i, root_j) =
+                        (find_block(&mut parents, i), find_block(&mut parents, j));
+                    parents[root_i.max(root_j)] = root_i.min(root_j);
+                }
+            }
+        }
+
+        // The blocks are labeled by their smallest spin
+        let mut blocks: BTreeMap<usize, Self> = BTreeMap::new();
+        let mut remainder = self.empty_clone(None);
+        for (product, value) in self.iter() {
+            let roots: BTreeSet<usize> = product
+                .iter()
+                .map(|(spin, _)| find_block(&mut parents, *spin))
+                .collect();
+            match roots.iter().next() {
+                Some(root) if roots.len() == 1 => blocks
+                    .entry(*root)
+                    .or_insert_with(|| self.empty_clone(None))
+                    .add_operator_product(product.clone(), value.clone())?,
+                _ => remainder.add_operator_product(product.clone(), value.clone())?,
+            }
+        }
+        Ok((blocks.into_values().collect(), remainder))
+    }
+
+    /// Computes the partial trace of the SpinHamiltonianSystem over a set of spins.
+    ///
+    /// A PauliProduct P_A P_B acting with P_A on the k traced spins has the partial trace Tr(P_A) P_B,
+    /// where Tr(P_A) = 2^k if P_A is the identity and zero otherwise.
+    /// The remaining spins are relabeled in ascending order starting from zero and a fixed number of spins is reduced by k.
+    /// The metadata of the SpinHamiltonianSystem is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits_to_trace` - The spins to trace out, spins given more than once are traced out once.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The reduced SpinHamiltonianSystem acting on the remaining spins.
+    /// * `Err(StruqtureError::GenericError)` - A traced spin exceeds the number of spins of the SpinHamiltonianSystem.
+    pub fn partial_trace(&self, qubits_to_trace: &[usize]) -> Result<Self, StruqtureError> {
+        let traced: BTreeSet<usize> = qubits_to_trace.iter().copied().collect();
+        if let Some(spin) = traced.iter().find(|spin| **spin >= self.number_spins()) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Cannot trace out spin {}, the system has {} spins",
+                    spin,
+                    self.number_spins()
+                ),
+            });
+        }
+        let factor = 2_f64.powi(traced.len() as i32);
+        let mut reduced = self.empty_clone(Some(self.len()));
+        reduced.number_spins = self.number_spins.map(|number| number - traced.len());
+        for (product, value) in self.iter() {
+            if let Some(remaining) = product.trace_out(&traced) {
+                reduced.add_operator_product(remaining, value.clone() * factor)?;
+            }
+        }
+        Ok(reduced)
+    }
+
+    /// Constructs the superoperator of a SpinHamiltonianSystem with symbolic coefficients.
+    ///
+    /// The terms are grouped by their symbolic coefficient and the superoperator of every group is constructed numerically,
+    /// so that the superoperator can be evaluated efficiently for many values of the symbolic parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the superoperator, defaults to the number of spins of the system.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SymbolicSuperOperator)` - The constant and per-expression superoperators.
+    /// * `Err(StruqtureError)` - The number of spins is smaller than the number of spins the terms act on.
+    pub fn symbolic_sparse_matrix_superoperator(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<SymbolicSuperOperator, StruqtureError> {
+        let number_spins = number_spins.unwrap_or_else(|| self.number_spins());
+        Ok(SymbolicSuperOperator::from_parts(
+            self.symbolic_superoperator_parts(number_spins)?,
+        ))
+    }
+
+    /// Constructs the superoperators of the terms of self grouped by their symbolic coefficient.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the superoperators.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Option<String>, CooSparseMatrix)>)` - The expression (None for numeric coefficients) and superoperator of every group.
+    /// * `Err(StruqtureError)` - The number of spins is smaller than the number of spins the terms act on.
+    pub(crate) fn symbolic_superoperator_parts(
+        &self,
+        number_spins: usize,
+    ) -> Result<Vec<(Option<String>, CooSparseMatrix)>, StruqtureError> {
+        let mut groups: HashMap<Option<String>, SpinHamiltonianSystem> = HashMap::new();
+        for (key, value) in self.iter() {
+            let (expression, factor) = match value {
+                CalculatorFloat::Float(x) => (None, *x),
+                CalculatorFloat::Str(expression) => (Some(expression.clone()), 1.0),
+            };
+            groups
+                .entry(expression)
+                .or_insert_with(|| SpinHamiltonianSystem::new(Some(number_spins)))
+                .add_operator_product(key.clone(), factor.into())?;
+        }
+        groups
+            .into_iter()
+            .map(|(expression, group)| {
+                Ok((
+                    expression,
+                    group.sparse_matrix_superoperator_coo(Some(number_spins))?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Returns the root of the block of a spin in a union-find forest, compressing the path to the root.
+fn find_block(parents: &mut [usize], spin: usize) -> usize {
+    let mut root = spin;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut current = spin;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
 }
 
 /// Implements the negative sign function of SpinHamiltonianSystem.
@@ -360,7 +992,7 @@ impl ops::Neg for SpinHamiltonianSystem {
 ///
 impl<T, V> ops::Add<T> for SpinHamiltonianSystem
 where
-    T: IntoIterator<Item = (PauliProduct, V)>,
+    T: IntoIterator<Item = (PauliProduct, V)> + 'static,
     V: Into<CalculatorFloat>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -375,6 +1007,7 @@ where
     /// * `Ok(Self)` - The two SpinHamiltonianSystems added together.
     /// * `Err(StruqtureError::NumberSpinsExceeded)` - Index of PauliProduct exceeds that of the SpinHamiltonianSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorFloat>::into(value))?;
         }
@@ -386,7 +1019,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for SpinHamiltonianSystem
 where
-    T: IntoIterator<Item = (PauliProduct, V)>,
+    T: IntoIterator<Item = (PauliProduct, V)> + 'static,
     V: Into<CalculatorFloat>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -401,6 +1034,7 @@ where
     /// * `Ok(Self)` - The two SpinHamiltonianSystems subtracted.
     /// * `Err(StruqtureError::NumberSpinsExceeded)` - Index of PauliProduct exceeds that of the SpinHamiltonianSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorFloat>::into(value) * -1.0)?;
         }
@@ -443,6 +1077,7 @@ impl ops::Mul<CalculatorComplex> for SpinHamiltonianSystem {
     fn mul(self, other: CalculatorComplex) -> Self::Output {
         let mut system = SpinSystem::new(self.number_spins);
         system.operator = self.hamiltonian * other;
+        system.metadata = self.metadata;
         system
     }
 }
@@ -462,9 +1097,12 @@ impl ops::Mul<SpinHamiltonianSystem> for SpinHamiltonianSystem {
     /// * `Ok(SpinSystem)` - The two SpinHamiltonianSystems multiplied.
     /// * `Err(StruqtureError::NumberSpinsExceeded)` - Index of PauliProduct exceeds that of the SpinSystem.
     fn mul(self, other: SpinHamiltonianSystem) -> Self::Output {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<SpinHamiltonianSystem>(&mut metadata, &other);
         Ok(SpinSystem {
             number_spins: Some(self.number_spins().max(other.number_spins())),
             operator: self.hamiltonian * other.hamiltonian,
+            metadata,
         })
     }
 }
@@ -573,6 +1211,54 @@ impl fmt::Display for SpinHamiltonianSystem {
     }
 }
 
+impl CanonicalText for SpinHamiltonianSystem {
+    /// Returns the canonical text representation of the SpinHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinHamiltonianSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "SpinHamiltonianSystem",
+            &[("number_spins", canonical_number(&self.number_spins))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a SpinHamiltonianSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinHamiltonianSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinHamiltonianSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinHamiltonianSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "SpinHamiltonianSystem", &["number_spins"])?;
+        let mut new_self = SpinHamiltonianSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in parse_canonical_text_entries::<PauliProduct, CalculatorFloat>(&lines)? {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for SpinHamiltonianSystem {
+    /// Returns an upper bound of the spectral norm of the SpinHamiltonianSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The SpinHamiltonianSystem contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.hamiltonian().spectral_norm_bound()
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinHamiltonianSystem {
     type Output = FermionHamiltonianSystem;
 
@@ -596,3 +1282,47 @@ impl JordanWignerSpinToFermion for SpinHamiltonianSystem {
             .expect("Internal bug in jordan_wigner() for SpinHamiltonian. The number of modes in the resulting fermionic Hamiltonian should equal the number of spins of the spin Hamiltonian.")
     }
 }
+
+impl SystemDimension for SpinHamiltonianSystem {
+    /// Returns the dimension of the Hilbert space of the SpinHamiltonianSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinHamiltonianSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_spins(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the SpinHamiltonianSystem.
+    ///
+    /// Terms that are not naturally hermitian also represent their hermitian conjugate and are counted twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinHamiltonianSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(
+            dimension,
+            hermitian_term_count(self.keys()),
+            bytes_per_entry,
+        )
+    }
+}
@@ -12,16 +12,25 @@
 
 use super::{OperateOnSpins, SingleDecoherenceOperator, ToSparseMatrixSuperOperator};
 use crate::fermions::FermionLindbladNoiseOperator;
+use crate::linalg::dense_logarithm;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{DecoherenceOperator, DecoherenceProduct};
+use crate::spins::{
+    DecoherenceOperator, DecoherenceProduct, PlusMinusLindbladNoiseOperator, PlusMinusProduct,
+    SpinLocality,
+};
 use crate::{
-    CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_noise_text_entries, canonical_text_document, noise_adjoint_violations,
+    noise_symmetrized_entries, parse_canonical_noise_text_entries, split_canonical_text,
+    CanonicalText, CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError,
+    StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 use itertools::Itertools;
+use ndarray::Array2;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeSet;
 use std::fmt::{self, Write};
 use std::iter::{FromIterator, IntoIterator};
 use std::ops;
@@ -69,6 +78,8 @@ pub struct SpinLindbladNoiseOperator {
     internal_map: IndexMap<(DecoherenceProduct, DecoherenceProduct), CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<(DecoherenceProduct, DecoherenceProduct), CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for SpinLindbladNoiseOperator {}
@@ -83,7 +94,7 @@ impl schemars::JsonSchema for SpinLindbladNoiseOperator {
         <SpinLindbladNoiseOperatorSerialize>::json_schema(gen)
     }
 }
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 struct SpinLindbladNoiseOperatorSerialize {
@@ -94,25 +105,65 @@ struct SpinLindbladNoiseOperatorSerialize {
         CalculatorFloat,
         CalculatorFloat,
     )>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// The struqture version
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for SpinLindbladNoiseOperatorSerialize {
+    /// Serialization function for SpinLindbladNoiseOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinLindbladNoiseOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinLindbladNoiseOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "SpinLindbladNoiseOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<SpinLindbladNoiseOperatorSerialize> for SpinLindbladNoiseOperator {
     fn from(value: SpinLindbladNoiseOperatorSerialize) -> Self {
-        let new_noise_op: SpinLindbladNoiseOperator = value
+        let mut new_noise_op: SpinLindbladNoiseOperator = value
             .items
             .into_iter()
             .map(|(left, right, real, imag)| {
                 ((left, right), CalculatorComplex { re: real, im: imag })
             })
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<SpinLindbladNoiseOperator> for SpinLindbladNoiseOperatorSerialize {
     fn from(value: SpinLindbladNoiseOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(
             DecoherenceProduct,
             DecoherenceProduct,
@@ -128,11 +179,23 @@ impl From<SpinLindbladNoiseOperator> for SpinLindbladNoiseOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for SpinLindbladNoiseOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = SpinLindbladNoiseOperatorSerialize::from(self.clone());
+        serializable
+            .items
+            .sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseOperator {
     type Index = (DecoherenceProduct, DecoherenceProduct);
     type Value = CalculatorComplex;
@@ -178,10 +241,25 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the SpinLindbladNoiseOperator with the given ((DecoherenceProduct, DecoherenceProduct) key, CalculatorComplex value) pair.
@@ -205,7 +283,7 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseOperator {
             return Err(StruqtureError::InvalidLindbladTerms);
         }
 
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -325,6 +403,7 @@ impl SpinLindbladNoiseOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -343,6 +422,7 @@ impl SpinLindbladNoiseOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -437,6 +517,285 @@ impl SpinLindbladNoiseOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns the keys (A, B) whose coefficient is not the complex conjugate of the coefficient of (B, A).
+    ///
+    /// The Kossakowski matrix of a physical noise operator is self-adjoint, so every entry (A, B)
+    /// has to be matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(DecoherenceProduct, DecoherenceProduct)>` - The keys violating the self-adjoint structure.
+    pub fn adjoint_violations(&self) -> Vec<(DecoherenceProduct, DecoherenceProduct)> {
+        noise_adjoint_violations(self.iter())
+    }
+
+    /// Returns true if every entry (A, B) is matched by the complex conjugate entry (B, A).
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the SpinLindbladNoiseOperator is self-adjoint.
+    pub fn is_self_adjoint(&self) -> bool {
+        self.adjoint_violations().is_empty()
+    }
+
+    /// Enforces the self-adjoint structure of the SpinLindbladNoiseOperator.
+    ///
+    /// The coefficient of every violating entry (A, B) is replaced by (c(A, B) + c(B, A)^*) / 2.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(DecoherenceProduct, DecoherenceProduct)>` - The keys whose coefficients were changed or added.
+    pub fn symmetrize(&mut self) -> Vec<(DecoherenceProduct, DecoherenceProduct)> {
+        let changes = noise_symmetrized_entries(self.iter());
+        let mut changed_keys: Vec<(DecoherenceProduct, DecoherenceProduct)> =
+            Vec::with_capacity(changes.len());
+        for (key, value) in changes {
+            self.set(key.clone(), value)
+                .expect("Internal bug in set for a key already contained in transposed form.");
+            changed_keys.push(key);
+        }
+        changed_keys
+    }
+
+    /// Returns the dense Kossakowski (rate) matrix of the SpinLindbladNoiseOperator in a given basis.
+    ///
+    /// The entry `[i, j]` of the matrix is the coefficient of the key `(basis[i], basis[j])`.
+    /// All terms with a left or right DecoherenceProduct outside of the basis are returned as the remainder,
+    /// so that the matrix and the remainder together represent the full noise operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The ordered DecoherenceProducts spanning the rows and columns of the matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Array2<Complex64>, SpinLindbladNoiseOperator))` - The Kossakowski matrix in the basis and the remaining terms.
+    /// * `Err(StruqtureError::GenericError)` - The basis contains a DecoherenceProduct more than once.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient of a term inside the basis is symbolic.
+    pub fn kossakowski_matrix(
+        &self,
+        basis: &[DecoherenceProduct],
+    ) -> Result<(Array2<Complex64>, SpinLindbladNoiseOperator), StruqtureError> {
+        let mut positions: HashMap<&DecoherenceProduct, usize> =
+            HashMap::with_capacity(basis.len());
+        for (position, product) in basis.iter().enumerate() {
+            if positions.insert(product, position).is_some() {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "DecoherenceProduct {} appears more than once in the basis",
+                        product
+                    ),
+                });
+            }
+        }
+        let mut matrix: Array2<Complex64> = Array2::zeros((basis.len(), basis.len()));
+        let mut remainder = SpinLindbladNoiseOperator::new();
+        for ((left, right), value) in self.iter() {
+            match (positions.get(left), positions.get(right)) {
+                (Some(row), Some(column)) => {
+                    matrix[[*row, *column]] =
+                        Complex64::new(*value.re.float()?, *value.im.float()?);
+                }
+                _ => {
+                    remainder.set((left.clone(), right.clone()), value.clone())?;
+                }
+            }
+        }
+        Ok((matrix, remainder))
+    }
+
+    /// Creates the standard thermalization noise of independent qubits with amplitude damping and pure dephasing.
+    ///
+    /// For qubit i with temperature T_i (in units of the qubit energy, k_B T_i / ħω_i), relaxation rate γ_i and
+    /// pure dephasing rate γ_φ,i the noise terms are
+    /// * relaxation from |1> to |0> (σ^+ in the PlusMinusProduct convention) with rate γ_i (n_i + 1),
+    /// * excitation from |0> to |1> (σ^-) with rate γ_i n_i,
+    /// * dephasing Z with rate γ_φ,i / 2, so that coherences decay with the rate γ_φ,i,
+    ///
+    /// where n_i = 1 / (exp(1 / T_i) - 1) is the thermal occupation (zero for T_i = 0).
+    ///
+    /// # Arguments
+    ///
+    /// * `temperatures` - The temperature of the bath of every qubit, in units of the qubit energy.
+    /// * `rates` - The (relaxation rate, pure dephasing rate) of every qubit.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseOperator with the thermalization noise of all qubits.
+    /// * `Err(StruqtureError::GenericError)` - The numbers of temperatures and rates differ or a temperature or rate is negative.
+    pub fn damping_and_dephasing(
+        temperatures: &[f64],
+        rates: &[(f64, f64)],
+    ) -> Result<Self, StruqtureError> {
+        if temperatures.len() != rates.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Got {} temperatures but {} rates, one of each is needed for every qubit",
+                    temperatures.len(),
+                    rates.len()
+                ),
+            });
+        }
+        let mut plus_minus_noise = PlusMinusLindbladNoiseOperator::new();
+        for (qubit, (temperature, (relaxation, dephasing))) in
+            temperatures.iter().zip(rates.iter()).enumerate()
+        {
+            if *temperature < 0.0 || *relaxation < 0.0 || *dephasing < 0.0 {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Temperature {} and rates ({}, {}) of qubit {} must not be negative",
+                        temperature, relaxation, dephasing, qubit
+                    ),
+                });
+            }
+            let occupation = thermal_occupation(*temperature);
+            for (product, rate) in [
+                (
+                    PlusMinusProduct::new().plus(qubit),
+                    relaxation * (occupation + 1.0),
+                ),
+                (
+                    PlusMinusProduct::new().minus(qubit),
+                    relaxation * occupation,
+                ),
+                (PlusMinusProduct::new().z(qubit), dephasing / 2.0),
+            ] {
+                if rate > 0.0 {
+                    plus_minus_noise
+                        .set((product.clone(), product), CalculatorComplex::from(rate))?;
+                }
+            }
+        }
+        Ok(SpinLindbladNoiseOperator::from(plus_minus_noise))
+    }
+
+    /// Returns the noise operator of the simultaneous action of self and another noise operator.
+    ///
+    /// Generators of the Lindblad equation add, so the result is the sum of both noise operators.
+    /// For a time step t the channel exp(t (L_1 + L_2)) only equals the sequential channel
+    /// exp(t L_2) exp(t L_1) if the generators commute, see `concatenate_channels` for the sequential composition.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The noise operator acting together with self.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The sum of the generators.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
+
+    /// Returns the effective noise operator of the channel of self followed by the channel of another noise operator.
+    ///
+    /// The channels exp(t L_1) of self and exp(t L_2) of other are concatenated and the effective generator
+    /// log(exp(t L_2) exp(t L_1)) / t is expanded in the basis of all DecoherenceProducts of the spins.
+    /// Coherent (Hamiltonian) contributions to the effective generator, which can arise from non-commuting noise,
+    /// are dropped, as are coefficients with an absolute value below 1e-12.
+    /// The dense matrices have 16^N entries, so the method is intended for a few spins.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The noise operator whose channel is applied second.
+    /// * `time` - The time step t of both channels.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The effective noise operator of the concatenated channel.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The time step is not positive or the concatenated channel has no logarithm.
+    pub fn concatenate_channels(&self, other: &Self, time: f64) -> Result<Self, StruqtureError> {
+        if !(time > 0.0) {
+            return Err(StruqtureError::GenericError {
+                msg: format!("Time step {} of the channels needs to be positive", time),
+            });
+        }
+        let number_spins = self
+            .current_number_spins()
+            .max(other.current_number_spins());
+        let dimension = 2usize.pow(number_spins as u32);
+        let to_dense = |(values, (rows, columns)): CooSparseMatrix| {
+            let mut matrix: Array2<Complex64> =
+                Array2::zeros((dimension * dimension, dimension * dimension));
+            for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+                matrix[[row, column]] += value;
+            }
+            matrix
+        };
+        let first = to_dense(self.channel_superoperator_coo(time, Some(number_spins))?);
+        let second = to_dense(other.channel_superoperator_coo(time, Some(number_spins))?);
+        let generator =
+            dense_logarithm(&second.dot(&first)).ok_or_else(|| StruqtureError::GenericError {
+                msg: "Concatenated channel has no principal logarithm".to_string(),
+            })? / Complex64::new(time, 0.0);
+
+        // All DecoherenceProducts except the identity, every spin is I, X, iY or Z
+        let mut basis: Vec<(DecoherenceProduct, CooSparseMatrix)> = Vec::new();
+        for index in 1..4usize.pow(number_spins as u32) {
+            let mut product = DecoherenceProduct::new();
+            for spin in 0..number_spins {
+                product = match (index >> (2 * spin)) % 4 {
+                    1 => product.x(spin),
+                    2 => product.iy(spin),
+                    3 => product.z(spin),
+                    _ => product,
+                };
+            }
+            let matrix = product.to_coo(number_spins)?;
+            basis.push((product, matrix));
+        }
+        // The superoperator of p -> A p B^† is A ⊗ conj(B) in the row-major flattening, these superoperators
+        // are orthogonal with the squared norm dimension^2
+        let normalization = (dimension * dimension) as f64;
+        let mut noise = SpinLindbladNoiseOperator::new();
+        for (left, (left_values, (left_rows, left_columns))) in basis.iter() {
+            for (right, (right_values, (right_rows, right_columns))) in basis.iter() {
+                let mut coefficient = Complex64::new(0.0, 0.0);
+                for ((left_value, left_row), left_column) in
+                    left_values.iter().zip(left_rows).zip(left_columns)
+                {
+                    for ((right_value, right_row), right_column) in
+                        right_values.iter().zip(right_rows).zip(right_columns)
+                    {
+                        coefficient += (left_value * right_value.conj()).conj()
+                            * generator[[
+                                left_row * dimension + right_row,
+                                left_column * dimension + right_column,
+                            ]];
+                    }
+                }
+                coefficient /= normalization;
+                if coefficient.norm() > CHANNEL_COEFFICIENT_THRESHOLD {
+                    noise.set(
+                        (left.clone(), right.clone()),
+                        CalculatorComplex::new(coefficient.re, coefficient.im),
+                    )?;
+                }
+            }
+        }
+        Ok(noise)
+    }
+}
+
+/// Coefficients of the effective noise of concatenated channels below this threshold are dropped.
+const CHANNEL_COEFFICIENT_THRESHOLD: f64 = 1e-12;
+
+/// Returns the Bose-Einstein occupation 1 / (exp(1 / T) - 1) for a temperature in units of the mode energy.
+///
+/// # Arguments
+///
+/// * `temperature` - The temperature k_B T / ħω.
+///
+/// # Returns
+///
+/// * `f64` - The thermal occupation, zero for a vanishing temperature.
+fn thermal_occupation(temperature: f64) -> f64 {
+    if temperature > 0.0 {
+        1.0 / (1.0 / temperature).exp_m1()
+    } else {
+        0.0
+    }
 }
 
 /// Implements the negative sign function of SpinLindbladNoiseOperator.
@@ -449,6 +808,7 @@ impl ops::Neg for SpinLindbladNoiseOperator {
     ///
     /// * `Self` - The SpinLindbladNoiseOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -458,6 +818,7 @@ impl ops::Neg for SpinLindbladNoiseOperator {
         }
         SpinLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -539,6 +900,7 @@ where
     ///
     /// * `Self` - The SpinLindbladNoiseOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
@@ -547,8 +909,10 @@ where
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         SpinLindbladNoiseOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -677,6 +1041,43 @@ impl fmt::Display for SpinLindbladNoiseOperator {
     }
 }
 
+impl CanonicalText for SpinLindbladNoiseOperator {
+    /// Returns the canonical text representation of the SpinLindbladNoiseOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinLindbladNoiseOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "SpinLindbladNoiseOperator",
+            &[],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a SpinLindbladNoiseOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinLindbladNoiseOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinLindbladNoiseOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "SpinLindbladNoiseOperator", &[])?;
+        let mut new_self = SpinLindbladNoiseOperator::new();
+        for (key, value) in
+            parse_canonical_noise_text_entries::<DecoherenceProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 /// Add anti-commutator Lindblad contributions
 fn add_anti_commutator(
     left: &DecoherenceProduct,
@@ -831,6 +1232,20 @@ fn add_lindblad_terms(
     Ok(())
 }
 
+impl SpinLocality for SpinLindbladNoiseOperator {
+    // From trait
+    fn term_supports(&self) -> Vec<BTreeSet<usize>> {
+        self.keys()
+            .map(|(left, right)| {
+                left.iter()
+                    .chain(right.iter())
+                    .map(|(index, _)| *index)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinLindbladNoiseOperator {
     type Output = FermionLindbladNoiseOperator;
 
@@ -12,12 +12,25 @@
 
 use super::{DecoherenceProduct, ToSparseMatrixSuperOperator};
 use crate::fermions::FermionLindbladNoiseSystem;
+use crate::linalg::hermitian_eigh;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{OperateOnSpins, SpinIndex, SpinLindbladNoiseOperator};
-use crate::{CooSparseMatrix, OperateOnDensityMatrix, StruqtureError};
+use crate::metadata::merge_operand_metadata;
+use crate::spins::{
+    OperateOnSpins, SpinIndex, SpinLindbladNoiseOperator, SpinOperator, SymbolicSuperOperator,
+};
+use crate::{
+    canonical_noise_text_entries, canonical_number, canonical_text_document,
+    hilbert_space_dimension, noise_is_completely_positive, parse_canonical_noise_text_entries,
+    parse_canonical_number, sparse_matrix_memory, split_canonical_text, CanonicalText,
+    CooSparseMatrix, OperateOnDensityMatrix, StruqtureError, SystemDimension, ZeroTermPolicy,
+};
+use ndarray::Array2;
 use num_complex::Complex64;
-use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::{
     fmt::{self, Write},
@@ -55,7 +68,7 @@ use std::collections::hash_map::{Iter, Keys, Values};
 /// assert_eq!(system.get(&(pp_0z.clone(), pp_0z.clone())), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 pub struct SpinLindbladNoiseSystem {
@@ -63,10 +76,88 @@ pub struct SpinLindbladNoiseSystem {
     pub(crate) number_spins: Option<usize>,
     /// The SpinLindbladNoiseOperator representing the Lindblad noise terms of the SpinLindbladNoiseSystem.
     pub(crate) operator: SpinLindbladNoiseOperator,
+    /// Provenance metadata of the SpinLindbladNoiseSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for SpinLindbladNoiseSystem {}
 
+impl Serialize for SpinLindbladNoiseSystem {
+    /// Serialization function for SpinLindbladNoiseSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinLindbladNoiseSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinLindbladNoiseSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for SpinLindbladNoiseSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a SpinLindbladNoiseSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - SpinLindbladNoiseSystem to be serialized.
+/// * `operator` - Serialization of the operator of the SpinLindbladNoiseSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of SpinLindbladNoiseSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &SpinLindbladNoiseSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer
+        .serialize_struct("SpinLindbladNoiseSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for SpinLindbladNoiseSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseSystem {
     type Value = CalculatorComplex;
     type Index = (DecoherenceProduct, DecoherenceProduct);
@@ -104,15 +195,27 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinLindbladNoiseSystem {
         match capacity {
             Some(cap) => Self {
                 number_spins: self.number_spins,
-                operator: SpinLindbladNoiseOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins,
-                operator: SpinLindbladNoiseOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the SpinLindbladNoiseSystem with the given ((DecoherenceProduct, DecoherenceProduct) key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -242,6 +345,7 @@ impl SpinLindbladNoiseSystem {
         SpinLindbladNoiseSystem {
             number_spins,
             operator: SpinLindbladNoiseOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -259,6 +363,7 @@ impl SpinLindbladNoiseSystem {
         SpinLindbladNoiseSystem {
             number_spins,
             operator: SpinLindbladNoiseOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -292,6 +397,7 @@ impl SpinLindbladNoiseSystem {
                     Ok(SpinLindbladNoiseSystem {
                         number_spins: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberSpinsExceeded)
@@ -300,10 +406,81 @@ impl SpinLindbladNoiseSystem {
             None => Ok(SpinLindbladNoiseSystem {
                 number_spins: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
 
+    /// Creates a SpinLindbladNoiseSystem from the measured relaxation and coherence times of the spins.
+    ///
+    /// Every spin i is damped towards the state |0> with the rate 1/T1_i using the jump operator
+    /// σ^- = (X + iY) / 2, and dephased with the pure dephasing rate 1/T_φ = 1/T2_i - 1/(2 T1_i)
+    /// using the jump operator Z with the coefficient 1/(2 T_φ).
+    /// The times need to be given in the unit of time used to evolve the system, infinite times disable the noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `t1` - The relaxation times T1 of the spins.
+    /// * `t2` - The coherence times T2 of the spins.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseSystem with one spin per entry of the times.
+    /// * `Err(StruqtureError::GenericError)` - The numbers of times differ, a time is not positive or T2 exceeds 2 T1.
+    pub fn from_t1_t2(t1: &[f64], t2: &[f64]) -> Result<Self, StruqtureError> {
+        if t1.len() != t2.len() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Number of T1 times {} does not match number of T2 times {}",
+                    t1.len(),
+                    t2.len()
+                ),
+            });
+        }
+        let mut system = SpinLindbladNoiseSystem::with_capacity(Some(t1.len()), 5 * t1.len());
+        for (spin, (relaxation_time, coherence_time)) in t1.iter().zip(t2.iter()).enumerate() {
+            // Also rejects NaN
+            if !(*relaxation_time > 0.0 && *coherence_time > 0.0) {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "Times of spin {} need to be positive, found T1 = {} and T2 = {}",
+                        spin, relaxation_time, coherence_time
+                    ),
+                });
+            }
+            let damping_rate = 1.0 / relaxation_time;
+            let dephasing_rate = 1.0 / coherence_time - 0.5 * damping_rate;
+            if dephasing_rate < 0.0 {
+                return Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "T2 = {} of spin {} exceeds twice T1 = {}",
+                        coherence_time, spin, relaxation_time
+                    ),
+                });
+            }
+            if damping_rate > 0.0 {
+                let x = DecoherenceProduct::new().x(spin);
+                let iy = DecoherenceProduct::new().iy(spin);
+                for left in [&x, &iy] {
+                    for right in [&x, &iy] {
+                        system.add_operator_product(
+                            (left.clone(), right.clone()),
+                            CalculatorComplex::from(0.25 * damping_rate),
+                        )?;
+                    }
+                }
+            }
+            if dephasing_rate > 0.0 {
+                let z = DecoherenceProduct::new().z(spin);
+                system.add_operator_product(
+                    (z.clone(), z),
+                    CalculatorComplex::from(0.5 * dephasing_rate),
+                )?;
+            }
+        }
+        Ok(system)
+    }
+
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
     ///
     /// # Arguments
@@ -331,6 +508,148 @@ impl SpinLindbladNoiseSystem {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns whether the noise is completely positive, i.e. whether its Kossakowski matrix is positive semidefinite.
+    ///
+    /// The Kossakowski matrix is constructed in the basis of all operator products appearing in the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues of the Kossakowski matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the SpinLindbladNoiseSystem is completely positive.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn is_completely_positive(&self, tolerance: f64) -> Result<bool, StruqtureError> {
+        noise_is_completely_positive(self.iter(), tolerance)
+    }
+
+    /// Returns the jump operators of the diagonal form of the noise.
+    ///
+    /// The Kossakowski matrix Γ of the noise is constructed in the basis of all DecoherenceProducts A_j appearing
+    /// in the keys and diagonalized as Γ = U diag(γ) U^†. The jump operators
+    /// L_m = sqrt(γ_m) sum_j U_{j,m} A_j then describe the same noise as the sum of the terms
+    /// L_m ρ L_m^† - 1/2 {L_m^† L_m, ρ}, which is the form expected by most master equation solvers.
+    /// Eigenvalues with an absolute value below the tolerance are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The tolerance for the self-adjoint structure and for negative eigenvalues of the Kossakowski matrix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<SpinOperator>)` - The jump operators.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - The noise is not completely positive.
+    pub fn jump_operators(&self, tolerance: f64) -> Result<Vec<SpinOperator>, StruqtureError> {
+        if !self.is_completely_positive(tolerance)? {
+            return Err(StruqtureError::GenericError {
+                msg: "Jump operators require completely positive noise".to_string(),
+            });
+        }
+        let mut basis: Vec<&DecoherenceProduct> = Vec::new();
+        let mut positions: HashMap<&DecoherenceProduct, usize> = HashMap::new();
+        for (left, right) in self.keys() {
+            for product in [left, right] {
+                if !positions.contains_key(product) {
+                    positions.insert(product, basis.len());
+                    basis.push(product);
+                }
+            }
+        }
+        let mut matrix: Array2<Complex64> = Array2::zeros((basis.len(), basis.len()));
+        for ((left, right), value) in self.iter() {
+            matrix[[positions[left], positions[right]]] =
+                Complex64::new(*value.re.float()?, *value.im.float()?);
+        }
+        let (eigenvalues, vectors) = hermitian_eigh(&matrix);
+        let mut jump_operators: Vec<SpinOperator> = Vec::new();
+        for (column, eigenvalue) in eigenvalues.iter().enumerate() {
+            if *eigenvalue <= tolerance {
+                continue;
+            }
+            let mut jump_operator = SpinOperator::new();
+            for (row, product) in basis.iter().enumerate() {
+                let (pauli_product, prefactor) =
+                    DecoherenceProduct::decoherence_to_spin((*product).clone());
+                let value = vectors[[row, column]] * prefactor * eigenvalue.sqrt();
+                jump_operator.add_operator_product(
+                    pauli_product,
+                    CalculatorComplex::new(value.re, value.im),
+                )?;
+            }
+            jump_operators.push(jump_operator);
+        }
+        Ok(jump_operators)
+    }
+
+    /// Constructs the superoperator of a SpinLindbladNoiseSystem with symbolic coefficients.
+    ///
+    /// The terms are grouped by the symbolic expressions in the real and imaginary parts of their coefficients and
+    /// the superoperator of every group is constructed numerically, so that the superoperator can be evaluated
+    /// efficiently for many values of the symbolic parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the superoperator, defaults to the number of spins of the system.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SymbolicSuperOperator)` - The constant and per-expression superoperators.
+    /// * `Err(StruqtureError)` - The number of spins is smaller than the number of spins the terms act on.
+    pub fn symbolic_sparse_matrix_superoperator(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<SymbolicSuperOperator, StruqtureError> {
+        let number_spins = number_spins.unwrap_or_else(|| self.number_spins());
+        Ok(SymbolicSuperOperator::from_parts(
+            self.symbolic_superoperator_parts(number_spins)?,
+        ))
+    }
+
+    /// Constructs the superoperators of the terms of self grouped by their symbolic coefficient.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the superoperators.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Option<String>, CooSparseMatrix)>)` - The expression (None for numeric coefficients) and superoperator of every group.
+    /// * `Err(StruqtureError)` - The number of spins is smaller than the number of spins the terms act on.
+    pub(crate) fn symbolic_superoperator_parts(
+        &self,
+        number_spins: usize,
+    ) -> Result<Vec<(Option<String>, CooSparseMatrix)>, StruqtureError> {
+        let mut groups: HashMap<Option<String>, SpinLindbladNoiseSystem> = HashMap::new();
+        for (key, value) in self.iter() {
+            // The real part enters with a factor 1 and the imaginary part with a factor i
+            for (part, unit) in [
+                (&value.re, CalculatorComplex::new(1.0, 0.0)),
+                (&value.im, CalculatorComplex::new(0.0, 1.0)),
+            ] {
+                let (expression, factor) = match part {
+                    CalculatorFloat::Float(x) if *x == 0.0 => continue,
+                    CalculatorFloat::Float(x) => (None, unit * *x),
+                    CalculatorFloat::Str(expression) => (Some(expression.clone()), unit),
+                };
+                groups
+                    .entry(expression)
+                    .or_insert_with(|| SpinLindbladNoiseSystem::new(Some(number_spins)))
+                    .add_operator_product(key.clone(), factor)?;
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(expression, group)| {
+                Ok((
+                    expression,
+                    group.sparse_matrix_superoperator_coo(Some(number_spins))?,
+                ))
+            })
+            .collect()
+    }
 }
 
 /// Implements the negative sign function of SpinLindbladNoiseSystem.
@@ -352,7 +671,7 @@ impl ops::Neg for SpinLindbladNoiseSystem {
 ///
 impl<T, V> ops::Add<T> for SpinLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((DecoherenceProduct, DecoherenceProduct), V)>,
+    T: IntoIterator<Item = ((DecoherenceProduct, DecoherenceProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -367,6 +686,7 @@ where
     /// * `Ok(Self)` - The two SpinLindbladNoiseSystem added together.
     /// * `Err(StruqtureError::NumberSpinsExceeded)` - Index of (DecoherenceProduct, DecoherenceProduct) exceeds that of the SpinLindbladNoiseSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -378,7 +698,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for SpinLindbladNoiseSystem
 where
-    T: IntoIterator<Item = ((DecoherenceProduct, DecoherenceProduct), V)>,
+    T: IntoIterator<Item = ((DecoherenceProduct, DecoherenceProduct), V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -393,6 +713,7 @@ where
     /// * `Ok(Self)` - The two SpinLindbladNoiseSystem subtracted.
     /// * `Err(StruqtureError::NumberSpinsExceeded)` - Index of (DecoherenceProduct, DecoherenceProduct) exceeds that of the SpinLindbladNoiseSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -545,6 +866,44 @@ impl fmt::Display for SpinLindbladNoiseSystem {
     }
 }
 
+impl CanonicalText for SpinLindbladNoiseSystem {
+    /// Returns the canonical text representation of the SpinLindbladNoiseSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinLindbladNoiseSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "SpinLindbladNoiseSystem",
+            &[("number_spins", canonical_number(&self.number_spins))],
+            &canonical_noise_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a SpinLindbladNoiseSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladNoiseSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinLindbladNoiseSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinLindbladNoiseSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) =
+            split_canonical_text(text, "SpinLindbladNoiseSystem", &["number_spins"])?;
+        let mut new_self = SpinLindbladNoiseSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in
+            parse_canonical_noise_text_entries::<DecoherenceProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinLindbladNoiseSystem {
     type Output = FermionLindbladNoiseSystem;
 
@@ -568,3 +927,44 @@ impl JordanWignerSpinToFermion for SpinLindbladNoiseSystem {
             .expect("Internal bug in jordan_wigner() for SpinLindbladNoiseOperator. The number of modes in the resulting fermionic noise operator should equal the number of spins of the spin noise operator.")
     }
 }
+
+impl SystemDimension for SpinLindbladNoiseSystem {
+    /// Returns the dimension of the Hilbert space of the SpinLindbladNoiseSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinLindbladNoiseSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_spins(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the SpinLindbladNoiseSystem.
+    ///
+    /// The estimate is for the sparse superoperator, to which every Lindblad term contributes at most three non-zero entries per row.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinLindbladNoiseSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Every Lindblad term contributes L ρ R^dagger and the two terms of its anticommutator
+        sparse_matrix_memory(dimension, 3 * self.len(), bytes_per_entry)
+    }
+}
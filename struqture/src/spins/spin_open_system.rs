@@ -10,14 +10,25 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::SpinLindbladNoiseSystem;
+use super::{
+    DecoherenceProduct, SpinLindbladNoiseOperator, SpinLindbladNoiseSystem, SymbolicSuperOperator,
+};
 use crate::fermions::FermionLindbladOpenSystem;
 use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{OperateOnSpins, SpinHamiltonianSystem, ToSparseMatrixSuperOperator};
-use crate::{CooSparseMatrix, OpenSystem, OperateOnDensityMatrix, StruqtureError};
+use crate::spins::{
+    OperateOnSpins, SpinHamiltonianSystem, ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
+};
+use crate::{
+    hermitian_term_count, hilbert_space_dimension, sparse_matrix_memory,
+    split_canonical_open_system, CanonicalText, CooSparseMatrix, MetadataMergePolicy, OpenSystem,
+    OperateOnDensityMatrix, SpinIndex, StruqtureError, SystemDimension,
+};
+use ndarray::Array2;
 use num_complex::Complex64;
 use qoqo_calculator::CalculatorFloat;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::ops;
@@ -56,6 +67,28 @@ pub struct SpinLindbladOpenSystem {
 
 impl crate::MinSupportedVersion for SpinLindbladOpenSystem {}
 
+impl crate::SerializeCanonical for SpinLindbladOpenSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SpinLindbladOpenSystem", 2)?;
+        state.serialize_field("system", &crate::Canonical(&self.system))?;
+        state.serialize_field("noise", &crate::Canonical(&self.noise))?;
+        state.end()
+    }
+}
+
+impl crate::OperateOnMetadata for SpinLindbladOpenSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.system.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.system.metadata
+    }
+}
+
 impl<'a> OpenSystem<'a> for SpinLindbladOpenSystem {
     type System = SpinHamiltonianSystem;
     type Noise = SpinLindbladNoiseSystem;
@@ -205,6 +238,20 @@ impl<'a> ToSparseMatrixSuperOperator<'a> for SpinLindbladOpenSystem {
     }
 }
 
+/// Maximal number of spins for which the detailed balance condition is checked numerically.
+const MAX_DETAILED_BALANCE_SPINS: usize = 5;
+
+/// Result of checking the quantum detailed balance condition of a SpinLindbladOpenSystem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedBalanceReport {
+    /// Whether the relative residual is below the requested tolerance.
+    pub satisfies_detailed_balance: bool,
+    /// The relative residual ||D R_ρ - R_ρ D^†|| / ||D R_ρ|| of the detailed balance condition.
+    pub residual: f64,
+    /// The noise terms contributing to the violation with their fraction of the squared residual, sorted by decreasing contribution.
+    pub violating_terms: Vec<((DecoherenceProduct, DecoherenceProduct), f64)>,
+}
+
 /// Functions for the SpinLindbladOpenSystem
 ///
 impl SpinLindbladOpenSystem {
@@ -223,6 +270,254 @@ impl SpinLindbladOpenSystem {
             noise: SpinLindbladNoiseSystem::new(number_spins),
         }
     }
+
+    /// Combines two SpinLindbladOpenSystems acting on disjoint sets of spins into one SpinLindbladOpenSystem.
+    ///
+    /// The spins of `other` are shifted by `index_offset`, which must not be smaller than the number of spins of `self`.
+    /// The Hamiltonian and the noise of the result are the sums of the Hamiltonians and the noises of both SpinLindbladOpenSystems,
+    /// so that the combined system evolves like the two systems independently.
+    /// The number of spins of the result is only fixed if it is fixed for both SpinLindbladOpenSystems.
+    /// The metadata of both SpinLindbladOpenSystems is merged, keeping the values of `self` for keys contained in both.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinLindbladOpenSystem acting on the spins starting at `index_offset`.
+    /// * `index_offset` - The index of the combined system that spin 0 of `other` is shifted to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The combined SpinLindbladOpenSystem.
+    /// * `Err(StruqtureError::GenericError)` - The shifted spins of `other` overlap with the spins of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{DecoherenceProduct, PauliProduct, SpinLindbladOpenSystem};
+    ///
+    /// let mut device = SpinLindbladOpenSystem::new(Some(2));
+    /// device.system_mut().add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0)).unwrap();
+    /// let damping = DecoherenceProduct::new().z(1);
+    /// device.noise_mut().add_operator_product((damping.clone(), damping), CalculatorComplex::from(0.1)).unwrap();
+    ///
+    /// let combined = device.direct_sum(&device, 2).unwrap();
+    /// assert_eq!(combined.number_spins(), 4);
+    /// assert_eq!(combined.system().get(&PauliProduct::new().z(2).z(3)), &CalculatorFloat::from(1.0));
+    /// let shifted = DecoherenceProduct::new().z(3);
+    /// assert_eq!(combined.noise().get(&(shifted.clone(), shifted)), &CalculatorComplex::from(0.1));
+    /// ```
+    pub fn direct_sum(&self, other: &Self, index_offset: usize) -> Result<Self, StruqtureError> {
+        if index_offset < self.number_spins() {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Index offset {} is smaller than the number of spins {} of the first system, the spins of both systems would overlap",
+                    index_offset,
+                    self.number_spins()
+                ),
+            });
+        }
+        let mapping: HashMap<usize, usize> = (0..other.number_spins())
+            .map(|index| (index, index + index_offset))
+            .collect();
+        let number_spins = self
+            .system
+            .number_spins
+            .and(other.system.number_spins)
+            .map(|_| index_offset + other.number_spins());
+        let mut combined = self.clone();
+        MetadataMergePolicy::PreferLeft
+            .merge(&mut combined.system.metadata, &other.system.metadata)?;
+        combined.system.number_spins = number_spins;
+        combined.noise.number_spins = number_spins;
+        for (product, value) in other.system.iter() {
+            combined
+                .system
+                .add_operator_product(product.remap_qubits(&mapping), value.clone())?;
+        }
+        for ((left, right), value) in other.noise.iter() {
+            combined.noise.add_operator_product(
+                (left.remap_qubits(&mapping), right.remap_qubits(&mapping)),
+                value.clone(),
+            )?;
+        }
+        Ok(combined)
+    }
+
+    /// Checks whether the noise satisfies quantum detailed balance with respect to the thermal state of the system.
+    ///
+    /// The dissipator D satisfies (GNS) detailed balance with respect to the thermal state
+    /// ρ = exp(-β H) / Tr(exp(-β H)) if D(X ρ) = D^†(X) ρ for all matrices X, where D^† is the adjoint
+    /// of D with respect to the Hilbert-Schmidt inner product. Davies generators obtained from weak coupling to a
+    /// thermal bath satisfy this condition and have ρ as a stationary state.
+    /// The check is carried out numerically on the dense superoperator and is therefore restricted to small systems.
+    ///
+    /// Every noise term whose individual residual has a positive overlap with the total residual, larger than `tolerance`
+    /// as a fraction of the squared total residual, is reported as violating.
+    ///
+    /// # Arguments
+    ///
+    /// * `beta` - The inverse temperature of the thermal state.
+    /// * `tolerance` - The tolerance for the relative residual and for reporting violating terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DetailedBalanceReport)` - The result of the check.
+    /// * `Err(StruqtureError::GenericError)` - The system has more spins than can be checked numerically.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn check_detailed_balance(
+        &self,
+        beta: f64,
+        tolerance: f64,
+    ) -> Result<DetailedBalanceReport, StruqtureError> {
+        let number_spins = self.number_spins();
+        if number_spins > MAX_DETAILED_BALANCE_SPINS {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Detailed balance can only be checked numerically for up to {} spins, the system has {} spins.",
+                    MAX_DETAILED_BALANCE_SPINS, number_spins
+                ),
+            });
+        }
+        let dimension = 2_usize.pow(number_spins as u32);
+        let mut hamiltonian: Array2<Complex64> = Array2::zeros((dimension, dimension));
+        for ((row, column), value) in self.system.sparse_matrix(Some(number_spins))? {
+            hamiltonian[[row, column]] = value;
+        }
+        let rho = thermal_state(&hamiltonian, beta);
+
+        // Superoperator of every noise term as a list of (row, column, value) entries in the
+        // row-major convention of ToSparseMatrixSuperOperator
+        let mut term_entries: Vec<(
+            (DecoherenceProduct, DecoherenceProduct),
+            Vec<(usize, usize, Complex64)>,
+        )> = Vec::with_capacity(self.noise.len());
+        for (key, value) in self.noise.iter() {
+            let mut single_term = SpinLindbladNoiseOperator::new();
+            single_term.set(key.clone(), value.clone())?;
+            let entries = single_term
+                .sparse_matrix_superoperator(Some(number_spins))?
+                .into_iter()
+                .map(|((row, column), value)| (row, column, value))
+                .collect();
+            term_entries.push((key.clone(), entries));
+        }
+
+        // The right multiplication X -> X ρ acts as I ⊗ ρ^T on row-major flattened matrices.
+        // Residual of one entry D[row, column] of the dissipator, written as (index, value) contributions
+        // to the residual D (I ⊗ ρ^T) - (I ⊗ ρ^T) D^†.
+        let rho = &rho;
+        let entry_residual = |row: usize, column: usize, value: Complex64| {
+            let (block, inner) = (column / dimension, column % dimension);
+            (0..dimension).flat_map(move |k| {
+                [
+                    ((row, block * dimension + k), value * rho[[k, inner]]),
+                    (
+                        (block * dimension + k, row),
+                        -rho[[inner, k]] * value.conj(),
+                    ),
+                ]
+            })
+        };
+        let mut residual: Array2<Complex64> = Array2::zeros((dimension.pow(2), dimension.pow(2)));
+        let mut forward: Array2<Complex64> = Array2::zeros((dimension.pow(2), dimension.pow(2)));
+        for (_, entries) in term_entries.iter() {
+            for (row, column, value) in entries.iter() {
+                for (index, contribution) in entry_residual(*row, *column, *value) {
+                    residual[index] += contribution;
+                }
+                let (block, inner) = (column / dimension, column % dimension);
+                for k in 0..dimension {
+                    forward[[*row, block * dimension + k]] += value * rho[[k, inner]];
+                }
+            }
+        }
+        let residual_norm_sqr: f64 = residual.iter().map(|x| x.norm_sqr()).sum();
+        let forward_norm = forward.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        let relative_residual = if forward_norm > 0.0 {
+            residual_norm_sqr.sqrt() / forward_norm
+        } else {
+            0.0
+        };
+
+        let mut violating_terms: Vec<((DecoherenceProduct, DecoherenceProduct), f64)> = Vec::new();
+        if relative_residual > tolerance {
+            for (key, entries) in term_entries.into_iter() {
+                let overlap: f64 = entries
+                    .iter()
+                    .flat_map(|(row, column, value)| entry_residual(*row, *column, *value))
+                    .map(|(index, contribution)| (contribution.conj() * residual[index]).re)
+                    .sum::<f64>()
+                    / residual_norm_sqr;
+                if overlap > tolerance {
+                    violating_terms.push((key, overlap));
+                }
+            }
+            violating_terms.sort_by(|a, b| b.1.total_cmp(&a.1));
+        }
+
+        Ok(DetailedBalanceReport {
+            satisfies_detailed_balance: relative_residual <= tolerance,
+            residual: relative_residual,
+            violating_terms,
+        })
+    }
+
+    /// Constructs the superoperator of a SpinLindbladOpenSystem with symbolic coefficients.
+    ///
+    /// The terms of the system and the noise are grouped by their symbolic coefficients and the superoperator of
+    /// every group is constructed numerically, so that parameterized Liouvillians can be evaluated efficiently
+    /// for many values of the symbolic parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_spins` - The number of spins for which to construct the superoperator, defaults to the number of spins of the open system.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SymbolicSuperOperator)` - The constant and per-expression superoperators.
+    /// * `Err(StruqtureError)` - The number of spins is smaller than the number of spins the terms act on.
+    pub fn symbolic_sparse_matrix_superoperator(
+        &self,
+        number_spins: Option<usize>,
+    ) -> Result<SymbolicSuperOperator, StruqtureError> {
+        let number_spins = number_spins.unwrap_or_else(|| self.number_spins());
+        let mut parts = self.system.symbolic_superoperator_parts(number_spins)?;
+        parts.extend(self.noise.symbolic_superoperator_parts(number_spins)?);
+        Ok(SymbolicSuperOperator::from_parts(parts))
+    }
+}
+
+/// Computes the normalized thermal state exp(-β H) / Tr(exp(-β H)) by scaling and squaring.
+fn thermal_state(hamiltonian: &Array2<Complex64>, beta: f64) -> Array2<Complex64> {
+    let dimension = hamiltonian.nrows();
+    let exponent = hamiltonian.mapv(|x| x * (-beta));
+    let norm = exponent
+        .rows()
+        .into_iter()
+        .map(|row| row.iter().map(|x| x.norm()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let mut squarings = 0;
+    while norm / 2_f64.powi(squarings) > 0.5 {
+        squarings += 1;
+    }
+    let scaled = exponent.mapv(|x| x / 2_f64.powi(squarings));
+    let mut state: Array2<Complex64> = Array2::eye(dimension);
+    let mut term: Array2<Complex64> = Array2::eye(dimension);
+    for order in 1..20 {
+        term = term.dot(&scaled).mapv(|x| x / order as f64);
+        state = state + &term;
+    }
+    // Renormalizing after every squaring avoids overflow for large β ||H||
+    let normalize = |matrix: Array2<Complex64>| {
+        let trace: Complex64 = matrix.diag().iter().sum();
+        matrix.mapv(|x| x / trace)
+    };
+    state = normalize(state);
+    for _ in 0..squarings {
+        state = normalize(state.dot(&state));
+    }
+    state
 }
 
 /// Implements the negative sign function of SpinLindbladOpenSystem.
@@ -339,6 +634,41 @@ impl fmt::Display for SpinLindbladOpenSystem {
     }
 }
 
+impl CanonicalText for SpinLindbladOpenSystem {
+    /// Returns the canonical text representation of the SpinLindbladOpenSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinLindbladOpenSystem.
+    fn to_canonical_text(&self) -> String {
+        format!(
+            "SpinLindbladOpenSystem\n{}{}",
+            self.system().to_canonical_text(),
+            self.noise().to_canonical_text()
+        )
+    }
+
+    /// Creates a SpinLindbladOpenSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinLindbladOpenSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinLindbladOpenSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinLindbladOpenSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (system, noise) =
+            split_canonical_open_system(text, "SpinLindbladOpenSystem", "SpinLindbladNoiseSystem")?;
+        Self::group(
+            SpinHamiltonianSystem::from_canonical_text(&system)?,
+            SpinLindbladNoiseSystem::from_canonical_text(&noise)?,
+        )
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinLindbladOpenSystem {
     type Output = FermionLindbladOpenSystem;
 
@@ -361,3 +691,46 @@ impl JordanWignerSpinToFermion for SpinLindbladOpenSystem {
             .expect("Internal bug in jordan_wigner() for SpinHamiltonianSystem or SpinLindbladNoiseSystem. The number of modes in the fermionic system should equal the number of spins in the spin system.")
     }
 }
+
+impl SystemDimension for SpinLindbladOpenSystem {
+    /// Returns the dimension of the Hilbert space of the SpinLindbladOpenSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinLindbladOpenSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_spins(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the SpinLindbladOpenSystem.
+    ///
+    /// The estimate is for the sparse superoperator of the Hamiltonian and the noise.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinLindbladOpenSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.superoperator_dimension(fock_cutoffs)?;
+        // Hamiltonian terms contribute to the commutator twice, Lindblad terms three times
+        let entries_per_row =
+            2 * hermitian_term_count(self.system().keys()) + 3 * self.noise().len();
+        sparse_matrix_memory(dimension, entries_per_row, bytes_per_entry)
+    }
+}
@@ -10,13 +10,24 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::packed_pauli_product::{
+    flat_pauli_arrays_to_single_precision, from_flat_pauli_arrays, multiply_pauli_terms,
+    to_flat_pauli_arrays,
+};
 use super::{ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
 use crate::fermions::FermionOperator;
-use crate::mappings::JordanWignerSpinToFermion;
-use crate::spins::{OperateOnSpins, PauliProduct, SpinHamiltonian, SpinIndex};
+use crate::mappings::{JordanWignerFermionToSpin, JordanWignerSpinToFermion};
+use crate::spins::{
+    FlatPauliArrays, FlatPauliArraysF32, OperateOnSpins, PauliProduct, SpinHamiltonian, SpinIndex,
+    SpinLocality,
+};
 use crate::{
-    CooSparseMatrix, GetValue, OperateOnDensityMatrix, OperateOnState, StruqtureError,
-    StruqtureVersionSerializable, SymmetricIndex, MINIMUM_STRUQTURE_VERSION,
+    canonical_text_document, canonical_text_entries, commutator_residual_terms,
+    expression_from_terms, non_hermitian_terms_error, operator_powi, parse_canonical_text_entries,
+    parse_expression_terms, split_canonical_text, CanonicalText, CoefficientEntry, CooSparseMatrix,
+    GetValue, OperateOnConstant, OperateOnDensityMatrix, OperateOnState, OperatorExpression,
+    RoundingDiagnostics, SpectralNormBound, StruqtureError, StruqtureVersionSerializable,
+    SymmetricIndex, ZeroTermPolicy, MINIMUM_STRUQTURE_VERSION,
 };
 #[cfg(feature = "indexed_map_iterators")]
 use indexmap::map::{Entry, Iter, Keys, Values};
@@ -24,9 +35,11 @@ use indexmap::map::{Entry, Iter, Keys, Values};
 use indexmap::IndexMap;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::hash_map::{Entry, Iter, Keys, Values};
+use std::collections::BTreeSet;
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::HashMap;
 use std::fmt::{self, Write};
@@ -66,6 +79,8 @@ pub struct SpinOperator {
     internal_map: IndexMap<PauliProduct, CalculatorComplex>,
     #[cfg(not(feature = "indexed_map_iterators"))]
     internal_map: HashMap<PauliProduct, CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
 }
 
 impl crate::MinSupportedVersion for SpinOperator {}
@@ -81,7 +96,7 @@ impl schemars::JsonSchema for SpinOperator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
 ///# SpinOperator
@@ -90,23 +105,63 @@ impl schemars::JsonSchema for SpinOperator {
 struct SpinOperatorSerialize {
     /// List of all non-zero entries in the SpinOperator in the form (PauliProduct, real part of weight, imaginary part of weight).
     items: Vec<(PauliProduct, CalculatorFloat, CalculatorFloat)>,
+    /// Policy deciding which terms are dropped when coefficients are set or added.
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
     /// Minimum struqture version required to de-serialize object
     _struqture_version: StruqtureVersionSerializable,
 }
 
+impl Serialize for SpinOperatorSerialize {
+    /// Serialization function for SpinOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "SpinOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
 impl From<SpinOperatorSerialize> for SpinOperator {
     fn from(value: SpinOperatorSerialize) -> Self {
-        let new_noise_op: SpinOperator = value
+        let mut new_noise_op: SpinOperator = value
             .items
             .into_iter()
             .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
             .collect();
+        new_noise_op.zero_term_policy = value.zero_term_policy;
         new_noise_op
     }
 }
 
 impl From<SpinOperator> for SpinOperatorSerialize {
     fn from(value: SpinOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
         let new_noise_op: Vec<(PauliProduct, CalculatorFloat, CalculatorFloat)> = value
             .into_iter()
             .map(|(key, val)| (key, val.re, val.im))
@@ -117,11 +172,21 @@ impl From<SpinOperator> for SpinOperatorSerialize {
         };
         Self {
             items: new_noise_op,
+            zero_term_policy,
             _struqture_version: current_version,
         }
     }
 }
 
+impl crate::SerializeCanonical for SpinOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = SpinOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinOperator {
     type IteratorType = Iter<'a, Self::Index, Self::Value>;
     type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
@@ -166,10 +231,25 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinOperator {
 
     // From trait
     fn empty_clone(&self, capacity: Option<usize>) -> Self {
-        match capacity {
+        let mut empty_clone = match capacity {
             Some(cap) => Self::with_capacity(cap),
             None => Self::new(),
-        }
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
     }
 
     /// Overwrites an existing entry or sets a new entry in the SpinOperator with the given (PauliProduct key, CalculatorComplex value) pair.
@@ -188,7 +268,7 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinOperator {
         key: Self::Index,
         value: Self::Value,
     ) -> Result<Option<Self::Value>, StruqtureError> {
-        if value != CalculatorComplex::ZERO {
+        if !self.zero_term_policy.drops(&value) {
             Ok(self.internal_map.insert(key, value))
         } else {
             match self.internal_map.entry(key) {
@@ -216,6 +296,13 @@ impl<'a> OperateOnState<'a> for SpinOperator {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for SpinOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PauliProduct::new()
+    }
+}
+
 impl<'a> OperateOnSpins<'a> for SpinOperator {
     // From trait
     fn current_number_spins(&self) -> usize {
@@ -293,6 +380,7 @@ impl SpinOperator {
             internal_map: HashMap::new(),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
         }
     }
 
@@ -311,7 +399,91 @@ impl SpinOperator {
             internal_map: HashMap::with_capacity(capacity),
             #[cfg(feature = "indexed_map_iterators")]
             internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Returns a mutable handle to the coefficient of a key, see [CoefficientEntry].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The PauliProduct whose coefficient is accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `CoefficientEntry<PauliProduct, CalculatorComplex>` - The handle to the coefficient, which is zero if the key is not in the SpinOperator.
+    pub fn entry(
+        &mut self,
+        key: PauliProduct,
+    ) -> CoefficientEntry<PauliProduct, CalculatorComplex> {
+        CoefficientEntry::new(
+            self.internal_map.entry(key),
+            CalculatorComplex::ZERO,
+            self.zero_term_policy,
+        )
+    }
+
+    /// Converts the SpinOperator into flat arrays of bitmasks and coefficients, see [FlatPauliArrays].
+    ///
+    /// The terms are sorted by their PauliProduct. The contiguous arrays can be handed to numerical kernels
+    /// without per-term overhead and converted back with [SpinOperator::from_flat_arrays].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FlatPauliArrays)` - The bitmasks of the terms, the real parts and the imaginary parts of the coefficients.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    pub fn to_flat_arrays(&self) -> Result<FlatPauliArrays, StruqtureError> {
+        let mut terms: Vec<(&PauliProduct, Complex64)> = Vec::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            terms.push((
+                product,
+                Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?),
+            ));
         }
+        Ok(to_flat_pauli_arrays(terms.into_iter()))
+    }
+
+    /// Converts the SpinOperator into flat arrays with single precision coefficients, see [FlatPauliArraysF32].
+    ///
+    /// The same as [SpinOperator::to_flat_arrays] with the coefficients rounded to single precision.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((FlatPauliArraysF32, RoundingDiagnostics))` - The flat arrays and the diagnostics of the rounding to single precision.
+    /// * `Err(StruqtureError::CalculatorError)` - A coefficient is symbolic.
+    /// * `Err(StruqtureError::GenericError)` - A coefficient exceeds the range of single precision floats.
+    pub fn to_flat_arrays_f32(
+        &self,
+    ) -> Result<(FlatPauliArraysF32, RoundingDiagnostics), StruqtureError> {
+        flat_pauli_arrays_to_single_precision(self.to_flat_arrays()?)
+    }
+
+    /// Creates a SpinOperator from flat arrays of bitmasks and coefficients, see [FlatPauliArrays].
+    ///
+    /// Terms with the same PauliProduct are summed.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_masks` - The x-bitmasks of all terms, with the same number of words per term.
+    /// * `z_masks` - The z-bitmasks of all terms, with the same number of words per term.
+    /// * `real` - The real parts of the coefficients.
+    /// * `imag` - The imaginary parts of the coefficients.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator with the terms of the arrays.
+    /// * `Err(StruqtureError::GenericError)` - The lengths of the arrays do not fit together.
+    pub fn from_flat_arrays(
+        x_masks: &[u64],
+        z_masks: &[u64],
+        real: &[f64],
+        imag: &[f64],
+    ) -> Result<Self, StruqtureError> {
+        let mut operator = Self::with_capacity(real.len());
+        for (product, value) in from_flat_pauli_arrays(x_masks, z_masks, real, imag)? {
+            operator.add_operator_product(product, CalculatorComplex::new(value.re, value.im))?;
+        }
+        Ok(operator)
     }
 
     /// Separate self into an operator with the terms of given number of spins and an operator with the remaining operations
@@ -338,6 +510,220 @@ impl SpinOperator {
         }
         Ok((separated, remainder))
     }
+
+    /// Returns true if the SpinOperator commutes with a SpinHamiltonian, i.e. if it is a conserved quantity of the SpinHamiltonian.
+    ///
+    /// The commutator [A, H] is evaluated term by term without constructing matrices. Symbolic terms of the commutator
+    /// only vanish when they cancel structurally, numerical terms when they cancel up to floating point precision.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The SpinHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the commutator [A, H] vanishes.
+    pub fn commutes_with(&self, hamiltonian: &SpinHamiltonian) -> bool {
+        self.commutator_residual(hamiltonian)
+            .map(|residual| residual.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Returns the sum of the absolute values of the coefficients of the commutator [A, H] with a SpinHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The SpinHamiltonian H to commute with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The 1-norm of the commutator, 0.0 if the SpinOperator is conserved.
+    /// * `Err(StruqtureError::GenericError)` - Symbolic terms of the commutator do not cancel.
+    pub fn symmetry_violation_norm(
+        &self,
+        hamiltonian: &SpinHamiltonian,
+    ) -> Result<f64, StruqtureError> {
+        Ok(self
+            .commutator_residual(hamiltonian)?
+            .iter()
+            .map(|(_, value)| value.norm())
+            .sum())
+    }
+
+    /// Raises the SpinOperator to a non-negative integer power.
+    ///
+    /// The power is computed by repeated squaring. Terms whose coefficients cancel exactly are removed
+    /// after every multiplication to keep the number of terms manageable.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The power, `powi(0)` is the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinOperator raised to the power n.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn powi(&self, n: usize) -> Self {
+        let mut identity = SpinOperator::new();
+        identity
+            .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+            .expect("Internal bug in add_operator_product");
+        operator_powi(self, n, identity, |left, right| {
+            Ok((left * right).truncate(f64::MIN_POSITIVE))
+        })
+        .expect("Internal bug in operator_powi")
+    }
+
+    /// Returns whether the SpinOperator is hermitian.
+    ///
+    /// Symbolic coefficients are compared structurally.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the SpinOperator equals its hermitian conjugate.
+    pub fn is_hermitian(&self) -> bool {
+        self.iter().all(|(product, value)| {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            *self.get(&conjugated_product) == value.conj() * prefactor
+        })
+    }
+
+    /// Returns the hermitian part (A + A^dagger) / 2 of the SpinOperator A.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian part of the SpinOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn hermitian_part(&self) -> Self {
+        self.hermitian_split_part(1.0)
+    }
+
+    /// Returns the anti-hermitian part (A - A^dagger) / 2 of the SpinOperator A.
+    ///
+    /// The SpinOperator is the sum of its hermitian and anti-hermitian parts.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The anti-hermitian part of the SpinOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    pub fn anti_hermitian_part(&self) -> Self {
+        self.hermitian_split_part(-1.0)
+    }
+
+    /// Returns (A + sign * A^dagger) / 2 for the SpinOperator A.
+    fn hermitian_split_part(&self, sign: f64) -> Self {
+        let mut part = self.empty_clone(Some(2 * self.len()));
+        for (product, value) in self.iter() {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            part.add_operator_product(product.clone(), value.clone() * 0.5)
+                .expect("Internal bug in add_operator_product");
+            part.add_operator_product(conjugated_product, value.conj() * (0.5 * sign * prefactor))
+                .expect("Internal bug in add_operator_product");
+        }
+        part
+    }
+
+    /// Creates the Jordan-Wigner transformed generators of the single and double excitations of a reference determinant.
+    ///
+    /// See [FermionOperator::singles_doubles_excitations] for the generators and the names of their amplitudes.
+    ///
+    /// # Arguments
+    ///
+    /// * `occupied` - The orbitals occupied in the reference determinant.
+    /// * `virtual_orbitals` - The orbitals empty in the reference determinant.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Self)>)` - The names of the amplitudes and the spin generators, singles before doubles.
+    /// * `Err(StruqtureError::GenericError)` - An orbital is given more than once.
+    pub fn singles_doubles_excitations(
+        occupied: &[usize],
+        virtual_orbitals: &[usize],
+    ) -> Result<Vec<(String, Self)>, StruqtureError> {
+        Ok(
+            FermionOperator::singles_doubles_excitations(occupied, virtual_orbitals)?
+                .into_iter()
+                .map(|(amplitude, generator)| (amplitude, generator.jordan_wigner()))
+                .collect(),
+        )
+    }
+
+    /// Converts the SpinOperator into a SpinHamiltonian if it is hermitian.
+    ///
+    /// In contrast to `SpinHamiltonian::try_from`, the error lists all terms violating hermiticity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpinHamiltonian)` - The SpinOperator as a SpinHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianTerms)` - The keys and residuals A - A^dagger of the terms violating hermiticity.
+    pub fn try_into_hamiltonian(&self) -> Result<SpinHamiltonian, StruqtureError> {
+        if !self.is_hermitian() {
+            return Err(non_hermitian_terms_error(self.anti_hermitian_part().iter()));
+        }
+        SpinHamiltonian::try_from(self.clone())
+    }
+
+    /// Computes the partial trace of the SpinOperator over a set of spins.
+    ///
+    /// A PauliProduct P_A P_B acting with P_A on the k traced spins has the partial trace Tr(P_A) P_B,
+    /// where Tr(P_A) = 2^k if P_A is the identity and zero otherwise.
+    /// The remaining spins are relabeled in ascending order starting from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `qubits_to_trace` - The spins to trace out, spins given more than once are traced out once.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The reduced SpinOperator acting on the remaining spins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use qoqo_calculator::CalculatorComplex;
+    /// use struqture::prelude::*;
+    /// use struqture::spins::{PauliProduct, SpinOperator};
+    ///
+    /// let mut operator = SpinOperator::new();
+    /// operator.add_operator_product(PauliProduct::new().z(0).x(2), CalculatorComplex::from(1.0)).unwrap();
+    /// operator.add_operator_product(PauliProduct::new().x(1), CalculatorComplex::from(1.0)).unwrap();
+    /// let reduced = operator.partial_trace(&[1]);
+    /// assert_eq!(reduced.get(&PauliProduct::new().z(0).x(1)), &CalculatorComplex::from(2.0));
+    /// assert_eq!(reduced.len(), 1);
+    /// ```
+    pub fn partial_trace(&self, qubits_to_trace: &[usize]) -> Self {
+        let traced: BTreeSet<usize> = qubits_to_trace.iter().copied().collect();
+        let factor = 2_f64.powi(traced.len() as i32);
+        let mut reduced = self.empty_clone(Some(self.len()));
+        for (product, value) in self.iter() {
+            if let Some(remaining) = product.trace_out(&traced) {
+                reduced
+                    .add_operator_product(remaining, value.clone() * factor)
+                    .expect("Internal bug in add_operator_product");
+            }
+        }
+        reduced
+    }
+
+    /// Returns the terms of the commutator with a SpinHamiltonian that do not cancel.
+    fn commutator_residual(
+        &self,
+        hamiltonian: &SpinHamiltonian,
+    ) -> Result<Vec<(PauliProduct, Complex64)>, StruqtureError> {
+        let hamiltonian = SpinOperator::from(hamiltonian.clone());
+        commutator_residual_terms(self.iter(), hamiltonian.iter(), |left, right| {
+            vec![left.clone() * right.clone()]
+        })
+    }
 }
 
 impl From<SpinHamiltonian> for SpinOperator {
@@ -356,6 +742,9 @@ impl From<SpinHamiltonian> for SpinOperator {
     /// * Internal error in add_operator_product.
     fn from(hamiltonian: SpinHamiltonian) -> Self {
         let mut internal = SpinOperator::new();
+        internal
+            .set_zero_term_policy(hamiltonian.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
         for (key, value) in hamiltonian.into_iter() {
             let bp = PauliProduct::get_key(&key);
             internal
@@ -376,6 +765,7 @@ impl ops::Neg for SpinOperator {
     ///
     /// * `Self` - The SpinOperator * -1.
     fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         #[cfg(not(feature = "indexed_map_iterators"))]
         let mut internal = HashMap::with_capacity(self.len());
         #[cfg(feature = "indexed_map_iterators")]
@@ -385,6 +775,7 @@ impl ops::Neg for SpinOperator {
         }
         SpinOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -466,13 +857,16 @@ where
     ///
     /// * `Self` - The SpinOperator multiplied by the CalculatorComplex/CalculatorFloat.
     fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
         let other_cc = Into::<CalculatorComplex>::into(other);
         let mut internal = self.internal_map.clone();
         for (key, val) in self {
             internal.insert(key, val * other_cc.clone());
         }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
         SpinOperator {
             internal_map: internal,
+            zero_term_policy,
         }
     }
 }
@@ -496,16 +890,12 @@ impl ops::Mul<SpinOperator> for SpinOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, other: SpinOperator) -> Self {
         let mut spin_op = SpinOperator::with_capacity(self.len() * other.len());
-        for (pps, vals) in self {
-            for (ppo, valo) in other.iter() {
-                let (ppp, coefficient) = pps.clone() * ppo.clone();
-                let coefficient =
-                    Into::<CalculatorComplex>::into(valo) * coefficient * vals.clone();
-                spin_op
-                    .add_operator_product(ppp, coefficient)
-                    .expect("Internal bug in add_operator_product");
-            }
-        }
+        spin_op.zero_term_policy = self.zero_term_policy;
+        multiply_pauli_terms(self.iter(), other.iter(), |ppp, coefficient| {
+            spin_op
+                .add_operator_product(ppp, coefficient)
+                .expect("Internal bug in add_operator_product");
+        });
         spin_op
     }
 }
@@ -529,13 +919,13 @@ impl ops::Mul<PauliProduct> for SpinOperator {
     /// * Internal error in add_operator_product.
     fn mul(self, ppo: PauliProduct) -> Self {
         let mut spin_op = SpinOperator::with_capacity(self.len());
-        for (pps, vals) in self {
-            let (ppp, coefficient) = pps.clone() * ppo.clone();
-            let coefficient = CalculatorComplex::from(coefficient) * vals.clone();
+        spin_op.zero_term_policy = self.zero_term_policy;
+        let one = CalculatorComplex::new(1.0, 0.0);
+        multiply_pauli_terms(self.iter(), [(&ppo, &one)], |ppp, coefficient| {
             spin_op
                 .add_operator_product(ppp, coefficient)
                 .expect("Internal bug in add_operator_product");
-        }
+        });
         spin_op
     }
 }
@@ -559,13 +949,13 @@ impl ops::Mul<SpinOperator> for PauliProduct {
     /// * Internal error in add_operator_product.
     fn mul(self, other: SpinOperator) -> SpinOperator {
         let mut spin_op = SpinOperator::with_capacity(other.len());
-        for (ppo, valo) in other.iter() {
-            let (ppp, coefficient) = self.clone() * ppo.clone();
-            let coefficient = valo.clone() * CalculatorComplex::from(coefficient);
+        spin_op.zero_term_policy = other.zero_term_policy;
+        let one = CalculatorComplex::new(1.0, 0.0);
+        multiply_pauli_terms([(&self, &one)], other.iter(), |ppp, coefficient| {
             spin_op
                 .add_operator_product(ppp, coefficient)
                 .expect("Internal bug in add_operator_product");
-        }
+        });
         spin_op
     }
 }
@@ -673,6 +1063,89 @@ impl fmt::Display for SpinOperator {
     }
 }
 
+impl CanonicalText for SpinOperator {
+    /// Returns the canonical text representation of the SpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinOperator.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document("SpinOperator", &[], &canonical_text_entries(self.iter()))
+    }
+
+    /// Creates a SpinOperator from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinOperator.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinOperator.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (_, lines) = split_canonical_text(text, "SpinOperator", &[])?;
+        let mut new_self = SpinOperator::new();
+        for (key, value) in parse_canonical_text_entries::<PauliProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl OperatorExpression for SpinOperator {
+    /// Returns the expression of the SpinOperator, e.g. `0.5*0X1Z - 1e-2*3Y`.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The expression with the terms sorted by their products.
+    fn to_expression(&self) -> String {
+        expression_from_terms(self.iter())
+    }
+
+    /// Creates a SpinOperator from an expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinOperator represented by the expression.
+    /// * `Err(StruqtureError::FromStringFailed)` - The expression is not a valid sum of terms.
+    /// * `Err(StruqtureError)` - A product could not be parsed or a term could not be added to the SpinOperator.
+    fn from_expression(expression: &str) -> Result<Self, StruqtureError> {
+        let mut new_self = SpinOperator::new();
+        for (key, value) in parse_expression_terms::<PauliProduct, CalculatorComplex>(expression)? {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for SpinOperator {
+    /// Returns an upper bound of the spectral norm of the SpinOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The SpinOperator contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.coefficient_1_norm()
+    }
+}
+
+impl SpinLocality for SpinOperator {
+    // From trait
+    fn term_supports(&self) -> Vec<BTreeSet<usize>> {
+        self.keys()
+            .map(|product| product.iter().map(|(index, _)| *index).collect())
+            .collect()
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinOperator {
     type Output = FermionOperator;
 
@@ -0,0 +1,796 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{OperateOnSpins, PauliProduct, SpinHamiltonian, SpinSOperator, SpinSProduct};
+use crate::{
+    CooSparseMatrix, CsrSparseMatrix, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy,
+};
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::{Entry, Iter, Keys, Values};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::IndexMap;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::{Entry, Iter, Keys, Values};
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::HashMap;
+
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+/// SpinSHamiltonians are hermitian combinations of SpinSProducts with specific CalculatorComplex coefficients.
+///
+/// SpinSHamiltonian is the hermitian equivalent of SpinSOperator. Every SpinSProduct that is not naturally
+/// hermitian (not equal to its hermitian conjugate) represents the product and its hermitian conjugate,
+/// c p + c^* p^\dagger. Only one of the two products is stored: the smaller one in the ordering of SpinSProducts,
+/// so for example 0+1- is stored instead of 0-1+. Naturally hermitian products (e.g. 0Z1Z or 0Z0Z) need real coefficients.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{SpinSHamiltonian, SpinSProduct};
+///
+/// let mut sh = SpinSHamiltonian::new();
+///
+/// // Representing the spin-1 hamiltonian $ 1/2 (S_0^{+} S_1^{-} + h.c.) + S_0^z S_1^z + D (S_0^z)^2 $
+/// sh.add_operator_product(SpinSProduct::new().plus(0).minus(1), CalculatorComplex::from(0.5)).unwrap();
+/// sh.add_operator_product(SpinSProduct::new().z(0).z(1), CalculatorComplex::from(1.0)).unwrap();
+/// sh.add_operator_product(SpinSProduct::new().z(0).z(0), CalculatorComplex::from("D")).unwrap();
+///
+/// // The hermitian conjugate is stored in the canonical form
+/// sh.add_operator_product(SpinSProduct::new().minus(0).plus(1), CalculatorComplex::from(0.5)).unwrap();
+/// assert_eq!(sh.get(&SpinSProduct::new().plus(0).minus(1)), &CalculatorComplex::from(1.0));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "SpinSHamiltonianSerialize")]
+#[serde(into = "SpinSHamiltonianSerialize")]
+pub struct SpinSHamiltonian {
+    // The internal HashMap of canonical SpinSProducts and coefficients (CalculatorComplex)
+    #[cfg(feature = "indexed_map_iterators")]
+    internal_map: IndexMap<SpinSProduct, CalculatorComplex>,
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    internal_map: HashMap<SpinSProduct, CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
+}
+
+impl crate::MinSupportedVersion for SpinSHamiltonian {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for SpinSHamiltonian {
+    fn schema_name() -> String {
+        "SpinSHamiltonian".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <SpinSHamiltonianSerialize>::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct SpinSHamiltonianSerialize {
+    items: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
+    _struqture_version: StruqtureVersionSerializable,
+}
+
+impl Serialize for SpinSHamiltonianSerialize {
+    /// Serialization function for SpinSHamiltonianSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinSHamiltonianSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinSHamiltonianSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "SpinSHamiltonianSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
+impl TryFrom<SpinSHamiltonianSerialize> for SpinSHamiltonian {
+    type Error = StruqtureError;
+    fn try_from(value: SpinSHamiltonianSerialize) -> Result<Self, Self::Error> {
+        let mut new_hamiltonian = SpinSHamiltonian::with_capacity(value.items.len());
+        for (key, real, imag) in value.items {
+            new_hamiltonian.add_operator_product(key, CalculatorComplex { re: real, im: imag })?;
+        }
+        new_hamiltonian.zero_term_policy = value.zero_term_policy;
+        Ok(new_hamiltonian)
+    }
+}
+
+impl From<SpinSHamiltonian> for SpinSHamiltonianSerialize {
+    fn from(value: SpinSHamiltonian) -> Self {
+        let zero_term_policy = value.zero_term_policy;
+        let new_hamiltonian: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)> = value
+            .into_iter()
+            .map(|(key, val)| (key, val.re, val.im))
+            .collect();
+        let current_version = StruqtureVersionSerializable {
+            major_version: 1,
+            minor_version: 8,
+        };
+        Self {
+            items: new_hamiltonian,
+            zero_term_policy,
+            _struqture_version: current_version,
+        }
+    }
+}
+
+impl crate::SerializeCanonical for SpinSHamiltonian {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = SpinSHamiltonianSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
+/// Returns the canonical form of a (SpinSProduct, coefficient) pair.
+///
+/// The canonical product is the smaller one of the product and its hermitian conjugate,
+/// the coefficient is conjugated when the product is replaced.
+fn canonical_term(
+    key: SpinSProduct,
+    value: CalculatorComplex,
+) -> (SpinSProduct, CalculatorComplex) {
+    let (conjugated_key, _) = key.hermitian_conjugate();
+    if conjugated_key < key {
+        (conjugated_key, value.conj())
+    } else {
+        (key, value)
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for SpinSHamiltonian {
+    type IteratorType = Iter<'a, Self::Index, Self::Value>;
+    type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
+    type ValueIteratorType = Values<'a, Self::Index, Self::Value>;
+    type Value = CalculatorComplex;
+    type Index = SpinSProduct;
+
+    /// Gets the coefficient of a canonical SpinSProduct key.
+    ///
+    /// The coefficient of the hermitian conjugate of a stored key is the complex conjugate of the
+    /// stored coefficient and is not returned by this function.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The canonical SpinSProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `&CalculatorComplex` - The coefficient of the key, zero if the key is not stored.
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> Self::IteratorType {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> Self::KeyIteratorType {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> Self::ValueIteratorType {
+        self.internal_map.values()
+    }
+
+    #[cfg(feature = "indexed_map_iterators")]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        let (key, _) = canonical_term(key.clone(), CalculatorComplex::ZERO);
+        self.internal_map.shift_remove(&key)
+    }
+
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        let (key, _) = canonical_term(key.clone(), CalculatorComplex::ZERO);
+        self.internal_map.remove(&key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        let mut empty_clone = match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the SpinSHamiltonian with the given (SpinSProduct key, CalculatorComplex value) pair.
+    ///
+    /// A key that is not canonical is replaced by its hermitian conjugate and the value is conjugated.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The SpinSProduct key to set in the SpinSHamiltonian.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian, but its corresponding value is not real.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        let (key, value) = canonical_term(key, value);
+        if !self.zero_term_policy.drops(&value) {
+            if key.is_natural_hermitian() && value.im != CalculatorFloat::ZERO {
+                Err(StruqtureError::NonHermitianOperator)
+            } else {
+                Ok(self.internal_map.insert(key, value))
+            }
+        } else {
+            match self.internal_map.entry(key) {
+                #[cfg(feature = "indexed_map_iterators")]
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                #[cfg(not(feature = "indexed_map_iterators"))]
+                Entry::Occupied(val) => Ok(Some(val.remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+
+    /// Adds a new (SpinSProduct key, CalculatorComplex value) pair to the SpinSHamiltonian.
+    ///
+    /// A key that is not canonical is replaced by its hermitian conjugate and the value is conjugated.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The SpinSProduct key to added to the SpinSHamiltonian.
+    /// * `value` - The corresponding CalculatorComplex value to add for the key in the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The (key, value) pair was successfully added.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian, but its corresponding value is not real.
+    fn add_operator_product(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<(), StruqtureError> {
+        let (key, value) = canonical_term(key, value);
+        let new_val = value + self.get(&key).clone();
+        if key.is_natural_hermitian() && new_val.im != CalculatorFloat::ZERO {
+            Err(StruqtureError::NonHermitianOperator)
+        } else {
+            self.set(key, new_val)?;
+            Ok(())
+        }
+    }
+}
+
+impl<'a> OperateOnState<'a> for SpinSHamiltonian {
+    /// Returns the hermitian conjugate of the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian conjugate of Self.
+    fn hermitian_conjugate(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<'a> OperateOnConstant<'a> for SpinSHamiltonian {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        SpinSProduct::new()
+    }
+}
+
+impl<'a> OperateOnSpins<'a> for SpinSHamiltonian {
+    // From trait
+    fn current_number_spins(&self) -> usize {
+        self.internal_map
+            .keys()
+            .map(|key| key.current_number_spins())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Gets the maximum index of the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins in the SpinSHamiltonian.
+    fn number_spins(&self) -> usize {
+        self.current_number_spins()
+    }
+}
+
+/// Implements the default function (Default trait) of SpinSHamiltonian (an empty SpinSHamiltonian).
+///
+impl Default for SpinSHamiltonian {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the SpinSHamiltonian
+///
+impl SpinSHamiltonian {
+    /// Creates a new SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSHamiltonian.
+    pub fn new() -> Self {
+        SpinSHamiltonian {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::new(),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Creates a new SpinSHamiltonian with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the hamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSHamiltonian.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpinSHamiltonian {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::with_capacity(capacity),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Constructs the sparse matrix representation of the SpinSHamiltonian in COO form, including the hermitian conjugate terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of every spin, e.g. 3 for spin-1.
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix, defaults to the number of spins in the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The values, rows and columns of the matrix representation, sorted by row and column.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The number of spins is smaller than the number of spins in the SpinSHamiltonian.
+    /// * `Err(StruqtureError::GenericError)` - The local dimension is smaller than 2 or the dimension of the matrix overflows.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        SpinSOperator::from(self.clone()).sparse_matrix_coo(local_dimension, number_spins)
+    }
+
+    /// Constructs the sparse matrix representation of the SpinSHamiltonian in CSR form, including the hermitian conjugate terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of every spin, e.g. 3 for spin-1.
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix, defaults to the number of spins in the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The number of spins is smaller than the number of spins in the SpinSHamiltonian.
+    /// * `Err(StruqtureError::GenericError)` - The local dimension is smaller than 2 or the dimension of the matrix overflows.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_csr(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        SpinSOperator::from(self.clone()).sparse_matrix_csr(local_dimension, number_spins)
+    }
+
+    /// Returns the SpinSHamiltonian of spin-1/2 spins as a SpinHamiltonian.
+    ///
+    /// The spin-1/2 operators are expressed by Pauli matrices, $ S^a = \sigma^a / 2 $ and $ S^{\pm} = \sigma^{\pm} $.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinHamiltonian` - The SpinHamiltonian equal to Self for spin-1/2.
+    pub fn to_spin_hamiltonian(&self) -> SpinHamiltonian {
+        let mut new_hamiltonian = SpinHamiltonian::with_capacity(2 * self.len());
+        for (product, val) in self.iter() {
+            // c p + c^* p^\dagger contributes 2 Re(c a) for every PauliProduct P with prefactor a in p
+            let multiplicity = if product.is_natural_hermitian() {
+                1.0
+            } else {
+                2.0
+            };
+            let transscribed_vector: Vec<(PauliProduct, Complex64)> = product.clone().into();
+            for (transscribed_product, prefactor) in transscribed_vector {
+                new_hamiltonian
+                    .add_operator_product(
+                        transscribed_product,
+                        (val.clone() * prefactor).re * multiplicity,
+                    )
+                    .expect("Internal bug in add_operator_product");
+            }
+        }
+        new_hamiltonian.truncate(1e-16)
+    }
+}
+
+impl From<SpinSHamiltonian> for SpinSOperator {
+    /// Converts a SpinSHamiltonian into a SpinSOperator, writing out the hermitian conjugate terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinSHamiltonian to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSHamiltonian converted into a SpinSOperator.
+    fn from(value: SpinSHamiltonian) -> Self {
+        let mut new_operator = SpinSOperator::with_capacity(2 * value.len());
+        new_operator
+            .set_zero_term_policy(value.zero_term_policy())
+            .expect("Internal bug in set_zero_term_policy");
+        for (product, val) in value.into_iter() {
+            if !product.is_natural_hermitian() {
+                let (conjugated_product, _) = product.hermitian_conjugate();
+                new_operator
+                    .add_operator_product(conjugated_product, val.conj())
+                    .expect("Internal bug in add_operator_product");
+            }
+            new_operator
+                .add_operator_product(product, val)
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+}
+
+impl TryFrom<SpinSOperator> for SpinSHamiltonian {
+    type Error = StruqtureError;
+
+    /// Tries to convert a SpinSOperator into a SpinSHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinSOperator to try to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinSOperator converted into a SpinSHamiltonian.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - The coefficients of a product and its hermitian conjugate are not complex conjugates, or a naturally hermitian product has a complex coefficient.
+    fn try_from(value: SpinSOperator) -> Result<Self, Self::Error> {
+        let mut new_hamiltonian = SpinSHamiltonian::with_capacity(value.len());
+        for (product, val) in value.iter() {
+            let (conjugated_product, _) = product.hermitian_conjugate();
+            if product.is_natural_hermitian() {
+                new_hamiltonian.add_operator_product(product.clone(), val.clone())?;
+            } else if value.get(&conjugated_product) != &val.conj() {
+                return Err(StruqtureError::NonHermitianOperator);
+            } else if product < &conjugated_product {
+                new_hamiltonian.add_operator_product(product.clone(), val.clone())?;
+            }
+        }
+        Ok(new_hamiltonian)
+    }
+}
+
+/// Implements the negative sign function of SpinSHamiltonian.
+///
+impl ops::Neg for SpinSHamiltonian {
+    type Output = SpinSHamiltonian;
+    /// Implement minus sign for SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSHamiltonian * -1.
+    fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val.neg());
+        }
+        SpinSHamiltonian {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the plus function of SpinSHamiltonian by SpinSHamiltonian.
+///
+impl<T, V> ops::Add<T> for SpinSHamiltonian
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Result<Self, StruqtureError>;
+    /// Implements `+` (add) for two SpinSHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSHamiltonian to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two SpinSHamiltonians added together.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian, but its corresponding value is not real.
+    fn add(mut self, other: T) -> Self::Output {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))?;
+        }
+        Ok(self)
+    }
+}
+
+/// Implements the minus function of SpinSHamiltonian by SpinSHamiltonian.
+///
+impl<T, V> ops::Sub<T> for SpinSHamiltonian
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Result<Self, StruqtureError>;
+    /// Implements `-` (subtract) for two SpinSHamiltonians.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSHamiltonian to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The two SpinSHamiltonians subtracted.
+    /// * `Err(StruqtureError::NonHermitianOperator)` - Key is naturally hermitian, but its corresponding value is not real.
+    fn sub(mut self, other: T) -> Self::Output {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)?;
+        }
+        Ok(self)
+    }
+}
+
+/// Implements the multiplication function of SpinSHamiltonian by CalculatorFloat.
+///
+impl ops::Mul<CalculatorFloat> for SpinSHamiltonian {
+    type Output = Self;
+    /// Implement `*` for SpinSHamiltonian and CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSHamiltonian multiplied by the CalculatorFloat.
+    fn mul(self, other: CalculatorFloat) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val * other.clone());
+        }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
+        SpinSHamiltonian {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the multiplication function of SpinSHamiltonian by CalculatorComplex.
+///
+impl ops::Mul<CalculatorComplex> for SpinSHamiltonian {
+    type Output = SpinSOperator;
+    /// Implement `*` for SpinSHamiltonian and CalculatorComplex.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinSOperator` - The SpinSHamiltonian multiplied by the CalculatorComplex.
+    fn mul(self, other: CalculatorComplex) -> Self::Output {
+        SpinSOperator::from(self) * other
+    }
+}
+
+/// Implements the multiplication function of SpinSHamiltonian by SpinSHamiltonian.
+///
+impl ops::Mul<SpinSHamiltonian> for SpinSHamiltonian {
+    type Output = SpinSOperator;
+    /// Implement `*` for SpinSHamiltonian and SpinSHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSHamiltonian to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinSOperator` - The two SpinSHamiltonians multiplied.
+    fn mul(self, other: SpinSHamiltonian) -> Self::Output {
+        SpinSOperator::from(self) * SpinSOperator::from(other)
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of SpinSHamiltonian.
+///
+impl IntoIterator for SpinSHamiltonian {
+    type Item = (SpinSProduct, CalculatorComplex);
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    type IntoIter = std::collections::hash_map::IntoIter<SpinSProduct, CalculatorComplex>;
+    #[cfg(feature = "indexed_map_iterators")]
+    type IntoIter = indexmap::map::IntoIter<SpinSProduct, CalculatorComplex>;
+
+    /// Returns the SpinSHamiltonian in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The SpinSHamiltonian in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference SpinSHamiltonian.
+///
+impl<'a> IntoIterator for &'a SpinSHamiltonian {
+    type Item = (&'a SpinSProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, SpinSProduct, CalculatorComplex>;
+
+    /// Returns the reference SpinSHamiltonian in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference SpinSHamiltonian in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of SpinSHamiltonian.
+///
+impl FromIterator<(SpinSProduct, CalculatorComplex)> for SpinSHamiltonian {
+    /// Returns the object in SpinSHamiltonian form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the SpinSHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in SpinSHamiltonian form.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    fn from_iter<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut sh = SpinSHamiltonian::new();
+        for (sp, cc) in iter {
+            sh.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+        sh
+    }
+}
+
+/// Implements the extend function (Extend trait) of SpinSHamiltonian.
+///
+impl Extend<(SpinSProduct, CalculatorComplex)> for SpinSHamiltonian {
+    /// Extends the SpinSHamiltonian by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the SpinSHamiltonian.
+    ///
+    /// # Panics
+    ///
+    /// * Internal bug in add_operator_product.
+    fn extend<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (sp, cc) in iter {
+            self.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of SpinSHamiltonian.
+///
+impl fmt::Display for SpinSHamiltonian {
+    /// Formats the SpinSHamiltonian using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinSHamiltonian.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "SpinSHamiltonian{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
@@ -0,0 +1,765 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{OperateOnSpins, PauliProduct, SpinOperator, SpinSProduct};
+use crate::{
+    CooSparseMatrix, CsrSparseMatrix, OperateOnConstant, OperateOnDensityMatrix, OperateOnState,
+    StruqtureError, StruqtureVersionSerializable, SymmetricIndex, ZeroTermPolicy,
+};
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::map::{Entry, Iter, Keys, Values};
+#[cfg(feature = "indexed_map_iterators")]
+use indexmap::IndexMap;
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::hash_map::{Entry, Iter, Keys, Values};
+#[cfg(not(feature = "indexed_map_iterators"))]
+use std::collections::HashMap;
+
+use std::fmt::{self, Write};
+use std::iter::{FromIterator, IntoIterator};
+use std::ops;
+
+/// SpinSOperators are combinations of SpinSProducts with specific CalculatorComplex coefficients.
+///
+/// This is a representation of sums of products of spin-S operators with weightings, e.g. for spin-1 chains.
+/// The SpinSOperator does not depend on the spin quantum number S, which is only needed when constructing
+/// the sparse matrix representation.
+///
+/// # Example
+///
+/// ```
+/// use struqture::prelude::*;
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::spins::{SpinSOperator, SpinSProduct};
+///
+/// let mut so = SpinSOperator::new();
+///
+/// // Representing the operator $ S_0^{+} S_1^{-} + (S_0^z)^2 $
+/// let sp_0p1m = SpinSProduct::new().plus(0).minus(1);
+/// let sp_0z0z = SpinSProduct::new().z(0).z(0);
+/// so.add_operator_product(sp_0p1m.clone(), CalculatorComplex::from(1.0)).unwrap();
+/// so.add_operator_product(sp_0z0z.clone(), CalculatorComplex::from(1.0)).unwrap();
+///
+/// // Access what you set:
+/// assert_eq!(so.get(&sp_0p1m), &CalculatorComplex::from(1.0));
+///
+/// // Sparse matrix of two spin-1 spins (2S+1 = 3 states per spin)
+/// let (values, (rows, columns)) = so.sparse_matrix_coo(3, None).unwrap();
+/// assert_eq!(values.len(), rows.len());
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SpinSOperatorSerialize")]
+#[serde(into = "SpinSOperatorSerialize")]
+pub struct SpinSOperator {
+    // The internal HashMap of SpinSProducts and coefficients (CalculatorComplex)
+    #[cfg(feature = "indexed_map_iterators")]
+    internal_map: IndexMap<SpinSProduct, CalculatorComplex>,
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    internal_map: HashMap<SpinSProduct, CalculatorComplex>,
+    // The policy deciding which terms are dropped when coefficients are set or added
+    zero_term_policy: ZeroTermPolicy,
+}
+
+impl crate::MinSupportedVersion for SpinSOperator {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for SpinSOperator {
+    fn schema_name() -> String {
+        "SpinSOperator".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <SpinSOperatorSerialize>::json_schema(gen)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "json_schema", schemars(deny_unknown_fields))]
+struct SpinSOperatorSerialize {
+    items: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)>,
+    #[serde(default)]
+    zero_term_policy: ZeroTermPolicy,
+    _struqture_version: StruqtureVersionSerializable,
+}
+
+impl Serialize for SpinSOperatorSerialize {
+    /// Serialization function for SpinSOperatorSerialize, leaving out the default zero-term policy in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinSOperatorSerialize to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinSOperatorSerialize.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let skip_zero_term_policy =
+            serializer.is_human_readable() && self.zero_term_policy == ZeroTermPolicy::default();
+        let mut state = serializer.serialize_struct(
+            "SpinSOperatorSerialize",
+            if skip_zero_term_policy { 2 } else { 3 },
+        )?;
+        state.serialize_field("items", &self.items)?;
+        if skip_zero_term_policy {
+            state.skip_field("zero_term_policy")?;
+        } else {
+            state.serialize_field("zero_term_policy", &self.zero_term_policy)?;
+        }
+        state.serialize_field("_struqture_version", &self._struqture_version)?;
+        state.end()
+    }
+}
+
+impl From<SpinSOperatorSerialize> for SpinSOperator {
+    fn from(value: SpinSOperatorSerialize) -> Self {
+        let mut new_operator: SpinSOperator = value
+            .items
+            .into_iter()
+            .map(|(key, real, imag)| (key, CalculatorComplex { re: real, im: imag }))
+            .collect();
+        new_operator.zero_term_policy = value.zero_term_policy;
+        new_operator
+    }
+}
+
+impl From<SpinSOperator> for SpinSOperatorSerialize {
+    fn from(value: SpinSOperator) -> Self {
+        let zero_term_policy = value.zero_term_policy;
+        let new_operator: Vec<(SpinSProduct, CalculatorFloat, CalculatorFloat)> = value
+            .into_iter()
+            .map(|(key, val)| (key, val.re, val.im))
+            .collect();
+        let current_version = StruqtureVersionSerializable {
+            major_version: 1,
+            minor_version: 8,
+        };
+        Self {
+            items: new_operator,
+            zero_term_policy,
+            _struqture_version: current_version,
+        }
+    }
+}
+
+impl crate::SerializeCanonical for SpinSOperator {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut serializable = SpinSOperatorSerialize::from(self.clone());
+        serializable.items.sort_by(|a, b| a.0.cmp(&b.0));
+        serializable.serialize(serializer)
+    }
+}
+
+impl<'a> OperateOnDensityMatrix<'a> for SpinSOperator {
+    type IteratorType = Iter<'a, Self::Index, Self::Value>;
+    type KeyIteratorType = Keys<'a, Self::Index, Self::Value>;
+    type ValueIteratorType = Values<'a, Self::Index, Self::Value>;
+    type Value = CalculatorComplex;
+    type Index = SpinSProduct;
+
+    // From trait
+    fn get(&self, key: &Self::Index) -> &Self::Value {
+        match self.internal_map.get(key) {
+            Some(value) => value,
+            None => &CalculatorComplex::ZERO,
+        }
+    }
+
+    // From trait
+    fn iter(&'a self) -> Self::IteratorType {
+        self.internal_map.iter()
+    }
+
+    // From trait
+    fn keys(&'a self) -> Self::KeyIteratorType {
+        self.internal_map.keys()
+    }
+
+    // From trait
+    fn values(&'a self) -> Self::ValueIteratorType {
+        self.internal_map.values()
+    }
+
+    #[cfg(feature = "indexed_map_iterators")]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.shift_remove(key)
+    }
+
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    // From trait
+    fn remove(&mut self, key: &Self::Index) -> Option<Self::Value> {
+        self.internal_map.remove(key)
+    }
+
+    // From trait
+    fn empty_clone(&self, capacity: Option<usize>) -> Self {
+        let mut empty_clone = match capacity {
+            Some(cap) => Self::with_capacity(cap),
+            None => Self::new(),
+        };
+        empty_clone.zero_term_policy = self.zero_term_policy;
+        empty_clone
+    }
+
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.zero_term_policy
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        policy.validate()?;
+        self.internal_map.retain(|_, value| !policy.drops(value));
+        self.zero_term_policy = policy;
+        Ok(())
+    }
+
+    /// Overwrites an existing entry or sets a new entry in the SpinSOperator with the given (SpinSProduct key, CalculatorComplex value) pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The SpinSProduct key to set in the SpinSOperator.
+    /// * `value` - The corresponding CalculatorComplex value to set for the key in the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(CalculatorComplex))` - The key existed, this is the value it had before it was set with the value input.
+    /// * `Ok(None)` - The key did not exist, it has been set with its corresponding value.
+    fn set(
+        &mut self,
+        key: Self::Index,
+        value: Self::Value,
+    ) -> Result<Option<Self::Value>, StruqtureError> {
+        if !self.zero_term_policy.drops(&value) {
+            Ok(self.internal_map.insert(key, value))
+        } else {
+            match self.internal_map.entry(key) {
+                #[cfg(feature = "indexed_map_iterators")]
+                Entry::Occupied(val) => Ok(Some(val.shift_remove())),
+                #[cfg(not(feature = "indexed_map_iterators"))]
+                Entry::Occupied(val) => Ok(Some(val.remove())),
+                Entry::Vacant(_) => Ok(None),
+            }
+        }
+    }
+}
+
+impl<'a> OperateOnState<'a> for SpinSOperator {
+    // From trait
+    fn hermitian_conjugate(&self) -> Self {
+        let mut new_operator = Self::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let (conjugated_product, prefactor) = product.hermitian_conjugate();
+            new_operator
+                .add_operator_product(conjugated_product, value.conj() * prefactor)
+                .expect("Internal bug in add_operator_product");
+        }
+        new_operator
+    }
+}
+
+impl<'a> OperateOnConstant<'a> for SpinSOperator {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        SpinSProduct::new()
+    }
+}
+
+impl<'a> OperateOnSpins<'a> for SpinSOperator {
+    // From trait
+    fn current_number_spins(&self) -> usize {
+        self.internal_map
+            .keys()
+            .map(|key| key.current_number_spins())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Gets the maximum index of the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of spins in the SpinSOperator.
+    fn number_spins(&self) -> usize {
+        self.current_number_spins()
+    }
+}
+
+/// Implements the default function (Default trait) of SpinSOperator (an empty SpinSOperator).
+///
+impl Default for SpinSOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Functions for the SpinSOperator
+///
+impl SpinSOperator {
+    /// Creates a new SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSOperator.
+    pub fn new() -> Self {
+        SpinSOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::new(),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::new(),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Creates a new SpinSOperator with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The pre-allocated capacity of the operator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSOperator.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpinSOperator {
+            #[cfg(not(feature = "indexed_map_iterators"))]
+            internal_map: HashMap::with_capacity(capacity),
+            #[cfg(feature = "indexed_map_iterators")]
+            internal_map: IndexMap::with_capacity(capacity),
+            zero_term_policy: ZeroTermPolicy::default(),
+        }
+    }
+
+    /// Constructs the sparse matrix representation of the SpinSOperator in COO form.
+    ///
+    /// The basis state index has the state of spin j as its j-th digit in base 2S+1 (see [SpinSProduct::apply_to_basis_state]).
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of every spin, e.g. 3 for spin-1.
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix, defaults to the number of spins in the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, (Vec<usize>, Vec<usize>)))` - The values, rows and columns of the matrix representation, sorted by row and column.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The number of spins is smaller than the number of spins in the SpinSOperator.
+    /// * `Err(StruqtureError::GenericError)` - The local dimension is smaller than 2 or the dimension of the matrix overflows.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_coo(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let entries = self.sparse_matrix_entries(local_dimension, number_spins)?;
+        let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+        let mut rows: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+        for ((row, column), value) in entries.into_iter() {
+            rows.push(row);
+            columns.push(column);
+            values.push(value);
+        }
+        Ok((values, (rows, columns)))
+    }
+
+    /// Constructs the sparse matrix representation of the SpinSOperator in CSR form.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of every spin, e.g. 3 for spin-1.
+    /// * `number_spins` - The number of spins for which to construct the sparse matrix, defaults to the number of spins in the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Vec<Complex64>, Vec<usize>, Vec<usize>))` - The values, column indices and row pointers of the matrix representation.
+    /// * `Err(StruqtureError::NumberSpinsExceeded)` - The number of spins is smaller than the number of spins in the SpinSOperator.
+    /// * `Err(StruqtureError::GenericError)` - The local dimension is smaller than 2 or the dimension of the matrix overflows.
+    /// * `Err(CalculatorError)` - CalculatorFloat could not be converted to f64.
+    pub fn sparse_matrix_csr(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<CsrSparseMatrix, StruqtureError> {
+        let dimension = self.matrix_dimension(local_dimension, number_spins)?;
+        let entries = self.sparse_matrix_entries(local_dimension, number_spins)?;
+        let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+        let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut row_pointers: Vec<usize> = Vec::with_capacity(dimension + 1);
+        row_pointers.push(0);
+        let mut entries = entries.into_iter().peekable();
+        for row in 0..dimension {
+            while let Some(((_, column), value)) =
+                entries.next_if(|((entry_row, _), _)| *entry_row == row)
+            {
+                columns.push(column);
+                values.push(value);
+            }
+            row_pointers.push(values.len());
+        }
+        Ok((values, columns, row_pointers))
+    }
+
+    /// Returns the SpinSOperator of spin-1/2 spins as a SpinOperator.
+    ///
+    /// The spin-1/2 operators are expressed by Pauli matrices, $ S^a = \sigma^a / 2 $ and $ S^{\pm} = \sigma^{\pm} $.
+    ///
+    /// # Returns
+    ///
+    /// * `SpinOperator` - The SpinOperator equal to Self for spin-1/2.
+    pub fn to_spin_operator(&self) -> SpinOperator {
+        let mut new_operator = SpinOperator::with_capacity(self.len());
+        for (product, val) in self.iter() {
+            let transscribed_vector: Vec<(PauliProduct, Complex64)> = product.clone().into();
+            for (transscribed_product, prefactor) in transscribed_vector {
+                new_operator
+                    .add_operator_product(transscribed_product, val.clone() * prefactor)
+                    .expect("Unexpected error adding operators. Internal struqture error");
+            }
+        }
+        new_operator.truncate(1e-16)
+    }
+
+    /// Returns the dimension of the matrix representation of Self.
+    fn matrix_dimension(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<usize, StruqtureError> {
+        if local_dimension < 2 {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Spins need at least 2 states, local dimension {} is not valid",
+                    local_dimension
+                ),
+            });
+        }
+        let number_spins = match number_spins {
+            Some(number_spins) if number_spins < self.current_number_spins() => {
+                return Err(StruqtureError::NumberSpinsExceeded)
+            }
+            Some(number_spins) => number_spins,
+            None => self.current_number_spins(),
+        };
+        local_dimension
+            .checked_pow(number_spins as u32)
+            .ok_or_else(|| StruqtureError::GenericError {
+                msg: format!(
+                    "Matrix dimension {}^{} exceeds the range of usize",
+                    local_dimension, number_spins
+                ),
+            })
+    }
+
+    /// Returns the non-zero entries of the matrix representation of Self, sorted by row and column.
+    fn sparse_matrix_entries(
+        &self,
+        local_dimension: usize,
+        number_spins: Option<usize>,
+    ) -> Result<BTreeMap<(usize, usize), Complex64>, StruqtureError> {
+        let dimension = self.matrix_dimension(local_dimension, number_spins)?;
+        let mut terms: Vec<(&SpinSProduct, Complex64)> = Vec::with_capacity(self.len());
+        for (product, value) in self.iter() {
+            let real_value: f64 = value.re.clone().try_into()?;
+            let imag_value: f64 = value.im.clone().try_into()?;
+            terms.push((product, Complex64::new(real_value, imag_value)));
+        }
+        let mut entries: BTreeMap<(usize, usize), Complex64> = BTreeMap::new();
+        for column in 0..dimension {
+            for (product, value) in terms.iter() {
+                for (row, amplitude) in product.apply_to_basis_state(local_dimension, column) {
+                    *entries
+                        .entry((row, column))
+                        .or_insert_with(|| Complex64::new(0.0, 0.0)) += amplitude * value;
+                }
+            }
+        }
+        entries.retain(|_, value| value.norm() != 0.0);
+        Ok(entries)
+    }
+}
+
+/// Implements the negative sign function of SpinSOperator.
+///
+impl ops::Neg for SpinSOperator {
+    type Output = SpinSOperator;
+    /// Implement minus sign for SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSOperator * -1.
+    fn neg(self) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val.neg());
+        }
+        SpinSOperator {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the plus function of SpinSOperator by SpinSOperator.
+///
+impl<T, V> ops::Add<T> for SpinSOperator
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `+` (add) for two SpinSOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSOperator to be added.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two SpinSOperators added together.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn add(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value))
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the minus function of SpinSOperator by SpinSOperator.
+///
+impl<T, V> ops::Sub<T> for SpinSOperator
+where
+    T: IntoIterator<Item = (SpinSProduct, V)>,
+    V: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implements `-` (subtract) for two SpinSOperators.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSOperator to be subtracted.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two SpinSOperators subtracted.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn sub(mut self, other: T) -> Self {
+        for (key, value) in other.into_iter() {
+            self.add_operator_product(key, Into::<CalculatorComplex>::into(value) * -1.0)
+                .expect("Internal bug in add_operator_product");
+        }
+        self
+    }
+}
+
+/// Implements the multiplication function of SpinSOperator by CalculatorComplex/CalculatorFloat.
+///
+impl<T> ops::Mul<T> for SpinSOperator
+where
+    T: Into<CalculatorComplex>,
+{
+    type Output = Self;
+    /// Implement `*` for SpinSOperator and CalculatorComplex/CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The CalculatorComplex or CalculatorFloat by which to multiply.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSOperator multiplied by the CalculatorComplex/CalculatorFloat.
+    fn mul(self, other: T) -> Self {
+        let zero_term_policy = self.zero_term_policy;
+        let other_cc = Into::<CalculatorComplex>::into(other);
+        #[cfg(not(feature = "indexed_map_iterators"))]
+        let mut internal = HashMap::with_capacity(self.len());
+        #[cfg(feature = "indexed_map_iterators")]
+        let mut internal = IndexMap::with_capacity(self.len());
+        for (key, val) in self {
+            internal.insert(key, val * other_cc.clone());
+        }
+        internal.retain(|_, value| !zero_term_policy.drops(value));
+        SpinSOperator {
+            internal_map: internal,
+            zero_term_policy,
+        }
+    }
+}
+
+/// Implements the multiplication function of SpinSOperator by SpinSOperator.
+///
+impl ops::Mul<SpinSOperator> for SpinSOperator {
+    type Output = Self;
+    /// Implement `*` for SpinSOperator and SpinSOperator.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The SpinSOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The two SpinSOperators multiplied.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn mul(self, other: SpinSOperator) -> Self {
+        let mut spin_s_op = SpinSOperator::with_capacity(self.len() * other.len());
+        spin_s_op.zero_term_policy = self.zero_term_policy;
+        for (sps, vals) in self {
+            for (spo, valo) in other.iter() {
+                spin_s_op
+                    .add_operator_product(
+                        SpinSProduct::multiply(sps.clone(), spo.clone()),
+                        vals.clone() * valo.clone(),
+                    )
+                    .expect("Internal bug in add_operator_product");
+            }
+        }
+        spin_s_op
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of SpinSOperator.
+///
+impl IntoIterator for SpinSOperator {
+    type Item = (SpinSProduct, CalculatorComplex);
+    #[cfg(not(feature = "indexed_map_iterators"))]
+    type IntoIter = std::collections::hash_map::IntoIter<SpinSProduct, CalculatorComplex>;
+    #[cfg(feature = "indexed_map_iterators")]
+    type IntoIter = indexmap::map::IntoIter<SpinSProduct, CalculatorComplex>;
+
+    /// Returns the SpinSOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The SpinSOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.into_iter()
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of reference SpinSOperator.
+///
+impl<'a> IntoIterator for &'a SpinSOperator {
+    type Item = (&'a SpinSProduct, &'a CalculatorComplex);
+    type IntoIter = Iter<'a, SpinSProduct, CalculatorComplex>;
+
+    /// Returns the reference SpinSOperator in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The reference SpinSOperator in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_map.iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of SpinSOperator.
+///
+impl FromIterator<(SpinSProduct, CalculatorComplex)> for SpinSOperator {
+    /// Returns the object in SpinSOperator form, from an Iterator form of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the information from which to create the SpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in SpinSOperator form.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn from_iter<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(iter: I) -> Self {
+        let mut so = SpinSOperator::new();
+        for (sp, cc) in iter {
+            so.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+        so
+    }
+}
+
+/// Implements the extend function (Extend trait) of SpinSOperator.
+///
+impl Extend<(SpinSProduct, CalculatorComplex)> for SpinSOperator {
+    /// Extends the SpinSOperator by the specified operations (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operations by which to extend the SpinSOperator.
+    ///
+    /// # Panics
+    ///
+    /// * Internal error in add_operator_product.
+    fn extend<I: IntoIterator<Item = (SpinSProduct, CalculatorComplex)>>(&mut self, iter: I) {
+        for (sp, cc) in iter {
+            self.add_operator_product(sp, cc)
+                .expect("Internal bug in add_operator_product");
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of SpinSOperator.
+///
+impl fmt::Display for SpinSOperator {
+    /// Formats the SpinSOperator using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinSOperator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = "SpinSOperator{\n".to_string();
+        for (key, val) in self.iter() {
+            writeln!(output, "{}: {},", key, val)?;
+        }
+        output.push('}');
+
+        write!(f, "{}", output)
+    }
+}
@@ -0,0 +1,841 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PauliProduct, SingleSpinOperator};
+use crate::{SpinIndex, StruqtureError, SymmetricIndex};
+use num_complex::Complex64;
+use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{FromIterator, IntoIterator};
+use std::str::FromStr;
+use tinyvec::{TinyVec, TinyVecIterator};
+
+/// Single spin operators for SpinSProducts, the generators of the spin-S representation.
+///
+/// I: identity matrix
+///
+/// X, Y, Z: the spin operators $ S^x $, $ S^y $ and $ S^z $
+///
+/// Plus, Minus: the ladder operators $ S^{+} = S^x + i S^y $ and $ S^{-} = S^x - i S^y $
+///
+/// The operators are not normalized like the Pauli matrices, for spin-1/2 $ S^z = \sigma^z / 2 $.
+/// The basis state $ |k\rangle $ of a spin with 2S+1 states has the magnetic quantum number $ m = S - k $,
+/// so for spin-1/2 the state $ |0\rangle $ is the spin-up state as for the Pauli matrices.
+///
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SingleSpinSOperator {
+    Identity,
+    X,
+    Y,
+    Z,
+    Plus,
+    Minus,
+}
+
+/// Creates a SingleSpinSOperator from an &str representation.
+///
+/// # Arguments
+///
+/// * `s` - The string (&str) to be converted to a SingleSpinSOperator.
+///
+/// # Returns
+///
+/// * `Ok(Self)` - The SingleSpinSOperator of the input string.
+/// * `Err(StruqtureError::IncorrectPauliEntry)` - The operator being set is not in [\"I\", \"X\", \"Y\", \"Z\", \"+\", \"-\"].
+///
+impl FromStr for SingleSpinSOperator {
+    type Err = StruqtureError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "I" => Ok(SingleSpinSOperator::Identity),
+            "X" => Ok(SingleSpinSOperator::X),
+            "Y" => Ok(SingleSpinSOperator::Y),
+            "Z" => Ok(SingleSpinSOperator::Z),
+            "+" => Ok(SingleSpinSOperator::Plus),
+            "-" => Ok(SingleSpinSOperator::Minus),
+            _ => Err(StruqtureError::IncorrectPauliEntry {
+                pauli: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Implements the default function (Default trait) of SingleSpinSOperator (an Identity SingleSpinSOperator).
+///
+impl Default for SingleSpinSOperator {
+    fn default() -> Self {
+        SingleSpinSOperator::Identity
+    }
+}
+
+/// Implements the fmt function (Display trait) of SingleSpinSOperator.
+///
+impl fmt::Display for SingleSpinSOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SingleSpinSOperator::Identity => write!(f, "I"),
+            SingleSpinSOperator::X => write!(f, "X"),
+            SingleSpinSOperator::Y => write!(f, "Y"),
+            SingleSpinSOperator::Z => write!(f, "Z"),
+            SingleSpinSOperator::Plus => write!(f, "+"),
+            SingleSpinSOperator::Minus => write!(f, "-"),
+        }
+    }
+}
+
+/// Functions for the SingleSpinSOperator
+///
+impl SingleSpinSOperator {
+    /// Returns the hermitian conjugate of the SingleSpinSOperator.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The hermitian conjugate, exchanging Plus and Minus.
+    pub fn hermitian_conjugate(&self) -> Self {
+        match self {
+            SingleSpinSOperator::Plus => SingleSpinSOperator::Minus,
+            SingleSpinSOperator::Minus => SingleSpinSOperator::Plus,
+            x => *x,
+        }
+    }
+
+    /// Applies the SingleSpinSOperator to a basis state of a single spin.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of the spin.
+    /// * `state` - The basis state $ |k\rangle $ with $ k < 2S+1 $ the operator is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(usize, Complex64)>` - The basis states and amplitudes of the image, empty if the state is annihilated.
+    pub fn apply_to_basis_state(
+        &self,
+        local_dimension: usize,
+        state: usize,
+    ) -> Vec<(usize, Complex64)> {
+        let dimension = local_dimension as f64;
+        let k = state as f64;
+        // S^+ |k> = sqrt(k (2S + 1 - k)) |k - 1>, S^- |k> = sqrt((2S - k) (k + 1)) |k + 1>
+        let raised = (state > 0).then(|| (state - 1, (k * (dimension - k)).sqrt()));
+        let lowered = (state + 1 < local_dimension)
+            .then(|| (state + 1, ((dimension - 1.0 - k) * (k + 1.0)).sqrt()));
+        match self {
+            SingleSpinSOperator::Identity => vec![(state, Complex64::new(1.0, 0.0))],
+            SingleSpinSOperator::Z => {
+                let magnetic_number = (dimension - 1.0) / 2.0 - k;
+                if magnetic_number == 0.0 {
+                    vec![]
+                } else {
+                    vec![(state, Complex64::new(magnetic_number, 0.0))]
+                }
+            }
+            SingleSpinSOperator::Plus => raised
+                .map(|(new_state, amplitude)| (new_state, Complex64::new(amplitude, 0.0)))
+                .into_iter()
+                .collect(),
+            SingleSpinSOperator::Minus => lowered
+                .map(|(new_state, amplitude)| (new_state, Complex64::new(amplitude, 0.0)))
+                .into_iter()
+                .collect(),
+            // S^x = (S^+ + S^-) / 2
+            SingleSpinSOperator::X => raised
+                .map(|(new_state, amplitude)| (new_state, Complex64::new(amplitude / 2.0, 0.0)))
+                .into_iter()
+                .chain(lowered.map(|(new_state, amplitude)| {
+                    (new_state, Complex64::new(amplitude / 2.0, 0.0))
+                }))
+                .collect(),
+            // S^y = -i/2 S^+ + i/2 S^-
+            SingleSpinSOperator::Y => raised
+                .map(|(new_state, amplitude)| (new_state, Complex64::new(0.0, -amplitude / 2.0)))
+                .into_iter()
+                .chain(lowered.map(|(new_state, amplitude)| {
+                    (new_state, Complex64::new(0.0, amplitude / 2.0))
+                }))
+                .collect(),
+        }
+    }
+}
+
+impl From<SingleSpinSOperator> for Vec<(SingleSpinOperator, Complex64)> {
+    /// Converts a spin-1/2 SingleSpinSOperator into a vector of tuples of (SingleSpinOperator, Complex64).
+    ///
+    /// # Arguments
+    ///
+    /// * `val` - The SingleSpinSOperator to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SingleSpinSOperator of a spin-1/2 converted into a vector of tuples of (SingleSpinOperator, Complex64).
+    fn from(val: SingleSpinSOperator) -> Self {
+        match val {
+            SingleSpinSOperator::Identity => {
+                vec![(SingleSpinOperator::Identity, Complex64::new(1.0, 0.0))]
+            }
+            SingleSpinSOperator::X => vec![(SingleSpinOperator::X, Complex64::new(0.5, 0.0))],
+            SingleSpinSOperator::Y => vec![(SingleSpinOperator::Y, Complex64::new(0.5, 0.0))],
+            SingleSpinSOperator::Z => vec![(SingleSpinOperator::Z, Complex64::new(0.5, 0.0))],
+            SingleSpinSOperator::Plus => vec![
+                (SingleSpinOperator::X, Complex64::new(0.5, 0.0)),
+                (SingleSpinOperator::Y, Complex64::new(0.0, 0.5)),
+            ],
+            SingleSpinSOperator::Minus => vec![
+                (SingleSpinOperator::X, Complex64::new(0.5, 0.0)),
+                (SingleSpinOperator::Y, Complex64::new(0.0, -0.5)),
+            ],
+        }
+    }
+}
+
+/// SpinSProducts are products of SingleSpinSOperators on spins with an arbitrary spin quantum number S.
+///
+/// For spin-S the products of generators on the same spin are not reduced (e.g. $ (S^z)^2 $ is not a
+/// combination of the generators for S > 1/2), so several operators can act on the same spin.
+/// The operators on the same spin are kept in the order of multiplication, operators on different spins commute.
+/// For instance, to represent the single-ion anisotropy term $ (S_0^z)^2 $ and the exchange term $ S_0^{+} S_1^{-} $:
+/// ` SpinSProduct::new().z(0).z(0) ` and ` SpinSProduct::new().plus(0).minus(1) `
+///
+/// The products do not depend on S, the spin quantum number is only needed to construct matrices.
+///
+/// # Example
+///
+/// ```
+/// use struqture::spins::{SingleSpinSOperator, SpinSProduct};
+/// use std::str::FromStr;
+///
+/// let sp = SpinSProduct::new().z(0).z(0).plus(1);
+/// assert_eq!(sp.operators_on_spin(0), vec![SingleSpinSOperator::Z, SingleSpinSOperator::Z]);
+/// assert_eq!(sp.to_string(), "0Z0Z1+");
+/// assert_eq!(SpinSProduct::from_str("0Z0Z1+").unwrap(), sp);
+/// ```
+///
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SpinSProduct {
+    /// The internal list of spin indices and operators, sorted by spin and in order of multiplication on the same spin
+    items: TinyVec<[(usize, SingleSpinSOperator); 5]>,
+}
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for SpinSProduct {
+    fn schema_name() -> String {
+        "struqture::spins::SpinSProduct".to_string()
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let tmp_schema = gen.subschema_for::<String>();
+        let mut obj = tmp_schema.into_object();
+        let meta = obj.metadata();
+        meta.description = Some("Represents products of spin-S operators (X, Y, Z, +, -) by a string of spin numbers followed by operators, several operators can act on the same spin. E.g. 0Z0Z1+2-.".to_string());
+
+        schemars::schema::Schema::Object(obj)
+    }
+}
+
+impl crate::MinSupportedVersion for SpinSProduct {
+    fn min_supported_version() -> (usize, usize, usize) {
+        (1, 8, 0)
+    }
+}
+
+impl crate::SerializeCanonical for SpinSProduct {}
+
+/// Implementing serde serialization writing directly to string.
+///
+impl Serialize for SpinSProduct {
+    /// Serialization function for SpinSProduct according to string type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinSProduct to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinSProduct.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let readable = serializer.is_human_readable();
+        if readable {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut sequence = serializer.serialize_seq(Some(self.items.len()))?;
+            for item in self.items.iter() {
+                sequence.serialize_element(item)?;
+            }
+            sequence.end()
+        }
+    }
+}
+
+/// Deserializing directly from string.
+///
+impl<'de> Deserialize<'de> for SpinSProduct {
+    /// Deserialization function for SpinSProduct.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - Serialized instance of SpinSProduct to be deserialized.
+    /// * `deserializer` - Deserializer used for deserialization.
+    ///
+    /// # Returns
+    ///
+    /// `SpinSProduct` - Deserialized instance of SpinSProduct.
+    /// `D::Error` - Error in the deserialization process.
+    fn deserialize<D>(deserializer: D) -> Result<SpinSProduct, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+        if human_readable {
+            struct TemporaryVisitor;
+            impl<'de> Visitor<'de> for TemporaryVisitor {
+                type Value = SpinSProduct;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("String")
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    SpinSProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+
+                fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+                where
+                    E: Error,
+                {
+                    SpinSProduct::from_str(v).map_err(|err| E::custom(format!("{:?}", err)))
+                }
+            }
+
+            deserializer.deserialize_str(TemporaryVisitor)
+        } else {
+            struct SpinSProductVisitor;
+            impl<'de> serde::de::Visitor<'de> for SpinSProductVisitor {
+                type Value = SpinSProduct;
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Formatter::write_str(formatter, "Identifier of SpinSProduct variant")
+                }
+                // when variants are marked by String values
+                fn visit_seq<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+                where
+                    M: SeqAccess<'de>,
+                {
+                    let mut sp = SpinSProduct::new();
+                    while let Some(item) = access.next_element()? {
+                        let entry: Entry = item;
+                        sp = sp.multiply_operator(entry.0 .0, entry.0 .1);
+                    }
+                    Ok(sp)
+                }
+            }
+            #[derive(Deserialize)]
+            #[serde(transparent)]
+            struct Entry((usize, SingleSpinSOperator));
+            let sp_visitor = SpinSProductVisitor;
+
+            deserializer.deserialize_seq(sp_visitor)
+        }
+    }
+}
+
+/// Functions for the SpinSProduct
+///
+impl SpinSProduct {
+    /// Creates a new (empty) SpinSProduct, representing the identity.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSProduct.
+    pub fn new() -> Self {
+        SpinSProduct {
+            items: TinyVec::<[(usize, SingleSpinSOperator); 5]>::with_capacity(5),
+        }
+    }
+
+    /// Creates a new SpinSProduct with pre-allocated capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The capacity of the SpinSProduct to create.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) SpinSProduct.
+    pub fn with_capacity(capacity: usize) -> Self {
+        SpinSProduct {
+            items: TinyVec::<[(usize, SingleSpinSOperator); 5]>::with_capacity(capacity),
+        }
+    }
+
+    /// Multiplies Self from the right by a single spin operator. This function consumes Self.
+    ///
+    /// The operator is applied before the operators already acting on the same spin.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin the operator acts on.
+    /// * `operator` - The SingleSpinSOperator to multiply by.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn multiply_operator(self, index: usize, operator: SingleSpinSOperator) -> Self {
+        let mut sp = self;
+        if operator != SingleSpinSOperator::Identity {
+            let position = sp
+                .items
+                .iter()
+                .position(|(spin, _)| *spin > index)
+                .unwrap_or(sp.items.len());
+            sp.items.insert(position, (index, operator));
+        }
+        sp
+    }
+
+    /// Multiplies Self from the right by $ S^x $ on a spin. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn x(self, index: usize) -> Self {
+        self.multiply_operator(index, SingleSpinSOperator::X)
+    }
+
+    /// Multiplies Self from the right by $ S^y $ on a spin. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn y(self, index: usize) -> Self {
+        self.multiply_operator(index, SingleSpinSOperator::Y)
+    }
+
+    /// Multiplies Self from the right by $ S^z $ on a spin. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn z(self, index: usize) -> Self {
+        self.multiply_operator(index, SingleSpinSOperator::Z)
+    }
+
+    /// Multiplies Self from the right by $ S^{+} $ on a spin. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn plus(self, index: usize) -> Self {
+        self.multiply_operator(index, SingleSpinSOperator::Plus)
+    }
+
+    /// Multiplies Self from the right by $ S^{-} $ on a spin. This function consumes Self.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of Self and the operator.
+    pub fn minus(self, index: usize) -> Self {
+        self.multiply_operator(index, SingleSpinSOperator::Minus)
+    }
+
+    /// Returns the operators acting on a spin in the order of multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the spin.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<SingleSpinSOperator>` - The operators acting on the spin, empty for the identity.
+    pub fn operators_on_spin(&self, index: usize) -> Vec<SingleSpinSOperator> {
+        self.iter()
+            .filter(|(spin, _)| *spin == index)
+            .map(|(_, operator)| *operator)
+            .collect()
+    }
+
+    /// Returns the iterator form of Self.
+    ///
+    /// # Returns
+    ///
+    /// * `Iter<(usize, SingleSpinSOperator)>` - The spin indices and operators sorted by spin.
+    pub fn iter(&self) -> std::slice::Iter<(usize, SingleSpinSOperator)> {
+        return match &self.items {
+            TinyVec::Heap(x) => x.iter(),
+            TinyVec::Inline(x) => x.iter(),
+        };
+    }
+
+    /// Returns maximum index in Self.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Maximum index.
+    pub fn current_number_spins(&self) -> usize {
+        if let Some((max, _)) = self.iter().last() {
+            *max + 1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the number of single spin operators in the SpinSProduct.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of operators in the SpinSProduct.
+    pub fn len(&self) -> usize {
+        self.iter().len()
+    }
+
+    /// Returns whether the SpinSProduct is empty (the identity) or not.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the SpinSProduct is empty or not.
+    pub fn is_empty(&self) -> bool {
+        self.iter().len() == 0
+    }
+
+    /// Implements multiplication function for a SpinSProduct by a SpinSProduct.
+    ///
+    /// The product of two SpinSProducts is again a SpinSProduct, the operators of right are applied first.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left-hand SpinSProduct to be multiplied.
+    /// * `right` - Right-hand SpinSProduct to be multiplied.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The product of the two SpinSProducts.
+    pub fn multiply(left: SpinSProduct, right: SpinSProduct) -> Self {
+        let mut product = left;
+        for (index, operator) in right.into_iter() {
+            product = product.multiply_operator(index, operator);
+        }
+        product
+    }
+
+    /// Applies the SpinSProduct to a basis state of spins with 2S+1 states each.
+    ///
+    /// The basis state index is the number with the state $ |k_j\rangle $ of spin j as its j-th digit in base 2S+1,
+    /// so spin 0 is the least significant digit as for the Pauli products.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_dimension` - The number of states 2S+1 of every spin.
+    /// * `state` - The index of the basis state the product is applied to.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(usize, Complex64)>` - The basis states and amplitudes of the image, empty if the state is annihilated.
+    pub fn apply_to_basis_state(
+        &self,
+        local_dimension: usize,
+        state: usize,
+    ) -> Vec<(usize, Complex64)> {
+        let mut image: Vec<(usize, Complex64)> = vec![(state, Complex64::new(1.0, 0.0))];
+        let items: Vec<(usize, SingleSpinSOperator)> = self.iter().copied().collect();
+        let mut start = 0;
+        while start < items.len() {
+            let spin = items[start].0;
+            let end = items[start..]
+                .iter()
+                .position(|(index, _)| *index != spin)
+                .map(|offset| start + offset)
+                .unwrap_or(items.len());
+            let operators = &items[start..end];
+            start = end;
+            let place = local_dimension.pow(spin as u32);
+            let digit = (state / place) % local_dimension;
+            // The rightmost operator is applied first
+            let mut local_image: Vec<(usize, Complex64)> = vec![(digit, Complex64::new(1.0, 0.0))];
+            for (_, operator) in operators.iter().rev() {
+                let mut new_local_image: Vec<(usize, Complex64)> = Vec::new();
+                for (local_state, amplitude) in local_image {
+                    for (new_local_state, factor) in
+                        operator.apply_to_basis_state(local_dimension, local_state)
+                    {
+                        match new_local_image
+                            .iter_mut()
+                            .find(|(existing, _)| *existing == new_local_state)
+                        {
+                            Some((_, existing_amplitude)) => {
+                                *existing_amplitude += amplitude * factor
+                            }
+                            None => new_local_image.push((new_local_state, amplitude * factor)),
+                        }
+                    }
+                }
+                local_image = new_local_image;
+            }
+            image = image
+                .into_iter()
+                .flat_map(|(basis_state, amplitude)| {
+                    local_image.iter().map(move |(new_digit, factor)| {
+                        (
+                            basis_state - digit * place + new_digit * place,
+                            amplitude * factor,
+                        )
+                    })
+                })
+                .collect();
+        }
+        image
+    }
+}
+
+/// Implements Ord for SpinSProduct; length then lexicographic sorting
+///
+/// # Arguments
+///
+/// * `self` - SpinSProduct to be ordered.
+///
+/// # Returns
+///
+/// `Ordering` - The ordering result
+impl Ord for SpinSProduct {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let me: &TinyVec<[(usize, SingleSpinSOperator); 5]> = &(self.items);
+        let them: &TinyVec<[(usize, SingleSpinSOperator); 5]> = &(other.items);
+
+        match me.len().cmp(&them.len()) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => me.cmp(them), // If lengths are equal use lexicographic
+            Ordering::Greater => Ordering::Greater,
+        }
+    }
+}
+
+/// This method returns an ordering between `self` and `other` values if one exists.
+impl PartialOrd for SpinSProduct {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl SymmetricIndex for SpinSProduct {
+    // From trait
+    fn hermitian_conjugate(&self) -> (Self, f64) {
+        let mut items: Vec<(usize, SingleSpinSOperator)> = self
+            .iter()
+            .map(|(index, operator)| (*index, operator.hermitian_conjugate()))
+            .collect();
+        // Reverses the order of the operators on every spin, the order of the spins is restored by the stable sort
+        items.reverse();
+        items.sort_by_key(|(index, _)| *index);
+        let mut conjugated = SpinSProduct::with_capacity(items.len());
+        conjugated.items.extend(items);
+        (conjugated, 1.0)
+    }
+
+    // From trait
+    fn is_natural_hermitian(&self) -> bool {
+        self.hermitian_conjugate().0 == *self
+    }
+}
+
+/// Implements the default function (Default trait) of SpinSProduct (an empty SpinSProduct).
+///
+impl Default for SpinSProduct {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for SpinSProduct {
+    type Err = StruqtureError;
+    /// Constructs a SpinSProduct from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The successfully converted SpinSProduct.
+    /// * `Err(StruqtureError::IncorrectPauliEntry)` - The operator being set is not in [\"I\", \"X\", \"Y\", \"Z\", \"+\", \"-\"].
+    /// * `Err(StruqtureError::FromStringFailed)` - An operator is not preceded by an unsigned integer spin index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "I" {
+            return Ok(Self::new()); // If the string is identity then it's an empty SpinSProduct
+        }
+        let mut sp = SpinSProduct::new();
+        let mut index = String::new();
+        for character in s.chars() {
+            if character.is_ascii_digit() {
+                index.push(character);
+                continue;
+            }
+            let spin: usize = index
+                .parse()
+                .map_err(|_| StruqtureError::FromStringFailed {
+                    msg: format!("Using {} instead of unsigned integer as spin index", index),
+                })?;
+            let operator = SingleSpinSOperator::from_str(&character.to_string())?;
+            sp = sp.multiply_operator(spin, operator);
+            index.clear();
+        }
+        if index.is_empty() {
+            Ok(sp)
+        } else {
+            Err(StruqtureError::FromStringFailed {
+                msg: format!("Spin index {} is not followed by an operator", index),
+            })
+        }
+    }
+}
+
+/// Implements the format function (Display trait) of SpinSProduct.
+///
+impl fmt::Display for SpinSProduct {
+    /// Formats the SpinSProduct using the given formatter.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to use.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted SpinSProduct.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string: String = String::new();
+        if self.items.is_empty() {
+            string.push('I');
+        } else {
+            for (index, operator) in self.items.iter() {
+                string.push_str(format!("{}", index).as_str());
+                string.push_str(format!("{}", operator).as_str());
+            }
+        }
+        write!(f, "{}", string)
+    }
+}
+
+/// Implements the into_iter function (IntoIterator trait) of SpinSProduct.
+///
+impl IntoIterator for SpinSProduct {
+    type Item = (usize, SingleSpinSOperator);
+
+    type IntoIter = TinyVecIterator<[(usize, SingleSpinSOperator); 5]>;
+    /// Returns the SpinSProduct in Iterator form.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The SpinSProduct in Iterator form.
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Implements the from_iter function (FromIterator trait) of SpinSProduct.
+///
+impl FromIterator<(usize, SingleSpinSOperator)> for SpinSProduct {
+    /// Returns the product of the operators of an Iterator in SpinSProduct form.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operators to multiply, in order of multiplication.
+    ///
+    /// # Returns
+    ///
+    /// * `Self::IntoIter` - The iterator in SpinSProduct form.
+    fn from_iter<I: IntoIterator<Item = (usize, SingleSpinSOperator)>>(iter: I) -> Self {
+        let mut sp = SpinSProduct::new();
+        for (index, operator) in iter {
+            sp = sp.multiply_operator(index, operator);
+        }
+        sp
+    }
+}
+
+/// Implements the extend function (Extend trait) of SpinSProduct.
+///
+impl Extend<(usize, SingleSpinSOperator)> for SpinSProduct {
+    /// Multiplies the SpinSProduct from the right by the specified operators (in Iterator form).
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The iterator containing the operators by which to multiply the SpinSProduct.
+    fn extend<I: IntoIterator<Item = (usize, SingleSpinSOperator)>>(&mut self, iter: I) {
+        let mut sp = self.clone();
+        for (index, operator) in iter {
+            sp = sp.multiply_operator(index, operator);
+        }
+        *self = sp;
+    }
+}
+
+impl From<SpinSProduct> for Vec<(PauliProduct, Complex64)> {
+    /// Converts a SpinSProduct of spin-1/2 spins into a vector of tuples of (PauliProduct, Complex64).
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The SpinSProduct to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SpinSProduct of spin-1/2 spins converted into a vector of tuples of (PauliProduct, Complex64).
+    fn from(value: SpinSProduct) -> Self {
+        let mut new_vec: Vec<(PauliProduct, Complex64)> =
+            vec![(PauliProduct::new(), Complex64::new(1.0, 0.0))];
+        for (index, operator) in value.into_iter() {
+            let temp_vec: Vec<(SingleSpinOperator, Complex64)> = operator.into();
+            let mut temp_new_vec: Vec<(PauliProduct, Complex64)> = Vec::new();
+            for (product, prefactor) in new_vec.iter() {
+                let current = product
+                    .get(&index)
+                    .copied()
+                    .unwrap_or(SingleSpinOperator::Identity);
+                for (new_op, new_prefactor) in temp_vec.iter() {
+                    let (multiplied, multiplied_prefactor) =
+                        SingleSpinOperator::multiply(current, *new_op);
+                    temp_new_vec.push((
+                        product.clone().set_pauli(index, multiplied),
+                        prefactor * new_prefactor * multiplied_prefactor,
+                    ));
+                }
+            }
+            new_vec = temp_new_vec;
+        }
+        new_vec
+    }
+}
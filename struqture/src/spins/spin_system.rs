@@ -13,17 +13,24 @@
 use super::{ToSparseMatrixOperator, ToSparseMatrixSuperOperator};
 use crate::fermions::FermionSystem;
 use crate::mappings::JordanWignerSpinToFermion;
+use crate::metadata::merge_operand_metadata;
 use crate::spins::{OperateOnSpins, PauliProduct, SpinIndex, SpinOperator};
 use crate::{
-    CooSparseMatrix, OperateOnDensityMatrix, OperateOnState, StruqtureError, SymmetricIndex,
+    canonical_number, canonical_text_document, canonical_text_entries, hilbert_space_dimension,
+    parse_canonical_number, parse_canonical_text_entries, sparse_matrix_memory,
+    split_canonical_text, CanonicalText, CooSparseMatrix, OperateOnConstant,
+    OperateOnDensityMatrix, OperateOnState, SpectralNormBound, StruqtureError, SymmetricIndex,
+    SystemDimension, ZeroTermPolicy,
 };
 #[cfg(feature = "indexed_map_iterators")]
 use indexmap::map::{Iter, Keys, Values};
 use num_complex::Complex64;
 use qoqo_calculator::CalculatorComplex;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 #[cfg(not(feature = "indexed_map_iterators"))]
 use std::collections::hash_map::{Iter, Keys, Values};
+use std::collections::BTreeMap;
 
 use std::iter::{FromIterator, IntoIterator};
 use std::{
@@ -55,17 +62,94 @@ use std::{
 /// assert_eq!(system.get(&pp_0z), &CalculatorComplex::from(0.2));
 /// ```
 ///
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct SpinSystem {
     /// The number of spins in the SpinSystem.
     pub(crate) number_spins: Option<usize>,
     /// The SpinOperator representing the Hamiltonian of the SpinSystem.
     pub(crate) operator: SpinOperator,
+    /// Provenance metadata of the SpinSystem, e.g. the geometry, basis set or generation parameters.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl crate::MinSupportedVersion for SpinSystem {}
 
+impl Serialize for SpinSystem {
+    /// Serialization function for SpinSystem, leaving out empty metadata in human-readable formats.
+    ///
+    /// Formats that are not human-readable, like bincode, are not self-describing and always contain the metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - SpinSystem to be serialized.
+    /// * `serializer` - Serializer used for serialization.
+    ///
+    /// # Returns
+    ///
+    /// `S::Ok` - Serialized instance of SpinSystem.
+    /// `S::Error` - Error in the serialization process.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_system(self, &self.operator, serializer)
+    }
+}
+
+impl crate::SerializeCanonical for SpinSystem {
+    // From trait
+    fn serialize_canonical<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_system(self, &crate::Canonical(&self.operator), serializer)
+    }
+}
+
+/// Serializes a SpinSystem with the given serialization of its operator.
+///
+/// # Arguments
+///
+/// * `system` - SpinSystem to be serialized.
+/// * `operator` - Serialization of the operator of the SpinSystem.
+/// * `serializer` - Serializer used for serialization.
+///
+/// # Returns
+///
+/// `S::Ok` - Serialized instance of SpinSystem.
+/// `S::Error` - Error in the serialization process.
+fn serialize_system<S, O>(
+    system: &SpinSystem,
+    operator: &O,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    O: Serialize,
+{
+    let skip_metadata = serializer.is_human_readable() && system.metadata.is_empty();
+    let mut state = serializer.serialize_struct("SpinSystem", if skip_metadata { 2 } else { 3 })?;
+    state.serialize_field("number_spins", &system.number_spins)?;
+    state.serialize_field("operator", operator)?;
+    if skip_metadata {
+        state.skip_field("metadata")?;
+    } else {
+        state.serialize_field("metadata", &system.metadata)?;
+    }
+    state.end()
+}
+
+impl crate::OperateOnMetadata for SpinSystem {
+    // From trait
+    fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    // From trait
+    fn metadata_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.metadata
+    }
+}
+
 impl<'a> OperateOnDensityMatrix<'a> for SpinSystem {
     type Value = CalculatorComplex;
     type Index = PauliProduct;
@@ -103,15 +187,27 @@ impl<'a> OperateOnDensityMatrix<'a> for SpinSystem {
         match capacity {
             Some(cap) => Self {
                 number_spins: self.number_spins,
-                operator: SpinOperator::with_capacity(cap),
+                operator: self.operator.empty_clone(Some(cap)),
+                metadata: self.metadata.clone(),
             },
             None => Self {
                 number_spins: self.number_spins,
-                operator: SpinOperator::new(),
+                operator: self.operator.empty_clone(None),
+                metadata: self.metadata.clone(),
             },
         }
     }
 
+    // From trait
+    fn zero_term_policy(&self) -> ZeroTermPolicy {
+        self.operator.zero_term_policy()
+    }
+
+    // From trait
+    fn set_zero_term_policy(&mut self, policy: ZeroTermPolicy) -> Result<(), StruqtureError> {
+        self.operator.set_zero_term_policy(policy)
+    }
+
     /// Overwrites an existing entry or sets a new entry in the SpinSystem with the given (PauliProduct key, CalculatorComplex value) pair.
     ///
     /// # Arguments
@@ -184,6 +280,13 @@ impl<'a> OperateOnState<'a> for SpinSystem {
     }
 }
 
+impl<'a> OperateOnConstant<'a> for SpinSystem {
+    // From trait
+    fn identity_index(&self) -> Self::Index {
+        PauliProduct::new()
+    }
+}
+
 impl<'a> OperateOnSpins<'a> for SpinSystem {
     /// Gets the number_spins input of the SpinSystem or returns the current_number_spins, if number_spins is None.
     ///
@@ -250,6 +353,7 @@ impl SpinSystem {
         SpinSystem {
             number_spins,
             operator: SpinOperator::new(),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -267,6 +371,7 @@ impl SpinSystem {
         SpinSystem {
             number_spins,
             operator: SpinOperator::with_capacity(capacity),
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -300,6 +405,7 @@ impl SpinSystem {
                     Ok(SpinSystem {
                         number_spins: Some(x),
                         operator,
+                        metadata: BTreeMap::new(),
                     })
                 } else {
                     Err(StruqtureError::NumberSpinsExceeded)
@@ -308,6 +414,7 @@ impl SpinSystem {
             None => Ok(SpinSystem {
                 number_spins: None,
                 operator,
+                metadata: BTreeMap::new(),
             }),
         }
     }
@@ -357,7 +464,7 @@ impl ops::Neg for SpinSystem {
 ///
 impl<T, V> ops::Add<T> for SpinSystem
 where
-    T: IntoIterator<Item = (PauliProduct, V)>,
+    T: IntoIterator<Item = (PauliProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -372,6 +479,7 @@ where
     /// * `Ok(Self)` - The two SpinSystems added together.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of PauliProduct exceeds that of the SpinSystem.
     fn add(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value))?;
         }
@@ -383,7 +491,7 @@ where
 ///
 impl<T, V> ops::Sub<T> for SpinSystem
 where
-    T: IntoIterator<Item = (PauliProduct, V)>,
+    T: IntoIterator<Item = (PauliProduct, V)> + 'static,
     V: Into<CalculatorComplex>,
 {
     type Output = Result<Self, StruqtureError>;
@@ -398,6 +506,7 @@ where
     /// * `Ok(Self)` - The two SpinSystems subtracted.
     /// * `Err(StruqtureError::NumberModesExceeded)` - Index of PauliProduct exceeds that of the SpinSystem.
     fn sub(mut self, other: T) -> Self::Output {
+        merge_operand_metadata::<Self>(&mut self.metadata, &other);
         for (key, value) in other.into_iter() {
             self.add_operator_product(key.clone(), Into::<CalculatorComplex>::into(value) * -1.0)?;
         }
@@ -445,9 +554,12 @@ impl ops::Mul<SpinSystem> for SpinSystem {
     ///
     /// * Internal error in add_operator_product.
     fn mul(self, other: SpinSystem) -> Self::Output {
+        let mut metadata = self.metadata.clone();
+        merge_operand_metadata::<SpinSystem>(&mut metadata, &other);
         SpinSystem {
             number_spins: Some(self.number_spins().max(other.number_spins())),
             operator: self.operator * other.operator,
+            metadata,
         }
     }
 }
@@ -555,6 +667,54 @@ impl fmt::Display for SpinSystem {
     }
 }
 
+impl CanonicalText for SpinSystem {
+    /// Returns the canonical text representation of the SpinSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The sorted, line-per-term text representation of the SpinSystem.
+    fn to_canonical_text(&self) -> String {
+        canonical_text_document(
+            "SpinSystem",
+            &[("number_spins", canonical_number(&self.number_spins))],
+            &canonical_text_entries(self.iter()),
+        )
+    }
+
+    /// Creates a SpinSystem from its canonical text representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The canonical text to parse.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The SpinSystem represented by the text.
+    /// * `Err(StruqtureError::FromStringFailed)` - The text is not a valid canonical text of a SpinSystem.
+    /// * `Err(StruqtureError)` - The parsed terms could not be added to the SpinSystem.
+    fn from_canonical_text(text: &str) -> Result<Self, StruqtureError> {
+        let (header, lines) = split_canonical_text(text, "SpinSystem", &["number_spins"])?;
+        let mut new_self = SpinSystem::new(parse_canonical_number(header[0])?);
+        for (key, value) in parse_canonical_text_entries::<PauliProduct, CalculatorComplex>(&lines)?
+        {
+            new_self.add_operator_product(key, value)?;
+        }
+        Ok(new_self)
+    }
+}
+
+impl SpectralNormBound for SpinSystem {
+    /// Returns an upper bound of the spectral norm of the SpinSystem.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The upper bound of the spectral norm.
+    /// * `Err(StruqtureError::GenericError)` - The SpinSystem contains symbolic coefficients.
+    fn spectral_norm_bound(&self) -> Result<f64, StruqtureError> {
+        self.operator().spectral_norm_bound()
+    }
+}
+
 impl JordanWignerSpinToFermion for SpinSystem {
     type Output = FermionSystem;
 
@@ -578,3 +738,43 @@ impl JordanWignerSpinToFermion for SpinSystem {
             .expect("Internal bug in jordan_wigner() for SpinSystem. The number of modes in the resulting FermionSystem should equal the number of spins of the SpinSystem.")
     }
 }
+
+impl SystemDimension for SpinSystem {
+    /// Returns the dimension of the Hilbert space of the SpinSystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinSystem has no bosonic modes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The dimension of the Hilbert space.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The dimension overflows usize.
+    fn hilbert_space_dimension(&self, fock_cutoffs: &[usize]) -> Result<usize, StruqtureError> {
+        hilbert_space_dimension(self.number_spins(), 0, fock_cutoffs)
+    }
+
+    /// Returns an upper bound of the memory of the non-zero entries of the sparse matrix export of the SpinSystem.
+    ///
+    /// Every term of the SpinSystem has at most one non-zero entry per row of its sparse matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `fock_cutoffs` - The Fock space cutoffs, must be empty as the SpinSystem has no bosonic modes.
+    /// * `bytes_per_entry` - The memory of a single non-zero entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The upper bound of the memory in bytes.
+    /// * `Err(StruqtureError::MissmatchedNumberModes)` - Fock space cutoffs were given.
+    /// * `Err(StruqtureError::GenericError)` - The estimate overflows usize.
+    fn estimated_matrix_memory(
+        &self,
+        fock_cutoffs: &[usize],
+        bytes_per_entry: usize,
+    ) -> Result<usize, StruqtureError> {
+        let dimension = self.hilbert_space_dimension(fock_cutoffs)?;
+        sparse_matrix_memory(dimension, self.len(), bytes_per_entry)
+    }
+}
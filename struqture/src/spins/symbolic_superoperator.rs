@@ -0,0 +1,135 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CooSparseMatrix, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::Calculator;
+use std::collections::{BTreeMap, HashMap};
+
+/// Sparse matrix entries accumulated by (row, column) index.
+type SparseEntries = HashMap<(usize, usize), Complex64>;
+
+/// Superoperator of a spin object with symbolic coefficients, decomposed into numeric sparse matrices.
+///
+/// The superoperator for given parameter values is `constant + Σ_e value(e) * expressions[e]`, where `e` runs over
+/// the symbolic expressions appearing in the real and imaginary parts of the coefficients.
+/// Parameterized Liouvillians can therefore be evaluated for many parameter sets without rebuilding the sparse matrices.
+///
+/// # Example
+///
+/// ```
+/// use qoqo_calculator::CalculatorFloat;
+/// use std::collections::HashMap;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinHamiltonianSystem, ToSparseMatrixSuperOperator};
+///
+/// let mut system = SpinHamiltonianSystem::new(Some(1));
+/// system.set(PauliProduct::new().z(0), CalculatorFloat::from("omega")).unwrap();
+/// let symbolic = system.symbolic_sparse_matrix_superoperator(None).unwrap();
+///
+/// let parameters: HashMap<String, f64> = [("omega".to_string(), 0.5)].into_iter().collect();
+/// system.set(PauliProduct::new().z(0), CalculatorFloat::from(0.5)).unwrap();
+/// assert_eq!(
+///     symbolic.evaluate(&parameters).unwrap().0.len(),
+///     system.sparse_matrix_superoperator_coo(None).unwrap().0.len()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SymbolicSuperOperator {
+    /// The superoperator of all numeric coefficients.
+    pub constant: CooSparseMatrix,
+    /// The superoperator of every symbolic expression, for a unit value of the expression.
+    pub expressions: BTreeMap<String, CooSparseMatrix>,
+}
+
+impl SymbolicSuperOperator {
+    /// Creates a SymbolicSuperOperator from the superoperators of groups of terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - The symbolic expression (None for numeric coefficients) and the superoperator of every group of terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The SymbolicSuperOperator with the parts of the same expression summed.
+    pub(crate) fn from_parts(parts: Vec<(Option<String>, CooSparseMatrix)>) -> Self {
+        let mut constant = SparseEntries::new();
+        let mut expressions: BTreeMap<String, SparseEntries> = BTreeMap::new();
+        for (expression, matrix) in parts {
+            let entries = match expression {
+                None => &mut constant,
+                Some(expression) => expressions.entry(expression).or_default(),
+            };
+            add_coo(entries, &matrix, 1.0);
+        }
+        SymbolicSuperOperator {
+            constant: sorted_coo(constant),
+            expressions: expressions
+                .into_iter()
+                .map(|(expression, entries)| (expression, sorted_coo(entries)))
+                .collect(),
+        }
+    }
+
+    /// Evaluates the superoperator for the given values of the symbolic parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - The values of the parameters appearing in the symbolic expressions.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CooSparseMatrix)` - The superoperator in COO form, sorted by row and column.
+    /// * `Err(StruqtureError::CalculatorError)` - An expression contains a parameter without value or cannot be parsed.
+    pub fn evaluate(
+        &self,
+        parameters: &HashMap<String, f64>,
+    ) -> Result<CooSparseMatrix, StruqtureError> {
+        let mut calculator = Calculator::new();
+        for (name, value) in parameters.iter() {
+            calculator.set_variable(name, *value);
+        }
+        let mut entries = SparseEntries::new();
+        add_coo(&mut entries, &self.constant, 1.0);
+        for (expression, matrix) in self.expressions.iter() {
+            let value = calculator.parse_get(expression)?;
+            add_coo(&mut entries, matrix, value);
+        }
+        Ok(sorted_coo(entries))
+    }
+}
+
+/// Adds a COO matrix multiplied by a real factor to accumulated sparse entries.
+fn add_coo(entries: &mut SparseEntries, matrix: &CooSparseMatrix, factor: f64) {
+    let (values, (rows, columns)) = matrix;
+    for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+        *entries.entry((*row, *column)).or_default() += value * factor;
+    }
+}
+
+/// Converts accumulated sparse entries into a COO matrix sorted by row and column, dropping exact zeros.
+fn sorted_coo(entries: SparseEntries) -> CooSparseMatrix {
+    let mut entries: Vec<((usize, usize), Complex64)> = entries
+        .into_iter()
+        .filter(|(_, value)| *value != Complex64::new(0.0, 0.0))
+        .collect();
+    entries.sort_by_key(|(index, _)| *index);
+    let mut values: Vec<Complex64> = Vec::with_capacity(entries.len());
+    let mut rows: Vec<usize> = Vec::with_capacity(entries.len());
+    let mut columns: Vec<usize> = Vec::with_capacity(entries.len());
+    for ((row, column), value) in entries {
+        values.push(value);
+        rows.push(row);
+        columns.push(column);
+    }
+    (values, (rows, columns))
+}
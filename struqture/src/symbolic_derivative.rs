@@ -0,0 +1,428 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Symbolic differentiation of the expressions of symbolic coefficients.
+//!
+//! qoqo_calculator only evaluates expressions, so the expressions are parsed into a small syntax tree here,
+//! differentiated with the usual rules and written back into a string the calculator can evaluate.
+
+use crate::StruqtureError;
+use qoqo_calculator::CalculatorFloat;
+use std::fmt;
+
+/// Syntax tree of a symbolic expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Expression {
+    Number(f64),
+    Variable(String),
+    Negate(Box<Expression>),
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+    Divide(Box<Expression>, Box<Expression>),
+    Power(Box<Expression>, Box<Expression>),
+    Function(String, Box<Expression>),
+}
+
+use Expression::*;
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number(x) if *x < 0.0 => write!(f, "({})", x),
+            Number(x) => write!(f, "{}", x),
+            Variable(name) => write!(f, "{}", name),
+            Negate(x) => write!(f, "(-{})", x),
+            Add(x, y) => write!(f, "({} + {})", x, y),
+            Subtract(x, y) => write!(f, "({} - {})", x, y),
+            Multiply(x, y) => write!(f, "({} * {})", x, y),
+            Divide(x, y) => write!(f, "({} / {})", x, y),
+            Power(x, y) => write!(f, "({} ^ {})", x, y),
+            Function(name, x) => write!(f, "{}({})", name, x),
+        }
+    }
+}
+
+// Constructors simplifying trivial cases, so that derivatives stay readable
+
+fn number(expression: &Expression) -> Option<f64> {
+    match expression {
+        Number(x) => Some(*x),
+        _ => None,
+    }
+}
+
+fn negate(x: Expression) -> Expression {
+    match x {
+        Number(x) => Number(-x),
+        Negate(x) => *x,
+        x => Negate(Box::new(x)),
+    }
+}
+
+fn add(x: Expression, y: Expression) -> Expression {
+    match (number(&x), number(&y)) {
+        (Some(a), Some(b)) => Number(a + b),
+        (Some(a), _) if a == 0.0 => y,
+        (_, Some(b)) if b == 0.0 => x,
+        _ => Add(Box::new(x), Box::new(y)),
+    }
+}
+
+fn subtract(x: Expression, y: Expression) -> Expression {
+    match (number(&x), number(&y)) {
+        (Some(a), Some(b)) => Number(a - b),
+        (Some(a), _) if a == 0.0 => negate(y),
+        (_, Some(b)) if b == 0.0 => x,
+        _ => Subtract(Box::new(x), Box::new(y)),
+    }
+}
+
+fn multiply(x: Expression, y: Expression) -> Expression {
+    match (number(&x), number(&y)) {
+        (Some(a), Some(b)) => Number(a * b),
+        (Some(a), _) | (_, Some(a)) if a == 0.0 => Number(0.0),
+        (Some(a), _) if a == 1.0 => y,
+        (_, Some(b)) if b == 1.0 => x,
+        (Some(a), _) if a == -1.0 => negate(y),
+        (_, Some(b)) if b == -1.0 => negate(x),
+        _ => Multiply(Box::new(x), Box::new(y)),
+    }
+}
+
+fn divide(x: Expression, y: Expression) -> Expression {
+    match (number(&x), number(&y)) {
+        (Some(a), Some(b)) if b != 0.0 => Number(a / b),
+        (Some(a), _) if a == 0.0 => Number(0.0),
+        (_, Some(b)) if b == 1.0 => x,
+        _ => Divide(Box::new(x), Box::new(y)),
+    }
+}
+
+fn power(x: Expression, y: Expression) -> Expression {
+    match number(&y) {
+        Some(b) if b == 0.0 => Number(1.0),
+        Some(b) if b == 1.0 => x,
+        _ => Power(Box::new(x), Box::new(y)),
+    }
+}
+
+fn function(name: &str, x: Expression) -> Expression {
+    Function(name.to_string(), Box::new(x))
+}
+
+impl Expression {
+    /// Returns whether the expression depends on the parameter.
+    fn depends_on(&self, parameter: &str) -> bool {
+        match self {
+            Number(_) => false,
+            Variable(name) => name == parameter,
+            Negate(x) | Function(_, x) => x.depends_on(parameter),
+            Add(x, y) | Subtract(x, y) | Multiply(x, y) | Divide(x, y) | Power(x, y) => {
+                x.depends_on(parameter) || y.depends_on(parameter)
+            }
+        }
+    }
+
+    /// Returns the derivative of the expression with respect to the parameter.
+    fn derivative(&self, parameter: &str) -> Result<Expression, StruqtureError> {
+        if !self.depends_on(parameter) {
+            return Ok(Number(0.0));
+        }
+        Ok(match self {
+            Number(_) => Number(0.0),
+            Variable(_) => Number(1.0),
+            Negate(x) => negate(x.derivative(parameter)?),
+            Add(x, y) => add(x.derivative(parameter)?, y.derivative(parameter)?),
+            Subtract(x, y) => subtract(x.derivative(parameter)?, y.derivative(parameter)?),
+            Multiply(x, y) => add(
+                multiply(x.derivative(parameter)?, *y.clone()),
+                multiply(*x.clone(), y.derivative(parameter)?),
+            ),
+            Divide(x, y) => divide(
+                subtract(
+                    multiply(x.derivative(parameter)?, *y.clone()),
+                    multiply(*x.clone(), y.derivative(parameter)?),
+                ),
+                power(*y.clone(), Number(2.0)),
+            ),
+            Power(x, y) if !y.depends_on(parameter) => multiply(
+                multiply(
+                    *y.clone(),
+                    power(*x.clone(), subtract(*y.clone(), Number(1.0))),
+                ),
+                x.derivative(parameter)?,
+            ),
+            // d(x^y) = x^y (y' log(x) + y x' / x)
+            Power(x, y) => multiply(
+                self.clone(),
+                add(
+                    multiply(y.derivative(parameter)?, function("log", *x.clone())),
+                    divide(multiply(*y.clone(), x.derivative(parameter)?), *x.clone()),
+                ),
+            ),
+            Function(name, x) => {
+                let inner = *x.clone();
+                let outer = match name.as_str() {
+                    "sin" => function("cos", inner),
+                    "cos" => negate(function("sin", inner)),
+                    "tan" => divide(Number(1.0), power(function("cos", inner), Number(2.0))),
+                    "sinh" => function("cosh", inner),
+                    "cosh" => function("sinh", inner),
+                    "tanh" => divide(Number(1.0), power(function("cosh", inner), Number(2.0))),
+                    "asin" | "acos" => {
+                        let derivative = divide(
+                            Number(1.0),
+                            function("sqrt", subtract(Number(1.0), power(inner, Number(2.0)))),
+                        );
+                        if name == "asin" {
+                            derivative
+                        } else {
+                            negate(derivative)
+                        }
+                    }
+                    "atan" => divide(Number(1.0), add(Number(1.0), power(inner, Number(2.0)))),
+                    "exp" => self.clone(),
+                    "log" => divide(Number(1.0), inner),
+                    "sqrt" => divide(Number(1.0), multiply(Number(2.0), self.clone())),
+                    "abs" => function("signum", inner),
+                    _ => {
+                        return Err(StruqtureError::GenericError {
+                            msg: format!("Cannot differentiate function {} in {}", name, self),
+                        })
+                    }
+                };
+                multiply(outer, x.derivative(parameter)?)
+            }
+        })
+    }
+}
+
+/// Tokens of a symbolic expression.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Identifier(String),
+    Operator(char),
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+/// Splits an expression into tokens.
+fn tokenize(expression: &str) -> Result<Vec<Token>, StruqtureError> {
+    let error = || StruqtureError::GenericError {
+        msg: format!("Cannot parse symbolic expression {}", expression),
+    };
+    let characters: Vec<char> = expression.chars().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut position = 0;
+    while position < characters.len() {
+        let character = characters[position];
+        if character.is_whitespace() {
+            position += 1;
+        } else if character.is_ascii_digit() || character == '.' {
+            let start = position;
+            while position < characters.len()
+                && (characters[position].is_ascii_digit() || characters[position] == '.')
+            {
+                position += 1;
+            }
+            // Exponent of the scientific notation, e.g. 1e-3
+            if position < characters.len() && matches!(characters[position], 'e' | 'E') {
+                let mut exponent_end = position + 1;
+                if exponent_end < characters.len() && matches!(characters[exponent_end], '+' | '-')
+                {
+                    exponent_end += 1;
+                }
+                if exponent_end < characters.len() && characters[exponent_end].is_ascii_digit() {
+                    position = exponent_end;
+                    while position < characters.len() && characters[position].is_ascii_digit() {
+                        position += 1;
+                    }
+                }
+            }
+            let text: String = characters[start..position].iter().collect();
+            tokens.push(Token::Number(text.parse().map_err(|_| error())?));
+        } else if character.is_alphabetic() || character == '_' {
+            let start = position;
+            while position < characters.len()
+                && (characters[position].is_alphanumeric() || characters[position] == '_')
+            {
+                position += 1;
+            }
+            tokens.push(Token::Identifier(
+                characters[start..position].iter().collect(),
+            ));
+        } else {
+            tokens.push(match character {
+                '(' => Token::LeftParenthesis,
+                ')' => Token::RightParenthesis,
+                '+' | '-' | '/' | '^' => Token::Operator(character),
+                // ** is an alternative notation for ^
+                '*' if characters.get(position + 1) == Some(&'*') => {
+                    position += 1;
+                    Token::Operator('^')
+                }
+                '*' => Token::Operator('*'),
+                _ => return Err(error()),
+            });
+            position += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser of symbolic expressions.
+struct Parser<'a> {
+    expression: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> StruqtureError {
+        StruqtureError::GenericError {
+            msg: format!("Cannot parse symbolic expression {}", self.expression),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// sum = product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<Expression, StruqtureError> {
+        let mut expression = self.product()?;
+        while let Some(Token::Operator(operator @ ('+' | '-'))) = self.peek().cloned() {
+            self.position += 1;
+            let right = self.product()?;
+            expression = if operator == '+' {
+                Add(Box::new(expression), Box::new(right))
+            } else {
+                Subtract(Box::new(expression), Box::new(right))
+            };
+        }
+        Ok(expression)
+    }
+
+    /// product = unary (('*' | '/') unary)*
+    fn product(&mut self) -> Result<Expression, StruqtureError> {
+        let mut expression = self.unary()?;
+        while let Some(Token::Operator(operator @ ('*' | '/'))) = self.peek().cloned() {
+            self.position += 1;
+            let right = self.unary()?;
+            expression = if operator == '*' {
+                Multiply(Box::new(expression), Box::new(right))
+            } else {
+                Divide(Box::new(expression), Box::new(right))
+            };
+        }
+        Ok(expression)
+    }
+
+    /// unary = ('+' | '-') unary | atom ('^' unary)?
+    fn unary(&mut self) -> Result<Expression, StruqtureError> {
+        match self.peek() {
+            Some(Token::Operator('-')) => {
+                self.position += 1;
+                Ok(Negate(Box::new(self.unary()?)))
+            }
+            Some(Token::Operator('+')) => {
+                self.position += 1;
+                self.unary()
+            }
+            _ => {
+                let base = self.atom()?;
+                if let Some(Token::Operator('^')) = self.peek() {
+                    self.position += 1;
+                    Ok(Power(Box::new(base), Box::new(self.unary()?)))
+                } else {
+                    Ok(base)
+                }
+            }
+        }
+    }
+
+    /// atom = number | identifier | identifier '(' sum ')' | '(' sum ')'
+    fn atom(&mut self) -> Result<Expression, StruqtureError> {
+        match self.advance() {
+            Some(Token::Number(x)) => Ok(Number(x)),
+            Some(Token::Identifier(name)) => {
+                if self.peek() == Some(&Token::LeftParenthesis) {
+                    self.position += 1;
+                    let argument = self.sum()?;
+                    self.expect_right_parenthesis()?;
+                    Ok(Function(name, Box::new(argument)))
+                } else {
+                    Ok(Variable(name))
+                }
+            }
+            Some(Token::LeftParenthesis) => {
+                let expression = self.sum()?;
+                self.expect_right_parenthesis()?;
+                Ok(expression)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn expect_right_parenthesis(&mut self) -> Result<(), StruqtureError> {
+        match self.advance() {
+            Some(Token::RightParenthesis) => Ok(()),
+            _ => Err(self.error()),
+        }
+    }
+}
+
+/// Parses a symbolic expression.
+fn parse(expression: &str) -> Result<Expression, StruqtureError> {
+    let mut parser = Parser {
+        expression,
+        tokens: tokenize(expression)?,
+        position: 0,
+    };
+    let parsed = parser.sum()?;
+    if parser.position != parser.tokens.len() {
+        return Err(parser.error());
+    }
+    Ok(parsed)
+}
+
+/// Differentiates a CalculatorFloat with respect to a symbolic parameter.
+///
+/// # Arguments
+///
+/// * `value` - The CalculatorFloat to differentiate.
+/// * `parameter` - The name of the symbolic parameter.
+///
+/// # Returns
+///
+/// * `Ok(CalculatorFloat)` - The derivative, a float if it does not contain any symbols.
+/// * `Err(StruqtureError::GenericError)` - The expression cannot be parsed or contains a function that cannot be differentiated.
+pub(crate) fn differentiate(
+    value: &CalculatorFloat,
+    parameter: &str,
+) -> Result<CalculatorFloat, StruqtureError> {
+    match value {
+        CalculatorFloat::Float(_) => Ok(CalculatorFloat::Float(0.0)),
+        CalculatorFloat::Str(expression) => match parse(expression)?.derivative(parameter)? {
+            Number(x) => Ok(CalculatorFloat::Float(x)),
+            derivative => Ok(CalculatorFloat::Str(derivative.to_string())),
+        },
+    }
+}
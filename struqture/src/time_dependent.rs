@@ -0,0 +1,285 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hamiltonians with an explicit time dependence.
+//!
+//! A [TimeDependentHamiltonian] is a sum of time-independent Hamiltonians, each multiplied by a
+//! scalar time envelope. Envelopes are either symbolic expressions in the time variable `t` or
+//! piecewise-constant schedules, which covers drive sequences as well as annealing schedules.
+//! Evaluating the container at a fixed time returns an ordinary struqture Hamiltonian.
+//!
+//! # Example
+//! ```
+//! use qoqo_calculator::CalculatorFloat;
+//! use struqture::prelude::*;
+//! use struqture::spins::{PauliProduct, SpinHamiltonian};
+//! use struqture::time_dependent::{TimeDependentHamiltonian, TimeEnvelope};
+//!
+//! let mut driver = SpinHamiltonian::new();
+//! driver.add_operator_product(PauliProduct::new().x(0), 1.0.into()).unwrap();
+//! let mut problem = SpinHamiltonian::new();
+//! problem.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+//!
+//! let mut annealing = TimeDependentHamiltonian::new();
+//! annealing.add_term(driver, CalculatorFloat::from("1.0 - t"));
+//! annealing.add_term(problem, CalculatorFloat::from("t"));
+//!
+//! let hamiltonian = annealing.evaluate_at(0.25).unwrap();
+//! assert_eq!(hamiltonian.get(&PauliProduct::new().x(0)), &CalculatorFloat::from(0.75));
+//! assert_eq!(hamiltonian.get(&PauliProduct::new().z(0)), &CalculatorFloat::from(0.25));
+//! ```
+
+use crate::bosons::BosonHamiltonian;
+use crate::fermions::FermionHamiltonian;
+use crate::mixed_systems::MixedHamiltonian;
+use crate::spins::SpinHamiltonian;
+use crate::{OperateOnDensityMatrix, StruqtureError};
+use qoqo_calculator::{Calculator, CalculatorFloat};
+use serde::{Deserialize, Serialize};
+
+/// The name of the time variable in symbolic time envelopes.
+pub const TIME_VARIABLE: &str = "t";
+
+/// Scalar time dependence of one term of a [TimeDependentHamiltonian].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TimeEnvelope {
+    /// Symbolic expression in the time variable `t`, e.g. "sin(2.0 * t)".
+    Expression(CalculatorFloat),
+    /// Piecewise-constant schedule taking the value `values[i]` for `times[i] <= t < times[i + 1]`
+    /// and zero outside of the interval covered by `times`.
+    PiecewiseConstant {
+        /// The ascending boundaries of the time intervals, one more than the number of values.
+        times: Vec<f64>,
+        /// The value of the envelope in each time interval.
+        values: Vec<f64>,
+    },
+}
+
+impl TimeEnvelope {
+    /// Creates a new piecewise-constant TimeEnvelope.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The ascending boundaries of the time intervals.
+    /// * `values` - The value of the envelope in each time interval.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The new piecewise-constant TimeEnvelope.
+    /// * `Err(StruqtureError::GenericError)` - The number of times is not the number of values plus one or the times are not ascending.
+    pub fn piecewise_constant(times: Vec<f64>, values: Vec<f64>) -> Result<Self, StruqtureError> {
+        if times.len() != values.len() + 1 {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "A piecewise-constant envelope with {} values needs {} interval boundaries, got {}",
+                    values.len(),
+                    values.len() + 1,
+                    times.len()
+                ),
+            });
+        }
+        if times.windows(2).any(|window| window[0] >= window[1]) {
+            return Err(StruqtureError::GenericError {
+                msg: format!(
+                    "Interval boundaries {:?} of piecewise-constant envelope are not strictly ascending",
+                    times
+                ),
+            });
+        }
+        Ok(TimeEnvelope::PiecewiseConstant { times, values })
+    }
+
+    /// Evaluates the TimeEnvelope at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time at which the envelope is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(f64)` - The value of the envelope at the given time.
+    /// * `Err(StruqtureError::CalculatorError)` - The expression contains symbols other than `t` or cannot be parsed.
+    pub fn evaluate_at(&self, time: f64) -> Result<f64, StruqtureError> {
+        match self {
+            TimeEnvelope::Expression(CalculatorFloat::Float(value)) => Ok(*value),
+            TimeEnvelope::Expression(CalculatorFloat::Str(expression)) => {
+                let mut calculator = Calculator::new();
+                calculator.set_variable(TIME_VARIABLE, time);
+                Ok(calculator.parse_get(expression)?)
+            }
+            TimeEnvelope::PiecewiseConstant { times, values } => Ok(times
+                .windows(2)
+                .zip(values.iter())
+                .find(|(window, _)| window[0] <= time && time < window[1])
+                .map_or(0.0, |(_, value)| *value)),
+        }
+    }
+}
+
+impl From<CalculatorFloat> for TimeEnvelope {
+    /// Creates a symbolic TimeEnvelope from a CalculatorFloat.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - The expression in the time variable `t`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The symbolic TimeEnvelope.
+    fn from(expression: CalculatorFloat) -> Self {
+        TimeEnvelope::Expression(expression)
+    }
+}
+
+/// Hamiltonians that can be the time-independent part of a term of a [TimeDependentHamiltonian].
+pub trait ModulatedHamiltonian: Clone + PartialEq + Default {
+    /// Returns an empty Hamiltonian with the same number of modes, spins and subsystems as self.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The empty Hamiltonian.
+    fn empty_like(&self) -> Self;
+
+    /// Adds all terms of another Hamiltonian, multiplied by a real factor, to self.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The Hamiltonian whose terms are added.
+    /// * `factor` - The factor multiplying the terms of `other`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The terms were added.
+    /// * `Err(StruqtureError)` - The Hamiltonians are not compatible.
+    fn add_scaled(&mut self, other: &Self, factor: f64) -> Result<(), StruqtureError>;
+}
+
+/// Implements ModulatedHamiltonian through the OperateOnDensityMatrix interface.
+macro_rules! impl_modulated_hamiltonian {
+    ($($hamiltonian:ident),* $(,)?) => {
+        $(
+            impl ModulatedHamiltonian for $hamiltonian {
+                fn empty_like(&self) -> Self {
+                    self.empty_clone(None)
+                }
+
+                fn add_scaled(&mut self, other: &Self, factor: f64) -> Result<(), StruqtureError> {
+                    for (key, value) in other.iter() {
+                        self.add_operator_product(key.clone(), value.clone() * factor)?;
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_modulated_hamiltonian!(
+    SpinHamiltonian,
+    BosonHamiltonian,
+    FermionHamiltonian,
+    MixedHamiltonian
+);
+
+/// Sum of time-independent Hamiltonians multiplied by scalar time envelopes.
+///
+/// The Hamiltonian at time `t` is `H(t) = sum_i f_i(t) H_i`. Symbolic coefficients of the
+/// Hamiltonians `H_i` are kept when the container is evaluated, only the envelopes are evaluated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeDependentHamiltonian<T> {
+    /// The (Hamiltonian, time envelope) pairs of the terms.
+    terms: Vec<(T, TimeEnvelope)>,
+}
+
+/// Implements the default function (Default trait) of TimeDependentHamiltonian (a TimeDependentHamiltonian without terms).
+///
+impl<T> Default for TimeDependentHamiltonian<T> {
+    fn default() -> Self {
+        TimeDependentHamiltonian { terms: Vec::new() }
+    }
+}
+
+impl<T> TimeDependentHamiltonian<T>
+where
+    T: ModulatedHamiltonian,
+{
+    /// Creates a new TimeDependentHamiltonian without terms.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new (empty) TimeDependentHamiltonian.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a term with a given time envelope to the TimeDependentHamiltonian.
+    ///
+    /// # Arguments
+    ///
+    /// * `hamiltonian` - The time-independent part of the term.
+    /// * `envelope` - The time envelope, a CalculatorFloat expression in `t` or a TimeEnvelope.
+    pub fn add_term(&mut self, hamiltonian: T, envelope: impl Into<TimeEnvelope>) {
+        self.terms.push((hamiltonian, envelope.into()));
+    }
+
+    /// Returns the (Hamiltonian, time envelope) pairs of the TimeDependentHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `&[(T, TimeEnvelope)]` - The terms in the order they were added.
+    pub fn terms(&self) -> &[(T, TimeEnvelope)] {
+        &self.terms
+    }
+
+    /// Returns the number of terms of the TimeDependentHamiltonian.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns whether the TimeDependentHamiltonian has no terms.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether there are no terms.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Evaluates the TimeDependentHamiltonian at a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time at which the Hamiltonian is evaluated.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The Hamiltonian at the given time.
+    /// * `Err(StruqtureError::CalculatorError)` - An envelope could not be evaluated.
+    /// * `Err(StruqtureError)` - The Hamiltonians of the terms are not compatible.
+    pub fn evaluate_at(&self, time: f64) -> Result<T, StruqtureError> {
+        let mut hamiltonian = match self.terms.first() {
+            Some((first, _)) => first.empty_like(),
+            None => return Ok(T::default()),
+        };
+        for (term, envelope) in self.terms.iter() {
+            let factor = envelope.evaluate_at(time)?;
+            if factor != 0.0 {
+                hamiltonian.add_scaled(term, factor)?;
+            }
+        }
+        Ok(hamiltonian)
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transformations of Hamiltonians in operator form.
+//!
+//! [schrieffer_wolff] computes the effective Hamiltonian of a Hamiltonian H = H0 + V in perturbation theory.
+//! The unperturbed Hamiltonian H0 is not diagonalized. Instead the terms P of the perturbation are expected to be
+//! eigenoperators of the commutator with H0, [H0, P] = ΔE(P) P, and the energy differences ΔE(P) are supplied by a callback.
+//! This is the case for spin Hamiltonians H0 = Σ_i h_i Z_i written in the +/-/Z basis of a PlusMinusProduct
+//! and for fermionic Hamiltonians H0 = Σ_p ε_p c^†_p c_p written with FermionProducts.
+//!
+//! # Example
+//! ```
+//! use qoqo_calculator::CalculatorFloat;
+//! use struqture::prelude::*;
+//! use struqture::spins::{PauliProduct, PlusMinusProduct, SinglePlusMinusOperator, SpinHamiltonian};
+//! use struqture::transforms::schrieffer_wolff;
+//!
+//! // A spin with level splitting 2 and a transverse field
+//! let mut h0 = SpinHamiltonian::new();
+//! h0.add_operator_product(PauliProduct::new().z(0), 1.0.into()).unwrap();
+//! let mut v = SpinHamiltonian::new();
+//! v.add_operator_product(PauliProduct::new().x(0), 1.0.into()).unwrap();
+//!
+//! // [Z, σ^+] = 2 σ^+ and [Z, σ^-] = -2 σ^-
+//! let energy_difference = |product: &PlusMinusProduct| {
+//!     product
+//!         .iter()
+//!         .map(|(_, operator)| match operator {
+//!             SinglePlusMinusOperator::Plus => 2.0,
+//!             SinglePlusMinusOperator::Minus => -2.0,
+//!             _ => 0.0,
+//!         })
+//!         .sum()
+//! };
+//! let effective = schrieffer_wolff(&h0, &v, 2, energy_difference).unwrap();
+//! assert_eq!(effective.get(&PauliProduct::new().z(0)), &CalculatorFloat::from(1.5));
+//! ```
+
+use crate::fermions::{FermionHamiltonian, FermionOperator, FermionProduct};
+use crate::spins::{PlusMinusOperator, PlusMinusProduct, SpinHamiltonian, SpinOperator};
+use crate::{OperateOnDensityMatrix, StruqtureError};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Energy differences with a smaller absolute value are treated as zero.
+const ENERGY_DIFFERENCE_TOLERANCE: f64 = 1e-12;
+
+/// Highest order of perturbation theory supported by [schrieffer_wolff].
+const MAX_SCHRIEFFER_WOLFF_ORDER: usize = 2;
+
+/// Hamiltonians for which the effective Hamiltonian of a Schrieffer-Wolff transformation can be computed.
+pub trait SchriefferWolff: Sized {
+    /// The key of the operator terms that are eigenoperators of the commutator with the unperturbed Hamiltonian.
+    type Product;
+
+    /// Computes the effective Hamiltonian of the unperturbed Hamiltonian and the perturbation.
+    ///
+    /// # Arguments
+    ///
+    /// * `unperturbed` - The unperturbed Hamiltonian H0.
+    /// * `perturbation` - The perturbation V.
+    /// * `order` - The order of perturbation theory, at most two.
+    /// * `energy_difference` - Returns the energy difference ΔE(P) with [H0, P] = ΔE(P) P for an operator term P.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The effective Hamiltonian.
+    /// * `Err(StruqtureError)` - A coefficient of the perturbation is symbolic or an energy difference is not finite.
+    fn effective_hamiltonian(
+        unperturbed: &Self,
+        perturbation: &Self,
+        order: usize,
+        energy_difference: &dyn Fn(&Self::Product) -> f64,
+    ) -> Result<Self, StruqtureError>;
+}
+
+/// Computes the effective Hamiltonian of a Schrieffer-Wolff transformation up to second order.
+///
+/// The perturbation V is split into the block-diagonal part V_d with ΔE(P) = 0 and the off-diagonal part V_od.
+/// The generator S = Σ_P v_P P / ΔE(P) of the off-diagonal terms solves [H0, S] = V_od, and the effective Hamiltonian is
+///
+/// H_eff = H0 + V_d + 1/2 [S, V_od]
+///
+/// restricted to the terms with ΔE(P) = 0, i.e. the block of H0 that is coupled by V only virtually.
+/// The first order only adds V_d and order zero returns H0.
+/// The callback is called for the terms of the perturbation and for the terms of the commutator, so it has to be
+/// consistent with ΔE(P^†) = -ΔE(P) and ΔE(P Q) = ΔE(P) + ΔE(Q).
+///
+/// # Arguments
+///
+/// * `unperturbed` - The unperturbed Hamiltonian H0.
+/// * `perturbation` - The perturbation V with numeric coefficients.
+/// * `order` - The order of perturbation theory, at most two.
+/// * `energy_difference` - Returns the energy difference ΔE(P) with [H0, P] = ΔE(P) P for an operator term P.
+///
+/// # Returns
+///
+/// * `Ok(H)` - The effective Hamiltonian.
+/// * `Err(StruqtureError::GenericError)` - The order is larger than two or an energy difference is not finite.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient of the perturbation is symbolic.
+pub fn schrieffer_wolff<H, F>(
+    unperturbed: &H,
+    perturbation: &H,
+    order: usize,
+    energy_difference: F,
+) -> Result<H, StruqtureError>
+where
+    H: SchriefferWolff,
+    F: Fn(&H::Product) -> f64,
+{
+    if order > MAX_SCHRIEFFER_WOLFF_ORDER {
+        return Err(StruqtureError::GenericError {
+            msg: format!(
+                "The Schrieffer-Wolff transformation is only implemented up to order {}, order {} was requested",
+                MAX_SCHRIEFFER_WOLFF_ORDER, order
+            ),
+        });
+    }
+    H::effective_hamiltonian(unperturbed, perturbation, order, &energy_difference)
+}
+
+impl SchriefferWolff for SpinHamiltonian {
+    type Product = PlusMinusProduct;
+
+    /// Computes the effective Hamiltonian with the perturbation expressed in the +/-/Z basis.
+    ///
+    /// # Arguments
+    ///
+    /// * `unperturbed` - The unperturbed Hamiltonian H0.
+    /// * `perturbation` - The perturbation V.
+    /// * `order` - The order of perturbation theory, at most two.
+    /// * `energy_difference` - Returns the energy difference ΔE(P) with [H0, P] = ΔE(P) P for a PlusMinusProduct P.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The effective Hamiltonian.
+    /// * `Err(StruqtureError)` - A coefficient of the perturbation is symbolic or an energy difference is not finite.
+    fn effective_hamiltonian(
+        unperturbed: &Self,
+        perturbation: &Self,
+        order: usize,
+        energy_difference: &dyn Fn(&PlusMinusProduct) -> f64,
+    ) -> Result<Self, StruqtureError> {
+        let plus_minus_perturbation = PlusMinusOperator::from(perturbation.clone());
+        let terms = effective_block_terms(
+            plus_minus_perturbation.iter(),
+            order,
+            energy_difference,
+            |left, right| PlusMinusProduct::multiply(left.clone(), right.clone()),
+        )?;
+        let block: PlusMinusOperator = terms
+            .into_iter()
+            .map(|(product, value)| (product, CalculatorComplex::new(value.re, value.im)))
+            .collect();
+        let mut effective = unperturbed.clone();
+        // The block is hermitian, its imaginary parts in the Pauli basis are rounding errors
+        for (product, value) in SpinOperator::from(block) {
+            effective.add_operator_product(product, value.re)?;
+        }
+        Ok(effective)
+    }
+}
+
+impl SchriefferWolff for FermionHamiltonian {
+    type Product = FermionProduct;
+
+    /// Computes the effective Hamiltonian with the perturbation expressed in normal-ordered FermionProducts.
+    ///
+    /// # Arguments
+    ///
+    /// * `unperturbed` - The unperturbed Hamiltonian H0.
+    /// * `perturbation` - The perturbation V.
+    /// * `order` - The order of perturbation theory, at most two.
+    /// * `energy_difference` - Returns the energy difference ΔE(P) with [H0, P] = ΔE(P) P for a FermionProduct P.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The effective Hamiltonian.
+    /// * `Err(StruqtureError)` - A coefficient of the perturbation is symbolic or an energy difference is not finite.
+    fn effective_hamiltonian(
+        unperturbed: &Self,
+        perturbation: &Self,
+        order: usize,
+        energy_difference: &dyn Fn(&FermionProduct) -> f64,
+    ) -> Result<Self, StruqtureError> {
+        let operator_perturbation = FermionOperator::from(perturbation.clone());
+        let terms = effective_block_terms(
+            operator_perturbation.iter(),
+            order,
+            energy_difference,
+            |left, right| {
+                (left.clone() * right.clone())
+                    .into_iter()
+                    .map(|(product, prefactor)| (product, Complex64::new(prefactor, 0.0)))
+                    .collect()
+            },
+        )?;
+        let block: FermionOperator = terms
+            .into_iter()
+            .map(|(product, value)| (product, CalculatorComplex::new(value.re, value.im)))
+            .collect();
+        let mut effective = unperturbed.clone();
+        // Removing the rounding errors that break the hermiticity of the block
+        for (product, value) in block.hermitian_part().try_into_hamiltonian()? {
+            effective.add_operator_product(product, value)?;
+        }
+        Ok(effective)
+    }
+}
+
+/// Computes the terms of V_d + 1/2 [S, V_od] with vanishing energy difference.
+///
+/// Contributions with the same key are summed numerically and a sum is treated as vanishing when it is zero
+/// up to the floating point precision of its contributions.
+///
+/// # Arguments
+///
+/// * `perturbation` - The terms of the perturbation V.
+/// * `order` - The order of perturbation theory, at most two.
+/// * `energy_difference` - Returns the energy difference of a key.
+/// * `multiply` - Multiplies two keys, returning the resulting keys with their numerical prefactors.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(K, Complex64)>)` - The terms of the effective Hamiltonian that are added to H0.
+/// * `Err(StruqtureError::GenericError)` - An energy difference is not finite.
+/// * `Err(StruqtureError::CalculatorError)` - A coefficient of the perturbation is symbolic.
+fn effective_block_terms<'b, K>(
+    perturbation: impl Iterator<Item = (&'b K, &'b CalculatorComplex)>,
+    order: usize,
+    energy_difference: &dyn Fn(&K) -> f64,
+    multiply: impl Fn(&K, &K) -> Vec<(K, Complex64)>,
+) -> Result<Vec<(K, Complex64)>, StruqtureError>
+where
+    K: Hash + Eq + Clone + std::fmt::Display + 'b,
+{
+    let checked_difference = |key: &K| -> Result<f64, StruqtureError> {
+        let difference = energy_difference(key);
+        if difference.is_finite() {
+            Ok(difference)
+        } else {
+            Err(StruqtureError::GenericError {
+                msg: format!("The energy difference of term {} is not finite", key),
+            })
+        }
+    };
+    // Sum of the contributions and sum of their absolute values for every key
+    let mut sums: HashMap<K, (Complex64, f64)> = HashMap::new();
+    let mut add_contribution = |key: K, contribution: Complex64| {
+        let sum = sums.entry(key).or_insert((Complex64::new(0.0, 0.0), 0.0));
+        sum.0 += contribution;
+        sum.1 += contribution.norm();
+    };
+    let mut off_diagonal: Vec<(&K, Complex64, f64)> = Vec::new();
+    for (key, value) in perturbation {
+        let value = Complex64::new(value.re.clone().try_into()?, value.im.clone().try_into()?);
+        let difference = checked_difference(key)?;
+        if difference.abs() > ENERGY_DIFFERENCE_TOLERANCE {
+            off_diagonal.push((key, value, difference));
+        } else if order >= 1 {
+            add_contribution(key.clone(), value);
+        }
+    }
+    if order >= 2 {
+        for &(generator_key, generator_value, generator_difference) in off_diagonal.iter() {
+            let generator_value = generator_value / generator_difference;
+            for &(key, value, _) in off_diagonal.iter() {
+                let factor = 0.5 * generator_value * value;
+                for (product, prefactor) in multiply(generator_key, key) {
+                    add_contribution(product, factor * prefactor);
+                }
+                for (product, prefactor) in multiply(key, generator_key) {
+                    add_contribution(product, -factor * prefactor);
+                }
+            }
+        }
+    }
+    let mut terms: Vec<(K, Complex64)> = Vec::with_capacity(sums.len());
+    for (key, (sum, magnitude)) in sums {
+        if sum.norm() <= 4.0 * f64::EPSILON * magnitude {
+            continue;
+        }
+        // Terms with a finite energy difference couple different blocks of H0
+        if checked_difference(&key)?.abs() <= ENERGY_DIFFERENCE_TOLERANCE {
+            terms.push((key, sum));
+        }
+    }
+    Ok(terms)
+}
@@ -0,0 +1,143 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Policies deciding which coefficients are dropped when terms are set or added.
+
+use crate::{AbsoluteValueTrait, StruqtureError};
+use std::str::FromStr;
+
+/// Policy deciding which coefficients are dropped when terms are set or added.
+///
+/// Every operator and system stores a policy, which is returned by [crate::OperateOnDensityMatrix::zero_term_policy]
+/// and changed with [crate::OperateOnDensityMatrix::set_zero_term_policy]. The stored policy is applied in `set`,
+/// `add_operator_product`, the arithmetic and the `entry` functions, and it is serialized with the object.
+/// The results of arithmetic take the policy of the left operand.
+/// The default policy drops exact zeros. Symbolic coefficients are never dropped.
+///
+/// # Example
+/// ```
+/// use qoqo_calculator::CalculatorComplex;
+/// use struqture::prelude::*;
+/// use struqture::spins::{PauliProduct, SpinOperator};
+/// use struqture::ZeroTermPolicy;
+///
+/// let mut so = SpinOperator::new();
+/// so.set(PauliProduct::new().z(0), CalculatorComplex::from(1e-20)).unwrap();
+/// assert_eq!(so.len(), 1);
+///
+/// // Setting the policy removes the terms that are dropped under it
+/// so.set_zero_term_policy(ZeroTermPolicy::DropBelowThreshold(1e-12)).unwrap();
+/// assert!(so.is_empty());
+///
+/// so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0)).unwrap();
+/// so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(-1.0 + 1e-15)).unwrap();
+/// assert!(so.is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub enum ZeroTermPolicy {
+    /// Terms with a coefficient of exactly zero are dropped.
+    #[default]
+    DropExactZeros,
+    /// Terms with a coefficient whose absolute value is smaller than or equal to the threshold are dropped.
+    DropBelowThreshold(f64),
+}
+
+impl ZeroTermPolicy {
+    /// Returns true if a term with the coefficient is dropped under the policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The coefficient of the term.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The term is dropped. Symbolic coefficients are never dropped.
+    pub fn drops<V: AbsoluteValueTrait>(&self, value: &V) -> bool {
+        match (self, value.absolute_value()) {
+            (_, Err(_)) => false,
+            (ZeroTermPolicy::DropExactZeros, Ok(absolute)) => absolute == 0.0,
+            (ZeroTermPolicy::DropBelowThreshold(threshold), Ok(absolute)) => absolute <= *threshold,
+        }
+    }
+
+    /// Checks that the threshold of the policy is valid.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The policy is valid.
+    /// * `Err(StruqtureError::GenericError)` - The threshold is negative or NaN.
+    pub(crate) fn validate(&self) -> Result<(), StruqtureError> {
+        match self {
+            ZeroTermPolicy::DropBelowThreshold(threshold)
+                if threshold.is_nan() || *threshold < 0.0 =>
+            {
+                Err(StruqtureError::GenericError {
+                    msg: format!(
+                        "The threshold of the zero-term policy must be non-negative, got {}",
+                        threshold
+                    ),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for ZeroTermPolicy {
+    /// Formats the ZeroTermPolicy using the representation accepted by `from_str`.
+    ///
+    /// # Returns
+    ///
+    /// * `std::fmt::Result` - The formatted policy.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZeroTermPolicy::DropExactZeros => write!(f, "drop_exact_zeros"),
+            ZeroTermPolicy::DropBelowThreshold(threshold) => {
+                write!(f, "drop_below_threshold:{:e}", threshold)
+            }
+        }
+    }
+}
+
+impl FromStr for ZeroTermPolicy {
+    type Err = StruqtureError;
+
+    /// Constructs a ZeroTermPolicy from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - One of "drop_exact_zeros" or "drop_below_threshold:<threshold>".
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The policy corresponding to the string.
+    /// * `Err(StruqtureError::FromStringFailed)` - The string is not a known zero-term policy.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "drop_exact_zeros" => Ok(ZeroTermPolicy::DropExactZeros),
+            Some(("drop_below_threshold", threshold)) => threshold
+                .trim()
+                .parse::<f64>()
+                .map(ZeroTermPolicy::DropBelowThreshold)
+                .map_err(|err| StruqtureError::FromStringFailed {
+                    msg: format!("Invalid threshold {} of zero-term policy: {}", threshold, err),
+                }),
+            _ => Err(StruqtureError::FromStringFailed {
+                msg: format!(
+                    "Unknown zero-term policy {}, expected one of drop_exact_zeros, drop_below_threshold:<threshold>",
+                    s
+                ),
+            }),
+        }
+    }
+}
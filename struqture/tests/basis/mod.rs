@@ -0,0 +1,108 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the basis enumeration
+
+use struqture::basis::{
+    binomial, fixed_particle_number_states, fock_states, fock_states_with_particle_number,
+    rank_fixed_particle_number_state, rank_fock_state, spin_z_sector_states,
+    unrank_fixed_particle_number_state, unrank_fock_state,
+};
+use struqture::StruqtureError;
+use test_case::test_case;
+
+#[test_case(0, 0, 1; "0 choose 0")]
+#[test_case(5, 2, 10; "5 choose 2")]
+#[test_case(5, 6, 0; "5 choose 6")]
+#[test_case(62, 31, 465428353255261088; "62 choose 31")]
+fn binomial_coefficients(n: usize, k: usize, expected: usize) {
+    assert_eq!(binomial(n, k).unwrap(), expected);
+}
+
+// Test the enumeration, ranking and unranking of all fixed particle number sectors of 6 modes
+#[test]
+fn fixed_particle_number_sectors() {
+    let number_modes = 6;
+    for number_particles in 0..=number_modes {
+        let states: Vec<usize> = fixed_particle_number_states(number_modes, number_particles)
+            .unwrap()
+            .collect();
+        let expected: Vec<usize> = (0..1 << number_modes)
+            .filter(|state: &usize| state.count_ones() as usize == number_particles)
+            .collect();
+        assert_eq!(states, expected);
+        assert_eq!(
+            states.len(),
+            binomial(number_modes, number_particles).unwrap()
+        );
+        for (rank, state) in states.iter().enumerate() {
+            assert_eq!(rank_fixed_particle_number_state(*state), rank);
+            assert_eq!(
+                unrank_fixed_particle_number_state(rank, number_modes, number_particles).unwrap(),
+                *state
+            );
+        }
+        assert!(
+            unrank_fixed_particle_number_state(states.len(), number_modes, number_particles)
+                .is_err()
+        );
+    }
+    assert!(fixed_particle_number_states(3, 4).is_err());
+    assert!(fixed_particle_number_states(usize::BITS as usize, 1).is_err());
+}
+
+#[test]
+fn spin_z_sectors() {
+    assert_eq!(
+        spin_z_sector_states(3, 1).unwrap().collect::<Vec<usize>>(),
+        vec![0b001, 0b010, 0b100]
+    );
+    assert_eq!(
+        spin_z_sector_states(3, -3).unwrap().collect::<Vec<usize>>(),
+        vec![0b111]
+    );
+    assert!(spin_z_sector_states(3, 0).is_err());
+    assert!(spin_z_sector_states(3, 5).is_err());
+}
+
+#[test]
+fn fock_sectors() {
+    let cutoffs = [2, 3];
+    let states: Vec<Vec<usize>> = fock_states(&cutoffs).collect();
+    assert_eq!(
+        states,
+        vec![
+            vec![0, 0],
+            vec![1, 0],
+            vec![0, 1],
+            vec![1, 1],
+            vec![0, 2],
+            vec![1, 2]
+        ]
+    );
+    for (index, state) in states.iter().enumerate() {
+        assert_eq!(rank_fock_state(state, &cutoffs).unwrap(), index);
+        assert_eq!(unrank_fock_state(index, &cutoffs).unwrap(), *state);
+    }
+    assert!(unrank_fock_state(6, &cutoffs).is_err());
+    assert!(rank_fock_state(&[2, 0], &cutoffs).is_err());
+    assert_eq!(
+        rank_fock_state(&[0], &cutoffs),
+        Err(StruqtureError::MissmatchedNumberModes)
+    );
+
+    assert_eq!(
+        fock_states_with_particle_number(&cutoffs, 2).collect::<Vec<Vec<usize>>>(),
+        vec![vec![1, 1], vec![0, 2]]
+    );
+    assert_eq!(fock_states(&[]).collect::<Vec<Vec<usize>>>(), vec![vec![]]);
+}
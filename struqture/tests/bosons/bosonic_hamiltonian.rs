@@ -19,7 +19,8 @@ use std::collections::BTreeMap;
 use std::iter::{FromIterator, IntoIterator};
 use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
 use struqture::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, OperatorExpression,
+    StruqtureError,
 };
 use test_case::test_case;
 
@@ -32,6 +33,37 @@ fn new() {
     assert_eq!(BosonHamiltonian::with_capacity(2), BosonHamiltonian::new());
 }
 
+// Test the expression representation of BosonHamiltonian
+#[test]
+fn expression() {
+    let bh = BosonHamiltonian::from_expression("0.5*c0a1 + 2*c1a1 + i*c0a1").unwrap();
+    assert_eq!(bh.len(), 2);
+    assert_eq!(
+        bh.get(&HermitianBosonProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::new(0.5, 1.0)
+    );
+    let expression = bh.to_expression();
+    assert_eq!(expression, "0.5*c0a1 + 1.0*i*c0a1 + 2.0*c1a1");
+    assert_eq!(BosonHamiltonian::from_expression(&expression).unwrap(), bh);
+    assert!(BosonHamiltonian::from_expression("i*c1a1").is_err());
+    assert!(BosonHamiltonian::from_expression("c1a0").is_err());
+}
+
+// Test the occupation_operator function of the BosonHamiltonian
+#[test]
+fn occupation_operator() {
+    let bh = BosonHamiltonian::occupation_operator([1, 3]);
+    assert_eq!(bh.len(), 2);
+    assert_eq!(
+        bh.get(&HermitianBosonProduct::number(3)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        BosonOperator::from(bh),
+        BosonOperator::occupation_operator([1, 3])
+    );
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_2: HermitianBosonProduct = HermitianBosonProduct::new([0], [2]).unwrap();
@@ -531,6 +563,101 @@ fn serde_readable() {
 }
 
 /// Test BosonHamiltonian Serialization and Deserialization traits (compact)
+// Test the normal modes of two coupled modes and of a squeezed mode
+#[test]
+fn normal_modes() {
+    let mut bh = BosonHamiltonian::occupation_operator([0, 1]) * CalculatorFloat::from(2.0);
+    bh.add_operator_product(
+        HermitianBosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    let modes = bh.normal_modes().unwrap();
+    assert_eq!(modes.frequencies.len(), 2);
+    assert!((modes.frequencies[0] - 1.5).abs() < 1e-12);
+    assert!((modes.frequencies[1] - 2.5).abs() < 1e-12);
+    assert!(
+        modes
+            .transformed_hamiltonian
+            .get(&HermitianBosonProduct::new([], []).unwrap())
+            .re
+            .float()
+            .unwrap()
+            .abs()
+            < 1e-12
+    );
+
+    // The transformation is symplectic, T \eta T^dagger = \eta
+    let transformation = &modes.transformation;
+    assert_eq!(transformation.dim(), (4, 4));
+    for row in 0..4 {
+        for column in 0..4 {
+            let entry: num_complex::Complex64 = (0..4)
+                .map(|k| {
+                    let sign = if k < 2 { 1.0 } else { -1.0 };
+                    transformation[[row, k]] * transformation[[column, k]].conj() * sign
+                })
+                .sum();
+            let expected = match (row == column, row < 2) {
+                (false, _) => 0.0,
+                (true, true) => 1.0,
+                (true, false) => -1.0,
+            };
+            assert!((entry - expected).norm() < 1e-12);
+        }
+    }
+
+    // 2 b^dagger b + 0.5 (b b + b^dagger b^dagger) has the frequency sqrt(2^2 - 4 * 0.5^2)
+    let mut bh = BosonHamiltonian::occupation_operator([0]) * CalculatorFloat::from(2.0);
+    bh.add_operator_product(
+        HermitianBosonProduct::new([], [0, 0]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    let modes = bh.normal_modes().unwrap();
+    let frequency = 3.0_f64.sqrt();
+    assert!((modes.frequencies[0] - frequency).abs() < 1e-12);
+    let transformed = modes.transformed_hamiltonian;
+    assert_eq!(transformed.len(), 2);
+    let number = transformed.get(&HermitianBosonProduct::number(0));
+    assert!((number.re.float().unwrap() - frequency).abs() < 1e-12);
+    let constant = transformed.get(&HermitianBosonProduct::new([], []).unwrap());
+    assert!((constant.re.float().unwrap() - (frequency - 2.0) / 2.0).abs() < 1e-12);
+}
+
+// Test the failure of the normal mode computation for unsupported Hamiltonians
+#[test]
+fn normal_modes_errors() {
+    let mut bh = BosonHamiltonian::occupation_operator([0]);
+    bh.add_operator_product(
+        HermitianBosonProduct::new([], [0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert!(matches!(
+        bh.normal_modes(),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    // The squeezing exceeds the frequency and the Hamiltonian is unstable
+    let mut bh = BosonHamiltonian::occupation_operator([0]);
+    bh.add_operator_product(
+        HermitianBosonProduct::new([], [0, 0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert!(matches!(
+        bh.normal_modes(),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    let bh = BosonHamiltonian::occupation_operator([0]) * CalculatorFloat::from("omega");
+    assert!(matches!(
+        bh.normal_modes(),
+        Err(StruqtureError::CalculatorError(_))
+    ));
+}
+
 #[test]
 fn bincode() {
     let pp = HermitianBosonProduct::new([0], [1]).unwrap();
@@ -561,7 +688,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -586,6 +713,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
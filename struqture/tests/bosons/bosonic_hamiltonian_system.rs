@@ -653,7 +653,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonHamiltonianSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -661,7 +661,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "BosonHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -686,6 +686,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -697,6 +702,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
@@ -503,7 +503,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -536,6 +536,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -551,6 +556,35 @@ fn serde_compact() {
     );
 }
 
+#[test]
+fn thermal_bath() {
+    let noise = BosonLindbladNoiseOperator::thermal_bath(&[0.0, 0.5], &[1.0, 2.0]).unwrap();
+    let annihilator_0 = BosonProduct::new([], [0]).unwrap();
+    let annihilator_1 = BosonProduct::new([], [1]).unwrap();
+    let creator_1 = BosonProduct::new([1], []).unwrap();
+
+    let mut expected = BosonLindbladNoiseOperator::new();
+    expected
+        .set(
+            (annihilator_0.clone(), annihilator_0),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .set(
+            (annihilator_1.clone(), annihilator_1),
+            CalculatorComplex::from(3.0),
+        )
+        .unwrap();
+    expected
+        .set((creator_1.clone(), creator_1), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(noise, expected);
+
+    assert!(BosonLindbladNoiseOperator::thermal_bath(&[0.0], &[1.0, 1.0]).is_err());
+    assert!(BosonLindbladNoiseOperator::thermal_bath(&[-0.1], &[1.0]).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_noise_operator_schema() {
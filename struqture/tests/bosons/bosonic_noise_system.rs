@@ -560,7 +560,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonLindbladNoiseSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -568,7 +568,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "BosonLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -601,6 +601,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -612,11 +617,35 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+#[test]
+fn is_completely_positive() {
+    let annihilator = BosonProduct::new([], [0]).unwrap();
+    let mut system = BosonLindbladNoiseSystem::new(Some(1));
+    system
+        .set(
+            (annihilator.clone(), annihilator.clone()),
+            CalculatorComplex::from(0.1),
+        )
+        .unwrap();
+    assert!(system.is_completely_positive(1e-10).unwrap());
+
+    system
+        .set(
+            (annihilator.clone(), annihilator),
+            CalculatorComplex::from(-0.1),
+        )
+        .unwrap();
+    assert!(!system.is_completely_positive(1e-10).unwrap());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
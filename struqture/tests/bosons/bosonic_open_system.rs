@@ -633,7 +633,7 @@ fn serde_compact() {
             Token::Str("system"),
             Token::Struct {
                 name: "BosonHamiltonianSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -641,7 +641,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "BosonHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -666,6 +666,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -677,11 +682,14 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::Str("noise"),
             Token::Struct {
                 name: "BosonLindbladNoiseSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -689,7 +697,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "BosonLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -722,6 +730,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -733,6 +746,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::StructEnd,
         ],
@@ -15,7 +15,8 @@
 use bincode::{deserialize, serialize};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::f64::consts::FRAC_1_SQRT_2;
 use std::iter::{FromIterator, IntoIterator};
 use struqture::bosons::{BosonHamiltonian, BosonOperator, BosonProduct, HermitianBosonProduct};
 use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState};
@@ -529,7 +530,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -554,6 +555,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -569,6 +575,145 @@ fn serde_compact() {
     );
 }
 
+// Test the commutation check of BosonOperator with a BosonHamiltonian
+#[test]
+fn commutes_with() {
+    let mut number = BosonOperator::new();
+    number
+        .add_operator_product(BosonProduct::new([0], [0]).unwrap(), 1.0.into())
+        .unwrap();
+    number
+        .add_operator_product(BosonProduct::new([1], [1]).unwrap(), 1.0.into())
+        .unwrap();
+
+    let mut hamiltonian = BosonHamiltonian::new();
+    hamiltonian
+        .add_operator_product(HermitianBosonProduct::new([0], [1]).unwrap(), "t".into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(
+            HermitianBosonProduct::new([0, 0], [0, 0]).unwrap(),
+            0.5.into(),
+        )
+        .unwrap();
+    assert!(number.commutes_with(&hamiltonian));
+    assert_eq!(number.symmetry_violation_norm(&hamiltonian), Ok(0.0));
+
+    // [N, 0.5 (a0 a1 + c0 c1)] = - a0 a1 + c0 c1
+    hamiltonian
+        .add_operator_product(HermitianBosonProduct::new([], [0, 1]).unwrap(), 0.5.into())
+        .unwrap();
+    assert!(!number.commutes_with(&hamiltonian));
+    assert_eq!(number.symmetry_violation_norm(&hamiltonian), Ok(2.0));
+}
+
+// Test the particle number statistics of the BosonOperator
+#[test]
+fn particle_number_sectors() {
+    let mut op = BosonOperator::new();
+    assert_eq!(op.max_particle_number_change(), 0);
+    assert!(op.particle_number_sectors().is_empty());
+
+    op.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(op.max_particle_number_change(), 0);
+    assert_eq!(op.particle_number_sectors(), BTreeSet::from([0]));
+
+    op.add_operator_product(
+        BosonProduct::new([0, 1], []).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    op.add_operator_product(
+        BosonProduct::new([], [2]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    assert_eq!(op.max_particle_number_change(), 2);
+    assert_eq!(op.particle_number_sectors(), BTreeSet::from([-1, 0, 2]));
+}
+
+// Test the displacement and squeezing generators and the quadratures
+#[test]
+fn continuous_variable_operators() {
+    let displacement = BosonOperator::displacement_generator(1, CalculatorComplex::new(1.0, 2.0));
+    assert_eq!(
+        displacement.get(&BosonProduct::new([1], []).unwrap()),
+        &CalculatorComplex::new(1.0, 2.0)
+    );
+    assert_eq!(
+        displacement.get(&BosonProduct::new([], [1]).unwrap()),
+        &CalculatorComplex::new(-1.0, 2.0)
+    );
+    assert_eq!(displacement.hermitian_conjugate(), -displacement.clone());
+
+    let squeezing = BosonOperator::squeezing_generator(0, "r");
+    assert_eq!(
+        squeezing.get(&BosonProduct::new([], [0, 0]).unwrap()),
+        &(CalculatorComplex::from("r") * 0.5)
+    );
+    assert_eq!(
+        squeezing.get(&BosonProduct::new([0, 0], []).unwrap()),
+        &(CalculatorComplex::from("r") * -0.5)
+    );
+    assert!(BosonOperator::displacement_generator(0, 0.0).is_empty());
+
+    let x = BosonOperator::x(2);
+    let p = BosonOperator::p(2);
+    assert!(x.is_hermitian());
+    assert!(p.is_hermitian());
+    let commutator = (x.clone() * p.clone() - p * x).truncate(1e-12);
+    assert_eq!(commutator.len(), 1);
+    let value = commutator.get(&BosonProduct::new([], []).unwrap());
+    assert!(value.re.float().unwrap().abs() < 1e-12);
+    assert!((value.im.float().unwrap() - 1.0).abs() < 1e-12);
+}
+
+// Test the fourier_transform function of the BosonOperator
+#[test]
+fn fourier_transform() {
+    // The total number of bosons is unchanged
+    let bo = BosonOperator::occupation_operator(0..2);
+    assert_eq!(bo.fourier_transform(2).unwrap(), bo);
+
+    let mut creator = BosonOperator::new();
+    creator
+        .add_operator_product(BosonProduct::new([0], []).unwrap(), 1.0.into())
+        .unwrap();
+    let transformed = creator.fourier_transform(2).unwrap();
+    assert_eq!(transformed.len(), 2);
+    for mode in 0..2 {
+        let value = transformed.get(&BosonProduct::new([mode], []).unwrap());
+        assert!((f64::try_from(value.re.clone()).unwrap() - FRAC_1_SQRT_2).abs() < 1e-15);
+        assert_eq!(value.im, CalculatorFloat::from(0.0));
+    }
+
+    // Products of the same bosonic mode are kept
+    let mut squeezing = BosonOperator::new();
+    squeezing
+        .add_operator_product(BosonProduct::new([], [0, 0]).unwrap(), 1.0.into())
+        .unwrap();
+    let transformed = squeezing.fourier_transform(2).unwrap();
+    assert_eq!(transformed.len(), 3);
+    assert_eq!(
+        transformed.get(&BosonProduct::new([], [0, 0]).unwrap()),
+        &CalculatorComplex::from(0.5)
+    );
+    assert_eq!(
+        transformed.get(&BosonProduct::new([], [0, 1]).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        transformed.get(&BosonProduct::new([], [1, 1]).unwrap()),
+        &CalculatorComplex::from(0.5)
+    );
+
+    assert!(bo.fourier_transform(0).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_boson_operator_schema() {
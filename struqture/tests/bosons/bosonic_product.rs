@@ -34,6 +34,24 @@ fn default() {
     );
 }
 
+// Test the number operator constructor and the density term predicate
+#[test]
+fn number_and_density_term() {
+    let number = BosonProduct::number(3);
+    assert_eq!(number, BosonProduct::new([3], [3]).unwrap());
+    assert!(number.is_density_term());
+    assert!(BosonProduct::new([0, 0, 1], [0, 0, 1])
+        .unwrap()
+        .is_density_term());
+    assert!(!BosonProduct::new([0], [1]).unwrap().is_density_term());
+    assert!(!BosonProduct::new([0, 0], [0]).unwrap().is_density_term());
+    assert!(!BosonProduct::new([], []).unwrap().is_density_term());
+    assert_eq!(
+        HermitianBosonProduct::number(1),
+        HermitianBosonProduct::new([1], [1]).unwrap()
+    );
+}
+
 #[test]
 fn test_remap_modes_passing() {
     let bp = BosonProduct::new([0, 1], []).unwrap();
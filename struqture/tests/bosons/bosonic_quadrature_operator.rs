@@ -0,0 +1,193 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of QuadratureProduct and BosonQuadratureOperator
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::str::FromStr;
+use struqture::bosons::{BosonOperator, BosonProduct, BosonQuadratureOperator, QuadratureProduct};
+use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes};
+
+// Test the string representation of QuadratureProducts
+#[test]
+fn product_from_string() {
+    let qp = QuadratureProduct::new().x(2).p(0).x(2).p(2);
+    assert_eq!(qp.powers(2), (2, 1));
+    assert_eq!(qp.powers(1), (0, 0));
+    assert_eq!(qp.degree(), 4);
+    assert_eq!(qp.current_number_modes(), 3);
+    assert_eq!(format!("{}", qp), "0P2X2X2P");
+    assert_eq!(QuadratureProduct::from_str("2X0P2X2P").unwrap(), qp);
+    assert_eq!(
+        QuadratureProduct::from_str("I").unwrap(),
+        QuadratureProduct::new()
+    );
+    assert!(QuadratureProduct::from_str("0P0X").is_err());
+    assert!(QuadratureProduct::from_str("0Y").is_err());
+    assert!(QuadratureProduct::from_str("X").is_err());
+    assert!(QuadratureProduct::from_str("0X1").is_err());
+    assert_eq!(
+        QuadratureProduct::new()
+            .set_powers(0, 1, 1)
+            .set_powers(0, 0, 0),
+        QuadratureProduct::new()
+    );
+}
+
+// Test the standard ordering of products of quadratures
+#[test]
+fn product_multiply() {
+    // p x = x p - i
+    let result = QuadratureProduct::multiply(
+        QuadratureProduct::new().p(0),
+        QuadratureProduct::new().x(0).x(1),
+    );
+    assert_eq!(
+        result,
+        vec![
+            (
+                QuadratureProduct::new().x(0).p(0).x(1),
+                Complex64::new(1.0, 0.0)
+            ),
+            (QuadratureProduct::new().x(1), Complex64::new(0.0, -1.0)),
+        ]
+    );
+
+    // p^2 x^2 = x^2 p^2 - 4i x p - 2
+    let result = QuadratureProduct::multiply(
+        QuadratureProduct::new().p(0).p(0),
+        QuadratureProduct::new().x(0).x(0),
+    );
+    assert_eq!(
+        result,
+        vec![
+            (
+                QuadratureProduct::new().set_powers(0, 2, 2),
+                Complex64::new(1.0, 0.0)
+            ),
+            (
+                QuadratureProduct::new().set_powers(0, 1, 1),
+                Complex64::new(0.0, -4.0)
+            ),
+            (QuadratureProduct::new(), Complex64::new(-2.0, 0.0)),
+        ]
+    );
+}
+
+// Test the conversion of the number operator into quadratures
+#[test]
+fn number_operator_conversion() {
+    let number = BosonOperator::occupation_operator([0]);
+    let quadrature = BosonQuadratureOperator::from(number.clone());
+
+    // b^dagger b = (x^2 + p^2 - 1) / 2
+    let mut expected = BosonQuadratureOperator::new();
+    expected
+        .add_operator_product(
+            QuadratureProduct::new().x(0).x(0),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            QuadratureProduct::new().p(0).p(0),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(QuadratureProduct::new(), CalculatorComplex::from(-0.5))
+        .unwrap();
+    assert_eq!(quadrature, expected);
+    assert_eq!(BosonOperator::from(quadrature), number);
+}
+
+// Test that the conversion to quadratures and back is exact
+#[test]
+fn roundtrip_conversion() {
+    let mut bo = BosonOperator::new();
+    bo.add_operator_product(
+        BosonProduct::new([0, 0], []).unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(3.0),
+    )
+    .unwrap();
+    bo.add_operator_product(
+        BosonProduct::new([1], [0, 0, 1]).unwrap(),
+        CalculatorComplex::from(-1.0),
+    )
+    .unwrap();
+    let quadrature = BosonQuadratureOperator::from(bo.clone());
+    assert_eq!(quadrature.current_number_modes(), 2);
+    assert_eq!(BosonOperator::from(quadrature), bo);
+
+    // x = (b + b^dagger) / sqrt(2)
+    let x = BosonQuadratureOperator::from(BosonOperator::x(1));
+    assert_eq!(x.len(), 1);
+    let value = x.get(&QuadratureProduct::new().x(1));
+    assert!((value.re.float().unwrap() - 1.0).abs() < 1e-12);
+    assert!(value.im.float().unwrap().abs() < 1e-12);
+}
+
+// Test the multiplication and hermitian conjugate of BosonQuadratureOperators
+#[test]
+fn multiply_and_conjugate() {
+    let mut x = BosonQuadratureOperator::new();
+    x.add_operator_product(QuadratureProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut p = BosonQuadratureOperator::new();
+    p.add_operator_product(QuadratureProduct::new().p(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    // [x, p] = i
+    let commutator = x.clone() * p.clone() - p.clone() * x.clone();
+    assert_eq!(commutator.len(), 1);
+    assert_eq!(
+        commutator.get(&QuadratureProduct::new()),
+        &CalculatorComplex::new(0.0, 1.0)
+    );
+
+    // (x p)^dagger = p x = x p - i
+    let conjugated = (x * p).hermitian_conjugate();
+    assert_eq!(conjugated.len(), 2);
+    assert_eq!(
+        conjugated.get(&QuadratureProduct::new().x(0).p(0)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        conjugated.get(&QuadratureProduct::new()),
+        &CalculatorComplex::new(0.0, -1.0)
+    );
+}
+
+// Test the serialization and deserialization of BosonQuadratureOperator
+#[test]
+fn serde_roundtrip() {
+    let mut bqo = BosonQuadratureOperator::new();
+    bqo.add_operator_product(
+        QuadratureProduct::new().x(0).x(0).p(0).p(3),
+        CalculatorComplex::new(1.0, "theta"),
+    )
+    .unwrap();
+    let serialized = serde_json::to_string(&bqo).unwrap();
+    assert!(serialized.contains("0X0X0P3P"));
+    let deserialized: BosonQuadratureOperator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(bqo, deserialized);
+
+    let encoded: Vec<u8> = bincode::serialize(&bqo).unwrap();
+    let decoded: BosonQuadratureOperator = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(bqo, decoded);
+}
@@ -20,6 +20,7 @@ use std::iter::{FromIterator, IntoIterator};
 use struqture::bosons::{BosonOperator, BosonProduct, BosonSystem};
 use struqture::{
     ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+    SystemDimension,
 };
 use test_case::test_case;
 
@@ -622,7 +623,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "BosonSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -630,7 +631,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "BosonOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -655,6 +656,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -666,11 +672,35 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Test the SystemDimension functions of the BosonSystem
+#[test]
+fn system_dimension() {
+    let mut system = BosonSystem::new(Some(2));
+    system
+        .add_operator_product(
+            BosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    assert_eq!(system.hilbert_space_dimension(&[3, 4]).unwrap(), 12);
+    assert_eq!(system.superoperator_dimension(&[3, 4]).unwrap(), 144);
+    assert_eq!(system.estimated_matrix_memory(&[3, 4], 16).unwrap(), 192);
+    assert_eq!(
+        system.hilbert_space_dimension(&[3]),
+        Err(StruqtureError::MissmatchedNumberModes)
+    );
+    assert!(system.hilbert_space_dimension(&[usize::MAX, 2]).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
@@ -17,5 +17,6 @@ mod bosonic_noise_system;
 mod bosonic_open_system;
 mod bosonic_operator;
 mod bosonic_product;
+mod bosonic_quadrature_operator;
 mod bosonic_system;
 mod hermitian_bosonic_product;
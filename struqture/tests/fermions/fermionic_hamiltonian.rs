@@ -13,15 +13,18 @@
 //! Integration test for public API of FermionHamiltonian
 
 use bincode::{deserialize, serialize};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::iter::{FromIterator, IntoIterator};
 use struqture::fermions::{
-    FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
+    FermionHamiltonian, FermionHamiltonianSystem, FermionOperator, FermionProduct,
+    HermitianFermionProduct,
 };
 use struqture::{
-    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, StruqtureError,
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, SpectralNormBound,
+    StruqtureError,
 };
 use test_case::test_case;
 
@@ -37,6 +40,30 @@ fn new() {
     );
 }
 
+// Test the constant term of FermionHamiltonian
+#[test]
+fn constant() {
+    let mut fh = FermionHamiltonian::new();
+    assert_eq!(fh.identity_index(), HermitianFermionProduct::default());
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 1.0),
+    )
+    .unwrap();
+    fh.add_constant(CalculatorComplex::from(0.5)).unwrap();
+    fh.add_constant(CalculatorComplex::from(0.25)).unwrap();
+    assert_eq!(fh.constant(), &CalculatorComplex::from(0.75));
+    assert_eq!(fh.without_constant().len(), 1);
+
+    let difference = (fh.clone() - fh.without_constant()).unwrap();
+    assert_eq!(difference.len(), 1);
+    assert_eq!(difference.constant(), &CalculatorComplex::from(0.75));
+
+    let serialized = serialize(&fh).unwrap();
+    let deserialized: FermionHamiltonian = deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.constant(), &CalculatorComplex::from(0.75));
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_2: HermitianFermionProduct = HermitianFermionProduct::new([0], [2]).unwrap();
@@ -191,6 +218,219 @@ fn hermitian_test() {
 
     assert_eq!(system.hermitian_conjugate(), system.clone());
 }
+
+// Test the mean-field decoupling of the FermionHamiltonian
+#[test]
+fn mean_field_decouple() {
+    let mut hamiltonian = FermionHamiltonian::new();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1], [0, 2]).unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    let averages = HashMap::from([
+        (
+            FermionProduct::new([0], [0]).unwrap(),
+            Complex64::new(1.0, 0.0),
+        ),
+        (
+            FermionProduct::new([1], [2]).unwrap(),
+            Complex64::new(0.25, 0.0),
+        ),
+        (
+            FermionProduct::new([2], [1]).unwrap(),
+            Complex64::new(0.25, 0.0),
+        ),
+    ]);
+
+    // (1 + i) (-c^†_1 c_2 - 0.25 n_0 + 0.25) + h.c. + 2 c^†_0 c_1 + h.c.
+    let mean_field = hamiltonian.mean_field_decouple(&averages).unwrap();
+    let mut expected = FermionHamiltonian::new();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([1], [2]).unwrap(),
+            CalculatorComplex::new(-1.0, -1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(-0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert!(mean_field.approx_eq(&expected, 1e-12, 0.0));
+
+    // The mean-field Hamiltonian reproduces the energy in a state with the same averages
+    let mut interaction = FermionHamiltonian::new();
+    interaction
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(),
+            CalculatorComplex::from(3.0),
+        )
+        .unwrap();
+    let occupation = [1.0, 0.5];
+    let diagonal_averages = HashMap::from([
+        (
+            FermionProduct::new([0], [0]).unwrap(),
+            Complex64::new(1.0, 0.0),
+        ),
+        (
+            FermionProduct::new([1], [1]).unwrap(),
+            Complex64::new(0.5, 0.0),
+        ),
+    ]);
+    let mean_field = interaction.mean_field_decouple(&diagonal_averages).unwrap();
+    assert_eq!(
+        mean_field
+            .to_operator()
+            .wick_expectation(&occupation)
+            .unwrap(),
+        interaction
+            .to_operator()
+            .wick_expectation(&occupation)
+            .unwrap()
+    );
+
+    let invalid_averages = HashMap::from([(
+        FermionProduct::new([0, 1], [0, 1]).unwrap(),
+        Complex64::new(1.0, 0.0),
+    )]);
+    assert!(hamiltonian.mean_field_decouple(&invalid_averages).is_err());
+    let mut three_body = FermionHamiltonian::new();
+    three_body
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1, 2], [0, 1, 2]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert!(three_body.mean_field_decouple(&averages).is_err());
+
+    let system = FermionHamiltonianSystem::from_hamiltonian(hamiltonian, Some(4)).unwrap();
+    let mean_field_system = system.mean_field_decouple(&averages).unwrap();
+    assert_eq!(mean_field_system.number_modes(), 4);
+    assert!(mean_field_system
+        .hamiltonian()
+        .approx_eq(&expected, 1e-12, 0.0));
+}
+
+// Test the split_two_body_terms function of the FermionHamiltonian
+#[test]
+fn split_two_body_terms() {
+    let mut hamiltonian = FermionHamiltonian::new();
+    let terms: [(&[usize], &[usize], CalculatorComplex); 6] = [
+        (&[0], &[1], CalculatorComplex::from(-1.0)),
+        (&[2], &[2], CalculatorComplex::from(0.5)),
+        (&[0, 1], &[0, 1], CalculatorComplex::from(-2.0)),
+        (&[0, 1], &[2, 3], CalculatorComplex::new(1.0, 0.5)),
+        (&[0, 2], &[0, 3], CalculatorComplex::from(0.3)),
+        (&[], &[0, 1], CalculatorComplex::from(0.2)),
+    ];
+    for (creators, annihilators, value) in terms {
+        hamiltonian
+            .add_operator_product(
+                HermitianFermionProduct::new(
+                    creators.iter().copied(),
+                    annihilators.iter().copied(),
+                )
+                .unwrap(),
+                value,
+            )
+            .unwrap();
+    }
+
+    let (factors, remainder) = hamiltonian.split_two_body_terms().unwrap();
+    assert!(!factors.is_empty());
+    let mut reconstructed = remainder.to_operator();
+    for (weight, quadratic) in factors.iter() {
+        assert!(quadratic
+            .keys()
+            .all(|key| key.number_creators() == 1 && key.number_annihilators() == 1));
+        reconstructed = reconstructed + quadratic.to_operator() * quadratic.to_operator() * *weight;
+    }
+    assert!(reconstructed.approx_eq(&hamiltonian.to_operator(), 1e-10, 0.0));
+    // The remainder contains the one-body terms and the term not conserving the particle number
+    assert!(remainder
+        .keys()
+        .all(|key| key.number_creators() + key.number_annihilators() <= 2));
+    assert_eq!(
+        remainder.get(&HermitianFermionProduct::new([], [0, 1]).unwrap()),
+        &CalculatorComplex::from(0.2)
+    );
+
+    // Hamiltonians without two-particle terms are the remainder
+    let mut hopping = FermionHamiltonian::new();
+    hopping
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let (factors, remainder) = hopping.split_two_body_terms().unwrap();
+    assert!(factors.is_empty());
+    assert_eq!(remainder, hopping);
+
+    let mut symbolic = FermionHamiltonian::new();
+    symbolic
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(),
+            CalculatorComplex::from("U"),
+        )
+        .unwrap();
+    assert!(symbolic.split_two_body_terms().is_err());
+}
+
+// Test the qdrift_sampler function of the FermionHamiltonian
+#[test]
+fn qdrift_sampler() {
+    let mut hamiltonian = FermionHamiltonian::new();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.0, 2.0),
+        )
+        .unwrap();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([1], [1]).unwrap(),
+            CalculatorComplex::from(-2.0),
+        )
+        .unwrap();
+
+    let sampler = hamiltonian.qdrift_sampler(3).unwrap();
+    assert_eq!(sampler.lambda(), 4.0);
+    let mut number_hopping = 0;
+    for (product, phase) in sampler.take(10000) {
+        if product == HermitianFermionProduct::new([0], [1]).unwrap() {
+            assert_eq!(phase, Complex64::new(0.0, 1.0));
+            number_hopping += 1;
+        } else {
+            assert_eq!(product, HermitianFermionProduct::new([1], [1]).unwrap());
+            assert_eq!(phase, Complex64::new(-1.0, 0.0));
+        }
+    }
+    assert!((number_hopping as f64 / 10000.0 - 0.5).abs() < 0.03);
+
+    assert!(FermionHamiltonian::new().qdrift_sampler(3).is_err());
+}
 // Test the separation of terms
 #[test_case((1, 1))]
 #[test_case((1, 2))]
@@ -579,7 +819,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "FermionHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -604,6 +844,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -619,6 +864,29 @@ fn serde_compact() {
     );
 }
 
+// Test that the spectral norm bound of FermionHamiltonian counts hermitian conjugates
+#[test]
+fn spectral_norm_bound() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(0.5, 0.0),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::new(-2.0, 0.0),
+    )
+    .unwrap();
+    assert_eq!(fh.coefficient_1_norm().unwrap(), 2.5);
+    assert_eq!(fh.coefficient_max_norm().unwrap(), 2.0);
+    assert_eq!(fh.spectral_norm_bound().unwrap(), 3.0);
+    assert_eq!(
+        FermionOperator::from(fh).spectral_norm_bound().unwrap(),
+        3.0
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_hamiltonian_schema() {
@@ -599,6 +599,100 @@ fn serde_readable() {
     );
 }
 
+// Test the construction of hopping FermionHamiltonianSystems from edge lists
+#[test]
+fn hopping_from_edges() {
+    let edges = [
+        (0, 1, CalculatorComplex::new(1.0, 2.0)),
+        (2, 1, CalculatorComplex::new(1.0, 2.0)),
+        (2, 2, CalculatorComplex::from("mu")),
+    ];
+    let system = FermionHamiltonianSystem::hopping_from_edges(&edges).unwrap();
+    assert_eq!(system.len(), 3);
+    assert_eq!(system.number_modes(), 3);
+    assert_eq!(
+        system.get(&HermitianFermionProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::new(1.0, 2.0)
+    );
+    // t c_2^dagger c_1 is stored as its hermitian conjugate t^* c_1^dagger c_2
+    assert_eq!(
+        system.get(&HermitianFermionProduct::new([1], [2]).unwrap()),
+        &CalculatorComplex::new(1.0, -2.0)
+    );
+    assert_eq!(
+        system.get(&HermitianFermionProduct::new([2], [2]).unwrap()),
+        &CalculatorComplex::from("mu")
+    );
+
+    let on_site = [(0, 0, CalculatorComplex::new(0.0, 1.0))];
+    assert_eq!(
+        FermionHamiltonianSystem::hopping_from_edges(&on_site),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+}
+
+// Test the reduction to an active space with a frozen core
+#[test]
+fn freeze_core_and_restrict() {
+    let mut system = FermionHamiltonianSystem::new(Some(4));
+    let terms: [(&[usize], &[usize], CalculatorComplex); 8] = [
+        (&[0], &[0], 1.0.into()),
+        (&[1], &[1], 2.0.into()),
+        (&[1], &[2], CalculatorComplex::new(1.0, 1.0)),
+        (&[0, 1], &[0, 2], (-3.0).into()),
+        (&[0, 1], &[0, 1], (-0.5).into()),
+        (&[3], &[3], 5.0.into()),
+        (&[0], &[3], 7.0.into()),
+        (&[0, 3], &[0, 3], 11.0.into()),
+    ];
+    for (creators, annihilators, value) in terms {
+        system
+            .add_operator_product(
+                HermitianFermionProduct::new(creators.to_vec(), annihilators.to_vec()).unwrap(),
+                value,
+            )
+            .unwrap();
+    }
+
+    let reduced = system.freeze_core_and_restrict(&[1, 2], &[0]).unwrap();
+    assert_eq!(reduced.number_modes(), 2);
+    assert_eq!(reduced.len(), 3);
+    assert_eq!(
+        reduced.get(&HermitianFermionProduct::new([], []).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        reduced.get(&HermitianFermionProduct::new([0], [0]).unwrap()),
+        &CalculatorComplex::from(2.5)
+    );
+    assert_eq!(
+        reduced.get(&HermitianFermionProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::new(4.0, 1.0)
+    );
+
+    // Reversing the active orbitals turns the hopping term into its hermitian conjugate
+    let reversed = system.freeze_core_and_restrict(&[2, 1], &[0]).unwrap();
+    assert_eq!(
+        reversed.get(&HermitianFermionProduct::new([1], [1]).unwrap()),
+        &CalculatorComplex::from(2.5)
+    );
+    assert_eq!(
+        reversed.get(&HermitianFermionProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::new(4.0, -1.0)
+    );
+
+    // Freezing both orbitals of the two-body term leaves the sum of all diagonal terms
+    let energy = system.freeze_core_and_restrict(&[], &[0, 1]).unwrap();
+    assert_eq!(energy.len(), 1);
+    assert_eq!(
+        energy.get(&HermitianFermionProduct::new([], []).unwrap()),
+        &CalculatorComplex::from(3.5)
+    );
+
+    assert!(system.freeze_core_and_restrict(&[1, 2], &[2]).is_err());
+    assert!(system.freeze_core_and_restrict(&[1, 1], &[0]).is_err());
+}
+
 /// Test FermionHamiltonianSystem Serialization and Deserialization traits (compact)
 #[test]
 fn bincode() {
@@ -630,7 +724,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "FermionHamiltonianSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -638,7 +732,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "FermionHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -663,6 +757,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -674,11 +773,50 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Test the canonical serialization of FermionHamiltonianSystem
+#[cfg(feature = "io")]
+#[test]
+fn serialize_canonical() {
+    let products: Vec<HermitianFermionProduct> = (0..10)
+        .map(|index| HermitianFermionProduct::new([index], [index + 1]).unwrap())
+        .collect();
+    let mut forward = FermionHamiltonianSystem::new(None);
+    for (index, product) in products.iter().enumerate() {
+        forward
+            .add_operator_product(product.clone(), CalculatorComplex::new(index as f64, 0.5))
+            .unwrap();
+    }
+    let mut backward = FermionHamiltonianSystem::new(None);
+    for (index, product) in products.iter().enumerate().rev() {
+        backward
+            .add_operator_product(product.clone(), CalculatorComplex::new(index as f64, 0.5))
+            .unwrap();
+    }
+
+    let json = forward.to_json_canonical().unwrap();
+    assert_eq!(json, backward.to_json_canonical().unwrap());
+    assert_eq!(
+        forward.to_bincode_canonical().unwrap(),
+        backward.to_bincode_canonical().unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<FermionHamiltonianSystem>(&json).unwrap(),
+        forward
+    );
+    assert_eq!(
+        deserialize::<FermionHamiltonianSystem>(&serialize(&backward).unwrap()).unwrap(),
+        backward
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
@@ -699,3 +837,76 @@ fn test_fermion_hamiltonian_system_schema(number_fermions: Option<usize>) {
 
     assert!(validation.is_ok());
 }
+
+// Test the double factorization of the two-particle terms of the FermionHamiltonianSystem
+#[test]
+fn double_factorization() {
+    use struqture::fermions::FermionOperator;
+
+    let mut system = FermionHamiltonianSystem::new(Some(4));
+    let terms: [(&[usize], &[usize], CalculatorComplex); 4] = [
+        (&[0], &[1], CalculatorComplex::from(-1.0)),
+        (&[0, 1], &[0, 1], CalculatorComplex::from(-2.0)),
+        (&[0, 1], &[2, 3], CalculatorComplex::new(1.0, 0.5)),
+        (&[0, 2], &[0, 3], CalculatorComplex::from(0.3)),
+    ];
+    for (creators, annihilators, value) in terms {
+        system
+            .add_operator_product(
+                HermitianFermionProduct::new(
+                    creators.iter().copied(),
+                    annihilators.iter().copied(),
+                )
+                .unwrap(),
+                value,
+            )
+            .unwrap();
+    }
+
+    let factorization = system.double_factorization(0.0).unwrap();
+    assert_eq!(factorization.eigenvalues.len(), factorization.weights.len());
+    assert_eq!(
+        factorization.leaf_matrices.len(),
+        factorization.weights.len()
+    );
+    assert_eq!(factorization.remainder.number_modes(), 4);
+    let mut reconstructed = factorization.remainder.hamiltonian().to_operator();
+    for ((weight, eigenvalues), rotation) in factorization
+        .weights
+        .iter()
+        .zip(factorization.eigenvalues.iter())
+        .zip(factorization.leaf_matrices.iter())
+    {
+        assert_eq!(rotation.dim(), (4, eigenvalues.len()));
+        let mut leaf = FermionOperator::new();
+        for (k, eigenvalue) in eigenvalues.iter().enumerate() {
+            for p in 0..4 {
+                for r in 0..4 {
+                    let value = rotation[(p, k)] * rotation[(r, k)].conj() * *eigenvalue;
+                    leaf.add_operator_product(
+                        FermionProduct::new([p], [r]).unwrap(),
+                        CalculatorComplex::new(value.re, value.im),
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        reconstructed = reconstructed + leaf.clone() * leaf * *weight;
+    }
+    assert!(reconstructed.approx_eq(&system.hamiltonian().to_operator(), 1e-10, 0.0));
+
+    // A larger threshold keeps fewer eigenvalues
+    let number_eigenvalues = |threshold: f64| -> usize {
+        system
+            .double_factorization(threshold)
+            .unwrap()
+            .eigenvalues
+            .iter()
+            .map(|eigenvalues| eigenvalues.len())
+            .sum()
+    };
+    assert!(number_eigenvalues(0.5) <= number_eigenvalues(0.0));
+    let empty = system.double_factorization(100.0).unwrap();
+    assert!(empty.weights.is_empty());
+    assert_eq!(empty.remainder, factorization.remainder);
+}
@@ -588,7 +588,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "FermionLindbladNoiseSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_modes"),
             Token::Some,
@@ -596,7 +596,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "FermionLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -629,6 +629,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -640,11 +645,55 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+#[test]
+fn is_completely_positive() {
+    let annihilator = FermionProduct::new([], [0]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    let mut system = FermionLindbladNoiseSystem::new(Some(1));
+    system
+        .set(
+            (annihilator.clone(), annihilator.clone()),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    system
+        .set(
+            (number.clone(), number.clone()),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    system
+        .set(
+            (annihilator.clone(), number.clone()),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    system
+        .set((number, annihilator), CalculatorComplex::from(1.0))
+        .unwrap();
+    // Rank one Kossakowski matrix with eigenvalues 0 and 2
+    assert!(system.is_completely_positive(1e-10).unwrap());
+
+    system
+        .set(
+            (
+                FermionProduct::new([], [0]).unwrap(),
+                FermionProduct::new([], [0]).unwrap(),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert!(!system.is_completely_positive(1e-10).unwrap());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
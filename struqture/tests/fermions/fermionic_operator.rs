@@ -13,14 +13,20 @@
 //! Integration test for public API of FermionOperator
 
 use bincode::{deserialize, serialize};
+use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::iter::{FromIterator, IntoIterator};
 use struqture::fermions::{
-    FermionHamiltonian, FermionOperator, FermionProduct, HermitianFermionProduct,
+    FermionHamiltonian, FermionIndex, FermionOperator, FermionProduct, HermitianFermionProduct,
+};
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::spins::ToSparseMatrixOperator;
+use struqture::{
+    ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState, OperatorExpression,
+    StruqtureError,
 };
-use struqture::{ModeIndex, OperateOnDensityMatrix, OperateOnModes, OperateOnState};
 use test_case::test_case;
 
 // Test the new function of the FermionOperator
@@ -32,6 +38,156 @@ fn new() {
     assert_eq!(FermionOperator::with_capacity(2), FermionOperator::new());
 }
 
+// Test the expression representation of FermionOperator
+#[test]
+fn expression() {
+    let fo = FermionOperator::from_expression("0.5*c0a1 - i*c1a1 + c2c3a2a3 + 3*I").unwrap();
+    assert_eq!(fo.len(), 4);
+    assert_eq!(
+        fo.get(&FermionProduct::new([1], [1]).unwrap()),
+        &CalculatorComplex::new(0.0, -1.0)
+    );
+    assert_eq!(
+        fo.get(&FermionProduct::new([2, 3], [2, 3]).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+    let expression = fo.to_expression();
+    assert_eq!(expression, "3.0*I + 0.5*c0a1 - 1.0*i*c1a1 + 1.0*c2c3a2a3");
+    assert_eq!(FermionOperator::from_expression(&expression).unwrap(), fo);
+    assert!(FermionOperator::from_expression("0.5*c0x1").is_err());
+    assert!(FermionOperator::from_expression("0.5*c1c1").is_err());
+}
+
+// Test the occupation_operator function of the FermionOperator
+#[test]
+fn occupation_operator() {
+    let fo = FermionOperator::occupation_operator([0, 2, 2]);
+    assert_eq!(fo.len(), 2);
+    assert_eq!(
+        fo.get(&FermionProduct::number(0)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        fo.get(&FermionProduct::number(2)),
+        &CalculatorComplex::from(2.0)
+    );
+    assert!(fo.keys().all(|key| key.is_density_term()));
+    assert!(FermionOperator::occupation_operator(Vec::<usize>::new()).is_empty());
+
+    let fh = FermionHamiltonian::occupation_operator(0..3);
+    assert_eq!(fh.len(), 3);
+    assert_eq!(
+        FermionOperator::from(fh),
+        FermionOperator::occupation_operator(0..3)
+    );
+}
+
+// Test the wick_expectation function of the FermionOperator
+#[test]
+fn wick_expectation() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([], []).unwrap(), 3.0.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([0], [0]).unwrap(), 1.0.into())
+        .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1], [0, 1]).unwrap(),
+        CalculatorComplex::new(2.0, 1.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([0, 1, 2], [0, 1, 2]).unwrap(),
+        4.0.into(),
+    )
+    .unwrap();
+    // Terms changing the occupation of the modes do not contribute
+    fo.add_operator_product(FermionProduct::new([0], [1]).unwrap(), 5.0.into())
+        .unwrap();
+    fo.add_operator_product(FermionProduct::new([0, 1], [2]).unwrap(), 5.0.into())
+        .unwrap();
+
+    // 3 + n_0 - (2 + i) n_0 n_1 - 4 n_0 n_1 n_2
+    let expectation = fo.wick_expectation(&[1.0, 0.5, 0.5]).unwrap();
+    assert_eq!(expectation, CalculatorComplex::new(2.0, -0.5));
+
+    // Empty modes
+    let expectation = fo.wick_expectation(&[0.0, 0.0, 0.0]).unwrap();
+    assert_eq!(expectation, CalculatorComplex::from(3.0));
+
+    // Symbolic coefficients
+    let mut symbolic = FermionOperator::new();
+    symbolic
+        .add_operator_product(FermionProduct::new([1], [1]).unwrap(), "t".into())
+        .unwrap();
+    let expectation = symbolic.wick_expectation(&[0.0, 0.5]).unwrap();
+    assert!(!expectation.re.is_float());
+    assert_eq!(expectation.im, CalculatorFloat::from(0.0));
+
+    assert!(fo.wick_expectation(&[1.0, 0.5]).is_err());
+    assert!(fo.wick_expectation(&[1.0, 1.5, 0.0]).is_err());
+    assert!(fo.wick_expectation(&[1.0, f64::NAN, 0.0]).is_err());
+}
+
+// Test the fourier_transform function of the FermionOperator
+#[test]
+fn fourier_transform() {
+    // Constant and hopping on a ring of four sites
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(FermionProduct::new([], []).unwrap(), 3.0.into())
+        .unwrap();
+    for site in 0..4 {
+        let next = (site + 1) % 4;
+        fo.add_operator_product(FermionProduct::new([site], [next]).unwrap(), (-1.0).into())
+            .unwrap();
+        fo.add_operator_product(FermionProduct::new([next], [site]).unwrap(), (-1.0).into())
+            .unwrap();
+    }
+    // The dispersion -2 cos(k) vanishes at k = π/2 and k = 3π/2
+    let transformed = fo.fourier_transform(4).unwrap();
+    assert_eq!(transformed.len(), 3);
+    assert_eq!(
+        transformed.get(&FermionProduct::new([], []).unwrap()),
+        &CalculatorComplex::from(3.0)
+    );
+    assert_eq!(
+        transformed.get(&FermionProduct::new([0], [0]).unwrap()),
+        &CalculatorComplex::from(-2.0)
+    );
+    assert_eq!(
+        transformed.get(&FermionProduct::new([2], [2]).unwrap()),
+        &CalculatorComplex::from(2.0)
+    );
+
+    // The product of two creators picks up the fermionic sign when it is reordered
+    let mut pair = FermionOperator::new();
+    pair.add_operator_product(FermionProduct::new([0, 1], []).unwrap(), 1.0.into())
+        .unwrap();
+    let transformed = pair.fourier_transform(2).unwrap();
+    assert_eq!(transformed.len(), 1);
+    assert_eq!(
+        transformed.get(&FermionProduct::new([0, 1], []).unwrap()),
+        &CalculatorComplex::from(-1.0)
+    );
+
+    // Mode 2 is the second orbital on the first site
+    let mut orbital = FermionOperator::new();
+    orbital
+        .add_operator_product(FermionProduct::new([2], [2]).unwrap(), "t".into())
+        .unwrap();
+    let transformed = orbital.fourier_transform(2).unwrap();
+    assert_eq!(transformed.len(), 4);
+    for (creator, annihilator) in [(2, 2), (2, 3), (3, 2), (3, 3)] {
+        let value = transformed.get(&FermionProduct::new([creator], [annihilator]).unwrap());
+        assert!(!value.re.is_float());
+    }
+
+    assert_eq!(
+        FermionOperator::new().fourier_transform(3).unwrap(),
+        FermionOperator::new()
+    );
+    assert!(fo.fourier_transform(0).is_err());
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_2: FermionProduct = FermionProduct::new([0], [2]).unwrap();
@@ -257,6 +413,108 @@ fn separate_out_terms(number_spins: (usize, usize)) {
     assert_eq!(result.1, remainder);
 }
 
+// Test the classification of FermionOperator terms by excitation rank
+#[test]
+fn excitation_ranks() {
+    // Reference determinant with orbitals 0 and 1 occupied
+    let occupied = [0, 1];
+    let identity = FermionProduct::new([], []).unwrap();
+    let single = FermionProduct::new([2], [0]).unwrap();
+    let double = FermionProduct::new([2, 3], [0, 1]).unwrap();
+    let de_excitation = FermionProduct::new([0], [2]).unwrap();
+    let number = FermionProduct::new([1], [1]).unwrap();
+
+    assert_eq!(identity.excitation_rank(&occupied), Some(0));
+    assert_eq!(single.excitation_rank(&occupied), Some(1));
+    assert_eq!(double.excitation_rank(&occupied), Some(2));
+    assert_eq!(de_excitation.excitation_rank(&occupied), None);
+    assert_eq!(number.excitation_rank(&occupied), None);
+
+    let mut fo = FermionOperator::new();
+    for (index, product) in [
+        identity,
+        single.clone(),
+        double.clone(),
+        de_excitation,
+        number,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        fo.add_operator_product(product, CalculatorComplex::from(index as f64 + 1.0))
+            .unwrap();
+    }
+
+    let mut singles_doubles = FermionOperator::new();
+    singles_doubles
+        .add_operator_product(single.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+    singles_doubles
+        .add_operator_product(double, CalculatorComplex::from(3.0))
+        .unwrap();
+    let (separated, remainder) = fo.separate_by_excitation_rank(&occupied, &[1, 2]).unwrap();
+    assert_eq!(separated, singles_doubles);
+    assert_eq!(remainder.len(), 3);
+    assert_eq!(separated + remainder, fo);
+
+    let (excitations, remainder) = fo.group_by_excitation_rank(&occupied).unwrap();
+    assert_eq!(
+        excitations.keys().copied().collect::<Vec<usize>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(excitations[&1].get(&single), &CalculatorComplex::from(2.0));
+    assert_eq!(remainder.len(), 2);
+}
+
+// Test the generators of single and double excitations
+#[test]
+fn singles_doubles_excitations() {
+    let excitations = FermionOperator::singles_doubles_excitations(&[1, 0], &[3, 2]).unwrap();
+    let amplitudes: Vec<&str> = excitations
+        .iter()
+        .map(|(amplitude, _)| amplitude.as_str())
+        .collect();
+    assert_eq!(
+        amplitudes,
+        vec!["t_2_0", "t_3_0", "t_2_1", "t_3_1", "t_2_3_0_1"]
+    );
+
+    let (_, single) = &excitations[2];
+    assert_eq!(single.len(), 2);
+    assert_eq!(
+        single.get(&FermionProduct::new([2], [1]).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(
+        single.get(&FermionProduct::new([1], [2]).unwrap()),
+        &CalculatorComplex::from(-1.0)
+    );
+
+    // c_2^dagger c_3^dagger c_1 c_0 = - c_2^dagger c_3^dagger c_0 c_1
+    let (_, double) = &excitations[4];
+    assert_eq!(double.len(), 2);
+    assert_eq!(
+        double.get(&FermionProduct::new([2, 3], [0, 1]).unwrap()),
+        &CalculatorComplex::from(-1.0)
+    );
+    assert_eq!(
+        double.get(&FermionProduct::new([0, 1], [2, 3]).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+    for (_, generator) in excitations.iter() {
+        assert_eq!(&generator.anti_hermitian_part(), generator);
+        let (ranks, remainder) = generator.group_by_excitation_rank(&[0, 1]).unwrap();
+        assert!(remainder.len() == 1 && ranks.len() == 1);
+    }
+
+    assert!(FermionOperator::singles_doubles_excitations(&[0, 1], &[1, 2]).is_err());
+    assert!(FermionOperator::singles_doubles_excitations(&[0, 0], &[2]).is_err());
+    assert!(FermionOperator::singles_doubles_excitations(&[0], &[1])
+        .unwrap()
+        .iter()
+        .all(|(amplitude, _)| amplitude == "t_1_0"));
+}
+
 // Test the negative operation: -FermionOperator
 #[test]
 fn negative_so() {
@@ -536,7 +794,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "FermionOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -561,6 +819,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -576,6 +839,249 @@ fn serde_compact() {
     );
 }
 
+// Test the commutation check of FermionOperator with a FermionHamiltonian
+#[test]
+fn commutes_with() {
+    let mut number = FermionOperator::new();
+    number
+        .add_operator_product(FermionProduct::new([0], [0]).unwrap(), 1.0.into())
+        .unwrap();
+    number
+        .add_operator_product(FermionProduct::new([1], [1]).unwrap(), 1.0.into())
+        .unwrap();
+
+    let mut hamiltonian = FermionHamiltonian::new();
+    hamiltonian
+        .add_operator_product(HermitianFermionProduct::new([0], [1]).unwrap(), "t".into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1], [0, 1]).unwrap(),
+            "V".into(),
+        )
+        .unwrap();
+    assert!(number.commutes_with(&hamiltonian));
+    assert_eq!(number.symmetry_violation_norm(&hamiltonian), Ok(0.0));
+
+    // [N, 0.5 (a0 a1 + c1 c0)] = - a0 a1 + c1 c0
+    hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([], [0, 1]).unwrap(),
+            0.5.into(),
+        )
+        .unwrap();
+    assert!(!number.commutes_with(&hamiltonian));
+    assert_eq!(number.symmetry_violation_norm(&hamiltonian), Ok(2.0));
+}
+
+#[test]
+fn powi() {
+    let mut number = FermionOperator::new();
+    number
+        .add_operator_product(
+            FermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut identity = FermionOperator::new();
+    identity
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(number.powi(0), identity);
+    // The number operator of a fermionic mode is a projector
+    assert_eq!(number.powi(3), number);
+
+    let mut hopping = FermionOperator::new();
+    hopping
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    hopping
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(
+        hopping.powi(4),
+        hopping.clone() * hopping.clone() * hopping.clone() * hopping.clone()
+    );
+}
+
+#[test]
+fn hermitian_split() {
+    let hopping = FermionProduct::new([0], [1]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(hopping.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+    fo.add_operator_product(number.clone(), CalculatorComplex::new(0.5, 1.0))
+        .unwrap();
+    assert!(!fo.is_hermitian());
+
+    let hermitian = fo.hermitian_part();
+    assert!(hermitian.is_hermitian());
+    assert_eq!(hermitian.len(), 3);
+    assert_eq!(hermitian.get(&hopping), &CalculatorComplex::from(1.0));
+    assert_eq!(hermitian.get(&number), &CalculatorComplex::from(0.5));
+
+    let anti_hermitian = fo.anti_hermitian_part();
+    assert_eq!(anti_hermitian.len(), 3);
+    assert_eq!(anti_hermitian.get(&hopping), &CalculatorComplex::from(1.0));
+    assert_eq!(
+        anti_hermitian.get(&number),
+        &CalculatorComplex::new(0.0, 1.0)
+    );
+    assert_eq!(
+        anti_hermitian.hermitian_conjugate(),
+        anti_hermitian.clone() * CalculatorFloat::from(-1.0)
+    );
+    assert_eq!(hermitian + anti_hermitian, fo);
+}
+
+#[test]
+fn try_into_hamiltonian() {
+    let hopping = FermionProduct::new([0], [1]).unwrap();
+    let hopping_conjugate = FermionProduct::new([1], [0]).unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(hopping.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    fo.add_operator_product(hopping_conjugate.clone(), CalculatorComplex::new(1.0, -2.0))
+        .unwrap();
+    fo.add_operator_product(number.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert!(FermionHamiltonian::try_from(fo.clone()).is_err());
+
+    let hamiltonian = fo.try_into_hamiltonian().unwrap();
+    let mut expected = FermionHamiltonian::new();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 2.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(hamiltonian, expected);
+    assert_eq!(hamiltonian.to_operator(), fo);
+
+    fo.add_operator_product(hopping_conjugate, CalculatorComplex::from(1.0))
+        .unwrap();
+    match fo.try_into_hamiltonian() {
+        Err(StruqtureError::NonHermitianTerms { terms }) => {
+            assert_eq!(terms.len(), 2);
+            assert!(terms.iter().all(|(key, _)| key != &number.to_string()));
+        }
+        _ => panic!("Expected NonHermitianTerms error"),
+    }
+}
+
+// Test the particle number statistics of the FermionOperator
+#[test]
+fn particle_number_sectors() {
+    let mut op = FermionOperator::new();
+    assert_eq!(op.max_particle_number_change(), 0);
+    assert!(op.particle_number_sectors().is_empty());
+
+    op.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(op.max_particle_number_change(), 0);
+    assert_eq!(op.particle_number_sectors(), BTreeSet::from([0]));
+
+    op.add_operator_product(
+        FermionProduct::new([0, 1], []).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    op.add_operator_product(
+        FermionProduct::new([], [2]).unwrap(),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    assert_eq!(op.max_particle_number_change(), 2);
+    assert_eq!(op.particle_number_sectors(), BTreeSet::from([-1, 0, 2]));
+}
+
+// Test the sparse_matrix_in_sector function of the FermionOperator against the full Jordan-Wigner matrix
+#[test]
+fn sparse_matrix_in_sector() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([2], [0]).unwrap(),
+        CalculatorComplex::new(1.0, -0.5),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1, 2], [0, 1]).unwrap(),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(-2.0),
+    )
+    .unwrap();
+
+    let full_matrix = fo.jordan_wigner().sparse_matrix(Some(3)).unwrap();
+    for (number_particles, states) in [
+        (0, vec![0]),
+        (1, vec![1, 2, 4]),
+        (2, vec![3, 5, 6]),
+        (3, vec![7]),
+    ] {
+        let (values, (rows, columns)) = fo.sparse_matrix_in_sector(3, number_particles).unwrap();
+        let sector_matrix: HashMap<(usize, usize), Complex64> = rows
+            .into_iter()
+            .zip(columns)
+            .zip(values)
+            .map(|((row, column), value)| ((states[row], states[column]), value))
+            .collect();
+        for row in states.iter() {
+            for column in states.iter() {
+                let expected = full_matrix
+                    .get(&(*row, *column))
+                    .copied()
+                    .unwrap_or_default();
+                let value = sector_matrix
+                    .get(&(*row, *column))
+                    .copied()
+                    .unwrap_or_default();
+                assert!((expected - value).norm() < 1e-12);
+            }
+        }
+    }
+
+    assert_eq!(
+        fo.sparse_matrix_in_sector(2, 1),
+        Err(StruqtureError::NumberModesExceeded)
+    );
+    assert!(fo.sparse_matrix_in_sector(3, 4).is_err());
+    fo.add_operator_product(
+        FermionProduct::new([0], []).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert!(fo.sparse_matrix_in_sector(3, 1).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_fermion_operator_schema() {
@@ -14,6 +14,8 @@ mod fermionic_product;
 
 mod hermitian_fermionic_product;
 
+mod spin_orbital_fermionic_product;
+
 mod fermionic_operator;
 
 mod fermionic_hamiltonian;
@@ -27,3 +29,5 @@ mod fermionic_noise_operator;
 mod fermionic_noise_system;
 
 mod fermionic_open_system;
+
+mod reduced_density_matrices;
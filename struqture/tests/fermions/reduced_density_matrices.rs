@@ -0,0 +1,156 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the fermionic reduced density matrices
+
+use ndarray::{Array2, Array4};
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use struqture::fermions::{
+    FermionHamiltonianSystem, HermitianFermionProduct, OneRDM, RdmIndex, TwoRDM,
+};
+use struqture::prelude::*;
+
+// Returns the RDMs of the Slater determinant with the given occupation numbers
+fn determinant_rdms(occupations: &[f64]) -> (OneRDM, TwoRDM) {
+    let number_modes = occupations.len();
+    let one_rdm = Array2::from_shape_fn((number_modes, number_modes), |(p, q)| {
+        if p == q {
+            Complex64::new(occupations[p], 0.0)
+        } else {
+            Complex64::new(0.0, 0.0)
+        }
+    });
+    let two_rdm = Array4::from_shape_fn(
+        (number_modes, number_modes, number_modes, number_modes),
+        |(p, q, r, s)| {
+            let occupation = occupations[p] * occupations[q];
+            let direct = if p == s && q == r { occupation } else { 0.0 };
+            let exchange = if p == r && q == s { occupation } else { 0.0 };
+            Complex64::new(direct - exchange, 0.0)
+        },
+    );
+    (OneRDM::new(one_rdm).unwrap(), TwoRDM::new(two_rdm).unwrap())
+}
+
+// Test the creation of the RDMs
+#[test]
+fn new_rdms() {
+    let (one_rdm, two_rdm) = determinant_rdms(&[1.0, 0.0, 1.0]);
+    assert_eq!(one_rdm.number_modes(), 3);
+    assert_eq!(two_rdm.number_modes(), 3);
+    assert_eq!(one_rdm.get(2, 2), Some(Complex64::new(1.0, 0.0)));
+    assert_eq!(one_rdm.get(3, 0), None);
+    assert_eq!(two_rdm.get(0, 2, 2, 0), Some(Complex64::new(1.0, 0.0)));
+    assert_eq!(two_rdm.get(0, 2, 0, 2), Some(Complex64::new(-1.0, 0.0)));
+    assert_eq!(two_rdm.get(0, 0, 0, 3), None);
+    assert_eq!(one_rdm.matrix().dim(), (3, 3));
+    assert_eq!(two_rdm.tensor().dim(), (3, 3, 3, 3));
+
+    assert!(OneRDM::new(Array2::zeros((2, 3))).is_err());
+    assert!(TwoRDM::new(Array4::zeros((2, 2, 2, 3))).is_err());
+}
+
+// Test the RDM entries of the terms of a FermionHamiltonianSystem
+#[test]
+fn rdm_index_map() {
+    let mut system = FermionHamiltonianSystem::new(None);
+    let identity = HermitianFermionProduct::default();
+    let hopping = HermitianFermionProduct::new([0], [2]).unwrap();
+    let interaction = HermitianFermionProduct::new([0, 2], [0, 2]).unwrap();
+    system
+        .add_operator_product(identity.clone(), 1.0.into())
+        .unwrap();
+    system
+        .add_operator_product(hopping.clone(), 1.0.into())
+        .unwrap();
+    system
+        .add_operator_product(interaction.clone(), 1.0.into())
+        .unwrap();
+
+    let map = system.rdm_index_map().unwrap();
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(&identity), Some(&RdmIndex::Identity));
+    assert_eq!(map.get(&hopping), Some(&RdmIndex::One(0, 2)));
+    assert_eq!(map.get(&interaction), Some(&RdmIndex::Two(0, 2, 0, 2)));
+
+    let mut pairing = FermionHamiltonianSystem::new(None);
+    pairing
+        .add_operator_product(
+            HermitianFermionProduct::new([], [0, 1]).unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    assert!(pairing.rdm_index_map().is_err());
+
+    let mut three_body = FermionHamiltonianSystem::new(None);
+    three_body
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 1, 2], [0, 1, 2]).unwrap(),
+            1.0.into(),
+        )
+        .unwrap();
+    assert!(three_body.rdm_index_map().is_err());
+}
+
+// Test the energy of a Slater determinant and of a delocalized particle
+#[test]
+fn energy_from_rdms() {
+    let mut system = FermionHamiltonianSystem::new(None);
+    system.set_constant(0.7.into()).unwrap();
+    system
+        .add_operator_product(HermitianFermionProduct::new([0], [0]).unwrap(), 0.5.into())
+        .unwrap();
+    system
+        .add_operator_product(HermitianFermionProduct::new([1], [1]).unwrap(), 2.0.into())
+        .unwrap();
+    system
+        .add_operator_product(HermitianFermionProduct::new([0], [2]).unwrap(), 0.3.into())
+        .unwrap();
+    system
+        .add_operator_product(
+            HermitianFermionProduct::new([0, 2], [0, 2]).unwrap(),
+            1.5.into(),
+        )
+        .unwrap();
+
+    let (one_rdm, two_rdm) = determinant_rdms(&[1.0, 0.0, 1.0]);
+    let energy = system.energy_from_rdms(&one_rdm, &two_rdm).unwrap();
+    assert!((energy - (-0.3)).abs() < 1e-12);
+
+    // One particle in the state (|0> + i|1>) / sqrt(2) with the hopping i c_0^dagger c_1 + h.c.
+    let mut hopping = FermionHamiltonianSystem::new(None);
+    hopping
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+    let mut matrix: Array2<Complex64> = Array2::zeros((2, 2));
+    matrix[(0, 0)] = Complex64::new(0.5, 0.0);
+    matrix[(1, 1)] = Complex64::new(0.5, 0.0);
+    matrix[(0, 1)] = Complex64::new(0.0, 0.5);
+    matrix[(1, 0)] = Complex64::new(0.0, -0.5);
+    let one_particle = OneRDM::new(matrix).unwrap();
+    let no_pairs = TwoRDM::new(Array4::zeros((2, 2, 2, 2))).unwrap();
+    let energy = hopping.energy_from_rdms(&one_particle, &no_pairs).unwrap();
+    assert!((energy - (-1.0)).abs() < 1e-12);
+
+    // The RDMs do not cover all modes of the Hamiltonian
+    assert!(system.energy_from_rdms(&one_particle, &no_pairs).is_err());
+
+    let mut symbolic = FermionHamiltonianSystem::new(None);
+    symbolic
+        .add_operator_product(HermitianFermionProduct::new([0], [0]).unwrap(), "e".into())
+        .unwrap();
+    assert!(symbolic.energy_from_rdms(&one_rdm, &two_rdm).is_err());
+}
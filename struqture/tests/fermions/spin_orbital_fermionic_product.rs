@@ -0,0 +1,144 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of SpinOrbitalFermionProduct
+
+use qoqo_calculator::CalculatorComplex;
+use std::str::FromStr;
+use struqture::fermions::{
+    FermionProduct, FermionSystem, SpinOrbitalFermionProduct, SpinOrbitalOrdering,
+    SpinProjection::{Down, Up},
+};
+use struqture::labeling::IndexLabeling;
+use struqture::prelude::*;
+use struqture::StruqtureError;
+
+// Test the creation and the string representation of SpinOrbitalFermionProducts
+#[test]
+fn new_and_from_string() {
+    let product = SpinOrbitalFermionProduct::new([(0, Up), (1, Down)], [(1, Up)]).unwrap();
+    assert_eq!(
+        product.creators().copied().collect::<Vec<_>>(),
+        vec![(0, Up), (1, Down)]
+    );
+    assert_eq!(
+        product.annihilators().copied().collect::<Vec<_>>(),
+        vec![(1, Up)]
+    );
+    assert_eq!(product.to_string(), "c0uc1da1u");
+    assert_eq!(
+        SpinOrbitalFermionProduct::from_str("c0uc1da1u").unwrap(),
+        product
+    );
+    assert_eq!(SpinOrbitalFermionProduct::default().to_string(), "I");
+    assert_eq!(
+        SpinOrbitalFermionProduct::from_str("I").unwrap(),
+        SpinOrbitalFermionProduct::default()
+    );
+
+    assert_eq!(
+        SpinOrbitalFermionProduct::new([(0, Down), (0, Up)], []),
+        Err(StruqtureError::IncorrectlyOrderedIndices)
+    );
+    assert_eq!(
+        SpinOrbitalFermionProduct::from_str("c1uc0u"),
+        Err(StruqtureError::IncorrectlyOrderedIndices)
+    );
+    assert!(SpinOrbitalFermionProduct::from_str("c0").is_err());
+    assert!(SpinOrbitalFermionProduct::from_str("c0x").is_err());
+    assert!(SpinOrbitalFermionProduct::from_str("cu").is_err());
+    assert!(SpinOrbitalFermionProduct::from_str("a0uc1u").is_err());
+
+    let serialized = serde_json::to_string(&product).unwrap();
+    let deserialized: SpinOrbitalFermionProduct = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, product);
+}
+
+// Test the sorting of spin orbitals in create_valid_pair
+#[test]
+fn create_valid_pair() {
+    let (product, value) = SpinOrbitalFermionProduct::create_valid_pair(
+        [(2, Up), (0, Down), (0, Up)],
+        [(1, Down)],
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    assert_eq!(
+        product,
+        SpinOrbitalFermionProduct::new([(0, Up), (0, Down), (2, Up)], [(1, Down)]).unwrap()
+    );
+    assert_eq!(value, CalculatorComplex::new(-1.0, -2.0));
+    assert!(SpinOrbitalFermionProduct::create_valid_pair(
+        [(0, Up), (0, Up)],
+        [],
+        CalculatorComplex::from(1.0)
+    )
+    .is_err());
+}
+
+// Test the interleaved and blocked orderings of spin orbitals
+#[test]
+fn orderings() {
+    let interleaved = SpinOrbitalOrdering::default();
+    assert_eq!(interleaved, SpinOrbitalOrdering::Interleaved);
+    assert_eq!(interleaved.to_index(&(2, Down)).unwrap(), 5);
+    assert_eq!(interleaved.to_label(4).unwrap(), (2, Up));
+
+    let blocked = SpinOrbitalOrdering::Blocked { number_orbitals: 2 };
+    assert_eq!(blocked.to_index(&(0, Down)).unwrap(), 2);
+    assert_eq!(blocked.to_label(1).unwrap(), (1, Up));
+    assert_eq!(blocked.to_label(3).unwrap(), (1, Down));
+    assert!(blocked.to_index(&(2, Up)).is_err());
+    assert!(blocked.to_label(4).is_err());
+}
+
+// Test the conversion between SpinOrbitalFermionProducts and FermionProducts
+#[test]
+fn fermion_product_conversion() {
+    let product = SpinOrbitalFermionProduct::new([(0, Down), (1, Up)], [(1, Down)]).unwrap();
+
+    let (interleaved, sign) = product
+        .to_fermion_product(SpinOrbitalOrdering::Interleaved)
+        .unwrap();
+    assert_eq!(interleaved, FermionProduct::new([1, 2], [3]).unwrap());
+    assert_eq!(sign, CalculatorComplex::from(1.0));
+
+    // In the blocked ordering, spin orbital (0, Down) is mode 2 and follows (1, Up) on mode 1
+    let blocked = SpinOrbitalOrdering::Blocked { number_orbitals: 2 };
+    let (flat, sign) = product.to_fermion_product(blocked).unwrap();
+    assert_eq!(flat, FermionProduct::new([1, 2], [3]).unwrap());
+    assert_eq!(sign, CalculatorComplex::from(-1.0));
+    assert_eq!(
+        SpinOrbitalFermionProduct::from_fermion_product(&flat, blocked).unwrap(),
+        (product.clone(), CalculatorComplex::from(-1.0))
+    );
+    assert!(product
+        .to_fermion_product(SpinOrbitalOrdering::Blocked { number_orbitals: 1 })
+        .is_err());
+
+    // Convert a system from the interleaved to the blocked ordering
+    let mut system = FermionSystem::new(Some(4));
+    system
+        .add_operator_product(
+            FermionProduct::new([0], [2]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mapping = SpinOrbitalOrdering::Interleaved
+        .relabeling(&blocked, 0..4)
+        .unwrap();
+    let relabeled = system.relabel(&mapping).unwrap();
+    assert_eq!(
+        relabeled.get(&FermionProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::from(1.0)
+    );
+}
@@ -0,0 +1,143 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the HamiltonianBuilder
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::path::PathBuf;
+use struqture::fermions::{FermionHamiltonianSystem, HermitianFermionProduct};
+use struqture::hamiltonian_builder::HamiltonianBuilder;
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonianSystem};
+use struqture::StruqtureError;
+
+// Returns an empty spill directory unique to the test
+fn spill_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("struqture_builder_test_{}", name));
+    let _ = std::fs::remove_dir_all(&directory);
+    directory
+}
+
+// Test that spilled runs are merged into the same SpinHamiltonianSystem as building in memory
+#[test]
+fn spin_builder_matches_in_memory() {
+    let directory = spill_directory("spin");
+    let mut builder =
+        HamiltonianBuilder::<PauliProduct, CalculatorFloat>::new(&directory, 2).unwrap();
+    let mut expected = SpinHamiltonianSystem::new(Some(5));
+    for step in 0..20 {
+        let pp = PauliProduct::new().z(step % 5).x((step + 1) % 5);
+        let value = CalculatorFloat::from(step as f64 + 1.0);
+        builder.add_term(pp.clone(), value.clone()).unwrap();
+        expected.add_operator_product(pp, value).unwrap();
+    }
+    assert!(builder.number_runs() > 1);
+    assert!(builder.number_terms_in_memory() <= 2);
+
+    let system = builder.finish(Some(5)).unwrap();
+    assert_eq!(system, expected);
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 0);
+}
+
+// Test that a cancelled term is removed and that the number of spins is checked
+#[test]
+fn spin_builder_cancellation_and_number_spins() {
+    let directory = spill_directory("spin_cancel");
+    let mut builder =
+        HamiltonianBuilder::<PauliProduct, CalculatorFloat>::new(&directory, 1).unwrap();
+    builder
+        .add_terms(vec![
+            (PauliProduct::new().z(0), 1.0.into()),
+            (PauliProduct::new().x(3), 2.0.into()),
+            (PauliProduct::new().z(0), (-1.0).into()),
+        ])
+        .unwrap();
+    let system = builder.finish(None).unwrap();
+    assert_eq!(system.len(), 1);
+    assert_eq!(
+        system.get(&PauliProduct::new().x(3)),
+        &CalculatorFloat::from(2.0)
+    );
+
+    let mut builder =
+        HamiltonianBuilder::<PauliProduct, CalculatorFloat>::new(&directory, 1).unwrap();
+    builder
+        .add_term(PauliProduct::new().x(3), 2.0.into())
+        .unwrap();
+    assert_eq!(
+        builder.finish(Some(2)),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test that a build can be resumed from the checkpoints on disk
+#[test]
+fn fermion_builder_resume() {
+    let directory = spill_directory("fermion_resume");
+    let hop = HermitianFermionProduct::new([0], [1]).unwrap();
+    let number = HermitianFermionProduct::new([0], [0]).unwrap();
+
+    let mut builder =
+        HamiltonianBuilder::<HermitianFermionProduct, CalculatorComplex>::new(&directory, 10)
+            .unwrap();
+    builder
+        .add_terms(vec![
+            (hop.clone(), CalculatorComplex::new(1.0, 0.5)),
+            (number.clone(), CalculatorComplex::new(2.0, 0.0)),
+        ])
+        .unwrap();
+    builder.checkpoint().unwrap();
+    assert_eq!(builder.number_runs(), 1);
+    assert_eq!(builder.number_terms_in_memory(), 0);
+    drop(builder);
+
+    assert!(
+        HamiltonianBuilder::<HermitianFermionProduct, CalculatorComplex>::new(&directory, 10)
+            .is_err()
+    );
+    let mut builder =
+        HamiltonianBuilder::<HermitianFermionProduct, CalculatorComplex>::resume(&directory, 10)
+            .unwrap();
+    assert_eq!(builder.number_runs(), 1);
+    builder
+        .add_term(hop.clone(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    let system = builder.finish(Some(2)).unwrap();
+
+    let mut expected = FermionHamiltonianSystem::new(Some(2));
+    expected
+        .add_operator_product(hop, CalculatorComplex::new(2.0, 0.5))
+        .unwrap();
+    expected
+        .add_operator_product(number, CalculatorComplex::new(2.0, 0.0))
+        .unwrap();
+    assert_eq!(system, expected);
+}
+
+// Test that a diagonal term with a complex coefficient is rejected at finish
+#[test]
+fn fermion_builder_non_hermitian() {
+    let directory = spill_directory("fermion_non_hermitian");
+    let mut builder =
+        HamiltonianBuilder::<HermitianFermionProduct, CalculatorComplex>::new(&directory, 10)
+            .unwrap();
+    builder
+        .add_term(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        builder.finish(None),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+}
@@ -0,0 +1,247 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the serialization format conversion
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::str::FromStr;
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+use struqture::bosons::{BosonHamiltonianSystem, HermitianBosonProduct};
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+use struqture::fermions::{FermionProduct, FermionSystem};
+use struqture::io::{convert, convert_by_type_name, deserialize, serialize, SerializationFormat};
+use struqture::prelude::*;
+use struqture::spins::{
+    DecoherenceProduct, PauliProduct, SpinHamiltonianSystem, SpinLindbladOpenSystem,
+};
+use struqture::StruqtureError;
+use test_case::test_case;
+
+fn open_system() -> SpinLindbladOpenSystem {
+    let mut system = SpinLindbladOpenSystem::new(Some(2));
+    system
+        .system_mut()
+        .set(PauliProduct::new().z(0).x(1), CalculatorFloat::from("h"))
+        .unwrap();
+    system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::from(0.1),
+        )
+        .unwrap();
+    system
+}
+
+#[test_case(SerializationFormat::Json, SerializationFormat::Bincode)]
+#[test_case(SerializationFormat::Bincode, SerializationFormat::JsonPretty)]
+#[test_case(SerializationFormat::JsonPretty, SerializationFormat::Json)]
+#[test_case(SerializationFormat::Bincode, SerializationFormat::Bincode)]
+fn convert_formats(from_format: SerializationFormat, to_format: SerializationFormat) {
+    let system = open_system();
+    let input = serialize(&system, from_format).unwrap();
+    let output = convert::<SpinLindbladOpenSystem>(&input, from_format, to_format).unwrap();
+    assert_eq!(
+        deserialize::<SpinLindbladOpenSystem>(&output, to_format).unwrap(),
+        system
+    );
+    assert_eq!(
+        convert_by_type_name("SpinLindbladOpenSystem", &input, from_format, to_format).unwrap(),
+        output
+    );
+}
+
+#[test]
+fn serialization_formats() {
+    let system = open_system();
+    assert_eq!(
+        serialize(&system, SerializationFormat::Json).unwrap(),
+        serde_json::to_vec(&system).unwrap()
+    );
+    assert_eq!(
+        serialize(&system, SerializationFormat::Bincode).unwrap(),
+        bincode::serialize(&system).unwrap()
+    );
+    for format in [
+        SerializationFormat::Json,
+        SerializationFormat::JsonPretty,
+        SerializationFormat::Bincode,
+    ] {
+        assert_eq!(
+            SerializationFormat::from_str(&format.to_string()).unwrap(),
+            format
+        );
+    }
+    assert!(matches!(
+        SerializationFormat::from_str("yaml"),
+        Err(StruqtureError::ParsingError { .. })
+    ));
+}
+
+#[test]
+fn conversion_errors() {
+    let input = serialize(&open_system(), SerializationFormat::Json).unwrap();
+    assert!(matches!(
+        convert::<SpinHamiltonianSystem>(
+            &input,
+            SerializationFormat::Json,
+            SerializationFormat::Bincode
+        ),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+    assert!(matches!(
+        convert::<SpinLindbladOpenSystem>(
+            &input,
+            SerializationFormat::Bincode,
+            SerializationFormat::Json
+        ),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+    assert!(matches!(
+        convert_by_type_name(
+            "SpinCircuit",
+            &input,
+            SerializationFormat::Json,
+            SerializationFormat::Bincode
+        ),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack() {
+    let system = open_system();
+    let serialized = system.to_msgpack().unwrap();
+    assert_eq!(
+        SpinLindbladOpenSystem::from_msgpack(&serialized).unwrap(),
+        system
+    );
+    assert_eq!(
+        serialized,
+        serialize(&system, SerializationFormat::MessagePack).unwrap()
+    );
+    assert_eq!(
+        SerializationFormat::from_str("msgpack").unwrap(),
+        SerializationFormat::MessagePack
+    );
+    let json = serialize(&system, SerializationFormat::Json).unwrap();
+    assert_eq!(
+        convert_by_type_name(
+            "SpinLindbladOpenSystem",
+            &json,
+            SerializationFormat::Json,
+            SerializationFormat::MessagePack
+        )
+        .unwrap(),
+        serialized
+    );
+
+    let mut fermions = FermionSystem::new(Some(3));
+    fermions
+        .add_operator_product(
+            FermionProduct::new([0, 2], [1]).unwrap(),
+            CalculatorComplex::new(1.0, -0.5),
+        )
+        .unwrap();
+    assert_eq!(
+        FermionSystem::from_msgpack(&fermions.to_msgpack().unwrap()).unwrap(),
+        fermions
+    );
+
+    let mut bosons = BosonHamiltonianSystem::new(None);
+    bosons
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from("g"),
+        )
+        .unwrap();
+    assert_eq!(
+        BosonHamiltonianSystem::from_msgpack(&bosons.to_msgpack().unwrap()).unwrap(),
+        bosons
+    );
+
+    assert!(matches!(
+        SpinHamiltonianSystem::from_msgpack(&serialized),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+    assert!(matches!(
+        SpinLindbladOpenSystem::from_msgpack(&json),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor() {
+    let system = open_system();
+    let serialized = system.to_cbor().unwrap();
+    assert_eq!(
+        SpinLindbladOpenSystem::from_cbor(&serialized).unwrap(),
+        system
+    );
+    assert_eq!(
+        serialized,
+        serialize(&system, SerializationFormat::Cbor).unwrap()
+    );
+    assert_eq!(
+        SerializationFormat::from_str("cbor").unwrap(),
+        SerializationFormat::Cbor
+    );
+    let json = serialize(&system, SerializationFormat::Json).unwrap();
+    assert_eq!(
+        convert_by_type_name(
+            "SpinLindbladOpenSystem",
+            &json,
+            SerializationFormat::Json,
+            SerializationFormat::Cbor
+        )
+        .unwrap(),
+        serialized
+    );
+
+    let mut fermions = FermionSystem::new(Some(3));
+    fermions
+        .add_operator_product(
+            FermionProduct::new([0, 2], [1]).unwrap(),
+            CalculatorComplex::new(1.0, -0.5),
+        )
+        .unwrap();
+    assert_eq!(
+        FermionSystem::from_cbor(&fermions.to_cbor().unwrap()).unwrap(),
+        fermions
+    );
+
+    let mut bosons = BosonHamiltonianSystem::new(None);
+    bosons
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from("g"),
+        )
+        .unwrap();
+    assert_eq!(
+        BosonHamiltonianSystem::from_cbor(&bosons.to_cbor().unwrap()).unwrap(),
+        bosons
+    );
+
+    assert!(matches!(
+        SpinHamiltonianSystem::from_cbor(&serialized),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+    assert!(matches!(
+        SpinLindbladOpenSystem::from_cbor(&json),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+}
@@ -0,0 +1,265 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the labeling of modes and spins
+
+use num_complex::Complex64;
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::collections::HashMap;
+use struqture::bosons::{BosonProduct, BosonSystem};
+use struqture::fermions::{
+    FermionHamiltonianSystem, FermionProduct, FermionSystem, HermitianFermionProduct,
+};
+use struqture::labeling::{IndexLabeling, IndexOffset, LabelMap};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonianSystem, SpinSystem};
+
+// Test the conversion between labels and indices with an IndexOffset
+#[test]
+fn index_offset() {
+    let fcidump = IndexOffset::one_based();
+    assert_eq!(fcidump.offset(), 1);
+    assert_eq!(fcidump.to_index(&1).unwrap(), 0);
+    assert_eq!(fcidump.to_label(4).unwrap(), 5);
+    assert!(fcidump.to_index(&0).is_err());
+    assert_eq!(fcidump.to_indices(&[3, 1]).unwrap(), vec![2, 0]);
+
+    let negative = IndexOffset::new(-2);
+    assert_eq!(negative.to_index(&-2).unwrap(), 0);
+    assert_eq!(negative.to_index(&1).unwrap(), 3);
+    assert_eq!(negative.to_labels([0, 1, 2]).unwrap(), vec![-2, -1, 0]);
+    assert!(negative.to_index(&-3).is_err());
+    assert!(IndexOffset::new(isize::MAX).to_label(1).is_err());
+    assert_eq!(IndexOffset::default(), IndexOffset::new(0));
+}
+
+// Test the conversion between labels and indices with a LabelMap
+#[test]
+fn label_map() {
+    let orbitals = LabelMap::new([(0, 'u'), (0, 'd'), (1, 'u'), (1, 'd')]).unwrap();
+    assert_eq!(orbitals.len(), 4);
+    assert!(!orbitals.is_empty());
+    assert_eq!(orbitals.labels()[1], (0, 'd'));
+    assert_eq!(orbitals.to_index(&(1, 'u')).unwrap(), 2);
+    assert_eq!(orbitals.to_label(3).unwrap(), (1, 'd'));
+    assert!(orbitals.to_index(&(2, 'u')).is_err());
+    assert!(orbitals.to_label(4).is_err());
+    assert!(LabelMap::new(["a", "b", "a"]).is_err());
+
+    // Interleaved to blocked ordering of spin orbitals
+    let blocked = LabelMap::new([(0, 'u'), (1, 'u'), (0, 'd'), (1, 'd')]).unwrap();
+    let mapping = orbitals.relabeling(&blocked, 0..4).unwrap();
+    assert_eq!(mapping, HashMap::from([(0, 0), (1, 2), (2, 1), (3, 3)]));
+    assert!(orbitals
+        .relabeling(&LabelMap::new([(0, 'u')]).unwrap(), 0..4)
+        .is_err());
+}
+
+// Test the relabeling of products
+#[test]
+fn relabel_products() {
+    let mapping = HashMap::from([(0, 3)]);
+
+    let fp = FermionProduct::new([0, 1], [2]).unwrap();
+    assert!(fp.remap_modes(&mapping).is_err());
+    assert_eq!(
+        fp.relabel(&mapping).unwrap(),
+        (
+            FermionProduct::new([1, 3], [2]).unwrap(),
+            CalculatorComplex::from(-1.0)
+        )
+    );
+
+    let bp = BosonProduct::new([0], [0, 1]).unwrap();
+    assert_eq!(
+        bp.relabel(&mapping).unwrap(),
+        (
+            BosonProduct::new([3], [1, 3]).unwrap(),
+            CalculatorComplex::from(1.0)
+        )
+    );
+    assert!(bp.relabel(&HashMap::from([(0, 1)])).is_err());
+
+    let pp = PauliProduct::new().x(0).z(1);
+    assert_eq!(pp.relabel(&mapping).unwrap(), PauliProduct::new().z(1).x(3));
+    assert!(pp.relabel(&HashMap::from([(1, 0)])).is_err());
+}
+
+// Test the relabeling of systems
+#[test]
+fn relabel_systems() {
+    let shift = IndexOffset::new(0)
+        .relabeling(&IndexOffset::new(-2), 0..2)
+        .unwrap();
+    assert_eq!(shift, HashMap::from([(0, 2), (1, 3)]));
+
+    let mut fs = FermionSystem::new(None);
+    fs.add_operator_product(
+        FermionProduct::new([0, 1], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    let relabeled = fs.relabel(&shift).unwrap();
+    assert_eq!(relabeled.len(), 1);
+    assert_eq!(
+        relabeled.get(&FermionProduct::new([2, 3], [3]).unwrap()),
+        &CalculatorComplex::new(1.0, 2.0)
+    );
+    assert!(FermionSystem::new(Some(2)).relabel(&shift).is_ok());
+
+    let mut fixed = FermionSystem::new(Some(2));
+    fixed
+        .add_operator_product(
+            FermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert!(fixed.relabel(&shift).is_err());
+
+    // The relabeled term c_2^dagger c_1 is stored as its hermitian conjugate
+    let mut fhs = FermionHamiltonianSystem::new(None);
+    fhs.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    let relabeled = fhs.relabel(&HashMap::from([(0, 2)])).unwrap();
+    assert_eq!(
+        relabeled.get(&HermitianFermionProduct::new([1], [2]).unwrap()),
+        &CalculatorComplex::new(1.0, -2.0)
+    );
+
+    let mut bs = BosonSystem::new(None);
+    bs.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert!(bs.relabel(&HashMap::from([(0, 1)])).is_err());
+
+    let mut shs = SpinHamiltonianSystem::new(Some(3));
+    shs.add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(0.5))
+        .unwrap();
+    let swapped = shs.relabel(&HashMap::from([(0, 2)])).unwrap();
+    assert_eq!(
+        swapped.get(&PauliProduct::new().z(1).z(2)),
+        &CalculatorFloat::from(0.5)
+    );
+    assert_eq!(swapped.number_spins(), 3);
+}
+
+// Test the projection of systems onto symmetry sectors of index permutations
+#[test]
+fn project_onto_symmetry_sector() {
+    // Translations on a ring of three spins
+    let translation = vec![vec![1, 2, 0]];
+    let mut ss = SpinSystem::new(Some(3));
+    ss.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(3.0))
+        .unwrap();
+    let uniform = ss
+        .project_onto_symmetry_sector(&translation, &[Complex64::new(1.0, 0.0)])
+        .unwrap();
+    assert_eq!(uniform.len(), 3);
+    for spin in 0..3 {
+        assert_eq!(
+            uniform.get(&PauliProduct::new().z(spin)),
+            &CalculatorComplex::from(1.0)
+        );
+    }
+
+    let phase = Complex64::from_polar(1.0, 2.0 * std::f64::consts::PI / 3.0);
+    let momentum = ss
+        .project_onto_symmetry_sector(&translation, &[phase])
+        .unwrap();
+    for (spin, power) in [(0, 0), (1, 1), (2, 2)] {
+        let expected = phase.conj().powi(power);
+        let value = momentum.get(&PauliProduct::new().z(spin));
+        assert!((f64::try_from(value.re.clone()).unwrap() - expected.re).abs() < 1e-10);
+        assert!((f64::try_from(value.im.clone()).unwrap() - expected.im).abs() < 1e-10);
+    }
+    // A sector is left unchanged by a second projection
+    assert_eq!(
+        momentum
+            .project_onto_symmetry_sector(&translation, &[phase])
+            .unwrap()
+            .len(),
+        3
+    );
+
+    // Antisymmetric part of a hopping under the exchange of two fermionic modes
+    let exchange = vec![vec![1, 0]];
+    let mut fs = FermionSystem::new(Some(2));
+    fs.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let antisymmetric = fs
+        .project_onto_symmetry_sector(&exchange, &[Complex64::new(-1.0, 0.0)])
+        .unwrap();
+    assert_eq!(
+        antisymmetric.get(&FermionProduct::new([0], [1]).unwrap()),
+        &CalculatorComplex::from(0.5)
+    );
+    assert_eq!(
+        antisymmetric.get(&FermionProduct::new([1], [0]).unwrap()),
+        &CalculatorComplex::from(-0.5)
+    );
+
+    // The number operators cancel in the antisymmetric sector of bosons
+    let mut bs = BosonSystem::new(None);
+    bs.add_operator_product(
+        BosonProduct::new([0], [0]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    bs.add_operator_product(
+        BosonProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let symmetric = bs
+        .project_onto_symmetry_sector(&exchange, &[Complex64::new(1.0, 0.0)])
+        .unwrap();
+    assert_eq!(symmetric, bs);
+    let antisymmetric = bs
+        .project_onto_symmetry_sector(&exchange, &[Complex64::new(-1.0, 0.0)])
+        .unwrap();
+    assert!(antisymmetric.is_empty());
+
+    // Invalid generators and characters
+    let one = [Complex64::new(1.0, 0.0)];
+    assert!(bs
+        .project_onto_symmetry_sector(&[vec![0, 0]], &one)
+        .is_err());
+    assert!(bs
+        .project_onto_symmetry_sector(&[vec![1, 2]], &one)
+        .is_err());
+    assert!(bs
+        .project_onto_symmetry_sector(&[vec![1, 0], vec![0, 1, 2]], &[one[0], one[0]])
+        .is_err());
+    assert!(bs.project_onto_symmetry_sector(&exchange, &[]).is_err());
+    assert!(bs
+        .project_onto_symmetry_sector(&exchange, &[Complex64::new(2.0, 0.0)])
+        .is_err());
+    // The exchange squares to the identity, so its character must square to one
+    assert!(bs
+        .project_onto_symmetry_sector(&exchange, &[Complex64::new(0.0, 1.0)])
+        .is_err());
+    // A permuted index exceeding the number of modes
+    assert!(FermionSystem::new(Some(2))
+        .project_onto_symmetry_sector(&[vec![1, 2, 0]], &one)
+        .is_ok());
+    assert!(fs
+        .project_onto_symmetry_sector(&[vec![2, 0, 1]], &one)
+        .is_err());
+}
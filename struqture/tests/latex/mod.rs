@@ -0,0 +1,154 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the LaTeX representation
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::bosons::{BosonProduct, QuadratureProduct};
+use struqture::fermions::{FermionHamiltonian, FermionProduct, HermitianFermionProduct};
+use struqture::latex::{LatexFormat, LatexOrdering, ToLatex};
+use struqture::mixed_systems::MixedProduct;
+use struqture::prelude::*;
+use struqture::spins::{
+    DecoherenceProduct, PauliProduct, PlusMinusProduct, SpinHamiltonianSystem,
+    SpinLindbladNoiseOperator, SpinOperator,
+};
+use test_case::test_case;
+
+// Test the LaTeX representation of products
+#[test]
+fn products() {
+    assert_eq!(
+        PauliProduct::new().x(0).z(12).to_latex(),
+        r"\sigma^x_{0} \sigma^z_{12}"
+    );
+    assert_eq!(PauliProduct::new().to_latex(), r"\mathbb{1}");
+    assert_eq!(DecoherenceProduct::new().iy(1).to_latex(), r"i\sigma^y_{1}");
+    assert_eq!(
+        PlusMinusProduct::new().plus(0).minus(1).to_latex(),
+        r"\sigma^+_{0} \sigma^-_{1}"
+    );
+    assert_eq!(
+        BosonProduct::new([0, 0], [1]).unwrap().to_latex(),
+        r"b^{\dagger}_{0} b^{\dagger}_{0} b_{1}"
+    );
+    assert_eq!(
+        FermionProduct::new([1], [0]).unwrap().to_latex(),
+        r"c^{\dagger}_{1} c_{0}"
+    );
+    assert_eq!(
+        QuadratureProduct::new().x(0).x(0).p(0).p(1).to_latex(),
+        r"x_{0}^{2} p_{0} p_{1}"
+    );
+    let mixed = MixedProduct::new(
+        [PauliProduct::new().x(0), PauliProduct::new().z(1)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    assert_eq!(
+        mixed.to_latex(),
+        r"\sigma^x_{0,0} \sigma^z_{1,1} b^{\dagger}_{0,0} b_{0,1}"
+    );
+}
+
+// Test the formatting of numeric and symbolic coefficients
+#[test_case(CalculatorComplex::new(0.5, 0.0), r"0.5\, \sigma^x_{0}"; "real")]
+#[test_case(CalculatorComplex::new(-2.0, 0.0), r"-2\, \sigma^x_{0}"; "negative")]
+#[test_case(CalculatorComplex::new(0.0, -0.5), r"-0.5i\, \sigma^x_{0}"; "imaginary")]
+#[test_case(CalculatorComplex::new(1.0, -2.0), r"(1 - 2i)\, \sigma^x_{0}"; "complex")]
+#[test_case(CalculatorComplex::from("theta"), r"theta\, \sigma^x_{0}"; "symbol")]
+#[test_case(CalculatorComplex::from("2*theta"), r"(2*theta)\, \sigma^x_{0}"; "expression")]
+#[test_case(CalculatorComplex::new("a", 1.0), r"(a + 1i)\, \sigma^x_{0}"; "symbolic complex")]
+fn coefficients(coefficient: CalculatorComplex, expected: &str) {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), coefficient)
+        .unwrap();
+    assert_eq!(so.to_latex(), expected);
+}
+
+// Test the hermitian conjugates of Hamiltonians and the terms of noise operators
+#[test]
+fn hamiltonians_and_noise() {
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    fh.add_operator_product(
+        HermitianFermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(-2.0),
+    )
+    .unwrap();
+    assert_eq!(
+        fh.to_latex(),
+        r"(1i\, c^{\dagger}_{0} c_{1} + \mathrm{h.c.}) - 2\, c^{\dagger}_{1} c_{1}"
+    );
+
+    let mut noise = SpinLindbladNoiseOperator::new();
+    noise
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().z(0),
+                DecoherenceProduct::new().x(1),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(
+        noise.to_latex(),
+        r"0.5\, \mathcal{D}[\sigma^z_{0}, \sigma^x_{1}]"
+    );
+
+    let mut system = SpinHamiltonianSystem::new(Some(2));
+    system
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(1.5))
+        .unwrap();
+    assert_eq!(system.to_latex(), "1.5");
+    assert_eq!(SpinHamiltonianSystem::new(None).to_latex(), "0");
+}
+
+// Test the ordering, truncation and precision of the LaTeX representation
+#[test]
+fn format_options() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from("h"))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::from(-3.0))
+        .unwrap();
+    assert_eq!(
+        so.to_latex(),
+        r"1\, \sigma^x_{0} + h\, \sigma^z_{1} - 3\, \sigma^y_{2}"
+    );
+
+    let mut format = LatexFormat {
+        ordering: LatexOrdering::Magnitude,
+        ..LatexFormat::default()
+    };
+    assert_eq!(
+        so.to_latex_with(&format),
+        r"-3\, \sigma^y_{2} + 1\, \sigma^x_{0} + h\, \sigma^z_{1}"
+    );
+
+    format.max_terms = Some(2);
+    format.precision = Some(1);
+    assert_eq!(
+        so.to_latex_with(&format),
+        r"-3.0\, \sigma^y_{2} + 1.0\, \sigma^x_{0} + \dots"
+    );
+
+    format.max_terms = Some(0);
+    assert_eq!(so.to_latex_with(&format), r"\dots");
+}
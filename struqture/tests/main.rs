@@ -14,12 +14,19 @@ use tinyvec::TinyVec;
 
 type BosonTinyVec = TinyVec<[usize; 2]>;
 
+#[cfg(test)]
+mod basis;
+
 #[cfg(test)]
 mod bosons;
 
 #[cfg(test)]
 mod fermions;
 
+#[cfg(test)]
+#[cfg(feature = "io")]
+mod hamiltonian_builder;
+
 #[cfg(test)]
 mod spins;
 
@@ -28,3 +35,26 @@ mod mixed_systems;
 
 #[cfg(test)]
 mod mappings;
+
+#[cfg(test)]
+#[cfg(feature = "io")]
+mod migrate;
+
+#[cfg(test)]
+mod models;
+
+#[cfg(test)]
+#[cfg(feature = "io")]
+mod io;
+
+#[cfg(test)]
+mod labeling;
+
+#[cfg(test)]
+mod latex;
+
+#[cfg(test)]
+mod time_dependent;
+
+#[cfg(test)]
+mod transforms;
@@ -0,0 +1,205 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionLindbladNoiseOperator,
+    FermionLindbladNoiseSystem, FermionLindbladOpenSystem, FermionOperator, FermionProduct,
+    FermionSystem, HermitianFermionProduct,
+};
+use struqture::mappings::{BravyiKitaevFermionToSpin, JordanWignerFermionToSpin};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian, SpinOperator};
+use test_case::test_case;
+
+// Transforms a single product in a FermionSystem with a fixed number of modes
+fn bk_system_operator(
+    creators: Vec<usize>,
+    annihilators: Vec<usize>,
+    number_modes: usize,
+) -> SpinOperator {
+    let mut fs = FermionSystem::new(Some(number_modes));
+    fs.add_operator_product(
+        FermionProduct::new(creators, annihilators).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    fs.bravyi_kitaev().operator().clone()
+}
+
+#[test]
+fn test_bk_number_operators() {
+    let fp = FermionProduct::new([0], [0]).unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::new(-0.5, 0.0))
+        .unwrap();
+    assert_eq!(fp.bravyi_kitaev(), so);
+
+    let fp = FermionProduct::new([1], [1]).unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().z(0).z(1),
+        CalculatorComplex::new(-0.5, 0.0),
+    )
+    .unwrap();
+    assert_eq!(fp.bravyi_kitaev(), so);
+
+    let fp = FermionProduct::new([], []).unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(fp.bravyi_kitaev(), so);
+}
+
+#[test]
+fn test_bk_single_mode_equals_jw() {
+    let fp = FermionProduct::new([0], []).unwrap();
+    assert_eq!(fp.bravyi_kitaev(), fp.jordan_wigner());
+    let fp = FermionProduct::new([], [0]).unwrap();
+    assert_eq!(fp.bravyi_kitaev(), fp.jordan_wigner());
+}
+
+#[test_case(4; "power of two")]
+#[test_case(5; "not a power of two")]
+fn test_bk_anticommutation(number_modes: usize) {
+    let mut identity = SpinOperator::new();
+    identity
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    for i in 0..number_modes {
+        for j in 0..number_modes {
+            let creator_i = bk_system_operator(vec![i], vec![], number_modes);
+            let annihilator_j = bk_system_operator(vec![], vec![j], number_modes);
+            let annihilator_i = bk_system_operator(vec![], vec![i], number_modes);
+
+            let anticommutator = creator_i.clone() * annihilator_j.clone()
+                + annihilator_j.clone() * creator_i.clone();
+            if i == j {
+                assert_eq!(anticommutator, identity);
+            } else {
+                assert!(anticommutator.is_empty());
+            }
+
+            let anticommutator =
+                annihilator_i.clone() * annihilator_j.clone() + annihilator_j * annihilator_i;
+            assert!(anticommutator.is_empty());
+
+            let product = bk_system_operator(vec![i], vec![j], number_modes);
+            assert_eq!(
+                product,
+                creator_i * bk_system_operator(vec![], vec![j], number_modes)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bk_fermion_operator() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([1], [0]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(0).z(1),
+        CalculatorComplex::new(-0.5, 0.0),
+    )
+    .unwrap();
+
+    assert_eq!(fo.bravyi_kitaev(), so);
+}
+
+#[test]
+fn test_bk_fermion_hamiltonian() {
+    let hfp = HermitianFermionProduct::new([0], [1]).unwrap();
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(hfp.clone(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().x(0).z(1), CalculatorFloat::from(-0.5))
+        .unwrap();
+
+    assert_eq!(fh.bravyi_kitaev(), sh);
+    assert_eq!(hfp.bravyi_kitaev(), sh);
+
+    let fhs = FermionHamiltonianSystem::from_hamiltonian(fh, Some(2)).unwrap();
+    assert_eq!(fhs.bravyi_kitaev().hamiltonian(), &sh);
+    assert_eq!(fhs.bravyi_kitaev().number_spins(), 2);
+}
+
+#[test]
+fn test_bk_fermion_system_uses_number_modes() {
+    let fp = FermionProduct::new([0], []).unwrap();
+    let mut fs = FermionSystem::new(Some(4));
+    fs.add_operator_product(fp.clone(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+
+    // The update set of mode 0 for four modes is {0, 1, 3}
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).x(1).x(3),
+        CalculatorComplex::new(0.5, 0.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(0).x(1).x(3),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+
+    let bk_system = fs.bravyi_kitaev();
+    assert_eq!(bk_system.operator(), &so);
+    assert_eq!(bk_system.number_spins(), 4);
+    assert_ne!(fp.bravyi_kitaev(), so);
+}
+
+#[test]
+fn test_bk_noise_and_open_system() {
+    let fp = FermionProduct::new([0], [1]).unwrap();
+    let mut flno = FermionLindbladNoiseOperator::new();
+    flno.add_operator_product((fp.clone(), fp.clone()), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    let spin_noise = flno.bravyi_kitaev();
+    assert!(!spin_noise.is_empty());
+
+    let flns = FermionLindbladNoiseSystem::from_operator(flno, Some(3)).unwrap();
+    let spin_noise_system = flns.bravyi_kitaev();
+    assert_eq!(spin_noise_system.number_spins(), 3);
+
+    let mut fhs = FermionHamiltonianSystem::new(Some(3));
+    fhs.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    let flos = FermionLindbladOpenSystem::group(fhs.clone(), flns.clone()).unwrap();
+    let spin_open_system = flos.bravyi_kitaev();
+    assert_eq!(spin_open_system.system(), &fhs.bravyi_kitaev());
+    assert_eq!(spin_open_system.noise(), &spin_noise_system);
+}
@@ -0,0 +1,283 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+use qoqo_calculator::CalculatorComplex;
+use struqture::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionLindbladNoiseOperator,
+    FermionLindbladNoiseSystem, FermionLindbladOpenSystem, FermionOperator, FermionProduct,
+    FermionSystem, HermitianFermionProduct,
+};
+use struqture::mappings::{
+    BravyiKitaevEncoding, BravyiKitaevFermionToSpin, FermionToSpin, FermionToSpinEncoding,
+    JordanWignerEncoding, JordanWignerFermionToSpin, ParityEncoding,
+};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinOperator};
+use struqture::StruqtureError;
+use test_case::test_case;
+
+// Custom binary-tree encoding equivalent to the parity encoding, defined outside of struqture
+struct CustomParityEncoding;
+
+impl FermionToSpinEncoding for CustomParityEncoding {
+    fn update_set(&self, mode: usize, number_modes: usize) -> Vec<usize> {
+        (mode..number_modes).collect()
+    }
+
+    fn parity_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        (0..mode).rev().take(1).collect()
+    }
+
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        (0..=mode).rev().take(2).collect()
+    }
+}
+
+// Custom encoding whose update set does not contain the mode itself
+struct InvalidUpdateEncoding;
+
+impl FermionToSpinEncoding for InvalidUpdateEncoding {
+    fn update_set(&self, mode: usize, number_modes: usize) -> Vec<usize> {
+        (mode + 1..number_modes).collect()
+    }
+
+    fn parity_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        (0..mode).collect()
+    }
+
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        vec![mode]
+    }
+}
+
+// Custom encoding whose parity set reaches beyond the number of modes
+struct OutOfRangeEncoding;
+
+impl FermionToSpinEncoding for OutOfRangeEncoding {
+    fn update_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        vec![mode]
+    }
+
+    fn parity_set(&self, _mode: usize, number_modes: usize) -> Vec<usize> {
+        vec![number_modes]
+    }
+
+    fn occupation_set(&self, mode: usize, _number_modes: usize) -> Vec<usize> {
+        vec![mode]
+    }
+}
+
+// Transforms a single product in a FermionSystem with a fixed number of modes
+fn encoded_system_operator(
+    encoding: &dyn FermionToSpinEncoding,
+    creators: Vec<usize>,
+    annihilators: Vec<usize>,
+    number_modes: usize,
+) -> SpinOperator {
+    let mut fs = FermionSystem::new(Some(number_modes));
+    fs.add_operator_product(
+        FermionProduct::new(creators, annihilators).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    fs.fermion_to_spin(encoding).unwrap().operator().clone()
+}
+
+fn identity() -> SpinOperator {
+    let mut identity = SpinOperator::new();
+    identity
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    identity
+}
+
+#[test_case(&JordanWignerEncoding, 4; "jordan wigner")]
+#[test_case(&ParityEncoding, 4; "parity")]
+#[test_case(&ParityEncoding, 5; "parity odd")]
+#[test_case(&BravyiKitaevEncoding, 4; "bravyi kitaev")]
+#[test_case(&BravyiKitaevEncoding, 5; "bravyi kitaev not a power of two")]
+#[test_case(&CustomParityEncoding, 3; "custom")]
+fn test_encoding_anticommutation(encoding: &dyn FermionToSpinEncoding, number_modes: usize) {
+    for i in 0..number_modes {
+        for j in 0..number_modes {
+            let creator_i = encoded_system_operator(encoding, vec![i], vec![], number_modes);
+            let annihilator_j = encoded_system_operator(encoding, vec![], vec![j], number_modes);
+            let annihilator_i = encoded_system_operator(encoding, vec![], vec![i], number_modes);
+
+            let anticommutator = creator_i.clone() * annihilator_j.clone()
+                + annihilator_j.clone() * creator_i.clone();
+            if i == j {
+                assert_eq!(anticommutator, identity());
+            } else {
+                assert!(anticommutator.is_empty());
+            }
+
+            let anticommutator =
+                annihilator_i.clone() * annihilator_j.clone() + annihilator_j * annihilator_i;
+            assert!(anticommutator.is_empty());
+        }
+    }
+}
+
+#[test_case(&JordanWignerEncoding; "jordan wigner")]
+#[test_case(&ParityEncoding; "parity")]
+#[test_case(&BravyiKitaevEncoding; "bravyi kitaev")]
+fn test_encoding_total_number_operator(encoding: &dyn FermionToSpinEncoding) {
+    // Every number operator is mapped to (1 - Z_{O(j)}) / 2 with distinct occupation sets O(j)
+    let number_modes = 4;
+    let mut number_operator = FermionOperator::new();
+    for mode in 0..number_modes {
+        number_operator
+            .add_operator_product(
+                FermionProduct::new([mode], [mode]).unwrap(),
+                CalculatorComplex::new(1.0, 0.0),
+            )
+            .unwrap();
+    }
+    let fs = FermionSystem::from_operator(number_operator, Some(number_modes)).unwrap();
+    let encoded = fs.fermion_to_spin(encoding).unwrap();
+    assert_eq!(
+        encoded.get(&PauliProduct::new()),
+        &CalculatorComplex::new(2.0, 0.0)
+    );
+    assert_eq!(encoded.len(), number_modes + 1);
+}
+
+#[test]
+fn test_jordan_wigner_encoding_equals_jordan_wigner() {
+    let mut fo = FermionOperator::new();
+    fo.add_operator_product(
+        FermionProduct::new([0, 2], [1]).unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    fo.add_operator_product(
+        FermionProduct::new([3], [0, 3]).unwrap(),
+        CalculatorComplex::new(-0.5, 0.0),
+    )
+    .unwrap();
+    assert_eq!(
+        fo.fermion_to_spin(&JordanWignerEncoding).unwrap(),
+        fo.jordan_wigner()
+    );
+
+    let hfp = HermitianFermionProduct::new([0], [2]).unwrap();
+    let mut fh = FermionHamiltonian::new();
+    fh.add_operator_product(hfp.clone(), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    assert_eq!(
+        fh.fermion_to_spin(&JordanWignerEncoding).unwrap(),
+        fh.jordan_wigner()
+    );
+}
+
+#[test]
+fn test_bravyi_kitaev_encoding_equals_bravyi_kitaev() {
+    let mut fhs = FermionHamiltonianSystem::new(Some(5));
+    fhs.add_operator_product(
+        HermitianFermionProduct::new([0], [3]).unwrap(),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    let mut flns = FermionLindbladNoiseSystem::new(Some(5));
+    let fp = FermionProduct::new([1], [4]).unwrap();
+    flns.add_operator_product((fp.clone(), fp), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    let flos = FermionLindbladOpenSystem::group(fhs, flns).unwrap();
+    assert_eq!(
+        flos.fermion_to_spin(&BravyiKitaevEncoding).unwrap(),
+        flos.bravyi_kitaev()
+    );
+}
+
+#[test]
+fn test_parity_encoding() {
+    // a^dagger_1 a_1 = (1 - Z_0 Z_1) / 2 in the parity encoding
+    let fp = FermionProduct::new([1], [1]).unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().z(0).z(1),
+        CalculatorComplex::new(-0.5, 0.0),
+    )
+    .unwrap();
+    assert_eq!(fp.fermion_to_spin(&ParityEncoding).unwrap(), so);
+
+    // a^dagger_0 = X_1 (X_0 - i Y_0) / 2 for two modes
+    let mut fs = FermionSystem::new(Some(2));
+    fs.add_operator_product(
+        FermionProduct::new([0], []).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().x(0).x(1),
+        CalculatorComplex::new(0.5, 0.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(0).x(1),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    assert_eq!(fs.fermion_to_spin(&ParityEncoding).unwrap().operator(), &so);
+
+    let mut fs_custom = FermionSystem::new(Some(3));
+    fs_custom
+        .add_operator_product(
+            FermionProduct::new([0, 2], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+        .unwrap();
+    assert_eq!(
+        fs_custom.fermion_to_spin(&ParityEncoding).unwrap(),
+        fs_custom.fermion_to_spin(&CustomParityEncoding).unwrap()
+    );
+
+    let mut flno = FermionLindbladNoiseOperator::new();
+    let fp = FermionProduct::new([0], [1]).unwrap();
+    flno.add_operator_product((fp.clone(), fp), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(
+        flno.fermion_to_spin(&ParityEncoding).unwrap(),
+        flno.fermion_to_spin(&CustomParityEncoding).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_encoding_sets() {
+    let fp = FermionProduct::new([0], [1]).unwrap();
+    assert!(matches!(
+        fp.fermion_to_spin(&InvalidUpdateEncoding),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        fp.fermion_to_spin(&OutOfRangeEncoding),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        JordanWignerEncoding.ladder_operator(1, 1, true),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    let mut fhs = FermionHamiltonianSystem::new(Some(3));
+    fhs.add_operator_product(
+        HermitianFermionProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    assert!(fhs.fermion_to_spin(&InvalidUpdateEncoding).is_err());
+    assert!(fhs.fermion_to_spin(&OutOfRangeEncoding).is_err());
+    assert!(fhs.fermion_to_spin(&ParityEncoding).is_ok());
+}
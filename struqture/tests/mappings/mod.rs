@@ -10,5 +10,7 @@
 // express or implied. See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bravyi_kitaev_fermion_to_spin;
+mod fermion_to_spin_encoding;
 mod jordan_wigner_fermion_to_spin;
 mod jordan_wigner_spin_to_fermion;
@@ -0,0 +1,257 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the schema migration
+
+use qoqo_calculator::CalculatorComplex;
+use std::str::FromStr;
+use struqture::fermions::{FermionLindbladOpenSystem, FermionProduct, HermitianFermionProduct};
+use struqture::migrate::{json_version, library_version, migrate_json, migrate_json_by_type_name};
+use struqture::mixed_systems::{MixedProduct, MixedSystem};
+use struqture::prelude::*;
+use struqture::spins::{
+    PauliProduct, PlusMinusHamiltonian, PlusMinusOperator, PlusMinusProduct, SpinHamiltonianSystem,
+    SpinOperator,
+};
+use struqture::StruqtureError;
+
+// Test the migration of a struqture 2.x PauliHamiltonian to a SpinHamiltonianSystem
+#[test]
+fn migrate_pauli_hamiltonian() {
+    let input = r#"{"items":[["0Z",1.0],["0X1X","theta"]],"serialisation_meta":{"type_name":"PauliHamiltonian","min_version":[2,0,0],"version":"2.1.0"}}"#;
+    assert_eq!(json_version(input).unwrap(), (2, 0));
+
+    let migrated = migrate_json(input, (1, 0)).unwrap();
+    assert_eq!(json_version(&migrated).unwrap(), (1, 0));
+    let system: SpinHamiltonianSystem = serde_json::from_str(&migrated).unwrap();
+
+    let mut expected = SpinHamiltonianSystem::new(None);
+    expected
+        .add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().x(0).x(1), "theta".into())
+        .unwrap();
+    assert_eq!(system, expected);
+}
+
+// Test the migration of a struqture 2.x open system, migrating the system and the noise separately
+#[test]
+fn migrate_fermion_open_system() {
+    let input = r#"{
+        "system": {"items":[["c0a1",1.0,0.5]],"serialisation_meta":{"type_name":"FermionHamiltonian","min_version":[2,0,0],"version":"2.0.0"}},
+        "noise": {"items":[["c0a0","c0a0",0.1,0.0]],"serialisation_meta":{"type_name":"FermionLindbladNoiseOperator","min_version":[2,0,0],"version":"2.0.0"}},
+        "serialisation_meta":{"type_name":"FermionLindbladOpenSystem","min_version":[2,0,0],"version":"2.0.0"}
+    }"#;
+    let migrated = migrate_json(input, (1, 0)).unwrap();
+    let open_system: FermionLindbladOpenSystem = serde_json::from_str(&migrated).unwrap();
+
+    let mut expected = FermionLindbladOpenSystem::new(None);
+    expected
+        .system_mut()
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    expected
+        .noise_mut()
+        .add_operator_product((number.clone(), number), CalculatorComplex::new(0.1, 0.0))
+        .unwrap();
+    assert_eq!(open_system, expected);
+}
+
+// Test the migration of a struqture 2.x MixedOperator to a MixedSystem
+#[test]
+fn migrate_mixed_operator() {
+    let input = r#"{"items":[["S2Z:Bc0a3:Fc0a2:",2.0,0.0]],"n_spins":1,"n_bosons":1,"n_fermions":1,"serialisation_meta":{"type_name":"MixedOperator","min_version":[2,0,0],"version":"2.0.0"}}"#;
+    let migrated = migrate_json(input, (1, 2)).unwrap();
+    let system: MixedSystem = serde_json::from_str(&migrated).unwrap();
+
+    let mut expected = MixedSystem::new([None], [None], [None]);
+    expected
+        .add_operator_product(
+            MixedProduct::from_str("S2Z:Bc0a3:Fc0a2:").unwrap(),
+            CalculatorComplex::new(2.0, 0.0),
+        )
+        .unwrap();
+    assert_eq!(system, expected);
+}
+
+// Test that the version records of struqture 1.x objects are lowered to the target version
+#[test]
+fn migrate_version_records() {
+    let mut operator = PlusMinusOperator::new();
+    operator
+        .add_operator_product(
+            PlusMinusProduct::new().plus(0),
+            CalculatorComplex::new(1.0, 0.0),
+        )
+        .unwrap();
+    let input = serde_json::to_string(&operator).unwrap();
+    assert_eq!(json_version(&input).unwrap(), (1, 1));
+    assert_eq!(library_version().0, 1);
+
+    let migrated = migrate_json(&input, (1, 0)).unwrap();
+    assert_eq!(json_version(&migrated).unwrap(), (1, 0));
+    assert_eq!(
+        json_version(&migrate_json(&input, (1, 5)).unwrap()).unwrap(),
+        (1, 1)
+    );
+
+    let newer = input.replace(r#""minor_version":1"#, r#""minor_version":5"#);
+    assert_eq!(json_version(&newer).unwrap(), (1, 5));
+    assert_eq!(
+        json_version(&migrate_json(&newer, (1, 1)).unwrap()).unwrap(),
+        (1, 1)
+    );
+    assert_eq!(
+        serde_json::from_str::<PlusMinusOperator>(&migrate_json(&newer, (1, 1)).unwrap()).unwrap(),
+        operator
+    );
+    assert_eq!(
+        json_version(&serde_json::to_string(&PauliProduct::new().z(0)).unwrap()).unwrap(),
+        (1, 0)
+    );
+}
+
+// Test the errors of the migration
+#[test]
+fn migrate_errors() {
+    let input = r#"{"items":[],"serialisation_meta":{"type_name":"QubitUnknown","min_version":[2,0,0],"version":"2.0.0"}}"#;
+    assert!(matches!(
+        migrate_json(input, (1, 0)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        migrate_json("{}", (2, 0)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        migrate_json("not json", (1, 0)),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+    // A struqture 2.x PauliHamiltonian with a complex coefficient is not valid
+    let invalid = r#"{"items":[["0Z",1.0,1.0]],"serialisation_meta":{"type_name":"PauliHamiltonian","min_version":[2,0,0],"version":"2.0.0"}}"#;
+    assert!(matches!(
+        migrate_json(invalid, (1, 0)),
+        Err(StruqtureError::SerializationError { .. })
+    ));
+}
+
+// Test the export of struqture 1.x objects to struqture 2.x and back
+#[test]
+fn export_struqture_2_roundtrip() {
+    let mut system = SpinHamiltonianSystem::new(Some(3));
+    system
+        .add_operator_product(PauliProduct::new().z(0).x(2), "theta".into())
+        .unwrap();
+    let exported = system.to_struqture_2_json().unwrap();
+    assert_eq!(json_version(&exported).unwrap(), (2, 0));
+    let value: serde_json::Value = serde_json::from_str(&exported).unwrap();
+    assert_eq!(
+        value["serialisation_meta"]["type_name"],
+        serde_json::Value::from("PauliHamiltonian")
+    );
+    assert!(value.get("number_spins").is_none());
+    // The number of spins is not part of struqture 2.x
+    let imported = SpinHamiltonianSystem::from_struqture_2_json(&exported).unwrap();
+    assert_eq!(imported.operator(), system.operator());
+    assert_eq!(imported.current_number_spins(), 3);
+
+    let mut open_system = FermionLindbladOpenSystem::new(None);
+    open_system
+        .system_mut()
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let number = FermionProduct::new([0], [0]).unwrap();
+    open_system
+        .noise_mut()
+        .add_operator_product((number.clone(), number), CalculatorComplex::new(0.1, 0.0))
+        .unwrap();
+    let exported = open_system.to_struqture_2_json().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&exported).unwrap();
+    assert_eq!(
+        value["system"]["serialisation_meta"]["type_name"],
+        serde_json::Value::from("FermionHamiltonian")
+    );
+    assert_eq!(
+        FermionLindbladOpenSystem::from_struqture_2_json(&exported).unwrap(),
+        open_system
+    );
+
+    let mut mixed_system = MixedSystem::new([None], [None], [None]);
+    mixed_system
+        .add_operator_product(
+            MixedProduct::from_str("S2Z:Bc0a3:Fc0a2:").unwrap(),
+            CalculatorComplex::new(2.0, 0.0),
+        )
+        .unwrap();
+    let exported = mixed_system.to_struqture_2_json().unwrap();
+    assert_eq!(
+        MixedSystem::from_struqture_2_json(&exported).unwrap(),
+        mixed_system
+    );
+
+    let product = PauliProduct::new().x(1);
+    assert_eq!(
+        PauliProduct::from_struqture_2_json(&product.to_struqture_2_json().unwrap()).unwrap(),
+        product
+    );
+}
+
+// Test the migration by type name in both directions
+#[test]
+fn migrate_by_type_name() {
+    let mut operator = SpinOperator::new();
+    operator
+        .add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    let input = serde_json::to_string(&operator).unwrap();
+
+    let exported = migrate_json_by_type_name("SpinOperator", &input, (2, 0)).unwrap();
+    assert_eq!(json_version(&exported).unwrap(), (2, 0));
+    assert_eq!(
+        migrate_json_by_type_name("SpinOperator", &exported, (2, 0)).unwrap(),
+        exported
+    );
+
+    let imported = migrate_json_by_type_name("SpinOperator", &exported, (1, 0)).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SpinOperator>(&imported).unwrap(),
+        operator
+    );
+    // The struqture 2.x PauliOperator is migrated to a SpinSystem or its SpinOperator
+    let system = migrate_json_by_type_name("SpinSystem", &exported, (1, 0)).unwrap();
+    assert_eq!(json_version(&system).unwrap(), (1, 0));
+
+    assert!(matches!(
+        migrate_json("{}", (2, 0)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        migrate_json_by_type_name(
+            "PlusMinusHamiltonian",
+            &serde_json::to_string(&PlusMinusHamiltonian::new()).unwrap(),
+            (2, 0)
+        ),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        migrate_json_by_type_name("SpinOperator", &input, (3, 0)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
@@ -20,7 +20,9 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use struqture::bosons::BosonProduct;
 use struqture::fermions::FermionProduct;
-use struqture::mixed_systems::{HermitianMixedProduct, MixedHamiltonian, MixedOperator};
+use struqture::mixed_systems::{
+    HermitianMixedProduct, MixedHamiltonian, MixedOperator, MixedProduct,
+};
 use struqture::prelude::*;
 use struqture::spins::PauliProduct;
 use struqture::{OperateOnDensityMatrix, SpinIndex, StruqtureError};
@@ -747,7 +749,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedHamiltonianSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -803,6 +805,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -818,6 +825,128 @@ fn serde_compact() {
     );
 }
 
+// Test the multiplication: MixedHamiltonian * MixedOperator
+#[test]
+fn mul_mh_mo() {
+    let hp: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([0], [3]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mp: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().x(1)],
+        [BosonProduct::new([1], [2]).unwrap()],
+        [FermionProduct::new([1], [3]).unwrap()],
+    )
+    .unwrap();
+    let mut mh = MixedHamiltonian::new(1, 1, 1);
+    mh.add_operator_product(hp.clone(), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.add_operator_product(mp.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let mut expected = MixedOperator::new(1, 1, 1);
+    for (product, coefficient) in (hp * mp).unwrap() {
+        expected
+            .add_operator_product(
+                product,
+                CalculatorComplex::new(2.0, 2.0) * CalculatorComplex::from(coefficient),
+            )
+            .unwrap();
+    }
+    assert!(!expected.is_empty());
+    assert_eq!(mh.clone() * mo.clone(), Ok(expected));
+
+    let mo_mismatched = MixedOperator::new(1, 0, 1);
+    assert_eq!(
+        mh * mo_mismatched,
+        Err(StruqtureError::MissmatchedNumberSubsystems {
+            target_number_spin_subsystems: 1,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 1,
+            actual_number_spin_subsystems: 1,
+            actual_number_boson_subsystems: 0,
+            actual_number_fermion_subsystems: 1,
+        })
+    );
+}
+
+// Test that the multiplication of MixedHamiltonians checks the number of subsystems, even for empty Hamiltonians
+#[test]
+fn mul_so_so_error() {
+    let mh_0 = MixedHamiltonian::new(2, 1, 0);
+    let mh_1 = MixedHamiltonian::new(1, 1, 0);
+    assert_eq!(
+        mh_0 * mh_1,
+        Err(StruqtureError::MissmatchedNumberSubsystems {
+            target_number_spin_subsystems: 2,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 0,
+            actual_number_spin_subsystems: 1,
+            actual_number_boson_subsystems: 1,
+            actual_number_fermion_subsystems: 0,
+        })
+    );
+}
+
+// Test the from_operator function of the MixedHamiltonian
+#[test]
+fn from_operator() {
+    let hermitian_product = HermitianMixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let valid = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let conjugated = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([1], [0]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let natural = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let natural_hermitian = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+
+    let mut mo = MixedOperator::new(1, 1, 0);
+    mo.add_operator_product(conjugated, CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    mo.add_operator_product(valid, CalculatorComplex::from(3.0))
+        .unwrap();
+    mo.add_operator_product(natural, CalculatorComplex::new(1.0, 5.0))
+        .unwrap();
+
+    let mh = MixedHamiltonian::from_operator(&mo).unwrap();
+    assert_eq!(mh.len(), 2);
+    assert_eq!(
+        mh.get(&hermitian_product),
+        &CalculatorComplex::new(2.0, -1.0)
+    );
+    assert_eq!(mh.get(&natural_hermitian), &CalculatorComplex::from(1.0));
+    assert_eq!(mh.to_operator(), mo.hermitian_part());
+    assert_eq!(
+        MixedHamiltonian::from_operator(&mh.to_operator()).unwrap(),
+        mh
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_hamiltonian_schema() {
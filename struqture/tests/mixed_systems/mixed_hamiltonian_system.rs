@@ -18,14 +18,19 @@ use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use struqture::bosons::BosonProduct;
-use struqture::fermions::FermionProduct;
+use struqture::bosons::{
+    BosonHamiltonian, BosonHamiltonianSystem, BosonProduct, HermitianBosonProduct,
+};
+use struqture::fermions::{
+    FermionHamiltonian, FermionHamiltonianSystem, FermionProduct, HermitianFermionProduct,
+};
 use struqture::prelude::*;
-use struqture::spins::PauliProduct;
+use struqture::spins::{PauliProduct, SpinHamiltonian, SpinHamiltonianSystem};
 use struqture::StruqtureError;
 
 use struqture::mixed_systems::{
-    HermitianMixedProduct, MixedHamiltonian, MixedHamiltonianSystem, MixedSystem,
+    HermitianMixedProduct, MixedHamiltonian, MixedHamiltonianSystem, MixedSubsystemKind,
+    MixedSystem,
 };
 use struqture::OperateOnDensityMatrix;
 use struqture::SpinIndex;
@@ -1025,7 +1030,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedHamiltonianSystem",
-                len: 4,
+                len: 5,
             },
             Token::Str("number_spins"),
             Token::Seq { len: Some(1) },
@@ -1045,7 +1050,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "MixedHamiltonianSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -1101,6 +1106,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -1112,11 +1122,204 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Test the subsystem views of MixedHamiltonianSystem
+#[test]
+fn subsystem_views() {
+    let mut mhs = MixedHamiltonianSystem::new([Some(2)], [Some(2)], [Some(3)]);
+    let spin_only = HermitianMixedProduct::new(
+        [PauliProduct::new().z(1)],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let boson_only = HermitianMixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let fermion_only = HermitianMixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let boson_fermion = HermitianMixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([1], [1]).unwrap()],
+        [FermionProduct::new([1], [1]).unwrap()],
+    )
+    .unwrap();
+    mhs.add_operator_product(spin_only.clone(), CalculatorComplex::from(1.5))
+        .unwrap();
+    mhs.add_operator_product(boson_only, CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    mhs.add_operator_product(fermion_only, CalculatorComplex::new(0.0, -1.0))
+        .unwrap();
+    mhs.add_operator_product(boson_fermion.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let mut spin_hamiltonian = SpinHamiltonian::new();
+    spin_hamiltonian
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(1.5))
+        .unwrap();
+    assert_eq!(mhs.spin_subsystem_operator(0).unwrap(), spin_hamiltonian);
+
+    let mut boson_hamiltonian = BosonHamiltonian::new();
+    boson_hamiltonian
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+    assert_eq!(mhs.boson_subsystem_operator(0).unwrap(), boson_hamiltonian);
+
+    let mut fermion_hamiltonian = FermionHamiltonian::new();
+    fermion_hamiltonian
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [2]).unwrap(),
+            CalculatorComplex::new(0.0, -1.0),
+        )
+        .unwrap();
+    assert_eq!(
+        mhs.fermion_subsystem_operator(0).unwrap(),
+        fermion_hamiltonian
+    );
+
+    let fermion_terms: Vec<&HermitianMixedProduct> = mhs
+        .terms_acting_on_subsystem(MixedSubsystemKind::Fermion, 0)
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(fermion_terms.len(), 2);
+    assert!(fermion_terms.contains(&&boson_fermion));
+    assert!(mhs.spin_subsystem_operator(1).is_err());
+    assert!(mhs
+        .terms_acting_on_subsystem(MixedSubsystemKind::Boson, 1)
+        .is_err());
+}
+
+// Test the insertion, removal and permutation of subsystems of MixedHamiltonianSystem
+#[test]
+fn rearrange_subsystems() {
+    let key = HermitianMixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [
+            BosonProduct::new([0], [1]).unwrap(),
+            BosonProduct::new([1], [0]).unwrap(),
+        ],
+        [],
+    )
+    .unwrap();
+    let mut mhs = MixedHamiltonianSystem::new([Some(1)], [Some(2), Some(2)], []);
+    mhs.add_operator_product(key.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+
+    // Swapping the bosonic subsystems exchanges the stored term with its hermitian conjugate
+    mhs.permute_subsystems(MixedSubsystemKind::Boson, &[1, 0])
+        .unwrap();
+    assert_eq!(mhs.get(&key), &CalculatorComplex::new(1.0, -2.0));
+    assert_eq!(mhs.number_bosonic_modes(), vec![2, 2]);
+
+    mhs.add_spin_subsystem(None);
+    mhs.remove_spin_subsystem(1).unwrap();
+    let mut expected = MixedHamiltonianSystem::new([Some(1)], [Some(2), Some(2)], []);
+    expected
+        .add_operator_product(key, CalculatorComplex::new(1.0, -2.0))
+        .unwrap();
+    assert_eq!(mhs, expected);
+
+    assert!(mhs.remove_boson_subsystem(0).is_err());
+    assert!(mhs
+        .permute_subsystems(MixedSubsystemKind::Fermion, &[0])
+        .is_err());
+    assert_eq!(mhs, expected);
+}
+
+// Test the embedding of spin, boson and fermion Hamiltonian systems into a MixedHamiltonianSystem
+#[test]
+fn embed_systems() {
+    let mut shs = SpinHamiltonianSystem::new(None);
+    shs.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(1.5))
+        .unwrap();
+    let mut bhs = BosonHamiltonianSystem::new(Some(3));
+    bhs.add_operator_product(
+        HermitianBosonProduct::new([0], [2]).unwrap(),
+        CalculatorComplex::new(1.0, 1.0),
+    )
+    .unwrap();
+    let mut fhs = FermionHamiltonianSystem::new(None);
+    fhs.add_operator_product(
+        HermitianFermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+
+    let mhs = MixedHamiltonianSystem::embed([shs.clone()], [bhs.clone()], [fhs.clone()]);
+    let mut expected = MixedHamiltonianSystem::new([None], [Some(3)], [None]);
+    expected
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new().z(0)],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([0], [2]).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(1.0, 1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([1], [1]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(2.0),
+        )
+        .unwrap();
+    assert_eq!(mhs, expected);
+
+    assert_eq!(
+        MixedHamiltonianSystem::from_spin_hamiltonian_system(shs.clone())
+            .spin_subsystem_operator(0)
+            .unwrap(),
+        shs.hamiltonian().clone()
+    );
+    assert_eq!(
+        MixedHamiltonianSystem::from_boson_hamiltonian_system(bhs.clone())
+            .boson_subsystem_operator(0)
+            .unwrap(),
+        bhs.hamiltonian().clone()
+    );
+    assert_eq!(
+        MixedHamiltonianSystem::from_fermion_hamiltonian_system(fhs.clone())
+            .fermion_subsystem_operator(0)
+            .unwrap(),
+        fhs.hamiltonian().clone()
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(4))]
@@ -755,7 +755,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedLindbladNoiseOperatorSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -844,6 +844,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -26,6 +26,7 @@ use struqture::StruqtureError;
 
 use struqture::mixed_systems::{
     MixedDecoherenceProduct, MixedLindbladNoiseOperator, MixedLindbladNoiseSystem,
+    MixedSubsystemKind,
 };
 use struqture::OperateOnDensityMatrix;
 use struqture::SpinIndex;
@@ -988,7 +989,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedLindbladNoiseSystem",
-                len: 4,
+                len: 5,
             },
             Token::Str("number_spins"),
             Token::Seq { len: Some(1) },
@@ -1008,7 +1009,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "MixedLindbladNoiseOperatorSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -1097,6 +1098,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -1108,11 +1114,98 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Test the insertion, removal and permutation of subsystems of MixedLindbladNoiseSystem
+#[test]
+fn rearrange_subsystems() {
+    let left = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().x(0)],
+        [],
+        [FermionProduct::new([0], [1]).unwrap()],
+    )
+    .unwrap();
+    let right = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [],
+        [FermionProduct::new([1], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut mlns = MixedLindbladNoiseSystem::new([Some(1)], [], [Some(2)]);
+    mlns.add_operator_product((left.clone(), right.clone()), CalculatorComplex::from(0.5))
+        .unwrap();
+    let original = mlns.clone();
+
+    mlns.add_boson_subsystem(Some(3));
+    mlns.add_spin_subsystem(Some(1));
+    mlns.permute_subsystems(MixedSubsystemKind::Spin, &[1, 0])
+        .unwrap();
+    let shifted = |product: &MixedDecoherenceProduct| {
+        MixedDecoherenceProduct::new(
+            [
+                DecoherenceProduct::new(),
+                product.spins().next().unwrap().clone(),
+            ],
+            [BosonProduct::new([], []).unwrap()],
+            product.fermions().cloned(),
+        )
+        .unwrap()
+    };
+    let mut expected = MixedLindbladNoiseSystem::new([Some(1), Some(1)], [Some(3)], [Some(2)]);
+    expected
+        .add_operator_product(
+            (shifted(&left), shifted(&right)),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(mlns, expected);
+
+    assert!(mlns.remove_spin_subsystem(1).is_err());
+    assert_eq!(mlns, expected);
+
+    mlns.remove_spin_subsystem(0).unwrap();
+    mlns.remove_boson_subsystem(0).unwrap();
+    assert_eq!(mlns, original);
+}
+
+#[test]
+fn is_completely_positive() {
+    let left = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().z(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let right = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().x(0)],
+        [BosonProduct::new([], [0]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut system = MixedLindbladNoiseSystem::new([Some(1)], [Some(1)], [Some(1)]);
+    system
+        .set((left.clone(), left.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    system
+        .set((right.clone(), right.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert!(system.is_completely_positive(1e-10).unwrap());
+
+    system
+        .set((left.clone(), right.clone()), CalculatorComplex::from(2.0))
+        .unwrap();
+    system
+        .set((right, left), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert!(!system.is_completely_positive(1e-10).unwrap());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(4))]
@@ -19,7 +19,7 @@ use struqture::bosons::BosonProduct;
 use struqture::fermions::FermionProduct;
 use struqture::mixed_systems::{
     HermitianMixedProduct, MixedDecoherenceProduct, MixedHamiltonianSystem,
-    MixedLindbladNoiseSystem, MixedLindbladOpenSystem,
+    MixedLindbladNoiseSystem, MixedLindbladOpenSystem, MixedSubsystemKind,
 };
 use struqture::prelude::*;
 use struqture::spins::{DecoherenceProduct, PauliProduct};
@@ -910,7 +910,7 @@ fn serde_compact() {
             Token::Str("system"),
             Token::Struct {
                 name: "MixedHamiltonianSystem",
-                len: 4,
+                len: 5,
             },
             Token::Str("number_spins"),
             Token::Seq { len: Some(1) },
@@ -930,7 +930,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "MixedHamiltonianSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -986,6 +986,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -997,11 +1002,14 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::Str("noise"),
             Token::Struct {
                 name: "MixedLindbladNoiseSystem",
-                len: 4,
+                len: 5,
             },
             Token::Str("number_spins"),
             Token::Seq { len: Some(1) },
@@ -1021,7 +1029,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "MixedLindbladNoiseOperatorSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -1110,6 +1118,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -1121,6 +1134,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::StructEnd,
         ],
@@ -1402,6 +1418,118 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the insertion, removal and permutation of subsystems of MixedLindbladOpenSystem
+#[test]
+fn rearrange_subsystems() {
+    let hp = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let dp = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().x(0)],
+        [BosonProduct::new([0], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    let mut mlos = MixedLindbladOpenSystem::new([Some(1)], [Some(2)], []);
+    mlos.system_mut()
+        .add_operator_product(hp, CalculatorComplex::from(1.0))
+        .unwrap();
+    mlos.noise_mut()
+        .add_operator_product((dp.clone(), dp), CalculatorComplex::from(0.5))
+        .unwrap();
+    let original = mlos.clone();
+
+    mlos.add_boson_subsystem(None);
+    mlos.permute_subsystems(MixedSubsystemKind::Boson, &[1, 0])
+        .unwrap();
+    assert_eq!(mlos.system().number_bosonic_modes(), vec![0, 2]);
+    assert_eq!(mlos.noise().number_bosonic_modes(), vec![0, 2]);
+    // Terms act on the removed bosonic subsystem, neither part is changed
+    assert!(mlos.remove_boson_subsystem(1).is_err());
+
+    mlos.remove_boson_subsystem(0).unwrap();
+    assert_eq!(mlos, original);
+}
+
+// Test the direct_sum function of the MixedLindbladOpenSystem
+#[test]
+fn direct_sum() {
+    let hp = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let dp = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().x(0)],
+        [BosonProduct::new([0], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    let mut first = MixedLindbladOpenSystem::new([Some(1)], [Some(2)], []);
+    first
+        .system_mut()
+        .add_operator_product(hp.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    first
+        .noise_mut()
+        .add_operator_product((dp.clone(), dp.clone()), CalculatorComplex::from(0.5))
+        .unwrap();
+    let mut second = MixedLindbladOpenSystem::new([None], [None], []);
+    second
+        .system_mut()
+        .add_operator_product(hp.clone(), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    second
+        .noise_mut()
+        .add_operator_product((dp.clone(), dp.clone()), CalculatorComplex::from(0.25))
+        .unwrap();
+
+    let combined = first.direct_sum(&second, 2).unwrap();
+    let shifted_hp = HermitianMixedProduct::new(
+        [PauliProduct::new().z(2)],
+        [BosonProduct::new([2], [3]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let shifted_dp = MixedDecoherenceProduct::new(
+        [DecoherenceProduct::new().x(2)],
+        [BosonProduct::new([2], []).unwrap()],
+        [],
+    )
+    .unwrap();
+    assert_eq!(combined.system().len(), 2);
+    assert_eq!(combined.system().get(&hp), &CalculatorComplex::from(1.0));
+    assert_eq!(
+        combined.system().get(&shifted_hp),
+        &CalculatorComplex::new(0.0, 2.0)
+    );
+    assert_eq!(combined.noise().len(), 2);
+    assert_eq!(
+        combined.noise().get(&(dp.clone(), dp.clone())),
+        &CalculatorComplex::from(0.5)
+    );
+    assert_eq!(
+        combined.noise().get(&(shifted_dp.clone(), shifted_dp)),
+        &CalculatorComplex::from(0.25)
+    );
+    assert_eq!(combined.number_spins(), vec![3]);
+    assert_eq!(combined.number_bosonic_modes(), vec![4]);
+
+    // The number of spins and modes stays fixed if it is fixed for both systems
+    let doubled = first.direct_sum(&first, 2).unwrap();
+    assert_eq!(doubled.system().number_spins(), vec![3]);
+    assert_eq!(doubled.noise().number_bosonic_modes(), vec![4]);
+
+    // Overlapping modes and different subsystems
+    assert!(first.direct_sum(&second, 1).is_err());
+    let fermionic = MixedLindbladOpenSystem::new([None], [None], [None]);
+    assert!(first.direct_sum(&fermionic, 2).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(4))]
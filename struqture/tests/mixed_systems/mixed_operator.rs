@@ -24,7 +24,9 @@ use struqture::prelude::*;
 use struqture::spins::PauliProduct;
 use struqture::StruqtureError;
 
-use struqture::mixed_systems::{MixedOperator, MixedProduct};
+use struqture::mixed_systems::{
+    HermitianMixedProduct, MixedHamiltonian, MixedOperator, MixedProduct,
+};
 use struqture::OperateOnDensityMatrix;
 use struqture::SpinIndex;
 use test_case::test_case;
@@ -75,6 +77,37 @@ fn new_with_capacity(
     assert_eq!(number_fermionic_modes, mo.current_number_fermionic_modes());
 }
 
+// Test the constant term of MixedOperator
+#[test]
+fn constant() {
+    let mut mo = MixedOperator::new(1, 1, 1);
+    let identity = MixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::default()],
+        [FermionProduct::default()],
+    )
+    .unwrap();
+    assert_eq!(mo.identity_index(), identity);
+    mo.set_constant(CalculatorComplex::new(1.0, 2.0)).unwrap();
+    assert_eq!(mo.get(&identity), &CalculatorComplex::new(1.0, 2.0));
+    assert_eq!(mo.constant(), &CalculatorComplex::new(1.0, 2.0));
+    assert!(mo.without_constant().is_empty());
+
+    let mut mhs = MixedHamiltonian::new(1, 1, 1);
+    mhs.add_constant(CalculatorComplex::from(0.5)).unwrap();
+    assert_eq!(
+        mhs.get(
+            &HermitianMixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::default()],
+                [FermionProduct::default()],
+            )
+            .unwrap()
+        ),
+        &CalculatorComplex::from(0.5)
+    );
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_0: MixedProduct = MixedProduct::new(
@@ -769,7 +802,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedOperatorSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -825,6 +858,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -840,6 +878,163 @@ fn serde_compact() {
     );
 }
 
+// Test that the spectral norm bound of MixedOperator fails for bosonic terms
+#[test]
+fn spectral_norm_bound() {
+    let mut mo = MixedOperator::new(1, 1, 1);
+    let spin_term = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([0], [1]).unwrap()],
+    )
+    .unwrap();
+    mo.add_operator_product(spin_term, CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    assert_eq!(mo.spectral_norm_bound().unwrap(), 2.0);
+
+    let boson_term = MixedProduct::new(
+        [PauliProduct::new()],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    mo.add_operator_product(boson_term, CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(mo.coefficient_1_norm().unwrap(), 3.0);
+    assert!(mo.spectral_norm_bound().is_err());
+}
+
+// Test the multiplication: MixedOperator * MixedHamiltonian
+#[test]
+fn mul_mo_mh() {
+    let mp: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([0], [1]).unwrap()],
+    )
+    .unwrap();
+    let hp: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().x(1)],
+        [BosonProduct::new([1], [1]).unwrap()],
+        [FermionProduct::new([2], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedOperator::new(1, 1, 1);
+    mo.add_operator_product(mp.clone(), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    let mut mh = MixedHamiltonian::new(1, 1, 1);
+    mh.add_operator_product(hp.clone(), CalculatorComplex::from(3.0))
+        .unwrap();
+
+    let mut expected = MixedOperator::new(1, 1, 1);
+    for (product, coefficient) in (mp * hp).unwrap() {
+        expected
+            .add_operator_product(
+                product,
+                CalculatorComplex::new(0.0, 3.0) * CalculatorComplex::from(coefficient),
+            )
+            .unwrap();
+    }
+    assert!(!expected.is_empty());
+    assert_eq!(mo.clone() * mh, Ok(expected));
+
+    assert_eq!(
+        mo * MixedHamiltonian::new(1, 1, 2),
+        Err(StruqtureError::MissmatchedNumberSubsystems {
+            target_number_spin_subsystems: 1,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 1,
+            actual_number_spin_subsystems: 1,
+            actual_number_boson_subsystems: 1,
+            actual_number_fermion_subsystems: 2,
+        })
+    );
+}
+
+#[test]
+fn powi() {
+    let mut mo = MixedOperator::new(1, 1, 0);
+    mo.add_operator_product(
+        MixedProduct::new(
+            [PauliProduct::new().x(0)],
+            [BosonProduct::new([], []).unwrap()],
+            [],
+        )
+        .unwrap(),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    let mut identity = MixedOperator::new(1, 1, 0);
+    identity
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new()],
+                [BosonProduct::new([], []).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    assert_eq!(mo.powi(0).unwrap(), identity);
+    assert_eq!(mo.powi(2).unwrap(), identity * CalculatorComplex::from(4.0));
+    assert_eq!(
+        mo.powi(3).unwrap(),
+        mo.clone() * CalculatorComplex::from(4.0)
+    );
+}
+
+#[test]
+fn try_into_hamiltonian() {
+    let product = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let hermitian_product = HermitianMixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        Vec::<FermionProduct>::new(),
+    )
+    .unwrap();
+    let mut mh = MixedHamiltonian::new(1, 1, 0);
+    mh.add_operator_product(hermitian_product, CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+
+    let mo = mh.to_operator();
+    assert_eq!(mo.len(), 2);
+    assert_eq!(mo.get(&product), &CalculatorComplex::new(1.0, 2.0));
+    assert!(mo.is_hermitian());
+    assert_eq!(mo.try_into_hamiltonian().unwrap(), mh);
+
+    let mut non_hermitian = MixedOperator::new(1, 1, 0);
+    non_hermitian
+        .add_operator_product(product, CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    match non_hermitian.try_into_hamiltonian() {
+        Err(StruqtureError::NonHermitianTerms { terms }) => assert_eq!(terms.len(), 2),
+        _ => panic!("Expected NonHermitianTerms error"),
+    }
+}
+
+#[test]
+fn entry() {
+    let product = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let mut mo = MixedOperator::new(1, 1, 1);
+    *mo.entry(product.clone()).unwrap() += CalculatorComplex::from(0.5);
+    *mo.entry(product.clone()).unwrap() += CalculatorComplex::from(0.5);
+    assert_eq!(mo.get(&product), &CalculatorComplex::from(1.0));
+
+    let mut wrong_subsystems = MixedOperator::new(2, 1, 1);
+    assert!(wrong_subsystems.entry(product).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_operator_schema() {
@@ -668,6 +668,70 @@ fn serde_compact() {
     );
 }
 
+// Test the symmetrize and antisymmetrize functions of MixedProduct
+#[test]
+fn symmetrize() {
+    let hermitian_product = HermitianMixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let valid = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let conjugated = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([1], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    assert_eq!(
+        valid.symmetrize(CalculatorComplex::new(1.0, 2.0)).unwrap(),
+        (hermitian_product.clone(), CalculatorComplex::new(1.0, 2.0))
+    );
+    assert_eq!(
+        conjugated
+            .symmetrize(CalculatorComplex::new(1.0, 2.0))
+            .unwrap(),
+        (hermitian_product.clone(), CalculatorComplex::new(1.0, -2.0))
+    );
+    assert_eq!(
+        conjugated
+            .antisymmetrize(CalculatorComplex::new(1.0, 0.0))
+            .unwrap(),
+        (hermitian_product, CalculatorComplex::new(0.0, -1.0))
+    );
+
+    let natural = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    let natural_hermitian = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [FermionProduct::new([0], [0]).unwrap()],
+    )
+    .unwrap();
+    assert_eq!(
+        natural
+            .symmetrize(CalculatorComplex::new(1.0, 2.0))
+            .unwrap(),
+        (natural_hermitian.clone(), CalculatorComplex::new(2.0, 0.0))
+    );
+    assert_eq!(
+        natural
+            .antisymmetrize(CalculatorComplex::new(0.0, 1.0))
+            .unwrap(),
+        (natural_hermitian, CalculatorComplex::new(-2.0, 0.0))
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_mixed_product_schema() {
@@ -0,0 +1,132 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of MixedProductBuilder
+
+use qoqo_calculator::CalculatorComplex;
+use struqture::bosons::BosonProduct;
+use struqture::fermions::FermionProduct;
+use struqture::mixed_systems::{
+    HermitianMixedProduct, MixedHamiltonianSystem, MixedProduct, MixedProductBuilder, MixedSystem,
+};
+use struqture::prelude::*;
+use struqture::spins::PauliProduct;
+use struqture::StruqtureError;
+
+// Test building a MixedProduct
+#[test]
+fn build() {
+    let mp = MixedProductBuilder::new(2, 1, 1)
+        .spin(1, PauliProduct::new().z(0))
+        .fermion(0, FermionProduct::new([0], [1]).unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(
+        mp,
+        MixedProduct::new(
+            [PauliProduct::new(), PauliProduct::new().z(0)],
+            [BosonProduct::new([], []).unwrap()],
+            [FermionProduct::new([0], [1]).unwrap()],
+        )
+        .unwrap()
+    );
+
+    // Setting a subsystem again replaces the product
+    let replaced = MixedProductBuilder::new(1, 0, 0)
+        .spin(0, PauliProduct::new().x(0))
+        .spin(0, PauliProduct::new().y(1))
+        .build()
+        .unwrap();
+    assert_eq!(
+        replaced,
+        MixedProduct::new([PauliProduct::new().y(1)], [], []).unwrap()
+    );
+
+    assert_eq!(
+        MixedProductBuilder::new(0, 0, 0).build().unwrap(),
+        MixedProduct::new([], [], []).unwrap()
+    );
+}
+
+// Test building a HermitianMixedProduct
+#[test]
+fn build_hermitian() {
+    let builder = MixedProductBuilder::new(1, 1, 0).boson(0, BosonProduct::new([0], [1]).unwrap());
+    assert_eq!(
+        builder.build_hermitian().unwrap(),
+        HermitianMixedProduct::new(
+            [PauliProduct::new()],
+            [BosonProduct::new([0], [1]).unwrap()],
+            [],
+        )
+        .unwrap()
+    );
+
+    let non_hermitian_order =
+        MixedProductBuilder::new(1, 1, 0).boson(0, BosonProduct::new([1], [0]).unwrap());
+    assert!(non_hermitian_order.build().is_ok());
+    assert!(matches!(
+        non_hermitian_order.build_hermitian(),
+        Err(StruqtureError::CreatorsAnnihilatorsMinimumIndex { .. })
+    ));
+}
+
+// Test that the subsystem indices are validated
+#[test]
+fn build_error() {
+    let builder = MixedProductBuilder::new(1, 1, 0)
+        .spin(2, PauliProduct::new().x(0))
+        .fermion(0, FermionProduct::new([0], [0]).unwrap());
+    let error = || StruqtureError::MissmatchedNumberSubsystems {
+        target_number_spin_subsystems: 1,
+        target_number_boson_subsystems: 1,
+        target_number_fermion_subsystems: 0,
+        actual_number_spin_subsystems: 3,
+        actual_number_boson_subsystems: 1,
+        actual_number_fermion_subsystems: 1,
+    };
+    assert_eq!(builder.build(), Err(error()));
+    assert_eq!(builder.build_hermitian(), Err(error()));
+}
+
+// Test the product builders of MixedSystem and MixedHamiltonianSystem
+#[test]
+fn system_product_builder() {
+    let mut system = MixedSystem::new([Some(2), Some(2)], [None], []);
+    let mp = system
+        .product_builder()
+        .spin(1, PauliProduct::new().x(1))
+        .boson(0, BosonProduct::new([0], [2]).unwrap())
+        .build()
+        .unwrap();
+    system
+        .add_operator_product(mp.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(system.get(&mp), &CalculatorComplex::from(1.0));
+
+    let mut hamiltonian = MixedHamiltonianSystem::new([Some(2)], [], [Some(3)]);
+    let hmp = hamiltonian
+        .product_builder()
+        .spin(0, PauliProduct::new().z(0))
+        .fermion(0, FermionProduct::new([0], [2]).unwrap())
+        .build_hermitian()
+        .unwrap();
+    hamiltonian
+        .add_operator_product(hmp.clone(), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    assert_eq!(hamiltonian.get(&hmp), &CalculatorComplex::new(1.0, 1.0));
+    assert!(hamiltonian
+        .product_builder()
+        .boson(0, BosonProduct::new([0], [0]).unwrap())
+        .build_hermitian()
+        .is_err());
+}
@@ -18,13 +18,16 @@ use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use struqture::bosons::BosonProduct;
-use struqture::fermions::FermionProduct;
+use struqture::bosons::{BosonOperator, BosonProduct, BosonSystem};
+use struqture::fermions::{FermionOperator, FermionProduct, FermionSystem};
 use struqture::prelude::*;
-use struqture::spins::PauliProduct;
+use struqture::spins::{PauliProduct, SpinOperator, SpinSystem};
 use struqture::StruqtureError;
 
-use struqture::mixed_systems::{MixedOperator, MixedProduct, MixedSystem};
+use struqture::mixed_systems::{
+    HermitianMixedProduct, MixedHamiltonianSystem, MixedOperator, MixedProduct, MixedSubsystemKind,
+    MixedSystem,
+};
 use struqture::OperateOnDensityMatrix;
 use struqture::SpinIndex;
 use test_case::test_case;
@@ -1049,7 +1052,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "MixedSystem",
-                len: 4,
+                len: 5,
             },
             Token::Str("number_spins"),
             Token::Seq { len: Some(1) },
@@ -1069,7 +1072,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "MixedOperatorSerialize",
-                len: 5,
+                len: 6,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -1125,6 +1128,11 @@ fn serde_compact() {
             Token::U64(1),
             Token::Str("n_fermions"),
             Token::U64(1),
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -1136,11 +1144,400 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Test the canonical text export of MixedSystem
+#[test]
+fn canonical_text() {
+    let pp: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().x(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([0], [2]).unwrap()],
+    )
+    .unwrap();
+    let mut ms = MixedSystem::new([Some(1)], [None], [Some(3)]);
+    ms.set(pp, CalculatorComplex::new(0.5, 1.0)).unwrap();
+
+    let text = ms.to_canonical_text();
+    assert_eq!(
+        text,
+        "MixedSystem\nnumber_spins\t1\nnumber_bosonic_modes\tnone\nnumber_fermionic_modes\t3\nS0X:Bc0a1:Fc0a2:\t0.5\t1.0\n"
+    );
+    assert_eq!(MixedSystem::from_canonical_text(&text).unwrap(), ms);
+
+    let empty = MixedSystem::new([], [], []);
+    assert_eq!(
+        MixedSystem::from_canonical_text(&empty.to_canonical_text()).unwrap(),
+        empty
+    );
+}
+
+// Test the multiplication: MixedSystem * MixedHamiltonianSystem and MixedHamiltonianSystem * MixedSystem
+#[test]
+fn mul_ms_mhs() {
+    let mp: MixedProduct = MixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let hp: HermitianMixedProduct = HermitianMixedProduct::new(
+        [PauliProduct::new().y(1)],
+        [BosonProduct::new([0], [2]).unwrap()],
+        [],
+    )
+    .unwrap();
+    let mut ms = MixedSystem::new([Some(2)], [Some(3)], []);
+    ms.add_operator_product(mp.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+    let mut mhs = MixedHamiltonianSystem::new([Some(2)], [Some(3)], []);
+    mhs.add_operator_product(hp.clone(), CalculatorComplex::new(1.0, -1.0))
+        .unwrap();
+
+    let mut expected_left = MixedOperator::new(1, 1, 0);
+    for (product, coefficient) in (mp.clone() * hp.clone()).unwrap() {
+        expected_left
+            .add_operator_product(
+                product,
+                CalculatorComplex::new(2.0, -2.0) * CalculatorComplex::from(coefficient),
+            )
+            .unwrap();
+    }
+    let mut expected_right = MixedOperator::new(1, 1, 0);
+    for (product, coefficient) in (hp * mp).unwrap() {
+        expected_right
+            .add_operator_product(
+                product,
+                CalculatorComplex::new(2.0, -2.0) * CalculatorComplex::from(coefficient),
+            )
+            .unwrap();
+    }
+    assert_eq!(
+        ms.clone() * mhs.clone(),
+        MixedSystem::from_operator(expected_left, [Some(2)], [Some(3)], [])
+    );
+    assert_eq!(
+        mhs.clone() * ms.clone(),
+        MixedSystem::from_operator(expected_right, [Some(2)], [Some(3)], [])
+    );
+
+    let mismatched = MixedHamiltonianSystem::new([Some(2)], [], []);
+    assert_eq!(
+        ms * mismatched,
+        Err(StruqtureError::MissmatchedNumberSubsystems {
+            target_number_spin_subsystems: 1,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 0,
+            actual_number_spin_subsystems: 1,
+            actual_number_boson_subsystems: 0,
+            actual_number_fermion_subsystems: 0,
+        })
+    );
+    assert_eq!(
+        mhs * MixedSystem::new([Some(2), None], [Some(3)], []),
+        Err(StruqtureError::MissmatchedNumberSubsystems {
+            target_number_spin_subsystems: 1,
+            target_number_boson_subsystems: 1,
+            target_number_fermion_subsystems: 0,
+            actual_number_spin_subsystems: 2,
+            actual_number_boson_subsystems: 1,
+            actual_number_fermion_subsystems: 0,
+        })
+    );
+}
+
+// Test the subsystem views of MixedSystem
+#[test]
+fn subsystem_views() {
+    let mut ms = MixedSystem::new([Some(2), None], [Some(2)], [Some(2)]);
+    let identity = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let spin_only = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new().x(0)],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let boson_only = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new()],
+        [BosonProduct::new([0], [1]).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let fermion_only = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new()],
+        [BosonProduct::new([], []).unwrap()],
+        [FermionProduct::new([1], [0]).unwrap()],
+    )
+    .unwrap();
+    let spin_boson = MixedProduct::new(
+        [PauliProduct::new(), PauliProduct::new().z(1)],
+        [BosonProduct::new([1], []).unwrap()],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    ms.add_operator_product(identity.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+    ms.add_operator_product(spin_only.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    ms.add_operator_product(boson_only.clone(), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    ms.add_operator_product(fermion_only.clone(), CalculatorComplex::from(3.0))
+        .unwrap();
+    ms.add_operator_product(spin_boson.clone(), CalculatorComplex::from(4.0))
+        .unwrap();
+
+    let mut spin_operator = SpinOperator::new();
+    spin_operator
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(0.5))
+        .unwrap();
+    spin_operator
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(ms.spin_subsystem_operator(1).unwrap(), spin_operator);
+    let mut identity_operator = SpinOperator::new();
+    identity_operator
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(ms.spin_subsystem_operator(0).unwrap(), identity_operator);
+
+    let mut boson_operator = BosonOperator::new();
+    boson_operator
+        .add_operator_product(
+            BosonProduct::new([], []).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    boson_operator
+        .add_operator_product(
+            BosonProduct::new([0], [1]).unwrap(),
+            CalculatorComplex::new(0.0, 2.0),
+        )
+        .unwrap();
+    assert_eq!(ms.boson_subsystem_operator(0).unwrap(), boson_operator);
+
+    let mut fermion_operator = FermionOperator::new();
+    fermion_operator
+        .add_operator_product(
+            FermionProduct::new([], []).unwrap(),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    fermion_operator
+        .add_operator_product(
+            FermionProduct::new([1], [0]).unwrap(),
+            CalculatorComplex::from(3.0),
+        )
+        .unwrap();
+    assert_eq!(ms.fermion_subsystem_operator(0).unwrap(), fermion_operator);
+
+    let mut spin_terms: Vec<MixedProduct> = ms
+        .terms_acting_on_subsystem(MixedSubsystemKind::Spin, 1)
+        .unwrap()
+        .map(|(key, _)| key.clone())
+        .collect();
+    spin_terms.sort();
+    let mut expected = vec![spin_only, spin_boson.clone()];
+    expected.sort();
+    assert_eq!(spin_terms, expected);
+    let boson_terms: Vec<(&MixedProduct, &CalculatorComplex)> = ms
+        .terms_acting_on_subsystem(MixedSubsystemKind::Boson, 0)
+        .unwrap()
+        .filter(|(key, _)| **key == spin_boson)
+        .collect();
+    assert_eq!(
+        boson_terms,
+        vec![(&spin_boson, &CalculatorComplex::from(4.0))]
+    );
+    assert_eq!(
+        ms.terms_acting_on_subsystem(MixedSubsystemKind::Spin, 0)
+            .unwrap()
+            .count(),
+        0
+    );
+
+    assert!(ms.spin_subsystem_operator(2).is_err());
+    assert!(ms.boson_subsystem_operator(1).is_err());
+    assert!(ms.fermion_subsystem_operator(1).is_err());
+    assert!(ms
+        .terms_acting_on_subsystem(MixedSubsystemKind::Fermion, 1)
+        .is_err());
+}
+
+// Test the insertion, removal and permutation of subsystems of MixedSystem
+#[test]
+fn rearrange_subsystems() {
+    let mut ms = MixedSystem::new([Some(2), None], [Some(3)], []);
+    ms.add_operator_product(
+        MixedProduct::new(
+            [PauliProduct::new().x(0), PauliProduct::new().z(1)],
+            [BosonProduct::new([], []).unwrap()],
+            [],
+        )
+        .unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+
+    ms.add_fermion_subsystem(Some(4));
+    let mut expected = MixedSystem::new([Some(2), None], [Some(3)], [Some(4)]);
+    expected
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new().x(0), PauliProduct::new().z(1)],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(1.0, 2.0),
+        )
+        .unwrap();
+    assert_eq!(ms, expected);
+
+    ms.permute_subsystems(MixedSubsystemKind::Spin, &[1, 0])
+        .unwrap();
+    ms.remove_boson_subsystem(0).unwrap();
+    let key = MixedProduct::new(
+        [PauliProduct::new().z(1), PauliProduct::new().x(0)],
+        [],
+        [FermionProduct::new([], []).unwrap()],
+    )
+    .unwrap();
+    let mut expected = MixedSystem::new([None, Some(2)], [], [Some(4)]);
+    expected
+        .add_operator_product(key.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    assert_eq!(ms, expected);
+
+    // Failed rearrangements leave the MixedSystem unchanged
+    assert_eq!(
+        ms.remove_spin_subsystem(1),
+        Err(StruqtureError::GenericError {
+            msg: format!("Cannot remove Spin subsystem 1, term {} acts on it", key)
+        })
+    );
+    assert_eq!(
+        ms.remove_boson_subsystem(0),
+        Err(StruqtureError::GenericError {
+            msg: "Subsystem index 0 exceeds the number of Boson subsystems 0".to_string()
+        })
+    );
+    assert_eq!(
+        ms.permute_subsystems(MixedSubsystemKind::Spin, &[0, 0]),
+        Err(StruqtureError::GenericError {
+            msg: "Order [0, 0] is not a permutation of the 2 Spin subsystems".to_string()
+        })
+    );
+    assert_eq!(ms, expected);
+}
+
+// Test the embedding of spin, boson and fermion systems into a MixedSystem
+#[test]
+fn embed_systems() {
+    let mut ss = SpinSystem::new(Some(2));
+    ss.add_operator_product(PauliProduct::new().x(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    ss.add_operator_product(PauliProduct::new(), CalculatorComplex::from(0.5))
+        .unwrap();
+    let mut bs = BosonSystem::new(None);
+    bs.add_operator_product(
+        BosonProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::new(0.0, 2.0),
+    )
+    .unwrap();
+    let mut fs = FermionSystem::new(Some(3));
+    fs.add_operator_product(
+        FermionProduct::new([], []).unwrap(),
+        CalculatorComplex::from(0.25),
+    )
+    .unwrap();
+
+    let ms = MixedSystem::embed([ss.clone(), SpinSystem::new(None)], [bs.clone()], [fs]);
+    let mut expected = MixedSystem::new([Some(2), None], [None], [Some(3)]);
+    expected
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new().x(1), PauliProduct::new()],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new(), PauliProduct::new()],
+                [BosonProduct::new([0], [1]).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::new(0.0, 2.0),
+        )
+        .unwrap();
+    // The identity terms of the spin and fermion systems are added up
+    expected
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new(), PauliProduct::new()],
+                [BosonProduct::new([], []).unwrap()],
+                [FermionProduct::new([], []).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(0.75),
+        )
+        .unwrap();
+    assert_eq!(ms, expected);
+
+    let spin_operator = ss.operator().clone();
+    let ms = MixedSystem::from_spin_system(ss);
+    assert_eq!(ms.number_spins(), vec![2]);
+    assert_eq!(ms.spin_subsystem_operator(0).unwrap(), spin_operator);
+    let ms = MixedSystem::from_boson_system(bs);
+    assert_eq!(ms.number_bosonic_modes(), vec![2]);
+    assert_eq!(ms.len(), 1);
+    assert_eq!(
+        MixedSystem::from_fermion_system(FermionSystem::new(Some(1))),
+        MixedSystem::new([], [], [Some(1)])
+    );
+}
+
+// Test the SystemDimension functions of the MixedSystem
+#[test]
+fn system_dimension() {
+    let mut system = MixedSystem::new([Some(2)], [Some(1)], [Some(1)]);
+    system
+        .add_operator_product(
+            MixedProduct::new(
+                [PauliProduct::new().x(0)],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [FermionProduct::new([0], [0]).unwrap()],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    assert_eq!(system.hilbert_space_dimension(&[5]).unwrap(), 40);
+    assert_eq!(system.superoperator_dimension(&[5]).unwrap(), 1600);
+    assert_eq!(system.estimated_matrix_memory(&[5], 24).unwrap(), 960);
+    assert_eq!(
+        system.hilbert_space_dimension(&[]),
+        Err(StruqtureError::MissmatchedNumberModes)
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(4))]
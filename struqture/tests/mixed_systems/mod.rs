@@ -12,6 +12,8 @@
 
 mod mixed_product;
 
+mod mixed_product_builder;
+
 mod mixed_plus_minus_product;
 
 mod mixed_hermitian_product;
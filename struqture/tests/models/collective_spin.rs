@@ -0,0 +1,144 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the collective spin observables
+
+use qoqo_calculator::CalculatorFloat;
+use std::collections::HashMap;
+use struqture::models::{
+    collective_spin_squared, collective_spin_x, collective_spin_y, collective_spin_z,
+    pauli_expectation_value, spin_squeezing_parameter,
+};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SingleSpinOperator};
+use struqture::StruqtureError;
+use test_case::test_case;
+
+// Expectation values of all single-qubit and two-qubit PauliProducts in the all-up product state
+fn polarized_expectation_values(number_qubits: usize) -> HashMap<PauliProduct, f64> {
+    let paulis = [
+        SingleSpinOperator::X,
+        SingleSpinOperator::Y,
+        SingleSpinOperator::Z,
+    ];
+    let mut values = HashMap::new();
+    for first in 0..number_qubits {
+        for first_pauli in paulis {
+            let single = PauliProduct::new().set_pauli(first, first_pauli);
+            let single_value = if first_pauli == SingleSpinOperator::Z {
+                1.0
+            } else {
+                0.0
+            };
+            values.insert(single.clone(), single_value);
+            for second in first + 1..number_qubits {
+                for second_pauli in paulis {
+                    let pair_value = if second_pauli == SingleSpinOperator::Z {
+                        single_value
+                    } else {
+                        0.0
+                    };
+                    values.insert(single.clone().set_pauli(second, second_pauli), pair_value);
+                }
+            }
+        }
+    }
+    values
+}
+
+#[test]
+fn collective_spin_components() {
+    let qubits = [0, 3, 3];
+    let jx = collective_spin_x(&qubits);
+    let jy = collective_spin_y(&qubits);
+    let jz = collective_spin_z(&qubits);
+    for (operator, pauli) in [
+        (jx, SingleSpinOperator::X),
+        (jy, SingleSpinOperator::Y),
+        (jz, SingleSpinOperator::Z),
+    ] {
+        assert_eq!(operator.len(), 2);
+        for qubit in [0, 3] {
+            assert_eq!(
+                operator.get(&PauliProduct::new().set_pauli(qubit, pauli)),
+                &CalculatorFloat::from(0.5)
+            );
+        }
+    }
+}
+
+#[test]
+fn collective_spin_squared_terms() {
+    let j_squared = collective_spin_squared(&[0, 1, 2]);
+    assert_eq!(j_squared.len(), 10);
+    assert_eq!(
+        j_squared.get(&PauliProduct::new()),
+        &CalculatorFloat::from(2.25)
+    );
+    assert_eq!(
+        j_squared.get(&PauliProduct::new().y(0).y(2)),
+        &CalculatorFloat::from(0.5)
+    );
+    assert_eq!(
+        j_squared.get(&PauliProduct::new().x(0).y(1)),
+        &CalculatorFloat::from(0.0)
+    );
+}
+
+#[test_case(1; "single qubit")]
+#[test_case(4; "four qubits")]
+fn polarized_state(number_qubits: usize) {
+    let qubits: Vec<usize> = (0..number_qubits).collect();
+    let values = polarized_expectation_values(number_qubits);
+    let spin = number_qubits as f64 / 2.0;
+    assert_eq!(
+        pauli_expectation_value(&collective_spin_z(&qubits), &values).unwrap(),
+        spin
+    );
+    assert_eq!(
+        pauli_expectation_value(&collective_spin_x(&qubits), &values).unwrap(),
+        0.0
+    );
+    assert!(
+        (pauli_expectation_value(&collective_spin_squared(&qubits), &values).unwrap()
+            - spin * (spin + 1.0))
+            .abs()
+            < 1e-12
+    );
+    assert!((spin_squeezing_parameter(&qubits, &values).unwrap() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn squeezed_state() {
+    let mut values = polarized_expectation_values(2);
+    values.insert(PauliProduct::new().x(0).x(1), -0.5);
+    assert!((spin_squeezing_parameter(&[0, 1], &values).unwrap() - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn evaluation_errors() {
+    let mut values = polarized_expectation_values(2);
+    values.remove(&PauliProduct::new().x(0).y(1));
+    assert!(pauli_expectation_value(&collective_spin_z(&[0, 1]), &values).is_ok());
+    assert!(matches!(
+        spin_squeezing_parameter(&[0, 1], &values),
+        Err(StruqtureError::GenericError { .. })
+    ));
+
+    let mut values = polarized_expectation_values(2);
+    values.insert(PauliProduct::new().z(0), 0.0);
+    values.insert(PauliProduct::new().z(1), 0.0);
+    assert!(matches!(
+        spin_squeezing_parameter(&[0, 1], &values),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
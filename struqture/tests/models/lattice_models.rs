@@ -0,0 +1,145 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the lattice model builders
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::bosons::HermitianBosonProduct;
+use struqture::fermions::HermitianFermionProduct;
+use struqture::mappings::JordanWignerFermionToSpin;
+use struqture::models::{bose_hubbard, fermi_hubbard, heisenberg_chain, transverse_field_ising};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+use test_case::test_case;
+
+#[test_case(1, 1; "single spin")]
+#[test_case(4, 7; "chain")]
+fn transverse_field_ising_chain(number_spins: usize, number_terms: usize) {
+    let hamiltonian = transverse_field_ising(number_spins, 1.0, "h");
+    assert_eq!(hamiltonian.number_spins(), number_spins);
+    assert_eq!(hamiltonian.len(), number_terms);
+    for spin in 0..number_spins {
+        assert_eq!(
+            hamiltonian.get(&PauliProduct::new().x(spin)),
+            &(-CalculatorFloat::from("h"))
+        );
+    }
+    for spin in 0..number_spins - 1 {
+        assert_eq!(
+            hamiltonian.get(&PauliProduct::new().z(spin).z(spin + 1)),
+            &CalculatorFloat::from(-1.0)
+        );
+    }
+    assert_eq!(
+        hamiltonian.get(&PauliProduct::new().z(0).z(number_spins)),
+        &CalculatorFloat::from(0.0)
+    );
+}
+
+#[test]
+fn heisenberg_xyz_chain() {
+    let hamiltonian = heisenberg_chain(3, 1.0, 2.0, "jz");
+    assert_eq!(hamiltonian.number_spins(), 3);
+    assert_eq!(hamiltonian.len(), 6);
+    for spin in 0..2 {
+        assert_eq!(
+            hamiltonian.get(&PauliProduct::new().x(spin).x(spin + 1)),
+            &CalculatorFloat::from(1.0)
+        );
+        assert_eq!(
+            hamiltonian.get(&PauliProduct::new().y(spin).y(spin + 1)),
+            &CalculatorFloat::from(2.0)
+        );
+        assert_eq!(
+            hamiltonian.get(&PauliProduct::new().z(spin).z(spin + 1)),
+            &CalculatorFloat::from("jz")
+        );
+    }
+
+    let single_spin = heisenberg_chain(1, 1.0, 1.0, 1.0);
+    assert_eq!(single_spin.number_spins(), 1);
+    assert!(single_spin.is_empty());
+}
+
+#[test]
+fn fermi_hubbard_single_site() {
+    // u n_up n_down = u / 4 (1 - Z_0 - Z_1 + Z_0 Z_1) in the Jordan-Wigner representation
+    let hamiltonian = fermi_hubbard(1, 1, 1.0, 2.0);
+    assert_eq!(hamiltonian.number_modes(), 2);
+    let mut expected = SpinHamiltonian::new();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.5))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(-0.5))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(-0.5))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(0.5))
+        .unwrap();
+    assert_eq!(hamiltonian.jordan_wigner().hamiltonian(), &expected);
+}
+
+#[test]
+fn fermi_hubbard_lattice() {
+    let hamiltonian = fermi_hubbard(2, 2, "t", "u");
+    assert_eq!(hamiltonian.number_modes(), 8);
+    // 4 bonds with two spin species and 4 sites with an interaction
+    assert_eq!(hamiltonian.len(), 12);
+    let hopping = CalculatorComplex::from(-CalculatorFloat::from("t"));
+    // site 0 has the neighbours 1 (x-direction) and 2 (y-direction)
+    assert_eq!(
+        hamiltonian.get(&HermitianFermionProduct::new([0], [2]).unwrap()),
+        &hopping
+    );
+    assert_eq!(
+        hamiltonian.get(&HermitianFermionProduct::new([1], [5]).unwrap()),
+        &hopping
+    );
+    // sites 1 and 2 are not neighbours
+    assert_eq!(
+        hamiltonian.get(&HermitianFermionProduct::new([2], [4]).unwrap()),
+        &CalculatorComplex::from(0.0)
+    );
+
+    let chain = fermi_hubbard(2, 1, 1.0, 0.0);
+    let spin_hamiltonian = chain.jordan_wigner();
+    assert_eq!(
+        spin_hamiltonian.get(&PauliProduct::new().x(0).z(1).x(2)),
+        &CalculatorFloat::from(-0.5)
+    );
+    assert_eq!(
+        spin_hamiltonian.get(&PauliProduct::new().y(1).z(2).y(3)),
+        &CalculatorFloat::from(-0.5)
+    );
+}
+
+#[test]
+fn bose_hubbard_chain() {
+    let hamiltonian = bose_hubbard(3, 1.0, "u");
+    assert_eq!(hamiltonian.number_modes(), 3);
+    assert_eq!(hamiltonian.len(), 5);
+    for mode in 0..2 {
+        assert_eq!(
+            hamiltonian.get(&HermitianBosonProduct::new([mode], [mode + 1]).unwrap()),
+            &CalculatorComplex::from(-1.0)
+        );
+    }
+    for mode in 0..3 {
+        assert_eq!(
+            hamiltonian.get(&HermitianBosonProduct::new([mode, mode], [mode, mode]).unwrap()),
+            &CalculatorComplex::from(CalculatorFloat::from("u") * 0.5)
+        );
+    }
+}
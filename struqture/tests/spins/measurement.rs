@@ -0,0 +1,219 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the measurement settings of SpinOperators
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use struqture::spins::{
+    BasisRotation, PauliProduct, ShotAllocationStrategy, SingleSpinOperator, SpinHamiltonian,
+    SpinHamiltonianSystem, SpinOperator,
+};
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+// Test the grouping of a Heisenberg dimer with a field into qubit-wise commuting sets
+#[test]
+fn measurement_circuits_info() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).x(1), 1.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(0).y(1), 1.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(1), 1.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), 0.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+
+    let groups = so.measurement_circuits_info();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(
+        groups[0].basis,
+        vec![(0, SingleSpinOperator::X), (1, SingleSpinOperator::X)]
+    );
+    assert_eq!(
+        groups[0].rotations,
+        vec![(0, BasisRotation::Hadamard), (1, BasisRotation::Hadamard)]
+    );
+    assert_eq!(
+        groups[0].readout,
+        vec![
+            (
+                PauliProduct::new().x(0).x(1),
+                vec![0, 1],
+                CalculatorComplex::from(1.0)
+            ),
+            (
+                PauliProduct::new(),
+                vec![],
+                CalculatorComplex::new(0.0, 2.0)
+            ),
+        ]
+    );
+    assert_eq!(
+        groups[1].rotations,
+        vec![
+            (0, BasisRotation::SDaggerHadamard),
+            (1, BasisRotation::SDaggerHadamard)
+        ]
+    );
+    assert!(groups[2].rotations.is_empty());
+    assert_eq!(groups[2].readout.len(), 2);
+    assert_eq!(groups[2].readout[1].0, PauliProduct::new().z(0));
+    assert_eq!(groups[2].readout[1].1, vec![0]);
+
+    // Every term is measured exactly once in the basis of its group
+    let number_terms: usize = groups.iter().map(|group| group.readout.len()).sum();
+    assert_eq!(number_terms, so.len());
+    for group in groups.iter() {
+        for (product, qubits, value) in group.readout.iter() {
+            assert_eq!(so.get(product), value);
+            assert_eq!(qubits.len(), product.len());
+            for (qubit, operator) in product.iter() {
+                assert!(group.basis.contains(&(*qubit, *operator)));
+            }
+        }
+    }
+
+    assert!(SpinOperator::new().measurement_circuits_info().is_empty());
+}
+
+// Test the distribution of shots over the measurement groups
+#[test]
+fn shot_allocation() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).x(1), 2.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.0, -1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new(), 10.0.into())
+        .unwrap();
+    let groups = so.measurement_circuits_info();
+    assert_eq!(groups.len(), 2);
+
+    let plan = so
+        .shot_allocation(100, ShotAllocationStrategy::CoefficientWeight)
+        .unwrap();
+    assert_eq!(plan.len(), 2);
+    assert_eq!(plan[0].group, groups[0]);
+    assert_eq!(plan[0].shots, 50);
+    assert_eq!(plan[1].shots, 50);
+
+    let plan = so
+        .shot_allocation(11, ShotAllocationStrategy::Uniform)
+        .unwrap();
+    assert_eq!(
+        plan.iter()
+            .map(|allocation| allocation.shots)
+            .sum::<usize>(),
+        11
+    );
+    assert_eq!(plan[0].shots.abs_diff(plan[1].shots), 1);
+
+    let plan = so
+        .shot_allocation(30, ShotAllocationStrategy::Variances(vec![4.0, 1.0]))
+        .unwrap();
+    assert_eq!(plan[0].shots, 20);
+    assert_eq!(plan[1].shots, 10);
+
+    assert!(so
+        .shot_allocation(30, ShotAllocationStrategy::Variances(vec![1.0]))
+        .is_err());
+    assert!(so
+        .shot_allocation(30, ShotAllocationStrategy::Variances(vec![1.0, -1.0]))
+        .is_err());
+
+    // An operator proportional to the identity does not need to be measured
+    let mut identity = SpinOperator::new();
+    identity
+        .add_operator_product(PauliProduct::new(), 1.0.into())
+        .unwrap();
+    let plan = identity
+        .shot_allocation(10, ShotAllocationStrategy::Uniform)
+        .unwrap();
+    assert_eq!(plan.len(), 1);
+    assert_eq!(plan[0].shots, 0);
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), "c".into())
+        .unwrap();
+    assert!(symbolic
+        .shot_allocation(10, ShotAllocationStrategy::CoefficientWeight)
+        .is_err());
+}
+
+// Test the estimation of expectation values from measured bitstrings
+#[test]
+fn expectation_from_samples() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).z(1), 0.5.into())
+        .unwrap();
+    so.add_operator_product(PauliProduct::new(), 2.0.into())
+        .unwrap();
+    let samples = [(0b00, 3), (0b01, 1)];
+    let (value, standard_error) = so.expectation_from_samples(&samples, &[]).unwrap();
+    assert_eq!(value, Complex64::new(2.75, 0.0));
+    assert_eq!(standard_error, 0.75);
+
+    let system = SpinHamiltonianSystem::from_hamiltonian(
+        SpinHamiltonian::try_from(so.clone()).unwrap(),
+        Some(2),
+    )
+    .unwrap();
+    assert_eq!(
+        system.expectation_from_samples(&samples, &[]).unwrap(),
+        (2.75, 0.75)
+    );
+
+    let mut imaginary = SpinOperator::new();
+    imaginary
+        .add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    let basis = [(1, SingleSpinOperator::Y)];
+    assert_eq!(
+        imaginary
+            .expectation_from_samples(&[(0b01, 2)], &basis)
+            .unwrap(),
+        (Complex64::new(0.0, 1.0), 0.0)
+    );
+    assert_eq!(
+        imaginary
+            .expectation_from_samples(&[(0b10, 1)], &basis)
+            .unwrap(),
+        (Complex64::new(0.0, -1.0), 0.0)
+    );
+
+    // Terms that are not diagonal in the measured basis can not be estimated
+    assert!(imaginary.expectation_from_samples(&[(0, 1)], &[]).is_err());
+    assert!(so
+        .expectation_from_samples(&samples, &[(1, SingleSpinOperator::X)])
+        .is_err());
+    assert!(so.expectation_from_samples(&[], &[]).is_err());
+    assert!(so.expectation_from_samples(&[(0, 0)], &[]).is_err());
+
+    let mut far = SpinOperator::new();
+    far.add_operator_product(PauliProduct::new().z(64), 1.0.into())
+        .unwrap();
+    assert!(far.expectation_from_samples(&[(0, 1)], &[]).is_err());
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), "c".into())
+        .unwrap();
+    assert!(symbolic.expectation_from_samples(&[(0, 1)], &[]).is_err());
+}
@@ -17,6 +17,8 @@ mod decoherence_product;
 
 mod pauli_product;
 
+mod packed_pauli_product;
+
 mod plus_minus_product;
 
 mod decoherence_operator;
@@ -25,6 +27,8 @@ mod spin_operator;
 
 mod plus_minus_operator;
 
+mod plus_minus_hamiltonian;
+
 mod spin_hamiltonian;
 
 mod spin_system;
@@ -39,6 +43,16 @@ mod spin_noise_system;
 
 mod spin_open_system;
 
+mod plus_minus_open_system;
+
+mod perturbation;
+
+mod spin_s_operator;
+
+mod observables;
+
+mod measurement;
+
 fn create_na_matrix_from_operator_list(operators: &[&str]) -> na::DMatrix<Complex64> {
     let cc1 = Complex64::new(1.0, 0.0);
     let cc0 = Complex64::new(0.0, 0.0);
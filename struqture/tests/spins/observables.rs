@@ -0,0 +1,71 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for the spin correlation and structure factor observables
+
+use qoqo_calculator::CalculatorComplex;
+use std::f64::consts::PI;
+use struqture::spins::{structure_factor, PauliProduct, SingleSpinOperator, SpinOperator};
+use struqture::{OperateOnDensityMatrix, SpinIndex};
+
+// Test the correlation operators of single components and of the full spin vector
+#[test]
+fn correlation() {
+    let so = SpinOperator::correlation(1, 3, SingleSpinOperator::Y);
+    assert_eq!(so.len(), 1);
+    assert_eq!(
+        so.get(&PauliProduct::new().y(1).y(3)),
+        &CalculatorComplex::from(0.25)
+    );
+
+    let so = SpinOperator::correlation(2, 2, SingleSpinOperator::X);
+    assert_eq!(so.len(), 1);
+    assert_eq!(so.get(&PauliProduct::new()), &CalculatorComplex::from(0.25));
+
+    let so = SpinOperator::heisenberg_correlation(0, 1);
+    assert_eq!(so.len(), 3);
+    for pauli in [
+        SingleSpinOperator::X,
+        SingleSpinOperator::Y,
+        SingleSpinOperator::Z,
+    ] {
+        let product = PauliProduct::new().set_pauli(0, pauli).set_pauli(1, pauli);
+        assert_eq!(so.get(&product), &CalculatorComplex::from(0.25));
+    }
+
+    let so = SpinOperator::heisenberg_correlation(4, 4);
+    assert_eq!(so.len(), 1);
+    assert_eq!(so.get(&PauliProduct::new()), &CalculatorComplex::from(0.75));
+}
+
+// Test the structure factor of a chain of spins at the edge of the Brillouin zone
+#[test]
+fn structure_factor_chain() {
+    let positions = vec![vec![0.0], vec![1.0], vec![2.0]];
+    let sf = structure_factor(&[PI], &positions).unwrap();
+    assert_eq!(sf.len(), 10);
+    let identity = sf.get(&PauliProduct::new());
+    assert!((identity.re.float().unwrap() - 0.75).abs() < 1e-12);
+    // Neighbouring spins contribute with 2 cos(pi) / 3, next-nearest neighbours with 2 cos(2 pi) / 3
+    let nearest = sf.get(&PauliProduct::new().z(0).z(1));
+    assert!((nearest.re.float().unwrap() + 1.0 / 6.0).abs() < 1e-12);
+    assert!(nearest.im.float().unwrap().abs() < 1e-12);
+    let next_nearest = sf.get(&PauliProduct::new().x(0).x(2));
+    assert!((next_nearest.re.float().unwrap() - 1.0 / 6.0).abs() < 1e-12);
+}
+
+// Test the failure of the structure factor for inconsistent positions
+#[test]
+fn structure_factor_errors() {
+    assert!(structure_factor(&[0.0], &[]).is_err());
+    assert!(structure_factor(&[0.0, 1.0], &[vec![0.0, 0.0], vec![1.0]]).is_err());
+}
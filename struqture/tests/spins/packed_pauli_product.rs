@@ -0,0 +1,151 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of PackedPauliProduct
+
+use qoqo_calculator::CalculatorComplex;
+use std::str::FromStr;
+use struqture::prelude::*;
+use struqture::spins::{
+    PackedPauliProduct, PauliProduct, SingleSpinOperator, SpinHamiltonian, SpinOperator,
+};
+use test_case::test_case;
+
+// Test the conversion between PauliProduct and PackedPauliProduct
+#[test_case("")]
+#[test_case("0X")]
+#[test_case("0X1Y5Z")]
+#[test_case("3Y63Z64X200Y")]
+fn conversion(representation: &str) {
+    let product = PauliProduct::from_str(representation).unwrap();
+    let packed = PackedPauliProduct::from(&product);
+    assert_eq!(packed.weight(), product.len());
+    for (index, pauli) in product.iter() {
+        assert_eq!(packed.get(*index), *pauli);
+    }
+    assert_eq!(PauliProduct::from(packed), product);
+}
+
+// Test the set_pauli, get and mask functions of PackedPauliProduct
+#[test]
+fn set_and_get() {
+    let packed = PackedPauliProduct::new()
+        .set_pauli(1, SingleSpinOperator::X)
+        .set_pauli(2, SingleSpinOperator::Y)
+        .set_pauli(65, SingleSpinOperator::Z);
+    assert_eq!(packed.x_mask(), &[0b110, 0]);
+    assert_eq!(packed.z_mask(), &[0b100, 0b10]);
+    assert_eq!(packed.get(0), SingleSpinOperator::Identity);
+    assert_eq!(packed.get(65), SingleSpinOperator::Z);
+    assert_eq!(packed.get(1000), SingleSpinOperator::Identity);
+
+    let trimmed = packed.set_pauli(65, SingleSpinOperator::Identity);
+    assert_eq!(trimmed.x_mask(), &[0b110]);
+    assert_eq!(trimmed.z_mask(), &[0b100]);
+    assert_eq!(
+        trimmed
+            .set_pauli(1, SingleSpinOperator::Identity)
+            .set_pauli(2, SingleSpinOperator::Identity),
+        PackedPauliProduct::new()
+    );
+}
+
+// Test that the multiplication of PackedPauliProducts agrees with the multiplication of PauliProducts
+#[test_case("0X", "0X")]
+#[test_case("0X", "0Y")]
+#[test_case("0Y", "0X")]
+#[test_case("0Z", "0X")]
+#[test_case("0Y", "0Z")]
+#[test_case("0X1Y2Z", "0Y1Y2X")]
+#[test_case("0X1Z70Y", "1X70Z130Y")]
+#[test_case("63Y64Y", "63X64Z")]
+#[test_case("", "5Z")]
+fn multiplication(left: &str, right: &str) {
+    let left = PauliProduct::from_str(left).unwrap();
+    let right = PauliProduct::from_str(right).unwrap();
+    let (expected_product, expected_factor) = left.clone() * right.clone();
+    let (packed_left, packed_right) = (
+        PackedPauliProduct::from(&left),
+        PackedPauliProduct::from(&right),
+    );
+    let (product, factor) = packed_left.clone() * packed_right.clone();
+    assert_eq!(PauliProduct::from(product), expected_product);
+    assert_eq!(factor, expected_factor);
+    assert_eq!(
+        packed_left.commutes_with(&packed_right),
+        (packed_right * packed_left).1 == factor
+    );
+}
+
+// Test that the products of operators agree with the products of their PauliProducts
+#[test]
+fn operator_multiplication() {
+    let mut left = SpinOperator::new();
+    left.add_operator_product(
+        PauliProduct::from_str("0X1Z70Y").unwrap(),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    left.add_operator_product(
+        PauliProduct::from_str("63Y").unwrap(),
+        CalculatorComplex::new(-0.5, 0.0),
+    )
+    .unwrap();
+    let mut right = SpinOperator::new();
+    right
+        .add_operator_product(
+            PauliProduct::from_str("1X70Z130Y").unwrap(),
+            CalculatorComplex::new(0.5, 0.0),
+        )
+        .unwrap();
+    right
+        .add_operator_product(
+            PauliProduct::from_str("63X64Z").unwrap(),
+            CalculatorComplex::new(0.0, -1.0),
+        )
+        .unwrap();
+
+    let mut expected = SpinOperator::new();
+    for (left_product, left_value) in left.iter() {
+        for (right_product, right_value) in right.iter() {
+            let (product, factor) = left_product.clone() * right_product.clone();
+            expected
+                .add_operator_product(product, left_value.clone() * right_value.clone() * factor)
+                .unwrap();
+        }
+    }
+    assert_eq!(left.clone() * right.clone(), expected);
+
+    let pp = PauliProduct::from_str("0Y63Z").unwrap();
+    let mut pp_operator = SpinOperator::new();
+    pp_operator
+        .add_operator_product(pp.clone(), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    assert_eq!(
+        left.clone() * pp.clone(),
+        left.clone() * pp_operator.clone()
+    );
+    assert_eq!(pp * left.clone(), pp_operator * left);
+
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::from_str("0X64Z").unwrap(), 2.0.into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::from_str("0Z").unwrap(), 0.5.into())
+        .unwrap();
+    let operator = SpinOperator::from(hamiltonian.clone());
+    assert_eq!(
+        hamiltonian.clone() * hamiltonian,
+        operator.clone() * operator
+    );
+}
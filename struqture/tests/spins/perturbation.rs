@@ -0,0 +1,128 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the perturbation analysis of spin Hamiltonians
+
+use nalgebra as na;
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorFloat;
+use struqture::prelude::*;
+use struqture::spins::{perturbation_analysis, PauliProduct, SpinHamiltonianSystem};
+
+fn hamiltonian(number_spins: usize, terms: &[(PauliProduct, f64)]) -> SpinHamiltonianSystem {
+    let mut hamiltonian = SpinHamiltonianSystem::new(Some(number_spins));
+    for (product, value) in terms.iter() {
+        hamiltonian
+            .add_operator_product(product.clone(), CalculatorFloat::from(*value))
+            .unwrap();
+    }
+    hamiltonian
+}
+
+#[test]
+fn single_spin() {
+    let g = 0.1;
+    let h0 = hamiltonian(1, &[(PauliProduct::new().z(0), 1.0)]);
+    let v = hamiltonian(1, &[(PauliProduct::new().x(0), g)]);
+    let levels = perturbation_analysis(&h0, &v, 1e-10).unwrap();
+    assert_eq!(levels.len(), 2);
+    for (level, sign) in levels.iter().zip([-1.0, 1.0]) {
+        assert_eq!(level.degeneracy, 1);
+        assert!((level.unperturbed_energy - sign).abs() < 1e-12);
+        assert!(level.first_order_corrections[0].abs() < 1e-12);
+        assert!((level.second_order_corrections[0] - sign * g * g / 2.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn degenerate_level() {
+    let g = 0.1;
+    let h0 = hamiltonian(2, &[(PauliProduct::new().z(0).z(1), 1.0)]);
+    let v = hamiltonian(
+        2,
+        &[(PauliProduct::new().x(0), g), (PauliProduct::new().x(1), g)],
+    );
+    let levels = perturbation_analysis(&h0, &v, 1e-10).unwrap();
+    assert_eq!(levels.len(), 2);
+    let ground = &levels[0];
+    assert_eq!(ground.degeneracy, 2);
+    assert!((ground.unperturbed_energy + 1.0).abs() < 1e-12);
+    for (correction, expected) in ground
+        .second_order_corrections
+        .iter()
+        .zip([-2.0 * g * g, 0.0])
+    {
+        assert!(ground
+            .first_order_corrections
+            .iter()
+            .all(|x| x.abs() < 1e-12));
+        assert!((correction - expected).abs() < 1e-12);
+    }
+
+    let effective = &ground.effective_hamiltonian;
+    for (product, expected) in [
+        (PauliProduct::new(), -0.5 - g * g / 2.0),
+        (PauliProduct::new().z(0).z(1), 0.5 + g * g / 2.0),
+        (PauliProduct::new().x(0).x(1), -g * g / 2.0),
+        (PauliProduct::new().y(0).y(1), -g * g / 2.0),
+    ] {
+        let value: f64 = effective.get(&product).clone().try_into().unwrap();
+        assert!((value - expected).abs() < 1e-12);
+    }
+    assert_eq!(effective.len(), 4);
+}
+
+#[test]
+fn compare_exact_eigenvalues() {
+    let g = 1e-3;
+    let h0 = hamiltonian(
+        2,
+        &[
+            (PauliProduct::new().x(0), 1.0),
+            (PauliProduct::new().z(1), 2.5),
+            (PauliProduct::new().y(0).z(1), 0.3),
+        ],
+    );
+    let v = hamiltonian(
+        2,
+        &[
+            (PauliProduct::new().z(0), g),
+            (PauliProduct::new().y(1), 0.5 * g),
+            (PauliProduct::new().x(0).x(1), g),
+        ],
+    );
+    let levels = perturbation_analysis(&h0, &v, 1e-10).unwrap();
+    let mut approximate: Vec<f64> = levels
+        .iter()
+        .map(|level| {
+            level.unperturbed_energy
+                + level.first_order_corrections[0]
+                + level.second_order_corrections[0]
+        })
+        .collect();
+    approximate.sort_by(|a, b| a.total_cmp(b));
+
+    let total = (h0.clone() + v.clone()).unwrap();
+    let mut matrix: na::DMatrix<Complex64> = na::DMatrix::zeros(4, 4);
+    for ((row, column), value) in total.sparse_matrix(None).unwrap() {
+        matrix[(row, column)] = value;
+    }
+    let mut exact: Vec<f64> = matrix.symmetric_eigenvalues().iter().copied().collect();
+    exact.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(levels.len(), 4);
+    for (approximate, exact) in approximate.iter().zip(exact.iter()) {
+        assert!((approximate - exact).abs() < 1e-8);
+    }
+
+    let too_large = SpinHamiltonianSystem::new(Some(9));
+    assert!(perturbation_analysis(&too_large, &v, 1e-10).is_err());
+}
@@ -0,0 +1,272 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of PlusMinusHamiltonian
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::prelude::*;
+use struqture::spins::{
+    PauliProduct, PlusMinusHamiltonian, PlusMinusOperator, PlusMinusProduct, SpinHamiltonian,
+    SpinHamiltonianSystem, SpinOperator,
+};
+use struqture::{OperateOnDensityMatrix, StruqtureError};
+
+// Test the new function of the PlusMinusHamiltonian
+#[test]
+fn new() {
+    let ph = PlusMinusHamiltonian::new();
+    assert!(ph.is_empty());
+    assert_eq!(PlusMinusHamiltonian::new(), PlusMinusHamiltonian::default());
+    assert_eq!(
+        PlusMinusHamiltonian::with_capacity(2),
+        PlusMinusHamiltonian::default()
+    );
+}
+
+// Test that the hermitian conjugate of a key is stored in the canonical form
+#[test]
+fn internal_map_set_get_remove_canonical() {
+    let pp_canonical = PlusMinusProduct::new().plus(0).minus(1);
+    let pp_conjugate = PlusMinusProduct::new().minus(0).plus(1);
+    let mut ph = PlusMinusHamiltonian::new();
+
+    ph.set(pp_conjugate.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    assert_eq!(ph.len(), 1);
+    assert_eq!(ph.get(&pp_canonical), &CalculatorComplex::new(1.0, -2.0));
+    assert_eq!(ph.get(&pp_conjugate), &CalculatorComplex::ZERO);
+
+    ph.add_operator_product(pp_canonical.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    assert_eq!(ph.get(&pp_canonical), &CalculatorComplex::from(2.0));
+
+    assert_eq!(ph.remove(&pp_conjugate), Some(CalculatorComplex::from(2.0)));
+    assert!(ph.is_empty());
+}
+
+// Test that naturally hermitian keys need real coefficients
+#[test]
+fn natural_hermitian_error() {
+    let pp = PlusMinusProduct::new().z(0).z(2);
+    let mut ph = PlusMinusHamiltonian::new();
+    assert_eq!(
+        ph.set(pp.clone(), CalculatorComplex::new(1.0, 1.0)),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+    assert_eq!(
+        ph.add_operator_product(pp.clone(), CalculatorComplex::new(0.0, 1.0)),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+    ph.add_operator_product(pp.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(ph.get(&pp), &CalculatorComplex::from(1.0));
+    assert_eq!(ph.hermitian_conjugate(), ph);
+}
+
+// Test the conversion from and to PlusMinusOperator
+#[test]
+fn pmo_from_ph() {
+    let mut ph = PlusMinusHamiltonian::new();
+    ph.add_operator_product(
+        PlusMinusProduct::new().plus(0),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    ph.add_operator_product(PlusMinusProduct::new().z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let mut pmo = PlusMinusOperator::new();
+    pmo.add_operator_product(
+        PlusMinusProduct::new().plus(0),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    pmo.add_operator_product(
+        PlusMinusProduct::new().minus(0),
+        CalculatorComplex::new(1.0, -2.0),
+    )
+    .unwrap();
+    pmo.add_operator_product(PlusMinusProduct::new().z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    assert_eq!(PlusMinusOperator::from(ph.clone()), pmo);
+    assert_eq!(PlusMinusHamiltonian::try_from(pmo.clone()).unwrap(), ph);
+
+    pmo.set(
+        PlusMinusProduct::new().minus(0),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        PlusMinusHamiltonian::try_from(pmo),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+}
+
+// Test the conversion from and to SpinHamiltonian
+#[test]
+fn sh_from_ph() {
+    let mut ph = PlusMinusHamiltonian::new();
+    ph.add_operator_product(
+        PlusMinusProduct::new().plus(0),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    ph.add_operator_product(
+        PlusMinusProduct::new().plus(0).minus(1),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    ph.add_operator_product(PlusMinusProduct::new().z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    // (1 + 2i) + + h.c. = X - 2 Y
+    // 1/2 (+- + -+) = 1/4 (XX + YY)
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().y(0), CalculatorFloat::from(-2.0))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().x(0).x(1), CalculatorFloat::from(0.25))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().y(0).y(1), CalculatorFloat::from(0.25))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.5))
+        .unwrap();
+
+    assert_eq!(SpinHamiltonian::from(ph.clone()), sh);
+    assert_eq!(PlusMinusHamiltonian::from(sh.clone()), ph);
+    assert_eq!(
+        SpinHamiltonian::try_from(PlusMinusOperator::from(ph.clone())).unwrap(),
+        sh
+    );
+    assert_eq!(
+        SpinOperator::from(PlusMinusOperator::from(ph)),
+        SpinOperator::from(sh)
+    );
+}
+
+// Test the conversion from and to SpinHamiltonianSystem
+#[test]
+fn shs_from_ph() {
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0).y(2), CalculatorFloat::from(1.5))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.7))
+        .unwrap();
+    let shs = SpinHamiltonianSystem::from_hamiltonian(sh.clone(), Some(3)).unwrap();
+
+    let ph = PlusMinusHamiltonian::from(shs.clone());
+    assert_eq!(ph, PlusMinusHamiltonian::from(sh));
+    assert_eq!(
+        ph.get(&PlusMinusProduct::new().z(1)),
+        &CalculatorComplex::from(0.7)
+    );
+    assert_eq!(ph.to_spin_hamiltonian_system(Some(3)).unwrap(), shs);
+    assert_eq!(
+        ph.to_spin_hamiltonian_system(Some(2)),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test the arithmetic of PlusMinusHamiltonian
+#[test]
+fn add_sub_mul() {
+    let pp_0 = PlusMinusProduct::new().plus(0);
+    let pp_1 = PlusMinusProduct::new().z(1);
+    let mut ph_0 = PlusMinusHamiltonian::new();
+    ph_0.add_operator_product(pp_0.clone(), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    let mut ph_1 = PlusMinusHamiltonian::new();
+    ph_1.add_operator_product(pp_1.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let mut ph_0_1 = PlusMinusHamiltonian::new();
+    ph_0_1
+        .add_operator_product(pp_0.clone(), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    ph_0_1
+        .add_operator_product(pp_1.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!((ph_0.clone() + ph_1.clone()).unwrap(), ph_0_1);
+    assert_eq!((ph_0_1.clone() - ph_1.clone()).unwrap(), ph_0);
+    assert_eq!(-ph_0.clone() * CalculatorFloat::from(-1.0), ph_0);
+
+    let product = ph_0.clone() * ph_1.clone();
+    assert_eq!(
+        product,
+        PlusMinusOperator::from(ph_0.clone()) * PlusMinusOperator::from(ph_1.clone())
+    );
+    assert_eq!(
+        product.get(&PlusMinusProduct::new().minus(0).z(1)),
+        &CalculatorComplex::new(2.0, -2.0)
+    );
+    assert_eq!(
+        ph_0 * CalculatorComplex::new(0.0, 1.0),
+        PlusMinusOperator::from_iter(vec![
+            (pp_0, CalculatorComplex::new(-1.0, 1.0)),
+            (
+                PlusMinusProduct::new().minus(0),
+                CalculatorComplex::new(1.0, 1.0)
+            ),
+        ])
+    );
+}
+
+// Test the Display trait and the canonical text of PlusMinusHamiltonian
+#[test]
+fn display_canonical_text() {
+    let mut ph = PlusMinusHamiltonian::new();
+    ph.set(PlusMinusProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    assert_eq!(
+        format!("{}", ph),
+        "PlusMinusHamiltonian{\n0Z: (5e-1 + i * 0e0),\n}"
+    );
+    ph.set(
+        PlusMinusProduct::new().plus(0).plus(1),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        PlusMinusHamiltonian::from_canonical_text(&ph.to_canonical_text()).unwrap(),
+        ph
+    );
+    assert_eq!(ph.spectral_norm_bound().unwrap(), 2.5);
+}
+
+// Test the serialization of PlusMinusHamiltonian
+#[test]
+fn serde_json_bincode() {
+    let mut ph = PlusMinusHamiltonian::new();
+    ph.set(
+        PlusMinusProduct::new().plus(0).z(1),
+        CalculatorComplex::new(1.0, 1.0),
+    )
+    .unwrap();
+    ph.set(PlusMinusProduct::new().z(2), "theta".into())
+        .unwrap();
+
+    let serialized = serde_json::to_string(&ph).unwrap();
+    let deserialized: PlusMinusHamiltonian = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(ph, deserialized);
+
+    let encoded: Vec<u8> = bincode::serialize(&ph).unwrap();
+    let decoded: PlusMinusHamiltonian = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(ph, decoded);
+
+    let non_hermitian = serde_json::json!({
+        "items": [["0Z", 1.0, 1.0]],
+        "_struqture_version": {"major_version": 1, "minor_version": 8}
+    });
+    assert!(serde_json::from_value::<PlusMinusHamiltonian>(non_hermitian).is_err());
+}
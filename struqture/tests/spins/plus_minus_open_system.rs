@@ -0,0 +1,204 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of PlusMinusLindbladOpenSystem
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use std::convert::TryFrom;
+use struqture::prelude::*;
+use struqture::spins::{
+    DecoherenceProduct, PauliProduct, PlusMinusLindbladNoiseOperator, PlusMinusLindbladOpenSystem,
+    PlusMinusOperator, PlusMinusProduct, SpinLindbladOpenSystem,
+};
+use struqture::StruqtureError;
+
+fn open_system(system_value: f64, noise_value: f64) -> PlusMinusLindbladOpenSystem {
+    let mut open_system = PlusMinusLindbladOpenSystem::new();
+    open_system
+        .system_mut()
+        .set(
+            PlusMinusProduct::new().z(0).plus(1),
+            CalculatorComplex::from(system_value),
+        )
+        .unwrap();
+    open_system
+        .noise_mut()
+        .set(
+            (
+                PlusMinusProduct::new().minus(0),
+                PlusMinusProduct::new().minus(0),
+            ),
+            CalculatorComplex::from(noise_value),
+        )
+        .unwrap();
+    open_system
+}
+
+#[test]
+fn new_group_ungroup() {
+    let empty = PlusMinusLindbladOpenSystem::new();
+    assert_eq!(empty, PlusMinusLindbladOpenSystem::default());
+    assert!(empty.system().is_empty());
+    assert!(empty.noise().is_empty());
+
+    let open_system = open_system(1.0, 0.5);
+    assert_eq!(open_system.empty_clone(), empty);
+    let (system, noise) = open_system.clone().ungroup();
+    assert_eq!(
+        system.get(&PlusMinusProduct::new().z(0).plus(1)),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(noise.len(), 1);
+    assert_eq!(
+        PlusMinusLindbladOpenSystem::group(system, noise).unwrap(),
+        open_system
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let first = open_system(1.0, 0.5);
+    let second = open_system(2.0, 1.0);
+
+    assert_eq!(first.clone() + first.clone(), second);
+    assert_eq!(second.clone() - first.clone(), first);
+    assert_eq!(first.clone() * CalculatorFloat::from(2.0), second);
+    assert_eq!(-first.clone(), first * CalculatorFloat::from(-1.0));
+}
+
+#[test]
+fn truncate() {
+    let mut open_system = open_system(1.0, 0.5);
+    open_system
+        .system_mut()
+        .set(PlusMinusProduct::new().z(2), CalculatorComplex::from(1e-6))
+        .unwrap();
+    open_system
+        .noise_mut()
+        .set(
+            (PlusMinusProduct::new().z(2), PlusMinusProduct::new().z(2)),
+            CalculatorComplex::from(1e-6),
+        )
+        .unwrap();
+    let truncated = open_system.truncate(1e-3);
+    assert_eq!(truncated, self::open_system(1.0, 0.5));
+}
+
+#[test]
+fn conversion_spin_open_system() {
+    let mut spin_open_system = SpinLindbladOpenSystem::new(None);
+    spin_open_system
+        .system_mut()
+        .set(PauliProduct::new().x(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    spin_open_system
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    let plus_minus = PlusMinusLindbladOpenSystem::from(spin_open_system.clone());
+    let mut expected_system = PlusMinusOperator::new();
+    expected_system
+        .set(
+            PlusMinusProduct::new().plus(0),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    expected_system
+        .set(
+            PlusMinusProduct::new().minus(0),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+    let mut expected_noise = PlusMinusLindbladNoiseOperator::new();
+    expected_noise
+        .set(
+            (PlusMinusProduct::new().z(1), PlusMinusProduct::new().z(1)),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    assert_eq!(plus_minus.system(), &expected_system);
+    assert_eq!(plus_minus.noise(), &expected_noise);
+    assert_eq!(
+        SpinLindbladOpenSystem::try_from(plus_minus).unwrap(),
+        spin_open_system
+    );
+
+    let mut non_hermitian = PlusMinusLindbladOpenSystem::new();
+    non_hermitian
+        .system_mut()
+        .set(
+            PlusMinusProduct::new().z(0),
+            CalculatorComplex::new(0.0, 1.0),
+        )
+        .unwrap();
+    assert!(matches!(
+        SpinLindbladOpenSystem::try_from(non_hermitian),
+        Err(StruqtureError::NonHermitianOperator)
+    ));
+}
+
+#[test]
+fn display() {
+    let open_system = open_system(1.0, 0.5);
+    let pp = PlusMinusProduct::new().z(0).plus(1);
+    let noise_pp = PlusMinusProduct::new().minus(0);
+    assert_eq!(
+        format!("{}", open_system),
+        format!(
+            "PlusMinusLindbladOpenSystem{{\nSystem: {{\n{}: {},\n}}\nNoise: {{\n({}, {}): {},\n}}\n}}",
+            pp,
+            CalculatorComplex::from(1.0),
+            noise_pp,
+            noise_pp,
+            CalculatorComplex::from(0.5)
+        )
+    );
+}
+
+#[test]
+fn serde_and_canonical_text() {
+    let open_system = open_system(1.0, 0.5);
+
+    let serialized = serde_json::to_string(&open_system).unwrap();
+    let deserialized: PlusMinusLindbladOpenSystem = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, open_system);
+
+    let encoded: Vec<u8> = bincode::serialize(&open_system).unwrap();
+    let decoded: PlusMinusLindbladOpenSystem = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, open_system);
+
+    let text = open_system.to_canonical_text();
+    assert_eq!(
+        PlusMinusLindbladOpenSystem::from_canonical_text(&text).unwrap(),
+        open_system
+    );
+    assert!(PlusMinusLindbladOpenSystem::from_canonical_text("SpinLindbladOpenSystem\n").is_err());
+}
+
+#[cfg(feature = "json_schema")]
+#[test]
+fn test_plus_minus_open_system_schema() {
+    let open_system = open_system(1.0, 0.5);
+    let schema = schemars::schema_for!(PlusMinusLindbladOpenSystem);
+    let schema_checker = jsonschema::JSONSchema::compile(&serde_json::to_value(&schema).unwrap())
+        .expect("schema is valid");
+    let value = serde_json::to_value(&open_system).unwrap();
+    let validation = schema_checker.validate(&value);
+    assert!(validation.is_ok());
+}
@@ -283,6 +283,79 @@ fn mul_so_cc() {
     assert_eq!(so_0 * CalculatorComplex::from(3.0), so_0_1);
 }
 
+// Test the multiplication: PlusMinusOperator * PlusMinusOperator
+#[test]
+fn mul_so_so() {
+    let mut so_0 = PlusMinusOperator::new();
+    so_0.add_operator_product(
+        PlusMinusProduct::new().plus(0).z(1),
+        CalculatorComplex::new(1.0, 2.0),
+    )
+    .unwrap();
+    so_0.add_operator_product(
+        PlusMinusProduct::new().minus(1),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    let mut so_1 = PlusMinusOperator::new();
+    so_1.add_operator_product(
+        PlusMinusProduct::new().minus(0).plus(1),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    so_1.add_operator_product(
+        PlusMinusProduct::new().z(0),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+
+    // + * - = 1/2 (1 + Z), Z * + = +, - * + = 1/2 (1 - Z), + * Z = -+
+    let mut so_0_1 = PlusMinusOperator::new();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().z(0).plus(1),
+            CalculatorComplex::new(1.0, 2.0),
+        )
+        .unwrap();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().plus(1),
+            CalculatorComplex::new(1.0, 2.0),
+        )
+        .unwrap();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().plus(0).z(1),
+            CalculatorComplex::new(2.0, -1.0),
+        )
+        .unwrap();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().minus(0),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().minus(0).z(1),
+            CalculatorComplex::from(-0.5),
+        )
+        .unwrap();
+    so_0_1
+        .add_operator_product(
+            PlusMinusProduct::new().z(0).minus(1),
+            CalculatorComplex::new(0.0, 0.5),
+        )
+        .unwrap();
+
+    let product = so_0.clone() * so_1.clone();
+    assert_eq!(product, so_0_1);
+    assert_eq!(
+        SpinOperator::from(product),
+        SpinOperator::from(so_0) * SpinOperator::from(so_1)
+    );
+}
+
 // Test the Debug trait of PlusMinusOperator
 #[test]
 fn debug() {
@@ -500,7 +573,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "PlusMinusOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -526,6 +599,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -552,6 +552,40 @@ fn test_single_plus_minus_operator_product() {
     }
 }
 
+#[test]
+fn multiply() {
+    let left = PlusMinusProduct::new().plus(0).z(1);
+    let right = PlusMinusProduct::new().minus(0).plus(1).z(2);
+    let result = PlusMinusProduct::multiply(left.clone(), right);
+    let expected = vec![
+        (
+            PlusMinusProduct::new().z(0).plus(1).z(2),
+            Complex64::new(0.5, 0.0),
+        ),
+        (
+            PlusMinusProduct::new().plus(1).z(2),
+            Complex64::new(0.5, 0.0),
+        ),
+    ];
+    assert_eq!(result, expected);
+
+    // + * + vanishes
+    assert!(PlusMinusProduct::multiply(left.clone(), left).is_empty());
+
+    // Products on disjoint spins are concatenated
+    let result = PlusMinusProduct::multiply(
+        PlusMinusProduct::new().plus(2),
+        PlusMinusProduct::new().minus(0),
+    );
+    assert_eq!(
+        result,
+        vec![(
+            PlusMinusProduct::new().minus(0).plus(2),
+            Complex64::new(1.0, 0.0)
+        )]
+    );
+}
+
 #[test]
 fn single_so_from_single_pm() {
     let result: Vec<(SingleSpinOperator, Complex64)> =
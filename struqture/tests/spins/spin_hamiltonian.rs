@@ -36,6 +36,54 @@ fn new() {
     assert_eq!(SpinHamiltonian::new(), SpinHamiltonian::default())
 }
 
+// Test the expression representation of SpinHamiltonian
+#[test]
+fn expression() {
+    let sh = SpinHamiltonian::from_expression("J*0Z1Z - 0.5*0X - 0.5*1X").unwrap();
+    assert_eq!(sh.len(), 3);
+    assert_eq!(
+        sh.get(&PauliProduct::new().z(0).z(1)),
+        &CalculatorFloat::from("J")
+    );
+    assert_eq!(sh.to_expression(), "-0.5*0X + (J)*0Z1Z - 0.5*1X");
+    assert_eq!(
+        SpinHamiltonian::from_expression(&sh.to_expression()).unwrap(),
+        sh
+    );
+    assert!(SpinHamiltonian::from_expression("i*0X").is_err());
+}
+
+// Test the constant term of SpinHamiltonian
+#[test]
+fn constant() {
+    let mut sh = SpinHamiltonian::new();
+    assert_eq!(sh.identity_index(), PauliProduct::new());
+    assert_eq!(sh.constant(), &CalculatorFloat::ZERO);
+    sh.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    assert_eq!(sh.set_constant(CalculatorFloat::from(1.0)).unwrap(), None);
+    sh.add_constant(CalculatorFloat::from(0.5)).unwrap();
+    assert_eq!(sh.constant(), &CalculatorFloat::from(1.5));
+    assert_eq!(
+        sh.set_constant(CalculatorFloat::from(-2.0)).unwrap(),
+        Some(CalculatorFloat::from(1.5))
+    );
+
+    let without = sh.without_constant();
+    assert_eq!(without.len(), 1);
+    assert_eq!(without.constant(), &CalculatorFloat::ZERO);
+    assert_eq!(sh.len(), 2);
+
+    // The constant term takes part in arithmetic and serialization
+    let sum = sh.clone() + sh.clone();
+    assert_eq!(sum.constant(), &CalculatorFloat::from(-4.0));
+    let scaled = sh.clone() * CalculatorFloat::from(0.5);
+    assert_eq!(scaled.constant(), &CalculatorFloat::from(-1.0));
+    let serialized = serde_json::to_string(&sh).unwrap();
+    let deserialized: SpinHamiltonian = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.constant(), &CalculatorFloat::from(-2.0));
+}
+
 #[test]
 fn empty_clone_options() {
     let pp_2: PauliProduct = PauliProduct::new().z(2);
@@ -644,7 +692,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -665,6 +713,11 @@ fn serde_compact() {
             Token::F64(1.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -811,6 +864,102 @@ fn sparse_lindblad_entries() {
     );
 }
 
+// Test the channel superoperator exp(-i t [H, .]) of a SpinHamiltonian
+#[test]
+fn channel_superoperator() {
+    let omega = 1.3;
+    let time = 0.9;
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(omega / 2.0))
+        .unwrap();
+
+    let (values, (rows, columns)) = sh.channel_superoperator_coo(time, None).unwrap();
+    assert_eq!(rows, vec![0, 1, 2, 3]);
+    assert_eq!(columns, vec![0, 1, 2, 3]);
+    let phase = Complex64::new(0.0, -omega * time).exp();
+    let expected = [
+        Complex64::new(1.0, 0.0),
+        phase,
+        phase.conj(),
+        Complex64::new(1.0, 0.0),
+    ];
+    for (value, expected_value) in values.iter().zip(expected) {
+        assert!((value - expected_value).norm() < 1e-12);
+    }
+
+    let dense = sh.channel_superoperator_dense(time, Some(2)).unwrap();
+    let (values, (rows, columns)) = sh.channel_superoperator_coo(time, Some(2)).unwrap();
+    assert_eq!(dense.shape(), &[16, 16]);
+    for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+        assert_eq!(dense[[row, column]], value);
+    }
+}
+
+// Test the conversion of the SpinHamiltonian to and from flat arrays
+#[test]
+fn flat_arrays() {
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().x(0).z(2), CalculatorFloat::from(0.5))
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().y(1), CalculatorFloat::from(-1.0))
+        .unwrap();
+
+    let ((x_masks, z_masks), real, imag) = sh.to_flat_arrays().unwrap();
+    assert_eq!(x_masks, vec![2, 1]);
+    assert_eq!(z_masks, vec![2, 4]);
+    assert_eq!(real, vec![-1.0, 0.5]);
+    assert_eq!(imag, vec![0.0, 0.0]);
+    assert_eq!(
+        SpinHamiltonian::from_flat_arrays(&x_masks, &z_masks, &real, &imag).unwrap(),
+        sh
+    );
+    assert_eq!(
+        SpinHamiltonian::from_flat_arrays(&x_masks, &z_masks, &real, &[0.0, 1.0]),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+}
+
+// Test the qdrift_sampler function of the SpinHamiltonian
+#[test]
+fn qdrift_sampler() {
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().z(0), 3.0.into())
+        .unwrap();
+    sh.add_operator_product(PauliProduct::new().x(0).x(1), (-1.0).into())
+        .unwrap();
+
+    let sampler = sh.qdrift_sampler(7).unwrap();
+    assert_eq!(sampler.lambda(), 4.0);
+    assert_eq!(sampler.number_terms(), 2);
+    let samples: Vec<(PauliProduct, f64)> = sampler.clone().take(20000).collect();
+    // The same seed draws the same terms
+    assert_eq!(samples, sampler.take(20000).collect::<Vec<_>>());
+    assert_ne!(
+        samples[..100].to_vec(),
+        sh.qdrift_sampler(8).unwrap().take(100).collect::<Vec<_>>()
+    );
+
+    let mut number_z = 0;
+    for (product, sign) in samples.iter() {
+        if *product == PauliProduct::new().z(0) {
+            assert_eq!(*sign, 1.0);
+            number_z += 1;
+        } else {
+            assert_eq!(*product, PauliProduct::new().x(0).x(1));
+            assert_eq!(*sign, -1.0);
+        }
+    }
+    let frequency = number_z as f64 / 20000.0;
+    assert!((frequency - 0.75).abs() < 0.02);
+
+    assert!(SpinHamiltonian::new().qdrift_sampler(7).is_err());
+    let mut symbolic = SpinHamiltonian::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), "h".into())
+        .unwrap();
+    assert!(symbolic.qdrift_sampler(7).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_spin_hamiltonian_schema() {
@@ -13,6 +13,7 @@
 //! Integration test for public API of SpinHamiltonianSystem
 
 use super::create_na_matrix_from_operator_list;
+use ndarray::{array, Array2};
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
@@ -22,10 +23,14 @@ use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
 use struqture::spins::{
-    OperateOnSpins, PauliProduct, SpinHamiltonian, SpinHamiltonianSystem, SpinSystem,
+    OperateOnSpins, PauliCoupling, PauliProduct, SingleSpinOperator, SpinHamiltonian,
+    SpinHamiltonianSystem, SpinLindbladNoiseSystem, SpinLindbladOpenSystem, SpinSystem,
     ToSparseMatrixOperator, ToSparseMatrixSuperOperator,
 };
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use struqture::{
+    CooSparseMatrix, MergePolicy, MetadataMergePolicy, OperateOnDensityMatrix, SpinIndex,
+    StruqtureError,
+};
 use test_case::test_case;
 
 // Test the new function of the SpinHamiltonianSystem
@@ -143,6 +148,32 @@ fn internal_map_add_operator_product() {
     assert_eq!(error, Err(StruqtureError::NumberSpinsExceeded));
 }
 
+// Test the add_with_policy function of the SpinHamiltonianSystem
+#[test]
+fn add_with_policy() {
+    let pp: PauliProduct = PauliProduct::new().z(0);
+    let mut left = SpinHamiltonianSystem::new(Some(2));
+    left.add_operator_product(pp.clone(), CalculatorFloat::from("theta"))
+        .unwrap();
+    let mut right = SpinHamiltonianSystem::new(Some(3));
+    right
+        .add_operator_product(pp.clone(), CalculatorFloat::from("phi"))
+        .unwrap();
+
+    let error = left.add_with_policy(&right, MergePolicy::ErrorOnSymbolConflict);
+    assert!(matches!(error, Err(StruqtureError::GenericError { .. })));
+    let preferred = left
+        .add_with_policy(&right, MergePolicy::PreferLeft)
+        .unwrap();
+    assert_eq!(preferred, left);
+
+    right
+        .add_operator_product(PauliProduct::new().x(2), CalculatorFloat::from(0.1))
+        .unwrap();
+    let error = left.add_with_policy(&right, MergePolicy::PreferLeft);
+    assert_eq!(error, Err(StruqtureError::NumberSpinsExceeded));
+}
+
 // Test the get and remove functions of the spinOperator
 #[test]
 fn internal_map_set_get_remove() {
@@ -804,7 +835,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinHamiltonianSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_spins"),
             Token::Some,
@@ -812,7 +843,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "SpinHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -833,6 +864,11 @@ fn serde_compact() {
             Token::F64(1.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -844,11 +880,139 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
 }
 
+// Returns the diagonal entry of the sparse matrix of system for the basis state of the binary variables
+fn diagonal_energy(matrix: &HashMap<(usize, usize), Complex64>, state: usize) -> f64 {
+    matrix
+        .get(&(state, state))
+        .map(|value| value.re)
+        .unwrap_or(0.0)
+}
+
+// Test the conversion of a QUBO matrix to a SpinHamiltonianSystem
+#[test]
+fn from_qubo() {
+    let qubo = array![[1.0, -2.0, 0.5], [0.0, -1.0, 3.0], [1.5, 0.0, 2.0]];
+    let system = SpinHamiltonianSystem::from_qubo(&qubo).unwrap();
+    assert_eq!(system.number_spins(), 3);
+    assert!(system
+        .keys()
+        .all(|product| product.iter().all(|(_, op)| *op == SingleSpinOperator::Z)));
+
+    let matrix = system.sparse_matrix(None).unwrap();
+    for state in 0..8 {
+        let x: Vec<f64> = (0..3).map(|i| ((state >> i) & 1) as f64).collect();
+        let mut cost = 0.0;
+        for ((i, j), value) in qubo.indexed_iter() {
+            cost += value * x[i] * x[j];
+        }
+        assert!((diagonal_energy(&matrix, state) - cost).abs() < 1e-12);
+    }
+
+    let error = SpinHamiltonianSystem::from_qubo(&Array2::zeros((2, 3)));
+    assert!(error.is_err());
+}
+
+// Test the export of diagonal SpinHamiltonianSystems to Ising models and QUBO matrices
+#[test]
+fn to_ising_and_qubo() {
+    let qubo = array![[1.0, -2.0, 0.5], [0.0, -1.0, 3.0], [1.5, 0.0, 2.0]];
+    let mut system = SpinHamiltonianSystem::from_qubo(&qubo).unwrap();
+    system.add_constant(0.25.into()).unwrap();
+
+    let (couplings, fields, offset) = system.to_ising().unwrap();
+    assert_eq!(couplings.dim(), (3, 3));
+    assert_eq!(couplings[(1, 0)], 0.0);
+    assert_eq!(couplings[(0, 2)], 0.5);
+    assert_eq!(fields.len(), 3);
+    assert_eq!(offset, f64::try_from(system.constant().clone()).unwrap());
+
+    let (exported, exported_offset) = system.to_qubo().unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            let expected = match i.cmp(&j) {
+                std::cmp::Ordering::Less => qubo[(i, j)] + qubo[(j, i)],
+                std::cmp::Ordering::Equal => qubo[(i, i)],
+                std::cmp::Ordering::Greater => 0.0,
+            };
+            assert!((exported[(i, j)] - expected).abs() < 1e-12);
+        }
+    }
+    assert!((exported_offset - 0.25).abs() < 1e-12);
+
+    let mut not_diagonal = SpinHamiltonianSystem::new(None);
+    not_diagonal
+        .add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    assert!(not_diagonal.to_ising().is_err());
+    assert!(not_diagonal.to_qubo().is_err());
+
+    let mut three_local = SpinHamiltonianSystem::new(None);
+    three_local
+        .add_operator_product(PauliProduct::new().z(0).z(1).z(2), 1.0.into())
+        .unwrap();
+    assert!(three_local.to_ising().is_err());
+
+    let mut symbolic = SpinHamiltonianSystem::new(None);
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), "h".into())
+        .unwrap();
+    assert!(symbolic.to_qubo().is_err());
+}
+
+// Test the penalty terms of linear equality constraints
+#[test]
+fn add_equality_constraint() {
+    let mut system = SpinHamiltonianSystem::new(Some(3));
+    system
+        .add_equality_constraint(&[(0, 1.0), (1, 2.0), (2, 1.0), (2, 1.0)], 2.0, 3.0.into())
+        .unwrap();
+
+    let matrix = system.sparse_matrix(None).unwrap();
+    for state in 0..8 {
+        let x: Vec<f64> = (0..3).map(|i| ((state >> i) & 1) as f64).collect();
+        let violation = x[0] + 2.0 * x[1] + 2.0 * x[2] - 2.0;
+        assert!((diagonal_energy(&matrix, state) - 3.0 * violation * violation).abs() < 1e-12);
+    }
+
+    let mut symbolic = SpinHamiltonianSystem::new(None);
+    symbolic
+        .add_equality_constraint(&[(0, 1.0), (1, 1.0)], 1.0, "lambda".into())
+        .unwrap();
+    assert!(!symbolic.get(&PauliProduct::new().z(0).z(1)).is_float());
+
+    let mut too_small = SpinHamiltonianSystem::new(Some(1));
+    assert_eq!(
+        too_small.add_equality_constraint(&[(1, 1.0)], 1.0, 1.0.into()),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+}
+
+// Test that the constant term is included in the sparse matrix
+#[test]
+fn constant_sparse_matrix() {
+    let mut system = SpinHamiltonianSystem::new(Some(1));
+    system
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    system.set_constant(CalculatorFloat::from(2.0)).unwrap();
+
+    let matrix = system.sparse_matrix(None).unwrap();
+    assert_eq!(matrix.len(), 2);
+    assert_eq!(matrix.get(&(0, 0)), Some(&Complex64::new(2.5, 0.0)));
+    assert_eq!(matrix.get(&(1, 1)), Some(&Complex64::new(1.5, 0.0)));
+
+    let matrix = system.without_constant().sparse_matrix(None).unwrap();
+    assert_eq!(matrix.get(&(0, 0)), Some(&Complex64::new(0.5, 0.0)));
+}
+
 // Test system_matrix function
 #[test]
 fn test_la_interface_y_triplets2() {
@@ -1020,6 +1184,45 @@ fn sparse_lindblad_entries() {
     );
 }
 
+// Test the construction of SpinHamiltonianSystems from edge lists
+#[test]
+fn from_edges() {
+    let edges = [
+        (0, 1, CalculatorFloat::from(1.0)),
+        (1, 2, CalculatorFloat::from("J")),
+        (0, 1, CalculatorFloat::from(0.5)),
+    ];
+    let system = SpinHamiltonianSystem::from_edges(&edges, PauliCoupling::Heisenberg).unwrap();
+    assert_eq!(system.len(), 6);
+    assert_eq!(system.number_spins(), 3);
+    assert_eq!(
+        system.get(&PauliProduct::new().y(0).y(1)),
+        &CalculatorFloat::from(1.5)
+    );
+    assert_eq!(
+        system.get(&PauliProduct::new().z(1).z(2)),
+        &CalculatorFloat::from("J")
+    );
+
+    let system = SpinHamiltonianSystem::from_edges(&edges[..2], PauliCoupling::XY).unwrap();
+    assert_eq!(system.len(), 4);
+    assert_eq!(
+        system.get(&PauliProduct::new().z(0).z(1)),
+        &CalculatorFloat::from(0.0)
+    );
+
+    let coupling = PauliCoupling::Pair(SingleSpinOperator::X, SingleSpinOperator::Z);
+    let system = SpinHamiltonianSystem::from_edges(&edges[1..2], coupling).unwrap();
+    assert_eq!(system.len(), 1);
+    assert_eq!(
+        system.get(&PauliProduct::new().x(1).z(2)),
+        &CalculatorFloat::from("J")
+    );
+
+    let loop_edge = [(3, 3, CalculatorFloat::from(1.0))];
+    assert!(SpinHamiltonianSystem::from_edges(&loop_edge, PauliCoupling::Ising).is_err());
+}
+
 #[test]
 fn test_truncate() {
     let mut system = SpinHamiltonianSystem::new(None);
@@ -1061,6 +1264,207 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the provenance metadata of the SpinHamiltonianSystem
+#[test]
+fn metadata() {
+    let mut system = SpinHamiltonianSystem::new(Some(2));
+    system
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    assert!(system.metadata().is_empty());
+    assert_eq!(system.set_metadata("geometry", "H2"), None);
+    assert_eq!(system.set_metadata("basis", "sto-3g"), None);
+    assert_eq!(
+        system.set_metadata("basis", "cc-pvdz"),
+        Some("sto-3g".to_string())
+    );
+    assert_eq!(system.get_metadata("basis"), Some("cc-pvdz"));
+    assert_eq!(system.remove_metadata("basis"), Some("cc-pvdz".to_string()));
+    assert_eq!(system.get_metadata("basis"), None);
+
+    // Merging with the different policies
+    let other = BTreeMap::from([
+        ("geometry".to_string(), "LiH".to_string()),
+        ("bond_length".to_string(), "0.74".to_string()),
+    ]);
+    let mut left = system.clone();
+    assert!(left
+        .merge_metadata(&other, MetadataMergePolicy::ErrorOnConflict)
+        .is_err());
+    assert_eq!(left.metadata(), system.metadata());
+    left.merge_metadata(&other, MetadataMergePolicy::PreferLeft)
+        .unwrap();
+    assert_eq!(left.get_metadata("geometry"), Some("H2"));
+    assert_eq!(left.get_metadata("bond_length"), Some("0.74"));
+    let mut right = system.clone();
+    right
+        .merge_metadata(&other, MetadataMergePolicy::PreferRight)
+        .unwrap();
+    assert_eq!(right.metadata(), &other);
+
+    // The metadata is compared and serialized
+    assert_ne!(left, right);
+    let serialized = serde_json::to_string(&left).unwrap();
+    assert!(serialized.contains("bond_length"));
+    let deserialized: SpinHamiltonianSystem = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, left);
+    let encoded: Vec<u8> = bincode::serialize(&left).unwrap();
+    let decoded: SpinHamiltonianSystem = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(decoded, left);
+
+    // Empty metadata is left out of JSON, so files written without metadata can still be read
+    let empty = SpinHamiltonianSystem::new(Some(2));
+    let serialized = serde_json::to_string(&empty).unwrap();
+    assert!(!serialized.contains("metadata"));
+    let deserialized: SpinHamiltonianSystem = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, empty);
+    let encoded: Vec<u8> = bincode::serialize(&empty).unwrap();
+    let decoded: SpinHamiltonianSystem = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(decoded, empty);
+
+    // Arithmetic of two systems merges the metadata, keeping the values of the left operand
+    let merged = BTreeMap::from([
+        ("geometry".to_string(), "H2".to_string()),
+        ("bond_length".to_string(), "0.74".to_string()),
+    ]);
+    let sum = (system.clone() + right.clone()).unwrap();
+    assert_eq!(sum.metadata(), &merged);
+    let difference = (system.clone() - right.clone()).unwrap();
+    assert_eq!(difference.metadata(), &merged);
+    let product = (system.clone() * right.clone()).unwrap();
+    assert_eq!(product.metadata(), &merged);
+    let reversed = (right.clone() + system.clone()).unwrap();
+    assert_eq!(reversed.metadata(), &other);
+
+    // Operands without metadata keep the metadata of the system
+    let operator_sum = (system.clone() + right.hamiltonian().clone()).unwrap();
+    assert_eq!(operator_sum.metadata(), system.metadata());
+    let scaled = system.clone() * CalculatorFloat::from(2.0);
+    assert_eq!(scaled.metadata(), system.metadata());
+    let complex_scaled = system.clone() * CalculatorComplex::new(0.0, 1.0);
+    assert_eq!(complex_scaled.metadata(), system.metadata());
+    assert_eq!(system.empty_clone(None).metadata(), system.metadata());
+
+    // Open systems keep their metadata in the Hamiltonian system
+    let mut open_system =
+        SpinLindbladOpenSystem::group(system.clone(), SpinLindbladNoiseSystem::new(Some(2)))
+            .unwrap();
+    assert_eq!(open_system.get_metadata("geometry"), Some("H2"));
+    open_system.set_metadata("geometry", "LiH");
+    assert_eq!(open_system.system().get_metadata("geometry"), Some("LiH"));
+}
+
+// Test the partition_by_locality function of the SpinHamiltonianSystem
+#[test]
+fn partition_by_locality() {
+    let geometry = vec![
+        vec![0.0, 0.0],
+        vec![1.0, 0.0],
+        vec![6.0, 0.0],
+        vec![6.0, 1.0],
+    ];
+    let mut system = SpinHamiltonianSystem::new(Some(4));
+    system.set_metadata("geometry", "chain");
+    system
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(2.0))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().z(1).z(2), CalculatorFloat::from(0.1))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(2).y(3), CalculatorFloat::from("g"))
+        .unwrap();
+
+    let (blocks, remainder) = system.partition_by_locality(1.5, &geometry).unwrap();
+    assert_eq!(blocks.len(), 2);
+    let mut first_block = SpinHamiltonianSystem::new(Some(4));
+    first_block
+        .add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    first_block
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    assert_eq!(blocks[0].hamiltonian(), first_block.hamiltonian());
+    let mut second_block = SpinHamiltonianSystem::new(Some(4));
+    second_block
+        .add_operator_product(PauliProduct::new().x(2).y(3), CalculatorFloat::from("g"))
+        .unwrap();
+    assert_eq!(blocks[1].hamiltonian(), second_block.hamiltonian());
+    let mut comparison_remainder = SpinHamiltonianSystem::new(Some(4));
+    comparison_remainder
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(2.0))
+        .unwrap();
+    comparison_remainder
+        .add_operator_product(PauliProduct::new().z(1).z(2), CalculatorFloat::from(0.1))
+        .unwrap();
+    assert_eq!(remainder.hamiltonian(), comparison_remainder.hamiltonian());
+    for block in blocks.iter().chain([&remainder]) {
+        assert_eq!(block.number_spins(), 4);
+        assert_eq!(block.get_metadata("geometry"), Some("chain"));
+    }
+
+    // A large distance puts all spins into one block
+    let (blocks, remainder) = system.partition_by_locality(10.0, &geometry).unwrap();
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].len(), 4);
+    assert_eq!(remainder.len(), 1);
+
+    // Invalid geometries
+    let mismatched = vec![vec![0.0, 0.0], vec![1.0], vec![6.0, 0.0], vec![6.0, 1.0]];
+    assert!(system.partition_by_locality(1.5, &mismatched).is_err());
+    assert!(system.partition_by_locality(1.5, &geometry[..3]).is_err());
+}
+
+// Test the partial_trace function of the SpinHamiltonianSystem
+#[test]
+fn partial_trace() {
+    let mut system = SpinHamiltonianSystem::new(Some(3));
+    system.set_metadata("geometry", "chain");
+    system
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(1.0))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(2), CalculatorFloat::from("h"))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().z(1).z(2), CalculatorFloat::from(0.5))
+        .unwrap();
+
+    let reduced = system.partial_trace(&[1]).unwrap();
+    let mut comparison = SpinHamiltonianSystem::new(Some(2));
+    comparison.set_metadata("geometry", "chain");
+    comparison
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(2.0))
+        .unwrap();
+    comparison
+        .add_operator_product(PauliProduct::new().x(1), CalculatorFloat::from("h") * 2.0)
+        .unwrap();
+    assert_eq!(reduced, comparison);
+    assert_eq!(reduced.number_spins(), 2);
+
+    // A system without a fixed number of spins keeps it unfixed
+    let mut unfixed = SpinHamiltonianSystem::new(None);
+    unfixed
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(0.5))
+        .unwrap();
+    let reduced = unfixed.partial_trace(&[0, 1]).unwrap();
+    assert!(reduced.is_empty());
+    assert_eq!(reduced.number_spins(), 0);
+
+    // Spins outside of the system cannot be traced out
+    assert!(system.partial_trace(&[3]).is_err());
+    assert!(unfixed.partial_trace(&[2]).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
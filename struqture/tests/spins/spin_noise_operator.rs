@@ -15,6 +15,7 @@
 use super::create_na_matrix_from_decoherence_list;
 use na::DMatrix;
 use nalgebra as na;
+use ndarray::array;
 use num_complex::Complex64;
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
@@ -264,6 +265,74 @@ fn into_iter_from_iter_extend() {
     assert_eq!(slno_0, slno_0_1);
 }
 
+// Test the adjoint_violations, is_self_adjoint and symmetrize functions of the SpinLindbladNoiseOperator
+#[test]
+fn adjoint_check_and_symmetrize() {
+    let dp_x = DecoherenceProduct::new().x(0);
+    let dp_z = DecoherenceProduct::new().z(0);
+    let dp_y = DecoherenceProduct::new().iy(1);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.set(
+        (dp_x.clone(), dp_x.clone()),
+        CalculatorComplex::new(1.0, 0.0),
+    )
+    .unwrap();
+    slno.set(
+        (dp_x.clone(), dp_z.clone()),
+        CalculatorComplex::new(0.5, 0.5),
+    )
+    .unwrap();
+    slno.set(
+        (dp_z.clone(), dp_x.clone()),
+        CalculatorComplex::new(0.5, -0.5),
+    )
+    .unwrap();
+    assert!(slno.is_self_adjoint());
+    assert!(slno.adjoint_violations().is_empty());
+
+    let mut symmetrized = slno.clone();
+    assert!(symmetrized.symmetrize().is_empty());
+    assert_eq!(symmetrized, slno);
+
+    // Missing transposed entry and complex diagonal entry
+    slno.set(
+        (dp_x.clone(), dp_y.clone()),
+        CalculatorComplex::new(0.0, 1.0),
+    )
+    .unwrap();
+    slno.set(
+        (dp_z.clone(), dp_z.clone()),
+        CalculatorComplex::new(2.0, 1.0),
+    )
+    .unwrap();
+    assert!(!slno.is_self_adjoint());
+    let violations = slno.adjoint_violations();
+    assert_eq!(violations.len(), 2);
+    assert!(violations.contains(&(dp_x.clone(), dp_y.clone())));
+    assert!(violations.contains(&(dp_z.clone(), dp_z.clone())));
+
+    let changed = slno.symmetrize();
+    assert_eq!(changed.len(), 3);
+    assert!(changed.contains(&(dp_y.clone(), dp_x.clone())));
+    assert!(slno.is_self_adjoint());
+    assert_eq!(
+        slno.get(&(dp_x.clone(), dp_y.clone())),
+        &CalculatorComplex::new(0.0, 0.5)
+    );
+    assert_eq!(
+        slno.get(&(dp_y, dp_x.clone())),
+        &CalculatorComplex::new(0.0, -0.5)
+    );
+    assert_eq!(
+        slno.get(&(dp_z.clone(), dp_z)),
+        &CalculatorComplex::new(2.0, 0.0)
+    );
+    assert_eq!(
+        slno.get(&(dp_x.clone(), dp_x)),
+        &CalculatorComplex::new(1.0, 0.0)
+    );
+}
+
 // Test the remap_qubits function of the PauliProduct
 #[test]
 fn remap_qubits() {
@@ -638,7 +707,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -673,6 +742,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -919,6 +993,268 @@ fn illegal_identity_operators() {
     assert!(ok);
 }
 
+// Test the channel superoperator exp(t L) of a dephasing SpinLindbladNoiseOperator
+#[test]
+fn channel_superoperator_dephasing() {
+    let rate = 0.5;
+    let time = 0.7;
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.add_operator_product(
+        (
+            DecoherenceProduct::new().z(0),
+            DecoherenceProduct::new().z(0),
+        ),
+        CalculatorComplex::from(rate),
+    )
+    .unwrap();
+
+    let (values, (rows, columns)) = slno.channel_superoperator_coo(time, None).unwrap();
+    assert_eq!(rows, vec![0, 1, 2, 3]);
+    assert_eq!(columns, vec![0, 1, 2, 3]);
+    let decay = (-2.0 * rate * time).exp();
+    for (value, expected) in values.iter().zip([1.0, decay, decay, 1.0]) {
+        assert!((value - Complex64::new(expected, 0.0)).norm() < 1e-12);
+    }
+
+    let (values, _) = slno.channel_superoperator_coo(0.0, Some(2)).unwrap();
+    assert_eq!(values, vec![Complex64::new(1.0, 0.0); 16]);
+}
+
+// Test that channels of SpinLindbladNoiseOperators are trace preserving and form a semigroup
+#[test]
+fn channel_superoperator_semigroup() {
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.add_operator_product(
+        (
+            DecoherenceProduct::new().x(0),
+            DecoherenceProduct::new().x(0),
+        ),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    slno.add_operator_product(
+        (
+            DecoherenceProduct::new().z(1),
+            DecoherenceProduct::new().z(1),
+        ),
+        CalculatorComplex::from(0.2),
+    )
+    .unwrap();
+    slno.add_operator_product(
+        (
+            DecoherenceProduct::new().x(0).iy(1),
+            DecoherenceProduct::new().z(0),
+        ),
+        CalculatorComplex::from(1.1),
+    )
+    .unwrap();
+    slno.add_operator_product(
+        (
+            DecoherenceProduct::new().z(0),
+            DecoherenceProduct::new().x(0).iy(1),
+        ),
+        CalculatorComplex::from(1.1),
+    )
+    .unwrap();
+
+    let dimension = 16;
+    let to_matrix = |(values, (rows, columns)): CooSparseMatrix| -> DMatrix<Complex64> {
+        let mut matrix = DMatrix::<Complex64>::zeros(dimension, dimension);
+        for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+            matrix[(row, column)] = value;
+        }
+        matrix
+    };
+    let channel = to_matrix(slno.channel_superoperator_coo(1.0, None).unwrap());
+    let first = to_matrix(slno.channel_superoperator_coo(0.4, None).unwrap());
+    let second = to_matrix(slno.channel_superoperator_coo(0.6, None).unwrap());
+    assert!((channel.clone() - first * second).norm() < 1e-10);
+
+    // Row-major flattening: the trace is the sum of the entries at the indices i * 4 + i
+    for column in 0..dimension {
+        let trace: Complex64 = (0..4).map(|i| channel[(i * 4 + i, column)]).sum();
+        let expected = if column % 5 == 0 { 1.0 } else { 0.0 };
+        assert!((trace - Complex64::new(expected, 0.0)).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn kossakowski_matrix() {
+    let dp_x = DecoherenceProduct::new().x(0);
+    let dp_z = DecoherenceProduct::new().z(0);
+    let dp_y = DecoherenceProduct::new().iy(1);
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.set((dp_x.clone(), dp_x.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    slno.set(
+        (dp_x.clone(), dp_z.clone()),
+        CalculatorComplex::new(0.0, 0.5),
+    )
+    .unwrap();
+    slno.set(
+        (dp_z.clone(), dp_x.clone()),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    slno.set(
+        (dp_x.clone(), dp_y.clone()),
+        CalculatorComplex::from("gamma"),
+    )
+    .unwrap();
+    slno.set((dp_y.clone(), dp_y.clone()), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let (matrix, remainder) = slno
+        .kossakowski_matrix(&[dp_z.clone(), dp_x.clone()])
+        .unwrap();
+    assert_eq!(
+        matrix,
+        array![
+            [Complex64::new(0.0, 0.0), Complex64::new(0.0, -0.5)],
+            [Complex64::new(0.0, 0.5), Complex64::new(1.0, 0.0)]
+        ]
+    );
+    let mut expected_remainder = SpinLindbladNoiseOperator::new();
+    expected_remainder
+        .set(
+            (dp_x.clone(), dp_y.clone()),
+            CalculatorComplex::from("gamma"),
+        )
+        .unwrap();
+    expected_remainder
+        .set((dp_y.clone(), dp_y.clone()), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(remainder, expected_remainder);
+
+    let (empty_matrix, full_remainder) = slno.kossakowski_matrix(&[]).unwrap();
+    assert_eq!(empty_matrix.shape(), &[0, 0]);
+    assert_eq!(full_remainder, slno);
+
+    assert!(slno
+        .kossakowski_matrix(&[dp_x.clone(), dp_y.clone()])
+        .is_err());
+    assert!(slno.kossakowski_matrix(&[dp_x.clone(), dp_x]).is_err());
+}
+
+#[test]
+fn damping_and_dephasing() {
+    let dp_x = DecoherenceProduct::new().x(1);
+    let dp_iy = DecoherenceProduct::new().iy(1);
+    let dp_z = DecoherenceProduct::new().z(1);
+
+    // Zero temperature damping of qubit 0 and thermal occupation 1 of qubit 1
+    let noise = SpinLindbladNoiseOperator::damping_and_dephasing(
+        &[0.0, 1.0 / 2.0_f64.ln()],
+        &[(1.0, 0.0), (1.0, 0.4)],
+    )
+    .unwrap();
+
+    let qubit_0: Vec<_> = noise
+        .iter()
+        .filter(|((left, _), _)| left.current_number_spins() == 1)
+        .collect();
+    assert_eq!(qubit_0.len(), 4);
+
+    let close = |key: (DecoherenceProduct, DecoherenceProduct), expected: f64| {
+        let value = noise.get(&key);
+        assert!((*value.re.float().unwrap() - expected).abs() < 1e-12);
+        assert!(value.im.float().unwrap().abs() < 1e-12);
+    };
+    close((dp_x.clone(), dp_x.clone()), 0.75);
+    close((dp_iy.clone(), dp_iy.clone()), 0.75);
+    close((dp_x.clone(), dp_iy.clone()), 0.25);
+    close((dp_iy, dp_x), 0.25);
+    close((dp_z.clone(), dp_z), 0.2);
+    close(
+        (
+            DecoherenceProduct::new().x(0),
+            DecoherenceProduct::new().iy(0),
+        ),
+        0.25,
+    );
+
+    assert!(SpinLindbladNoiseOperator::damping_and_dephasing(&[0.0], &[]).is_err());
+    assert!(SpinLindbladNoiseOperator::damping_and_dephasing(&[-1.0], &[(1.0, 0.0)]).is_err());
+    assert!(SpinLindbladNoiseOperator::damping_and_dephasing(&[0.0], &[(1.0, -1.0)]).is_err());
+}
+
+#[test]
+fn locality_statistics() {
+    let mut slno = SpinLindbladNoiseOperator::new();
+    slno.set(
+        (
+            DecoherenceProduct::new().z(0),
+            DecoherenceProduct::new().z(0),
+        ),
+        CalculatorComplex::from(0.1),
+    )
+    .unwrap();
+    slno.set(
+        (
+            DecoherenceProduct::new().x(1),
+            DecoherenceProduct::new().iy(3),
+        ),
+        CalculatorComplex::from(0.2),
+    )
+    .unwrap();
+    slno.set(
+        (
+            DecoherenceProduct::new().x(1).z(2),
+            DecoherenceProduct::new().z(2),
+        ),
+        CalculatorComplex::from(0.3),
+    )
+    .unwrap();
+    assert_eq!(slno.weight_distribution(), BTreeMap::from([(1, 1), (2, 2)]));
+    assert_eq!(slno.max_weight(), 2);
+    assert_eq!(
+        slno.support().into_iter().collect::<Vec<usize>>(),
+        vec![0, 1, 2, 3]
+    );
+}
+
+#[test]
+fn compose_and_concatenate_channels() {
+    let dp_x0 = DecoherenceProduct::new().x(0);
+    let dp_z0 = DecoherenceProduct::new().z(0);
+    let dp_x1 = DecoherenceProduct::new().x(1);
+    let mut first = SpinLindbladNoiseOperator::new();
+    first
+        .set((dp_z0.clone(), dp_z0.clone()), CalculatorComplex::from(0.1))
+        .unwrap();
+    let mut second = SpinLindbladNoiseOperator::new();
+    second
+        .set((dp_x1.clone(), dp_x1.clone()), CalculatorComplex::from(0.2))
+        .unwrap();
+    second
+        .set(
+            (dp_z0.clone(), dp_z0.clone()),
+            CalculatorComplex::from(0.05),
+        )
+        .unwrap();
+    second
+        .set((dp_x0.clone(), dp_x0.clone()), CalculatorComplex::from(0.3))
+        .unwrap();
+
+    let composed = first.compose(&second);
+    assert_eq!(composed.len(), 3);
+    assert_eq!(
+        composed.get(&(dp_z0.clone(), dp_z0.clone())),
+        &CalculatorComplex::from(0.15)
+    );
+
+    // Pauli channels commute, so the concatenated channel is generated by the sum of the generators
+    let concatenated = first.concatenate_channels(&second, 0.5).unwrap();
+    assert_eq!(concatenated.len(), composed.len());
+    for (key, value) in composed.iter() {
+        let effective = concatenated.get(key);
+        assert!((effective.re.float().unwrap() - value.re.float().unwrap()).abs() < 1e-9);
+        assert!(effective.im.float().unwrap().abs() < 1e-9);
+    }
+
+    assert!(first.concatenate_channels(&second, 0.0).is_err());
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_spin_noise_operator_schema() {
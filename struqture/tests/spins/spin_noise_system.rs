@@ -23,7 +23,10 @@ use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 use struqture::prelude::*;
-use struqture::spins::{DecoherenceProduct, SpinLindbladNoiseOperator, SpinLindbladNoiseSystem};
+use struqture::spins::{
+    DecoherenceProduct, PauliProduct, SpinLindbladNoiseOperator, SpinLindbladNoiseSystem,
+    SpinOperator,
+};
 use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError};
 use test_case::test_case;
 
@@ -594,7 +597,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinLindbladNoiseSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_spins"),
             Token::Some,
@@ -602,7 +605,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "SpinLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -637,6 +640,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -648,6 +656,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
@@ -862,6 +873,197 @@ fn unitary_matrix() {
     assert_eq!(system.unitary_sparse_matrix_coo().unwrap(), unitary_matrix);
 }
 
+#[test]
+fn is_completely_positive() {
+    let dp_x = DecoherenceProduct::new().x(0);
+    let dp_z = DecoherenceProduct::new().z(0);
+    let mut slns = SpinLindbladNoiseSystem::new(Some(1));
+    assert!(slns.is_completely_positive(1e-10).unwrap());
+
+    slns.set((dp_x.clone(), dp_x.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    slns.set((dp_z.clone(), dp_z.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    slns.set(
+        (dp_x.clone(), dp_z.clone()),
+        CalculatorComplex::new(0.0, 0.5),
+    )
+    .unwrap();
+    slns.set(
+        (dp_z.clone(), dp_x.clone()),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    assert!(slns.is_completely_positive(1e-10).unwrap());
+
+    // Eigenvalues 0.25 - 0.5 and 0.25 + 0.5
+    let mut negative = slns.clone();
+    negative
+        .set((dp_x.clone(), dp_x.clone()), CalculatorComplex::from(0.25))
+        .unwrap();
+    negative
+        .set((dp_z.clone(), dp_z.clone()), CalculatorComplex::from(0.25))
+        .unwrap();
+    assert!(!negative.is_completely_positive(1e-10).unwrap());
+
+    let mut not_self_adjoint = slns.clone();
+    not_self_adjoint
+        .set(
+            (dp_z.clone(), dp_x.clone()),
+            CalculatorComplex::new(0.0, 0.5),
+        )
+        .unwrap();
+    assert!(!not_self_adjoint.is_completely_positive(1e-10).unwrap());
+
+    let mut symbolic = slns;
+    symbolic
+        .set((dp_x.clone(), dp_x), CalculatorComplex::from("gamma"))
+        .unwrap();
+    assert!(matches!(
+        symbolic.is_completely_positive(1e-10),
+        Err(StruqtureError::CalculatorError(_))
+    ));
+}
+
+#[test]
+fn jump_operators() {
+    let dp_x = DecoherenceProduct::new().x(0);
+    let dp_z = DecoherenceProduct::new().z(0);
+    let mut slns = SpinLindbladNoiseSystem::new(Some(1));
+    assert!(slns.jump_operators(1e-10).unwrap().is_empty());
+
+    slns.set((dp_x.clone(), dp_x.clone()), CalculatorComplex::from(2.0))
+        .unwrap();
+    let jump_operators = slns.jump_operators(1e-10).unwrap();
+    assert_eq!(jump_operators.len(), 1);
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(2.0))
+        .unwrap();
+    let product = jump_operators[0].hermitian_conjugate() * jump_operators[0].clone();
+    assert!((product - expected).truncate(1e-10).is_empty());
+
+    // sum_m L_m^dagger L_m = sum_jk Gamma_jk A_k^dagger A_j = 3 I - Y
+    slns.set((dp_z.clone(), dp_z.clone()), CalculatorComplex::from(1.0))
+        .unwrap();
+    slns.set(
+        (dp_x.clone(), dp_z.clone()),
+        CalculatorComplex::new(0.0, 0.5),
+    )
+    .unwrap();
+    slns.set(
+        (dp_z.clone(), dp_x.clone()),
+        CalculatorComplex::new(0.0, -0.5),
+    )
+    .unwrap();
+    let jump_operators = slns.jump_operators(1e-10).unwrap();
+    assert_eq!(jump_operators.len(), 2);
+    let mut sum = SpinOperator::new();
+    for jump_operator in jump_operators {
+        sum = sum + jump_operator.hermitian_conjugate() * jump_operator;
+    }
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(3.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::from(-1.0))
+        .unwrap();
+    assert!((sum - expected).truncate(1e-10).is_empty());
+
+    let mut negative = slns.clone();
+    negative
+        .set((dp_x.clone(), dp_x), CalculatorComplex::from(0.25))
+        .unwrap();
+    negative
+        .set((dp_z.clone(), dp_z), CalculatorComplex::from(0.25))
+        .unwrap();
+    assert!(matches!(
+        negative.jump_operators(1e-10),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[test]
+fn from_t1_t2() {
+    let slns =
+        SpinLindbladNoiseSystem::from_t1_t2(&[10.0, f64::INFINITY], &[5.0, f64::INFINITY]).unwrap();
+    assert_eq!(slns.number_spins(), 2);
+    assert_eq!(slns.len(), 5);
+    let dp_x = DecoherenceProduct::new().x(0);
+    let dp_iy = DecoherenceProduct::new().iy(0);
+    let dp_z = DecoherenceProduct::new().z(0);
+    assert_eq!(
+        slns.get(&(dp_x.clone(), dp_iy)),
+        &CalculatorComplex::from(0.025)
+    );
+    assert_eq!(
+        slns.get(&(dp_x.clone(), dp_x)),
+        &CalculatorComplex::from(0.025)
+    );
+    assert_eq!(
+        slns.get(&(dp_z.clone(), dp_z)),
+        &CalculatorComplex::from(0.075)
+    );
+
+    // The population of |1> decays with 1/T1 and the coherence with 1/T2
+    let single = SpinLindbladNoiseSystem::from_t1_t2(&[10.0], &[5.0]).unwrap();
+    let (values, (rows, columns)) = single.sparse_matrix_superoperator_coo(None).unwrap();
+    let entries: HashMap<(usize, usize), Complex64> =
+        rows.into_iter().zip(columns).zip(values).collect();
+    assert!((entries[&(3, 3)] - Complex64::new(-0.1, 0.0)).norm() < 1e-12);
+    assert!((entries[&(0, 3)] - Complex64::new(0.1, 0.0)).norm() < 1e-12);
+    assert!((entries[&(1, 1)] - Complex64::new(-0.2, 0.0)).norm() < 1e-12);
+
+    assert!(SpinLindbladNoiseSystem::from_t1_t2(&[10.0], &[]).is_err());
+    assert!(SpinLindbladNoiseSystem::from_t1_t2(&[10.0], &[25.0]).is_err());
+    assert!(SpinLindbladNoiseSystem::from_t1_t2(&[-1.0], &[1.0]).is_err());
+    assert!(SpinLindbladNoiseSystem::from_t1_t2(&[f64::NAN], &[1.0]).is_err());
+}
+
+// Test the canonical serialization of SpinLindbladNoiseSystem
+#[cfg(feature = "io")]
+#[test]
+fn serialize_canonical() {
+    let terms: Vec<(DecoherenceProduct, DecoherenceProduct)> = (0..10)
+        .flat_map(|index| {
+            [
+                (
+                    DecoherenceProduct::new().x(index),
+                    DecoherenceProduct::new().z(index),
+                ),
+                (
+                    DecoherenceProduct::new().z(index),
+                    DecoherenceProduct::new().x(index),
+                ),
+            ]
+        })
+        .collect();
+    let mut forward = SpinLindbladNoiseSystem::new(Some(10));
+    for (left, right) in terms.iter() {
+        forward
+            .add_operator_product((left.clone(), right.clone()), 0.5.into())
+            .unwrap();
+    }
+    let mut backward = SpinLindbladNoiseSystem::new(Some(10));
+    for (left, right) in terms.iter().rev() {
+        backward
+            .add_operator_product((left.clone(), right.clone()), 0.5.into())
+            .unwrap();
+    }
+
+    let json = forward.to_json_canonical().unwrap();
+    assert_eq!(json, backward.to_json_canonical().unwrap());
+    assert_eq!(
+        forward.to_bincode_canonical().unwrap(),
+        backward.to_bincode_canonical().unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<SpinLindbladNoiseSystem>(&json).unwrap(),
+        forward
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
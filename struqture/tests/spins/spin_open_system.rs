@@ -218,6 +218,63 @@ fn internal_map_keys() {
     }
 }
 
+// Test the pass-through accessors of the system and noise of the SpinLindbladOpenSystem
+#[test]
+fn internal_map_pass_through() {
+    let pp_0: PauliProduct = PauliProduct::new().x(0);
+    let pp_1: PauliProduct = PauliProduct::new().z(1);
+    let dp_2: DecoherenceProduct = DecoherenceProduct::new().z(2);
+    let mut slos = SpinLindbladOpenSystem::default();
+
+    slos.system_mut()
+        .set(pp_0.clone(), CalculatorFloat::from(0.5))
+        .unwrap();
+    slos.system_mut()
+        .set(pp_1.clone(), CalculatorFloat::from(0.2))
+        .unwrap();
+    slos.noise_mut()
+        .set((dp_2.clone(), dp_2.clone()), CalculatorComplex::from(0.3))
+        .unwrap();
+
+    assert_eq!(slos.system_len(), 2);
+    assert_eq!(slos.noise_len(), 1);
+    assert_eq!(slos.system_get(&pp_0), &CalculatorFloat::from(0.5));
+    assert_eq!(
+        slos.noise_get(&(dp_2.clone(), dp_2.clone())),
+        &CalculatorComplex::from(0.3)
+    );
+    assert_eq!(
+        slos.system_keys().collect::<Vec<_>>(),
+        slos.system().keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        slos.noise_keys().collect::<Vec<_>>(),
+        vec![&(dp_2.clone(), dp_2.clone())]
+    );
+    assert_eq!(
+        slos.system_values().collect::<Vec<_>>(),
+        slos.system().values().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        slos.noise_values().collect::<Vec<_>>(),
+        vec![&CalculatorComplex::from(0.3)]
+    );
+
+    assert_eq!(slos.system_remove(&pp_0), Some(CalculatorFloat::from(0.5)));
+    assert_eq!(slos.system_remove(&pp_0), None);
+    assert_eq!(slos.system_get(&pp_0), &CalculatorFloat::from(0.0));
+    assert_eq!(slos.system_len(), 1);
+    assert_eq!(
+        slos.noise_remove(&(dp_2.clone(), dp_2.clone())),
+        Some(CalculatorComplex::from(0.3))
+    );
+    assert_eq!(slos.noise_len(), 0);
+    assert_eq!(
+        slos.noise_get(&(dp_2.clone(), dp_2)),
+        &CalculatorComplex::from(0.0)
+    );
+}
+
 // Test the noise and system functions of the SpinLindbladOpenSystem
 #[test]
 fn noise_system() {
@@ -635,7 +692,7 @@ fn serde_compact() {
             Token::Str("system"),
             Token::Struct {
                 name: "SpinHamiltonianSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_spins"),
             Token::Some,
@@ -643,7 +700,7 @@ fn serde_compact() {
             Token::Str("hamiltonian"),
             Token::Struct {
                 name: "SpinHamiltonianSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -664,6 +721,11 @@ fn serde_compact() {
             Token::F64(1.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -675,11 +737,14 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::Str("noise"),
             Token::Struct {
                 name: "SpinLindbladNoiseSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_spins"),
             Token::Some,
@@ -687,7 +752,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "SpinLindbladNoiseOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -722,6 +787,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -733,6 +803,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
             Token::StructEnd,
         ],
@@ -1110,6 +1183,429 @@ fn test_truncate() {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the canonical text export of SpinLindbladOpenSystem
+#[test]
+fn canonical_text() {
+    let mut slos = SpinLindbladOpenSystem::new(Some(3));
+    slos.system_mut()
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(-1.0))
+        .unwrap();
+    slos.noise_mut()
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().z(2),
+                DecoherenceProduct::new().z(2),
+            ),
+            CalculatorComplex::from("gamma"),
+        )
+        .unwrap();
+
+    let text = slos.to_canonical_text();
+    assert_eq!(
+        text,
+        "SpinLindbladOpenSystem\nSpinHamiltonianSystem\nnumber_spins\t3\n0Z1Z\t-1.0\nSpinLindbladNoiseSystem\nnumber_spins\t3\n2Z\t2Z\tgamma\t0.0\n"
+    );
+    assert_eq!(
+        SpinLindbladOpenSystem::from_canonical_text(&text).unwrap(),
+        slos
+    );
+
+    let empty = SpinLindbladOpenSystem::new(None);
+    assert_eq!(
+        SpinLindbladOpenSystem::from_canonical_text(&empty.to_canonical_text()).unwrap(),
+        empty
+    );
+}
+
+// Test the truncation of SpinLindbladOpenSystem by cumulative weight
+#[test]
+fn truncate_to_weight() {
+    let mut slos = SpinLindbladOpenSystem::new(Some(2));
+    slos.system_mut()
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    slos.system_mut()
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.01))
+        .unwrap();
+    slos.noise_mut()
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().z(0),
+                DecoherenceProduct::new().z(0),
+            ),
+            CalculatorComplex::from(0.5),
+        )
+        .unwrap();
+
+    let (kept, dropped) = slos.truncate_to_weight(0.9).unwrap();
+    assert_eq!(kept.system().len(), 1);
+    assert_eq!(kept.noise().len(), 1);
+    assert_eq!(dropped.system().len(), 1);
+    assert!(dropped.noise().is_empty());
+    assert_eq!(kept.system().number_spins(), 2);
+    assert_eq!(kept + dropped, Ok(slos));
+}
+
+// Test the truncation of SpinLindbladOpenSystem returning the discarded remainder
+#[test]
+fn truncate_with_remainder() {
+    let mut slos = SpinLindbladOpenSystem::new(Some(2));
+    slos.system_mut()
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    slos.system_mut()
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(0.01))
+        .unwrap();
+    slos.noise_mut()
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().z(0),
+                DecoherenceProduct::new().z(0),
+            ),
+            CalculatorComplex::from(0.05),
+        )
+        .unwrap();
+
+    let (kept, discarded) = slos.truncate_with_remainder(0.1);
+    assert_eq!(kept, slos.truncate(0.1));
+    assert_eq!(discarded.system().len(), 1);
+    assert_eq!(
+        discarded.system().get(&PauliProduct::new().z(1)),
+        &CalculatorFloat::from(0.01)
+    );
+    assert_eq!(discarded.noise().len(), 1);
+    assert_eq!(discarded.system().number_spins(), 2);
+    assert_eq!(kept + discarded, Ok(slos));
+}
+
+// Open system of a single spin with H = Z/2 and decay (rate 1) and excitation (rate `excitation_rate`)
+fn decay_open_system(excitation_rate: f64) -> SpinLindbladOpenSystem {
+    let mut system = SpinLindbladOpenSystem::new(Some(1));
+    system
+        .system_mut()
+        .set(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    let x = DecoherenceProduct::new().x(0);
+    let iy = DecoherenceProduct::new().iy(0);
+    let diagonal = (1.0 + excitation_rate) / 4.0;
+    let off_diagonal = (excitation_rate - 1.0) / 4.0;
+    for (key, value) in [
+        ((x.clone(), x.clone()), diagonal),
+        ((x.clone(), iy.clone()), off_diagonal),
+        ((iy.clone(), x.clone()), off_diagonal),
+        ((iy.clone(), iy.clone()), diagonal),
+    ] {
+        system
+            .noise_mut()
+            .set(key, CalculatorComplex::from(value))
+            .unwrap();
+    }
+    system
+}
+
+#[test]
+fn detailed_balance() {
+    let beta = 0.7;
+    let balanced = decay_open_system((-beta).exp())
+        .check_detailed_balance(beta, 1e-10)
+        .unwrap();
+    assert!(balanced.satisfies_detailed_balance);
+    assert!(balanced.residual < 1e-10);
+    assert!(balanced.violating_terms.is_empty());
+
+    let unbalanced = decay_open_system(0.9)
+        .check_detailed_balance(beta, 1e-10)
+        .unwrap();
+    assert!(!unbalanced.satisfies_detailed_balance);
+    assert!(unbalanced.residual > 1e-3);
+    assert!(!unbalanced.violating_terms.is_empty());
+
+    let mut dephasing = SpinLindbladOpenSystem::new(Some(2));
+    dephasing
+        .system_mut()
+        .set(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    dephasing
+        .noise_mut()
+        .set(
+            (
+                DecoherenceProduct::new().z(1),
+                DecoherenceProduct::new().z(1),
+            ),
+            CalculatorComplex::from(0.3),
+        )
+        .unwrap();
+    assert!(
+        dephasing
+            .check_detailed_balance(2.0, 1e-10)
+            .unwrap()
+            .satisfies_detailed_balance
+    );
+
+    let large = SpinLindbladOpenSystem::new(Some(6));
+    assert!(large.check_detailed_balance(1.0, 1e-10).is_err());
+}
+
+// Converts a COO matrix into a map from (row, column) to the summed entries for order-independent comparison
+fn coo_to_map(matrix: struqture::CooSparseMatrix) -> HashMap<(usize, usize), Complex64> {
+    let (values, (rows, columns)) = matrix;
+    let mut map: HashMap<(usize, usize), Complex64> = HashMap::new();
+    for ((value, row), column) in values.into_iter().zip(rows).zip(columns) {
+        *map.entry((row, column)).or_default() += value;
+    }
+    map.retain(|_, value| value.norm() > 1e-12);
+    map
+}
+
+#[test]
+fn symbolic_sparse_matrix_superoperator() {
+    let build = |omega: CalculatorFloat, gamma: CalculatorFloat| {
+        let mut system = SpinLindbladOpenSystem::new(Some(2));
+        system
+            .system_mut()
+            .set(PauliProduct::new().z(0), omega)
+            .unwrap();
+        system
+            .system_mut()
+            .set(PauliProduct::new().x(0).x(1), CalculatorFloat::from(0.3))
+            .unwrap();
+        system
+            .noise_mut()
+            .set(
+                (
+                    DecoherenceProduct::new().x(1),
+                    DecoherenceProduct::new().iy(1),
+                ),
+                CalculatorComplex::new(gamma.clone(), gamma * 0.5),
+            )
+            .unwrap();
+        system
+            .noise_mut()
+            .set(
+                (
+                    DecoherenceProduct::new().z(0),
+                    DecoherenceProduct::new().z(0),
+                ),
+                CalculatorComplex::from(0.2),
+            )
+            .unwrap();
+        system
+    };
+    let symbolic_system = build("omega".into(), "gamma".into());
+    assert!(symbolic_system
+        .sparse_matrix_superoperator_coo(None)
+        .is_err());
+    let symbolic = symbolic_system
+        .symbolic_sparse_matrix_superoperator(None)
+        .unwrap();
+    assert_eq!(symbolic.expressions.len(), 3);
+    assert!(symbolic.expressions.contains_key("gamma"));
+    assert!(symbolic.expressions.contains_key("omega"));
+
+    for (omega, gamma) in [(0.7, 0.1), (-1.5, 2.0)] {
+        let parameters: HashMap<String, f64> =
+            [("omega".to_string(), omega), ("gamma".to_string(), gamma)]
+                .into_iter()
+                .collect();
+        let numeric = build(omega.into(), gamma.into())
+            .sparse_matrix_superoperator_coo(None)
+            .unwrap();
+        let evaluated = coo_to_map(symbolic.evaluate(&parameters).unwrap());
+        let expected = coo_to_map(numeric);
+        assert_eq!(evaluated.len(), expected.len());
+        for (index, value) in expected {
+            assert!((evaluated[&index] - value).norm() < 1e-12);
+        }
+    }
+
+    let missing: HashMap<String, f64> = [("omega".to_string(), 1.0)].into_iter().collect();
+    assert!(symbolic.evaluate(&missing).is_err());
+}
+
+#[test]
+fn derivative() {
+    let mut system = SpinLindbladOpenSystem::new(Some(1));
+    system
+        .system_mut()
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from("omega"))
+        .unwrap();
+    system
+        .noise_mut()
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().z(0),
+                DecoherenceProduct::new().z(0),
+            ),
+            CalculatorComplex::from("gamma * t"),
+        )
+        .unwrap();
+
+    let derivative = system.derivative("omega").unwrap();
+    assert_eq!(derivative.current_number_spins(), 1);
+    assert_eq!(
+        derivative.system().get(&PauliProduct::new().z(0)),
+        &CalculatorFloat::from(1.0)
+    );
+    assert!(derivative.noise().is_empty());
+
+    let derivative = system.derivative("t").unwrap();
+    assert!(derivative.system().is_empty());
+    assert_eq!(
+        derivative.noise().get(&(
+            DecoherenceProduct::new().z(0),
+            DecoherenceProduct::new().z(0)
+        )),
+        &CalculatorComplex::from("gamma")
+    );
+}
+
+// Test the SystemDimension functions of the SpinLindbladOpenSystem
+#[test]
+fn system_dimension() {
+    let dp_0: DecoherenceProduct = DecoherenceProduct::new().z(0);
+    let mut slos = SpinLindbladOpenSystem::new(Some(2));
+    slos.system_mut()
+        .set(PauliProduct::new().x(0), CalculatorFloat::from(0.4))
+        .unwrap();
+    slos.noise_mut()
+        .set((dp_0.clone(), dp_0), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    assert_eq!(slos.hilbert_space_dimension(&[]).unwrap(), 4);
+    assert_eq!(slos.superoperator_dimension(&[]).unwrap(), 16);
+    // Two entries per row from the commutator and three from the Lindblad term
+    assert_eq!(slos.estimated_matrix_memory(&[], 8).unwrap(), 16 * 5 * 8);
+}
+
+// Test the direct_sum function of the SpinLindbladOpenSystem
+#[test]
+fn direct_sum() {
+    let mut first = SpinLindbladOpenSystem::new(Some(2));
+    first.set_metadata("device", "first");
+    first
+        .system_mut()
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    first
+        .system_mut()
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.5))
+        .unwrap();
+    let dp_z1 = DecoherenceProduct::new().z(1);
+    first
+        .noise_mut()
+        .add_operator_product((dp_z1.clone(), dp_z1.clone()), CalculatorComplex::from(0.1))
+        .unwrap();
+    let mut second = SpinLindbladOpenSystem::new(None);
+    second.set_metadata("device", "second");
+    second.set_metadata("qubits", "transmon");
+    second
+        .system_mut()
+        .add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(2.0))
+        .unwrap();
+    second
+        .system_mut()
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.25))
+        .unwrap();
+    let dp_x0 = DecoherenceProduct::new().x(0);
+    let dp_iy0 = DecoherenceProduct::new().iy(0);
+    second
+        .noise_mut()
+        .add_operator_product((dp_x0, dp_iy0), CalculatorComplex::new(0.0, 0.2))
+        .unwrap();
+
+    let combined = first.direct_sum(&second, 3).unwrap();
+    let mut comparison = SpinLindbladOpenSystem::new(None);
+    comparison.set_metadata("device", "first");
+    comparison.set_metadata("qubits", "transmon");
+    comparison
+        .system_mut()
+        .add_operator_product(PauliProduct::new().z(0).z(1), CalculatorFloat::from(1.0))
+        .unwrap();
+    comparison
+        .system_mut()
+        .add_operator_product(PauliProduct::new().x(3), CalculatorFloat::from(2.0))
+        .unwrap();
+    comparison
+        .system_mut()
+        .add_operator_product(PauliProduct::new(), CalculatorFloat::from(0.75))
+        .unwrap();
+    comparison
+        .noise_mut()
+        .add_operator_product((dp_z1.clone(), dp_z1), CalculatorComplex::from(0.1))
+        .unwrap();
+    comparison
+        .noise_mut()
+        .add_operator_product(
+            (
+                DecoherenceProduct::new().x(3),
+                DecoherenceProduct::new().iy(3),
+            ),
+            CalculatorComplex::new(0.0, 0.2),
+        )
+        .unwrap();
+    assert_eq!(combined, comparison);
+    assert_eq!(combined.metadata(), comparison.metadata());
+    assert_eq!(combined.number_spins(), 4);
+
+    // The number of spins stays fixed if it is fixed for both systems
+    let doubled = first.direct_sum(&first, 2).unwrap();
+    assert_eq!(doubled.system().number_spins(), 4);
+    assert_eq!(doubled.noise().number_spins(), 4);
+    assert_eq!(
+        doubled.system().get(&PauliProduct::new().z(2).z(3)),
+        &CalculatorFloat::from(1.0)
+    );
+    assert_eq!(
+        doubled.system().get(&PauliProduct::new()),
+        &CalculatorFloat::from(1.0)
+    );
+    assert!(doubled
+        .clone()
+        .system_mut()
+        .add_operator_product(PauliProduct::new().z(4), CalculatorFloat::from(1.0))
+        .is_err());
+
+    // Overlapping spins
+    assert!(first.direct_sum(&second, 1).is_err());
+}
+
+// Test the canonical serialization of SpinLindbladOpenSystem with the serde_json serializer
+#[test]
+fn serialize_canonical() {
+    let add_terms = |open_system: &mut SpinLindbladOpenSystem, indices: Vec<usize>| {
+        for index in indices {
+            let dp = DecoherenceProduct::new().x(index);
+            open_system
+                .system_mut()
+                .add_operator_product(PauliProduct::new().z(index), CalculatorFloat::from(1.0))
+                .unwrap();
+            open_system
+                .noise_mut()
+                .add_operator_product((dp.clone(), dp), CalculatorComplex::from(0.5))
+                .unwrap();
+        }
+    };
+    let mut forward = SpinLindbladOpenSystem::new(None);
+    add_terms(&mut forward, (0..20).collect());
+    let mut backward = SpinLindbladOpenSystem::new(None);
+    add_terms(&mut backward, (0..20).rev().collect());
+    assert_eq!(forward, backward);
+
+    let mut forward_json = Vec::new();
+    forward
+        .serialize_canonical(&mut serde_json::Serializer::new(&mut forward_json))
+        .unwrap();
+    let mut backward_json = Vec::new();
+    backward
+        .serialize_canonical(&mut serde_json::Serializer::new(&mut backward_json))
+        .unwrap();
+    assert_eq!(forward_json, backward_json);
+    assert_eq!(
+        serde_json::from_slice::<SpinLindbladOpenSystem>(&forward_json).unwrap(),
+        forward
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
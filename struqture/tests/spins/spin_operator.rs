@@ -15,18 +15,24 @@
 use super::create_na_matrix_from_operator_list;
 use nalgebra as na;
 use num_complex::Complex64;
-use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use qoqo_calculator::{Calculator, CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::iter::{FromIterator, IntoIterator};
 use std::ops::{Add, Sub};
 use std::str::FromStr;
+use struqture::fermions::FermionOperator;
+use struqture::mappings::JordanWignerFermionToSpin;
 use struqture::prelude::*;
 use struqture::spins::{
-    OperateOnSpins, PauliProduct, SpinHamiltonian, SpinOperator, ToSparseMatrixOperator,
+    cumulants_from_moments, OperateOnSpins, PauliProduct, SingleSpinOperator,
+    SpinExpectationSource, SpinHamiltonian, SpinOperator, ToSparseMatrixOperator,
+};
+use struqture::{
+    CooSparseMatrix, MergePolicy, OperateOnDensityMatrix, RoundingDiagnostics, SpinIndex,
+    StruqtureError, TermOrdering, ZeroTermPolicy,
 };
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex};
 use test_case::test_case;
 
 // Test the new function of the SpinOperator
@@ -139,6 +145,62 @@ fn internal_map_add_operator_product() {
     assert_eq!(so.get(&pp_2), &CalculatorComplex::from(0.0));
 }
 
+// Test the add_with_policy function of the SpinOperator
+#[test]
+fn add_with_policy() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(1);
+    let pp_2: PauliProduct = PauliProduct::new().y(2);
+    let mut left = SpinOperator::new();
+    left.add_operator_product(pp_0.clone(), CalculatorComplex::from("a"))
+        .unwrap();
+    left.add_operator_product(pp_1.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut right = SpinOperator::new();
+    right
+        .add_operator_product(pp_0.clone(), CalculatorComplex::from("b"))
+        .unwrap();
+    right
+        .add_operator_product(pp_1.clone(), CalculatorComplex::from(2.0))
+        .unwrap();
+    right
+        .add_operator_product(pp_2.clone(), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    let summed = left.add_with_policy(&right, MergePolicy::Sum).unwrap();
+    assert_eq!(summed, left.clone() + right.clone());
+
+    let preferred = left
+        .add_with_policy(&right, MergePolicy::PreferLeft)
+        .unwrap();
+    assert_eq!(preferred.get(&pp_0), &CalculatorComplex::from("a"));
+    assert_eq!(preferred.get(&pp_1), &CalculatorComplex::from(1.0));
+    assert_eq!(preferred.get(&pp_2), &CalculatorComplex::from(0.5));
+
+    let conflict = left.add_with_policy(&right, MergePolicy::ErrorOnSymbolConflict);
+    assert!(conflict.is_err());
+
+    let mut right_same_symbol = right.clone();
+    right_same_symbol
+        .set(pp_0.clone(), CalculatorComplex::from("a"))
+        .unwrap();
+    let no_conflict = left
+        .add_with_policy(&right_same_symbol, MergePolicy::ErrorOnSymbolConflict)
+        .unwrap();
+    assert_eq!(no_conflict, left + right_same_symbol);
+
+    assert_eq!(MergePolicy::from_str("sum").unwrap(), MergePolicy::Sum);
+    assert_eq!(
+        MergePolicy::from_str("prefer_left").unwrap(),
+        MergePolicy::PreferLeft
+    );
+    assert_eq!(
+        MergePolicy::from_str("error_on_symbol_conflict").unwrap(),
+        MergePolicy::ErrorOnSymbolConflict
+    );
+    assert!(MergePolicy::from_str("left").is_err());
+}
+
 // Test the iter, keys and values functions of the SpinOperator
 #[test]
 fn internal_map_keys() {
@@ -618,7 +680,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -644,6 +706,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -861,6 +928,1070 @@ fn test_spin_operator_pauli_multiplication() {
     assert_eq!(prod_3, spin_op_4 * CalculatorComplex::from((0.0, 1.0)));
 }
 
+// Test the canonical text export of SpinOperator
+#[test]
+fn canonical_text() {
+    let pp_0 = PauliProduct::new().x(0).z(2);
+    let pp_1 = PauliProduct::new().y(1);
+    let mut so_0 = SpinOperator::new();
+    so_0.add_operator_product(pp_0.clone(), CalculatorComplex::new(0.1, -0.0))
+        .unwrap();
+    so_0.add_operator_product(pp_1.clone(), CalculatorComplex::new("theta", 2.0))
+        .unwrap();
+    let mut so_1 = SpinOperator::new();
+    so_1.add_operator_product(pp_1, CalculatorComplex::new("theta", 2.0))
+        .unwrap();
+    so_1.add_operator_product(pp_0, CalculatorComplex::new(0.1, 0.0))
+        .unwrap();
+
+    let text = so_0.to_canonical_text();
+    assert_eq!(text, "SpinOperator\n0X2Z\t0.1\t0.0\n1Y\ttheta\t2.0\n");
+    assert_eq!(so_1.to_canonical_text(), text);
+    assert_eq!(SpinOperator::from_canonical_text(&text).unwrap(), so_0);
+
+    let commented = format!("# model definition\n\n{}", text);
+    assert_eq!(SpinOperator::from_canonical_text(&commented).unwrap(), so_0);
+    assert!(SpinOperator::from_canonical_text("SpinHamiltonian\n0X\t1.0\n").is_err());
+    assert!(SpinOperator::from_canonical_text("SpinOperator\n0X\t1.0\n").is_err());
+    assert!(SpinOperator::from_canonical_text("SpinOperator\n0X0X\t1.0\t0.0\n").is_err());
+}
+
+// Test the expression representation of SpinOperator
+#[test]
+fn expression() {
+    let so = SpinOperator::from_expression("0.5*0X1Z - 1e-2*3Y + 2*theta*0Z + i*(1 - x)*2X - 0X1Z")
+        .unwrap();
+    assert_eq!(so.len(), 4);
+    assert_eq!(
+        so.get(&PauliProduct::new().x(0).z(1)),
+        &CalculatorComplex::from(-0.5)
+    );
+    assert_eq!(
+        so.get(&PauliProduct::new().y(3)),
+        &CalculatorComplex::from(-0.01)
+    );
+    assert_eq!(
+        so.get(&PauliProduct::new().z(0)),
+        &CalculatorComplex::from("2*theta")
+    );
+    assert_eq!(
+        so.get(&PauliProduct::new().x(2)),
+        &CalculatorComplex::new(0.0, "1-x")
+    );
+
+    let expression = so.to_expression();
+    assert_eq!(
+        expression,
+        "-0.5*0X1Z + (2*theta)*0Z + (1-x)*i*2X - 0.01*3Y"
+    );
+    assert_eq!(SpinOperator::from_expression(&expression).unwrap(), so);
+
+    assert_eq!(SpinOperator::new().to_expression(), "0");
+    assert_eq!(
+        SpinOperator::from_expression("0").unwrap(),
+        SpinOperator::new()
+    );
+    assert_eq!(
+        SpinOperator::from_expression("-2*i*i*I").unwrap(),
+        SpinOperator::from_expression("2.0*I").unwrap()
+    );
+    assert!(SpinOperator::from_expression("0.5*").is_err());
+    assert!(SpinOperator::from_expression("0.5*0X +").is_err());
+    assert!(SpinOperator::from_expression("(0.5*0X").is_err());
+    assert!(SpinOperator::from_expression("0.5)*0X").is_err());
+    assert!(SpinOperator::from_expression("0.5*0X0X").is_err());
+}
+
+// Test the coefficient norms and the spectral norm bound of SpinOperator
+#[test]
+fn norms() {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.coefficient_1_norm().unwrap(), 0.0);
+    assert_eq!(so.coefficient_max_norm().unwrap(), 0.0);
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(3.0, 4.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::new(-1.0, 0.0))
+        .unwrap();
+    assert_eq!(so.coefficient_1_norm().unwrap(), 6.0);
+    assert_eq!(so.coefficient_max_norm().unwrap(), 5.0);
+    assert_eq!(so.spectral_norm_bound().unwrap(), 6.0);
+
+    so.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::from("g"))
+        .unwrap();
+    assert!(so.coefficient_1_norm().is_err());
+    assert!(so.coefficient_max_norm().is_err());
+    assert!(so.spectral_norm_bound().is_err());
+}
+
+// Test the truncation of SpinOperator by cumulative weight
+#[test]
+fn truncate_to_weight() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(6.0, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.0, -3.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+
+    let (kept, dropped) = so.truncate_to_weight(0.8).unwrap();
+    let mut expected_kept = SpinOperator::new();
+    expected_kept
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(6.0, 0.0))
+        .unwrap();
+    expected_kept
+        .add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.0, -3.0))
+        .unwrap();
+    assert_eq!(kept, expected_kept);
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(kept + dropped, so);
+
+    let (kept, dropped) = so.truncate_to_weight(0.6).unwrap();
+    assert_eq!(kept.len(), 1);
+    assert_eq!(dropped.len(), 2);
+
+    let (kept, dropped) = so.truncate_to_weight(1.0).unwrap();
+    assert_eq!(kept, so);
+    assert!(dropped.is_empty());
+
+    let (kept, dropped) = so.truncate_to_weight(0.0).unwrap();
+    assert!(kept.is_empty());
+    assert_eq!(dropped, so);
+
+    assert!(so.truncate_to_weight(1.5).is_err());
+    so.add_operator_product(PauliProduct::new().z(3), CalculatorComplex::from("g"))
+        .unwrap();
+    assert!(so.truncate_to_weight(0.5).is_err());
+}
+
+// Test the truncation of SpinOperator returning the discarded remainder
+#[test]
+fn truncate_with_remainder() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 0.001))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.01, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::from("g"))
+        .unwrap();
+
+    let (kept, discarded) = so.truncate_with_remainder(0.1);
+    assert_eq!(kept, so.truncate(0.1));
+    let mut expected_discarded = SpinOperator::new();
+    expected_discarded
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.0, 0.001))
+        .unwrap();
+    expected_discarded
+        .add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(0.01, 0.0))
+        .unwrap();
+    assert_eq!(discarded, expected_discarded);
+    assert_eq!(kept + discarded, so);
+
+    let (kept, discarded) = so.truncate_with_remainder(0.0);
+    assert_eq!(kept, so);
+    assert!(discarded.is_empty());
+}
+
+// Test the sorting of the terms of SpinOperator by index and magnitude
+#[test]
+fn sorted_and_largest_terms() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::new(1.0, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.0, -3.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::new(6.0, 0.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(3), CalculatorComplex::new(-1.0, 0.0))
+        .unwrap();
+
+    let pp_0x = PauliProduct::new().x(0);
+    let pp_1y = PauliProduct::new().y(1);
+    let pp_2z = PauliProduct::new().z(2);
+    let pp_3x = PauliProduct::new().x(3);
+    let keys: Vec<&PauliProduct> = so
+        .sorted_terms(TermOrdering::Index)
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec![&pp_0x, &pp_1y, &pp_2z, &pp_3x]);
+
+    // Terms with equal magnitude are sorted by index
+    let keys: Vec<&PauliProduct> = so
+        .sorted_terms(TermOrdering::Magnitude)
+        .unwrap()
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec![&pp_1y, &pp_0x, &pp_2z, &pp_3x]);
+
+    assert_eq!(
+        so.largest_terms(2).unwrap(),
+        vec![
+            (&pp_1y, &CalculatorComplex::new(6.0, 0.0)),
+            (&pp_0x, &CalculatorComplex::new(0.0, -3.0))
+        ]
+    );
+    assert_eq!(so.largest_terms(10).unwrap().len(), 4);
+    assert!(so.largest_terms(0).unwrap().is_empty());
+
+    so.add_operator_product(PauliProduct::new().z(4), CalculatorComplex::from("g"))
+        .unwrap();
+    assert_eq!(so.sorted_terms(TermOrdering::Index).unwrap().len(), 5);
+    assert!(so.largest_terms(1).is_err());
+}
+
+// Test the approximate comparison of SpinOperators
+#[test]
+fn approx_eq() {
+    let mut so_0 = SpinOperator::new();
+    so_0.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 0.5))
+        .unwrap();
+    so_0.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from("g"))
+        .unwrap();
+    let mut so_1 = SpinOperator::new();
+    so_1.add_operator_product(
+        PauliProduct::new().x(0),
+        CalculatorComplex::new(1.0 + 1e-10, 0.5),
+    )
+    .unwrap();
+    so_1.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from("g"))
+        .unwrap();
+    so_1.add_operator_product(PauliProduct::new().y(2), CalculatorComplex::new(1e-12, 0.0))
+        .unwrap();
+
+    assert_ne!(so_0, so_1);
+    assert!(so_0.approx_eq(&so_1, 1e-8, 0.0));
+    assert!(so_1.approx_eq(&so_0, 1e-8, 0.0));
+    assert!(!so_0.approx_eq(&so_1, 0.0, 1e-8));
+    assert!(!so_0.approx_eq(&so_1, 1e-14, 1e-14));
+
+    let mut so_2 = so_1.clone();
+    so_2.set(PauliProduct::new().z(1), CalculatorComplex::from("h"))
+        .unwrap();
+    assert!(!so_0.approx_eq(&so_2, 1.0, 1.0));
+}
+
+// Test the commutation check of SpinOperator with a SpinHamiltonian
+#[test]
+fn commutes_with() {
+    let mut magnetization = SpinOperator::new();
+    magnetization
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    magnetization
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    // The symbolic XX and YY terms only cancel structurally
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0).x(1), "J".into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().y(0).y(1), "J".into())
+        .unwrap();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(0.5))
+        .unwrap();
+    assert!(magnetization.commutes_with(&hamiltonian));
+    assert_eq!(magnetization.symmetry_violation_norm(&hamiltonian), Ok(0.0));
+
+    // [Z0 + Z1, 0.25 X0] = 0.5i Y0
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(0.25))
+        .unwrap();
+    assert!(!magnetization.commutes_with(&hamiltonian));
+    assert_eq!(magnetization.symmetry_violation_norm(&hamiltonian), Ok(0.5));
+
+    let mut hamiltonian = SpinHamiltonian::new();
+    hamiltonian
+        .add_operator_product(PauliProduct::new().x(1), "h".into())
+        .unwrap();
+    assert!(!magnetization.commutes_with(&hamiltonian));
+    assert!(magnetization.symmetry_violation_norm(&hamiltonian).is_err());
+}
+
+#[test]
+fn locality_statistics() {
+    let mut so = SpinOperator::new();
+    assert_eq!(so.weight_distribution(), BTreeMap::new());
+    assert_eq!(so.max_weight(), 0);
+    assert!(so.support().is_empty());
+
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.2))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(4), CalculatorComplex::from(0.2))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(1).y(2).z(4),
+        CalculatorComplex::from(0.5),
+    )
+    .unwrap();
+    assert_eq!(
+        so.weight_distribution(),
+        BTreeMap::from([(0, 1), (1, 2), (3, 1)])
+    );
+    assert_eq!(so.max_weight(), 3);
+    assert_eq!(
+        so.support().into_iter().collect::<Vec<usize>>(),
+        vec![0, 1, 2, 4]
+    );
+}
+
+#[cfg(feature = "npz")]
+#[test]
+fn npz_roundtrip() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::new(0.5, 0.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(0).y(1).z(2),
+        CalculatorComplex::new(1.0, -2.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(63).x(64).z(130),
+        CalculatorComplex::new(-0.25, 0.75),
+    )
+    .unwrap();
+
+    let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
+    so.to_npz(&mut buffer).unwrap();
+    buffer.set_position(0);
+    assert_eq!(SpinOperator::from_npz(&mut buffer).unwrap(), so);
+
+    let mut symbolic = SpinOperator::new();
+    symbolic
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    let mut buffer = std::io::Cursor::new(Vec::<u8>::new());
+    assert!(symbolic.to_npz(&mut buffer).is_err());
+
+    let mut empty = std::io::Cursor::new(Vec::<u8>::new());
+    assert!(SpinOperator::from_npz(&mut empty).is_err());
+}
+
+#[test]
+fn powi() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+
+    let mut identity = SpinOperator::new();
+    identity
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(so.powi(0), identity);
+    assert_eq!(so.powi(1), so);
+    // (X + Z)^2 = 2, the anticommuting cross terms cancel and are removed
+    assert_eq!(so.powi(2), identity * CalculatorComplex::from(2.0));
+    assert_eq!(so.powi(5), so.clone() * CalculatorComplex::from(4.0));
+}
+
+#[test]
+fn moments_and_cumulants() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    let state = [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)];
+    let from_state = SpinExpectationSource::StateVector(&state);
+    // Expectation values of PauliProducts in the state |0>
+    let callback = |product: &PauliProduct| {
+        Ok(
+            if product
+                .iter()
+                .all(|(_, pauli)| *pauli == SingleSpinOperator::Z)
+            {
+                Complex64::new(1.0, 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            },
+        )
+    };
+    let from_callback = SpinExpectationSource::Callback(&callback);
+
+    let expected_moments = vec![
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.0, 0.0),
+        Complex64::new(1.25, 0.0),
+        Complex64::new(1.25, 0.0),
+    ];
+    assert_eq!(so.moments(3, &from_state).unwrap(), expected_moments);
+    assert_eq!(so.moments(3, &from_callback).unwrap(), expected_moments);
+    assert_eq!(
+        so.cumulants(3, &from_state).unwrap(),
+        vec![
+            Complex64::new(1.0, 0.0),
+            Complex64::new(0.25, 0.0),
+            Complex64::new(-0.5, 0.0)
+        ]
+    );
+
+    let mut y = SpinOperator::new();
+    y.add_operator_product(PauliProduct::new().y(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    let plus_i = [
+        Complex64::new(0.5_f64.sqrt(), 0.0),
+        Complex64::new(0.0, 0.0),
+        Complex64::new(0.0, 0.5_f64.sqrt()),
+        Complex64::new(0.0, 0.0),
+    ];
+    let first = y
+        .moments(1, &SpinExpectationSource::StateVector(&plus_i))
+        .unwrap()[1];
+    assert!((first - Complex64::new(1.0, 0.0)).norm() < 1e-12);
+    assert!(y
+        .moments(1, &SpinExpectationSource::StateVector(&state))
+        .is_err());
+    assert!(cumulants_from_moments(&[Complex64::new(0.0, 0.0)]).is_err());
+}
+
+// Test the variance operator and the variance estimated from states and samples
+#[test]
+fn variance() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    let state = [Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)];
+    let from_state = SpinExpectationSource::StateVector(&state);
+    let variance = so.variance_operator(&from_state).unwrap();
+    assert_eq!(variance.len(), 1);
+    assert_eq!(
+        variance.get(&PauliProduct::new()),
+        &CalculatorComplex::from(1.0)
+    );
+    assert_eq!(so.estimate_variance(&from_state).unwrap(), 1.0);
+
+    // The non-hermitian X + iY annihilates |0> and has no fluctuations
+    let mut lowering = SpinOperator::new();
+    lowering
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    lowering
+        .add_operator_product(PauliProduct::new().y(0), CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    assert!(lowering.estimate_variance(&from_state).unwrap().abs() < 1e-12);
+
+    let mut zz = SpinOperator::new();
+    zz.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    zz.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1.0))
+        .unwrap();
+    let samples = [(0b00, 3), (0b11, 1)];
+    let from_samples = SpinExpectationSource::Samples(&samples, &[]);
+    assert_eq!(
+        from_samples.expectation_value(&zz).unwrap(),
+        Complex64::new(1.0, 0.0)
+    );
+    assert_eq!(zz.estimate_variance(&from_samples).unwrap(), 3.0);
+    assert!(so.estimate_variance(&from_samples).is_err());
+}
+
+#[test]
+fn derivative() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(
+        PauliProduct::new().z(0),
+        CalculatorComplex::new("theta^2", "sin(theta)"),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(0),
+        CalculatorComplex::from("2.0 * phi"),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().x(1), CalculatorComplex::from(1.5))
+        .unwrap();
+
+    let derivative = so.derivative("theta").unwrap();
+    assert_eq!(derivative.len(), 1);
+    let value = derivative.get(&PauliProduct::new().z(0));
+    let mut calculator = Calculator::new();
+    calculator.set_variable("theta", 0.3);
+    let re = calculator.parse_get(&value.re.to_string()).unwrap();
+    let im = calculator.parse_get(&value.im.to_string()).unwrap();
+    assert!((re - 0.6).abs() < 1e-12);
+    assert!((im - 0.3_f64.cos()).abs() < 1e-12);
+
+    let derivative = so.derivative("phi").unwrap();
+    assert_eq!(derivative.len(), 1);
+    assert_eq!(
+        derivative.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::from(2.0)
+    );
+    assert!(so.derivative("chi").unwrap().is_empty());
+
+    let mut unsupported = SpinOperator::new();
+    unsupported
+        .add_operator_product(
+            PauliProduct::new().z(0),
+            CalculatorComplex::from("floor(theta)"),
+        )
+        .unwrap();
+    assert!(unsupported.derivative("theta").is_err());
+    assert!(unsupported.derivative("phi").unwrap().is_empty());
+}
+
+#[test]
+fn hermitian_split() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(3.0))
+        .unwrap();
+    assert!(!so.is_hermitian());
+    assert!(SpinHamiltonian::try_from(so.clone()).is_err());
+
+    let hermitian = so.hermitian_part();
+    let mut expected_hermitian = SpinOperator::new();
+    expected_hermitian
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    expected_hermitian
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(3.0))
+        .unwrap();
+    assert_eq!(hermitian, expected_hermitian);
+    assert!(hermitian.is_hermitian());
+    assert!(SpinHamiltonian::try_from(hermitian.clone()).is_ok());
+
+    let anti_hermitian = so.anti_hermitian_part();
+    let mut expected_anti_hermitian = SpinOperator::new();
+    expected_anti_hermitian
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(0.0, 2.0))
+        .unwrap();
+    assert_eq!(anti_hermitian, expected_anti_hermitian);
+    assert!(!anti_hermitian.is_hermitian());
+    assert_eq!(hermitian + anti_hermitian, so);
+
+    assert!(SpinOperator::new().is_hermitian());
+    assert!(SpinOperator::new().anti_hermitian_part().is_empty());
+}
+
+// Test the Jordan-Wigner transformed generators of single and double excitations
+#[test]
+fn singles_doubles_excitations() {
+    let fermionic = FermionOperator::singles_doubles_excitations(&[0, 1], &[2, 3]).unwrap();
+    let spin = SpinOperator::singles_doubles_excitations(&[0, 1], &[2, 3]).unwrap();
+    assert_eq!(spin.len(), 5);
+    for ((amplitude, generator), (fermionic_amplitude, fermionic_generator)) in
+        spin.iter().zip(fermionic.iter())
+    {
+        assert_eq!(amplitude, fermionic_amplitude);
+        assert_eq!(generator, &fermionic_generator.jordan_wigner());
+        assert!(!generator.is_empty());
+        assert_eq!(&generator.anti_hermitian_part(), generator);
+    }
+    assert!(SpinOperator::singles_doubles_excitations(&[0], &[0]).is_err());
+}
+
+#[test]
+fn try_into_hamiltonian() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(3.0))
+        .unwrap();
+    assert_eq!(
+        so.try_into_hamiltonian(),
+        Err(StruqtureError::NonHermitianTerms {
+            terms: vec![(
+                PauliProduct::new().x(0).to_string(),
+                CalculatorComplex::new(0.0, 4.0).to_string()
+            )]
+        })
+    );
+
+    let hermitian = so.hermitian_part();
+    let hamiltonian = hermitian.try_into_hamiltonian().unwrap();
+    let mut expected = SpinHamiltonian::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0), CalculatorFloat::from(1.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().z(1), CalculatorFloat::from(3.0))
+        .unwrap();
+    assert_eq!(hamiltonian, expected);
+    assert_eq!(hamiltonian.to_operator(), hermitian);
+}
+
+#[test]
+fn filter_terms_and_retain() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0).z(3), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().y(3), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(0).x(1), CalculatorComplex::from(3.0))
+        .unwrap();
+
+    let acting_on_3 = so.filter_terms(|product, _| product.get(&3).is_some());
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0).z(3), CalculatorComplex::from(1.0))
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(3), CalculatorComplex::from(2.0))
+        .unwrap();
+    assert_eq!(acting_on_3, expected);
+
+    let two_body = so.filter_terms(|product, _| product.len() == 2);
+    assert_eq!(two_body.len(), 2);
+    assert_eq!(
+        two_body.get(&PauliProduct::new().y(3)),
+        &CalculatorComplex::ZERO
+    );
+
+    let mut retained = so.clone();
+    retained.retain(|_, value| *value.re.float().unwrap() > 1.5);
+    assert_eq!(retained.len(), 2);
+    assert_eq!(
+        retained.get(&PauliProduct::new().x(0).x(1)),
+        &CalculatorComplex::from(3.0)
+    );
+    retained.retain(|_, _| false);
+    assert!(retained.is_empty());
+}
+
+#[test]
+fn map_values() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::new(1.0, 1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(1e-9))
+        .unwrap();
+
+    let rotated = so
+        .map_values(|_, value| value.clone() * CalculatorComplex::new(0.0, 1.0))
+        .unwrap();
+    assert_eq!(
+        rotated.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::new(-1.0, 1.0)
+    );
+    assert_eq!(
+        rotated.get(&PauliProduct::new().z(1)),
+        &CalculatorComplex::new(0.0, 1e-9)
+    );
+
+    let cut = so
+        .map_values(|_, value| {
+            if *value.re.float().unwrap() < 1e-6 {
+                CalculatorComplex::ZERO
+            } else {
+                value.clone()
+            }
+        })
+        .unwrap();
+    assert_eq!(cut.len(), 1);
+
+    let mut sh = SpinHamiltonian::new();
+    sh.add_operator_product(PauliProduct::new().z(0), CalculatorFloat::from(2.0))
+        .unwrap();
+    let scaled = sh
+        .map_values(|product, value| value.clone() * product.len() as f64 * 0.5)
+        .unwrap();
+    assert_eq!(
+        scaled.get(&PauliProduct::new().z(0)),
+        &CalculatorFloat::from(1.0)
+    );
+}
+
+#[test]
+fn entry() {
+    let mut so = SpinOperator::new();
+    for _ in 0..3 {
+        *so.entry(PauliProduct::new().x(0)) += CalculatorComplex::new(0.5, 1.0);
+    }
+    assert_eq!(
+        so.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::new(1.5, 3.0)
+    );
+    assert_eq!(so.len(), 1);
+
+    // Reading a missing key does not insert it
+    assert_eq!(*so.entry(PauliProduct::new().z(1)), CalculatorComplex::ZERO);
+    assert_eq!(so.len(), 1);
+
+    *so.entry(PauliProduct::new().x(0)) = CalculatorComplex::ZERO;
+    assert!(so.is_empty());
+
+    let mut sh = SpinHamiltonian::new();
+    *sh.entry(PauliProduct::new().z(0)) += CalculatorFloat::from(2.0);
+    *sh.entry(PauliProduct::new().z(0)) *= CalculatorFloat::from(0.5);
+    assert_eq!(
+        sh.get(&PauliProduct::new().z(0)),
+        &CalculatorFloat::from(1.0)
+    );
+}
+
+// Test the conversion of the SpinOperator to and from flat arrays
+#[test]
+fn flat_arrays() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(1).z(70),
+        CalculatorComplex::new(0.5, -0.25),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let ((x_masks, z_masks), real, imag) = so.to_flat_arrays().unwrap();
+    assert_eq!(x_masks, vec![0, 0, 1, 0, 2, 0]);
+    assert_eq!(z_masks, vec![0, 0, 0, 0, 2, 1 << 6]);
+    assert_eq!(real, vec![2.0, 1.0, 0.5]);
+    assert_eq!(imag, vec![0.0, 0.0, -0.25]);
+    assert_eq!(
+        SpinOperator::from_flat_arrays(&x_masks, &z_masks, &real, &imag).unwrap(),
+        so
+    );
+
+    assert!(SpinOperator::from_flat_arrays(&x_masks, &z_masks[1..], &real, &imag).is_err());
+    assert!(SpinOperator::from_flat_arrays(&x_masks[1..], &z_masks[1..], &real, &imag).is_err());
+    assert!(SpinOperator::from_flat_arrays(&x_masks, &z_masks, &real, &imag[1..]).is_err());
+    assert_eq!(
+        SpinOperator::from_flat_arrays(&[], &[], &[], &[]).unwrap(),
+        SpinOperator::new()
+    );
+
+    let (((x_masks_f32, z_masks_f32), real_f32, imag_f32), diagnostics) =
+        so.to_flat_arrays_f32().unwrap();
+    assert_eq!(x_masks_f32, x_masks);
+    assert_eq!(z_masks_f32, z_masks);
+    assert_eq!(real_f32, vec![2.0_f32, 1.0, 0.5]);
+    assert_eq!(imag_f32, vec![0.0_f32, 0.0, -0.25]);
+    assert_eq!(diagnostics, RoundingDiagnostics::default());
+
+    so.add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from("theta"))
+        .unwrap();
+    assert!(so.to_flat_arrays().is_err());
+    assert!(so.to_flat_arrays_f32().is_err());
+}
+
+// Test the canonical serialization of SpinOperator
+#[cfg(feature = "io")]
+#[test]
+fn serialize_canonical() {
+    let products: Vec<PauliProduct> = (0..20)
+        .map(|index| PauliProduct::new().z(index).x(index + 1))
+        .collect();
+    let mut forward = SpinOperator::new();
+    for (index, pp) in products.iter().enumerate() {
+        forward
+            .add_operator_product(pp.clone(), CalculatorComplex::new(index as f64, 1.0))
+            .unwrap();
+    }
+    let mut backward = SpinOperator::with_capacity(3);
+    for (index, pp) in products.iter().enumerate().rev() {
+        backward
+            .add_operator_product(pp.clone(), CalculatorComplex::new(index as f64, 1.0))
+            .unwrap();
+    }
+
+    let json = forward.to_json_canonical().unwrap();
+    assert_eq!(json, backward.to_json_canonical().unwrap());
+    assert_eq!(
+        forward.to_bincode_canonical().unwrap(),
+        backward.to_bincode_canonical().unwrap()
+    );
+    assert_eq!(
+        serde_json::from_str::<SpinOperator>(&json).unwrap(),
+        forward
+    );
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let keys: Vec<PauliProduct> = value["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| serde_json::from_value(item[0].clone()).unwrap())
+        .collect();
+    let mut sorted_keys = keys.clone();
+    sorted_keys.sort();
+    assert_eq!(keys, sorted_keys);
+}
+
+// Test the partial_trace function of the SpinOperator
+#[test]
+fn partial_trace() {
+    let mut so = SpinOperator::new();
+    so.add_operator_product(PauliProduct::new(), CalculatorComplex::from(0.5))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().z(0).x(2), CalculatorComplex::from(1.0))
+        .unwrap();
+    so.add_operator_product(PauliProduct::new().x(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().y(0).z(1).y(3),
+        CalculatorComplex::new(1.0, 1.0),
+    )
+    .unwrap();
+    so.add_operator_product(
+        PauliProduct::new().x(0).z(3),
+        CalculatorComplex::new(0.0, 3.0),
+    )
+    .unwrap();
+    so.add_operator_product(PauliProduct::new().z(2), CalculatorComplex::from("a"))
+        .unwrap();
+
+    let mut comparison = SpinOperator::new();
+    comparison
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    comparison
+        .add_operator_product(PauliProduct::new().z(0).x(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    comparison
+        .add_operator_product(
+            PauliProduct::new().x(0).z(2),
+            CalculatorComplex::new(0.0, 6.0),
+        )
+        .unwrap();
+    comparison
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from("a") * 2.0)
+        .unwrap();
+    assert_eq!(so.partial_trace(&[1, 1]), comparison);
+
+    // Tracing out all spins gives the trace of the SpinOperator
+    let mut trace = SpinOperator::new();
+    trace
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(8.0))
+        .unwrap();
+    assert_eq!(so.partial_trace(&[3, 2, 1, 0]), trace);
+
+    // Tracing out no spins does not change the SpinOperator
+    assert_eq!(so.partial_trace(&[]), so);
+}
+
+// Test the zero-term policies passed to set, add_operator_product and entry of the SpinOperator
+#[test]
+fn zero_term_policy() {
+    for policy in [
+        ZeroTermPolicy::DropExactZeros,
+        ZeroTermPolicy::DropBelowThreshold(1e-10),
+    ] {
+        assert_eq!(
+            ZeroTermPolicy::from_str(&policy.to_string()).unwrap(),
+            policy
+        );
+        let serialized = serde_json::to_string(&policy).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ZeroTermPolicy>(&serialized).unwrap(),
+            policy
+        );
+    }
+    assert_eq!(ZeroTermPolicy::default(), ZeroTermPolicy::DropExactZeros);
+    assert!(ZeroTermPolicy::from_str("drop_everything").is_err());
+    assert!(ZeroTermPolicy::from_str("drop_below_threshold:x").is_err());
+
+    let pp = PauliProduct::new().z(0);
+    let pp_1 = PauliProduct::new().x(1);
+    let threshold = ZeroTermPolicy::DropBelowThreshold(1e-12);
+
+    // set only drops exact zeros
+    let mut so = SpinOperator::new();
+    so.set(pp.clone(), CalculatorComplex::from(1e-20)).unwrap();
+    so.set(pp_1.clone(), CalculatorComplex::ZERO).unwrap();
+    assert_eq!(so.len(), 1);
+
+    // Near-zero values are dropped with a threshold, symbolic values are kept
+    assert_eq!(
+        so.set_with_zero_term_policy(pp.clone(), CalculatorComplex::new(1e-13, 1e-13), threshold)
+            .unwrap(),
+        Some(CalculatorComplex::from(1e-20))
+    );
+    assert!(so.is_empty());
+    so.set_with_zero_term_policy(pp_1.clone(), "theta".into(), threshold)
+        .unwrap();
+    assert_eq!(so.len(), 1);
+
+    // Rounding residues of cancelling terms are dropped with a threshold
+    let mut first = SpinOperator::new();
+    first.set(pp.clone(), CalculatorComplex::from(0.3)).unwrap();
+    let mut exact = first.clone();
+    exact
+        .add_operator_product_with_zero_term_policy(
+            pp.clone(),
+            CalculatorComplex::from(-0.1 - 0.2),
+            ZeroTermPolicy::default(),
+        )
+        .unwrap();
+    assert_eq!(exact.len(), 1);
+    first
+        .add_operator_product_with_zero_term_policy(
+            pp.clone(),
+            CalculatorComplex::from(-0.1 - 0.2),
+            threshold,
+        )
+        .unwrap();
+    assert!(first.is_empty());
+
+    let mut entry_so = SpinOperator::new();
+    *entry_so.entry(pp.clone()).with_zero_term_policy(threshold) += CalculatorComplex::from(1e-13);
+    assert!(entry_so.is_empty());
+
+    // Invalid thresholds are rejected
+    assert!(matches!(
+        so.set_with_zero_term_policy(
+            pp.clone(),
+            CalculatorComplex::from(1.0),
+            ZeroTermPolicy::DropBelowThreshold(-1.0)
+        ),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(so
+        .add_operator_product_with_zero_term_policy(
+            pp,
+            CalculatorComplex::from(1.0),
+            ZeroTermPolicy::DropBelowThreshold(f64::NAN)
+        )
+        .is_err());
+    assert_eq!(so.len(), 1);
+
+    // Hamiltonians drop near-zero values the same way
+    let mut sh = SpinHamiltonian::new();
+    sh.set_with_zero_term_policy(pp_1, CalculatorFloat::from(1e-13), threshold)
+        .unwrap();
+    assert!(sh.is_empty());
+}
+
+// Test the zero-term policy stored in the SpinOperator
+#[test]
+fn stored_zero_term_policy() {
+    let pp = PauliProduct::new().z(0);
+    let pp_1 = PauliProduct::new().x(1);
+    let threshold = ZeroTermPolicy::DropBelowThreshold(1e-12);
+
+    let mut so = SpinOperator::new();
+    assert_eq!(so.zero_term_policy(), ZeroTermPolicy::DropExactZeros);
+    so.set(pp.clone(), CalculatorComplex::from(1e-20)).unwrap();
+    so.set(pp_1.clone(), "theta".into()).unwrap();
+    assert_eq!(so.len(), 2);
+
+    // Setting the policy removes the near-zero terms, symbolic values are kept
+    so.set_zero_term_policy(threshold).unwrap();
+    assert_eq!(so.zero_term_policy(), threshold);
+    assert_eq!(so.len(), 1);
+    assert_eq!(so.get(&pp_1), &CalculatorComplex::from("theta"));
+    assert!(matches!(
+        so.set_zero_term_policy(ZeroTermPolicy::DropBelowThreshold(-1.0)),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert_eq!(so.zero_term_policy(), threshold);
+
+    // set, add_operator_product and entry drop near-zero values under the stored policy
+    so.set(pp.clone(), CalculatorComplex::new(1e-13, 1e-13))
+        .unwrap();
+    assert_eq!(so.len(), 1);
+    so.set(pp.clone(), CalculatorComplex::from(0.3)).unwrap();
+    so.add_operator_product(pp.clone(), CalculatorComplex::from(-0.1 - 0.2))
+        .unwrap();
+    assert_eq!(so.len(), 1);
+    *so.entry(pp.clone()) += CalculatorComplex::from(1e-13);
+    assert_eq!(so.len(), 1);
+
+    // Add and Sub keep the policy of the left operand and drop rounding residues
+    let mut left = SpinOperator::new();
+    left.set_zero_term_policy(threshold).unwrap();
+    left.set(pp.clone(), CalculatorComplex::from(0.3)).unwrap();
+    let mut right = SpinOperator::new();
+    right.set(pp.clone(), CalculatorComplex::from(0.1)).unwrap();
+    right
+        .set(pp_1.clone(), CalculatorComplex::from(0.2))
+        .unwrap();
+    let difference = left.clone() - right.clone() - right.clone() * CalculatorFloat::from(2.0);
+    assert_eq!(difference.zero_term_policy(), threshold);
+    assert_eq!(difference.get(&pp), &CalculatorComplex::ZERO);
+    assert_eq!(difference.len(), 1);
+    assert_eq!(
+        (right.clone() + left.clone()).zero_term_policy(),
+        ZeroTermPolicy::DropExactZeros
+    );
+
+    // Products and multiplication by scalars drop near-zero terms
+    let mut small = SpinOperator::new();
+    small
+        .set(pp.clone(), CalculatorComplex::from(1e-7))
+        .unwrap();
+    small.set_zero_term_policy(threshold).unwrap();
+    assert!((small.clone() * small.clone()).is_empty());
+    assert!((small.clone() * CalculatorFloat::from(1e-6)).is_empty());
+    assert!((small.clone() * PauliProduct::new().x(0) * CalculatorFloat::from(1e-6)).is_empty());
+    assert_eq!(
+        (PauliProduct::new().x(0) * small.clone()).zero_term_policy(),
+        threshold
+    );
+    assert_eq!((-small.clone()).zero_term_policy(), threshold);
+    assert_eq!(small.empty_clone(None).zero_term_policy(), threshold);
+
+    // Conversions from Hamiltonians keep the policy
+    let mut sh = SpinHamiltonian::new();
+    sh.set_zero_term_policy(threshold).unwrap();
+    assert_eq!(SpinOperator::from(sh).zero_term_policy(), threshold);
+
+    // The policy is compared and serialized, the default policy is left out of JSON
+    let mut exact = small.clone();
+    exact
+        .set_zero_term_policy(ZeroTermPolicy::DropExactZeros)
+        .unwrap();
+    assert_ne!(exact, small);
+    assert!(!serde_json::to_string(&exact)
+        .unwrap()
+        .contains("zero_term_policy"));
+    for operator in [exact, small] {
+        let serialized = serde_json::to_string(&operator).unwrap();
+        let deserialized: SpinOperator = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, operator);
+        let encoded: Vec<u8> = bincode::serialize(&operator).unwrap();
+        let decoded: SpinOperator = bincode::deserialize(&encoded[..]).unwrap();
+        assert_eq!(decoded, operator);
+    }
+}
+
+// Test the set_zero_threshold function of the SpinOperator
+#[test]
+fn set_zero_threshold() {
+    let pp = PauliProduct::new().z(0);
+    let mut so = SpinOperator::new();
+    so.set(pp.clone(), CalculatorComplex::from(1e-10)).unwrap();
+    assert_eq!(so.len(), 1);
+
+    so.set_zero_threshold(1e-8).unwrap();
+    assert_eq!(
+        so.zero_term_policy(),
+        ZeroTermPolicy::DropBelowThreshold(1e-8)
+    );
+    assert!(so.is_empty());
+    so.add_operator_product(pp.clone(), CalculatorComplex::from(1e-9))
+        .unwrap();
+    assert!(so.is_empty());
+    so.add_operator_product(pp.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert!((so.clone() * CalculatorFloat::from(1e-9)).is_empty());
+    assert!((so.clone() - so.clone()).is_empty());
+
+    assert!(matches!(
+        so.set_zero_threshold(-1.0),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(so.set_zero_threshold(f64::NAN).is_err());
+    assert_eq!(
+        so.zero_term_policy(),
+        ZeroTermPolicy::DropBelowThreshold(1e-8)
+    );
+}
+
 #[cfg(feature = "json_schema")]
 #[test]
 fn test_spin_operator_schema() {
@@ -0,0 +1,248 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of SpinSProduct, SpinSOperator and SpinSHamiltonian
+
+use num_complex::Complex64;
+use qoqo_calculator::CalculatorComplex;
+use std::str::FromStr;
+use struqture::prelude::*;
+use struqture::spins::{
+    PauliProduct, SingleSpinSOperator, SpinHamiltonian, SpinOperator, SpinSHamiltonian,
+    SpinSOperator, SpinSProduct,
+};
+use struqture::{OperateOnDensityMatrix, StruqtureError};
+
+// Test the string representation of SpinSProducts
+#[test]
+fn product_from_string() {
+    let sp = SpinSProduct::new().z(0).z(0).plus(1);
+    assert_eq!(format!("{}", sp), "0Z0Z1+");
+    assert_eq!(SpinSProduct::from_str("0Z0Z1+").unwrap(), sp);
+    assert_eq!(SpinSProduct::from_str("I").unwrap(), SpinSProduct::new());
+    assert_eq!(format!("{}", SpinSProduct::new()), "I");
+    assert_eq!(
+        SpinSProduct::from_str("1-0X").unwrap(),
+        SpinSProduct::new().x(0).minus(1)
+    );
+    assert!(SpinSProduct::from_str("Z0").is_err());
+    assert!(SpinSProduct::from_str("0Z1").is_err());
+    assert!(SpinSProduct::from_str("0A").is_err());
+}
+
+// Test the order of operators acting on the same spin and the hermitian conjugate
+#[test]
+fn product_hermitian_conjugate() {
+    let sp = SpinSProduct::new().plus(0).z(0).minus(2);
+    assert_eq!(
+        sp.operators_on_spin(0),
+        vec![SingleSpinSOperator::Plus, SingleSpinSOperator::Z]
+    );
+    let (conjugate, prefactor) = sp.hermitian_conjugate();
+    assert_eq!(conjugate, SpinSProduct::new().z(0).minus(0).plus(2));
+    assert_eq!(prefactor, 1.0);
+    assert!(!sp.is_natural_hermitian());
+    assert!(SpinSProduct::new().z(0).z(0).x(1).is_natural_hermitian());
+    assert_eq!(
+        SpinSProduct::multiply(SpinSProduct::new().plus(0), SpinSProduct::new().minus(0)),
+        SpinSProduct::new().plus(0).minus(0)
+    );
+}
+
+// Test the sparse matrices of single spin-1 operators
+#[test]
+fn spin_one_matrices() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(SpinSProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let (values, (rows, columns)) = so.sparse_matrix_coo(3, None).unwrap();
+    assert_eq!(
+        values,
+        vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)]
+    );
+    assert_eq!(rows, vec![0, 2]);
+    assert_eq!(columns, vec![0, 2]);
+
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(SpinSProduct::new().plus(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let (values, (rows, columns)) = so.sparse_matrix_coo(3, None).unwrap();
+    let sqrt_two = Complex64::new(2.0_f64.sqrt(), 0.0);
+    assert_eq!(rows, vec![0, 1]);
+    assert_eq!(columns, vec![1, 2]);
+    for value in values {
+        assert!((value - sqrt_two).norm() < 1e-12);
+    }
+
+    // S^2 = S(S+1) = 2 for spin-1
+    let mut so = SpinSOperator::new();
+    for product in [
+        SpinSProduct::new().x(0).x(0),
+        SpinSProduct::new().y(0).y(0),
+        SpinSProduct::new().z(0).z(0),
+    ] {
+        so.add_operator_product(product, CalculatorComplex::from(1.0))
+            .unwrap();
+    }
+    let (values, (rows, columns)) = so.sparse_matrix_coo(3, None).unwrap();
+    for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+        if row == column {
+            assert!((value - Complex64::new(2.0, 0.0)).norm() < 1e-12);
+        } else {
+            assert!(value.norm() < 1e-12);
+        }
+    }
+}
+
+// Test that the CSR and COO representations agree
+#[test]
+fn sparse_matrix_csr_coo() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(
+        SpinSProduct::new().plus(0).minus(1),
+        CalculatorComplex::new(1.0, 0.5),
+    )
+    .unwrap();
+    so.add_operator_product(SpinSProduct::new().z(1), CalculatorComplex::from(2.0))
+        .unwrap();
+    let (coo_values, (coo_rows, coo_columns)) = so.sparse_matrix_coo(3, Some(3)).unwrap();
+    let (csr_values, csr_columns, row_pointers) = so.sparse_matrix_csr(3, Some(3)).unwrap();
+    assert_eq!(row_pointers.len(), 28);
+    assert_eq!(coo_values, csr_values);
+    assert_eq!(coo_columns, csr_columns);
+    for (row, window) in row_pointers.windows(2).enumerate() {
+        for coo_row in coo_rows[window[0]..window[1]].iter() {
+            assert_eq!(coo_row, &row);
+        }
+    }
+
+    assert_eq!(
+        so.sparse_matrix_coo(3, Some(1)),
+        Err(StruqtureError::NumberSpinsExceeded)
+    );
+    assert!(so.sparse_matrix_coo(1, None).is_err());
+}
+
+// Test the conversion of spin-1/2 operators to SpinOperator
+#[test]
+fn spin_half_conversion() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(
+        SpinSProduct::new().plus(0).z(1),
+        CalculatorComplex::from(2.0),
+    )
+    .unwrap();
+    so.add_operator_product(SpinSProduct::new().x(0).x(0), CalculatorComplex::from(4.0))
+        .unwrap();
+
+    let mut expected = SpinOperator::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0).z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+    expected
+        .add_operator_product(
+            PauliProduct::new().y(0).z(1),
+            CalculatorComplex::new(0.0, 0.5),
+        )
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new(), CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(so.to_spin_operator(), expected);
+
+    let mut sh = SpinSHamiltonian::new();
+    sh.add_operator_product(
+        SpinSProduct::new().plus(0).minus(1),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+    let mut expected = SpinHamiltonian::new();
+    expected
+        .add_operator_product(PauliProduct::new().x(0).x(1), 0.5.into())
+        .unwrap();
+    expected
+        .add_operator_product(PauliProduct::new().y(0).y(1), 0.5.into())
+        .unwrap();
+    assert_eq!(sh.to_spin_hamiltonian(), expected);
+}
+
+// Test the canonical form and hermiticity of the SpinSHamiltonian
+#[test]
+fn hamiltonian_canonical() {
+    let sp_canonical = SpinSProduct::new().plus(0).minus(1);
+    let sp_conjugate = SpinSProduct::new().minus(0).plus(1);
+    let mut sh = SpinSHamiltonian::new();
+    sh.set(sp_conjugate.clone(), CalculatorComplex::new(1.0, 2.0))
+        .unwrap();
+    assert_eq!(sh.get(&sp_canonical), &CalculatorComplex::new(1.0, -2.0));
+    assert_eq!(sh.get(&sp_conjugate), &CalculatorComplex::ZERO);
+
+    assert_eq!(
+        sh.add_operator_product(
+            SpinSProduct::new().z(0).z(0),
+            CalculatorComplex::new(0.0, 1.0)
+        ),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+
+    let so = SpinSOperator::from(sh.clone());
+    assert_eq!(so.len(), 2);
+    assert_eq!(so.get(&sp_conjugate), &CalculatorComplex::new(1.0, 2.0));
+    assert_eq!(SpinSHamiltonian::try_from(so), Ok(sh.clone()));
+
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(sp_canonical, CalculatorComplex::from(1.0))
+        .unwrap();
+    assert_eq!(
+        SpinSHamiltonian::try_from(so),
+        Err(StruqtureError::NonHermitianOperator)
+    );
+
+    let (values, (rows, columns)) = sh.sparse_matrix_coo(3, None).unwrap();
+    for ((value, row), column) in values.iter().zip(rows.iter()).zip(columns.iter()) {
+        let index = rows
+            .iter()
+            .zip(columns.iter())
+            .position(|(r, c)| r == column && c == row)
+            .unwrap();
+        assert_eq!(values[index], value.conj());
+    }
+}
+
+// Test the serialization and deserialization of SpinSOperator and SpinSHamiltonian
+#[test]
+fn serde_json() {
+    let mut so = SpinSOperator::new();
+    so.add_operator_product(
+        SpinSProduct::new().plus(0).z(0).minus(3),
+        CalculatorComplex::new(1.0, "theta"),
+    )
+    .unwrap();
+    let serialized = serde_json::to_string(&so).unwrap();
+    assert!(serialized.contains("0+0Z3-"));
+    let deserialized: SpinSOperator = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(so, deserialized);
+
+    let encoded: Vec<u8> = bincode::serialize(&so).unwrap();
+    let decoded: SpinSOperator = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(so, decoded);
+
+    let mut sh = SpinSHamiltonian::new();
+    sh.add_operator_product(
+        SpinSProduct::new().x(0).y(1),
+        CalculatorComplex::from("theta"),
+    )
+    .unwrap();
+    let serialized = serde_json::to_string(&sh).unwrap();
+    let deserialized: SpinSHamiltonian = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(sh, deserialized);
+}
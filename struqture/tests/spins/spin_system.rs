@@ -13,7 +13,7 @@
 //! Integration test for public API of SpinSystem
 
 use super::create_na_matrix_from_operator_list;
-use num_complex::Complex64;
+use num_complex::{Complex32, Complex64};
 use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
 use serde_test::{assert_tokens, Configure, Token};
 use std::collections::{BTreeMap, HashMap};
@@ -24,7 +24,10 @@ use struqture::prelude::*;
 use struqture::spins::{
     OperateOnSpins, PauliProduct, SpinOperator, SpinSystem, ToSparseMatrixOperator,
 };
-use struqture::{CooSparseMatrix, OperateOnDensityMatrix, SpinIndex, StruqtureError};
+use struqture::{
+    CooSparseMatrix, CooSparseMatrixF32, CsrSparseMatrix, CsrSparseMatrixF32,
+    OperateOnDensityMatrix, RoundingDiagnostics, SpinIndex, StruqtureError, ZeroTermPolicy,
+};
 use test_case::test_case;
 
 // Test the new function of the SpinSystem
@@ -399,6 +402,217 @@ fn separate_out_terms(number_spins: usize) {
     assert_eq!(result.1, remainder);
 }
 
+#[test]
+fn matrices_f32() {
+    let pp_0: PauliProduct = PauliProduct::new().z(0);
+    let pp_1: PauliProduct = PauliProduct::new().x(0);
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(pp_0, CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(pp_1, CalculatorComplex::from(0.1))
+        .unwrap();
+
+    let (coo, diagnostics) = system.sparse_matrix_coo_f32(None).unwrap();
+    let coo_f64 = system.sparse_matrix_coo(None).unwrap();
+    assert_eq!(coo.1, coo_f64.1);
+    for (single, double) in coo.0.iter().zip(coo_f64.0.iter()) {
+        assert_eq!(*single, Complex32::new(double.re as f32, double.im as f32));
+    }
+    assert!(diagnostics.max_absolute_error > 0.0);
+    assert!(diagnostics.max_absolute_error < 1e-7);
+    assert!(diagnostics.max_relative_error < 1e-6);
+    assert_eq!(diagnostics.number_underflows, 0);
+
+    let (superoperator, _) = system.sparse_matrix_superoperator_coo_f32(None).unwrap();
+    let superoperator_f64 = system.sparse_matrix_superoperator_coo(None).unwrap();
+    assert_eq!(superoperator.1, superoperator_f64.1);
+    assert_eq!(superoperator.0.len(), superoperator_f64.0.len());
+
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let expected: CooSparseMatrixF32 = (
+        vec![Complex32::new(1.0, 0.0), Complex32::new(-1.0, 0.0)],
+        (vec![0, 1], vec![0, 1]),
+    );
+    assert_eq!(
+        system.sparse_matrix_coo_f32(None).unwrap(),
+        (expected, RoundingDiagnostics::default())
+    );
+    let expected: CsrSparseMatrixF32 = (
+        vec![Complex32::new(1.0, 0.0), Complex32::new(-1.0, 0.0)],
+        vec![0, 1],
+        vec![0, 1, 2],
+    );
+    assert_eq!(
+        system.sparse_matrix_csr_f32(None).unwrap(),
+        (expected, RoundingDiagnostics::default())
+    );
+}
+
+#[test]
+fn matrices_csr_f32() {
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(1).y(0), CalculatorComplex::from(0.1))
+        .unwrap();
+
+    for (single, double) in [
+        (
+            system.sparse_matrix_csr_f32(None).unwrap(),
+            system.sparse_matrix_csr(None).unwrap(),
+        ),
+        (
+            system.sparse_matrix_superoperator_csr_f32(None).unwrap(),
+            system.sparse_matrix_superoperator_csr(None).unwrap(),
+        ),
+    ] {
+        let ((values, columns, row_pointers), diagnostics) = single;
+        assert_eq!(columns, double.1);
+        assert_eq!(row_pointers, double.2);
+        for (single, double) in values.iter().zip(double.0.iter()) {
+            assert_eq!(*single, Complex32::new(double.re as f32, double.im as f32));
+        }
+        assert!(diagnostics.max_absolute_error > 0.0);
+        assert!(diagnostics.max_relative_error < 1e-6);
+    }
+
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1e50))
+        .unwrap();
+    assert!(matches!(
+        system.sparse_matrix_csr_f32(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        system.sparse_matrix_superoperator_csr_f32(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[test]
+fn matrices_f32_range() {
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1e-50))
+        .unwrap();
+    let (_, diagnostics) = system.sparse_matrix_coo_f32(None).unwrap();
+    assert_eq!(diagnostics.number_underflows, 2);
+
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1e50))
+        .unwrap();
+    assert!(matches!(
+        system.sparse_matrix_coo_f32(None),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[test]
+fn matrices_csr() {
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(1).y(0), CalculatorComplex::from(0.2))
+        .unwrap();
+
+    let matrix = system.sparse_matrix(None).unwrap();
+    let superoperator = system.sparse_matrix_superoperator(None).unwrap();
+    for (csr, expected, dimension) in [
+        (system.sparse_matrix_csr(None).unwrap(), matrix, 4),
+        (
+            system.sparse_matrix_superoperator_csr(None).unwrap(),
+            superoperator,
+            16,
+        ),
+    ] {
+        let (values, columns, row_pointers) = csr;
+        assert_eq!(row_pointers.len(), dimension + 1);
+        assert_eq!(row_pointers[dimension], values.len());
+        assert_eq!(columns.len(), values.len());
+        let mut reconstructed: HashMap<(usize, usize), Complex64> = HashMap::new();
+        for row in 0..dimension {
+            let row_columns = &columns[row_pointers[row]..row_pointers[row + 1]];
+            assert!(row_columns.windows(2).all(|pair| pair[0] < pair[1]));
+            for index in row_pointers[row]..row_pointers[row + 1] {
+                reconstructed.insert((row, columns[index]), values[index]);
+            }
+        }
+        assert_eq!(reconstructed, expected);
+    }
+
+    let mut system = SpinSystem::new(Some(1));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    let expected: CsrSparseMatrix = (
+        vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)],
+        vec![0, 1],
+        vec![0, 1, 2],
+    );
+    assert_eq!(system.sparse_matrix_csr(None).unwrap(), expected);
+}
+
+#[test]
+fn dense_matrices() {
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .add_operator_product(PauliProduct::new().z(0), CalculatorComplex::from(0.5))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(1).y(0), CalculatorComplex::from(0.2))
+        .unwrap();
+
+    let dense = system.to_dense_matrix(None).unwrap();
+    assert_eq!(dense.dim(), (4, 4));
+    let sparse = system.sparse_matrix(None).unwrap();
+    for ((row, column), value) in dense.indexed_iter() {
+        assert_eq!(
+            *value,
+            *sparse
+                .get(&(row, column))
+                .unwrap_or(&Complex64::new(0.0, 0.0))
+        );
+    }
+
+    let dense_superoperator = system.to_dense_superoperator_matrix(Some(3)).unwrap();
+    assert_eq!(dense_superoperator.dim(), (64, 64));
+    let sparse_superoperator = system.sparse_matrix_superoperator(Some(3)).unwrap();
+    for ((row, column), value) in dense_superoperator.indexed_iter() {
+        assert_eq!(
+            *value,
+            *sparse_superoperator
+                .get(&(row, column))
+                .unwrap_or(&Complex64::new(0.0, 0.0))
+        );
+    }
+
+    let (dense_f32, diagnostics) = system.to_dense_matrix_f32(None).unwrap();
+    assert_eq!(dense_f32.dim(), (4, 4));
+    for (single, double) in dense_f32.iter().zip(dense.iter()) {
+        assert_eq!(*single, Complex32::new(double.re as f32, double.im as f32));
+    }
+    assert!(diagnostics.max_relative_error < 1e-6);
+    let (dense_superoperator_f32, _) = system.to_dense_superoperator_matrix_f32(Some(3)).unwrap();
+    assert_eq!(dense_superoperator_f32.dim(), (64, 64));
+    for (single, double) in dense_superoperator_f32
+        .iter()
+        .zip(dense_superoperator.iter())
+    {
+        assert_eq!(*single, Complex32::new(double.re as f32, double.im as f32));
+    }
+}
+
 #[test]
 fn matrices() {
     let pp_0: PauliProduct = PauliProduct::new().z(0);
@@ -711,6 +925,47 @@ fn serde_json() {
     assert_eq!(ss, deserialized);
 }
 
+// Test the zero-term policy of the SpinSystem, which is stored in its SpinOperator
+#[test]
+fn zero_term_policy() {
+    let pp = PauliProduct::new().z(0);
+    let threshold = ZeroTermPolicy::DropBelowThreshold(1e-12);
+    let mut system = SpinSystem::new(Some(2));
+    system
+        .set(pp.clone(), CalculatorComplex::from(1e-20))
+        .unwrap();
+    assert_eq!(system.zero_term_policy(), ZeroTermPolicy::DropExactZeros);
+
+    system.set_zero_term_policy(threshold).unwrap();
+    assert!(system.is_empty());
+    assert_eq!(system.operator().zero_term_policy(), threshold);
+    assert!(system
+        .set_zero_term_policy(ZeroTermPolicy::DropBelowThreshold(f64::NAN))
+        .is_err());
+
+    system
+        .set(pp.clone(), CalculatorComplex::from(0.3))
+        .unwrap();
+    system
+        .add_operator_product(pp.clone(), CalculatorComplex::from(-0.1 - 0.2))
+        .unwrap();
+    assert!(system.is_empty());
+    assert_eq!(system.empty_clone(None).zero_term_policy(), threshold);
+
+    system
+        .set(pp.clone(), CalculatorComplex::from(1e-7))
+        .unwrap();
+    assert!((system.clone() * system.clone()).is_empty());
+    assert!((system.clone() * CalculatorFloat::from(1e-6)).is_empty());
+
+    let serialized = serde_json::to_string(&system).unwrap();
+    let deserialized: SpinSystem = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.zero_term_policy(), threshold);
+    let encoded: Vec<u8> = bincode::serialize(&system).unwrap();
+    let decoded: SpinSystem = bincode::deserialize(&encoded[..]).unwrap();
+    assert_eq!(decoded, system);
+}
+
 /// Test SpinSystem Serialization and Deserialization traits (readable)
 #[test]
 fn serde_readable() {
@@ -792,7 +1047,7 @@ fn serde_compact() {
         &[
             Token::Struct {
                 name: "SpinSystem",
-                len: 2,
+                len: 3,
             },
             Token::Str("number_spins"),
             Token::Some,
@@ -800,7 +1055,7 @@ fn serde_compact() {
             Token::Str("operator"),
             Token::Struct {
                 name: "SpinOperatorSerialize",
-                len: 2,
+                len: 3,
             },
             Token::Str("items"),
             Token::Seq { len: Some(1) },
@@ -826,6 +1081,11 @@ fn serde_compact() {
             Token::F64(0.0),
             Token::TupleEnd,
             Token::SeqEnd,
+            Token::Str("zero_term_policy"),
+            Token::UnitVariant {
+                name: "ZeroTermPolicy",
+                variant: "DropExactZeros",
+            },
             Token::Str("_struqture_version"),
             Token::Struct {
                 name: "StruqtureVersionSerializable",
@@ -837,6 +1097,9 @@ fn serde_compact() {
             Token::U32(minor_version),
             Token::StructEnd,
             Token::StructEnd,
+            Token::Str("metadata"),
+            Token::Map { len: Some(0) },
+            Token::MapEnd,
             Token::StructEnd,
         ],
     );
@@ -1049,6 +1312,75 @@ fn test_truncate(re: f64, im: f64) {
     assert_eq!(test_system2, comparison_system2);
 }
 
+// Test the approximate comparison of SpinSystems
+#[test]
+fn approx_eq() {
+    let mut ss_0 = SpinSystem::new(Some(2));
+    ss_0.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(100.0))
+        .unwrap();
+    let mut ss_1 = SpinSystem::new(Some(2));
+    ss_1.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(100.001))
+        .unwrap();
+    assert!(ss_0.approx_eq(&ss_1, 0.0, 1e-4));
+    assert!(!ss_0.approx_eq(&ss_1, 0.0, 1e-6));
+
+    let mut ss_2 = SpinSystem::new(Some(3));
+    ss_2.add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(100.0))
+        .unwrap();
+    assert!(!ss_0.approx_eq(&ss_2, 1.0, 1.0));
+}
+
+#[test]
+fn filter_terms_and_retain() {
+    let mut system = SpinSystem::new(Some(4));
+    system
+        .add_operator_product(PauliProduct::new().z(3), CalculatorComplex::from(1.0))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(2.0))
+        .unwrap();
+
+    let filtered = system.filter_terms(|product, _| product.get(&3).is_some());
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered.number_spins(), 4);
+
+    system.retain(|product, _| product.get(&0).is_some());
+    assert_eq!(system.len(), 1);
+    assert_eq!(system.number_spins(), 4);
+    assert_eq!(
+        system.get(&PauliProduct::new().x(0)),
+        &CalculatorComplex::from(2.0)
+    );
+}
+
+// Test the SystemDimension functions of the SpinSystem
+#[test]
+fn system_dimension() {
+    let mut system = SpinSystem::new(Some(3));
+    system
+        .add_operator_product(PauliProduct::new().x(0), CalculatorComplex::from(1.0))
+        .unwrap();
+    system
+        .add_operator_product(PauliProduct::new().z(1), CalculatorComplex::from(0.5))
+        .unwrap();
+
+    assert_eq!(system.hilbert_space_dimension(&[]).unwrap(), 8);
+    assert_eq!(system.superoperator_dimension(&[]).unwrap(), 64);
+    assert_eq!(system.estimated_matrix_memory(&[], 16).unwrap(), 256);
+    assert_eq!(
+        system.hilbert_space_dimension(&[2]),
+        Err(StruqtureError::MissmatchedNumberModes)
+    );
+
+    let large_system = SpinSystem::new(Some(40));
+    assert_eq!(large_system.hilbert_space_dimension(&[]).unwrap(), 1 << 40);
+    assert!(matches!(
+        large_system.superoperator_dimension(&[]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(large_system.estimated_matrix_memory(&[], 16).is_ok());
+}
+
 #[cfg(feature = "json_schema")]
 #[test_case(None)]
 #[test_case(Some(3))]
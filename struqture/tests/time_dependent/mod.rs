@@ -0,0 +1,170 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of time-dependent Hamiltonians
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::bosons::{BosonHamiltonian, BosonProduct, HermitianBosonProduct};
+use struqture::mixed_systems::{HermitianMixedProduct, MixedHamiltonian};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, SpinHamiltonian};
+use struqture::time_dependent::{TimeDependentHamiltonian, TimeEnvelope};
+use struqture::StruqtureError;
+use test_case::test_case;
+
+#[test_case(-1.0, 0.0; "before")]
+#[test_case(0.0, 1.0; "first interval start")]
+#[test_case(0.5, 1.0; "first interval")]
+#[test_case(1.0, -2.0; "second interval start")]
+#[test_case(2.5, 0.5; "third interval")]
+#[test_case(3.0, 0.0; "end")]
+fn piecewise_constant_envelope(time: f64, expected: f64) {
+    let envelope =
+        TimeEnvelope::piecewise_constant(vec![0.0, 1.0, 2.0, 3.0], vec![1.0, -2.0, 0.5]).unwrap();
+    assert_eq!(envelope.evaluate_at(time).unwrap(), expected);
+}
+
+#[test]
+fn piecewise_constant_envelope_errors() {
+    assert!(matches!(
+        TimeEnvelope::piecewise_constant(vec![0.0, 1.0], vec![1.0, 2.0]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        TimeEnvelope::piecewise_constant(vec![0.0, 2.0, 1.0], vec![1.0, 2.0]),
+        Err(StruqtureError::GenericError { .. })
+    ));
+}
+
+#[test]
+fn symbolic_envelope() {
+    assert_eq!(
+        TimeEnvelope::from(CalculatorFloat::from(2.0))
+            .evaluate_at(5.0)
+            .unwrap(),
+        2.0
+    );
+    assert_eq!(
+        TimeEnvelope::from(CalculatorFloat::from("2.0 * t + 1.0"))
+            .evaluate_at(0.5)
+            .unwrap(),
+        2.0
+    );
+    assert!(matches!(
+        TimeEnvelope::from(CalculatorFloat::from("omega * t")).evaluate_at(0.5),
+        Err(StruqtureError::CalculatorError(_))
+    ));
+}
+
+#[test]
+fn spin_annealing_schedule() {
+    let mut driver = SpinHamiltonian::new();
+    driver
+        .add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    let mut problem = SpinHamiltonian::new();
+    problem
+        .add_operator_product(PauliProduct::new().z(0).z(1), "J".into())
+        .unwrap();
+    problem
+        .add_operator_product(PauliProduct::new().x(0), 2.0.into())
+        .unwrap();
+
+    let mut annealing = TimeDependentHamiltonian::new();
+    assert!(annealing.is_empty());
+    assert_eq!(annealing.evaluate_at(0.0).unwrap(), SpinHamiltonian::new());
+    annealing.add_term(driver.clone(), CalculatorFloat::from("1.0 - t"));
+    annealing.add_term(problem.clone(), CalculatorFloat::from("t"));
+    assert_eq!(annealing.len(), 2);
+    assert_eq!(annealing.terms()[0].0, driver);
+
+    let hamiltonian = annealing.evaluate_at(0.25).unwrap();
+    assert_eq!(hamiltonian.len(), 2);
+    assert_eq!(
+        hamiltonian.get(&PauliProduct::new().x(0)),
+        &CalculatorFloat::from(1.25)
+    );
+    assert_eq!(
+        hamiltonian.get(&PauliProduct::new().z(0).z(1)),
+        &(CalculatorFloat::from("J") * 0.25 + CalculatorFloat::from(0.0))
+    );
+
+    let start = annealing.evaluate_at(0.0).unwrap();
+    assert_eq!(start, driver);
+
+    let serialized = serde_json::to_string(&annealing).unwrap();
+    let deserialized: TimeDependentHamiltonian<SpinHamiltonian> =
+        serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, annealing);
+}
+
+#[test]
+fn boson_drive_sequence() {
+    let mut drive = BosonHamiltonian::new();
+    drive
+        .add_operator_product(
+            HermitianBosonProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::new(1.0, 0.5),
+        )
+        .unwrap();
+    let mut sequence = TimeDependentHamiltonian::new();
+    sequence.add_term(
+        drive,
+        TimeEnvelope::piecewise_constant(vec![0.0, 1.0, 2.0], vec![2.0, 0.0]).unwrap(),
+    );
+
+    let hamiltonian = sequence.evaluate_at(0.5).unwrap();
+    assert_eq!(
+        hamiltonian.get(&HermitianBosonProduct::new([0], [0]).unwrap()),
+        &CalculatorComplex::new(2.0, 1.0)
+    );
+    assert!(sequence.evaluate_at(1.5).unwrap().is_empty());
+}
+
+#[test]
+fn mixed_evaluate_at() {
+    let mut hamiltonian = MixedHamiltonian::new(1, 1, 0);
+    let key = HermitianMixedProduct::new(
+        [PauliProduct::new().z(0)],
+        [BosonProduct::new([0], [0]).unwrap()],
+        [],
+    )
+    .unwrap();
+    hamiltonian
+        .add_operator_product(key.clone(), CalculatorComplex::from(1.0))
+        .unwrap();
+    let mut other = MixedHamiltonian::new(2, 1, 0);
+    other
+        .add_operator_product(
+            HermitianMixedProduct::new(
+                [PauliProduct::new(), PauliProduct::new().x(0)],
+                [BosonProduct::new([0], [0]).unwrap()],
+                [],
+            )
+            .unwrap(),
+            CalculatorComplex::from(1.0),
+        )
+        .unwrap();
+
+    let mut time_dependent = TimeDependentHamiltonian::new();
+    time_dependent.add_term(hamiltonian.clone(), CalculatorFloat::from("cos(t)"));
+    assert_eq!(
+        time_dependent.evaluate_at(0.0).unwrap(),
+        hamiltonian.clone()
+    );
+    let evaluated = time_dependent.evaluate_at(0.0).unwrap();
+    assert_eq!(evaluated.number_spins(), vec![1]);
+    assert_eq!(evaluated.get(&key), &CalculatorComplex::from(1.0));
+
+    time_dependent.add_term(other, CalculatorFloat::from(1.0));
+    assert!(time_dependent.evaluate_at(0.0).is_err());
+}
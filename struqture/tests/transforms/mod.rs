@@ -0,0 +1,170 @@
+// Copyright © 2021-2023 HQS Quantum Simulations GmbH. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License"); you may not use this file except
+// in compliance with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software distributed under the
+// License is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either
+// express or implied. See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration test for public API of the transformations of Hamiltonians
+
+use qoqo_calculator::{CalculatorComplex, CalculatorFloat};
+use struqture::fermions::{FermionHamiltonian, FermionProduct, HermitianFermionProduct};
+use struqture::prelude::*;
+use struqture::spins::{PauliProduct, PlusMinusProduct, SinglePlusMinusOperator, SpinHamiltonian};
+use struqture::transforms::schrieffer_wolff;
+use struqture::StruqtureError;
+use test_case::test_case;
+
+/// Energy difference of a PlusMinusProduct for H0 = Σ_i h_i Z_i
+fn spin_energy_difference(fields: &[f64], product: &PlusMinusProduct) -> f64 {
+    product
+        .iter()
+        .map(|(index, operator)| match operator {
+            SinglePlusMinusOperator::Plus => 2.0 * fields[*index],
+            SinglePlusMinusOperator::Minus => -2.0 * fields[*index],
+            _ => 0.0,
+        })
+        .sum()
+}
+
+/// Energy difference of a FermionProduct for H0 = Σ_p ε_p c^†_p c_p
+fn fermion_energy_difference(energies: &[f64], product: &FermionProduct) -> f64 {
+    product.creators().map(|p| energies[*p]).sum::<f64>()
+        - product.annihilators().map(|p| energies[*p]).sum::<f64>()
+}
+
+// Test the second-order shift of a spin in a transverse field
+#[test_case(0, 1.0, 0.0; "order 0")]
+#[test_case(1, 1.0, 0.25; "order 1")]
+#[test_case(2, 1.5, 0.25; "order 2")]
+fn schrieffer_wolff_spin(order: usize, z_coefficient: f64, constant: f64) {
+    let fields = [1.0];
+    let mut h0 = SpinHamiltonian::new();
+    h0.add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    let mut v = SpinHamiltonian::new();
+    v.add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    v.add_operator_product(PauliProduct::new(), 0.25.into())
+        .unwrap();
+
+    let effective = schrieffer_wolff(&h0, &v, order, |product: &PlusMinusProduct| {
+        spin_energy_difference(&fields, product)
+    })
+    .unwrap();
+    let mut comparison = SpinHamiltonian::new();
+    comparison
+        .add_operator_product(PauliProduct::new().z(0), z_coefficient.into())
+        .unwrap();
+    if constant != 0.0 {
+        comparison
+            .add_operator_product(PauliProduct::new(), constant.into())
+            .unwrap();
+    }
+    assert_eq!(effective, comparison);
+}
+
+// Test the effective exchange between two spins coupled by an XX interaction
+#[test]
+fn schrieffer_wolff_spin_exchange() {
+    let fields = [1.0, 3.0];
+    let mut h0 = SpinHamiltonian::new();
+    h0.add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    h0.add_operator_product(PauliProduct::new().z(1), 3.0.into())
+        .unwrap();
+    let mut v = SpinHamiltonian::new();
+    v.add_operator_product(PauliProduct::new().x(0).x(1), 0.5.into())
+        .unwrap();
+
+    let effective = schrieffer_wolff(&h0, &v, 2, |product: &PlusMinusProduct| {
+        spin_energy_difference(&fields, product)
+    })
+    .unwrap();
+    // The levels |00> and |11> are shifted by ±1/32, the levels |01> and |10> by ∓1/16,
+    // which corresponds to -1/64 Z_0 + 3/64 Z_1 without a Z_0 Z_1 term
+    let z0 = effective.get(&PauliProduct::new().z(0)).float().unwrap();
+    let z1 = effective.get(&PauliProduct::new().z(1)).float().unwrap();
+    assert!((z0 - (1.0 - 1.0 / 64.0)).abs() < 1e-14);
+    assert!((z1 - (3.0 + 3.0 / 64.0)).abs() < 1e-14);
+    assert_eq!(effective.len(), 2);
+}
+
+// Test the second-order hopping correction of two fermionic modes
+#[test]
+fn schrieffer_wolff_fermion() {
+    let energies = [0.0, 4.0];
+    let mut h0 = FermionHamiltonian::new();
+    h0.add_operator_product(
+        HermitianFermionProduct::new([1], [1]).unwrap(),
+        CalculatorComplex::from(4.0),
+    )
+    .unwrap();
+    let mut v = FermionHamiltonian::new();
+    v.add_operator_product(
+        HermitianFermionProduct::new([0], [1]).unwrap(),
+        CalculatorComplex::from(1.0),
+    )
+    .unwrap();
+
+    let effective = schrieffer_wolff(&h0, &v, 2, |product: &FermionProduct| {
+        fermion_energy_difference(&energies, product)
+    })
+    .unwrap();
+    // The occupied mode 1 is pushed up and mode 0 down by t^2 / ε, the quartic terms cancel
+    let mut comparison = FermionHamiltonian::new();
+    comparison
+        .add_operator_product(
+            HermitianFermionProduct::new([0], [0]).unwrap(),
+            CalculatorComplex::from(-0.25),
+        )
+        .unwrap();
+    comparison
+        .add_operator_product(
+            HermitianFermionProduct::new([1], [1]).unwrap(),
+            CalculatorComplex::from(4.25),
+        )
+        .unwrap();
+    assert_eq!(effective, comparison);
+
+    // In first order the hopping couples different blocks and does not contribute
+    let first_order = schrieffer_wolff(&h0, &v, 1, |product: &FermionProduct| {
+        fermion_energy_difference(&energies, product)
+    })
+    .unwrap();
+    assert_eq!(first_order, h0);
+}
+
+// Test the errors of the Schrieffer-Wolff transformation
+#[test]
+fn schrieffer_wolff_errors() {
+    let mut h0 = SpinHamiltonian::new();
+    h0.add_operator_product(PauliProduct::new().z(0), 1.0.into())
+        .unwrap();
+    let mut v = SpinHamiltonian::new();
+    v.add_operator_product(PauliProduct::new().x(0), 1.0.into())
+        .unwrap();
+    let energy_difference = |product: &PlusMinusProduct| spin_energy_difference(&[1.0], product);
+
+    assert!(matches!(
+        schrieffer_wolff(&h0, &v, 3, energy_difference),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    assert!(matches!(
+        schrieffer_wolff(&h0, &v, 2, |_: &PlusMinusProduct| f64::NAN),
+        Err(StruqtureError::GenericError { .. })
+    ));
+    let mut symbolic = v.clone();
+    symbolic
+        .add_operator_product(PauliProduct::new().y(0), CalculatorFloat::from("g"))
+        .unwrap();
+    assert!(matches!(
+        schrieffer_wolff(&h0, &symbolic, 2, energy_difference),
+        Err(StruqtureError::CalculatorError(..))
+    ));
+}